@@ -8,14 +8,21 @@
 //! Credentials Provider for external process
 
 use crate::json_credentials::{json_parse_loop, InvalidJsonCredentials};
+use crate::provider_config::ProviderConfig;
 use crate::sensitive_command::CommandWithSensitiveArgs;
 use aws_credential_types::provider::{self, error::CredentialsError, future, ProvideCredentials};
 use aws_credential_types::Credentials;
+use aws_smithy_async::future::timeout::Timeout;
+use aws_smithy_async::rt::sleep::{AsyncSleep, SharedAsyncSleep};
 use aws_smithy_json::deserialize::Token;
-use std::process::Command;
-use std::time::SystemTime;
+use std::error::Error as StdError;
+use std::fmt;
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::{Duration, SystemTime};
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
+use tokio::io::AsyncReadExt;
 
 /// External process credentials provider
 ///
@@ -40,8 +47,9 @@ use time::OffsetDateTime;
 /// The `Expiration` is optional, and must be given in the RFC 3339 date time format (e.g.,
 /// `2022-05-26T12:34:56.789Z`).
 ///
-/// If the external process exits with a non-zero status, then the contents of `stderr`
-/// will be output as part of the credentials provider error message.
+/// If the external process exits with a non-zero status or times out, then the contents of
+/// `stderr` will be output as part of the credentials provider error message, and are available
+/// from its [`CredentialProcessError`] source.
 ///
 /// This credentials provider is included in the profile credentials provider, and can be
 /// configured using the `credential_process` attribute. For example:
@@ -50,11 +58,166 @@ use time::OffsetDateTime;
 /// [profile example]
 /// credential_process = /path/to/my/process --some --arguments
 /// ```
+///
+/// To configure how the process is run, such as its timeout, use the [`Builder`]:
+///
+/// ```no_run
+/// use aws_config::credential_process::CredentialProcessProvider;
+/// use std::time::Duration;
+///
+/// let provider = CredentialProcessProvider::builder()
+///     .command("/path/to/my/process --some --arguments")
+///     .timeout(Duration::from_secs(60))
+///     .working_directory("/path/to")
+///     .clear_env(true)
+///     .env("PATH", "/usr/bin")
+///     .build();
+/// ```
 #[derive(Debug)]
 pub struct CredentialProcessProvider {
     command: CommandWithSensitiveArgs<String>,
+    timeout: Option<Duration>,
+    working_directory: Option<PathBuf>,
+    clear_env: bool,
+    env: Vec<(String, String)>,
+    sleep_impl: Option<SharedAsyncSleep>,
+}
+
+/// Builder for [`CredentialProcessProvider`]
+#[derive(Debug, Default)]
+pub struct Builder {
+    provider_config: Option<ProviderConfig>,
+    command: Option<CommandWithSensitiveArgs<String>>,
+    timeout: Option<Duration>,
+    working_directory: Option<PathBuf>,
+    clear_env: bool,
+    env: Vec<(String, String)>,
+}
+
+impl Builder {
+    /// Override the configuration used for this provider
+    pub fn configure(mut self, provider_config: &ProviderConfig) -> Self {
+        self.provider_config = Some(provider_config.clone());
+        self
+    }
+
+    /// Set the command used to run the external process
+    ///
+    /// The command is run by the system shell (`sh -c` or `cmd.exe /C` on Windows).
+    ///
+    /// This is a required field.
+    pub fn command(mut self, command: impl Into<String>) -> Self {
+        self.command = Some(CommandWithSensitiveArgs::new(command.into()));
+        self
+    }
+
+    /// Set the maximum amount of time the external process may run for
+    ///
+    /// When the timeout elapses, the process is killed and an error is returned. By default,
+    /// there is no timeout, so that long-running processes (e.g., ones waiting for multi-factor
+    /// authentication) aren't interrupted.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the working directory the external process is run in
+    ///
+    /// By default, the process inherits the working directory of the current process.
+    pub fn working_directory(mut self, working_directory: impl Into<PathBuf>) -> Self {
+        self.working_directory = Some(working_directory.into());
+        self
+    }
+
+    /// Clear the environment of the external process
+    ///
+    /// By default, the process inherits the environment variables of the current process. When
+    /// cleared, it's only given the variables set with [`env`](Self::env), which keeps secrets
+    /// in the environment of the current process from being exposed to it.
+    pub fn clear_env(mut self, clear_env: bool) -> Self {
+        self.clear_env = clear_env;
+        self
+    }
+
+    /// Set an environment variable for the external process
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Create a [`CredentialProcessProvider`] from this builder.
+    ///
+    /// # Panics
+    /// This method will panic if the [`command`](Self::command) is unset.
+    pub fn build(self) -> CredentialProcessProvider {
+        let provider_config = self.provider_config.unwrap_or_default();
+        CredentialProcessProvider {
+            command: self.command.expect("command must be set"),
+            timeout: self.timeout,
+            working_directory: self.working_directory,
+            clear_env: self.clear_env,
+            env: self.env,
+            sleep_impl: provider_config.sleep_impl(),
+        }
+    }
+}
+
+/// An error running the external process of a [`CredentialProcessProvider`]
+///
+/// This is the source of the [`CredentialsError`] returned when the external process fails or
+/// times out.
+#[derive(Debug)]
+pub struct CredentialProcessError {
+    kind: CredentialProcessErrorKind,
+    stderr: String,
+}
+
+#[derive(Debug)]
+enum CredentialProcessErrorKind {
+    Exited(ExitStatus),
+    TimedOut(Duration),
+}
+
+impl CredentialProcessError {
+    fn new(kind: CredentialProcessErrorKind, stderr: &[u8]) -> Self {
+        Self {
+            kind,
+            stderr: std::str::from_utf8(stderr)
+                .unwrap_or("could not decode stderr as UTF-8")
+                .to_owned(),
+        }
+    }
+
+    /// Returns what the external process wrote to `stderr`
+    pub fn stderr(&self) -> &str {
+        &self.stderr
+    }
+
+    /// Returns true if the external process was killed because it timed out
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.kind, CredentialProcessErrorKind::TimedOut(_))
+    }
+}
+
+impl fmt::Display for CredentialProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            CredentialProcessErrorKind::Exited(status) => write!(
+                f,
+                "Error retrieving credentials: external process exited with code {}. Stderr: {}",
+                status, self.stderr
+            ),
+            CredentialProcessErrorKind::TimedOut(timeout) => write!(
+                f,
+                "Error retrieving credentials: external process timed out after {:?}. Stderr: {}",
+                timeout, self.stderr
+            ),
+        }
+    }
 }
 
+impl StdError for CredentialProcessError {}
+
 impl ProvideCredentials for CredentialProcessProvider {
     fn provide_credentials<'a>(&'a self) -> future::ProvideCredentials<'a>
     where
@@ -67,22 +230,30 @@ impl ProvideCredentials for CredentialProcessProvider {
 impl CredentialProcessProvider {
     /// Create new [`CredentialProcessProvider`] with the `command` needed to execute the external process.
     pub fn new(command: String) -> Self {
-        Self {
-            command: CommandWithSensitiveArgs::new(command),
-        }
+        Self::builder().command(command).build()
+    }
+
+    /// Create a builder for [`CredentialProcessProvider`]
+    pub fn builder() -> Builder {
+        Builder::default()
     }
 
     pub(crate) fn from_command(command: &CommandWithSensitiveArgs<&str>) -> Self {
-        Self {
-            command: command.to_owned_string(),
-        }
+        Self::builder().command(command.unredacted()).build()
+    }
+
+    fn spawn_error(err: std::io::Error) -> CredentialsError {
+        CredentialsError::provider_error(format!(
+            "Error retrieving credentials from external process: {}",
+            err
+        ))
     }
 
     async fn credentials(&self) -> provider::Result {
         // Security: command arguments must be redacted at debug level
         tracing::debug!(command = %self.command, "loading credentials from external process");
 
-        let command = if cfg!(windows) {
+        let mut command = if cfg!(windows) {
             let mut command = Command::new("cmd.exe");
             command.args(["/C", self.command.unredacted()]);
             command
@@ -91,29 +262,69 @@ impl CredentialProcessProvider {
             command.args(["-c", self.command.unredacted()]);
             command
         };
-        let output = tokio::process::Command::from(command)
-            .output()
-            .await
-            .map_err(|e| {
-                CredentialsError::provider_error(format!(
-                    "Error retrieving credentials from external process: {}",
-                    e
+        if let Some(working_directory) = &self.working_directory {
+            command.current_dir(working_directory);
+        }
+        if self.clear_env {
+            command.env_clear();
+        }
+        command.envs(self.env.iter().map(|(k, v)| (k, v)));
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let mut child = tokio::process::Command::from(command)
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(Self::spawn_error)?;
+
+        // Read both outputs while waiting for the process to exit, so that whatever it wrote to
+        // `stderr` is kept if it times out.
+        let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+        let mut stdout_pipe = child.stdout.take().expect("piped");
+        let mut stderr_pipe = child.stderr.take().expect("piped");
+        let run = async {
+            tokio::try_join!(
+                stdout_pipe.read_to_end(&mut stdout),
+                stderr_pipe.read_to_end(&mut stderr)
+            )?;
+            child.wait().await
+        };
+        let status = match (self.timeout, &self.sleep_impl) {
+            (Some(timeout), Some(sleep_impl)) => {
+                let result = Timeout::new(run, sleep_impl.sleep(timeout)).await;
+                match result {
+                    Ok(status) => status,
+                    Err(_) => {
+                        let _ = child.kill().await;
+                        return Err(CredentialsError::provider_error(
+                            CredentialProcessError::new(
+                                CredentialProcessErrorKind::TimedOut(timeout),
+                                &stderr,
+                            ),
+                        ));
+                    }
+                }
+            }
+            (Some(_), None) => {
+                return Err(CredentialsError::invalid_configuration(
+                    "a sleep implementation is required to time out the external process",
                 ))
-            })?;
+            }
+            (None, _) => run.await,
+        }
+        .map_err(Self::spawn_error)?;
 
         // Security: command arguments can be logged at trace level
-        tracing::trace!(command = ?self.command, status = ?output.status, "executed command (unredacted)");
+        tracing::trace!(command = ?self.command, status = ?status, "executed command (unredacted)");
 
-        if !output.status.success() {
-            let reason =
-                std::str::from_utf8(&output.stderr).unwrap_or("could not decode stderr as UTF-8");
-            return Err(CredentialsError::provider_error(format!(
-                "Error retrieving credentials: external process exited with code {}. Stderr: {}",
-                output.status, reason
-            )));
+        if !status.success() {
+            return Err(CredentialsError::provider_error(
+                CredentialProcessError::new(CredentialProcessErrorKind::Exited(status), &stderr),
+            ));
         }
 
-        let output = std::str::from_utf8(&output.stdout).map_err(|e| {
+        let output = std::str::from_utf8(&stdout).map_err(|e| {
             CredentialsError::provider_error(format!(
                 "Error retrieving credentials from external process: could not decode output as UTF-8: {}",
                 e
@@ -217,8 +428,10 @@ fn parse_expiration(expiration: impl AsRef<str>) -> Result<SystemTime, InvalidJs
 
 #[cfg(test)]
 mod test {
-    use crate::credential_process::CredentialProcessProvider;
+    use crate::credential_process::{CredentialProcessError, CredentialProcessProvider};
+    use aws_credential_types::provider::error::CredentialsError;
     use aws_credential_types::provider::ProvideCredentials;
+    use std::error::Error;
     use std::time::{Duration, SystemTime};
     use time::format_description::well_known::Rfc3339;
     use time::OffsetDateTime;
@@ -268,4 +481,56 @@ mod test {
             .await
             .expect_err("timeout forced");
     }
+
+    fn process_error(err: &CredentialsError) -> &CredentialProcessError {
+        err.source()
+            .and_then(|source| source.downcast_ref::<CredentialProcessError>())
+            .expect("the source is a CredentialProcessError")
+    }
+
+    // TODO(https://github.com/awslabs/aws-sdk-rust/issues/1117) This test is ignored on Windows because it uses Unix-style paths
+    #[tokio::test]
+    #[cfg_attr(windows, ignore)]
+    async fn failures_include_stderr() {
+        let provider =
+            CredentialProcessProvider::new(String::from("echo 'token expired' >&2; exit 1"));
+        let err = provider.provide_credentials().await.expect_err("failed");
+        let process_error = process_error(&err);
+        assert!(!process_error.is_timeout());
+        assert_eq!("token expired\n", process_error.stderr());
+    }
+
+    // TODO(https://github.com/awslabs/aws-sdk-rust/issues/1117) This test is ignored on Windows because it uses Unix-style paths
+    #[tokio::test]
+    #[cfg_attr(windows, ignore)]
+    async fn timeouts_kill_the_process_and_include_stderr() {
+        let provider = CredentialProcessProvider::builder()
+            .command("echo 'waiting for MFA' >&2; sleep 1000")
+            .timeout(Duration::from_millis(500))
+            .build();
+        let err = timeout(Duration::from_secs(10), provider.provide_credentials())
+            .await
+            .expect("timed out by the provider")
+            .expect_err("timed out");
+        let process_error = process_error(&err);
+        assert!(process_error.is_timeout());
+        assert_eq!("waiting for MFA\n", process_error.stderr());
+    }
+
+    // TODO(https://github.com/awslabs/aws-sdk-rust/issues/1117) This test is ignored on Windows because it uses Unix-style paths
+    #[tokio::test]
+    #[cfg_attr(windows, ignore)]
+    async fn process_environment_can_be_configured() {
+        std::env::set_var("CREDENTIAL_PROCESS_TEST_SECRET", "inherited");
+        let provider = CredentialProcessProvider::builder()
+            .command(r#"echo "{ \"Version\": 1, \"AccessKeyId\": \"$ACCESS_KEY_ID\", \"SecretAccessKey\": \"$PWD\", \"SessionToken\": \"token$CREDENTIAL_PROCESS_TEST_SECRET\" }""#)
+            .working_directory("/")
+            .clear_env(true)
+            .env("ACCESS_KEY_ID", "ASIARTESTID")
+            .build();
+        let creds = provider.provide_credentials().await.expect("valid creds");
+        assert_eq!(creds.access_key_id(), "ASIARTESTID");
+        assert_eq!(creds.secret_access_key(), "/");
+        assert_eq!(creds.session_token(), Some("token"));
+    }
 }
@@ -48,6 +48,9 @@ pub mod use_fips;
 /// Default dual-stack provider chain
 pub mod use_dual_stack;
 
+/// Default "account ID endpoint mode" provider chain
+pub mod account_id_endpoint_mode;
+
 /// Default access token provider chain
 #[cfg(feature = "sso")]
 pub mod token;
@@ -10,7 +10,7 @@ use aws_credential_types::Credentials;
 use tracing::Instrument;
 
 use crate::environment::credentials::EnvironmentVariableCredentialsProvider;
-use crate::meta::credentials::CredentialsProviderChain;
+use crate::meta::credentials::{CredentialsChainDiagnostics, CredentialsProviderChain};
 use crate::meta::region::ProvideRegion;
 use crate::provider_config::ProviderConfig;
 
@@ -100,6 +100,7 @@ pub struct Builder {
     region_override: Option<Box<dyn ProvideRegion>>,
     region_chain: crate::default_provider::region::Builder,
     conf: Option<ProviderConfig>,
+    diagnostics: Option<CredentialsChainDiagnostics>,
 }
 
 impl Builder {
@@ -160,6 +161,14 @@ impl Builder {
         self
     }
 
+    /// Record each evaluation of the chain in `diagnostics`
+    ///
+    /// See [`CredentialsChainDiagnostics`] for more details.
+    pub fn diagnostics(mut self, diagnostics: CredentialsChainDiagnostics) -> Self {
+        self.diagnostics = Some(diagnostics);
+        self
+    }
+
     /// Override the configuration used for this provider
     pub fn configure(mut self, config: ProviderConfig) -> Self {
         self.region_chain = self.region_chain.configure(&config);
@@ -186,11 +195,14 @@ impl Builder {
         let imds_provider = self.imds_builder.configure(&conf).build();
         let ecs_provider = self.ecs_builder.configure(&conf).build();
 
-        let provider_chain = CredentialsProviderChain::first_try("Environment", env_provider)
+        let mut provider_chain = CredentialsProviderChain::first_try("Environment", env_provider)
             .or_else("Profile", profile_provider)
             .or_else("WebIdentityToken", web_identity_token_provider)
             .or_else("EcsContainer", ecs_provider)
             .or_else("Ec2InstanceMetadata", imds_provider);
+        if let Some(diagnostics) = self.diagnostics {
+            provider_chain = provider_chain.with_diagnostics(diagnostics);
+        }
 
         DefaultCredentialsChain { provider_chain }
     }
@@ -3,7 +3,7 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use crate::environment::parse_uint;
+use crate::environment::parse_byte_size;
 use crate::provider_config::ProviderConfig;
 use aws_runtime::env_config::EnvConfigValue;
 use aws_smithy_types::error::display::DisplayErrorContext;
@@ -24,6 +24,9 @@ mod profile_key {
 /// 1. The environment variable `AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES=10240`
 /// 2. The profile key `request_min_compression_size_bytes=10240`
 ///
+/// Values are a number of bytes, optionally with a unit, e.g. `10KiB`. See
+/// [`parse_byte_size`](aws_smithy_types::units::parse_byte_size) for the accepted units.
+///
 /// If invalid values are found, the provider will return None and an error will be logged.
 pub(crate) async fn request_min_compression_size_bytes_provider(
     provider_config: &ProviderConfig,
@@ -34,7 +37,7 @@ pub(crate) async fn request_min_compression_size_bytes_provider(
     EnvConfigValue::new()
         .env(env::REQUEST_MIN_COMPRESSION_SIZE_BYTES)
         .profile(profile_key::REQUEST_MIN_COMPRESSION_SIZE_BYTES)
-        .validate(&env, profiles, parse_uint)
+        .validate(&env, profiles, parse_byte_size)
         .map_err(
             |err| tracing::warn!(err = %DisplayErrorContext(&err), "invalid value for `request minimum compression size bytes` setting"),
         )
@@ -67,6 +70,34 @@ mod test {
         assert!(logs_contain("AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES"));
     }
 
+    #[tokio::test]
+    async fn byte_size_units() {
+        let conf = ProviderConfig::empty().with_env(Env::from_slice(&[(
+            "AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES",
+            "10KiB",
+        )]));
+        assert_eq!(
+            request_min_compression_size_bytes_provider(&conf).await,
+            Some(10240)
+        );
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn log_error_on_value_too_large() {
+        let conf = ProviderConfig::empty().with_env(Env::from_slice(&[(
+            "AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES",
+            "4GiB",
+        )]));
+        assert_eq!(
+            request_min_compression_size_bytes_provider(&conf).await,
+            None
+        );
+        assert!(logs_contain(
+            "invalid value for `request minimum compression size bytes` setting"
+        ));
+    }
+
     #[tokio::test]
     #[traced_test]
     async fn environment_priority() {
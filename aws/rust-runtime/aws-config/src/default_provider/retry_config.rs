@@ -88,10 +88,14 @@ impl Builder {
     ///
     /// Precedence is considered on a per-field basis
     ///
+    /// The legacy retry mode name `legacy`, which other AWS SDKs support, is treated as
+    /// `standard` and a warning is logged. Combinations of settings that are valid but likely
+    /// unintended, such as `adaptive` mode with `max_attempts` set to 1, are also logged.
+    ///
     /// # Panics
     ///
     /// - Panics if the `AWS_MAX_ATTEMPTS` env var or `max_attempts` profile var is set to 0
-    /// - Panics if the `AWS_RETRY_MODE` env var or `retry_mode` profile var is set to "adaptive" (it's not yet supported)
+    /// - Panics if the `AWS_RETRY_MODE` env var or `retry_mode` profile var is set to an unrecognized mode
     pub async fn retry_config(self) -> RetryConfig {
         match self.try_retry_config().await {
             Ok(conf) => conf,
@@ -116,10 +120,7 @@ impl Builder {
         let retry_mode = EnvConfigValue::new()
             .env(env::RETRY_MODE)
             .profile(profile_keys::RETRY_MODE)
-            .validate(&env, profiles, |s| {
-                RetryMode::from_str(s)
-                    .map_err(|err| RetryConfigErrorKind::InvalidRetryMode { source: err }.into())
-            });
+            .validate(&env, profiles, validate_retry_mode);
 
         if let Some(max_attempts) = max_attempts? {
             retry_config = retry_config.with_max_attempts(max_attempts);
@@ -129,10 +130,41 @@ impl Builder {
             retry_config = retry_config.with_retry_mode(retry_mode);
         }
 
+        warn_on_conflicting_settings(&retry_config);
         Ok(retry_config)
     }
 }
 
+/// Logs a warning for retry settings that are valid individually but conflict with each other.
+///
+/// This is also applied to a [`RetryConfig`] given directly to [`ConfigLoader`](crate::ConfigLoader),
+/// since programmatic configuration bypasses the validation of environment and profile values.
+pub(crate) fn warn_on_conflicting_settings(retry_config: &RetryConfig) {
+    if retry_config.max_attempts() == 0 {
+        tracing::warn!(
+            "retry config has `max_attempts` set to 0, which is invalid. \
+             Use `RetryConfig::disabled()` to make a single attempt without retries."
+        );
+    } else if retry_config.mode() == RetryMode::Adaptive && retry_config.max_attempts() == 1 {
+        tracing::warn!(
+            "retry config uses `adaptive` retry mode with `max_attempts` set to 1. \
+             Failed requests will not be retried, so adaptive mode can only throttle initial attempts. \
+             Set `max_attempts` to a value greater than 1 to retry throttled requests."
+        );
+    }
+}
+
+fn validate_retry_mode(retry_mode: &str) -> Result<RetryMode, RetryConfigError> {
+    if retry_mode.trim().eq_ignore_ascii_case("legacy") {
+        tracing::warn!(
+            "retry mode `legacy` is not supported by the AWS SDK for Rust and will be treated as `standard`"
+        );
+        return Ok(RetryMode::Standard);
+    }
+    RetryMode::from_str(retry_mode)
+        .map_err(|source| RetryConfigErrorKind::InvalidRetryMode { source }.into())
+}
+
 fn validate_max_attempts(max_attempts: &str) -> Result<u32, RetryConfigError> {
     match max_attempts.parse::<u32>() {
         Ok(0) => Err(RetryConfigErrorKind::MaxAttemptsMustNotBeZero.into()),
@@ -149,7 +181,9 @@ mod test {
         error::RetryConfigError, error::RetryConfigErrorKind, RetryConfig, RetryMode,
     };
     use aws_runtime::env_config::EnvConfigError;
+    use aws_smithy_types::error::display::DisplayErrorContext;
     use aws_types::os_shim_internal::{Env, Fs};
+    use tracing_test::traced_test;
 
     async fn test_provider(
         vars: &[(&str, &str)],
@@ -338,4 +372,46 @@ max_attempts = potato
             }
         ));
     }
+
+    #[tokio::test]
+    async fn adaptive_retry_mode_is_read_correctly() {
+        assert_eq!(
+            test_provider(&[(env::RETRY_MODE, "Adaptive"), (env::MAX_ATTEMPTS, "5")])
+                .await
+                .unwrap(),
+            RetryConfig::adaptive().with_max_attempts(5)
+        );
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn legacy_retry_mode_is_treated_as_standard() {
+        assert_eq!(
+            test_provider(&[(env::RETRY_MODE, "legacy")]).await.unwrap(),
+            RetryConfig::standard()
+        );
+        assert!(logs_contain("retry mode `legacy` is not supported"));
+    }
+
+    #[tokio::test]
+    async fn unrecognized_retry_mode_lists_valid_modes() {
+        let err = test_provider(&[(env::RETRY_MODE, "aggressive")])
+            .await
+            .unwrap_err();
+        let message = format!("{}", DisplayErrorContext(&err));
+        assert!(message.contains("`AWS_RETRY_MODE`"), "{message}");
+        assert!(message.contains("`standard` or `adaptive`"), "{message}");
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn adaptive_mode_without_retries_is_reported() {
+        let config = test_provider(&[(env::RETRY_MODE, "adaptive"), (env::MAX_ATTEMPTS, "1")])
+            .await
+            .unwrap();
+        assert_eq!(config, RetryConfig::adaptive().with_max_attempts(1));
+        assert!(logs_contain(
+            "`adaptive` retry mode with `max_attempts` set to 1"
+        ));
+    }
 }
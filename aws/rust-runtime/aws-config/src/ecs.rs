@@ -63,6 +63,7 @@ use aws_smithy_runtime_api::client::dns::{ResolveDns, ResolveDnsError, SharedDns
 use aws_smithy_runtime_api::client::http::HttpConnectorSettings;
 use aws_smithy_runtime_api::shared::IntoShared;
 use aws_smithy_types::error::display::DisplayErrorContext;
+use aws_smithy_types::retry::RetryConfig;
 use aws_types::os_shim_internal::{Env, Fs};
 use http::header::InvalidHeaderValue;
 use http::uri::{InvalidUri, PathAndQuery, Scheme};
@@ -203,15 +204,18 @@ impl Provider {
         .expect("parts will be valid")
         .to_string();
 
-        let http_provider = HttpCredentialProvider::builder()
+        let mut http_provider = HttpCredentialProvider::builder()
             .configure(&provider_config)
             .http_connector_settings(
                 HttpConnectorSettings::builder()
                     .connect_timeout(DEFAULT_CONNECT_TIMEOUT)
                     .read_timeout(DEFAULT_READ_TIMEOUT)
                     .build(),
-            )
-            .build("EcsContainer", &endpoint, path_and_query);
+            );
+        if let Some(retry_config) = builder.retry_config {
+            http_provider = http_provider.retry_config(retry_config);
+        }
+        let http_provider = http_provider.build("EcsContainer", &endpoint, path_and_query);
         Provider::Configured(http_provider)
     }
 
@@ -292,6 +296,7 @@ pub struct Builder {
     dns: Option<SharedDnsResolver>,
     connect_timeout: Option<Duration>,
     read_timeout: Option<Duration>,
+    retry_config: Option<RetryConfig>,
 }
 
 impl Builder {
@@ -326,6 +331,16 @@ impl Builder {
         self
     }
 
+    /// Override the retry config used when the container credentials endpoint can't be reached or
+    /// returns a 5xx error
+    ///
+    /// This defaults to [`RetryConfig::standard`]: 3 attempts with jittered exponential backoff.
+    /// Retries require a sleep implementation, which is provided by the default [`ProviderConfig`].
+    pub fn retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = Some(retry_config);
+        self
+    }
+
     /// Create an [`EcsCredentialsProvider`] from this builder
     pub fn build(self) -> EcsCredentialsProvider {
         let env = self
@@ -3,11 +3,16 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::default_provider::ignore_configured_endpoint_urls::ignore_configured_endpoint_urls;
+use crate::environment::parse_url;
 use aws_runtime::env_config::section::EnvConfigSections;
 use aws_runtime::env_config::EnvConfigValue;
+use aws_smithy_types::error::display::DisplayErrorContext;
 use aws_types::os_shim_internal::Env;
 use aws_types::service_config::{LoadServiceConfig, ServiceConfigKey};
 
+const ENDPOINT_URL_PROFILE_KEY: &str = "endpoint_url";
+
 #[derive(Debug)]
 pub(crate) struct EnvServiceConfig {
     pub(crate) env: Env,
@@ -16,12 +21,138 @@ pub(crate) struct EnvServiceConfig {
 
 impl LoadServiceConfig for EnvServiceConfig {
     fn load_config(&self, key: ServiceConfigKey<'_>) -> Option<String> {
+        // Endpoint URLs get the same treatment as the global endpoint URL loaded by `ConfigLoader`:
+        // they can be ignored altogether, and invalid URLs are logged and ignored.
+        let is_endpoint_url = key.profile() == ENDPOINT_URL_PROFILE_KEY;
+        if is_endpoint_url
+            && ignore_configured_endpoint_urls(&self.env, Some(&self.env_config_sections))
+                .unwrap_or_default()
+        {
+            tracing::trace!(
+                service_id = key.service_id(),
+                "`ignore_configured_endpoint_urls` is set, the configured endpoint URL will be ignored"
+            );
+            return None;
+        }
+
         let (value, _source) = EnvConfigValue::new()
             .env(key.env())
             .profile(key.profile())
             .service_id(key.service_id())
             .load(&self.env, Some(&self.env_config_sections))?;
 
+        if is_endpoint_url {
+            return parse_url(&value)
+                .map_err(|err| {
+                    tracing::warn!(
+                        err = %DisplayErrorContext(&err),
+                        service_id = key.service_id(),
+                        "invalid value for endpoint URL setting"
+                    )
+                })
+                .ok();
+        }
         Some(value.to_string())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::EnvServiceConfig;
+    #[allow(deprecated)]
+    use crate::profile::profile_file::{ProfileFileKind, ProfileFiles};
+    use crate::provider_config::ProviderConfig;
+    use aws_types::os_shim_internal::{Env, Fs};
+    use aws_types::service_config::{LoadServiceConfig, ServiceConfigKey};
+    use tracing_test::traced_test;
+
+    const CONFIG: &str = r#"
+[default]
+endpoint_url = http://global-profile
+services = local
+
+[services local]
+s3 =
+  endpoint_url = http://s3-profile
+"#;
+
+    async fn service_config(env: &[(&str, &str)]) -> EnvServiceConfig {
+        let conf = ProviderConfig::empty()
+            .with_env(Env::from_slice(env))
+            .with_profile_config(
+                Some(
+                    #[allow(deprecated)]
+                    ProfileFiles::builder()
+                        .with_file(
+                            #[allow(deprecated)]
+                            ProfileFileKind::Config,
+                            "conf",
+                        )
+                        .build(),
+                ),
+                None,
+            )
+            .with_fs(Fs::from_slice(&[("conf", CONFIG)]));
+        EnvServiceConfig {
+            env: conf.env(),
+            env_config_sections: conf.profile().await.cloned().unwrap(),
+        }
+    }
+
+    fn endpoint_url(config: &EnvServiceConfig, service_id: &str) -> Option<String> {
+        config.load_config(
+            ServiceConfigKey::builder()
+                .service_id(service_id)
+                .env("AWS_ENDPOINT_URL")
+                .profile("endpoint_url")
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn endpoint_url_precedence() {
+        let config = service_config(&[
+            ("AWS_ENDPOINT_URL_S3", "http://s3-env"),
+            ("AWS_ENDPOINT_URL", "http://global-env"),
+        ])
+        .await;
+        assert_eq!(
+            Some("http://s3-env"),
+            endpoint_url(&config, "s3").as_deref()
+        );
+        assert_eq!(
+            Some("http://global-env"),
+            endpoint_url(&config, "sts").as_deref()
+        );
+
+        let config = service_config(&[]).await;
+        assert_eq!(
+            Some("http://s3-profile"),
+            endpoint_url(&config, "s3").as_deref()
+        );
+        assert_eq!(
+            Some("http://global-profile"),
+            endpoint_url(&config, "sts").as_deref()
+        );
+    }
+
+    #[tokio::test]
+    async fn configured_endpoint_urls_may_be_ignored() {
+        let config = service_config(&[
+            ("AWS_ENDPOINT_URL_S3", "http://s3-env"),
+            ("AWS_IGNORE_CONFIGURED_ENDPOINT_URLS", "true"),
+        ])
+        .await;
+        assert_eq!(None, endpoint_url(&config, "s3"));
+        assert_eq!(None, endpoint_url(&config, "sts"));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn invalid_endpoint_urls_are_ignored() {
+        let config = service_config(&[("AWS_ENDPOINT_URL_S3", "not-a-url")]).await;
+        assert_eq!(None, endpoint_url(&config, "s3"));
+        assert!(logs_contain("invalid value for endpoint URL setting"));
+    }
+}
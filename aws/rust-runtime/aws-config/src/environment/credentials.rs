@@ -5,6 +5,7 @@
 
 use std::env::VarError;
 
+use aws_credential_types::credential_feature::AwsCredentialFeature;
 use aws_credential_types::provider::{self, error::CredentialsError, future, ProvideCredentials};
 use aws_credential_types::Credentials;
 use aws_types::os_shim_internal::Env;
@@ -15,6 +16,7 @@ use aws_types::os_shim_internal::Env;
 /// - `AWS_ACCESS_KEY_ID`
 /// - `AWS_SECRET_ACCESS_KEY` with fallback to `SECRET_ACCESS_KEY`
 /// - `AWS_SESSION_TOKEN`
+/// - `AWS_ACCOUNT_ID`
 #[derive(Debug, Clone)]
 pub struct EnvironmentVariableCredentialsProvider {
     env: Env,
@@ -42,13 +44,22 @@ impl EnvironmentVariableCredentialsProvider {
                     "" => None,
                     s => Some(s.to_string()),
                 });
-        Ok(Credentials::new(
-            access_key,
-            secret_key,
-            session_token,
-            None,
-            ENV_PROVIDER,
-        ))
+        let account_id =
+            self.env
+                .get("AWS_ACCOUNT_ID")
+                .ok()
+                .and_then(|account_id| match account_id.trim() {
+                    "" => None,
+                    s => Some(s.to_string()),
+                });
+        Ok(Credentials::builder()
+            .access_key_id(access_key)
+            .secret_access_key(secret_key)
+            .set_session_token(session_token)
+            .set_account_id(account_id.map(Into::into))
+            .provider_name(ENV_PROVIDER)
+            .feature(AwsCredentialFeature::CredentialsEnvVars)
+            .build())
     }
 }
 
@@ -105,6 +116,7 @@ mod test {
     use futures_util::FutureExt;
 
     use super::EnvironmentVariableCredentialsProvider;
+    use aws_credential_types::credential_feature::AwsCredentialFeature;
 
     fn make_provider(vars: &[(&str, &str)]) -> EnvironmentVariableCredentialsProvider {
         EnvironmentVariableCredentialsProvider {
@@ -146,6 +158,29 @@ mod test {
         assert_eq!(creds.secret_access_key(), "secret");
     }
 
+    #[test]
+    fn valid_with_account_id() {
+        let provider = make_provider(&[
+            ("AWS_ACCESS_KEY_ID", "access"),
+            ("AWS_SECRET_ACCESS_KEY", "secret"),
+            ("AWS_ACCOUNT_ID", "123456789012"),
+        ]);
+
+        let creds = provider
+            .provide_credentials()
+            .now_or_never()
+            .unwrap()
+            .expect("valid credentials");
+        assert_eq!(
+            creds.account_id().map(|id| id.as_str()),
+            Some("123456789012")
+        );
+        assert_eq!(
+            creds.features(),
+            &[AwsCredentialFeature::CredentialsEnvVars]
+        );
+    }
+
     #[test]
     fn empty_token_env_var() {
         for token_value in &["", " "] {
@@ -42,22 +42,26 @@ pub(crate) fn parse_bool(value: &str) -> Result<bool, InvalidBooleanValue> {
 }
 
 #[derive(Debug)]
-pub(crate) struct InvalidUintValue {
+pub(crate) struct InvalidByteSizeValue {
     value: String,
 }
 
-impl fmt::Display for InvalidUintValue {
+impl fmt::Display for InvalidByteSizeValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} is not a valid u32", self.value)
+        write!(f, "{} is not a valid byte size", self.value)
     }
 }
 
-impl Error for InvalidUintValue {}
+impl Error for InvalidByteSizeValue {}
 
-pub(crate) fn parse_uint(value: &str) -> Result<u32, InvalidUintValue> {
-    value.parse::<u32>().map_err(|_| InvalidUintValue {
-        value: value.to_string(),
-    })
+/// Parses a number of bytes that fits in a `u32`, like `10240` or `10KiB`.
+pub(crate) fn parse_byte_size(value: &str) -> Result<u32, InvalidByteSizeValue> {
+    aws_smithy_types::units::parse_byte_size(value)
+        .ok()
+        .and_then(|size| u32::try_from(size.as_u64()).ok())
+        .ok_or_else(|| InvalidByteSizeValue {
+            value: value.to_string(),
+        })
 }
 
 #[derive(Debug)]
@@ -65,6 +65,7 @@ impl HttpCredentialProvider {
 pub(crate) struct Builder {
     provider_config: Option<ProviderConfig>,
     http_connector_settings: Option<HttpConnectorSettings>,
+    retry_config: Option<RetryConfig>,
 }
 
 impl Builder {
@@ -81,6 +82,13 @@ impl Builder {
         self
     }
 
+    /// Override the retry config. Defaults to [`RetryConfig::standard`]: 3 attempts with jittered
+    /// exponential backoff.
+    pub(crate) fn retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = Some(retry_config);
+        self
+    }
+
     pub(crate) fn build(
         self,
         provider_name: &'static str,
@@ -111,12 +119,13 @@ impl Builder {
         }
         if let Some(sleep_impl) = provider_config.sleep_impl() {
             builder = builder
-                .standard_retry(&RetryConfig::standard())
+                .standard_retry(&self.retry_config.unwrap_or_else(RetryConfig::standard))
                 // The following errors are retryable:
                 //   - Socket errors
                 //   - Networking timeouts
                 //   - 5xx errors
                 //   - Non-parseable 200 responses.
+                // Other responses, including 4xx errors, are not retried.
                 .retry_classifier(HttpCredentialRetryClassifier)
                 // Socket errors and network timeouts
                 .retry_classifier(TransientErrorClassifier::<Error>::new())
@@ -227,14 +236,36 @@ mod test {
 
     async fn provide_creds(
         http_client: StaticReplayClient,
+    ) -> Result<Credentials, CredentialsError> {
+        provide_creds_with_retry_config(http_client, None).await
+    }
+
+    async fn provide_creds_with_retry_config(
+        http_client: StaticReplayClient,
+        retry_config: Option<RetryConfig>,
     ) -> Result<Credentials, CredentialsError> {
         let provider_config = ProviderConfig::default().with_http_client(http_client.clone());
-        let provider = HttpCredentialProvider::builder()
-            .configure(&provider_config)
-            .build("test", "http://localhost:1234/", "/some-creds");
+        let mut builder = HttpCredentialProvider::builder().configure(&provider_config);
+        if let Some(retry_config) = retry_config {
+            builder = builder.retry_config(retry_config);
+        }
+        let provider = builder.build("test", "http://localhost:1234/", "/some-creds");
         provider.credentials(None).await
     }
 
+    fn error_req_resp(status: u16) -> ReplayEvent {
+        ReplayEvent::new(
+            Request::builder()
+                .uri(Uri::from_static("http://localhost:1234/some-creds"))
+                .body(SdkBody::empty())
+                .unwrap(),
+            Response::builder()
+                .status(status)
+                .body(SdkBody::from(r#"it broke"#))
+                .unwrap(),
+        )
+    }
+
     fn successful_req_resp() -> ReplayEvent {
         ReplayEvent::new(
             Request::builder()
@@ -332,4 +363,45 @@ mod test {
         );
         http_client.assert_requests_match(&[]);
     }
+
+    #[tokio::test]
+    async fn gives_up_after_three_attempts_by_default() {
+        let http_client = StaticReplayClient::new(vec![
+            error_req_resp(503),
+            error_req_resp(503),
+            error_req_resp(503),
+            successful_req_resp(),
+        ]);
+        provide_creds(http_client.clone())
+            .await
+            .expect_err("it should fail after three attempts");
+        assert_eq!(3, http_client.actual_requests().count());
+    }
+
+    #[tokio::test]
+    async fn retry_attempts_are_configurable() {
+        let http_client = StaticReplayClient::new(vec![
+            error_req_resp(503),
+            error_req_resp(503),
+            error_req_resp(503),
+            successful_req_resp(),
+        ]);
+        let retry_config = RetryConfig::standard()
+            .with_max_attempts(4)
+            .with_initial_backoff(Duration::from_millis(1));
+        let creds = provide_creds_with_retry_config(http_client.clone(), Some(retry_config))
+            .await
+            .expect("success");
+        assert_eq!("MUA...", creds.access_key_id());
+        http_client.assert_requests_match(&[]);
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let http_client = StaticReplayClient::new(vec![error_req_resp(403), successful_req_resp()]);
+        provide_creds(http_client.clone())
+            .await
+            .expect_err("it should fail");
+        assert_eq!(1, http_client.actual_requests().count());
+    }
 }
@@ -7,7 +7,11 @@
 //!
 //! Client for direct access to IMDSv2.
 
+use crate::imds::client::dual_stack::{
+    DualStackFallback, DualStackFallbackInterceptor, DualStackFallbackRetryClassifier,
+};
 use crate::imds::client::error::{BuildError, ImdsError, InnerImdsError, InvalidEndpointMode};
+use crate::imds::client::metadata::{IamInfo, InstanceIdentityDocument, NetworkInterface};
 use crate::imds::client::token::TokenRuntimePlugin;
 use crate::provider_config::ProviderConfig;
 use crate::PKG_VERSION;
@@ -44,7 +48,9 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 
+mod dual_stack;
 pub mod error;
+pub mod metadata;
 mod token;
 
 // 6 hours
@@ -194,6 +200,46 @@ impl Client {
                 _ => ImdsError::unexpected(err),
             })
     }
+
+    /// Retrieve the instance identity document
+    ///
+    /// The document is loaded from `/latest/dynamic/instance-identity/document` and describes the
+    /// instance, e.g. its ID, type, account, and Region.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use aws_config::imds::client::Client;
+    /// # async fn docs() {
+    /// let client = Client::builder().build();
+    /// let document = client
+    ///   .get_instance_identity_document()
+    ///   .await
+    ///   .expect("failure communicating with IMDS");
+    /// println!("running in {}", document.region());
+    /// # }
+    /// ```
+    pub async fn get_instance_identity_document(
+        &self,
+    ) -> Result<InstanceIdentityDocument, ImdsError> {
+        metadata::instance_identity_document(self).await
+    }
+
+    /// Retrieve information about the IAM instance profile associated with the instance
+    ///
+    /// The information is loaded from `/latest/meta-data/iam/info`, which returns an error response
+    /// if the instance doesn't have an instance profile.
+    pub async fn get_iam_info(&self) -> Result<IamInfo, ImdsError> {
+        metadata::iam_info(self).await
+    }
+
+    /// Retrieve the network interfaces attached to the instance, ordered by device number
+    ///
+    /// The attributes of each interface are loaded from
+    /// `/latest/meta-data/network/interfaces/macs/<mac>/`, so this makes several requests to IMDS.
+    pub async fn get_network_interfaces(&self) -> Result<Vec<NetworkInterface>, ImdsError> {
+        metadata::network_interfaces(self).await
+    }
 }
 
 /// New-type around `String` that doesn't emit the string value in the `Debug` impl.
@@ -242,6 +288,7 @@ impl ImdsCommonRuntimePlugin {
         retry_config: RetryConfig,
         retry_classifier: SharedRetryClassifier,
         timeout_config: TimeoutConfig,
+        dual_stack_fallback: Option<Arc<DualStackFallback>>,
     ) -> Self {
         let mut layer = Layer::new("ImdsCommonRuntimePlugin");
         layer.store_put(AuthSchemeOptionResolverParams::new(()));
@@ -251,16 +298,25 @@ impl ImdsCommonRuntimePlugin {
         layer.store_put(timeout_config);
         layer.store_put(user_agent());
 
+        let mut components = RuntimeComponentsBuilder::new("ImdsCommonRuntimePlugin")
+            .with_http_client(config.http_client())
+            .with_endpoint_resolver(Some(endpoint_resolver))
+            .with_interceptor(UserAgentInterceptor::new())
+            .with_retry_classifier(retry_classifier)
+            .with_retry_strategy(Some(StandardRetryStrategy::new()))
+            .with_time_source(Some(config.time_source()))
+            .with_sleep_impl(config.sleep_impl());
+        if let Some(fallback) = dual_stack_fallback {
+            components = components
+                .with_interceptor(DualStackFallbackInterceptor::new(fallback.clone()))
+                .with_retry_classifier(SharedRetryClassifier::new(
+                    DualStackFallbackRetryClassifier::new(fallback),
+                ));
+        }
+
         Self {
             config: layer.freeze(),
-            components: RuntimeComponentsBuilder::new("ImdsCommonRuntimePlugin")
-                .with_http_client(config.http_client())
-                .with_endpoint_resolver(Some(endpoint_resolver))
-                .with_interceptor(UserAgentInterceptor::new())
-                .with_retry_classifier(retry_classifier)
-                .with_retry_strategy(Some(StandardRetryStrategy::new()))
-                .with_time_source(Some(config.time_source()))
-                .with_sleep_impl(config.sleep_impl()),
+            components,
         }
     }
 }
@@ -322,6 +378,7 @@ pub struct Builder {
     max_attempts: Option<u32>,
     endpoint: Option<EndpointSource>,
     mode_override: Option<EndpointMode>,
+    dual_stack_fallback: bool,
     token_ttl: Option<Duration>,
     connect_timeout: Option<Duration>,
     read_timeout: Option<Duration>,
@@ -378,6 +435,21 @@ impl Builder {
         self
     }
 
+    /// Fall back to the endpoint of the other address family when IMDS can't be reached
+    ///
+    /// When enabled, a request that fails to connect to the endpoint of the configured
+    /// [endpoint mode](EndpointMode) is retried with the endpoint of the other address family
+    /// (e.g., `http://169.254.169.254` when [`IpV6`](EndpointMode::IpV6) is configured). The
+    /// failure is cached for 5 minutes, during which requests are sent directly to the other
+    /// endpoint, so that hosts with a partial network stack don't wait for the connect timeout on
+    /// every request.
+    ///
+    /// This has no effect when an explicit endpoint is configured. By default, this is disabled.
+    pub fn dual_stack_fallback(mut self, enabled: bool) -> Self {
+        self.dual_stack_fallback = enabled;
+        self
+    }
+
     /// Override the time-to-live for the session token
     ///
     /// Requests to IMDS utilize a session token for authentication. By default, session tokens last
@@ -452,9 +524,13 @@ impl Builder {
         let endpoint_source = self
             .endpoint
             .unwrap_or_else(|| EndpointSource::Env(config.clone()));
+        let dual_stack_fallback = self
+            .dual_stack_fallback
+            .then(|| Arc::new(DualStackFallback::new(config.time_source())));
         let endpoint_resolver = ImdsEndpointResolver {
             endpoint_source: Arc::new(endpoint_source),
             mode_override: self.mode_override,
+            dual_stack_fallback: dual_stack_fallback.clone(),
         };
         let retry_config = RetryConfig::standard()
             .with_max_attempts(self.max_attempts.unwrap_or(DEFAULT_ATTEMPTS));
@@ -467,6 +543,7 @@ impl Builder {
             retry_config,
             retry_classifier,
             timeout_config,
+            dual_stack_fallback,
         ));
         let operation = Operation::builder()
             .service_name("imds")
@@ -518,7 +595,11 @@ enum EndpointSource {
 }
 
 impl EndpointSource {
-    async fn endpoint(&self, mode_override: Option<EndpointMode>) -> Result<Uri, BuildError> {
+    async fn endpoint(
+        &self,
+        mode_override: Option<EndpointMode>,
+        dual_stack_fallback: Option<&DualStackFallback>,
+    ) -> Result<Uri, BuildError> {
         match self {
             EndpointSource::Explicit(uri) => {
                 if mode_override.is_some() {
@@ -556,6 +637,10 @@ impl EndpointSource {
                 } else {
                     EndpointMode::IpV4
                 };
+                let mode = match dual_stack_fallback {
+                    Some(fallback) => fallback.endpoint_mode(mode),
+                    None => mode,
+                };
 
                 Ok(mode.endpoint())
             }
@@ -567,13 +652,17 @@ impl EndpointSource {
 struct ImdsEndpointResolver {
     endpoint_source: Arc<EndpointSource>,
     mode_override: Option<EndpointMode>,
+    dual_stack_fallback: Option<Arc<DualStackFallback>>,
 }
 
 impl ResolveEndpoint for ImdsEndpointResolver {
     fn resolve_endpoint<'a>(&'a self, _: &'a EndpointResolverParams) -> EndpointFuture<'a> {
         EndpointFuture::new(async move {
             self.endpoint_source
-                .endpoint(self.mode_override.clone())
+                .endpoint(
+                    self.mode_override.clone(),
+                    self.dual_stack_fallback.as_deref(),
+                )
                 .await
                 .map(|uri| Endpoint::builder().url(uri.to_string()).build())
                 .map_err(|err| err.into())
@@ -642,6 +731,9 @@ pub(crate) mod test {
         capture_request, ReplayEvent, StaticReplayClient,
     };
     use aws_smithy_runtime::test_util::capture_test_logs::capture_test_logs;
+    use aws_smithy_runtime_api::client::http::{
+        http_client_fn, HttpConnector, HttpConnectorFuture, SharedHttpConnector,
+    };
     use aws_smithy_runtime_api::client::interceptors::context::{
         Input, InterceptorContext, Output,
     };
@@ -661,6 +753,7 @@ pub(crate) mod test {
     use std::collections::HashMap;
     use std::error::Error;
     use std::io;
+    use std::sync::{Arc, Mutex};
     use std::time::SystemTime;
     use std::time::{Duration, UNIX_EPOCH};
     use tracing_test::traced_test;
@@ -1172,6 +1265,108 @@ pub(crate) mod test {
         );
     }
 
+    /// HTTP client for a host that can only reach IMDS over IPv4
+    #[derive(Clone, Debug, Default)]
+    struct Ipv4OnlyHttpClient {
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl HttpConnector for Ipv4OnlyHttpClient {
+        fn call(&self, request: HttpRequest) -> HttpConnectorFuture {
+            self.requests
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", request.method(), request.uri()));
+            HttpConnectorFuture::ready(if request.uri().starts_with("http://[fd00:ec2::254]") {
+                Err(ConnectorError::io("network is unreachable".into()))
+            } else if request.method() == "PUT" {
+                Ok(token_response(21600, TOKEN_A))
+            } else {
+                Ok(imds_response("test-imds-output"))
+            })
+        }
+    }
+
+    impl Ipv4OnlyHttpClient {
+        fn take_requests(&self) -> Vec<String> {
+            std::mem::take(&mut self.requests.lock().unwrap())
+        }
+    }
+
+    #[tokio::test]
+    async fn dual_stack_fallback() {
+        let http_client = Ipv4OnlyHttpClient::default();
+        let (time_source, sleep) = instant_time_and_sleep(UNIX_EPOCH);
+        let client = super::Client::builder()
+            .configure(
+                &ProviderConfig::no_configuration()
+                    .with_http_client(http_client_fn({
+                        let http_client = http_client.clone();
+                        move |_, _| SharedHttpConnector::new(http_client.clone())
+                    }))
+                    .with_time_source(time_source.clone())
+                    .with_sleep_impl(sleep),
+            )
+            .endpoint_mode(EndpointMode::IpV6)
+            .dual_stack_fallback(true)
+            .build();
+
+        // The IPv6 endpoint is probed, and the request falls back to IPv4
+        let metadata = client.get("/latest/metadata").await.expect("success");
+        assert_eq!("test-imds-output", metadata.as_ref());
+        assert_eq!(
+            vec![
+                "PUT http://[fd00:ec2::254]/latest/api/token",
+                "PUT http://169.254.169.254/latest/api/token",
+                "GET http://169.254.169.254/latest/metadata",
+            ],
+            http_client.take_requests()
+        );
+
+        // The failed probe is cached
+        client.get("/latest/metadata").await.expect("success");
+        assert_eq!(
+            vec!["GET http://169.254.169.254/latest/metadata"],
+            http_client.take_requests()
+        );
+
+        // Once the failure expires, the IPv6 endpoint is probed again
+        time_source.advance(Duration::from_secs(300));
+        client.get("/latest/metadata").await.expect("success");
+        assert_eq!(
+            vec![
+                "GET http://[fd00:ec2::254]/latest/metadata",
+                "GET http://169.254.169.254/latest/metadata",
+            ],
+            http_client.take_requests()
+        );
+    }
+
+    #[tokio::test]
+    async fn no_dual_stack_fallback_by_default() {
+        let http_client = Ipv4OnlyHttpClient::default();
+        let client = super::Client::builder()
+            .configure(
+                &ProviderConfig::no_configuration()
+                    .with_http_client(http_client_fn({
+                        let http_client = http_client.clone();
+                        move |_, _| SharedHttpConnector::new(http_client.clone())
+                    }))
+                    .with_sleep_impl(InstantSleep::unlogged()),
+            )
+            .endpoint_mode(EndpointMode::IpV6)
+            .build();
+
+        client
+            .get("/latest/metadata")
+            .await
+            .expect_err("IPv6 is unreachable");
+        assert_eq!(
+            vec!["PUT http://[fd00:ec2::254]/latest/api/token"],
+            http_client.take_requests()
+        );
+    }
+
     #[derive(Debug, Deserialize)]
     struct ImdsConfigTest {
         env: HashMap<String, String>,
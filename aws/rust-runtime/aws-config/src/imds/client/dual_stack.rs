@@ -0,0 +1,253 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! IMDS Dual-Stack Fallback
+//!
+//! Hosts with a partial network stack (e.g., containers without IPv6 connectivity) can't reach
+//! IMDS over one of the address families. Requests to that family's endpoint fail to connect, or
+//! hang until the connect timeout elapses.
+//!
+//! When dual-stack fallback is enabled, the first request to the endpoint of the configured
+//! [`EndpointMode`] probes it. If it fails to connect, the failure is cached and the request is
+//! retried with the endpoint of the other address family. Until the cached failure expires,
+//! requests go directly to the other endpoint, and once it expires, the configured endpoint is
+//! probed again.
+
+use crate::imds::client::EndpointMode;
+use aws_smithy_async::time::SharedTimeSource;
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::context::{
+    BeforeTransmitInterceptorContextRef, FinalizerInterceptorContextRef, InterceptorContext,
+};
+use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::orchestrator::OrchestratorError;
+use aws_smithy_runtime_api::client::retries::classifiers::{ClassifyRetry, RetryAction};
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
+use http::Uri;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+/// How long a failure to connect to an endpoint is cached for before it's probed again.
+const FAILED_PROBE_TTL: Duration = Duration::from_secs(300);
+
+/// When each address family's endpoint last failed to connect.
+#[derive(Debug, Default)]
+struct Failures {
+    ipv4: Option<SystemTime>,
+    ipv6: Option<SystemTime>,
+}
+
+impl Failures {
+    fn get_mut(&mut self, mode: &EndpointMode) -> &mut Option<SystemTime> {
+        match mode {
+            EndpointMode::IpV4 => &mut self.ipv4,
+            EndpointMode::IpV6 => &mut self.ipv6,
+        }
+    }
+
+    fn is_failed(&mut self, mode: &EndpointMode, now: SystemTime) -> bool {
+        let failed_at = self.get_mut(mode);
+        match *failed_at {
+            Some(at) if now < at + FAILED_PROBE_TTL => true,
+            _ => {
+                *failed_at = None;
+                false
+            }
+        }
+    }
+}
+
+fn other_family(mode: &EndpointMode) -> EndpointMode {
+    match mode {
+        EndpointMode::IpV4 => EndpointMode::IpV6,
+        EndpointMode::IpV6 => EndpointMode::IpV4,
+    }
+}
+
+/// Health of the IMDS endpoints of both address families, shared by all requests of a client.
+#[derive(Debug)]
+pub(super) struct DualStackFallback {
+    time_source: SharedTimeSource,
+    failures: Mutex<Failures>,
+}
+
+impl DualStackFallback {
+    pub(super) fn new(time_source: SharedTimeSource) -> Self {
+        Self {
+            time_source,
+            failures: Default::default(),
+        }
+    }
+
+    /// Returns the endpoint mode to use instead of the `configured` one.
+    ///
+    /// If neither endpoint is reachable, the configured one is used.
+    pub(super) fn endpoint_mode(&self, configured: EndpointMode) -> EndpointMode {
+        let now = self.time_source.now();
+        let mut failures = self.failures.lock().unwrap();
+        let other = other_family(&configured);
+        if failures.is_failed(&configured, now) && !failures.is_failed(&other, now) {
+            tracing::debug!(configured = ?configured, fallback = ?other, "IMDS endpoint is unreachable, falling back to the other address family");
+            other
+        } else {
+            configured
+        }
+    }
+
+    fn mark_failed(&self, mode: &EndpointMode) {
+        let now = self.time_source.now();
+        *self.failures.lock().unwrap().get_mut(mode) = Some(now);
+    }
+
+    /// Returns true if exactly one endpoint is unreachable, so that requests can fall back to the
+    /// other one.
+    fn can_fall_back(&self) -> bool {
+        let now = self.time_source.now();
+        let mut failures = self.failures.lock().unwrap();
+        failures.is_failed(&EndpointMode::IpV4, now) != failures.is_failed(&EndpointMode::IpV6, now)
+    }
+}
+
+/// The endpoint mode of the endpoint the current attempt was sent to.
+#[derive(Clone, Debug)]
+struct AttemptEndpointMode(EndpointMode);
+
+impl Storable for AttemptEndpointMode {
+    type Storer = StoreReplace<Self>;
+}
+
+fn failed_to_connect(error: &OrchestratorError<impl std::fmt::Debug>) -> bool {
+    error
+        .as_connector_error()
+        .map(|err| err.is_timeout() || err.is_io())
+        .unwrap_or_default()
+}
+
+/// Interceptor that caches the failures of attempts that couldn't connect to IMDS.
+#[derive(Debug)]
+pub(super) struct DualStackFallbackInterceptor {
+    fallback: Arc<DualStackFallback>,
+}
+
+impl DualStackFallbackInterceptor {
+    pub(super) fn new(fallback: Arc<DualStackFallback>) -> Self {
+        Self { fallback }
+    }
+}
+
+impl Intercept for DualStackFallbackInterceptor {
+    fn name(&self) -> &'static str {
+        "DualStackFallbackInterceptor"
+    }
+
+    fn read_before_transmit(
+        &self,
+        context: &BeforeTransmitInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        // Requests to an explicitly configured endpoint don't have an endpoint mode
+        let host = context
+            .request()
+            .uri()
+            .parse::<Uri>()
+            .ok()
+            .and_then(|uri| uri.host().map(str::to_owned));
+        let mode = [EndpointMode::IpV4, EndpointMode::IpV6]
+            .into_iter()
+            .find(|mode| mode.endpoint().host() == host.as_deref());
+        match mode {
+            Some(mode) => cfg.interceptor_state().store_put(AttemptEndpointMode(mode)),
+            None => cfg.interceptor_state().unset::<AttemptEndpointMode>(),
+        };
+        Ok(())
+    }
+
+    fn read_after_attempt(
+        &self,
+        context: &FinalizerInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if let (Some(Err(error)), Some(AttemptEndpointMode(mode))) =
+            (context.output_or_error(), cfg.load::<AttemptEndpointMode>())
+        {
+            if failed_to_connect(error) {
+                self.fallback.mark_failed(mode);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Retry classifier that retries attempts that couldn't connect to IMDS when the endpoint of the
+/// other address family can be fallen back to.
+#[derive(Debug)]
+pub(super) struct DualStackFallbackRetryClassifier {
+    fallback: Arc<DualStackFallback>,
+}
+
+impl DualStackFallbackRetryClassifier {
+    pub(super) fn new(fallback: Arc<DualStackFallback>) -> Self {
+        Self { fallback }
+    }
+}
+
+impl ClassifyRetry for DualStackFallbackRetryClassifier {
+    fn name(&self) -> &'static str {
+        "DualStackFallbackRetryClassifier"
+    }
+
+    fn classify_retry(&self, ctx: &InterceptorContext) -> RetryAction {
+        match ctx.output_or_error() {
+            Some(Err(error)) if failed_to_connect(error) && self.fallback.can_fall_back() => {
+                RetryAction::transient_error()
+            }
+            _ => RetryAction::NoActionIndicated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aws_smithy_async::test_util::ManualTimeSource;
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn failed_endpoints_are_cached() {
+        let time_source = ManualTimeSource::new(UNIX_EPOCH);
+        let fallback = DualStackFallback::new(SharedTimeSource::new(time_source.clone()));
+        assert!(matches!(
+            fallback.endpoint_mode(EndpointMode::IpV6),
+            EndpointMode::IpV6
+        ));
+        assert!(!fallback.can_fall_back());
+
+        fallback.mark_failed(&EndpointMode::IpV6);
+        assert!(fallback.can_fall_back());
+        assert!(matches!(
+            fallback.endpoint_mode(EndpointMode::IpV6),
+            EndpointMode::IpV4
+        ));
+
+        // When neither endpoint is reachable, the configured endpoint is used
+        fallback.mark_failed(&EndpointMode::IpV4);
+        assert!(!fallback.can_fall_back());
+        assert!(matches!(
+            fallback.endpoint_mode(EndpointMode::IpV6),
+            EndpointMode::IpV6
+        ));
+
+        // Both failures expire, so the configured endpoint is probed again
+        time_source.advance(FAILED_PROBE_TTL);
+        assert!(!fallback.can_fall_back());
+        assert!(matches!(
+            fallback.endpoint_mode(EndpointMode::IpV6),
+            EndpointMode::IpV6
+        ));
+    }
+}
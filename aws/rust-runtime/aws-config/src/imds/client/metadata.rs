@@ -0,0 +1,520 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Typed instance metadata
+//!
+//! These types are returned by the typed helpers of the IMDS [`Client`], such as
+//! [`Client::get_instance_identity_document`]. They parse the responses of the metadata
+//! categories that are most commonly needed, so that they don't have to be parsed by hand.
+
+use crate::imds::client::error::ImdsError;
+use crate::imds::client::Client;
+use crate::json_credentials::{json_parse_loop, InvalidJsonCredentials};
+use aws_smithy_json::deserialize::Token;
+use aws_smithy_types::date_time::Format;
+use aws_smithy_types::DateTime;
+
+const INSTANCE_IDENTITY_DOCUMENT: &str = "/latest/dynamic/instance-identity/document";
+const IAM_INFO: &str = "/latest/meta-data/iam/info";
+const NETWORK_INTERFACE_MACS: &str = "/latest/meta-data/network/interfaces/macs/";
+
+/// The instance identity document of an EC2 instance.
+///
+/// For more information, see [Instance identity documents](https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-identity-documents.html).
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstanceIdentityDocument {
+    account_id: String,
+    architecture: Option<String>,
+    availability_zone: String,
+    image_id: String,
+    instance_id: String,
+    instance_type: String,
+    kernel_id: Option<String>,
+    pending_time: Option<DateTime>,
+    private_ip: Option<String>,
+    ramdisk_id: Option<String>,
+    region: String,
+}
+
+impl InstanceIdentityDocument {
+    /// The ID of the AWS account that launched the instance.
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// The architecture of the AMI used to launch the instance, e.g. `x86_64` or `arm64`.
+    pub fn architecture(&self) -> Option<&str> {
+        self.architecture.as_deref()
+    }
+
+    /// The Availability Zone in which the instance is running.
+    pub fn availability_zone(&self) -> &str {
+        &self.availability_zone
+    }
+
+    /// The ID of the AMI used to launch the instance.
+    pub fn image_id(&self) -> &str {
+        &self.image_id
+    }
+
+    /// The ID of the instance.
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    /// The instance type of the instance, e.g. `m5.large`.
+    pub fn instance_type(&self) -> &str {
+        &self.instance_type
+    }
+
+    /// The ID of the kernel associated with the instance, if any.
+    pub fn kernel_id(&self) -> Option<&str> {
+        self.kernel_id.as_deref()
+    }
+
+    /// The date and time that the instance was launched.
+    pub fn pending_time(&self) -> Option<&DateTime> {
+        self.pending_time.as_ref()
+    }
+
+    /// The private IPv4 address of the instance.
+    pub fn private_ip(&self) -> Option<&str> {
+        self.private_ip.as_deref()
+    }
+
+    /// The ID of the RAM disk associated with the instance, if any.
+    pub fn ramdisk_id(&self) -> Option<&str> {
+        self.ramdisk_id.as_deref()
+    }
+
+    /// The Region in which the instance is running.
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+}
+
+/// Information about the IAM instance profile associated with an EC2 instance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IamInfo {
+    instance_profile_arn: String,
+    instance_profile_id: String,
+    last_updated: Option<DateTime>,
+}
+
+impl IamInfo {
+    /// The ARN of the instance profile.
+    pub fn instance_profile_arn(&self) -> &str {
+        &self.instance_profile_arn
+    }
+
+    /// The ID of the instance profile.
+    pub fn instance_profile_id(&self) -> &str {
+        &self.instance_profile_id
+    }
+
+    /// The date and time that the instance profile information was last updated.
+    pub fn last_updated(&self) -> Option<&DateTime> {
+        self.last_updated.as_ref()
+    }
+}
+
+/// A network interface attached to an EC2 instance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkInterface {
+    mac: String,
+    device_number: u32,
+    interface_id: String,
+    local_ipv4s: Vec<String>,
+    public_ipv4s: Vec<String>,
+    ipv6s: Vec<String>,
+    subnet_id: String,
+    vpc_id: String,
+}
+
+impl NetworkInterface {
+    /// The MAC address of the network interface.
+    pub fn mac(&self) -> &str {
+        &self.mac
+    }
+
+    /// The device number of the network interface. The primary network interface is `0`.
+    pub fn device_number(&self) -> u32 {
+        self.device_number
+    }
+
+    /// The ID of the network interface.
+    pub fn interface_id(&self) -> &str {
+        &self.interface_id
+    }
+
+    /// The private IPv4 addresses of the network interface.
+    pub fn local_ipv4s(&self) -> &[String] {
+        &self.local_ipv4s
+    }
+
+    /// The public IPv4 addresses associated with the network interface.
+    pub fn public_ipv4s(&self) -> &[String] {
+        &self.public_ipv4s
+    }
+
+    /// The IPv6 addresses of the network interface.
+    pub fn ipv6s(&self) -> &[String] {
+        &self.ipv6s
+    }
+
+    /// The ID of the subnet the network interface is in.
+    pub fn subnet_id(&self) -> &str {
+        &self.subnet_id
+    }
+
+    /// The ID of the VPC the network interface is in.
+    pub fn vpc_id(&self) -> &str {
+        &self.vpc_id
+    }
+}
+
+fn parse_date_time(field: &'static str, value: &str) -> Result<DateTime, InvalidJsonCredentials> {
+    DateTime::from_str(value, Format::DateTime).map_err(|err| {
+        InvalidJsonCredentials::InvalidField {
+            field,
+            err: err.into(),
+        }
+    })
+}
+
+fn parse_instance_identity_document(
+    document: &str,
+) -> Result<InstanceIdentityDocument, InvalidJsonCredentials> {
+    let mut account_id = None;
+    let mut architecture = None;
+    let mut availability_zone = None;
+    let mut image_id = None;
+    let mut instance_id = None;
+    let mut instance_type = None;
+    let mut kernel_id = None;
+    let mut pending_time = None;
+    let mut private_ip = None;
+    let mut ramdisk_id = None;
+    let mut region = None;
+    json_parse_loop(document.as_bytes(), |key, value| {
+        if let Token::ValueString { value, .. } = value {
+            let value = value.to_unescaped()?.into_owned();
+            match key.as_ref() {
+                "accountId" => account_id = Some(value),
+                "architecture" => architecture = Some(value),
+                "availabilityZone" => availability_zone = Some(value),
+                "imageId" => image_id = Some(value),
+                "instanceId" => instance_id = Some(value),
+                "instanceType" => instance_type = Some(value),
+                "kernelId" => kernel_id = Some(value),
+                "pendingTime" => pending_time = Some(parse_date_time("pendingTime", &value)?),
+                "privateIp" => private_ip = Some(value),
+                "ramdiskId" => ramdisk_id = Some(value),
+                "region" => region = Some(value),
+                _ => {}
+            }
+        }
+        Ok(())
+    })?;
+    Ok(InstanceIdentityDocument {
+        account_id: account_id.ok_or(InvalidJsonCredentials::MissingField("accountId"))?,
+        architecture,
+        availability_zone: availability_zone
+            .ok_or(InvalidJsonCredentials::MissingField("availabilityZone"))?,
+        image_id: image_id.ok_or(InvalidJsonCredentials::MissingField("imageId"))?,
+        instance_id: instance_id.ok_or(InvalidJsonCredentials::MissingField("instanceId"))?,
+        instance_type: instance_type.ok_or(InvalidJsonCredentials::MissingField("instanceType"))?,
+        kernel_id,
+        pending_time,
+        private_ip,
+        ramdisk_id,
+        region: region.ok_or(InvalidJsonCredentials::MissingField("region"))?,
+    })
+}
+
+fn parse_iam_info(info: &str) -> Result<IamInfo, InvalidJsonCredentials> {
+    let mut code = None;
+    let mut message = None;
+    let mut last_updated = None;
+    let mut instance_profile_arn = None;
+    let mut instance_profile_id = None;
+    json_parse_loop(info.as_bytes(), |key, value| {
+        if let Token::ValueString { value, .. } = value {
+            let value = value.to_unescaped()?.into_owned();
+            match key.as_ref() {
+                "Code" => code = Some(value),
+                "Message" => message = Some(value),
+                "LastUpdated" => last_updated = Some(parse_date_time("LastUpdated", &value)?),
+                "InstanceProfileArn" => instance_profile_arn = Some(value),
+                "InstanceProfileId" => instance_profile_id = Some(value),
+                _ => {}
+            }
+        }
+        Ok(())
+    })?;
+    match code.as_deref() {
+        None | Some("Success") => Ok(IamInfo {
+            instance_profile_arn: instance_profile_arn
+                .ok_or(InvalidJsonCredentials::MissingField("InstanceProfileArn"))?,
+            instance_profile_id: instance_profile_id
+                .ok_or(InvalidJsonCredentials::MissingField("InstanceProfileId"))?,
+            last_updated,
+        }),
+        Some(code) => Err(InvalidJsonCredentials::Other(
+            format!(
+                "IMDS returned an error for the IAM info ({code}): {}",
+                message.as_deref().unwrap_or("no message")
+            )
+            .into(),
+        )),
+    }
+}
+
+/// Splits a line-based metadata listing into its entries.
+fn parse_lines(listing: &str) -> Vec<String> {
+    listing
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Loads a line-based metadata listing. Listings that don't exist, e.g. the IPv6 addresses of an
+/// interface that doesn't have any, are empty.
+async fn get_listing(client: &Client, path: String) -> Result<Vec<String>, ImdsError> {
+    match client.get(path).await {
+        Ok(listing) => Ok(parse_lines(listing.as_ref())),
+        Err(ImdsError::ErrorResponse(context)) if context.response().status().as_u16() == 404 => {
+            Ok(Vec::new())
+        }
+        Err(err) => Err(err),
+    }
+}
+
+pub(super) async fn instance_identity_document(
+    client: &Client,
+) -> Result<InstanceIdentityDocument, ImdsError> {
+    let document = client.get(INSTANCE_IDENTITY_DOCUMENT).await?;
+    parse_instance_identity_document(document.as_ref()).map_err(ImdsError::unexpected)
+}
+
+pub(super) async fn iam_info(client: &Client) -> Result<IamInfo, ImdsError> {
+    let info = client.get(IAM_INFO).await?;
+    parse_iam_info(info.as_ref()).map_err(ImdsError::unexpected)
+}
+
+pub(super) async fn network_interfaces(
+    client: &Client,
+) -> Result<Vec<NetworkInterface>, ImdsError> {
+    let macs = client.get(NETWORK_INTERFACE_MACS).await?;
+    let mut interfaces = Vec::new();
+    for mac in parse_lines(macs.as_ref()) {
+        // Listings of metadata categories end with a `/`
+        let mac = mac.trim_end_matches('/').to_owned();
+        let path = |field: &str| format!("{NETWORK_INTERFACE_MACS}{mac}/{field}");
+        let device_number = client.get(path("device-number")).await?;
+        let device_number = device_number.as_ref().trim().parse().map_err(|err| {
+            ImdsError::unexpected(InvalidJsonCredentials::InvalidField {
+                field: "device-number",
+                err: Box::new(err),
+            })
+        })?;
+        interfaces.push(NetworkInterface {
+            device_number,
+            interface_id: client.get(path("interface-id")).await?.into(),
+            local_ipv4s: get_listing(client, path("local-ipv4s")).await?,
+            public_ipv4s: get_listing(client, path("public-ipv4s")).await?,
+            ipv6s: get_listing(client, path("ipv6s")).await?,
+            subnet_id: client.get(path("subnet-id")).await?.into(),
+            vpc_id: client.get(path("vpc-id")).await?.into(),
+            mac,
+        });
+    }
+    interfaces.sort_by_key(NetworkInterface::device_number);
+    Ok(interfaces)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::imds::client::test::{
+        imds_request, imds_response, make_imds_client, token_request, token_response,
+    };
+    use aws_smithy_runtime::client::http::test_util::{ReplayEvent, StaticReplayClient};
+    use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
+    use aws_smithy_types::body::SdkBody;
+
+    const TOKEN: &str = "token";
+
+    fn not_found() -> HttpResponse {
+        HttpResponse::try_from(
+            http::Response::builder()
+                .status(404)
+                .body(SdkBody::empty())
+                .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn event(path: &'static str, response: HttpResponse) -> ReplayEvent {
+        ReplayEvent::new(imds_request(path, TOKEN), response)
+    }
+
+    fn token_event() -> ReplayEvent {
+        ReplayEvent::new(
+            token_request("http://169.254.169.254", 21600),
+            token_response(21600, TOKEN),
+        )
+    }
+
+    #[tokio::test]
+    async fn instance_identity_document() {
+        let http_client = StaticReplayClient::new(vec![
+            token_event(),
+            event(
+                "http://169.254.169.254/latest/dynamic/instance-identity/document",
+                imds_response(
+                    r#"{
+                        "accountId" : "123456789012",
+                        "architecture" : "x86_64",
+                        "availabilityZone" : "us-west-2b",
+                        "billingProducts" : null,
+                        "devpayProductCodes" : null,
+                        "marketplaceProductCodes" : [ "1abc2defghijklm3nopqrs4tu" ],
+                        "imageId" : "ami-5fb8c835",
+                        "instanceId" : "i-1234567890abcdef0",
+                        "instanceType" : "t2.micro",
+                        "kernelId" : null,
+                        "pendingTime" : "2016-11-19T16:32:11Z",
+                        "privateIp" : "10.158.112.84",
+                        "ramdiskId" : null,
+                        "region" : "us-west-2",
+                        "version" : "2017-09-30"
+                    }"#,
+                ),
+            ),
+        ]);
+        let client = make_imds_client(&http_client);
+        let document = client
+            .get_instance_identity_document()
+            .await
+            .expect("valid document");
+        assert_eq!("123456789012", document.account_id());
+        assert_eq!(Some("x86_64"), document.architecture());
+        assert_eq!("us-west-2b", document.availability_zone());
+        assert_eq!("ami-5fb8c835", document.image_id());
+        assert_eq!("i-1234567890abcdef0", document.instance_id());
+        assert_eq!("t2.micro", document.instance_type());
+        assert_eq!(None, document.kernel_id());
+        assert_eq!(
+            Some(&DateTime::from_secs(1479573131)),
+            document.pending_time()
+        );
+        assert_eq!(Some("10.158.112.84"), document.private_ip());
+        assert_eq!("us-west-2", document.region());
+        http_client.assert_requests_match(&[]);
+    }
+
+    #[test]
+    fn invalid_instance_identity_document() {
+        let err = parse_instance_identity_document(r#"{ "accountId": "123456789012" }"#)
+            .expect_err("missing fields");
+        assert!(
+            matches!(
+                err,
+                InvalidJsonCredentials::MissingField("availabilityZone")
+            ),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn iam_info() {
+        let http_client = StaticReplayClient::new(vec![
+            token_event(),
+            event(
+                "http://169.254.169.254/latest/meta-data/iam/info",
+                imds_response(
+                    r#"{
+                        "Code" : "Success",
+                        "LastUpdated" : "2021-09-20T21:42:26Z",
+                        "InstanceProfileArn" : "arn:aws:iam::123456789012:instance-profile/my-profile",
+                        "InstanceProfileId" : "AIPAEXAMPLE"
+                    }"#,
+                ),
+            ),
+        ]);
+        let client = make_imds_client(&http_client);
+        let info = client.get_iam_info().await.expect("valid info");
+        assert_eq!(
+            "arn:aws:iam::123456789012:instance-profile/my-profile",
+            info.instance_profile_arn()
+        );
+        assert_eq!("AIPAEXAMPLE", info.instance_profile_id());
+        assert_eq!(Some(&DateTime::from_secs(1632174146)), info.last_updated());
+        http_client.assert_requests_match(&[]);
+    }
+
+    #[test]
+    fn iam_info_error() {
+        let err =
+            parse_iam_info(r#"{ "Code": "AssumeRoleUnauthorizedAccess", "Message": "denied" }"#)
+                .expect_err("error code");
+        assert!(
+            err.to_string().contains("AssumeRoleUnauthorizedAccess"),
+            "{err}"
+        );
+        assert!(err.to_string().contains("denied"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn network_interfaces() {
+        macro_rules! mac {
+            ($field:literal) => {
+                concat!(
+                    "http://169.254.169.254/latest/meta-data/network/interfaces/macs/0e:49:61:0f:c3:11/",
+                    $field
+                )
+            };
+        }
+        let http_client = StaticReplayClient::new(vec![
+            token_event(),
+            event(
+                "http://169.254.169.254/latest/meta-data/network/interfaces/macs/",
+                imds_response("0e:49:61:0f:c3:11/\n"),
+            ),
+            event(mac!("device-number"), imds_response("0")),
+            event(mac!("interface-id"), imds_response("eni-0f95d3625f5c521cc")),
+            event(
+                mac!("local-ipv4s"),
+                imds_response("192.168.0.12\n192.168.0.13"),
+            ),
+            event(mac!("public-ipv4s"), not_found()),
+            event(mac!("ipv6s"), imds_response("2001:db8::1")),
+            event(mac!("subnet-id"), imds_response("subnet-0ac62554")),
+            event(mac!("vpc-id"), imds_response("vpc-d295a6a7")),
+        ]);
+        let client = make_imds_client(&http_client);
+        let interfaces = client
+            .get_network_interfaces()
+            .await
+            .expect("valid interfaces");
+        assert_eq!(
+            vec![NetworkInterface {
+                mac: "0e:49:61:0f:c3:11".to_owned(),
+                device_number: 0,
+                interface_id: "eni-0f95d3625f5c521cc".to_owned(),
+                local_ipv4s: vec!["192.168.0.12".to_owned(), "192.168.0.13".to_owned()],
+                public_ipv4s: vec![],
+                ipv6s: vec!["2001:db8::1".to_owned()],
+                subnet_id: "subnet-0ac62554".to_owned(),
+                vpc_id: "vpc-d295a6a7".to_owned(),
+            }],
+            interfaces
+        );
+        http_client.assert_requests_match(&[]);
+    }
+}
@@ -235,16 +235,18 @@ mod loader {
     use aws_smithy_types::timeout::TimeoutConfig;
     use aws_types::app_name::AppName;
     use aws_types::docs_for;
+    use aws_types::endpoint_config::AccountIdEndpointMode;
     use aws_types::origin::Origin;
     use aws_types::os_shim_internal::{Env, Fs};
     use aws_types::sdk_config::SharedHttpClient;
     use aws_types::SdkConfig;
 
     use crate::default_provider::{
-        app_name, checksums, credentials, disable_request_compression, endpoint_url,
-        ignore_configured_endpoint_urls as ignore_ep, region, request_min_compression_size_bytes,
-        retry_config, timeout_config, use_dual_stack, use_fips,
+        account_id_endpoint_mode, app_name, checksums, credentials, disable_request_compression,
+        endpoint_url, ignore_configured_endpoint_urls as ignore_ep, region,
+        request_min_compression_size_bytes, retry_config, timeout_config, use_dual_stack, use_fips,
     };
+    use crate::meta::credentials::CredentialsChainDiagnostics;
     use crate::meta::region::ProvideRegion;
     #[allow(deprecated)]
     use crate::profile::profile_file::ProfileFiles;
@@ -285,6 +287,7 @@ mod loader {
         profile_files_override: Option<ProfileFiles>,
         use_fips: Option<bool>,
         use_dual_stack: Option<bool>,
+        account_id_endpoint_mode: Option<AccountIdEndpointMode>,
         time_source: Option<SharedTimeSource>,
         disable_request_compression: Option<bool>,
         request_min_compression_size_bytes: Option<u32>,
@@ -660,6 +663,17 @@ mod loader {
         /// When this method is used, the [`Region`](aws_types::region::Region) is only used for signing;
         /// It is **not** used to route the request.
         ///
+        /// Endpoint URLs can also be configured without code changes. When this method isn't used,
+        /// each client loads its endpoint URL from the first of the following that is set:
+        /// 1. The service-specific environment variable, e.g. `AWS_ENDPOINT_URL_S3`
+        /// 2. The `AWS_ENDPOINT_URL` environment variable
+        /// 3. The `endpoint_url` of the service in the profile's `services` section
+        /// 4. The `endpoint_url` key of the profile
+        ///
+        /// Setting `AWS_IGNORE_CONFIGURED_ENDPOINT_URLS=true` or `ignore_configured_endpoint_urls = true`
+        /// in the profile ignores all of these. Endpoint URLs set with this method, or on a
+        /// service's config, are always used.
+        ///
         /// # Examples
         ///
         /// Use a static endpoint for all services
@@ -687,6 +701,12 @@ mod loader {
             self
         }
 
+        #[doc = docs_for!(account_id_endpoint_mode)]
+        pub fn account_id_endpoint_mode(mut self, mode: AccountIdEndpointMode) -> Self {
+            self.account_id_endpoint_mode = Some(mode);
+            self
+        }
+
         #[doc = docs_for!(disable_request_compression)]
         pub fn disable_request_compression(mut self, disable_request_compression: bool) -> Self {
             self.disable_request_compression = Some(disable_request_compression);
@@ -742,6 +762,62 @@ mod loader {
         /// This means that if you provide a region provider that does not return a region, no region will
         /// be set in the resulting [`SdkConfig`].
         pub async fn load(self) -> SdkConfig {
+            self.load_inner(false).await.0
+        }
+
+        /// Load the default configuration chain, and diagnose how credentials are loaded
+        ///
+        /// This loads the config like [`load`](Self::load) does, and then asks its credentials
+        /// provider for credentials once, recording how it was evaluated in the returned
+        /// [`CredentialsChainDiagnostics`]. When the default credentials provider chain is used,
+        /// every provider of the chain that was tried is recorded. A provider set with
+        /// [`credentials_provider`](Self::credentials_provider) is recorded as a single
+        /// `CredentialsProvider` attempt.
+        ///
+        /// The diagnostics keep recording the evaluations of the default credentials provider chain
+        /// after this returns, so they can also be inspected when a client fails to load credentials
+        /// later. The credentials loaded here aren't cached, so clients created from the config load
+        /// them again.
+        ///
+        /// # Examples
+        /// ```no_run
+        /// # async fn example() {
+        /// use aws_config::BehaviorVersion;
+        ///
+        /// let (config, diagnostics) = aws_config::defaults(BehaviorVersion::latest())
+        ///     .load_with_diagnostics()
+        ///     .await;
+        /// // Lists each credentials provider that was tried, how long it took, and what it returned
+        /// println!("{diagnostics}");
+        /// # }
+        /// ```
+        pub async fn load_with_diagnostics(self) -> (SdkConfig, CredentialsChainDiagnostics) {
+            let (config, diagnostics) = self.load_inner(true).await;
+            let time_source = config.time_source().unwrap_or_default();
+            let diagnostics = match (config.credentials_provider(), diagnostics) {
+                // The default chain records its own evaluation
+                (Some(provider), Some(diagnostics)) => {
+                    let _ = provider.provide_credentials().await;
+                    diagnostics
+                }
+                (Some(provider), None) => {
+                    let diagnostics = CredentialsChainDiagnostics::new(time_source);
+                    let _ = diagnostics
+                        .record("CredentialsProvider".into(), provider.provide_credentials())
+                        .await;
+                    diagnostics
+                }
+                (None, _) => CredentialsChainDiagnostics::new(time_source),
+            };
+            (config, diagnostics)
+        }
+
+        /// Loads the config, along with the diagnostics of the default credentials provider chain
+        /// if `diagnose_credentials` is set and the chain is used.
+        async fn load_inner(
+            self,
+            diagnose_credentials: bool,
+        ) -> (SdkConfig, Option<CredentialsChainDiagnostics>) {
             let time_source = self.time_source.unwrap_or_default();
 
             let sleep_impl = if self.sleep.is_some() {
@@ -800,6 +876,7 @@ mod loader {
             let conf = conf.with_region(region.clone());
 
             let retry_config = if let Some(retry_config) = self.retry_config {
+                retry_config::warn_on_conflicting_settings(&retry_config);
                 retry_config
             } else {
                 retry_config::default_provider()
@@ -817,6 +894,12 @@ mod loader {
                     .await
             };
 
+            let account_id_endpoint_mode = if self.account_id_endpoint_mode.is_some() {
+                self.account_id_endpoint_mode
+            } else {
+                account_id_endpoint_mode::account_id_endpoint_mode_provider(&conf).await
+            };
+
             let disable_request_compression = if self.disable_request_compression.is_some() {
                 self.disable_request_compression
             } else {
@@ -842,12 +925,18 @@ mod loader {
                 .unwrap_or_else(|| TimeoutConfig::builder().build());
             timeout_config.take_defaults_from(&base_config);
 
+            let mut credentials_diagnostics = None;
             let credentials_provider = match self.credentials_provider {
                 TriStateOption::Set(provider) => Some(provider),
                 TriStateOption::NotSet => {
                     let mut builder =
                         credentials::DefaultCredentialsChain::builder().configure(conf.clone());
                     builder.set_region(region.clone());
+                    if diagnose_credentials {
+                        let diagnostics = CredentialsChainDiagnostics::new(conf.time_source());
+                        builder = builder.diagnostics(diagnostics.clone());
+                        credentials_diagnostics = Some(diagnostics);
+                    }
                     Some(SharedCredentialsProvider::new(builder.build().await))
                 }
                 TriStateOption::ExplicitlyUnset => None,
@@ -946,10 +1035,11 @@ mod loader {
             builder.set_sleep_impl(sleep_impl);
             builder.set_use_fips(use_fips);
             builder.set_use_dual_stack(use_dual_stack);
+            builder.set_account_id_endpoint_mode(account_id_endpoint_mode);
             builder.set_disable_request_compression(disable_request_compression);
             builder.set_request_min_compression_size_bytes(request_min_compression_size_bytes);
             builder.set_stalled_stream_protection(self.stalled_stream_protection_config);
-            builder.build()
+            (builder.build(), credentials_diagnostics)
         }
     }
 
@@ -968,6 +1058,7 @@ mod loader {
 
     #[cfg(test)]
     mod test {
+        use crate::meta::credentials::ProviderOutcome;
         #[allow(deprecated)]
         use crate::profile::profile_file::{ProfileFileKind, ProfileFiles};
         use crate::test_case::{no_traffic_client, InstantSleep};
@@ -978,6 +1069,7 @@ mod loader {
         use aws_smithy_runtime::client::http::test_util::{infallible_client_fn, NeverClient};
         use aws_smithy_runtime::test_util::capture_test_logs::capture_test_logs;
         use aws_types::app_name::AppName;
+        use aws_types::endpoint_config::AccountIdEndpointMode;
         use aws_types::origin::Origin;
         use aws_types::os_shim_internal::{Env, Fs};
         use std::sync::atomic::{AtomicUsize, Ordering};
@@ -1039,6 +1131,45 @@ mod loader {
             };
         }
 
+        #[tokio::test]
+        async fn load_with_diagnostics() {
+            let env = Env::from_slice(&[
+                ("AWS_REGION", "us-west-4"),
+                ("AWS_EC2_METADATA_DISABLED", "true"),
+            ]);
+            let (_, diagnostics) = base_conf()
+                .env(env)
+                .fs(Fs::from_slice(&[]))
+                .load_with_diagnostics()
+                .await;
+            let attempts = diagnostics.attempts();
+            assert_eq!(
+                vec![
+                    "Environment",
+                    "Profile",
+                    "WebIdentityToken",
+                    "EcsContainer",
+                    "Ec2InstanceMetadata"
+                ],
+                attempts
+                    .iter()
+                    .map(|attempt| attempt.provider())
+                    .collect::<Vec<_>>()
+            );
+            assert!(
+                attempts
+                    .iter()
+                    .all(|attempt| matches!(attempt.outcome(), ProviderOutcome::NotLoaded(_))),
+                "{diagnostics}"
+            );
+
+            let (_, diagnostics) = base_conf().test_credentials().load_with_diagnostics().await;
+            let attempts = diagnostics.attempts();
+            assert_eq!(1, attempts.len(), "{diagnostics}");
+            assert_eq!("CredentialsProvider", attempts[0].provider());
+            assert_eq!(&ProviderOutcome::Loaded, attempts[0].outcome());
+        }
+
         fn base_conf() -> ConfigLoader {
             defaults(BehaviorVersion::latest())
                 .sleep_impl(InstantSleep)
@@ -1123,6 +1254,33 @@ mod loader {
             assert_eq!(Some(true), conf.use_fips());
         }
 
+        #[tokio::test]
+        async fn load_account_id_endpoint_mode() {
+            let conf = base_conf()
+                .account_id_endpoint_mode(AccountIdEndpointMode::Required)
+                .load()
+                .await;
+            assert_eq!(
+                Some(AccountIdEndpointMode::Required),
+                conf.account_id_endpoint_mode()
+            );
+
+            let conf = base_conf()
+                .env(Env::from_slice(&[(
+                    "AWS_ACCOUNT_ID_ENDPOINT_MODE",
+                    "disabled",
+                )]))
+                .load()
+                .await;
+            assert_eq!(
+                Some(AccountIdEndpointMode::Disabled),
+                conf.account_id_endpoint_mode()
+            );
+
+            let conf = base_conf().load().await;
+            assert_eq!(None, conf.account_id_endpoint_mode());
+        }
+
         #[tokio::test]
         async fn load_dual_stack() {
             let conf = base_conf().use_dual_stack(false).load().await;
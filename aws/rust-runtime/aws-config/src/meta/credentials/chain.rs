@@ -3,6 +3,7 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::meta::credentials::CredentialsChainDiagnostics;
 use aws_credential_types::{
     provider::{self, error::CredentialsError, future, ProvideCredentials},
     Credentials,
@@ -34,6 +35,7 @@ use tracing::Instrument;
 /// ```
 pub struct CredentialsProviderChain {
     providers: Vec<(Cow<'static, str>, Box<dyn ProvideCredentials>)>,
+    diagnostics: Option<CredentialsChainDiagnostics>,
 }
 
 impl Debug for CredentialsProviderChain {
@@ -59,6 +61,7 @@ impl CredentialsProviderChain {
     ) -> Self {
         CredentialsProviderChain {
             providers: vec![(name.into(), Box::new(provider))],
+            diagnostics: None,
         }
     }
 
@@ -72,6 +75,14 @@ impl CredentialsProviderChain {
         self
     }
 
+    /// Record each evaluation of this chain in `diagnostics`
+    ///
+    /// See [`CredentialsChainDiagnostics`] for more details.
+    pub fn with_diagnostics(mut self, diagnostics: CredentialsChainDiagnostics) -> Self {
+        self.diagnostics = Some(diagnostics);
+        self
+    }
+
     /// Add a fallback to the default provider chain
     #[cfg(feature = "rustls")]
     pub async fn or_default_provider(self) -> Self {
@@ -91,9 +102,21 @@ impl CredentialsProviderChain {
     }
 
     async fn credentials(&self) -> provider::Result {
+        if let Some(diagnostics) = &self.diagnostics {
+            diagnostics.start();
+        }
         for (name, provider) in &self.providers {
             let span = tracing::debug_span!("load_credentials", provider = %name);
-            match provider.provide_credentials().instrument(span).await {
+            let credentials = match &self.diagnostics {
+                Some(diagnostics) => {
+                    diagnostics
+                        .record(name.clone(), provider.provide_credentials())
+                        .instrument(span)
+                        .await
+                }
+                None => provider.provide_credentials().instrument(span).await,
+            };
+            match credentials {
                 Ok(credentials) => {
                     tracing::debug!(provider = %name, "loaded credentials");
                     return Ok(credentials);
@@ -134,7 +157,7 @@ impl ProvideCredentials for CredentialsProviderChain {
 
 #[cfg(test)]
 mod tests {
-    use std::time::Duration;
+    use std::time::{Duration, UNIX_EPOCH};
 
     use aws_credential_types::{
         credential_fn::provide_credentials_fn,
@@ -142,8 +165,11 @@ mod tests {
         Credentials,
     };
     use aws_smithy_async::future::timeout::Timeout;
+    use aws_smithy_async::test_util::ManualTimeSource;
 
-    use crate::meta::credentials::CredentialsProviderChain;
+    use crate::meta::credentials::{
+        CredentialsChainDiagnostics, CredentialsProviderChain, ProviderOutcome,
+    };
 
     #[derive(Debug)]
     struct FallbackCredentials(Credentials);
@@ -229,4 +255,63 @@ mod tests {
             },
         };
     }
+
+    #[tokio::test]
+    async fn diagnostics_record_each_provider_that_was_tried() {
+        let time_source = ManualTimeSource::new(UNIX_EPOCH);
+        let diagnostics = CredentialsChainDiagnostics::new(time_source.clone());
+        let chain = CredentialsProviderChain::first_try(
+            "provider1",
+            provide_credentials_fn(move || {
+                let time_source = time_source.clone();
+                async move {
+                    time_source.advance(Duration::from_millis(5));
+                    Err(CredentialsError::not_loaded("no credentials configured"))
+                }
+            }),
+        )
+        .or_else(
+            "provider2",
+            provide_credentials_fn(|| async {
+                Err(CredentialsError::provider_error("access denied"))
+            }),
+        )
+        .or_else("provider3", Credentials::for_tests())
+        .with_diagnostics(diagnostics.clone());
+
+        chain
+            .provide_credentials()
+            .await
+            .expect_err("provider2 fails");
+        let attempts = diagnostics.attempts();
+        assert_eq!(
+            vec!["provider1", "provider2"],
+            attempts
+                .iter()
+                .map(|attempt| attempt.provider())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Duration::from_millis(5), attempts[0].latency());
+        assert!(
+            matches!(attempts[0].outcome(), ProviderOutcome::NotLoaded(reason) if reason.contains("no credentials configured")),
+            "{diagnostics}"
+        );
+        assert!(
+            matches!(attempts[1].outcome(), ProviderOutcome::Failed(error) if error.contains("access denied")),
+            "{diagnostics}"
+        );
+        assert!(
+            diagnostics
+                .to_string()
+                .starts_with("provider1: not loaded after 5ms: "),
+            "{diagnostics}"
+        );
+
+        // Only the latest evaluation is kept
+        chain
+            .provide_credentials()
+            .await
+            .expect_err("provider2 fails");
+        assert_eq!(2, diagnostics.attempts().len());
+    }
 }
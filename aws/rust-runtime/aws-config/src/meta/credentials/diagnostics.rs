@@ -0,0 +1,149 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use aws_credential_types::provider::{self, error::CredentialsError};
+use aws_smithy_async::time::{SharedTimeSource, TimeSource};
+use aws_smithy_types::error::display::DisplayErrorContext;
+use std::borrow::Cow;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The outcome of asking a credentials provider for credentials
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProviderOutcome {
+    /// The provider returned credentials
+    Loaded,
+
+    /// The provider wasn't configured to provide credentials, so the next provider was tried
+    NotLoaded(String),
+
+    /// The provider failed to provide credentials, which ended the evaluation of the chain
+    Failed(String),
+}
+
+/// A credentials provider that was asked for credentials
+#[derive(Clone, Debug)]
+pub struct ProviderAttempt {
+    provider: Cow<'static, str>,
+    latency: Duration,
+    outcome: ProviderOutcome,
+}
+
+impl ProviderAttempt {
+    /// The name of the provider in the chain
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// How long the provider took to return
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    /// What the provider returned
+    pub fn outcome(&self) -> &ProviderOutcome {
+        &self.outcome
+    }
+}
+
+impl fmt::Display for ProviderAttempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (provider, latency) = (&self.provider, self.latency);
+        match &self.outcome {
+            ProviderOutcome::Loaded => write!(f, "{provider}: loaded after {latency:?}"),
+            ProviderOutcome::NotLoaded(reason) => {
+                write!(f, "{provider}: not loaded after {latency:?}: {reason}")
+            }
+            ProviderOutcome::Failed(error) => {
+                write!(f, "{provider}: failed after {latency:?}: {error}")
+            }
+        }
+    }
+}
+
+/// Records how a [`CredentialsProviderChain`](crate::meta::credentials::CredentialsProviderChain) was evaluated
+///
+/// When a chain fails to provide credentials, the error only describes the last provider that was
+/// tried. The diagnostics record every provider of the chain that was asked for credentials, how
+/// long it took, and what it returned, which is useful to include in bug reports and support
+/// tickets. Its [`Display`](fmt::Display) implementation lists one provider per line.
+///
+/// Diagnostics are cheap to clone, and clones share the same record. Only the latest evaluation of
+/// the chain is kept.
+///
+/// Use [`ConfigLoader::load_with_diagnostics`](crate::ConfigLoader::load_with_diagnostics) to
+/// get the diagnostics of the default credentials provider chain, or
+/// [`CredentialsProviderChain::with_diagnostics`](crate::meta::credentials::CredentialsProviderChain::with_diagnostics)
+/// to record the evaluations of a custom chain.
+#[derive(Clone, Debug)]
+pub struct CredentialsChainDiagnostics {
+    time_source: SharedTimeSource,
+    attempts: Arc<Mutex<Vec<ProviderAttempt>>>,
+}
+
+impl CredentialsChainDiagnostics {
+    /// Creates diagnostics that measure the latency of providers with `time_source`
+    pub fn new(time_source: impl TimeSource + 'static) -> Self {
+        Self {
+            time_source: SharedTimeSource::new(time_source),
+            attempts: Default::default(),
+        }
+    }
+
+    /// Returns the providers that were asked for credentials during the latest evaluation of the
+    /// chain, in order
+    pub fn attempts(&self) -> Vec<ProviderAttempt> {
+        self.attempts.lock().unwrap().clone()
+    }
+
+    /// Clears the record at the start of an evaluation of the chain
+    pub(crate) fn start(&self) {
+        self.attempts.lock().unwrap().clear();
+    }
+
+    /// Records the outcome of asking the `provider` for credentials
+    pub(crate) async fn record(
+        &self,
+        provider: Cow<'static, str>,
+        credentials: impl Future<Output = provider::Result>,
+    ) -> provider::Result {
+        let started = self.time_source.now();
+        let result = credentials.await;
+        let latency = self
+            .time_source
+            .now()
+            .duration_since(started)
+            .unwrap_or_default();
+        let outcome = match &result {
+            Ok(_) => ProviderOutcome::Loaded,
+            Err(err @ CredentialsError::CredentialsNotLoaded(_)) => {
+                ProviderOutcome::NotLoaded(DisplayErrorContext(err).to_string())
+            }
+            Err(err) => ProviderOutcome::Failed(DisplayErrorContext(err).to_string()),
+        };
+        self.attempts.lock().unwrap().push(ProviderAttempt {
+            provider,
+            latency,
+            outcome,
+        });
+        result
+    }
+}
+
+impl fmt::Display for CredentialsChainDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let attempts = self.attempts();
+        if attempts.is_empty() {
+            return writeln!(f, "no credentials providers were asked for credentials");
+        }
+        for attempt in attempts {
+            writeln!(f, "{attempt}")?;
+        }
+        Ok(())
+    }
+}
@@ -7,3 +7,6 @@
 
 mod chain;
 pub use chain::CredentialsProviderChain;
+
+mod diagnostics;
+pub use diagnostics::{CredentialsChainDiagnostics, ProviderAttempt, ProviderOutcome};
@@ -13,6 +13,7 @@ pub mod parser;
 pub mod credentials;
 pub mod profile_file;
 pub mod region;
+pub mod watcher;
 
 #[cfg(feature = "sso")]
 pub mod token;
@@ -34,6 +35,8 @@ pub use credentials::ProfileFileCredentialsProvider;
 pub use parser::load;
 #[doc(inline)]
 pub use region::ProfileFileRegionProvider;
+#[doc(inline)]
+pub use watcher::ProfileFileWatcher;
 
 mod cell {
     use std::future::Future;
@@ -104,7 +104,7 @@ impl ProfileFileRegionProvider {
     }
 }
 
-fn resolve_profile_chain_for_region(profile_set: &'_ ProfileSet) -> Option<Region> {
+pub(super) fn resolve_profile_chain_for_region(profile_set: &'_ ProfileSet) -> Option<Region> {
     if profile_set.is_empty() {
         return None;
     }
@@ -0,0 +1,311 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Reload profiles when the profile files change
+
+use crate::meta::region::{future as region_future, ProvideRegion};
+#[allow(deprecated)]
+use crate::profile::profile_file::ProfileFiles;
+use crate::profile::region::resolve_profile_chain_for_region;
+use crate::profile::{ProfileFileCredentialsProvider, ProfileFileLoadError, ProfileSet};
+use crate::provider_config::ProviderConfig;
+use aws_credential_types::provider::{self, future, ProvideCredentials};
+use aws_types::region::Region;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::Mutex;
+
+const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+/// Profile provider that picks up changes to the profile files
+///
+/// The other profile providers parse the profile files once, so long-running services need to be
+/// restarted to pick up rotated credentials or a changed region. `ProfileFileWatcher` loads the
+/// profile lazily, and once it is older than the TTL (one minute by default), the next use
+/// re-reads the profile files. When the profile changed, the region and credentials are resolved
+/// from the new profile. When it didn't, the credentials provider built from the previous profile
+/// is kept, along with any credentials it cached.
+///
+/// `ProfileFileWatcher` is a credentials provider and a region provider. It is cheap to clone, and
+/// clones share the loaded profile, so the same watcher can be used for both:
+///
+/// ```no_run
+/// use aws_config::profile::ProfileFileWatcher;
+/// use std::time::Duration;
+///
+/// # async fn example() {
+/// let watcher = ProfileFileWatcher::builder()
+///     .ttl(Duration::from_secs(30))
+///     .build();
+/// let sdk_config = aws_config::from_env()
+///     .credentials_provider(watcher.clone())
+///     .region(watcher)
+///     .load()
+///     .await;
+/// # }
+/// ```
+///
+/// Credentials are resolved like [`ProfileFileCredentialsProvider`] resolves them, and the region
+/// like [`ProfileFileRegionProvider`](crate::profile::ProfileFileRegionProvider) resolves it.
+#[derive(Clone, Debug)]
+pub struct ProfileFileWatcher {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    provider_config: ProviderConfig,
+    ttl: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    checked_at: Option<SystemTime>,
+    loaded: Option<Loaded>,
+}
+
+#[derive(Clone, Debug)]
+struct Loaded {
+    profile: Result<ProfileSet, ProfileFileLoadError>,
+    credentials_provider: Arc<ProfileFileCredentialsProvider>,
+}
+
+impl ProfileFileWatcher {
+    /// Creates a watcher for the default profile files
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// Builder for this profile provider
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Returns the current profile, re-reading the profile files if the TTL expired
+    pub async fn profile(&self) -> Result<ProfileSet, ProfileFileLoadError> {
+        self.current().await.profile
+    }
+
+    async fn current(&self) -> Loaded {
+        let inner = &self.inner;
+        let mut state = inner.state.lock().await;
+        let now = inner.provider_config.time_source().now();
+        let expired = state.checked_at.map_or(true, |checked_at| {
+            now.duration_since(checked_at).unwrap_or_default() >= inner.ttl
+        });
+        if expired {
+            let profile = inner.provider_config.load_profile().await;
+            state.checked_at = Some(now);
+            let changed = match (&state.loaded, &profile) {
+                (Some(loaded), Ok(new)) => loaded.profile.as_ref().map_or(true, |old| old != new),
+                _ => true,
+            };
+            if changed {
+                if state.loaded.is_some() {
+                    tracing::debug!("profile files changed, reloading the profile");
+                }
+                let provider_config = inner
+                    .provider_config
+                    .clone()
+                    .with_parsed_profile(profile.clone());
+                let credentials_provider = ProfileFileCredentialsProvider::builder()
+                    .configure(&provider_config)
+                    .build();
+                state.loaded = Some(Loaded {
+                    profile,
+                    credentials_provider: Arc::new(credentials_provider),
+                });
+            }
+        }
+        state.loaded.clone().expect("loaded above")
+    }
+
+    async fn credentials(&self) -> provider::Result {
+        let credentials_provider = self.current().await.credentials_provider;
+        credentials_provider.provide_credentials().await
+    }
+
+    async fn region(&self) -> Option<Region> {
+        let profile = self.current().await.profile.ok()?;
+        resolve_profile_chain_for_region(&profile)
+    }
+}
+
+impl Default for ProfileFileWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProvideCredentials for ProfileFileWatcher {
+    fn provide_credentials<'a>(&'a self) -> future::ProvideCredentials<'a>
+    where
+        Self: 'a,
+    {
+        future::ProvideCredentials::new(self.credentials())
+    }
+}
+
+impl ProvideRegion for ProfileFileWatcher {
+    fn region(&self) -> region_future::ProvideRegion<'_> {
+        region_future::ProvideRegion::new(self.region())
+    }
+}
+
+/// Builder for [`ProfileFileWatcher`]
+#[derive(Debug, Default)]
+pub struct Builder {
+    config: Option<ProviderConfig>,
+    profile_override: Option<String>,
+    #[allow(deprecated)]
+    profile_files: Option<ProfileFiles>,
+    ttl: Option<Duration>,
+}
+
+impl Builder {
+    /// Override the configuration for the [`ProfileFileWatcher`]
+    pub fn configure(mut self, config: &ProviderConfig) -> Self {
+        self.config = Some(config.clone());
+        self
+    }
+
+    /// Override the profile name used by the [`ProfileFileWatcher`]
+    pub fn profile_name(mut self, profile_name: impl Into<String>) -> Self {
+        self.profile_override = Some(profile_name.into());
+        self
+    }
+
+    /// Set the profile file that should be used by the [`ProfileFileWatcher`]
+    #[allow(deprecated)]
+    pub fn profile_files(mut self, profile_files: ProfileFiles) -> Self {
+        self.profile_files = Some(profile_files);
+        self
+    }
+
+    /// How long a loaded profile is used before the profile files are read again
+    ///
+    /// Defaults to one minute.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Build a [`ProfileFileWatcher`] from this builder
+    pub fn build(self) -> ProfileFileWatcher {
+        let provider_config = self
+            .config
+            .unwrap_or_default()
+            .with_profile_config(self.profile_files, self.profile_override);
+        ProfileFileWatcher {
+            inner: Arc::new(Inner {
+                provider_config,
+                ttl: self.ttl.unwrap_or(DEFAULT_TTL),
+                state: Default::default(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ProfileFileWatcher;
+    #[allow(deprecated)]
+    use crate::profile::profile_file::{ProfileFileKind, ProfileFiles};
+    use crate::provider_config::ProviderConfig;
+    use crate::test_case::no_traffic_client;
+    use aws_credential_types::provider::ProvideCredentials;
+    use aws_smithy_async::test_util::ManualTimeSource;
+    use aws_types::os_shim_internal::{Env, Fs};
+    use aws_types::region::Region;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    const CONFIG: &str = "[default]\nregion = us-east-1\naws_access_key_id = key-1\naws_secret_access_key = secret-1\n";
+    const ROTATED: &str = "[default]\nregion = us-west-2\naws_access_key_id = key-2\naws_secret_access_key = secret-2\n";
+
+    fn watcher(fs: &Fs, time_source: &ManualTimeSource) -> ProfileFileWatcher {
+        let conf = ProviderConfig::empty()
+            .with_fs(fs.clone())
+            .with_env(Env::from_slice(&[]))
+            .with_time_source(time_source.clone())
+            .with_http_client(no_traffic_client());
+        ProfileFileWatcher::builder()
+            .configure(&conf)
+            .profile_files(
+                #[allow(deprecated)]
+                ProfileFiles::builder()
+                    .with_file(
+                        #[allow(deprecated)]
+                        ProfileFileKind::Config,
+                        "config",
+                    )
+                    .build(),
+            )
+            .ttl(Duration::from_secs(60))
+            .build()
+    }
+
+    async fn access_key_id(watcher: &ProfileFileWatcher) -> String {
+        let credentials = watcher.provide_credentials().await.unwrap();
+        credentials.access_key_id().to_owned()
+    }
+
+    #[tokio::test]
+    async fn changes_are_picked_up_after_the_ttl() {
+        let fs = Fs::from_slice(&[("config", CONFIG)]);
+        let time_source = ManualTimeSource::new(UNIX_EPOCH);
+        let watcher = watcher(&fs, &time_source);
+        assert_eq!(
+            Some(Region::from_static("us-east-1")),
+            watcher.region().await
+        );
+        assert_eq!("key-1", access_key_id(&watcher).await);
+
+        fs.write("config", ROTATED).await.unwrap();
+        time_source.advance(Duration::from_secs(30));
+        assert_eq!(
+            Some(Region::from_static("us-east-1")),
+            watcher.region().await,
+            "the profile isn't re-read before the TTL expires"
+        );
+        assert_eq!("key-1", access_key_id(&watcher).await);
+
+        time_source.advance(Duration::from_secs(30));
+        assert_eq!(
+            Some(Region::from_static("us-west-2")),
+            watcher.region().await
+        );
+        assert_eq!("key-2", access_key_id(&watcher).await);
+    }
+
+    #[tokio::test]
+    async fn providers_are_kept_when_the_profile_did_not_change() {
+        let fs = Fs::from_slice(&[("config", CONFIG)]);
+        let time_source = ManualTimeSource::new(UNIX_EPOCH);
+        let watcher = watcher(&fs, &time_source);
+        let first = watcher.current().await.credentials_provider;
+
+        time_source.advance(Duration::from_secs(120));
+        let second = watcher.current().await.credentials_provider;
+        assert!(std::sync::Arc::ptr_eq(&first, &second));
+    }
+
+    #[tokio::test]
+    async fn clones_share_the_loaded_profile() {
+        let fs = Fs::from_slice(&[("config", CONFIG)]);
+        let time_source = ManualTimeSource::new(UNIX_EPOCH);
+        let watcher = watcher(&fs, &time_source);
+        let clone = watcher.clone();
+        assert_eq!("key-1", access_key_id(&watcher).await);
+
+        fs.write("config", ROTATED).await.unwrap();
+        time_source.advance(Duration::from_secs(60));
+        assert_eq!("key-2", access_key_id(&clone).await);
+        assert_eq!(
+            Some(Region::from_static("us-west-2")),
+            watcher.region().await
+        );
+    }
+}
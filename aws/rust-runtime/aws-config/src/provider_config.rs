@@ -268,23 +268,26 @@ impl ProviderConfig {
     pub(crate) async fn try_profile(&self) -> Result<&ProfileSet, &ProfileFileLoadError> {
         let parsed_profile = self
             .parsed_profile
-            .get_or_init(|| async {
-                let profile = profile::load(
-                    &self.fs,
-                    &self.env,
-                    &self.profile_files,
-                    self.profile_name_override.clone(),
-                )
-                .await;
-                if let Err(err) = profile.as_ref() {
-                    tracing::warn!(err = %DisplayErrorContext(&err), "failed to parse profile")
-                }
-                profile
-            })
+            .get_or_init(|| self.load_profile())
             .await;
         parsed_profile.as_ref()
     }
 
+    /// Load the profile from the profile files, bypassing the profile cached in this config
+    pub(crate) async fn load_profile(&self) -> Result<ProfileSet, ProfileFileLoadError> {
+        let profile = profile::load(
+            &self.fs,
+            &self.env,
+            &self.profile_files,
+            self.profile_name_override.clone(),
+        )
+        .await;
+        if let Err(err) = profile.as_ref() {
+            tracing::warn!(err = %DisplayErrorContext(&err), "failed to parse profile")
+        }
+        profile
+    }
+
     pub(crate) async fn profile(&self) -> Option<&ProfileSet> {
         self.try_profile().await.ok()
     }
@@ -334,6 +337,17 @@ impl ProviderConfig {
         }
     }
 
+    /// Use a profile that was already loaded instead of loading it from the profile files
+    pub(crate) fn with_parsed_profile(
+        self,
+        parsed_profile: Result<ProfileSet, ProfileFileLoadError>,
+    ) -> Self {
+        ProviderConfig {
+            parsed_profile: Arc::new(OnceCell::new_with(Some(parsed_profile))),
+            ..self
+        }
+    }
+
     /// Use the [default region chain](crate::default_provider::region) to set the
     /// region for this configuration
     ///
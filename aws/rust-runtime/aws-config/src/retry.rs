@@ -48,7 +48,7 @@ pub mod error {
             use RetryConfigErrorKind::*;
             match &self.kind {
                 InvalidRetryMode { .. } => {
-                    write!(f, "invalid retry configuration")
+                    write!(f, "invalid retry configuration: the retry mode must be `standard` or `adaptive`")
                 }
                 MaxAttemptsMustNotBeZero { .. } => {
                     write!(f, "invalid configuration: It is invalid to set max attempts to 0. Unset it or set it to an integer greater than or equal to one.")
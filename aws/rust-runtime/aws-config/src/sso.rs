@@ -13,4 +13,7 @@ pub mod token;
 
 pub use token::SsoTokenProvider;
 
+#[cfg(feature = "sso-login")]
+pub mod login;
+
 mod cache;
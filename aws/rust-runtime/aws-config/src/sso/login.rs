@@ -0,0 +1,649 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! SSO Login
+//!
+//! This module drives the [SSO OIDC device authorization flow] programmatically, as `aws sso login`
+//! does, for applications that can't shell out to the AWS CLI. The resulting token is written to
+//! `~/.aws/sso/cache`, where [`SsoTokenProvider`](super::SsoTokenProvider) and
+//! [`SsoCredentialsProvider`](super::SsoCredentialsProvider) pick it up.
+//!
+//! [SSO OIDC device authorization flow]: https://docs.aws.amazon.com/singlesignon/latest/OIDCAPIReference/Welcome.html
+//!
+//! ```no_run
+//! # async fn docs() -> Result<(), aws_config::sso::login::SsoLoginError> {
+//! use aws_config::sso::login::SsoLogin;
+//! use aws_types::region::Region;
+//!
+//! let login = SsoLogin::builder()
+//!     .region(Region::new("us-west-2"))
+//!     .session_name("my-sso-session")
+//!     .start_url("https://d-123.awsapps.com/start")
+//!     .build()
+//!     .await;
+//! let _token = login
+//!     .login(|authorization| {
+//!         println!(
+//!             "Open {} and confirm the code {}",
+//!             authorization.verification_uri_complete(),
+//!             authorization.user_code()
+//!         );
+//!     })
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::identity::IdentityCache;
+use crate::sso::cache::{save_cached_token, CachedSsoToken, CachedSsoTokenError};
+use aws_sdk_ssooidc::error::{DisplayErrorContext, SdkError};
+use aws_sdk_ssooidc::Client as SsoOidcClient;
+use aws_smithy_async::rt::sleep::AsyncSleep;
+use aws_smithy_runtime_api::client::identity::http::Token;
+use aws_types::os_shim_internal::{Env, Fs};
+use aws_types::region::Region;
+use aws_types::SdkConfig;
+use std::error::Error as StdError;
+use std::fmt;
+use std::time::{Duration, SystemTime};
+use zeroize::Zeroizing;
+
+const DEFAULT_CLIENT_NAME: &str = "aws-sdk-rust";
+const DEFAULT_SCOPE: &str = "sso:account:access";
+const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+/// Polling interval used when SSO OIDC doesn't specify one, as recommended by RFC 8628.
+const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_secs(5);
+/// Amount the polling interval is increased by when SSO OIDC asks to slow down, as required by RFC 8628.
+const SLOW_DOWN_INTERVAL_INCREASE: Duration = Duration::from_secs(5);
+
+/// SSO Login
+///
+/// Logs in to AWS IAM Identity Center with the SSO OIDC device authorization flow, and caches the
+/// resulting token in `~/.aws/sso/cache/<hash>.json`.
+///
+/// `<hash>` is computed from the configured [`session_name`](Builder::session_name), or from the
+/// [`start_url`](Builder::start_url) when no session name is given, as legacy SSO profiles do.
+#[derive(Debug)]
+pub struct SsoLogin {
+    env: Env,
+    fs: Fs,
+    region: Region,
+    session_name: Option<String>,
+    start_url: String,
+    client_name: String,
+    scopes: Vec<String>,
+    sdk_config: SdkConfig,
+}
+
+/// A pending device authorization that the user must approve in a browser.
+#[derive(Debug)]
+pub struct DeviceAuthorization {
+    user_code: String,
+    verification_uri: String,
+    verification_uri_complete: String,
+    expires_at: SystemTime,
+}
+
+impl DeviceAuthorization {
+    /// Returns the code the user must confirm in the browser.
+    pub fn user_code(&self) -> &str {
+        &self.user_code
+    }
+
+    /// Returns the URI where the user enters the [`user_code`](Self::user_code).
+    pub fn verification_uri(&self) -> &str {
+        &self.verification_uri
+    }
+
+    /// Returns the URI where the user confirms the [`user_code`](Self::user_code) without entering it.
+    pub fn verification_uri_complete(&self) -> &str {
+        &self.verification_uri_complete
+    }
+
+    /// Returns the time after which the authorization can no longer be approved.
+    pub fn expires_at(&self) -> SystemTime {
+        self.expires_at
+    }
+}
+
+impl SsoLogin {
+    /// Creates a `SsoLogin` builder.
+    pub fn builder() -> Builder {
+        Default::default()
+    }
+
+    /// Logs in, returning the new access token once the user has approved the device authorization.
+    ///
+    /// `on_authorization` is called with the pending [`DeviceAuthorization`], and is expected to show
+    /// its URI and code to the user or open a browser. This then polls SSO OIDC until the user approves
+    /// or denies the authorization, or until it expires. The token is cached before being returned.
+    pub async fn login(
+        &self,
+        on_authorization: impl FnOnce(&DeviceAuthorization),
+    ) -> Result<Token, SsoLoginError> {
+        let time_source = self
+            .sdk_config
+            .time_source()
+            .expect("a time source is required by SsoLogin");
+        let sleep_impl = self
+            .sdk_config
+            .sleep_impl()
+            .expect("a sleep implementation is required by SsoLogin");
+        // TODO(enableNewSmithyRuntimeCleanup): Use `customize().config_override()` to set the region instead of creating a new client once middleware is removed
+        let config = self
+            .sdk_config
+            .to_builder()
+            .region(Some(self.region.clone()))
+            .identity_cache(IdentityCache::no_cache())
+            .build();
+        let client = SsoOidcClient::new(&config);
+
+        let registration = client
+            .register_client()
+            .client_name(&self.client_name)
+            .client_type("public")
+            .set_scopes(Some(self.scopes.clone()))
+            .send()
+            .await
+            .map_err(|err| SsoLoginError::service("RegisterClient", err))?;
+        let client_id = registration
+            .client_id()
+            .ok_or_else(|| SsoLoginError::missing_field("RegisterClient", "clientId"))?;
+        let client_secret = Zeroizing::new(
+            registration
+                .client_secret()
+                .ok_or_else(|| SsoLoginError::missing_field("RegisterClient", "clientSecret"))?
+                .to_owned(),
+        );
+        let registration_expires_at = u64::try_from(registration.client_secret_expires_at())
+            .ok()
+            .filter(|secs| *secs > 0)
+            .map(|secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+
+        let now = time_source.now();
+        let device = client
+            .start_device_authorization()
+            .client_id(client_id)
+            .client_secret(client_secret.as_str())
+            .start_url(&self.start_url)
+            .send()
+            .await
+            .map_err(|err| SsoLoginError::service("StartDeviceAuthorization", err))?;
+        let device_code = device.device_code().ok_or_else(|| {
+            SsoLoginError::missing_field("StartDeviceAuthorization", "deviceCode")
+        })?;
+        let authorization = DeviceAuthorization {
+            user_code: device
+                .user_code()
+                .ok_or_else(|| {
+                    SsoLoginError::missing_field("StartDeviceAuthorization", "userCode")
+                })?
+                .to_owned(),
+            verification_uri: device
+                .verification_uri()
+                .ok_or_else(|| {
+                    SsoLoginError::missing_field("StartDeviceAuthorization", "verificationUri")
+                })?
+                .to_owned(),
+            verification_uri_complete: device
+                .verification_uri_complete()
+                .ok_or_else(|| {
+                    SsoLoginError::missing_field(
+                        "StartDeviceAuthorization",
+                        "verificationUriComplete",
+                    )
+                })?
+                .to_owned(),
+            expires_at: now + seconds(device.expires_in()).unwrap_or_default(),
+        };
+        on_authorization(&authorization);
+
+        let mut interval = seconds(device.interval()).unwrap_or(DEFAULT_POLLING_INTERVAL);
+        let created = loop {
+            sleep_impl.sleep(interval).await;
+            if time_source.now() >= authorization.expires_at {
+                return Err(SsoLoginError::AuthorizationExpired);
+            }
+            let result = client
+                .create_token()
+                .grant_type(DEVICE_CODE_GRANT_TYPE)
+                .device_code(device_code)
+                .client_id(client_id)
+                .client_secret(client_secret.as_str())
+                .send()
+                .await;
+            match result {
+                Ok(created) => break created,
+                Err(SdkError::ServiceError(context))
+                    if context.err().is_authorization_pending_exception() =>
+                {
+                    tracing::debug!("SSO device authorization is pending approval");
+                }
+                Err(SdkError::ServiceError(context)) if context.err().is_slow_down_exception() => {
+                    interval += SLOW_DOWN_INTERVAL_INCREASE;
+                    tracing::debug!(interval = ?interval, "SSO OIDC asked to slow down polling");
+                }
+                Err(SdkError::ServiceError(context))
+                    if context.err().is_expired_token_exception() =>
+                {
+                    return Err(SsoLoginError::AuthorizationExpired);
+                }
+                Err(SdkError::ServiceError(context))
+                    if context.err().is_access_denied_exception() =>
+                {
+                    return Err(SsoLoginError::AccessDenied);
+                }
+                Err(err) => return Err(SsoLoginError::service("CreateToken", err)),
+            }
+        };
+
+        let access_token = Zeroizing::new(
+            created
+                .access_token()
+                .ok_or_else(|| SsoLoginError::missing_field("CreateToken", "accessToken"))?
+                .to_owned(),
+        );
+        let expires_at = time_source.now()
+            + seconds(created.expires_in()).ok_or(SsoLoginError::BadExpirationTime)?;
+        let token = CachedSsoToken {
+            access_token,
+            client_id: Some(client_id.to_owned()),
+            client_secret: Some(client_secret),
+            expires_at,
+            refresh_token: created
+                .refresh_token()
+                .map(|t| Zeroizing::new(t.to_owned())),
+            region: Some(self.region.to_string()),
+            registration_expires_at,
+            start_url: Some(self.start_url.clone()),
+        };
+        let identifier = self.session_name.as_deref().unwrap_or(&self.start_url);
+        save_cached_token(&self.env, &self.fs, identifier, &token).await?;
+        tracing::debug!("saved SSO token from device authorization");
+
+        Ok(Token::new(token.access_token.as_str(), Some(expires_at)))
+    }
+}
+
+/// Converts a number of seconds returned by SSO OIDC to a `Duration`, if it is positive.
+fn seconds(value: i32) -> Option<Duration> {
+    u64::try_from(value)
+        .ok()
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs)
+}
+
+/// Builder for [`SsoLogin`].
+#[derive(Debug, Default)]
+pub struct Builder {
+    sdk_config: Option<SdkConfig>,
+    region: Option<Region>,
+    session_name: Option<String>,
+    start_url: Option<String>,
+    client_name: Option<String>,
+    scopes: Option<Vec<String>>,
+}
+
+impl Builder {
+    /// Creates a new builder for [`SsoLogin`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Override the configuration used for the SSO OIDC calls.
+    ///
+    /// The configuration must have a time source and a sleep implementation.
+    pub fn configure(mut self, sdk_config: &SdkConfig) -> Self {
+        self.sdk_config = Some(sdk_config.clone());
+        self
+    }
+
+    /// Sets the SSO region.
+    ///
+    /// This is a required field.
+    pub fn region(mut self, region: impl Into<Region>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Sets the SSO region.
+    ///
+    /// This is a required field.
+    pub fn set_region(&mut self, region: Option<Region>) -> &mut Self {
+        self.region = region;
+        self
+    }
+
+    /// Sets the SSO session name the token is cached for.
+    ///
+    /// When unset, the token is cached for the [`start_url`](Self::start_url) instead.
+    pub fn session_name(mut self, session_name: impl Into<String>) -> Self {
+        self.session_name = Some(session_name.into());
+        self
+    }
+
+    /// Sets the SSO session name the token is cached for.
+    ///
+    /// When unset, the token is cached for the [`start_url`](Self::start_url) instead.
+    pub fn set_session_name(&mut self, session_name: Option<String>) -> &mut Self {
+        self.session_name = session_name;
+        self
+    }
+
+    /// Sets the SSO start URL.
+    ///
+    /// This is a required field.
+    pub fn start_url(mut self, start_url: impl Into<String>) -> Self {
+        self.start_url = Some(start_url.into());
+        self
+    }
+
+    /// Sets the SSO start URL.
+    ///
+    /// This is a required field.
+    pub fn set_start_url(&mut self, start_url: Option<String>) -> &mut Self {
+        self.start_url = start_url;
+        self
+    }
+
+    /// Sets the name of the OIDC client registered for the login, shown to the user when approving it.
+    ///
+    /// Defaults to `aws-sdk-rust`.
+    pub fn client_name(mut self, client_name: impl Into<String>) -> Self {
+        self.client_name = Some(client_name.into());
+        self
+    }
+
+    /// Sets the name of the OIDC client registered for the login, shown to the user when approving it.
+    ///
+    /// Defaults to `aws-sdk-rust`.
+    pub fn set_client_name(&mut self, client_name: Option<String>) -> &mut Self {
+        self.client_name = client_name;
+        self
+    }
+
+    /// Sets the scopes requested for the token.
+    ///
+    /// Defaults to `sso:account:access`, which is required to get role credentials.
+    pub fn scopes(mut self, scopes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.scopes = Some(scopes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the scopes requested for the token.
+    ///
+    /// Defaults to `sso:account:access`, which is required to get role credentials.
+    pub fn set_scopes(&mut self, scopes: Option<Vec<String>>) -> &mut Self {
+        self.scopes = scopes;
+        self
+    }
+
+    /// Builds the [`SsoLogin`].
+    ///
+    /// # Panics
+    ///
+    /// This will panic if any of the required fields are not given.
+    pub async fn build(mut self) -> SsoLogin {
+        if self.sdk_config.is_none() {
+            self.sdk_config = Some(crate::load_defaults(crate::BehaviorVersion::latest()).await);
+        }
+        self.build_with(Env::real(), Fs::real())
+    }
+
+    pub(crate) fn build_with(self, env: Env, fs: Fs) -> SsoLogin {
+        SsoLogin {
+            env,
+            fs,
+            region: self.region.expect("region is required"),
+            session_name: self.session_name,
+            start_url: self.start_url.expect("start_url is required"),
+            client_name: self
+                .client_name
+                .unwrap_or_else(|| DEFAULT_CLIENT_NAME.to_owned()),
+            scopes: self
+                .scopes
+                .unwrap_or_else(|| vec![DEFAULT_SCOPE.to_owned()]),
+            sdk_config: self.sdk_config.expect("sdk_config is required"),
+        }
+    }
+}
+
+/// Error returned when an [`SsoLogin`] fails.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SsoLoginError {
+    /// The user denied the device authorization.
+    AccessDenied,
+    /// The device authorization expired before the user approved it.
+    AuthorizationExpired,
+    /// SSO OIDC responded with a token that has a non-positive expiration duration.
+    BadExpirationTime,
+    /// The token couldn't be written to the SSO token cache.
+    FailedToCacheToken(Box<dyn StdError + Send + Sync>),
+    /// A call to SSO OIDC failed, or responded without a required field.
+    ServiceError {
+        /// The SSO OIDC operation that failed.
+        operation: &'static str,
+        /// The underlying error.
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+impl SsoLoginError {
+    fn service<E, R>(operation: &'static str, err: SdkError<E, R>) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+        R: fmt::Debug + Send + Sync + 'static,
+    {
+        tracing::debug!(
+            "call to SSO OIDC {} for SSO login failed: {}",
+            operation,
+            DisplayErrorContext(&err)
+        );
+        Self::ServiceError {
+            operation,
+            source: err.into(),
+        }
+    }
+
+    fn missing_field(operation: &'static str, field: &'static str) -> Self {
+        Self::ServiceError {
+            operation,
+            source: format!("SSO OIDC responded without `{field}`").into(),
+        }
+    }
+}
+
+impl From<CachedSsoTokenError> for SsoLoginError {
+    fn from(source: CachedSsoTokenError) -> Self {
+        Self::FailedToCacheToken(source.into())
+    }
+}
+
+impl fmt::Display for SsoLoginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccessDenied => f.write_str("the SSO device authorization was denied"),
+            Self::AuthorizationExpired => {
+                f.write_str("the SSO device authorization expired before it was approved")
+            }
+            Self::BadExpirationTime => {
+                f.write_str("SSO OIDC responded with a non-positive expiration duration")
+            }
+            Self::FailedToCacheToken(_) => f.write_str("failed to cache the SSO token"),
+            Self::ServiceError { operation, .. } => {
+                write!(f, "call to SSO OIDC {operation} failed")
+            }
+        }
+    }
+}
+
+impl StdError for SsoLoginError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::FailedToCacheToken(source) => Some(source.as_ref()),
+            Self::ServiceError { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sso::cache::load_cached_token;
+    use aws_smithy_async::rt::sleep::SharedAsyncSleep;
+    use aws_smithy_async::test_util::instant_time_and_sleep;
+    use aws_smithy_async::time::SharedTimeSource;
+    use aws_smithy_runtime::client::http::test_util::{ReplayEvent, StaticReplayClient};
+    use aws_smithy_types::body::SdkBody;
+    use aws_smithy_types::retry::RetryConfig;
+
+    fn response(
+        status: u16,
+        error_type: Option<&str>,
+        body: &'static str,
+    ) -> http::Response<SdkBody> {
+        let mut response = http::Response::builder().status(status);
+        if let Some(error_type) = error_type {
+            response = response.header("x-amzn-errortype", error_type);
+        }
+        response.body(SdkBody::from(body)).unwrap()
+    }
+
+    fn event(response: http::Response<SdkBody>) -> ReplayEvent {
+        ReplayEvent::new(
+            http::Request::builder()
+                .uri("https://oidc.us-west-2.amazonaws.com/")
+                .body(SdkBody::empty())
+                .unwrap(),
+            response,
+        )
+    }
+
+    fn register_client() -> ReplayEvent {
+        event(response(
+            200,
+            None,
+            r#"{ "clientId": "clientid",
+                 "clientSecret": "YSBzZWNyZXQ=",
+                 "clientIdIssuedAt": 1640433600,
+                 "clientSecretExpiresAt": 1671975000 }"#,
+        ))
+    }
+
+    fn start_device_authorization() -> ReplayEvent {
+        event(response(
+            200,
+            None,
+            r#"{ "deviceCode": "devicecode",
+                 "userCode": "ABCD-EFGH",
+                 "verificationUri": "https://device.sso.us-west-2.amazonaws.com/",
+                 "verificationUriComplete": "https://device.sso.us-west-2.amazonaws.com/?user_code=ABCD-EFGH",
+                 "expiresIn": 600,
+                 "interval": 1 }"#,
+        ))
+    }
+
+    fn login(http_client: StaticReplayClient, start_time: SystemTime) -> (SsoLogin, Env, Fs) {
+        let (time_source, sleep_impl) = instant_time_and_sleep(start_time);
+        let config = SdkConfig::builder()
+            .http_client(http_client)
+            .time_source(SharedTimeSource::new(time_source))
+            .sleep_impl(SharedAsyncSleep::new(sleep_impl))
+            // disable retry to simplify testing
+            .retry_config(RetryConfig::disabled())
+            .behavior_version(crate::BehaviorVersion::latest())
+            .build();
+        let env = Env::from_slice(&[("HOME", "/home/user")]);
+        let fs = Fs::from_slice(&[]);
+        let login = SsoLogin::builder()
+            .configure(&config)
+            .region(Region::new("us-west-2"))
+            .session_name("test")
+            .start_url("https://d-123.awsapps.com/start")
+            .build_with(env.clone(), fs.clone());
+        (login, env, fs)
+    }
+
+    // TODO(https://github.com/awslabs/aws-sdk-rust/issues/1117) This test is ignored on Windows because it uses Unix-style paths
+    #[cfg_attr(windows, ignore)]
+    #[tokio::test]
+    async fn device_authorization_login() {
+        let http_client = StaticReplayClient::new(vec![
+            register_client(),
+            start_device_authorization(),
+            event(response(
+                400,
+                Some("AuthorizationPendingException"),
+                r#"{ "error": "authorization_pending" }"#,
+            )),
+            event(response(
+                400,
+                Some("SlowDownException"),
+                r#"{ "error": "slow_down" }"#,
+            )),
+            event(response(
+                200,
+                None,
+                r#"{ "tokenType": "Bearer",
+                     "accessToken": "newtoken",
+                     "expiresIn": 28800,
+                     "refreshToken": "newrefreshtoken" }"#,
+            )),
+        ]);
+        let start_time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_640_433_600);
+        let (login, env, fs) = login(http_client.clone(), start_time);
+
+        let mut user_code = None;
+        let token = login
+            .login(|authorization| user_code = Some(authorization.user_code().to_owned()))
+            .await
+            .unwrap();
+        assert_eq!(Some("ABCD-EFGH".to_owned()), user_code);
+        assert_eq!("newtoken", token.token());
+
+        // Polling waited 1 second, 1 second, then 6 seconds after being asked to slow down.
+        let expires_at = start_time + Duration::from_secs(8 + 28800);
+        assert_eq!(Some(expires_at), token.expiration());
+
+        let cached = load_cached_token(&env, &fs, "test").await.unwrap();
+        assert_eq!("newtoken", cached.access_token.as_str());
+        assert_eq!(expires_at, cached.expires_at);
+        assert_eq!("newrefreshtoken", cached.refresh_token.unwrap().as_str());
+        assert_eq!("clientid", cached.client_id.unwrap());
+        assert_eq!("YSBzZWNyZXQ=", cached.client_secret.unwrap().as_str());
+        assert_eq!(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_671_975_000),
+            cached.registration_expires_at.unwrap()
+        );
+        assert_eq!("us-west-2", cached.region.unwrap());
+        assert_eq!("https://d-123.awsapps.com/start", cached.start_url.unwrap());
+
+        let requests = http_client.actual_requests().collect::<Vec<_>>();
+        let create_token: serde_json::Value =
+            serde_json::from_slice(requests[2].body().bytes().unwrap()).unwrap();
+        assert_eq!(DEVICE_CODE_GRANT_TYPE, create_token["grantType"]);
+        assert_eq!("devicecode", create_token["deviceCode"]);
+        assert_eq!("clientid", create_token["clientId"]);
+    }
+
+    #[tokio::test]
+    async fn device_authorization_denied() {
+        let http_client = StaticReplayClient::new(vec![
+            register_client(),
+            start_device_authorization(),
+            event(response(
+                400,
+                Some("AccessDeniedException"),
+                r#"{ "error": "access_denied" }"#,
+            )),
+        ]);
+        let (login, _, _) = login(http_client, SystemTime::UNIX_EPOCH);
+
+        let err = login.login(|_| {}).await.unwrap_err();
+        assert!(matches!(err, SsoLoginError::AccessDenied), "{err:?}");
+    }
+}
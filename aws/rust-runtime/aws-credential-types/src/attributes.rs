@@ -0,0 +1,46 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Attributes that may be attached to [`Credentials`](crate::Credentials)
+
+use std::fmt;
+
+/// The ID of the AWS account that credentials belong to
+///
+/// The account ID is used by services that resolve account-based endpoints.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct AccountId {
+    inner: String,
+}
+
+impl AccountId {
+    /// Returns the account ID as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl fmt::Debug for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.inner)
+    }
+}
+
+impl<T> From<T> for AccountId
+where
+    T: Into<String>,
+{
+    fn from(value: T) -> Self {
+        Self {
+            inner: value.into(),
+        }
+    }
+}
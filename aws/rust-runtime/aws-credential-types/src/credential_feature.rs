@@ -0,0 +1,55 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! IDs for the features of the credentials providers that resolved a set of [`Credentials`](crate::Credentials)
+
+/// IDs for the features of the credentials providers that may resolve credentials
+///
+/// Credentials providers record these features on the credentials they resolve, so that they can
+/// be reported in the user agent metrics of the requests signed with those credentials.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AwsCredentialFeature {
+    /// Credentials that include an account ID
+    ResolvedAccountId,
+    /// Credentials set in code, e.g. with `Credentials::from_keys`
+    CredentialsCode,
+    /// Credentials resolved from environment variables
+    CredentialsEnvVars,
+    /// Credentials resolved with a web identity token set by environment variables
+    CredentialsEnvVarsStsWebIdToken,
+    /// Credentials resolved by assuming a role with STS
+    CredentialsStsAssumeRole,
+    /// Credentials resolved by assuming a role with STS and a SAML assertion
+    CredentialsStsAssumeRoleSaml,
+    /// Credentials resolved by assuming a role with STS and a web identity token
+    CredentialsStsAssumeRoleWebId,
+    /// Credentials resolved with an STS federation token
+    CredentialsStsFederationToken,
+    /// Credentials resolved with SSO
+    CredentialsSso,
+    /// Credentials resolved with legacy SSO configuration
+    CredentialsSsoLegacy,
+    /// Credentials resolved from a profile
+    CredentialsProfile,
+    /// Credentials resolved from the source profile of a profile
+    CredentialsProfileSourceProfile,
+    /// Credentials resolved by a named provider of a profile
+    CredentialsProfileNamedProvider,
+    /// Credentials resolved with a web identity token set by a profile
+    CredentialsProfileStsWebIdToken,
+    /// Credentials resolved with SSO configured by a profile
+    CredentialsProfileSso,
+    /// Credentials resolved with legacy SSO configured by a profile
+    CredentialsProfileSsoLegacy,
+    /// Credentials resolved by a credential process configured by a profile
+    CredentialsProfileProcess,
+    /// Credentials resolved by a credential process
+    CredentialsProcess,
+    /// Credentials resolved from an HTTP endpoint, e.g. the ECS container credentials endpoint
+    CredentialsHttp,
+    /// Credentials resolved from the EC2 instance metadata service
+    CredentialsImds,
+}
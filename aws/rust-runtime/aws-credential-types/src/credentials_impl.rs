@@ -3,6 +3,8 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::attributes::AccountId;
+use crate::credential_feature::AwsCredentialFeature;
 use aws_smithy_types::date_time::Format;
 use std::fmt;
 use std::fmt::{Debug, Formatter};
@@ -37,6 +39,10 @@ struct Inner {
     /// If these credentials never expire, this value will be set to `None`
     expires_after: Option<SystemTime>,
 
+    // Optional attributes
+    account_id: Option<AccountId>,
+    features: Vec<AwsCredentialFeature>,
+
     provider_name: &'static str,
 }
 
@@ -60,6 +66,9 @@ impl Debug for Credentials {
         } else {
             creds.field("expires_after", &"never");
         }
+        if let Some(account_id) = &self.0.account_id {
+            creds.field("account_id", account_id);
+        }
         creds.finish()
     }
 }
@@ -72,6 +81,8 @@ impl Credentials {
     ///
     /// This is intended to be used from a custom credentials provider implementation.
     /// It is __NOT__ secure to hardcode credentials into your application.
+    ///
+    /// Use [`Credentials::builder`] to set optional attributes such as the account ID.
     pub fn new(
         access_key_id: impl Into<String>,
         secret_access_key: impl Into<String>,
@@ -84,10 +95,32 @@ impl Credentials {
             secret_access_key: Zeroizing::new(secret_access_key.into()),
             session_token: Zeroizing::new(session_token),
             expires_after,
+            account_id: None,
+            features: Vec::new(),
             provider_name,
         }))
     }
 
+    /// Returns a builder for `Credentials`.
+    ///
+    /// This is intended to be used from a custom credentials provider implementation.
+    /// It is __NOT__ secure to hardcode credentials into your application.
+    ///
+    /// ```rust
+    /// use aws_credential_types::Credentials;
+    ///
+    /// let creds = Credentials::builder()
+    ///     .access_key_id("akid")
+    ///     .secret_access_key("secret")
+    ///     .account_id("123456789012")
+    ///     .provider_name("my-provider")
+    ///     .build();
+    /// assert_eq!(Some("123456789012"), creds.account_id().map(|id| id.as_str()));
+    /// ```
+    pub fn builder() -> CredentialsBuilder {
+        CredentialsBuilder::default()
+    }
+
     /// Creates `Credentials` from hardcoded access key, secret key, and session token.
     ///
     /// _Note: In general, you should prefer to use the credential providers that come
@@ -135,13 +168,13 @@ impl Credentials {
         secret_access_key: impl Into<String>,
         session_token: Option<String>,
     ) -> Self {
-        Self::new(
-            access_key_id,
-            secret_access_key,
-            session_token,
-            None,
-            STATIC_CREDENTIALS,
-        )
+        Self::builder()
+            .access_key_id(access_key_id)
+            .secret_access_key(secret_access_key)
+            .set_session_token(session_token)
+            .provider_name(STATIC_CREDENTIALS)
+            .feature(AwsCredentialFeature::CredentialsCode)
+            .build()
     }
 
     /// Returns the access key ID.
@@ -168,6 +201,149 @@ impl Credentials {
     pub fn session_token(&self) -> Option<&str> {
         self.0.session_token.as_deref()
     }
+
+    /// Returns the ID of the AWS account that the credentials belong to, if known.
+    pub fn account_id(&self) -> Option<&AccountId> {
+        self.0.account_id.as_ref()
+    }
+
+    /// Returns the name of the provider that resolved the credentials.
+    pub fn provider_name(&self) -> &'static str {
+        self.0.provider_name
+    }
+
+    /// Returns the features of the credentials providers that resolved the credentials.
+    ///
+    /// Features are reported in the user agent of the requests signed with the credentials.
+    pub fn features(&self) -> &[AwsCredentialFeature] {
+        &self.0.features
+    }
+
+    /// Returns a mutable reference to the features of the credentials providers that resolved
+    /// the credentials.
+    ///
+    /// Credentials providers that wrap other providers use this to record their own features.
+    pub fn features_mut(&mut self) -> &mut Vec<AwsCredentialFeature> {
+        &mut Arc::make_mut(&mut self.0).features
+    }
+}
+
+/// Builder for [`Credentials`].
+///
+/// The access key ID, secret access key and provider name are required.
+#[derive(Default)]
+pub struct CredentialsBuilder {
+    access_key_id: Option<Zeroizing<String>>,
+    secret_access_key: Option<Zeroizing<String>>,
+    session_token: Zeroizing<Option<String>>,
+    expiry: Option<SystemTime>,
+    account_id: Option<AccountId>,
+    features: Vec<AwsCredentialFeature>,
+    provider_name: Option<&'static str>,
+}
+
+impl CredentialsBuilder {
+    /// Sets the access key ID.
+    pub fn access_key_id(mut self, access_key_id: impl Into<String>) -> Self {
+        self.access_key_id = Some(Zeroizing::new(access_key_id.into()));
+        self
+    }
+
+    /// Sets the secret access key.
+    pub fn secret_access_key(mut self, secret_access_key: impl Into<String>) -> Self {
+        self.secret_access_key = Some(Zeroizing::new(secret_access_key.into()));
+        self
+    }
+
+    /// Sets the session token.
+    pub fn session_token(self, session_token: impl Into<String>) -> Self {
+        self.set_session_token(Some(session_token.into()))
+    }
+
+    /// Sets the session token.
+    pub fn set_session_token(mut self, session_token: Option<String>) -> Self {
+        self.session_token = Zeroizing::new(session_token);
+        self
+    }
+
+    /// Sets the time when the credentials will expire.
+    pub fn expiry(self, expiry: SystemTime) -> Self {
+        self.set_expiry(Some(expiry))
+    }
+
+    /// Sets the time when the credentials will expire.
+    pub fn set_expiry(mut self, expiry: Option<SystemTime>) -> Self {
+        self.expiry = expiry;
+        self
+    }
+
+    /// Sets the ID of the AWS account that the credentials belong to.
+    pub fn account_id(self, account_id: impl Into<AccountId>) -> Self {
+        self.set_account_id(Some(account_id.into()))
+    }
+
+    /// Sets the ID of the AWS account that the credentials belong to.
+    pub fn set_account_id(mut self, account_id: Option<AccountId>) -> Self {
+        self.account_id = account_id;
+        self
+    }
+
+    /// Sets the name of the provider that resolved the credentials.
+    pub fn provider_name(mut self, provider_name: &'static str) -> Self {
+        self.provider_name = Some(provider_name);
+        self
+    }
+
+    /// Adds a feature of the credentials provider that resolved the credentials.
+    pub fn feature(mut self, feature: AwsCredentialFeature) -> Self {
+        self.features.push(feature);
+        self
+    }
+
+    /// Sets the features of the credentials providers that resolved the credentials.
+    pub fn set_features(mut self, features: Vec<AwsCredentialFeature>) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Builds the [`Credentials`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the access key ID, the secret access key or the provider name weren't set.
+    pub fn build(self) -> Credentials {
+        Credentials(Arc::new(Inner {
+            access_key_id: self
+                .access_key_id
+                .expect("an access key ID is required to build `Credentials`"),
+            secret_access_key: self
+                .secret_access_key
+                .expect("a secret access key is required to build `Credentials`"),
+            session_token: self.session_token,
+            expires_after: self.expiry,
+            account_id: self.account_id,
+            features: self.features,
+            provider_name: self
+                .provider_name
+                .expect("a provider name is required to build `Credentials`"),
+        }))
+    }
+}
+
+impl Debug for CredentialsBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CredentialsBuilder")
+            .field("provider_name", &self.provider_name)
+            .field(
+                "access_key_id",
+                &self.access_key_id.as_ref().map(|id| id.as_str()),
+            )
+            .field("secret_access_key", &"** redacted **")
+            .field("expiry", &self.expiry)
+            .field("account_id", &self.account_id)
+            .field("features", &self.features)
+            .finish()
+    }
 }
 
 #[cfg(feature = "test-util")]
@@ -198,12 +374,19 @@ impl Credentials {
 impl From<Credentials> for Identity {
     fn from(val: Credentials) -> Self {
         let expiry = val.expiry();
-        Identity::new(val, expiry)
+        let account_id = val.account_id().cloned();
+        let identity = Identity::new(val, expiry);
+        match account_id {
+            // The account ID is exposed to endpoint resolution through an identity property
+            Some(account_id) => identity.with_property(account_id),
+            None => identity,
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::credential_feature::AwsCredentialFeature;
     use crate::Credentials;
     use std::time::{Duration, UNIX_EPOCH};
 
@@ -221,4 +404,70 @@ mod test {
             r#"Credentials { provider_name: "debug tester", access_key_id: "akid", secret_access_key: "** redacted **", expires_after: "2009-02-13T23:31:30Z" }"#
         );
     }
+
+    #[test]
+    fn builder() {
+        let creds = Credentials::builder()
+            .access_key_id("akid")
+            .secret_access_key("secret")
+            .session_token("token")
+            .expiry(UNIX_EPOCH + Duration::from_secs(1234567890))
+            .account_id("123456789012")
+            .feature(AwsCredentialFeature::CredentialsEnvVars)
+            .provider_name("builder tester")
+            .build();
+        assert_eq!("akid", creds.access_key_id());
+        assert_eq!("secret", creds.secret_access_key());
+        assert_eq!(Some("token"), creds.session_token());
+        assert_eq!("builder tester", creds.provider_name());
+        assert_eq!(
+            Some("123456789012"),
+            creds.account_id().map(|id| id.as_str())
+        );
+        assert_eq!(
+            &[AwsCredentialFeature::CredentialsEnvVars],
+            creds.features()
+        );
+        assert_eq!(
+            format!("{:?}", creds),
+            r#"Credentials { provider_name: "builder tester", access_key_id: "akid", secret_access_key: "** redacted **", expires_after: "2009-02-13T23:31:30Z", account_id: "123456789012" }"#
+        );
+
+        let mut with_feature = creds.clone();
+        with_feature
+            .features_mut()
+            .push(AwsCredentialFeature::CredentialsProfile);
+        assert_eq!(1, creds.features().len());
+        assert_eq!(2, with_feature.features().len());
+    }
+
+    #[test]
+    #[should_panic(expected = "a secret access key is required")]
+    fn builder_requires_a_secret_access_key() {
+        Credentials::builder()
+            .access_key_id("akid")
+            .provider_name("builder tester")
+            .build();
+    }
+
+    #[test]
+    fn account_id_is_an_identity_property() {
+        use crate::attributes::AccountId;
+        use aws_smithy_runtime_api::client::identity::Identity;
+
+        let creds = Credentials::builder()
+            .access_key_id("akid")
+            .secret_access_key("secret")
+            .account_id("123456789012")
+            .provider_name("test")
+            .build();
+        let identity = Identity::from(creds);
+        assert_eq!(
+            Some("123456789012"),
+            identity.property::<AccountId>().map(|id| id.as_str())
+        );
+
+        let identity = Identity::from(Credentials::from_keys("akid", "secret", None));
+        assert!(identity.property::<AccountId>().is_none());
+    }
 }
@@ -10,8 +10,8 @@
 use aws_runtime::auth::PayloadSigningOverride;
 use aws_runtime::content_encoding::header_value::AWS_CHUNKED;
 use aws_runtime::content_encoding::{AwsChunkedBody, AwsChunkedBodyOptions};
+use aws_smithy_checksums::body::calculate;
 use aws_smithy_checksums::ChecksumAlgorithm;
-use aws_smithy_checksums::{body::calculate, http::HttpChecksum};
 use aws_smithy_runtime::client::sdk_feature::SmithySdkFeature;
 use aws_smithy_runtime_api::box_error::BoxError;
 use aws_smithy_runtime_api::client::interceptors::context::{
@@ -297,20 +297,24 @@ fn add_checksum_for_request_body(
     checksum_algorithm: ChecksumAlgorithm,
     cfg: &mut ConfigBag,
 ) -> Result<(), BoxError> {
+    // A streaming body known to be empty has nothing to stream a checksum of. Replace it with an
+    // in-memory body so that its precomputed checksum is sent as a header instead of a trailer.
+    if request.body().bytes().is_none() && request.body().size_hint().exact() == Some(0) {
+        *request.body_mut() = SdkBody::empty();
+    }
+
     match request.body().bytes() {
         // Body is in-memory: read it and insert the checksum as a header.
         Some(data) => {
-            let mut checksum = checksum_algorithm.into_impl();
+            let header_name = checksum_algorithm.header_name();
 
             // If the header has not already been set we set it. If it was already set by the user
             // we do nothing and maintain their set value.
-            if request.headers().get(checksum.header_name()).is_none() {
+            if request.headers().get(header_name).is_none() {
                 tracing::debug!("applying {checksum_algorithm:?} of the request body as a header");
-                checksum.update(data);
+                let header_value = checksum_algorithm.header_value(data);
 
-                request
-                    .headers_mut()
-                    .insert(checksum.header_name(), checksum.header_value());
+                request.headers_mut().insert(header_name, header_value);
             }
         }
         // Body is streaming: wrap the body so it will emit a checksum as a trailer.
@@ -345,9 +349,8 @@ fn wrap_streaming_request_body_in_checksum_calculating_body(
         let body = mem::replace(request.body_mut(), SdkBody::taken());
 
         body.map(move |body| {
-            let checksum = checksum_algorithm.into_impl();
-            let trailer_len = HttpChecksum::size(checksum.as_ref());
-            let body = calculate::ChecksumBody::new(body, checksum);
+            let body = calculate::ChecksumBody::new(body, checksum_algorithm.into_impl());
+            let trailer_len = body.trailer_len();
             let aws_chunked_body_options =
                 AwsChunkedBodyOptions::new(original_body_size, vec![trailer_len]);
 
@@ -391,12 +394,16 @@ fn wrap_streaming_request_body_in_checksum_calculating_body(
 
 #[cfg(test)]
 mod tests {
-    use crate::http_request_checksum::wrap_streaming_request_body_in_checksum_calculating_body;
+    use crate::http_request_checksum::{
+        add_checksum_for_request_body, wrap_streaming_request_body_in_checksum_calculating_body,
+    };
+    use aws_runtime::auth::PayloadSigningOverride;
     use aws_smithy_checksums::ChecksumAlgorithm;
     use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
     use aws_smithy_types::base64;
     use aws_smithy_types::body::SdkBody;
     use aws_smithy_types::byte_stream::ByteStream;
+    use aws_smithy_types::config_bag::ConfigBag;
     use bytes::BytesMut;
     use http_body::Body;
     use tempfile::NamedTempFile;
@@ -479,4 +486,30 @@ mod tests {
             "expected {body} to end with '{expected}'"
         );
     }
+
+    #[tokio::test]
+    async fn test_empty_streaming_body_checksum_is_sent_as_a_header() {
+        let file = NamedTempFile::new().unwrap();
+        let mut request = HttpRequest::new(
+            ByteStream::read_from()
+                .path(&file)
+                .build()
+                .await
+                .unwrap()
+                .into_inner(),
+        );
+        assert!(request.body().bytes().is_none());
+
+        let mut cfg = ConfigBag::base();
+        add_checksum_for_request_body(&mut request, ChecksumAlgorithm::Crc32, &mut cfg).unwrap();
+
+        assert_eq!(
+            "AAAAAA==",
+            request.headers().get("x-amz-checksum-crc32").unwrap()
+        );
+        assert!(request.headers().get("x-amz-trailer").is_none());
+        assert!(request.headers().get("content-encoding").is_none());
+        assert_eq!(Some(&b""[..]), request.body().bytes());
+        assert!(cfg.load::<PayloadSigningOverride>().is_none());
+    }
 }
@@ -27,6 +27,10 @@ pub mod sigv4;
 /// Auth implementations for SigV4a.
 pub mod sigv4a;
 
+#[cfg(feature = "signing-debug-info")]
+/// Debugging information for requests rejected because of their signature.
+pub mod signing_debug_info;
+
 /// Type of SigV4 signature.
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum HttpSignatureType {
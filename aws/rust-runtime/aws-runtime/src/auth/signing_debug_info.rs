@@ -0,0 +1,258 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Debugging information for requests the service rejected because of their signature.
+//!
+//! With the `signing-debug-info` feature, the SigV4 signer records the [`SigningDebugInfo`] of
+//! every request it signs. When the service responds with a signature mismatch,
+//! [`SigningDebugInfoInterceptor`] attaches it to the raw response of the error:
+//!
+//! ```rust,ignore
+//! use aws_runtime::auth::signing_debug_info::SigningDebugInfo;
+//!
+//! if let Err(err) = client.get_object().bucket("bucket").key("key").send().await {
+//!     if let Some(info) = err.raw_response().and_then(|r| r.extension::<SigningDebugInfo>()) {
+//!         println!("canonical request:\n{}", info.canonical_request());
+//!     }
+//! }
+//! ```
+
+pub use aws_sigv4::http_request::SigningDebugInfo;
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::context::{
+    BeforeTransmitInterceptorContextRef, FinalizerInterceptorContextMut,
+};
+use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
+
+/// Error codes services respond with when the signature they calculated doesn't match the
+/// request's.
+const SIGNATURE_MISMATCH_CODES: &[&str] = &["SignatureDoesNotMatch", "InvalidSignatureException"];
+
+/// The [`SigningDebugInfo`] of the request being attempted.
+#[derive(Debug)]
+struct AttemptSigningDebugInfo(SigningDebugInfo);
+
+impl Storable for AttemptSigningDebugInfo {
+    type Storer = StoreReplace<Self>;
+}
+
+/// Interceptor that attaches the [`SigningDebugInfo`] of a request to the response when the
+/// service rejects its signature.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct SigningDebugInfoInterceptor;
+
+impl SigningDebugInfoInterceptor {
+    /// Creates a new `SigningDebugInfoInterceptor`.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+fn is_signature_mismatch(response: &HttpResponse) -> bool {
+    if response.status().as_u16() != 403 {
+        return false;
+    }
+    let error_type = response.headers().get("x-amzn-errortype").unwrap_or("");
+    let body = response.body().bytes().unwrap_or_default();
+    SIGNATURE_MISMATCH_CODES.iter().any(|code| {
+        error_type.starts_with(code)
+            || body
+                .windows(code.len())
+                .any(|window| window == code.as_bytes())
+    })
+}
+
+/// Returns `canonical_request` with the session token of temporary credentials redacted, so that
+/// it can be logged.
+fn redact_security_token(canonical_request: &str) -> String {
+    const REDACTED: &str = "** REDACTED **";
+    canonical_request
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            if line.starts_with("x-amz-security-token:") {
+                format!("x-amz-security-token:{REDACTED}")
+            } else if index == 2 {
+                // The canonical query string of presigned requests includes the token
+                line.split('&')
+                    .map(|param| match param.split_once('=') {
+                        Some((name @ "X-Amz-Security-Token", _)) => format!("{name}={REDACTED}"),
+                        _ => param.to_owned(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("&")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Intercept for SigningDebugInfoInterceptor {
+    fn name(&self) -> &'static str {
+        "SigningDebugInfoInterceptor"
+    }
+
+    fn read_before_transmit(
+        &self,
+        context: &BeforeTransmitInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        // The request is gone by the time the response is received, so keep its debug info around.
+        if let Some(info) = context.request().extension::<SigningDebugInfo>() {
+            cfg.interceptor_state()
+                .store_put(AttemptSigningDebugInfo(info.clone()));
+        }
+        Ok(())
+    }
+
+    fn modify_before_attempt_completion(
+        &self,
+        context: &mut FinalizerInterceptorContextMut<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if !matches!(context.output_or_error(), Some(Err(_))) {
+            return Ok(());
+        }
+        let info = match cfg.load::<AttemptSigningDebugInfo>() {
+            Some(AttemptSigningDebugInfo(info)) => info,
+            None => return Ok(()),
+        };
+        if let Some(response) = context.response_mut() {
+            if is_signature_mismatch(response) {
+                tracing::debug!(
+                    signed_headers = info.signed_headers(),
+                    "the service rejected the request's signature"
+                );
+                tracing::trace!(
+                    canonical_request = %redact_security_token(info.canonical_request()),
+                    string_to_sign = info.string_to_sign(),
+                    "signing debug info of the rejected request"
+                );
+                response.add_extension(info.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_credential_types::Credentials;
+    use aws_sigv4::http_request::{sign_with_debug_info, SignableBody, SignableRequest};
+    use aws_sigv4::sign::v4;
+    use aws_smithy_runtime_api::client::interceptors::context::{Error, Input, InterceptorContext};
+    use aws_smithy_runtime_api::client::orchestrator::{HttpRequest, OrchestratorError};
+    use aws_smithy_runtime_api::client::runtime_components::RuntimeComponentsBuilder;
+    use aws_smithy_types::body::SdkBody;
+    use aws_smithy_types::config_bag::Layer;
+    use std::time::SystemTime;
+
+    fn debug_info() -> SigningDebugInfo {
+        let identity = Credentials::for_tests().into();
+        let params = v4::SigningParams::builder()
+            .identity(&identity)
+            .region("us-east-1")
+            .name("s3")
+            .time(SystemTime::UNIX_EPOCH)
+            .settings(Default::default())
+            .build()
+            .unwrap()
+            .into();
+        let request = SignableRequest::new(
+            "GET",
+            "https://bucket.s3.amazonaws.com/key",
+            std::iter::empty(),
+            SignableBody::Bytes(b""),
+        )
+        .unwrap();
+        sign_with_debug_info(request, &params).unwrap().1
+    }
+
+    fn attempt(response: HttpResponse) -> Option<SigningDebugInfo> {
+        let rc = RuntimeComponentsBuilder::for_tests().build().unwrap();
+        let mut cfg = ConfigBag::of_layers(vec![Layer::new("test")]);
+        let mut context = InterceptorContext::new(Input::doesnt_matter());
+        let mut request = HttpRequest::empty();
+        request.add_extension(debug_info());
+        context.set_request(request);
+
+        let interceptor = SigningDebugInfoInterceptor::new();
+        interceptor
+            .read_before_transmit(&(&context).into(), &rc, &mut cfg)
+            .unwrap();
+        context.take_request();
+        context.set_response(response);
+        context.set_output_or_error(Err(OrchestratorError::operation(Error::doesnt_matter())));
+        interceptor
+            .modify_before_attempt_completion(&mut (&mut context).into(), &rc, &mut cfg)
+            .unwrap();
+        context
+            .response()
+            .unwrap()
+            .extension::<SigningDebugInfo>()
+            .cloned()
+    }
+
+    #[test]
+    fn signature_mismatches_include_debug_info() {
+        let response = HttpResponse::new(
+            403.try_into().unwrap(),
+            SdkBody::from("<Error><Code>SignatureDoesNotMatch</Code></Error>"),
+        );
+        assert_eq!(Some(debug_info()), attempt(response));
+
+        let mut response = HttpResponse::new(403.try_into().unwrap(), SdkBody::empty());
+        response.headers_mut().insert(
+            "x-amzn-errortype",
+            "InvalidSignatureException:http://internal.amazon.com/coral/com.amazon.coral.service/",
+        );
+        assert_eq!(Some(debug_info()), attempt(response));
+    }
+
+    #[test]
+    fn other_errors_dont_include_debug_info() {
+        let response = HttpResponse::new(
+            403.try_into().unwrap(),
+            SdkBody::from("<Error><Code>AccessDenied</Code></Error>"),
+        );
+        assert_eq!(None, attempt(response));
+
+        let response = HttpResponse::new(
+            400.try_into().unwrap(),
+            SdkBody::from("<Error><Code>SignatureDoesNotMatch</Code></Error>"),
+        );
+        assert_eq!(None, attempt(response));
+    }
+
+    #[test]
+    fn session_tokens_are_redacted_from_logged_canonical_requests() {
+        let canonical_request = "GET\n\
+            /key\n\
+            X-Amz-Date=20150830T123600Z&X-Amz-Security-Token=query-token\n\
+            host:bucket.s3.amazonaws.com\n\
+            x-amz-security-token:header-token\n\
+            \n\
+            host;x-amz-security-token\n\
+            UNSIGNED-PAYLOAD";
+        let redacted = redact_security_token(canonical_request);
+        assert!(!redacted.contains("query-token"), "{redacted}");
+        assert!(!redacted.contains("header-token"), "{redacted}");
+        assert_eq!(
+            canonical_request
+                .replace("query-token", "** REDACTED **")
+                .replace("header-token", "** REDACTED **"),
+            redacted
+        );
+    }
+}
@@ -9,10 +9,11 @@ use crate::auth::{
     PayloadSigningOverride, SigV4OperationSigningConfig, SigV4SessionTokenNameOverride,
     SigV4SigningError,
 };
+use crate::service_clock_skew::SigningClockSkew;
 use aws_credential_types::Credentials;
-use aws_sigv4::http_request::{
-    sign, SignableBody, SignableRequest, SigningParams, SigningSettings,
-};
+#[cfg(not(feature = "signing-debug-info"))]
+use aws_sigv4::http_request::sign;
+use aws_sigv4::http_request::{SignableBody, SignableRequest, SigningParams, SigningSettings};
 use aws_sigv4::sign::v4;
 use aws_smithy_runtime_api::box_error::BoxError;
 use aws_smithy_runtime_api::client::auth::{
@@ -159,7 +160,10 @@ impl Sign for SigV4Signer {
 
         let operation_config =
             Self::extract_operation_config(auth_scheme_endpoint_config, config_bag)?;
-        let request_time = runtime_components.time_source().unwrap_or_default().now();
+        let mut request_time = runtime_components.time_source().unwrap_or_default().now();
+        if let Some(skew) = config_bag.load::<SigningClockSkew>() {
+            request_time = skew.apply(request_time);
+        }
 
         let settings = if let Some(session_token_name_override) =
             config_bag.load::<SigV4SessionTokenNameOverride>()
@@ -175,6 +179,8 @@ impl Sign for SigV4Signer {
         let signing_params =
             Self::signing_params(settings, identity, &operation_config, request_time)?;
 
+        #[cfg(feature = "signing-debug-info")]
+        let debug_info;
         let (signing_instructions, _signature) = {
             // A body that is already in memory can be signed directly. A body that is not in memory
             // (any sort of streaming body or presigned request) will be signed via UNSIGNED-PAYLOAD.
@@ -208,7 +214,18 @@ impl Sign for SigV4Signer {
                 request.headers().iter(),
                 signable_body,
             )?;
-            sign(signable_request, &SigningParams::V4(signing_params))?
+            let signing_params = SigningParams::V4(signing_params);
+            #[cfg(feature = "signing-debug-info")]
+            {
+                let (output, info) = aws_sigv4::http_request::sign_with_debug_info(
+                    signable_request,
+                    &signing_params,
+                )?;
+                debug_info = info;
+                output
+            }
+            #[cfg(not(feature = "signing-debug-info"))]
+            sign(signable_request, &signing_params)?
         }
         .into_parts();
 
@@ -234,6 +251,8 @@ impl Sign for SigV4Signer {
             }
         }
         auth::apply_signing_instructions(signing_instructions, request)?;
+        #[cfg(feature = "signing-debug-info")]
+        request.add_extension(debug_info);
         Ok(())
     }
 }
@@ -4,24 +4,49 @@
  */
 
 use aws_smithy_runtime_api::box_error::BoxError;
-use aws_smithy_runtime_api::client::interceptors::context::BeforeDeserializationInterceptorContextMut;
+use aws_smithy_runtime_api::client::interceptors::context::{
+    BeforeDeserializationInterceptorContextMut, BeforeTransmitInterceptorContextRef,
+};
 use aws_smithy_runtime_api::client::interceptors::Intercept;
 use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
 use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
 use aws_smithy_types::date_time::Format;
 use aws_smithy_types::DateTime;
-use std::time::Duration;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// The default largest clock skew corrected by [`ServiceClockSkewInterceptor::with_signing_correction`].
+pub const DEFAULT_MAX_SIGNING_CORRECTION: Duration = Duration::from_secs(60 * 60);
+
+/// Skew between the client's and the service's clocks smaller than this is left uncorrected.
+///
+/// SigV4 signatures are accepted for five minutes either side of the service's clock, so smaller
+/// skews can't cause signing failures, and the second precision of the `Date` header makes smaller
+/// skews imprecise.
+const CORRECTION_THRESHOLD: Duration = Duration::from_secs(60);
 
 /// Amount of clock skew between the client and the service.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub(crate) struct ServiceClockSkew {
-    inner: Duration,
+    /// The service's clock minus the client's clock, in milliseconds.
+    offset_millis: i64,
 }
 
 impl ServiceClockSkew {
-    fn new(inner: Duration) -> Self {
-        Self { inner }
+    fn new(offset_millis: i64) -> Self {
+        Self { offset_millis }
+    }
+
+    /// Returns `time` corrected by the skew between the client's and the service's clocks.
+    pub(crate) fn apply(&self, time: SystemTime) -> SystemTime {
+        let offset = Duration::from_millis(self.offset_millis.unsigned_abs());
+        if self.offset_millis >= 0 {
+            time + offset
+        } else {
+            time - offset
+        }
     }
 }
 
@@ -29,27 +54,94 @@ impl Storable for ServiceClockSkew {
     type Storer = StoreReplace<Self>;
 }
 
+/// How far the service's clock is ahead of the client's clock.
 impl From<ServiceClockSkew> for Duration {
     fn from(skew: ServiceClockSkew) -> Duration {
-        skew.inner
+        Duration::from_millis(skew.offset_millis.max(0) as u64)
+    }
+}
+
+/// The skew requests are signed with, stored by a [`ServiceClockSkewInterceptor`] correcting the signing time.
+#[derive(Debug, Clone)]
+pub(crate) struct SigningClockSkew(ServiceClockSkew);
+
+impl SigningClockSkew {
+    /// Returns `time` corrected by the skew between the client's and the service's clocks.
+    pub(crate) fn apply(&self, time: SystemTime) -> SystemTime {
+        self.0.apply(time)
+    }
+}
+
+impl Storable for SigningClockSkew {
+    type Storer = StoreReplace<Self>;
+}
+
+#[derive(Debug)]
+struct SigningCorrection {
+    offset_millis: AtomicI64,
+    max_correction: Duration,
+}
+
+impl SigningCorrection {
+    fn update(&self, skew: &ServiceClockSkew) {
+        let max_millis = i64::try_from(self.max_correction.as_millis()).unwrap_or(i64::MAX);
+        let offset_millis = skew.offset_millis.clamp(-max_millis, max_millis);
+        let previous_millis = self.offset_millis.load(Ordering::Relaxed);
+        if offset_millis.abs_diff(previous_millis) < CORRECTION_THRESHOLD.as_millis() as u64 {
+            return;
+        }
+        tracing::debug!(
+            offset_millis,
+            "correcting the signing time for clock skew between the client and the service"
+        );
+        self.offset_millis.store(offset_millis, Ordering::Relaxed);
     }
 }
 
 /// Interceptor that determines the clock skew between the client and service.
-#[derive(Debug, Default)]
+///
+/// It can also correct the time SigV4 and SigV4a requests are signed at, so that clients with a
+/// skewed clock still sign requests the service accepts. See
+/// [`with_signing_correction`](ServiceClockSkewInterceptor::with_signing_correction).
+#[derive(Clone, Debug, Default)]
 #[non_exhaustive]
-pub struct ServiceClockSkewInterceptor;
+pub struct ServiceClockSkewInterceptor {
+    signing_correction: Option<Arc<SigningCorrection>>,
+}
 
 impl ServiceClockSkewInterceptor {
     /// Creates a new `ServiceClockSkewInterceptor`.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Corrects the time requests are signed at by the clock skew, up to `max_correction`.
+    ///
+    /// The skew is learned from the `Date` header of `400`, `401` and `403` responses, which is how
+    /// services reject requests signed with a skewed clock, e.g. with a `RequestTimeTooSkewed` or
+    /// `InvalidSignatureException` error. It applies to the requests signed afterwards. Clones share
+    /// the same correction, so a single interceptor can be added to several clients.
+    ///
+    /// [`DEFAULT_MAX_SIGNING_CORRECTION`] is a reasonable `max_correction`.
+    pub fn with_signing_correction(mut self, max_correction: Duration) -> Self {
+        self.signing_correction = Some(Arc::new(SigningCorrection {
+            offset_millis: AtomicI64::new(0),
+            max_correction,
+        }));
+        self
+    }
+
+    /// Removes the signing time correction, so requests are signed with the client's clock again.
+    pub fn reset_signing_correction(&self) {
+        if let Some(correction) = &self.signing_correction {
+            correction.offset_millis.store(0, Ordering::Relaxed);
+        }
+    }
 }
 
-fn calculate_skew(time_sent: DateTime, time_received: DateTime) -> Duration {
-    let skew = (time_sent.as_secs_f64() - time_received.as_secs_f64()).max(0.0);
-    Duration::from_secs_f64(skew)
+fn calculate_skew(time_sent: DateTime, time_received: DateTime) -> ServiceClockSkew {
+    let skew_secs = time_sent.as_secs_f64() - time_received.as_secs_f64();
+    ServiceClockSkew::new((skew_secs * 1000.0) as i64)
 }
 
 fn extract_time_sent_from_response(
@@ -68,6 +160,19 @@ impl Intercept for ServiceClockSkewInterceptor {
         "ServiceClockSkewInterceptor"
     }
 
+    fn read_before_signing(
+        &self,
+        _ctx: &BeforeTransmitInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if let Some(correction) = &self.signing_correction {
+            let skew = ServiceClockSkew::new(correction.offset_millis.load(Ordering::Relaxed));
+            cfg.interceptor_state().store_put(SigningClockSkew(skew));
+        }
+        Ok(())
+    }
+
     fn modify_before_deserialization(
         &self,
         ctx: &mut BeforeDeserializationInterceptorContextMut<'_>,
@@ -90,8 +195,145 @@ impl Intercept for ServiceClockSkewInterceptor {
                 return Ok(());
             }
         };
-        let skew = ServiceClockSkew::new(calculate_skew(time_sent, time_received));
+        let skew = calculate_skew(time_sent, time_received);
+        // Services reject requests signed with a skewed clock as unauthenticated or invalid.
+        if let Some(correction) = &self.signing_correction {
+            if matches!(ctx.response().status().as_u16(), 400 | 401 | 403) {
+                correction.update(&skew);
+            }
+        }
         cfg.interceptor_state().store_put(skew);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_smithy_async::time::{SharedTimeSource, StaticTimeSource};
+    use aws_smithy_runtime_api::client::interceptors::context::{Input, InterceptorContext};
+    use aws_smithy_runtime_api::client::orchestrator::{HttpRequest, HttpResponse};
+    use aws_smithy_runtime_api::client::runtime_components::RuntimeComponentsBuilder;
+    use aws_smithy_types::body::SdkBody;
+    use aws_smithy_types::config_bag::Layer;
+    use std::time::UNIX_EPOCH;
+
+    // Sun, 06 Nov 1994 08:49:37 GMT
+    const SERVICE_TIME_SECS: u64 = 784111777;
+    const SERVICE_DATE: &str = "Sun, 06 Nov 1994 08:49:37 GMT";
+
+    /// Runs an attempt receiving a response with `status` and `date` through `interceptor`, and
+    /// returns the time the next request is signed at.
+    fn next_signing_time(
+        interceptor: &ServiceClockSkewInterceptor,
+        status: u16,
+        date: Option<&str>,
+        client_time: SystemTime,
+    ) -> SystemTime {
+        let rc = RuntimeComponentsBuilder::for_tests()
+            .with_time_source(Some(SharedTimeSource::new(StaticTimeSource::new(
+                client_time,
+            ))))
+            .build()
+            .unwrap();
+        let mut cfg = ConfigBag::of_layers(vec![Layer::new("test")]);
+        let mut context = InterceptorContext::new(Input::doesnt_matter());
+        let mut response = HttpResponse::new(status.try_into().unwrap(), SdkBody::empty());
+        if let Some(date) = date {
+            response.headers_mut().insert("date", date.to_owned());
+        }
+        context.set_response(response);
+        interceptor
+            .modify_before_deserialization(&mut (&mut context).into(), &rc, &mut cfg)
+            .unwrap();
+
+        let mut context = InterceptorContext::new(Input::doesnt_matter());
+        context.set_request(HttpRequest::empty());
+        interceptor
+            .read_before_signing(&(&context).into(), &rc, &mut cfg)
+            .unwrap();
+        cfg.load::<SigningClockSkew>()
+            .map_or(client_time, |skew| skew.apply(client_time))
+    }
+
+    #[test]
+    fn measures_skew_without_correcting_the_signing_time_by_default() {
+        let interceptor = ServiceClockSkewInterceptor::new();
+        let client_time = UNIX_EPOCH + Duration::from_secs(SERVICE_TIME_SECS - 600);
+        assert_eq!(
+            client_time,
+            next_signing_time(&interceptor, 403, Some(SERVICE_DATE), client_time)
+        );
+    }
+
+    #[test]
+    fn corrects_signing_time_from_date_of_failed_responses() {
+        let interceptor = ServiceClockSkewInterceptor::new()
+            .with_signing_correction(DEFAULT_MAX_SIGNING_CORRECTION);
+        let client_time = UNIX_EPOCH + Duration::from_secs(SERVICE_TIME_SECS - 600);
+
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_secs(SERVICE_TIME_SECS),
+            next_signing_time(&interceptor, 403, Some(SERVICE_DATE), client_time)
+        );
+        // The correction applies to requests signed afterwards, and is shared by clones.
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_secs(SERVICE_TIME_SECS),
+            next_signing_time(&interceptor.clone(), 200, None, client_time)
+        );
+
+        interceptor.reset_signing_correction();
+        assert_eq!(
+            client_time,
+            next_signing_time(&interceptor, 200, None, client_time)
+        );
+    }
+
+    #[test]
+    fn ignores_responses_that_cant_be_signing_failures() {
+        let interceptor = ServiceClockSkewInterceptor::new()
+            .with_signing_correction(DEFAULT_MAX_SIGNING_CORRECTION);
+        let client_time = UNIX_EPOCH + Duration::from_secs(SERVICE_TIME_SECS - 600);
+
+        for (status, date) in [
+            (500, Some(SERVICE_DATE)),
+            (403, None),
+            (403, Some("not a date")),
+        ] {
+            assert_eq!(
+                client_time,
+                next_signing_time(&interceptor, status, date, client_time)
+            );
+        }
+    }
+
+    #[test]
+    fn corrections_are_bounded() {
+        let interceptor =
+            ServiceClockSkewInterceptor::new().with_signing_correction(Duration::from_secs(300));
+        let client_time = UNIX_EPOCH + Duration::from_secs(SERVICE_TIME_SECS + 3600);
+        assert_eq!(
+            client_time - Duration::from_secs(300),
+            next_signing_time(&interceptor, 403, Some(SERVICE_DATE), client_time)
+        );
+    }
+
+    #[test]
+    fn small_skews_are_left_uncorrected() {
+        let interceptor = ServiceClockSkewInterceptor::new()
+            .with_signing_correction(DEFAULT_MAX_SIGNING_CORRECTION);
+        let client_time = UNIX_EPOCH + Duration::from_secs(SERVICE_TIME_SECS - 2);
+        assert_eq!(
+            client_time,
+            next_signing_time(&interceptor, 403, Some(SERVICE_DATE), client_time)
+        );
+
+        let client_time = UNIX_EPOCH + Duration::from_secs(SERVICE_TIME_SECS - 600);
+        next_signing_time(&interceptor, 403, Some(SERVICE_DATE), client_time);
+        let client_time = UNIX_EPOCH + Duration::from_secs(SERVICE_TIME_SECS - 610);
+        assert_eq!(
+            client_time + Duration::from_secs(600),
+            next_signing_time(&interceptor, 403, Some(SERVICE_DATE), client_time)
+        );
+    }
+}
@@ -8,9 +8,12 @@ use std::fmt;
 
 use http_02x::header::{HeaderName, HeaderValue, InvalidHeaderValue, USER_AGENT};
 
+use aws_credential_types::credential_feature::AwsCredentialFeature;
+use aws_credential_types::Credentials;
 use aws_smithy_runtime::client::sdk_feature::SmithySdkFeature;
 use aws_smithy_runtime_api::box_error::BoxError;
 use aws_smithy_runtime_api::client::http::HttpClient;
+use aws_smithy_runtime_api::client::identity::Identity;
 use aws_smithy_runtime_api::client::interceptors::context::{
     BeforeTransmitInterceptorContextMut, BeforeTransmitInterceptorContextRef,
 };
@@ -22,6 +25,7 @@ use aws_types::os_shim_internal::Env;
 
 use crate::sdk_feature::AwsSdkFeature;
 use crate::user_agent::metrics::ProvideBusinessMetric;
+use crate::user_agent::BusinessMetric;
 use crate::user_agent::{AdditionalMetadata, ApiMetadata, AwsUserAgent, InvalidMetadataValue};
 
 #[allow(clippy::declare_interior_mutable_const)] // we will never mutate this
@@ -146,6 +150,25 @@ impl Intercept for UserAgentInterceptor {
                 .map(|m| ua.add_business_metric(m));
         }
 
+        // The identity is resolved before the `modify_before_signing` interceptors run
+        let credentials = cfg
+            .load::<Identity>()
+            .and_then(|identity| identity.data::<Credentials>());
+        if let Some(credentials) = credentials {
+            for aws_credential_feature in credentials.features() {
+                aws_credential_feature
+                    .provide_business_metric()
+                    .map(|m| ua.add_business_metric(m));
+            }
+            if credentials.account_id().is_some()
+                && !credentials
+                    .features()
+                    .contains(&AwsCredentialFeature::ResolvedAccountId)
+            {
+                ua.add_business_metric(BusinessMetric::ResolvedAccountId);
+            }
+        }
+
         let maybe_connector_metadata = runtime_components
             .http_client()
             .and_then(|c| c.connector_metadata());
@@ -251,6 +274,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_credential_features() {
+        let rc = RuntimeComponentsBuilder::for_tests().build().unwrap();
+        let mut context = context();
+
+        let credentials = Credentials::builder()
+            .access_key_id("akid")
+            .secret_access_key("secret")
+            .account_id("123456789012")
+            .feature(AwsCredentialFeature::CredentialsEnvVars)
+            .provider_name("test")
+            .build();
+        let mut layer = Layer::new("test");
+        layer.store_put(AwsUserAgent::for_tests());
+        layer.store_put(Identity::from(credentials));
+        let mut cfg = ConfigBag::of_layers(vec![layer]);
+
+        let interceptor = UserAgentInterceptor::new();
+        let mut ctx = Into::into(&mut context);
+        interceptor
+            .modify_before_signing(&mut ctx, &rc, &mut cfg)
+            .unwrap();
+
+        // `CredentialsEnvVars` is "g" and `ResolvedAccountId` is "T"
+        let header = expect_header(&context, "x-amz-user-agent");
+        assert!(header.contains("m/g,T"), "{header}");
+    }
+
     #[test]
     fn test_app_name() {
         let rc = RuntimeComponentsBuilder::for_tests().build().unwrap();
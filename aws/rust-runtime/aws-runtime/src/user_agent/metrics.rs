@@ -4,6 +4,7 @@
  */
 
 use crate::sdk_feature::AwsSdkFeature;
+use aws_credential_types::credential_feature::AwsCredentialFeature;
 use aws_smithy_runtime::client::sdk_feature::SmithySdkFeature;
 use once_cell::sync::Lazy;
 use std::borrow::Cow;
@@ -136,7 +137,30 @@ iterable_enum!(
     FlexibleChecksumsReqWhenSupported,
     FlexibleChecksumsReqWhenRequired,
     FlexibleChecksumsResWhenSupported,
-    FlexibleChecksumsResWhenRequired
+    FlexibleChecksumsResWhenRequired,
+    DdbMapper,
+    CredentialsCode,
+    CredentialsJvmSystemProperties,
+    CredentialsEnvVars,
+    CredentialsEnvVarsStsWebIdToken,
+    CredentialsStsAssumeRole,
+    CredentialsStsAssumeRoleSaml,
+    CredentialsStsAssumeRoleWebId,
+    CredentialsStsFederationToken,
+    CredentialsSso,
+    CredentialsSsoLegacy,
+    CredentialsProfile,
+    CredentialsProfileSourceProfile,
+    CredentialsProfileNamedProvider,
+    CredentialsProfileStsWebIdToken,
+    CredentialsProfileSso,
+    CredentialsProfileSsoLegacy,
+    CredentialsProfileProcess,
+    CredentialsProcess,
+    CredentialsBoto2ConfigFile,
+    CredentialsAwsSdkStore,
+    CredentialsHttp,
+    CredentialsImds
 );
 
 pub(crate) trait ProvideBusinessMetric {
@@ -193,6 +217,51 @@ impl ProvideBusinessMetric for AwsSdkFeature {
     }
 }
 
+impl ProvideBusinessMetric for AwsCredentialFeature {
+    fn provide_business_metric(&self) -> Option<BusinessMetric> {
+        use AwsCredentialFeature::*;
+        match self {
+            ResolvedAccountId => Some(BusinessMetric::ResolvedAccountId),
+            CredentialsCode => Some(BusinessMetric::CredentialsCode),
+            CredentialsEnvVars => Some(BusinessMetric::CredentialsEnvVars),
+            CredentialsEnvVarsStsWebIdToken => {
+                Some(BusinessMetric::CredentialsEnvVarsStsWebIdToken)
+            }
+            CredentialsStsAssumeRole => Some(BusinessMetric::CredentialsStsAssumeRole),
+            CredentialsStsAssumeRoleSaml => Some(BusinessMetric::CredentialsStsAssumeRoleSaml),
+            CredentialsStsAssumeRoleWebId => Some(BusinessMetric::CredentialsStsAssumeRoleWebId),
+            CredentialsStsFederationToken => Some(BusinessMetric::CredentialsStsFederationToken),
+            CredentialsSso => Some(BusinessMetric::CredentialsSso),
+            CredentialsSsoLegacy => Some(BusinessMetric::CredentialsSsoLegacy),
+            CredentialsProfile => Some(BusinessMetric::CredentialsProfile),
+            CredentialsProfileSourceProfile => {
+                Some(BusinessMetric::CredentialsProfileSourceProfile)
+            }
+            CredentialsProfileNamedProvider => {
+                Some(BusinessMetric::CredentialsProfileNamedProvider)
+            }
+            CredentialsProfileStsWebIdToken => {
+                Some(BusinessMetric::CredentialsProfileStsWebIdToken)
+            }
+            CredentialsProfileSso => Some(BusinessMetric::CredentialsProfileSso),
+            CredentialsProfileSsoLegacy => Some(BusinessMetric::CredentialsProfileSsoLegacy),
+            CredentialsProfileProcess => Some(BusinessMetric::CredentialsProfileProcess),
+            CredentialsProcess => Some(BusinessMetric::CredentialsProcess),
+            CredentialsHttp => Some(BusinessMetric::CredentialsHttp),
+            CredentialsImds => Some(BusinessMetric::CredentialsImds),
+            otherwise => {
+                // This may occur if a customer upgrades only the `aws-credential-types` crate
+                // while continuing to use an outdated version of the `aws-runtime` crate.
+                tracing::warn!(
+                    "Attempted to provide `BusinessMetric` for `{otherwise:?}`, which is not recognized in the current version of the `aws-runtime` crate. \
+                    Consider upgrading to the latest version to ensure that all tracked features are properly reported in your metrics."
+                );
+                None
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub(super) struct BusinessMetrics(Vec<BusinessMetric>);
 
@@ -297,7 +366,30 @@ mod tests {
   "FLEXIBLE_CHECKSUMS_REQ_WHEN_SUPPORTED" : "Z",
   "FLEXIBLE_CHECKSUMS_REQ_WHEN_REQUIRED" : "a",
   "FLEXIBLE_CHECKSUMS_RES_WHEN_SUPPORTED" : "b",
-  "FLEXIBLE_CHECKSUMS_RES_WHEN_REQUIRED" : "c"
+  "FLEXIBLE_CHECKSUMS_RES_WHEN_REQUIRED" : "c",
+  "DDB_MAPPER": "d",
+  "CREDENTIALS_CODE": "e",
+  "CREDENTIALS_JVM_SYSTEM_PROPERTIES": "f",
+  "CREDENTIALS_ENV_VARS": "g",
+  "CREDENTIALS_ENV_VARS_STS_WEB_ID_TOKEN": "h",
+  "CREDENTIALS_STS_ASSUME_ROLE": "i",
+  "CREDENTIALS_STS_ASSUME_ROLE_SAML": "j",
+  "CREDENTIALS_STS_ASSUME_ROLE_WEB_ID": "k",
+  "CREDENTIALS_STS_FEDERATION_TOKEN": "l",
+  "CREDENTIALS_SSO": "m",
+  "CREDENTIALS_SSO_LEGACY": "n",
+  "CREDENTIALS_PROFILE": "o",
+  "CREDENTIALS_PROFILE_SOURCE_PROFILE": "p",
+  "CREDENTIALS_PROFILE_NAMED_PROVIDER": "q",
+  "CREDENTIALS_PROFILE_STS_WEB_ID_TOKEN": "r",
+  "CREDENTIALS_PROFILE_SSO": "s",
+  "CREDENTIALS_PROFILE_SSO_LEGACY": "t",
+  "CREDENTIALS_PROFILE_PROCESS": "u",
+  "CREDENTIALS_PROCESS": "v",
+  "CREDENTIALS_BOTO2_CONFIG_FILE": "w",
+  "CREDENTIALS_AWS_SDK_STORE": "x",
+  "CREDENTIALS_HTTP": "y",
+  "CREDENTIALS_IMDS": "z"
 }
         "#;
 
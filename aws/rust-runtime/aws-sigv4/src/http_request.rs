@@ -56,6 +56,8 @@
 //! ```
 
 mod canonical_request;
+#[cfg(feature = "signing-debug-info")]
+mod debug_info;
 mod error;
 mod settings;
 mod sign;
@@ -70,12 +72,14 @@ use crate::sign::v4;
 use crate::sign::v4a;
 use crate::SignatureVersion;
 use aws_credential_types::Credentials;
+#[cfg(feature = "signing-debug-info")]
+pub use debug_info::{sign_with_debug_info, SigningDebugInfo};
 pub use error::SigningError;
 pub use settings::{
-    PayloadChecksumKind, PercentEncodingMode, SessionTokenMode, SignatureLocation, SigningSettings,
-    UriPathNormalizationMode,
+    PayloadChecksumKind, PercentEncodingMode, SessionTokenMode, SignatureLocation,
+    SigningKeyCacheMode, SigningSettings, UriPathNormalizationMode, MAX_PRESIGNED_EXPIRES_IN,
 };
-pub use sign::{sign, SignableBody, SignableRequest, SigningInstructions};
+pub use sign::{sign, sign_websocket_upgrade, SignableBody, SignableRequest, SigningInstructions};
 use std::time::SystemTime;
 
 // Individual Debug impls are responsible for redacting sensitive fields.
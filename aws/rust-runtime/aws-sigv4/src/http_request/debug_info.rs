@@ -0,0 +1,133 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use super::canonical_request::{CanonicalRequest, StringToSign};
+use super::error::SigningError;
+use super::sign::validate_expires_in;
+use super::{sign, SignableRequest, SignatureLocation, SigningInstructions, SigningParams};
+use crate::sign::v4;
+use crate::SigningOutput;
+
+/// The intermediate values a request's signature was calculated from.
+///
+/// When a service rejects a signature (for example, with a `SignatureDoesNotMatch` error), it
+/// usually describes the canonical request and string to sign it expected. Comparing them with
+/// these values shows which part of the request was signed differently.
+///
+/// **Note**: The canonical request contains the values of the signed headers, including the
+/// session token of temporary credentials, so this shouldn't be logged where those values
+/// shouldn't be.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SigningDebugInfo {
+    canonical_request: String,
+    string_to_sign: String,
+    signed_headers: String,
+}
+
+impl SigningDebugInfo {
+    /// Returns the canonical request.
+    pub fn canonical_request(&self) -> &str {
+        &self.canonical_request
+    }
+
+    /// Returns the string to sign, which incorporates the hash of the canonical request.
+    pub fn string_to_sign(&self) -> &str {
+        &self.string_to_sign
+    }
+
+    /// Returns the semicolon-separated names of the signed headers.
+    pub fn signed_headers(&self) -> &str {
+        &self.signed_headers
+    }
+}
+
+/// Like [`sign`], but also returns the [`SigningDebugInfo`] the signature was calculated from.
+pub fn sign_with_debug_info<'a>(
+    request: SignableRequest<'a>,
+    params: &'a SigningParams<'a>,
+) -> Result<(SigningOutput<SigningInstructions>, SigningDebugInfo), SigningError> {
+    if params.settings().signature_location == SignatureLocation::QueryParams {
+        validate_expires_in(params)?;
+    }
+    let creq = CanonicalRequest::from(&request, params)?;
+    let canonical_request = creq.to_string();
+    let encoded_creq = v4::sha256_hex_string(canonical_request.as_bytes());
+    let string_to_sign = match params {
+        SigningParams::V4(params) => {
+            StringToSign::new_v4(params.time, params.region, params.name, &encoded_creq)
+        }
+        #[cfg(feature = "sigv4a")]
+        SigningParams::V4a(params) => {
+            StringToSign::new_v4a(params.time, params.region_set, params.name, &encoded_creq)
+        }
+    }
+    .to_string();
+    let debug_info = SigningDebugInfo {
+        signed_headers: creq.values.signed_headers().as_str().to_owned(),
+        canonical_request,
+        string_to_sign,
+    };
+
+    let output = sign(request, params)?;
+    Ok((output, debug_info))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date_time::test_parsers::parse_date_time;
+    use crate::http_request::{test, SigningSettings};
+    use aws_credential_types::Credentials;
+    use std::time::Duration;
+
+    #[test]
+    fn debug_info_matches_the_signature() {
+        let identity = &Credentials::for_tests().into();
+        for (settings, signed_headers) in [
+            (SigningSettings::default(), "host;x-amz-date"),
+            (
+                SigningSettings {
+                    signature_location: SignatureLocation::QueryParams,
+                    expires_in: Some(Duration::from_secs(35)),
+                    ..Default::default()
+                },
+                "host",
+            ),
+        ] {
+            let params = v4::SigningParams {
+                identity,
+                region: "us-east-1",
+                name: "service",
+                time: parse_date_time("20150830T123600Z").unwrap(),
+                settings,
+            }
+            .into();
+            let original = test::v4::test_request("get-vanilla-query-order-key-case");
+            let signable = SignableRequest::from(&original);
+
+            let (output, debug_info) = sign_with_debug_info(signable, &params).unwrap();
+            let expected = sign(SignableRequest::from(&original), &params).unwrap();
+            assert_eq!(expected.signature(), output.signature());
+            assert_eq!(signed_headers, debug_info.signed_headers());
+            assert!(debug_info.canonical_request().starts_with("GET\n/\n"));
+            assert!(debug_info
+                .string_to_sign()
+                .starts_with("AWS4-HMAC-SHA256\n20150830T123600Z\n"));
+            assert_eq!(
+                output.signature(),
+                v4::calculate_signature(
+                    v4::generate_signing_key(
+                        "notrealrnrELgWzOk3IfjzDKtFBhDby",
+                        parse_date_time("20150830T123600Z").unwrap(),
+                        "us-east-1",
+                        "service",
+                    ),
+                    debug_info.string_to_sign().as_bytes(),
+                )
+            );
+        }
+    }
+}
@@ -7,11 +7,14 @@ use http0::header::{InvalidHeaderName, InvalidHeaderValue};
 use http0::uri::InvalidUri;
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
 
 #[derive(Debug)]
 enum SigningErrorKind {
     FailedToCreateCanonicalRequest { source: CanonicalRequestError },
     UnsupportedIdentityType,
+    MissingExpiresIn,
+    ExpiresInTooLong { expires_in: Duration },
 }
 
 /// Error signing request
@@ -26,6 +29,18 @@ impl SigningError {
             kind: SigningErrorKind::UnsupportedIdentityType,
         }
     }
+
+    pub(crate) fn missing_expires_in() -> Self {
+        Self {
+            kind: SigningErrorKind::MissingExpiresIn,
+        }
+    }
+
+    pub(crate) fn expires_in_too_long(expires_in: Duration) -> Self {
+        Self {
+            kind: SigningErrorKind::ExpiresInTooLong { expires_in },
+        }
+    }
 }
 
 impl fmt::Display for SigningError {
@@ -37,6 +52,14 @@ impl fmt::Display for SigningError {
             SigningErrorKind::UnsupportedIdentityType => {
                 write!(f, "only 'AWS credentials' are supported for signing")
             }
+            SigningErrorKind::MissingExpiresIn => {
+                write!(f, "presigned requests require `expires_in` to be set")
+            }
+            SigningErrorKind::ExpiresInTooLong { expires_in } => write!(
+                f,
+                "`expires_in` of {}s is longer than the maximum of one week",
+                expires_in.as_secs()
+            ),
         }
     }
 }
@@ -45,7 +68,9 @@ impl Error for SigningError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match &self.kind {
             SigningErrorKind::FailedToCreateCanonicalRequest { source } => Some(source),
-            SigningErrorKind::UnsupportedIdentityType => None,
+            SigningErrorKind::UnsupportedIdentityType
+            | SigningErrorKind::MissingExpiresIn
+            | SigningErrorKind::ExpiresInTooLong { .. } => None,
         }
     }
 }
@@ -9,6 +9,10 @@ use std::time::Duration;
 
 const HEADER_NAME_X_RAY_TRACE_ID: &str = "x-amzn-trace-id";
 
+/// The longest a presigned request can be valid for. Services reject presigned requests with a longer
+/// `X-Amz-Expires`.
+pub const MAX_PRESIGNED_EXPIRES_IN: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
 /// HTTP-specific signing settings
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
@@ -25,6 +29,9 @@ pub struct SigningSettings {
     pub signature_location: SignatureLocation,
 
     /// For presigned requests, how long the presigned request is valid for
+    ///
+    /// This is required when signing with [`SignatureLocation::QueryParams`], and can't be longer than
+    /// [`MAX_PRESIGNED_EXPIRES_IN`].
     pub expires_in: Option<Duration>,
 
     /// Headers that should be excluded from the signing process
@@ -41,6 +48,12 @@ pub struct SigningSettings {
     /// Some services require an alternative session token header or query param instead of
     /// `x-amz-security-token` or `X-Amz-Security-Token`.
     pub session_token_name_override: Option<&'static str>,
+
+    /// Specifies whether SigV4a signing keys derived from credentials are cached and reused when
+    /// signing later requests with the same credentials.
+    ///
+    /// This has no effect on SigV4 signing.
+    pub signing_key_cache_mode: SigningKeyCacheMode,
 }
 
 /// HTTP payload checksum type
@@ -109,6 +122,20 @@ pub enum SessionTokenMode {
     Exclude,
 }
 
+/// Config value to specify whether derived SigV4a signing keys should be cached.
+///
+/// Deriving a SigV4a signing key from credentials is expensive, so by default, the most recently
+/// derived keys are kept in memory and reused by requests signed with the same credentials.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SigningKeyCacheMode {
+    /// Reuse signing keys derived for earlier requests signed with the same credentials.
+    Enabled,
+
+    /// Derive a new signing key for every request.
+    Disabled,
+}
+
 impl Default for SigningSettings {
     fn default() -> Self {
         // Headers that are potentially altered by proxies or as a part of standard service operations.
@@ -140,6 +167,7 @@ impl Default for SigningSettings {
             uri_path_normalization_mode: UriPathNormalizationMode::Enabled,
             session_token_mode: SessionTokenMode::Include,
             session_token_name_override: None,
+            signing_key_cache_mode: SigningKeyCacheMode::Enabled,
         }
     }
 }
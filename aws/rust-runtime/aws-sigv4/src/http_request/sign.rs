@@ -4,7 +4,9 @@
  */
 
 use super::error::SigningError;
-use super::{PayloadChecksumKind, SignatureLocation};
+#[cfg(feature = "sigv4a")]
+use super::SigningKeyCacheMode;
+use super::{PayloadChecksumKind, SignatureLocation, MAX_PRESIGNED_EXPIRES_IN};
 use crate::http_request::canonical_request::header;
 use crate::http_request::canonical_request::param;
 use crate::http_request::canonical_request::{CanonicalRequest, StringToSign};
@@ -258,6 +260,7 @@ pub fn sign<'a>(
             ))
         }
         SignatureLocation::QueryParams => {
+            validate_expires_in(params)?;
             let (params, signature) = calculate_signing_params(&request, params)?;
             Ok(SigningOutput::new(
                 SigningInstructions::new(vec![], params),
@@ -267,6 +270,57 @@ pub fn sign<'a>(
     }
 }
 
+/// Presigns a WebSocket upgrade request to `uri`, such as the requests that start streams with
+/// Amazon Transcribe or AWS IoT over WebSockets, and returns the query parameters to add to `uri`.
+///
+/// WebSocket clients add their own handshake headers to the upgrade request, and can't send a body
+/// with it, so the canonical request is built from a `GET` of `uri` with only the `host` header
+/// signed and the hash of an empty payload, regardless of the `signature_location` and
+/// `payload_checksum_kind` settings. `expires_in` must be set, and can't be longer than
+/// [`MAX_PRESIGNED_EXPIRES_IN`].
+///
+/// Services disagree on where the `X-Amz-Security-Token` of temporary credentials goes:
+/// [`SessionTokenMode::Include`](super::SessionTokenMode::Include) signs it, as Amazon Transcribe
+/// expects, while [`SessionTokenMode::Exclude`](super::SessionTokenMode::Exclude) adds it after
+/// the signature is calculated, as AWS IoT expects.
+pub fn sign_websocket_upgrade<'a>(
+    uri: &'a str,
+    params: &'a SigningParams<'a>,
+) -> Result<SigningOutput<SigningInstructions>, SigningError> {
+    let mut settings = params.settings().clone();
+    settings.signature_location = SignatureLocation::QueryParams;
+    settings.payload_checksum_kind = PayloadChecksumKind::NoHeader;
+    let params = match params {
+        SigningParams::V4(params) => SigningParams::V4(v4::SigningParams {
+            identity: params.identity,
+            region: params.region,
+            name: params.name,
+            time: params.time,
+            settings,
+        }),
+        #[cfg(feature = "sigv4a")]
+        SigningParams::V4a(params) => SigningParams::V4a(v4a::SigningParams {
+            identity: params.identity,
+            region_set: params.region_set,
+            name: params.name,
+            time: params.time,
+            settings,
+        }),
+    };
+    let request = SignableRequest::new("GET", uri, std::iter::empty(), SignableBody::empty())?;
+    sign(request, &params)
+}
+
+pub(super) fn validate_expires_in(params: &SigningParams<'_>) -> Result<(), SigningError> {
+    match params.settings().expires_in {
+        None => Err(SigningError::missing_expires_in()),
+        Some(expires_in) if expires_in > MAX_PRESIGNED_EXPIRES_IN => {
+            Err(SigningError::expires_in_too_long(expires_in))
+        }
+        Some(_) => Ok(()),
+    }
+}
+
 type CalculatedParams = Vec<(&'static str, Cow<'static, str>)>;
 
 fn calculate_signing_params<'a>(
@@ -297,8 +351,7 @@ fn calculate_signing_params<'a>(
                 StringToSign::new_v4a(params.time, params.region_set, params.name, encoded_creq)
                     .to_string();
 
-            let secret_key =
-                v4a::generate_signing_key(creds.access_key_id(), creds.secret_access_key());
+            let secret_key = v4a_signing_key(creds, params.settings.signing_key_cache_mode);
             let signature = v4a::calculate_signature(&secret_key, string_to_sign.as_bytes());
             (signature, string_to_sign)
         }
@@ -341,6 +394,23 @@ fn calculate_signing_params<'a>(
     Ok((signing_params, signature))
 }
 
+#[cfg(feature = "sigv4a")]
+fn v4a_signing_key(
+    creds: &aws_credential_types::Credentials,
+    cache_mode: SigningKeyCacheMode,
+) -> impl AsRef<[u8]> {
+    match cache_mode {
+        SigningKeyCacheMode::Enabled => {
+            v4a::cached_signing_key(creds.access_key_id(), creds.secret_access_key())
+        }
+        SigningKeyCacheMode::Disabled => zeroize::Zeroizing::new(
+            v4a::generate_signing_key(creds.access_key_id(), creds.secret_access_key())
+                .as_ref()
+                .to_vec(),
+        ),
+    }
+}
+
 /// Calculates the signature headers that need to get added to the given `request`.
 ///
 /// `request` MUST NOT contain any of the following headers:
@@ -423,8 +493,7 @@ fn calculate_signing_headers<'a>(
                 encoded_creq.as_str(),
             );
 
-            let signing_key =
-                v4a::generate_signing_key(creds.access_key_id(), creds.secret_access_key());
+            let signing_key = v4a_signing_key(creds, params.settings.signing_key_cache_mode);
             let signature = v4a::calculate_signature(&signing_key, sts.to_string().as_bytes());
 
             let values = creq.values.as_headers().expect("signing with headers");
@@ -506,8 +575,8 @@ mod tests {
     use crate::date_time::test_parsers::parse_date_time;
     use crate::http_request::sign::{add_header, SignableRequest};
     use crate::http_request::{
-        sign, test, SessionTokenMode, SignableBody, SignatureLocation, SigningInstructions,
-        SigningSettings,
+        sign, sign_websocket_upgrade, test, SessionTokenMode, SignableBody, SignatureLocation,
+        SigningInstructions, SigningSettings, MAX_PRESIGNED_EXPIRES_IN,
     };
     use crate::sign::v4;
     use aws_credential_types::Credentials;
@@ -627,10 +696,42 @@ mod tests {
 
             let signing_key = SigningKey::from_bytes(signing_key.as_ref()).unwrap();
             let peer_public_key = signing_key.verifying_key();
+            assert_eq!(
+                test::v4a::test_public_key(test_name),
+                peer_public_key.to_encoded_point(false).as_bytes(),
+                "derived public key didn't match"
+            );
             let sts = actual_string_to_sign.as_bytes();
             peer_public_key.verify(sts, &sig).unwrap();
         }
 
+        #[test]
+        fn test_suite_in_every_signature_location() {
+            // These vectors hash the request body, but test requests are parsed without their body.
+            const BODY_HASHING_VECTORS: &[(&str, SignatureLocation)] = &[
+                ("post-x-www-form-urlencoded", SignatureLocation::QueryParams),
+                (
+                    "post-x-www-form-urlencoded-parameters",
+                    SignatureLocation::Headers,
+                ),
+            ];
+            let mut test_names: Vec<_> = std::fs::read_dir("aws-sig-v4a-test-suite")
+                .unwrap()
+                .map(|entry| entry.unwrap())
+                .filter(|entry| entry.file_type().unwrap().is_dir())
+                .map(|entry| entry.file_name().into_string().unwrap())
+                .collect();
+            test_names.sort();
+            assert!(!test_names.is_empty());
+            for test_name in &test_names {
+                for location in [SignatureLocation::Headers, SignatureLocation::QueryParams] {
+                    if !BODY_HASHING_VECTORS.contains(&(test_name.as_str(), location)) {
+                        run_v4a_test_suite(test_name, location);
+                    }
+                }
+            }
+        }
+
         #[test]
         fn test_get_header_key_duplicate() {
             run_v4a_test_suite("get-header-key-duplicate", SignatureLocation::Headers);
@@ -884,6 +985,149 @@ mod tests {
         assert_req_eq!(expected, signed);
     }
 
+    #[test]
+    fn test_sign_websocket_upgrade() {
+        let settings = SigningSettings {
+            // Overridden, since WebSocket upgrades are always presigned
+            signature_location: SignatureLocation::Headers,
+            expires_in: Some(Duration::from_secs(300)),
+            ..Default::default()
+        };
+        let identity = &Credentials::for_tests().into();
+        let params = v4::SigningParams {
+            identity,
+            region: "us-east-1",
+            name: "transcribe",
+            time: parse_date_time("20150830T123600Z").unwrap(),
+            settings,
+        }
+        .into();
+
+        let out = sign_websocket_upgrade(
+            "wss://transcribestreaming.us-east-1.amazonaws.com/stream-transcription-websocket?language-code=en-US&media-encoding=pcm&sample-rate=16000",
+            &params,
+        )
+        .unwrap();
+        assert_eq!(
+            "fcc01ab1e12d306ca20b477d380794c27a92c74f10f6a0efc50afe3944b06459",
+            out.signature
+        );
+        assert!(out.output.headers().next().is_none());
+        let params: Vec<_> = out
+            .output
+            .params()
+            .iter()
+            .map(|(name, value)| (*name, value.as_ref()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("X-Amz-Algorithm", "AWS4-HMAC-SHA256"),
+                (
+                    "X-Amz-Credential",
+                    "ANOTREAL/20150830/us-east-1/transcribe/aws4_request"
+                ),
+                ("X-Amz-Date", "20150830T123600Z"),
+                ("X-Amz-Expires", "300"),
+                ("X-Amz-SignedHeaders", "host"),
+                (
+                    "X-Amz-Signature",
+                    "fcc01ab1e12d306ca20b477d380794c27a92c74f10f6a0efc50afe3944b06459"
+                ),
+            ],
+            params
+        );
+    }
+
+    #[test]
+    fn test_sign_websocket_upgrade_session_token_placement() {
+        let identity = &Credentials::for_tests_with_session_token().into();
+        let uri = "wss://data-ats.iot.us-east-1.amazonaws.com/mqtt";
+        let signature = |session_token_mode| {
+            let params = v4::SigningParams {
+                identity,
+                region: "us-east-1",
+                name: "iotdevicegateway",
+                time: parse_date_time("20150830T123600Z").unwrap(),
+                settings: SigningSettings {
+                    expires_in: Some(Duration::from_secs(300)),
+                    session_token_mode,
+                    ..Default::default()
+                },
+            }
+            .into();
+            let out = sign_websocket_upgrade(uri, &params).unwrap();
+            let (_, params) = out.output.into_parts();
+            assert_eq!(
+                Some(&("X-Amz-Security-Token", "notarealsessiontoken".into())),
+                params.last()
+            );
+            out.signature
+        };
+
+        // The session token only changes the signature when it's signed
+        assert_ne!(
+            signature(SessionTokenMode::Include),
+            signature(SessionTokenMode::Exclude)
+        );
+        let identity_without_token = &Credentials::for_tests().into();
+        let without_token = v4::SigningParams {
+            identity: identity_without_token,
+            region: "us-east-1",
+            name: "iotdevicegateway",
+            time: parse_date_time("20150830T123600Z").unwrap(),
+            settings: SigningSettings {
+                expires_in: Some(Duration::from_secs(300)),
+                ..Default::default()
+            },
+        }
+        .into();
+        assert_eq!(
+            sign_websocket_upgrade(uri, &without_token)
+                .unwrap()
+                .signature,
+            signature(SessionTokenMode::Exclude)
+        );
+    }
+
+    #[test]
+    fn test_presigning_validates_expires_in() {
+        let identity = &Credentials::for_tests().into();
+        let params = |expires_in| {
+            v4::SigningParams {
+                identity,
+                region: "us-east-1",
+                name: "service",
+                time: parse_date_time("20150830T123600Z").unwrap(),
+                settings: SigningSettings {
+                    signature_location: SignatureLocation::QueryParams,
+                    expires_in,
+                    ..Default::default()
+                },
+            }
+            .into()
+        };
+        let uri = "https://some-endpoint.some-region.amazonaws.com";
+        let signable = || SignableRequest::new("GET", uri, iter::empty(), SignableBody::empty());
+
+        sign(signable().unwrap(), &params(Some(MAX_PRESIGNED_EXPIRES_IN)))
+            .expect("one week is the longest valid expiry");
+        let err = sign(
+            signable().unwrap(),
+            &params(Some(MAX_PRESIGNED_EXPIRES_IN + Duration::from_secs(1))),
+        )
+        .expect_err("expiry longer than one week");
+        assert_eq!(
+            "`expires_in` of 604801s is longer than the maximum of one week",
+            err.to_string()
+        );
+        let err = sign(signable().unwrap(), &params(None)).expect_err("expiry is required");
+        assert_eq!(
+            "presigned requests require `expires_in` to be set",
+            err.to_string()
+        );
+        sign_websocket_upgrade(uri, &params(None)).expect_err("expiry is required");
+    }
+
     #[test]
     fn test_sign_headers_utf8() {
         let settings = SigningSettings::default();
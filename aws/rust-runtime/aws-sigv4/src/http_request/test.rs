@@ -108,6 +108,24 @@ pub(crate) mod v4a {
         tc_builder.build()
     }
 
+    /// Returns the SEC1-encoded public key for the signing key derived from the test credentials
+    pub(crate) fn test_public_key(test_name: &str) -> Vec<u8> {
+        let path = format!("aws-sig-v4a-test-suite/{test_name}/public-key.json");
+        let public_key: TestPublicKey = serde_json::from_str(&read(&path)).unwrap();
+        let mut encoded = vec![0x04];
+        encoded.extend(hex::decode(public_key.x).unwrap());
+        encoded.extend(hex::decode(public_key.y).unwrap());
+        encoded
+    }
+
+    #[derive(Deserialize)]
+    struct TestPublicKey {
+        #[serde(rename = "X")]
+        x: String,
+        #[serde(rename = "Y")]
+        y: String,
+    }
+
     pub(crate) struct TestContext {
         pub(crate) identity: Identity,
         pub(crate) expiration_in_seconds: u64,
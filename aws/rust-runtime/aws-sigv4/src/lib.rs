@@ -24,6 +24,9 @@ pub mod sign;
 
 mod date_time;
 
+#[cfg(feature = "sign-http")]
+pub mod aws_chunked;
+
 #[cfg(feature = "sign-eventstream")]
 pub mod event_stream;
 
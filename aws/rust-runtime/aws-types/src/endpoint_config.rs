@@ -8,6 +8,9 @@
 //! Parameters require newtypes so they have distinct types when stored in layers in config bag.
 
 use aws_smithy_types::config_bag::{Storable, StoreReplace};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
 
 /// Newtype for `use_fips`
 #[derive(Clone, Debug)]
@@ -29,3 +32,109 @@ pub struct EndpointUrl(pub String);
 impl Storable for EndpointUrl {
     type Storer = StoreReplace<EndpointUrl>;
 }
+
+/// Controls whether the account ID of the credentials is used to route requests to
+/// account-based endpoints.
+///
+/// * [AccountIdEndpointMode::Preferred] - (default) Use an account-based endpoint when the
+///   credentials have an account ID, and fall back to a regular endpoint otherwise.
+/// * [AccountIdEndpointMode::Disabled] - Never use account-based endpoints.
+/// * [AccountIdEndpointMode::Required] - Always use an account-based endpoint. Requests fail when
+///   the credentials don't have an account ID.
+///
+/// Only some services support account-based endpoints. For services that don't, this setting
+/// does nothing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AccountIdEndpointMode {
+    /// Use account-based endpoints when an account ID is available.
+    #[default]
+    Preferred,
+    /// Never use account-based endpoints.
+    Disabled,
+    /// Always use account-based endpoints.
+    Required,
+}
+
+impl AccountIdEndpointMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Preferred => "preferred",
+            Self::Disabled => "disabled",
+            Self::Required => "required",
+        }
+    }
+}
+
+impl Storable for AccountIdEndpointMode {
+    type Storer = StoreReplace<Self>;
+}
+
+impl fmt::Display for AccountIdEndpointMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AccountIdEndpointMode {
+    type Err = UnknownAccountIdEndpointModeError;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        [Self::Preferred, Self::Disabled, Self::Required]
+            .into_iter()
+            .find(|known| mode.eq_ignore_ascii_case(known.as_str()))
+            .ok_or_else(|| UnknownAccountIdEndpointModeError {
+                mode: mode.to_owned(),
+            })
+    }
+}
+
+/// Unknown setting for `account_id_endpoint_mode`
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct UnknownAccountIdEndpointModeError {
+    mode: String,
+}
+
+impl UnknownAccountIdEndpointModeError {
+    /// The unknown value
+    pub fn mode(&self) -> &str {
+        &self.mode
+    }
+}
+
+impl fmt::Display for UnknownAccountIdEndpointModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            r#"unknown account_id_endpoint_mode value "{}", please pass a known name ("preferred", "disabled", "required")"#,
+            self.mode
+        )
+    }
+}
+
+impl Error for UnknownAccountIdEndpointModeError {}
+
+#[cfg(test)]
+mod test {
+    use super::AccountIdEndpointMode;
+
+    #[test]
+    fn parse_account_id_endpoint_mode() {
+        for mode in [
+            AccountIdEndpointMode::Preferred,
+            AccountIdEndpointMode::Disabled,
+            AccountIdEndpointMode::Required,
+        ] {
+            assert_eq!(mode, mode.to_string().parse().unwrap());
+        }
+        assert_eq!(AccountIdEndpointMode::Required, "REQUIRED".parse().unwrap());
+
+        let err = "sometimes".parse::<AccountIdEndpointMode>().unwrap_err();
+        assert_eq!("sometimes", err.mode());
+        assert_eq!(
+            r#"unknown account_id_endpoint_mode value "sometimes", please pass a known name ("preferred", "disabled", "required")"#,
+            err.to_string()
+        );
+    }
+}
@@ -152,7 +152,9 @@ impl Fs {
 
     /// Write a slice as the entire contents of a file.
     ///
-    /// This is equivalent to `std::fs::write`.
+    /// This is equivalent to `std::fs::write`, except that the file is replaced atomically: the
+    /// contents are written to a temporary file in the same directory that is then renamed, so
+    /// concurrent readers never see a partially written file.
     pub async fn write(
         &self,
         path: impl AsRef<Path>,
@@ -162,7 +164,7 @@ impl Fs {
         match &self.0 {
             // TODO(https://github.com/awslabs/aws-sdk-rust/issues/867): Use async IO below
             Inner::Real => {
-                std::fs::write(path, contents)?;
+                fs::write_atomically(path.as_ref(), contents.as_ref())?;
             }
             Inner::Fake(fake) => match fake.as_ref() {
                 Fake::MapFs(fs) => {
@@ -178,7 +180,7 @@ impl Fs {
                         .as_ref()
                         .strip_prefix(namespaced_to)
                         .map_err(|_| std::io::Error::from(std::io::ErrorKind::NotFound))?;
-                    std::fs::write(real_path.join(actual_path), contents)?;
+                    fs::write_atomically(&real_path.join(actual_path), contents.as_ref())?;
                 }
             },
         }
@@ -189,7 +191,9 @@ impl Fs {
 mod fs {
     use std::collections::HashMap;
     use std::ffi::OsString;
-    use std::path::PathBuf;
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, Mutex};
 
     #[derive(Clone, Debug)]
@@ -206,6 +210,54 @@ mod fs {
             namespaced_to: PathBuf,
         },
     }
+
+    /// Writes `contents` to a temporary file next to `path`, and then renames it to `path`.
+    pub(super) fn write_atomically(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+        // Distinguishes temporary files written concurrently by this process
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+        let mut temp_file_name = OsString::from(".");
+        temp_file_name.push(file_name);
+        temp_file_name.push(format!(
+            ".{}.{}.tmp",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let temp_path = path.with_file_name(temp_file_name);
+
+        let result = write_temp_file(&temp_path, path, contents)
+            .and_then(|_| std::fs::rename(&temp_path, path));
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result
+    }
+
+    /// Writes `contents` to a new file at `temp_path`, which is going to replace `path`.
+    ///
+    /// On Unix, the file takes the permissions of the file it replaces. A new file is only
+    /// accessible by its owner, since cached credentials and tokens are written through here.
+    fn write_temp_file(temp_path: &Path, path: &Path, contents: &[u8]) -> std::io::Result<()> {
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(temp_path)?;
+        file.write_all(contents)?;
+        #[cfg(unix)]
+        if let Ok(metadata) = std::fs::metadata(path) {
+            file.set_permissions(metadata.permissions())?;
+        }
+        #[cfg(not(unix))]
+        let _ = path;
+        Ok(())
+    }
 }
 
 /// Environment variable abstraction
@@ -325,4 +377,47 @@ mod test {
         let result = fs.read_to_end(&path).await.expect("success");
         assert_eq!(b"test", &result[..]);
     }
+
+    #[tokio::test]
+    async fn fs_write_replaces_files_atomically() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("test-file");
+
+        let fs = Fs::real();
+        fs.write(&path, b"first").await.expect("success");
+        fs.write(&path, b"second").await.expect("success");
+
+        let result = fs.read_to_end(&path).await.expect("success");
+        assert_eq!(b"second", &result[..]);
+        let files: Vec<_> = std::fs::read_dir(temp.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(vec![std::ffi::OsString::from("test-file")], files);
+
+        fs.write(temp.path().join("missing-dir").join("test-file"), b"test")
+            .await
+            .expect_err("the directory doesn't exist");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn fs_write_restricts_permissions_of_new_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempfile::tempdir().unwrap();
+        let mode =
+            |path: &std::path::Path| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        let fs = Fs::real();
+
+        let new_file = temp.path().join("new-file");
+        fs.write(&new_file, b"secret").await.expect("success");
+        assert_eq!(0o600, mode(&new_file));
+
+        let existing_file = temp.path().join("existing-file");
+        std::fs::write(&existing_file, b"first").unwrap();
+        std::fs::set_permissions(&existing_file, std::fs::Permissions::from_mode(0o640)).unwrap();
+        fs.write(&existing_file, b"second").await.expect("success");
+        assert_eq!(0o640, mode(&existing_file));
+    }
 }
@@ -11,6 +11,7 @@
 
 use crate::app_name::AppName;
 use crate::docs_for;
+use crate::endpoint_config::AccountIdEndpointMode;
 use crate::origin::Origin;
 use crate::region::Region;
 use crate::service_config::LoadServiceConfig;
@@ -33,6 +34,9 @@ pub use aws_smithy_types::timeout::TimeoutConfig;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+mod report;
+pub use report::{SdkConfigDiff, SdkConfigReport};
+
 /// Unified docstrings to keep crates in sync. Not intended for public use
 pub mod unified_docs {
     /// A macro that generates docs for selected fields of `SdkConfig`.
@@ -50,6 +54,15 @@ If no dual-stack endpoint is available the request MAY return an error.
 
 **Note**: Some services do not offer dual-stack as a configurable parameter (e.g. Code Catalyst). For
 these services, this setting has no effect"
+        };
+        (account_id_endpoint_mode) => {
+"Controls whether requests are routed to account-based endpoints using the account ID of the
+credentials. Defaults to `preferred`.
+
+When `required`, requests fail if the credentials don't include an account ID.
+
+**Only some services support account-based endpoints.** For services that don't,
+this setting does nothing."
         };
         (time_source) => {
 "The time source use to use for this client.
@@ -90,6 +103,7 @@ pub struct SdkConfig {
     http_client: Option<SharedHttpClient>,
     use_fips: Option<bool>,
     use_dual_stack: Option<bool>,
+    account_id_endpoint_mode: Option<AccountIdEndpointMode>,
     behavior_version: Option<BehaviorVersion>,
     service_config: Option<Arc<dyn LoadServiceConfig>>,
     config_origins: HashMap<&'static str, Origin>,
@@ -120,6 +134,7 @@ pub struct Builder {
     http_client: Option<SharedHttpClient>,
     use_fips: Option<bool>,
     use_dual_stack: Option<bool>,
+    account_id_endpoint_mode: Option<AccountIdEndpointMode>,
     behavior_version: Option<BehaviorVersion>,
     service_config: Option<Arc<dyn LoadServiceConfig>>,
     config_origins: HashMap<&'static str, Origin>,
@@ -665,6 +680,21 @@ impl Builder {
         self
     }
 
+    #[doc = docs_for!(account_id_endpoint_mode)]
+    pub fn account_id_endpoint_mode(mut self, mode: AccountIdEndpointMode) -> Self {
+        self.set_account_id_endpoint_mode(Some(mode));
+        self
+    }
+
+    #[doc = docs_for!(account_id_endpoint_mode)]
+    pub fn set_account_id_endpoint_mode(
+        &mut self,
+        mode: Option<AccountIdEndpointMode>,
+    ) -> &mut Self {
+        self.account_id_endpoint_mode = mode;
+        self
+    }
+
     #[doc = docs_for!(time_source)]
     pub fn time_source(mut self, time_source: impl TimeSource + 'static) -> Self {
         self.set_time_source(Some(SharedTimeSource::new(time_source)));
@@ -768,6 +798,7 @@ impl Builder {
             http_client: self.http_client,
             use_fips: self.use_fips,
             use_dual_stack: self.use_dual_stack,
+            account_id_endpoint_mode: self.account_id_endpoint_mode,
             time_source: self.time_source,
             behavior_version: self.behavior_version,
             stalled_stream_protection_config: self.stalled_stream_protection_config,
@@ -918,6 +949,11 @@ impl SdkConfig {
         self.use_dual_stack
     }
 
+    /// Account ID based endpoint routing mode
+    pub fn account_id_endpoint_mode(&self) -> Option<AccountIdEndpointMode> {
+        self.account_id_endpoint_mode
+    }
+
     /// When true, request compression is disabled.
     pub fn disable_request_compression(&self) -> Option<bool> {
         self.disable_request_compression
@@ -978,6 +1014,24 @@ impl SdkConfig {
             .unwrap_or_default()
     }
 
+    /// Returns a report of the settings of this config, for debugging.
+    ///
+    /// The report lists each setting, and where it was loaded from when that's known. It never
+    /// includes credentials or tokens, so it's safe to log. Use [`SdkConfigReport::diff`] to
+    /// compare the reports of two configs.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use aws_types::region::Region;
+    /// use aws_types::SdkConfig;
+    ///
+    /// let config = SdkConfig::builder().region(Region::new("us-east-1")).build();
+    /// println!("{}", config.debug_report());
+    /// ```
+    pub fn debug_report(&self) -> SdkConfigReport {
+        SdkConfigReport::new(self)
+    }
+
     /// Convert this [`SdkConfig`] back to a builder to enable modification
     pub fn into_builder(self) -> Builder {
         Builder {
@@ -994,6 +1048,7 @@ impl SdkConfig {
             http_client: self.http_client,
             use_fips: self.use_fips,
             use_dual_stack: self.use_dual_stack,
+            account_id_endpoint_mode: self.account_id_endpoint_mode,
             behavior_version: self.behavior_version,
             stalled_stream_protection_config: self.stalled_stream_protection_config,
             service_config: self.service_config,
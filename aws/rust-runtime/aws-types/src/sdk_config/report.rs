@@ -0,0 +1,257 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::sdk_config::SdkConfig;
+use aws_smithy_runtime_api::client::http::HttpClient;
+use std::fmt;
+
+const UNSET: &str = "<unset>";
+
+/// A setting of an [`SdkConfig`] and where it was loaded from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Setting {
+    name: &'static str,
+    value: Option<String>,
+    origin: Option<String>,
+}
+
+impl fmt::Display for Setting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}",
+            self.name,
+            self.value.as_deref().unwrap_or(UNSET)
+        )?;
+        if let Some(origin) = &self.origin {
+            write!(f, " (from {origin})")?;
+        }
+        Ok(())
+    }
+}
+
+/// A report of the settings of an [`SdkConfig`], for debugging.
+///
+/// Returned by [`SdkConfig::debug_report`]. Its [`Display`](fmt::Display) implementation lists
+/// one setting per line, along with where the setting was loaded from when that's known:
+///
+/// ```text
+/// region: us-west-2
+/// endpoint_url: http://localhost:4566 (from shared environment variable)
+/// use_fips: <unset>
+/// ...
+/// ```
+///
+/// Credentials and tokens are never included. Identity providers are listed by their `Debug`
+/// representation, which redacts secrets, and HTTP clients by the name and version of their
+/// connector.
+///
+/// Two reports can be compared with [`SdkConfigReport::diff`] to find out why clients created
+/// from different configs behave differently.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SdkConfigReport {
+    settings: Vec<Setting>,
+}
+
+impl SdkConfigReport {
+    pub(super) fn new(config: &SdkConfig) -> Self {
+        fn debug<T: fmt::Debug>(value: Option<T>) -> Option<String> {
+            value.map(|value| format!("{value:?}"))
+        }
+        fn display<T: fmt::Display>(value: Option<T>) -> Option<String> {
+            value.map(|value| value.to_string())
+        }
+        fn configured<T>(value: Option<T>) -> Option<String> {
+            value.map(|_| "configured".to_owned())
+        }
+
+        let http_client = config.http_client().map(|http_client| {
+            http_client
+                .connector_metadata()
+                .map(|metadata| metadata.to_string())
+                .unwrap_or_else(|| "configured".to_owned())
+        });
+        let settings = [
+            ("app_name", display(config.app_name())),
+            ("region", display(config.region())),
+            ("endpoint_url", display(config.endpoint_url())),
+            ("use_fips", display(config.use_fips())),
+            ("use_dual_stack", display(config.use_dual_stack())),
+            (
+                "account_id_endpoint_mode",
+                display(config.account_id_endpoint_mode()),
+            ),
+            ("retry_config", debug(config.retry_config())),
+            ("timeout_config", debug(config.timeout_config())),
+            (
+                "stalled_stream_protection",
+                debug(config.stalled_stream_protection()),
+            ),
+            ("credentials_provider", debug(config.credentials_provider())),
+            ("token_provider", debug(config.token_provider())),
+            ("identity_cache", configured(config.identity_cache())),
+            ("http_client", http_client),
+            ("sleep_impl", configured(config.sleep_impl())),
+            ("time_source", configured(config.time_source())),
+            ("behavior_version", debug(config.behavior_version())),
+            (
+                "disable_request_compression",
+                display(config.disable_request_compression()),
+            ),
+            (
+                "request_min_compression_size_bytes",
+                display(config.request_min_compression_size_bytes()),
+            ),
+            (
+                "request_checksum_calculation",
+                debug(config.request_checksum_calculation()),
+            ),
+            (
+                "response_checksum_validation",
+                debug(config.response_checksum_validation()),
+            ),
+            ("service_config", configured(config.service_config())),
+        ]
+        .into_iter()
+        .map(|(name, value)| Setting {
+            name,
+            value,
+            origin: config
+                .config_origins
+                .get(name)
+                .map(|origin| origin.to_string()),
+        })
+        .collect();
+        Self { settings }
+    }
+
+    /// Returns the settings that differ between this report and `other`.
+    pub fn diff(&self, other: &SdkConfigReport) -> SdkConfigDiff {
+        let changes = self
+            .settings
+            .iter()
+            .zip(&other.settings)
+            .filter(|(before, after)| before != after)
+            .map(|(before, after)| (before.clone(), after.clone()))
+            .collect();
+        SdkConfigDiff { changes }
+    }
+}
+
+impl fmt::Display for SdkConfigReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for setting in &self.settings {
+            writeln!(f, "{setting}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The settings that differ between two [`SdkConfigReport`]s.
+///
+/// Returned by [`SdkConfigReport::diff`]. Its [`Display`](fmt::Display) implementation lists the
+/// differing settings of both reports, one per line:
+///
+/// ```text
+/// - region: us-west-2
+/// + region: us-east-1 (from shared profile file)
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SdkConfigDiff {
+    changes: Vec<(Setting, Setting)>,
+}
+
+impl SdkConfigDiff {
+    /// Returns true if the reports don't differ.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the names of the settings that differ.
+    pub fn settings(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.changes.iter().map(|(before, _)| before.name)
+    }
+}
+
+impl fmt::Display for SdkConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (before, after) in &self.changes {
+            writeln!(f, "- {before}")?;
+            writeln!(f, "+ {after}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::origin::Origin;
+    use crate::region::Region;
+    use crate::sdk_config::SdkConfig;
+    use aws_credential_types::provider::SharedCredentialsProvider;
+    use aws_credential_types::Credentials;
+
+    fn config() -> SdkConfig {
+        SdkConfig::builder()
+            .region(Region::new("us-west-2"))
+            .credentials_provider(SharedCredentialsProvider::new(Credentials::new(
+                "AKIDTEST",
+                "SECRET_KEY",
+                Some("SESSION_TOKEN".to_owned()),
+                None,
+                "test",
+            )))
+            .build()
+    }
+
+    #[test]
+    fn reports_settings_without_secrets() {
+        let mut builder = config().into_builder();
+        builder.insert_origin("endpoint_url", Origin::shared_environment_variable());
+        let report = builder
+            .endpoint_url("http://localhost:4566")
+            .build()
+            .debug_report()
+            .to_string();
+
+        assert!(report.contains("\nregion: us-west-2\n"), "{report}");
+        assert!(
+            report.contains(
+                "\nendpoint_url: http://localhost:4566 (from shared environment variable)\n"
+            ),
+            "{report}"
+        );
+        assert!(report.contains("\nuse_fips: <unset>\n"), "{report}");
+        assert!(report.contains("credentials_provider: "), "{report}");
+        assert!(!report.contains("SECRET_KEY"), "{report}");
+        assert!(!report.contains("SESSION_TOKEN"), "{report}");
+    }
+
+    #[test]
+    fn diff_reports() {
+        let config = config();
+        let before = config.debug_report();
+        assert!(before.diff(&before).is_empty());
+
+        let after = config
+            .into_builder()
+            .region(Region::new("us-east-1"))
+            .use_fips(true)
+            .build()
+            .debug_report();
+        let diff = before.diff(&after);
+        assert_eq!(
+            vec!["region", "use_fips"],
+            diff.settings().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "- region: us-west-2\n\
+             + region: us-east-1\n\
+             - use_fips: <unset>\n\
+             + use_fips: true\n",
+            diff.to_string()
+        );
+    }
+}
@@ -0,0 +1,196 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rustsdk
+
+import software.amazon.smithy.model.node.Node
+import software.amazon.smithy.rulesengine.aws.language.functions.AwsBuiltIns
+import software.amazon.smithy.rulesengine.language.syntax.parameters.Parameter
+import software.amazon.smithy.rust.codegen.client.smithy.ClientCodegenContext
+import software.amazon.smithy.rust.codegen.client.smithy.configReexport
+import software.amazon.smithy.rust.codegen.client.smithy.customize.ClientCodegenDecorator
+import software.amazon.smithy.rust.codegen.client.smithy.endpoint.EndpointCustomization
+import software.amazon.smithy.rust.codegen.client.smithy.generators.config.ConfigCustomization
+import software.amazon.smithy.rust.codegen.client.smithy.generators.config.ServiceConfig
+import software.amazon.smithy.rust.codegen.core.rustlang.Writable
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
+import software.amazon.smithy.rust.codegen.core.rustlang.writable
+import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType.Companion.preludeScope
+import software.amazon.smithy.rust.codegen.core.smithy.customize.AdHocCustomization
+import software.amazon.smithy.rust.codegen.core.smithy.customize.NamedCustomization
+import software.amazon.smithy.rust.codegen.core.smithy.customize.adhocCustomization
+import software.amazon.smithy.rust.codegen.core.util.dq
+import software.amazon.smithy.rust.codegen.core.util.extendIf
+
+/**
+ * Support for account ID based endpoint routing
+ *
+ * - The `AWS::Auth::AccountId` builtIn is loaded from the `AccountId` property of the resolved identity, so that the
+ *   endpoint can be resolved again once the credentials are known.
+ * - The `AWS::Auth::AccountIdEndpointMode` builtIn is configured with the `AccountIdEndpointMode` enum from `aws-types`,
+ *   which is also copied from the `SdkConfig`.
+ */
+class AccountIdEndpointDecorator : ClientCodegenDecorator {
+    override val name: String = "AccountIdEndpoint"
+    override val order: Byte = 0
+
+    private fun supportsAccountIdEndpointMode(codegenContext: ClientCodegenContext) =
+        codegenContext.getBuiltIn(AwsBuiltIns.ACCOUNT_ID_ENDPOINT_MODE) != null
+
+    override fun configCustomizations(
+        codegenContext: ClientCodegenContext,
+        baseCustomizations: List<ConfigCustomization>,
+    ): List<ConfigCustomization> =
+        baseCustomizations.extendIf(supportsAccountIdEndpointMode(codegenContext)) {
+            AccountIdEndpointModeConfigCustomization(codegenContext)
+        }
+
+    /**
+     * Copy the `account_id_endpoint_mode` value from the `SdkConfig` to the client config
+     */
+    override fun extraSections(codegenContext: ClientCodegenContext): List<AdHocCustomization> =
+        if (!supportsAccountIdEndpointMode(codegenContext)) {
+            listOf()
+        } else {
+            listOf(
+                adhocCustomization<SdkConfigSection.CopySdkConfigToClientConfig> { section ->
+                    rust(
+                        """
+                        ${section.serviceConfigBuilder}.set_account_id_endpoint_mode(${section.sdkConfig}.account_id_endpoint_mode());
+                        """,
+                    )
+                },
+            )
+        }
+
+    override fun endpointCustomizations(codegenContext: ClientCodegenContext): List<EndpointCustomization> {
+        val rc = codegenContext.runtimeConfig
+        val accountIdEndpointMode = AwsRuntimeType.awsTypes(rc).resolve("endpoint_config::AccountIdEndpointMode")
+        return listOf(
+            object : EndpointCustomization {
+                override fun loadBuiltInFromServiceConfig(
+                    parameter: Parameter,
+                    configRef: String,
+                ): Writable? =
+                    when (parameter.builtIn) {
+                        AwsBuiltIns.ACCOUNT_ID_ENDPOINT_MODE.builtIn ->
+                            writable {
+                                rustTemplate(
+                                    "$configRef.load::<#{AccountIdEndpointMode}>().map(|mode| mode.to_string())",
+                                    "AccountIdEndpointMode" to accountIdEndpointMode,
+                                )
+                            }
+
+                        else -> null
+                    }
+
+                override fun setBuiltInOnServiceConfig(
+                    name: String,
+                    value: Node,
+                    configBuilderRef: String,
+                ): Writable? {
+                    if (name != AwsBuiltIns.ACCOUNT_ID_ENDPOINT_MODE.builtIn.get()) {
+                        return null
+                    }
+                    val mode = value.expectStringNode().value
+                    return writable {
+                        rustTemplate(
+                            """
+                            let $configBuilderRef = $configBuilderRef.account_id_endpoint_mode(
+                                ${mode.dq()}.parse::<#{AccountIdEndpointMode}>().expect("valid account ID endpoint mode")
+                            );
+                            """,
+                            "AccountIdEndpointMode" to accountIdEndpointMode,
+                        )
+                    }
+                }
+
+                override fun loadBuiltInFromIdentity(
+                    parameter: Parameter,
+                    identityRef: String,
+                ): Writable? =
+                    when (parameter.builtIn) {
+                        AwsBuiltIns.ACCOUNT_ID.builtIn ->
+                            writable {
+                                rustTemplate(
+                                    "$identityRef.property::<#{AccountId}>().map(|id| id.as_str().to_string())",
+                                    "AccountId" to AwsRuntimeType.awsCredentialTypes(rc).resolve("attributes::AccountId"),
+                                )
+                            }
+
+                        else -> null
+                    }
+            },
+        )
+    }
+}
+
+/**
+ * Add an `account_id_endpoint_mode` setting to service config
+ */
+class AccountIdEndpointModeConfigCustomization(codegenContext: ClientCodegenContext) :
+    NamedCustomization<ServiceConfig>() {
+    private val codegenScope =
+        arrayOf(
+            *preludeScope,
+            "AccountIdEndpointMode" to
+                configReexport(
+                    AwsRuntimeType.awsTypes(codegenContext.runtimeConfig)
+                        .resolve("endpoint_config::AccountIdEndpointMode"),
+                ),
+        )
+
+    override fun section(section: ServiceConfig): Writable =
+        when (section) {
+            is ServiceConfig.ConfigImpl ->
+                writable {
+                    rustTemplate(
+                        """
+                        /// Return the [`AccountIdEndpointMode`](#{AccountIdEndpointMode}) contained in this config, if any.
+                        pub fn account_id_endpoint_mode(&self) -> #{Option}<&#{AccountIdEndpointMode}> {
+                            self.config.load::<#{AccountIdEndpointMode}>()
+                        }
+                        """,
+                        *codegenScope,
+                    )
+                }
+
+            is ServiceConfig.BuilderImpl ->
+                writable {
+                    rustTemplate(
+                        """
+                        /// Set the [`AccountIdEndpointMode`](#{AccountIdEndpointMode}) to control whether requests
+                        /// are routed to account-based endpoints using the account ID of the credentials.
+                        pub fn account_id_endpoint_mode(mut self, account_id_endpoint_mode: #{AccountIdEndpointMode}) -> Self {
+                            self.set_account_id_endpoint_mode(#{Some}(account_id_endpoint_mode));
+                            self
+                        }
+
+                        /// Set the [`AccountIdEndpointMode`](#{AccountIdEndpointMode}) to control whether requests
+                        /// are routed to account-based endpoints using the account ID of the credentials.
+                        pub fn set_account_id_endpoint_mode(
+                            &mut self,
+                            account_id_endpoint_mode: #{Option}<#{AccountIdEndpointMode}>
+                        ) -> &mut Self {
+                            self.config.store_or_unset(account_id_endpoint_mode);
+                            self
+                        }
+                        """,
+                        *codegenScope,
+                    )
+                }
+
+            is ServiceConfig.BuilderFromConfigBag ->
+                writable {
+                    rustTemplate(
+                        "${section.builder}.set_account_id_endpoint_mode(${section.configBag}.load::<#{AccountIdEndpointMode}>().cloned());",
+                        *codegenScope,
+                    )
+                }
+
+            else -> emptySection
+        }
+}
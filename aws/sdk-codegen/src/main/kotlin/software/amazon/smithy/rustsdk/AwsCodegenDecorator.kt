@@ -55,6 +55,7 @@ val DECORATORS: List<ClientCodegenDecorator> =
             AwsCrateDocsDecorator(),
             AwsEndpointsStdLib(),
             *PromotedBuiltInsDecorators,
+            AccountIdEndpointDecorator(),
             GenericSmithySdkConfigSettings(),
             OperationInputTestDecorator(),
             AwsRequestIdDecorator(),
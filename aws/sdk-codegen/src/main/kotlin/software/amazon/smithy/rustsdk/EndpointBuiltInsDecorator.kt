@@ -129,7 +129,7 @@ fun Model.sdkConfigSetter(
  * Create a client codegen decorator that creates bindings for a builtIn parameter. Optionally, you can provide
  * [clientParam.Builder] which allows control over the config parameter that will be generated. You can also opt
  * to exclude including the extra sections that set the builtIn value on the SdkConfig. This is useful for builtIns
- * that are only minimally supported, like accountId and accountIdEndpointMode.
+ * that are only minimally supported, like accountId.
  */
 fun decoratorForBuiltIn(
     builtIn: Parameter,
@@ -247,6 +247,5 @@ val PromotedBuiltInsDecorators =
                 .type(RuntimeType.String.toSymbol())
                 .setterDocs(endpointUrlDocs),
         ),
-        decoratorForBuiltIn(AwsBuiltIns.ACCOUNT_ID_ENDPOINT_MODE, null, false),
         decoratorForBuiltIn(AwsBuiltIns.ACCOUNT_ID, null, false),
     ).toTypedArray()
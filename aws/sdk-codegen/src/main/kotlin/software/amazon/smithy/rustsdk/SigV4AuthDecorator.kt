@@ -115,6 +115,10 @@ class SigV4AuthDecorator : ConditionalDecorator(
                 codegenContext: ClientCodegenContext,
                 rustCrate: RustCrate,
             ) {
+                // Add optional feature for recording how requests were signed, for debugging signature mismatches
+                rustCrate.mergeFeature(
+                    Feature("signing-debug-info", false, listOf("aws-runtime/signing-debug-info")),
+                )
                 if (codegenContext.usesSigV4a()) {
                     // Add optional feature for SigV4a support
                     rustCrate.mergeFeature(Feature("sigv4a", true, listOf("aws-runtime/sigv4a")))
@@ -192,6 +196,8 @@ private class AuthServiceRuntimePluginCustomization(private val codegenContext:
         arrayOf(
             "SigV4AuthScheme" to awsRuntime.resolve("auth::sigv4::SigV4AuthScheme"),
             "SigV4aAuthScheme" to awsRuntime.resolve("auth::sigv4a::SigV4aAuthScheme"),
+            "SigningDebugInfoInterceptor" to
+                awsRuntime.resolve("auth::signing_debug_info::SigningDebugInfoInterceptor"),
             "SharedAuthScheme" to
                 RuntimeType.smithyRuntimeApiClient(runtimeConfig)
                     .resolve("client::auth::SharedAuthScheme"),
@@ -215,6 +221,12 @@ private class AuthServiceRuntimePluginCustomization(private val codegenContext:
                         rustTemplate("#{SharedAuthScheme}::new(#{SigV4AuthScheme}::new())", *codegenScope)
                     }
 
+                    featureGateBlock("signing-debug-info") {
+                        section.registerInterceptor(this) {
+                            rustTemplate("#{SigningDebugInfoInterceptor}::new()", *codegenScope)
+                        }
+                    }
+
                     if (codegenContext.usesSigV4a()) {
                         featureGateBlock("sigv4a") {
                             section.registerAuthScheme(this) {
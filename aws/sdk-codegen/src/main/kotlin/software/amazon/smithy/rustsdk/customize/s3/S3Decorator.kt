@@ -66,6 +66,24 @@ class S3Decorator : ClientCodegenDecorator {
             ShapeId.from("com.amazonaws.s3#CopyObject"),
         )
 
+    override fun additionalErrorCodes(
+        codegenContext: ClientCodegenContext,
+        baseCodes: List<String>,
+    ): List<String> =
+        baseCodes +
+            // Documented in https://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html but not modeled
+            listOf(
+                "AccessDenied",
+                "ExpiredToken",
+                "InternalError",
+                "InvalidAccessKeyId",
+                "PreconditionFailed",
+                "RequestTimeout",
+                "ServiceUnavailable",
+                "SignatureDoesNotMatch",
+                "SlowDown",
+            )
+
     override fun protocols(
         serviceId: ShapeId,
         currentProtocols: ProtocolMap<OperationGenerator, ClientCodegenContext>,
@@ -0,0 +1,176 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rustsdk
+
+import org.junit.jupiter.api.Test
+import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
+import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
+import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
+import software.amazon.smithy.rust.codegen.core.testutil.integrationTest
+import software.amazon.smithy.rust.codegen.core.testutil.tokioTest
+
+class AccountIdEndpointDecoratorTest {
+    // Routes to an account-based endpoint when the credentials have an account ID, unless the
+    // account ID endpoint mode is `disabled`. The `required` mode fails without an account ID.
+    private val model =
+        """
+        namespace test
+
+        use aws.api#service
+        use aws.auth#sigv4
+        use aws.protocols#restJson1
+        use smithy.rules#endpointRuleSet
+
+        @service(sdkId: "dontcare")
+        @restJson1
+        @sigv4(name: "dontcare")
+        @auth([sigv4])
+        @suppress(["RuleSetAwsBuiltIn.AWS::Auth::AccountId", "RuleSetAwsBuiltIn.AWS::Auth::AccountIdEndpointMode"])
+        @endpointRuleSet({
+            "version": "1.0"
+            "parameters": {
+                "region": { "required": false, "type": "String", "builtIn": "AWS::Region" },
+                "accountId": { "required": false, "type": "String", "builtIn": "AWS::Auth::AccountId" },
+                "accountIdEndpointMode": { "required": false, "type": "String", "builtIn": "AWS::Auth::AccountIdEndpointMode" },
+            }
+            "rules": [
+                {
+                    "type": "error"
+                    "conditions": [
+                        {"fn": "isSet", "argv": [{"ref": "accountIdEndpointMode"}]},
+                        {"fn": "stringEquals", "argv": [{"ref": "accountIdEndpointMode"}, "required"]},
+                        {"fn": "not", "argv": [{"fn": "isSet", "argv": [{"ref": "accountId"}]}]}
+                    ],
+                    "error": "AccountIdEndpointMode is required but no AccountID was provided"
+                },
+                {
+                    "type": "endpoint"
+                    "conditions": [
+                        {"fn": "isSet", "argv": [{"ref": "accountId"}]},
+                        {"fn": "isSet", "argv": [{"ref": "accountIdEndpointMode"}]},
+                        {"fn": "stringEquals", "argv": [{"ref": "accountIdEndpointMode"}, "disabled"]}
+                    ],
+                    "endpoint": { "url": "https://regional.example.com" }
+                },
+                {
+                    "type": "endpoint"
+                    "conditions": [
+                        {"fn": "isSet", "argv": [{"ref": "accountId"}]}
+                    ],
+                    "endpoint": { "url": "https://{accountId}.example.com" }
+                },
+                {
+                    "type": "endpoint"
+                    "conditions": []
+                    "endpoint": { "url": "https://regional.example.com" }
+                }
+            ]
+        })
+        service TestService {
+            version: "2023-01-01",
+            operations: [SomeOperation]
+        }
+
+        @http(uri: "/SomeOperation", method: "GET")
+        operation SomeOperation {
+        }
+        """.asSmithyModel()
+
+    @Test
+    fun accountIdRoutesToAccountBasedEndpoints() {
+        awsSdkIntegrationTest(model) { codegenContext, rustCrate ->
+            val rc = codegenContext.runtimeConfig
+            val moduleName = codegenContext.moduleUseName()
+            val codegenScope =
+                arrayOf(
+                    "capture_request" to RuntimeType.captureRequest(rc),
+                    "Credentials" to AwsRuntimeType.awsCredentialTypes(rc).resolve("Credentials"),
+                    "AccountIdEndpointMode" to
+                        AwsRuntimeType.awsTypes(rc).resolve("endpoint_config::AccountIdEndpointMode"),
+                    "DisplayErrorContext" to RuntimeType.smithyTypes(rc).resolve("error::display::DisplayErrorContext"),
+                    "Region" to AwsRuntimeType.awsTypes(rc).resolve("region::Region"),
+                )
+            rustCrate.integrationTest("account_id_endpoint") {
+                rustTemplate(
+                    """
+                    fn credentials(account_id: Option<&str>) -> #{Credentials} {
+                        #{Credentials}::builder()
+                            .access_key_id("ANOTREAL")
+                            .secret_access_key("notrealrnrELgWzOk3IfjzDKtFBhDby")
+                            .set_account_id(account_id.map(Into::into))
+                            .provider_name("test")
+                            .build()
+                    }
+
+                    async fn request_uri(
+                        account_id: Option<&str>,
+                        mode: Option<#{AccountIdEndpointMode}>,
+                    ) -> Result<String, String> {
+                        let (http_client, rcvr) = #{capture_request}(None);
+                        let mut conf = $moduleName::Config::builder()
+                            .http_client(http_client)
+                            .region(#{Region}::new("us-west-2"))
+                            .credentials_provider(credentials(account_id));
+                        conf.set_account_id_endpoint_mode(mode);
+                        let client = $moduleName::Client::from_conf(conf.build());
+                        match client.some_operation().send().await {
+                            Ok(_) => Ok(rcvr.expect_request().uri().to_string()),
+                            Err(err) => Err(format!("{}", #{DisplayErrorContext}(&err))),
+                        }
+                    }
+                    """,
+                    *codegenScope,
+                )
+
+                tokioTest("account_id_is_used_when_preferred") {
+                    rustTemplate(
+                        """
+                        assert_eq!(
+                            "https://111122223333.example.com/SomeOperation",
+                            request_uri(Some("111122223333"), None).await.unwrap()
+                        );
+                        assert_eq!(
+                            "https://111122223333.example.com/SomeOperation",
+                            request_uri(Some("111122223333"), Some(#{AccountIdEndpointMode}::Preferred)).await.unwrap()
+                        );
+                        assert_eq!(
+                            "https://regional.example.com/SomeOperation",
+                            request_uri(None, Some(#{AccountIdEndpointMode}::Preferred)).await.unwrap()
+                        );
+                        """,
+                        *codegenScope,
+                    )
+                }
+
+                tokioTest("account_id_is_ignored_when_disabled") {
+                    rustTemplate(
+                        """
+                        assert_eq!(
+                            "https://regional.example.com/SomeOperation",
+                            request_uri(Some("111122223333"), Some(#{AccountIdEndpointMode}::Disabled)).await.unwrap()
+                        );
+                        """,
+                        *codegenScope,
+                    )
+                }
+
+                tokioTest("account_id_is_required_when_required") {
+                    rustTemplate(
+                        """
+                        assert_eq!(
+                            "https://111122223333.example.com/SomeOperation",
+                            request_uri(Some("111122223333"), Some(#{AccountIdEndpointMode}::Required)).await.unwrap()
+                        );
+                        let err = request_uri(None, Some(#{AccountIdEndpointMode}::Required)).await.unwrap_err();
+                        assert!(err.contains("AccountIdEndpointMode is required but no AccountID was provided"), "{err}");
+                        """,
+                        *codegenScope,
+                    )
+                }
+            }
+        }
+    }
+}
@@ -37,7 +37,7 @@ class HttpRequestCompressionDecoratorTest {
             })
             service TestService {
                 version: "2023-01-01",
-                operations: [SomeOperation, SomeStreamingOperation, NotACompressibleOperation]
+                operations: [SomeOperation, SomeStreamingOperation, SomeBrotliOperation, NotACompressibleOperation]
             }
 
             @streaming
@@ -81,6 +81,14 @@ class HttpRequestCompressionDecoratorTest {
             @output
             structure SomeStreamingOutput {}
 
+            @http(uri: "/SomeBrotliOperation", method: "POST")
+            @optionalAuth
+            @requestCompression(encodings: ["unsupported", "br", "gzip"])
+            operation SomeBrotliOperation {
+                input: SomeInput,
+                output: SomeOutput
+            }
+
             @http(uri: "/NotACompressibleOperation", method: "PUT")
             @optionalAuth
             operation NotACompressibleOperation {
@@ -227,6 +235,40 @@ class HttpRequestCompressionDecoratorTest {
                         assert_eq!(COMPRESSED_OUTPUT, compressed_body.as_slice());
                     }
 
+                    ##[#{tokio}::test]
+                    async fn test_request_compression_uses_the_first_supported_encoding() {
+                        let (http_client, rx) = ::aws_smithy_runtime::client::http::test_util::capture_request(None);
+                        let config = $moduleName::Config::builder()
+                            .region(Region::from_static("doesntmatter"))
+                            .with_test_defaults()
+                            .http_client(http_client)
+                            .disable_request_compression(false)
+                            .request_min_compression_size_bytes(128)
+                            .build();
+
+                        let client = $moduleName::Client::from_conf(config);
+                        let _ = client
+                            .some_brotli_operation()
+                            .body(Blob::new(UNCOMPRESSED_INPUT))
+                            .send()
+                            .await;
+                        let request = rx.expect_request();
+                        // Check that the content-encoding header is set to "br"
+                        assert_eq!(
+                            Some("br"),
+                            request.headers().get(#{http}::header::CONTENT_ENCODING)
+                        );
+
+                        let compressed_body = ByteStream::from(request.into_body())
+                            .collect()
+                            .await
+                            .unwrap()
+                            .to_vec();
+                        // Assert input body was compressed
+                        assert_ne!(UNCOMPRESSED_INPUT, compressed_body.as_slice());
+                        assert!(compressed_body.len() < UNCOMPRESSED_INPUT.len());
+                    }
+
                     ##[#{tokio}::test]
                     async fn test_request_min_size_body_under_minimum() {
                         let (http_client, rx) = ::aws_smithy_runtime::client::http::test_util::capture_request(None);
@@ -7,8 +7,13 @@ use aws_credential_types::provider::SharedCredentialsProvider;
 use aws_sdk_s3::config::{Credentials, Region};
 use aws_sdk_s3::presigning::PresigningConfig;
 use aws_sdk_s3::{Client, Config};
-use aws_smithy_runtime::client::http::test_util::capture_request;
+use aws_smithy_runtime::client::http::test_util::{
+    capture_request, ReplayEvent, StaticReplayClient,
+};
+use aws_smithy_types::body::SdkBody;
 use http::HeaderValue;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
 #[tokio::test]
@@ -80,6 +85,47 @@ async fn customized_presigning() {
     assert_eq!(req.uri(), expect);
 }
 
+#[tokio::test]
+async fn streaming_response_body_is_mappable() {
+    let http_client = StaticReplayClient::new(vec![ReplayEvent::new(
+        http::Request::builder()
+            .uri("https://foo.s3.us-east-1.amazonaws.com/bar?x-id=GetObject")
+            .body(SdkBody::empty())
+            .unwrap(),
+        http::Response::builder()
+            .status(200)
+            .body(SdkBody::from("hello world"))
+            .unwrap(),
+    )]);
+    let config = Config::builder()
+        .credentials_provider(Credentials::for_tests_with_session_token())
+        .region(Region::new("us-east-1"))
+        .http_client(http_client)
+        .build();
+    let client = Client::from_conf(config);
+
+    let tapped = Arc::new(AtomicBool::new(false));
+    let output = client
+        .get_object()
+        .bucket("foo")
+        .key("bar")
+        .customize()
+        .map_response_body({
+            let tapped = tapped.clone();
+            move |body| {
+                tapped.store(true, Ordering::Relaxed);
+                body
+            }
+        })
+        .send()
+        .await
+        .expect("success");
+
+    assert!(tapped.load(Ordering::Relaxed));
+    let body = output.body.collect().await.unwrap().into_bytes();
+    assert_eq!("hello world", std::str::from_utf8(&body).unwrap());
+}
+
 fn assert_send<T: Send>(t: T) -> T {
     t
 }
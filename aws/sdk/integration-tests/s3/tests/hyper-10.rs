@@ -11,7 +11,8 @@ use aws_smithy_runtime_api::client::behavior_version::BehaviorVersion;
 async fn hyper_10_end_to_end() {
     let http_client = aws_smithy_experimental::hyper_1_0::HyperClientBuilder::default()
         .crypto_mode(CryptoMode::Ring)
-        .build_https();
+        .build_https()
+        .unwrap();
     let conf = aws_config::defaults(BehaviorVersion::latest())
         .http_client(http_client)
         .load()
@@ -84,6 +84,13 @@ data class ClientRustSettings(
  * [includeFluentClient]: Generate a `client` module in the generated SDK (currently the AWS SDK sets this to `false`
  *   and generates its own client)
  * [addMessageToErrors]: Adds a `message` field automatically to all error shapes
+ * [compatManifest]: Path to a manifest of public names of the previous release that have been renamed. When set, a
+ *   `compat` module with deprecated aliases for those names is generated
+ * [convertManifest]: Path to a manifest of shapes that are structurally identical to types of a crate generated from
+ *   another version of the service model. When set, a `convert` module with `From` implementations from those types
+ *   is generated behind the `convert` feature
+ * [generateApiReport]: Generate an `api.json` and an `API.md` report of the public items of the crate and their
+ *   stability, to check for breaking changes between generations with `tools/ci-build/api-diff`
  */
 data class ClientCodegenConfig(
     override val formatTimeoutSeconds: Int = DEFAULT_FORMAT_TIMEOUT_SECONDS,
@@ -98,6 +105,9 @@ data class ClientCodegenConfig(
     /** If true, adds `endpoint_url`/`set_endpoint_url` methods to the service config */
     val includeEndpointUrlConfig: Boolean = DEFAULT_INCLUDE_ENDPOINT_URL_CONFIG,
     val enableUserConfigurableRuntimePlugins: Boolean = DEFAULT_ENABLE_USER_CONFIGURABLE_RUNTIME_PLUGINS,
+    val compatManifest: String? = null,
+    val convertManifest: String? = null,
+    val generateApiReport: Boolean = DEFAULT_GENERATE_API_REPORT,
 ) : CoreCodegenConfig(
         formatTimeoutSeconds, debugMode, DEFAULT_FLATTEN_ACCESSORS,
     ) {
@@ -109,6 +119,7 @@ data class ClientCodegenConfig(
         private const val DEFAULT_INCLUDE_ENDPOINT_URL_CONFIG = true
         private const val DEFAULT_ENABLE_USER_CONFIGURABLE_RUNTIME_PLUGINS = true
         private const val DEFAULT_NULLABILITY_CHECK_MODE = "CLIENT"
+        private const val DEFAULT_GENERATE_API_REPORT = false
 
         // Note: only clients default to true, servers default to false
         private const val DEFAULT_FLATTEN_ACCESSORS = true
@@ -133,6 +144,9 @@ data class ClientCodegenConfig(
                 includeEndpointUrlConfig = node.get().getBooleanMemberOrDefault("includeEndpointUrlConfig", DEFAULT_INCLUDE_ENDPOINT_URL_CONFIG),
                 enableUserConfigurableRuntimePlugins = node.get().getBooleanMemberOrDefault("enableUserConfigurableRuntimePlugins", DEFAULT_ENABLE_USER_CONFIGURABLE_RUNTIME_PLUGINS),
                 nullabilityCheckMode = NullableIndex.CheckMode.valueOf(node.get().getStringMemberOrDefault("nullabilityCheckMode", DEFAULT_NULLABILITY_CHECK_MODE)),
+                compatManifest = node.get().getStringMember("compatManifest").orNull()?.value,
+                convertManifest = node.get().getStringMember("convertManifest").orNull()?.value,
+                generateApiReport = node.get().getBooleanMemberOrDefault("generateApiReport", DEFAULT_GENERATE_API_REPORT),
             )
         } else {
             ClientCodegenConfig(
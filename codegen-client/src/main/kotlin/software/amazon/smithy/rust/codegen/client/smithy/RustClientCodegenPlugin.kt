@@ -10,6 +10,9 @@ import software.amazon.smithy.codegen.core.ReservedWordSymbolProvider
 import software.amazon.smithy.model.Model
 import software.amazon.smithy.model.shapes.ServiceShape
 import software.amazon.smithy.rust.codegen.client.smithy.customizations.ClientCustomizations
+import software.amazon.smithy.rust.codegen.client.smithy.customizations.ApiReportDecorator
+import software.amazon.smithy.rust.codegen.client.smithy.customizations.CompatShimDecorator
+import software.amazon.smithy.rust.codegen.client.smithy.customizations.ConvertDecorator
 import software.amazon.smithy.rust.codegen.client.smithy.customizations.HttpAuthDecorator
 import software.amazon.smithy.rust.codegen.client.smithy.customizations.HttpConnectorConfigDecorator
 import software.amazon.smithy.rust.codegen.client.smithy.customizations.IdempotencyTokenDecorator
@@ -72,6 +75,9 @@ class RustClientCodegenPlugin : ClientDecoratableBuildPlugin() {
                 IdempotencyTokenDecorator(),
                 StalledStreamProtectionDecorator(),
                 StaticSdkFeatureTrackerDecorator(),
+                CompatShimDecorator(),
+                ApiReportDecorator(),
+                ConvertDecorator(),
                 *decorator,
             )
 
@@ -0,0 +1,231 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.client.smithy.customizations
+
+import software.amazon.smithy.model.knowledge.TopDownIndex
+import software.amazon.smithy.model.neighbor.Walker
+import software.amazon.smithy.model.node.Node
+import software.amazon.smithy.model.node.ObjectNode
+import software.amazon.smithy.model.shapes.MemberShape
+import software.amazon.smithy.model.shapes.Shape
+import software.amazon.smithy.model.shapes.StructureShape
+import software.amazon.smithy.model.shapes.UnionShape
+import software.amazon.smithy.model.traits.DeprecatedTrait
+import software.amazon.smithy.model.traits.EnumTrait
+import software.amazon.smithy.model.traits.ErrorTrait
+import software.amazon.smithy.model.traits.UnstableTrait
+import software.amazon.smithy.rust.codegen.client.smithy.ClientCodegenContext
+import software.amazon.smithy.rust.codegen.client.smithy.customize.ClientCodegenDecorator
+import software.amazon.smithy.rust.codegen.client.smithy.generators.client.FluentClientGenerator
+import software.amazon.smithy.rust.codegen.core.rustlang.qualifiedName
+import software.amazon.smithy.rust.codegen.core.rustlang.render
+import software.amazon.smithy.rust.codegen.core.smithy.RustCrate
+import software.amazon.smithy.rust.codegen.core.smithy.generators.EnumMemberModel
+import software.amazon.smithy.rust.codegen.core.smithy.rustType
+import software.amazon.smithy.rust.codegen.core.smithy.transformers.operationErrors
+import software.amazon.smithy.rust.codegen.core.util.hasTrait
+import software.amazon.smithy.rust.codegen.core.util.isTargetUnit
+
+/** Whether a public item is stable, unstable (exempt from semver), or deprecated */
+enum class ApiStability(val value: String) {
+    STABLE("stable"),
+    UNSTABLE("unstable"),
+    DEPRECATED("deprecated"),
+    ;
+
+    companion object {
+        fun of(shape: Shape): ApiStability =
+            when {
+                shape.hasTrait<UnstableTrait>() -> UNSTABLE
+                shape.hasTrait<DeprecatedTrait>() -> DEPRECATED
+                else -> STABLE
+            }
+    }
+}
+
+/**
+ * A report of the public items of a generated crate, and of their stability.
+ *
+ * The report is rendered as a machine-readable `api.json`, which `tools/ci-build/api-diff` compares between two
+ * generations of a crate to flag breaking changes, and as a human-readable `API.md`:
+ *
+ * ```json
+ * {
+ *     "crate": "aws-sdk-dynamodb",
+ *     "version": "1.2.0",
+ *     "items": [
+ *         {
+ *             "path": "crate::types::AttributeValue",
+ *             "kind": "union",
+ *             "stability": "stable",
+ *             "members": [
+ *                 { "name": "B", "type": "::aws_smithy_types::Blob", "stability": "stable" }
+ *             ]
+ *         }
+ *     ]
+ * }
+ * ```
+ *
+ * Items are operations (`crate::Client` methods), structures, errors, unions and enums. Struct fields, union and enum
+ * variants are recorded as members, with their Rust type if they have one.
+ */
+data class ApiReport(val crateName: String, val version: String, val items: List<Item>) {
+    data class Item(val path: String, val kind: String, val stability: ApiStability, val members: List<Member>)
+
+    data class Member(val name: String, val type: String?, val stability: ApiStability)
+
+    fun toNode(): ObjectNode =
+        Node.objectNodeBuilder()
+            .withMember("crate", crateName)
+            .withMember("version", version)
+            .withMember(
+                "items",
+                Node.fromNodes(
+                    items.map { item ->
+                        Node.objectNodeBuilder()
+                            .withMember("path", item.path)
+                            .withMember("kind", item.kind)
+                            .withMember("stability", item.stability.value)
+                            .withMember(
+                                "members",
+                                Node.fromNodes(
+                                    item.members.map { member ->
+                                        Node.objectNodeBuilder()
+                                            .withMember("name", member.name)
+                                            .also { builder -> member.type?.let { builder.withMember("type", it) } }
+                                            .withMember("stability", member.stability.value)
+                                            .build()
+                                    },
+                                ),
+                            )
+                            .build()
+                    },
+                ),
+            )
+            .build()
+
+    fun toMarkdown(): String =
+        buildString {
+            appendLine("# API report for `$crateName` $version")
+            appendLine()
+            appendLine("Public items of this crate and their stability.")
+            appendLine("Unstable items are exempt from semver guarantees.")
+            appendLine()
+            appendLine("| Item | Kind | Stability |")
+            appendLine("| --- | --- | --- |")
+            items.forEach { item ->
+                appendLine("| `${item.path}` | ${item.kind} | ${item.stability.value} |")
+                item.members.forEach { member ->
+                    appendLine("| `${item.path}::${member.name}` | member | ${member.stability.value} |")
+                }
+            }
+        }
+
+    companion object {
+        fun fromContext(codegenContext: ClientCodegenContext): ApiReport {
+            val model = codegenContext.model
+            val symbolProvider = codegenContext.symbolProvider
+            val service = codegenContext.serviceShape
+            val operations = TopDownIndex.of(model).getContainedOperations(service).sortedBy { it.id }
+
+            fun path(shape: Shape) = symbolProvider.toSymbol(shape).rustType().qualifiedName()
+
+            fun member(member: MemberShape) =
+                Member(
+                    symbolProvider.toMemberName(member),
+                    if (member.isTargetUnit()) null else symbolProvider.toSymbol(member).rustType().render(true),
+                    ApiStability.of(member).takeIf { it != ApiStability.STABLE }
+                        ?: ApiStability.of(model.expectShape(member.target)),
+                )
+
+            val operationItems =
+                operations.flatMap { operation ->
+                    val errors = operation.operationErrors(model)
+                    listOf(
+                        Item(
+                            "crate::Client::${FluentClientGenerator.clientOperationFnName(operation, symbolProvider)}",
+                            "operation",
+                            ApiStability.of(operation),
+                            emptyList(),
+                        ),
+                        Item(
+                            symbolProvider.symbolForOperationError(operation).rustType().qualifiedName(),
+                            "error",
+                            ApiStability.of(operation),
+                            errors.map { error ->
+                                Member(symbolProvider.toSymbol(error).name, path(error), ApiStability.of(error))
+                            }.sortedBy { it.name },
+                        ),
+                    )
+                }
+
+            val shapeItems =
+                Walker(model).walkShapes(service)
+                    .mapNotNull { shape ->
+                        when {
+                            shape is StructureShape ->
+                                Item(
+                                    path(shape),
+                                    if (shape.hasTrait<ErrorTrait>()) "error" else "struct",
+                                    ApiStability.of(shape),
+                                    shape.members().map(::member),
+                                )
+
+                            shape is UnionShape ->
+                                Item(path(shape), "union", ApiStability.of(shape), shape.members().map(::member))
+
+                            shape.hasTrait<EnumTrait>() ->
+                                Item(
+                                    path(shape),
+                                    "enum",
+                                    ApiStability.of(shape),
+                                    shape.expectTrait(EnumTrait::class.java).values.mapNotNull { definition ->
+                                        @Suppress("DEPRECATION")
+                                        EnumMemberModel.toEnumVariantName(symbolProvider, shape, definition)?.let {
+                                            val stability =
+                                                if (definition.isDeprecated) ApiStability.DEPRECATED else ApiStability.STABLE
+                                            Member(it.name, null, stability)
+                                        }
+                                    },
+                                )
+
+                            else -> null
+                        }
+                    }
+
+            return ApiReport(
+                codegenContext.settings.moduleName,
+                codegenContext.settings.moduleVersion,
+                (operationItems + shapeItems).distinctBy { it.path }.sortedBy { it.path },
+            )
+        }
+    }
+}
+
+/**
+ * Generates an API report of the public items of the crate and their stability, when the `generateApiReport`
+ * codegen setting is enabled. See [ApiReport].
+ */
+class ApiReportDecorator : ClientCodegenDecorator {
+    override val name: String = "ApiReport"
+    override val order: Byte = 0
+
+    override fun extras(
+        codegenContext: ClientCodegenContext,
+        rustCrate: RustCrate,
+    ) {
+        if (!codegenContext.settings.codegenConfig.generateApiReport) {
+            return
+        }
+        val report = ApiReport.fromContext(codegenContext)
+        rustCrate.withFile("api.json") {
+            raw(Node.prettyPrintJson(report.toNode()))
+        }
+        rustCrate.withFile("API.md") {
+            raw(report.toMarkdown())
+        }
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.client.smithy.customizations
+
+import software.amazon.smithy.codegen.core.CodegenException
+import software.amazon.smithy.model.node.Node
+import software.amazon.smithy.model.node.ObjectNode
+import software.amazon.smithy.rust.codegen.client.smithy.ClientCodegenContext
+import software.amazon.smithy.rust.codegen.client.smithy.customize.ClientCodegenDecorator
+import software.amazon.smithy.rust.codegen.core.rustlang.Attribute
+import software.amazon.smithy.rust.codegen.core.rustlang.RustModule
+import software.amazon.smithy.rust.codegen.core.rustlang.docs
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.smithy.RustCrate
+import software.amazon.smithy.rust.codegen.core.util.orNull
+import java.io.File
+
+/**
+ * A manifest of the public names of a previous release of a generated crate that have been renamed.
+ *
+ * The manifest is a JSON document listing the old and new paths of each renamed item, relative to the crate root:
+ *
+ * ```json
+ * {
+ *     "previousVersion": "1.2.0",
+ *     "renames": [
+ *         {
+ *             "old": "operation::get_item::builders::GetItemFluentBuilder",
+ *             "new": "operation::get_item::builders::GetItemRequestBuilder"
+ *         }
+ *     ]
+ * }
+ * ```
+ *
+ * Only types can be renamed, and they must not have generic parameters.
+ */
+data class CompatManifest(val previousVersion: String?, val renames: List<Rename>) {
+    data class Rename(val old: List<String>, val new: List<String>)
+
+    companion object {
+        private val IDENTIFIER = Regex("[A-Za-z_][A-Za-z0-9_]*")
+
+        private fun parsePath(path: String): List<String> {
+            val segments = path.removePrefix("crate::").split("::")
+            if (segments.any { !IDENTIFIER.matches(it) }) {
+                throw CodegenException("`$path` in the compat manifest is not a valid path")
+            }
+            return segments
+        }
+
+        fun fromNode(node: ObjectNode): CompatManifest {
+            val renames =
+                node.expectArrayMember("renames").elements.map { element ->
+                    val rename = element.expectObjectNode()
+                    Rename(
+                        old = parsePath(rename.expectStringMember("old").value),
+                        new = parsePath(rename.expectStringMember("new").value),
+                    )
+                }
+            renames.groupBy { it.old }.filterValues { it.size > 1 }.keys.firstOrNull()?.also { duplicate ->
+                throw CodegenException("`${duplicate.joinToString("::")}` is renamed more than once in the compat manifest")
+            }
+            return CompatManifest(node.getStringMember("previousVersion").orNull()?.value, renames)
+        }
+
+        fun fromFile(file: File): CompatManifest = fromNode(Node.parse(file.readText()).expectObjectNode())
+    }
+}
+
+/**
+ * Generates a `compat` module that keeps the public names of the previous release of the crate compiling after
+ * they've been renamed.
+ *
+ * The renamed items are listed by the [CompatManifest] that the `compatManifest` codegen setting points to. Each
+ * one gets a deprecated type alias in `compat`, under the same module path as before, pointing to its new name.
+ * Only the renamed items are in `compat`, so consumers must only change the imports of those items: the docs of
+ * the `compat` module list each old path along with its alias and its new name. Consumers can import the aliases
+ * first, and then fix the deprecation warnings at their own pace.
+ */
+class CompatShimDecorator : ClientCodegenDecorator {
+    override val name: String = "CompatShim"
+    override val order: Byte = 0
+
+    override fun extras(
+        codegenContext: ClientCodegenContext,
+        rustCrate: RustCrate,
+    ) {
+        val manifestPath = codegenContext.settings.codegenConfig.compatManifest ?: return
+        val manifest = CompatManifest.fromFile(File(manifestPath))
+        val previousRelease = manifest.previousVersion?.let { "version $it" } ?: "the previous release"
+
+        val renamedPaths =
+            manifest.renames.joinToString("\n") { rename ->
+                val old = rename.old.joinToString("::")
+                "- `crate::$old`: [`crate::compat::$old`](crate::compat::$old), renamed to " +
+                    "[`crate::${rename.new.joinToString("::")}`](crate::${rename.new.joinToString("::")})"
+            }
+        val compatModule =
+            RustModule.public(
+                "compat",
+                documentationOverride =
+                    "Deprecated aliases for public names of $previousRelease of this crate that have been renamed.\n\n" +
+                        "Only the renamed items below have an alias here, and every other item keeps its path. " +
+                        "To upgrade without migrating yet, replace the imports of these items with their alias. " +
+                        "To migrate, replace them with their new name.\n\n" +
+                        renamedPaths,
+            )
+        rustCrate.withModule(compatModule) {}
+        manifest.renames.groupBy { it.old.dropLast(1) }.forEach { (modulePath, renames) ->
+            val module =
+                modulePath.foldIndexed(compatModule) { index, parent, segment ->
+                    RustModule.public(
+                        segment,
+                        parent = parent,
+                        documentationOverride = "Deprecated aliases for renamed items of `crate::${modulePath.take(index + 1).joinToString("::")}`.",
+                    )
+                }
+            rustCrate.withModule(module) {
+                renames.forEach { rename ->
+                    val oldName = rename.old.last()
+                    val newPath = "crate::${rename.new.joinToString("::")}"
+                    docs("Renamed to [`${rename.new.last()}`]($newPath).")
+                    Attribute(
+                        Attribute.deprecated(
+                            since = codegenContext.settings.moduleVersion,
+                            note = "renamed to `$newPath`",
+                        ),
+                    ).render(this)
+                    rust("pub type $oldName = $newPath;")
+                }
+            }
+        }
+    }
+}
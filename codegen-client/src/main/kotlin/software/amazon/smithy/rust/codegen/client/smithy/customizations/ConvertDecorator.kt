@@ -0,0 +1,325 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.client.smithy.customizations
+
+import software.amazon.smithy.codegen.core.CodegenException
+import software.amazon.smithy.model.node.Node
+import software.amazon.smithy.model.node.ObjectNode
+import software.amazon.smithy.model.shapes.CollectionShape
+import software.amazon.smithy.model.shapes.MapShape
+import software.amazon.smithy.model.shapes.MemberShape
+import software.amazon.smithy.model.shapes.Shape
+import software.amazon.smithy.model.shapes.ShapeId
+import software.amazon.smithy.model.shapes.StructureShape
+import software.amazon.smithy.model.shapes.UnionShape
+import software.amazon.smithy.model.traits.EnumTrait
+import software.amazon.smithy.model.traits.ErrorTrait
+import software.amazon.smithy.model.traits.StreamingTrait
+import software.amazon.smithy.rust.codegen.client.smithy.ClientCodegenContext
+import software.amazon.smithy.rust.codegen.client.smithy.customize.ClientCodegenDecorator
+import software.amazon.smithy.rust.codegen.core.rustlang.Attribute
+import software.amazon.smithy.rust.codegen.core.rustlang.CargoDependency
+import software.amazon.smithy.rust.codegen.core.rustlang.CratesIo
+import software.amazon.smithy.rust.codegen.core.rustlang.Feature
+import software.amazon.smithy.rust.codegen.core.rustlang.Local
+import software.amazon.smithy.rust.codegen.core.rustlang.RustModule
+import software.amazon.smithy.rust.codegen.core.rustlang.RustWriter
+import software.amazon.smithy.rust.codegen.core.rustlang.qualifiedName
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.rustlang.rustBlock
+import software.amazon.smithy.rust.codegen.core.rustlang.rustBlockTemplate
+import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
+import software.amazon.smithy.rust.codegen.core.rustlang.writable
+import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
+import software.amazon.smithy.rust.codegen.core.smithy.RustCrate
+import software.amazon.smithy.rust.codegen.core.smithy.canUseDefault
+import software.amazon.smithy.rust.codegen.core.smithy.generators.BuilderGenerator
+import software.amazon.smithy.rust.codegen.core.smithy.generators.setterName
+import software.amazon.smithy.rust.codegen.core.smithy.isOptional
+import software.amazon.smithy.rust.codegen.core.smithy.isRustBoxed
+import software.amazon.smithy.rust.codegen.core.smithy.rustType
+import software.amazon.smithy.rust.codegen.core.util.hasTrait
+import software.amazon.smithy.rust.codegen.core.util.isTargetUnit
+import software.amazon.smithy.rust.codegen.core.util.orNull
+import java.io.File
+
+/**
+ * A manifest of the shapes of a generated crate that are structurally identical to types of another crate, generated
+ * from a different version of the service model.
+ *
+ * The manifest is a JSON document naming the source crate, and listing each shape with the path of its counterpart,
+ * relative to the root of the source crate:
+ *
+ * ```json
+ * {
+ *     "crate": "aws-sdk-example-v1",
+ *     "version": "1.2.0",
+ *     "shapes": [
+ *         { "shape": "com.example#Greeting", "from": "types::Greeting", "newMembers": ["nickname"] }
+ *     ]
+ * }
+ * ```
+ *
+ * The source crate is a crates.io dependency of `version`, or a path dependency if `path`, relative to the generated
+ * crate, is set. `newMembers` lists the members of a shape that its counterpart doesn't have, which are left unset, so
+ * they can't be required members without a default.
+ *
+ * Variants of a union's counterpart that the union doesn't have, such as variants removed from the model, are
+ * converted to the union's `Unknown` variant.
+ */
+data class ConvertManifest(
+    val crateName: String,
+    val version: String?,
+    val path: String?,
+    val shapes: Map<ShapeId, Conversion>,
+) {
+    data class Conversion(val from: List<String>, val newMembers: Set<String>)
+
+    /** The optional dependency on the source crate */
+    fun dependency(): CargoDependency =
+        if (path != null) {
+            val directory = File(path)
+            CargoDependency(
+                directory.name,
+                Local(directory.parent ?: ".", version),
+                optional = true,
+                `package` = crateName.takeIf { it != directory.name },
+            )
+        } else {
+            CargoDependency(crateName, CratesIo(version!!), optional = true)
+        }
+
+    companion object {
+        private val IDENTIFIER = Regex("[A-Za-z_][A-Za-z0-9_]*")
+
+        private fun parsePath(path: String): List<String> {
+            val segments = path.split("::")
+            if (segments.any { !IDENTIFIER.matches(it) }) {
+                throw CodegenException("`$path` in the convert manifest is not a valid path")
+            }
+            return segments
+        }
+
+        fun fromNode(node: ObjectNode): ConvertManifest {
+            val version = node.getStringMember("version").orNull()?.value
+            val path = node.getStringMember("path").orNull()?.value
+            if (version == null && path == null) {
+                throw CodegenException("The convert manifest must set the `version` or the `path` of the source crate")
+            }
+            val shapes =
+                node.expectArrayMember("shapes").elements.map { element ->
+                    val shape = element.expectObjectNode()
+                    ShapeId.from(shape.expectStringMember("shape").value) to
+                        Conversion(
+                            from = parsePath(shape.expectStringMember("from").value),
+                            newMembers =
+                                shape.getArrayMember("newMembers").orNull()
+                                    ?.elements?.map { it.expectStringNode().value }?.toSet() ?: emptySet(),
+                        )
+                }
+            shapes.groupBy { it.first }.filterValues { it.size > 1 }.keys.firstOrNull()?.also { duplicate ->
+                throw CodegenException("`$duplicate` is listed more than once in the convert manifest")
+            }
+            return ConvertManifest(node.expectStringMember("crate").value, version, path, shapes.toMap())
+        }
+
+        fun fromFile(file: File): ConvertManifest = fromNode(Node.parse(file.readText()).expectObjectNode())
+    }
+}
+
+/**
+ * Generates a `convert` module with `From` implementations from the types of another version of the crate to the
+ * structurally identical types of this one, to ease migrating between versions of a service.
+ *
+ * The shapes to convert are listed by the [ConvertManifest] that the `convertManifest` codegen setting points to.
+ * Structures and unions referenced by a listed shape must be listed as well, while enums are converted through their
+ * string values. The module and the dependency on the source crate are behind the `convert` Cargo feature.
+ */
+class ConvertDecorator : ClientCodegenDecorator {
+    override val name: String = "Convert"
+    override val order: Byte = 0
+
+    override fun extras(
+        codegenContext: ClientCodegenContext,
+        rustCrate: RustCrate,
+    ) {
+        val manifestPath = codegenContext.settings.codegenConfig.convertManifest ?: return
+        val manifest = ConvertManifest.fromFile(File(manifestPath))
+        val dependency = manifest.dependency()
+        rustCrate.mergeFeature(Feature(FEATURE, default = false, listOf(dependency.name)))
+
+        val convertModule =
+            RustModule.public(
+                "convert",
+                documentationOverride =
+                    "Conversions from the types of `${manifest.crateName}` to the types of this crate.\n\n" +
+                        "Blobs, timestamps and documents are passed through as they are, so both crates must " +
+                        "depend on the same major version of `aws-smithy-types`.\n\n" +
+                        "Union variants that this crate doesn't have are converted to the `Unknown` variant, " +
+                        "so their values are lost.",
+                additionalAttributes = listOf(Attribute.featureGate(FEATURE)),
+            )
+        rustCrate.withModule(convertModule) {
+            ConvertGenerator(codegenContext, manifest, dependency.toType()).render(this)
+        }
+    }
+
+    companion object {
+        const val FEATURE = "convert"
+    }
+}
+
+private class ConvertGenerator(
+    codegenContext: ClientCodegenContext,
+    private val manifest: ConvertManifest,
+    private val sourceCrate: RuntimeType,
+) {
+    private val model = codegenContext.model
+    private val symbolProvider = codegenContext.symbolProvider
+
+    fun render(writer: RustWriter) {
+        manifest.shapes.forEach { (shapeId, conversion) ->
+            val shape =
+                model.getShape(shapeId).orNull()
+                    ?: throw CodegenException("`$shapeId` in the convert manifest is not in the model")
+            val body =
+                when {
+                    shape is StructureShape && !shape.hasTrait<ErrorTrait>() ->
+                        writable { renderStructure(shape, conversion) }
+                    shape is UnionShape && !shape.hasTrait<StreamingTrait>() ->
+                        writable { renderUnion(shape, conversion) }
+                    shape.hasTrait<EnumTrait>() -> writable { rust("Self::from(value.as_str())") }
+                    else -> throw CodegenException("`$shapeId` in the convert manifest can't be converted")
+                }
+            validateNewMembers(shape, conversion)
+            val source = sourceCrate.resolve(conversion.from.joinToString("::"))
+            val value = if (shape is StructureShape && members(shape, conversion).isEmpty()) "_value" else "value"
+            Attribute.AllowDeprecated.render(writer)
+            writer.rustBlockTemplate(
+                "impl #{From}<#{Source}> for #{Target}",
+                "From" to RuntimeType.From,
+                "Source" to source,
+                "Target" to symbolProvider.toSymbol(shape),
+            ) {
+                rustBlockTemplate("fn from($value: #{Source}) -> Self", "Source" to source) {
+                    body(this)
+                }
+            }
+        }
+    }
+
+    /** New members are never set, so they must exist and must be optional or have a default */
+    private fun validateNewMembers(
+        shape: Shape,
+        conversion: ConvertManifest.Conversion,
+    ) {
+        conversion.newMembers.forEach { memberName ->
+            val member =
+                shape.getMember(memberName).orNull()
+                    ?: throw CodegenException("`$memberName` in the convert manifest is not a member of `${shape.id}`")
+            val symbol = symbolProvider.toSymbol(member)
+            if (shape is StructureShape && !symbol.isOptional() && !symbol.canUseDefault()) {
+                throw CodegenException(
+                    "`${member.id}` is required, so it can't be a new member in the convert manifest",
+                )
+            }
+        }
+    }
+
+    private fun members(
+        shape: Shape,
+        conversion: ConvertManifest.Conversion,
+    ): List<MemberShape> = shape.members().filter { it.memberName !in conversion.newMembers }
+
+    private fun RustWriter.renderStructure(
+        shape: StructureShape,
+        conversion: ConvertManifest.Conversion,
+    ) {
+        rust("Self::builder()")
+        members(shape, conversion).forEach { member ->
+            val field = "value.${symbolProvider.toMemberName(member)}"
+            val value =
+                if (symbolProvider.toSymbol(member).isOptional()) {
+                    convert(member, "v")?.let { "$field.map(|v| $it)" } ?: field
+                } else {
+                    "Some(${convert(member, field) ?: field})"
+                }
+            rust(".${member.setterName()}($value)")
+        }
+        if (BuilderGenerator.hasFallibleBuilder(shape, symbolProvider)) {
+            rust(""".build().expect("the source has the same required members")""")
+        } else {
+            rust(".build()")
+        }
+    }
+
+    private fun RustWriter.renderUnion(
+        shape: UnionShape,
+        conversion: ConvertManifest.Conversion,
+    ) {
+        val source = sourceCrate.resolve(conversion.from.joinToString("::"))
+        rustBlock("match value") {
+            members(shape, conversion).forEach { member ->
+                val variant = symbolProvider.toMemberName(member)
+                if (member.isTargetUnit()) {
+                    rustTemplate("#{Source}::$variant => Self::$variant,", "Source" to source)
+                } else {
+                    val value = convert(member, "v") ?: "v"
+                    rustTemplate("#{Source}::$variant(v) => Self::$variant($value),", "Source" to source)
+                }
+            }
+            rust("_ => Self::Unknown,")
+        }
+    }
+
+    /**
+     * Returns an expression converting [value], the counterpart of a value of [member], or `null` if it has the same
+     * type in both crates.
+     */
+    private fun convert(
+        member: MemberShape,
+        value: String,
+    ): String? {
+        val target = model.expectShape(member.target)
+        val targetType = symbolProvider.toSymbol(target).rustType().qualifiedName()
+        return when {
+            target.hasTrait<EnumTrait>() -> "$targetType::from($value.as_str())"
+            target is StructureShape || target is UnionShape -> {
+                if (target.id !in manifest.shapes) {
+                    throw CodegenException("`${target.id}` must be in the convert manifest, because `${member.id}` targets it")
+                }
+                if (symbolProvider.toSymbol(member).isRustBoxed()) {
+                    "Box::new($targetType::from(*$value))"
+                } else {
+                    "$targetType::from($value)"
+                }
+            }
+            target is CollectionShape ->
+                convertElement(target.member, "v")?.let { "$value.into_iter().map(|v| $it).collect()" }
+            target is MapShape -> {
+                val key = convert(target.key, "k")
+                val element = convertElement(target.value, "v")
+                if (key == null && element == null) {
+                    null
+                } else {
+                    "$value.into_iter().map(|(k, v)| (${key ?: "k"}, ${element ?: "v"})).collect()"
+                }
+            }
+            else -> null
+        }
+    }
+
+    /** Like [convert], but for the elements of lists and maps, which are optional in sparse collections */
+    private fun convertElement(
+        member: MemberShape,
+        value: String,
+    ): String? =
+        if (symbolProvider.toSymbol(member).isOptional()) {
+            convert(member, "v")?.let { "$value.map(|v| $it)" }
+        } else {
+            convert(member, value)
+        }
+}
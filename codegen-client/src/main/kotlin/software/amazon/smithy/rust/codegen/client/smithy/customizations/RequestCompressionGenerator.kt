@@ -9,16 +9,23 @@ import software.amazon.smithy.model.shapes.OperationShape
 import software.amazon.smithy.model.traits.RequestCompressionTrait
 import software.amazon.smithy.rust.codegen.client.smithy.generators.OperationCustomization
 import software.amazon.smithy.rust.codegen.client.smithy.generators.OperationSection
+import software.amazon.smithy.rust.codegen.core.rustlang.CargoDependency
 import software.amazon.smithy.rust.codegen.core.rustlang.Writable
-import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
 import software.amazon.smithy.rust.codegen.core.rustlang.writable
 import software.amazon.smithy.rust.codegen.core.smithy.CodegenContext
 import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
 import software.amazon.smithy.rust.codegen.core.util.getTrait
 import java.util.logging.Logger
 
-// Currently, gzip is the only supported encoding.
-fun isSupportedEncoding(encoding: String): Boolean = encoding == "gzip"
+// Maps the supported encodings to their `CompressionAlgorithm` variant.
+private val supportedEncodings =
+    mapOf(
+        "gzip" to "Gzip",
+        "br" to "Brotli",
+    )
+
+fun isSupportedEncoding(encoding: String): Boolean = supportedEncodings.containsKey(encoding)
 
 fun firstSupportedEncoding(encodings: List<String>): String? = encodings.firstOrNull { isSupportedEncoding(it) }
 
@@ -36,17 +43,21 @@ class RequestCompressionGenerator(
                 return emptySection
             }
             val encoding = firstSupportedEncoding(requestCompressionTrait.encodings) ?: return emptySection
-            // We can remove this once we start supporting other algorithms.
-            // Until then, we shouldn't see anything else coming up here.
-            assert(encoding == "gzip") { "Only gzip is supported but encoding was `$encoding`" }
             val runtimeConfig = codegenContext.runtimeConfig
             val compression = RuntimeType.clientRequestCompression(runtimeConfig)
+            val compressionAlgorithm =
+                CargoDependency.smithyCompression(runtimeConfig).withFeature("http-body-0-4-x").toType()
+                    .resolve("CompressionAlgorithm")
 
             return writable {
                 when (section) {
                     is OperationSection.AdditionalRuntimePlugins ->
                         section.addOperationRuntimePlugin(this) {
-                            rust("#T::new()", compression.resolve("RequestCompressionRuntimePlugin"))
+                            rustTemplate(
+                                "#{RequestCompressionRuntimePlugin}::new(#{CompressionAlgorithm}::${supportedEncodings.getValue(encoding)})",
+                                "RequestCompressionRuntimePlugin" to compression.resolve("RequestCompressionRuntimePlugin"),
+                                "CompressionAlgorithm" to compressionAlgorithm,
+                            )
                         }
 
                     else -> {}
@@ -36,6 +36,7 @@ class ResiliencyConfigCustomization(codegenContext: ClientCodegenContext) : Conf
             "RetryConfig" to retryConfig.resolve("RetryConfig"),
             "RetryMode" to RuntimeType.smithyTypes(runtimeConfig).resolve("retry::RetryMode"),
             "RetryPartition" to retries.resolve("RetryPartition"),
+            "RetryQuotaPartitioning" to retries.resolve("RetryQuotaPartitioning"),
             "SharedAsyncSleep" to configReexport(sleepModule.resolve("SharedAsyncSleep")),
             "SharedRetryStrategy" to configReexport(RuntimeType.smithyRuntimeApiClient(runtimeConfig).resolve("client::retries::SharedRetryStrategy")),
             "SharedTimeSource" to configReexport(RuntimeType.smithyAsync(runtimeConfig).resolve("time::SharedTimeSource")),
@@ -72,6 +73,11 @@ class ResiliencyConfigCustomization(codegenContext: ClientCodegenContext) : Conf
                         pub fn retry_partition(&self) -> #{Option}<&#{RetryPartition}> {
                             self.config.load::<#{RetryPartition}>()
                         }
+
+                        /// Return a reference to the retry quota partitioning contained in this config, if any.
+                        pub fn retry_quota_partitioning(&self) -> #{Option}<&#{RetryQuotaPartitioning}> {
+                            self.config.load::<#{RetryQuotaPartitioning}>()
+                        }
                         """,
                         *codegenScope,
                     )
@@ -288,6 +294,41 @@ class ResiliencyConfigCustomization(codegenContext: ClientCodegenContext) : Conf
                         """,
                         *codegenScope,
                     )
+
+                    rustTemplate(
+                        """
+                        /// Set whether operations share the retry quota of the retry partition.
+                        ///
+                        /// By default, all operations share a single retry quota, so an operation that fails repeatedly
+                        /// can exhaust it and prevent other operations from being retried. Use
+                        /// [`RetryQuotaPartitioning::PerOperation`](#{RetryQuotaPartitioning}::PerOperation) to give each
+                        /// operation a retry quota of its own.
+                        pub fn retry_quota_partitioning(mut self, retry_quota_partitioning: #{RetryQuotaPartitioning}) -> Self {
+                            self.set_retry_quota_partitioning(Some(retry_quota_partitioning));
+                            self
+                        }
+                        """,
+                        *codegenScope,
+                    )
+
+                    rustTemplate(
+                        """
+                        /// Set whether operations share the retry quota of the retry partition.
+                        ///
+                        /// By default, all operations share a single retry quota, so an operation that fails repeatedly
+                        /// can exhaust it and prevent other operations from being retried. Use
+                        /// [`RetryQuotaPartitioning::PerOperation`](#{RetryQuotaPartitioning}::PerOperation) to give each
+                        /// operation a retry quota of its own.
+                        pub fn set_retry_quota_partitioning(
+                            &mut self,
+                            retry_quota_partitioning: #{Option}<#{RetryQuotaPartitioning}>,
+                        ) -> &mut Self {
+                            self.config.store_or_unset(retry_quota_partitioning);
+                            self
+                        }
+                        """,
+                        *codegenScope,
+                    )
                 }
 
                 is ServiceConfig.BuilderFromConfigBag -> {
@@ -303,6 +344,10 @@ class ResiliencyConfigCustomization(codegenContext: ClientCodegenContext) : Conf
                         "${section.builder}.set_retry_partition(${section.configBag}.load::<#{RetryPartition}>().cloned());",
                         *codegenScope,
                     )
+                    rustTemplate(
+                        "${section.builder}.set_retry_quota_partitioning(${section.configBag}.load::<#{RetryQuotaPartitioning}>().cloned());",
+                        *codegenScope,
+                    )
                 }
 
                 else -> emptySection
@@ -327,7 +372,7 @@ class ResiliencyReExportCustomization(codegenContext: ClientCodegenContext) {
             )
 
             rustTemplate(
-                "pub use #{types_retry}::RetryPartition;",
+                "pub use #{types_retry}::{RetryPartition, RetryQuotaPartitioning};",
                 "types_retry" to RuntimeType.smithyRuntime(runtimeConfig).resolve("client::retries"),
             )
         }
@@ -70,6 +70,14 @@ interface ClientCodegenDecorator : CoreCodegenDecorator<ClientCodegenContext, Cl
         baseCustomizations: List<ErrorCustomization>,
     ): List<ErrorCustomization> = baseCustomizations
 
+    /**
+     * Hook to add error codes that a service documents but does not model to the generated `ErrorCode` enum.
+     */
+    fun additionalErrorCodes(
+        codegenContext: ClientCodegenContext,
+        baseCodes: List<String>,
+    ): List<String> = baseCodes
+
     fun protocols(
         serviceId: ShapeId,
         currentProtocols: ClientProtocolMap,
@@ -140,6 +148,14 @@ open class CombinedClientCodegenDecorator(decorators: List<ClientCodegenDecorato
             decorator.errorCustomizations(codegenContext, customizations)
         }
 
+    override fun additionalErrorCodes(
+        codegenContext: ClientCodegenContext,
+        baseCodes: List<String>,
+    ): List<String> =
+        combineCustomizations(baseCodes) { decorator, codes ->
+            decorator.additionalErrorCodes(codegenContext, codes)
+        }
+
     override fun protocols(
         serviceId: ShapeId,
         currentProtocols: ClientProtocolMap,
@@ -78,4 +78,23 @@ class EndpointTypesGenerator(
         }
         return defaultProviders.firstOrNull()
     }
+
+    /**
+     * Load the value for [parameter] from the resolved identity. [identity] refers to
+     * `&aws_smithy_runtime_api::client::identity::Identity`
+     *
+     * Exactly one endpoint customization must provide the value for this builtIn or null is returned.
+     */
+    fun builtInFromIdentity(
+        parameter: Parameter,
+        identity: String,
+    ): Writable? {
+        val identityProviders =
+            customizations
+                .mapNotNull { it.loadBuiltInFromIdentity(parameter, identity) }
+        if (identityProviders.size > 1) {
+            error("Multiple providers provided an identity value for the builtin $parameter")
+        }
+        return identityProviders.firstOrNull()
+    }
 }
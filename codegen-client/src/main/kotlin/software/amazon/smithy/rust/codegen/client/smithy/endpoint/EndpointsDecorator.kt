@@ -75,6 +75,18 @@ interface EndpointCustomization {
         configBuilderRef: String,
     ): Writable? = null
 
+    /**
+     * Provide the value for [parameter] from the resolved identity, given a reference to the identity ([identityRef])
+     *
+     * The endpoint is resolved again with the value when the parameter was not already set, e.g. to route requests
+     * to an account-based endpoint using the account ID of the credentials. The writable must evaluate to an
+     * `Option` of the parameter type. If this parameter is not recognized, return null.
+     */
+    fun loadBuiltInFromIdentity(
+        parameter: Parameter,
+        identityRef: String,
+    ): Writable? = null
+
     /**
      * Provide a list of additional endpoints standard library functions that rules can use
      */
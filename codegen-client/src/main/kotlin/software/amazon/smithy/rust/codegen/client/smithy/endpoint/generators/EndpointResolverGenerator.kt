@@ -396,9 +396,21 @@ internal class EndpointResolverGenerator(
 
 fun ClientCodegenContext.serviceSpecificEndpointResolver(): RuntimeType {
     val generator = EndpointTypesGenerator.fromContext(this)
+    // Optional parameters that can be filled in from the identity once it has been resolved
+    val identityParams =
+        generator.params.toList().filter { !it.isRequired }.mapNotNull { parameter ->
+            generator.builtInFromIdentity(parameter, "identity")?.let { parameter to it }
+        }
     return RuntimeType.forInlineFun("ResolveEndpoint", ClientRustModule.Config.endpoint) {
         val ctx =
-            arrayOf(*preludeScope, "Params" to generator.paramsStruct(), *Types(runtimeConfig).toArray(), "Debug" to RuntimeType.Debug)
+            arrayOf(
+                *preludeScope,
+                "Params" to generator.paramsStruct(),
+                *Types(runtimeConfig).toArray(),
+                "Debug" to RuntimeType.Debug,
+                "BoxError" to RuntimeType.boxError(runtimeConfig),
+                "Identity" to RuntimeType.smithyRuntimeApiClient(runtimeConfig).resolve("client::identity::Identity"),
+            )
         rustTemplate(
             """
             /// Endpoint resolver trait specific to ${serviceShape.serviceNameOrDefault("this service")}
@@ -430,10 +442,52 @@ fun ClientCodegenContext.serviceSpecificEndpointResolver(): RuntimeType {
                     };
                     ep
                 }
+
+                #{finalize_params:W}
             }
 
             """,
             *ctx,
+            "finalize_params" to
+                writable {
+                    if (identityParams.isNotEmpty()) {
+                        rustTemplate(
+                            """
+                            fn finalize_params(
+                                &self,
+                                params: &#{EndpointResolverParams},
+                                identity: &#{Identity},
+                            ) -> #{Result}<#{Option}<#{EndpointResolverParams}>, #{BoxError}> {
+                                let #{Some}(params) = params.get::<#{Params}>() else {
+                                    return #{Ok}(#{None});
+                                };
+                                let mut finalized = params.clone();
+                                #{load_from_identity:W}
+                                #{Ok}((&finalized != params).then(|| #{EndpointResolverParams}::new(finalized)))
+                            }
+
+                            fn params_depend_on_identity(&self) -> bool {
+                                true
+                            }
+                            """,
+                            *ctx,
+                            "load_from_identity" to
+                                identityParams.map { (parameter, value) ->
+                                    writable {
+                                        val name = parameter.memberName()
+                                        rustTemplate(
+                                            """
+                                            if finalized.$name.is_none() {
+                                                finalized.$name = #{value};
+                                            }
+                                            """,
+                                            "value" to value,
+                                        )
+                                    }
+                                }.join("\n"),
+                        )
+                    }
+                },
         )
     }
 }
@@ -25,11 +25,14 @@ class ClientRuntimeTypesReExportGenerator(
                 """
                 pub use #{ConfigBag};
                 pub use #{RuntimeComponents};
+                pub use #{RuntimeComponentsReport};
                 pub use #{IdentityCache};
                 """,
                 "ConfigBag" to RuntimeType.configBag(rc),
                 "Intercept" to RuntimeType.intercept(rc),
                 "RuntimeComponents" to RuntimeType.runtimeComponents(rc),
+                "RuntimeComponentsReport" to
+                    smithyRuntimeApi.resolve("client::runtime_components::RuntimeComponentsReport"),
                 "SharedInterceptor" to RuntimeType.sharedInterceptor(rc),
                 "IdentityCache" to RuntimeType.smithyRuntime(rc).resolve("client::identity::IdentityCache"),
             )
@@ -73,11 +73,20 @@ class EndpointTraitBindings(
                         }
                         if (generateValidation) {
                             val errorString = "$field was unset or empty but must be set as part of the endpoint prefix"
+                            // Host labels may only contain characters that are unreserved in URIs (RFC 3986)
                             val contents =
                                 """
                                 if $field.is_empty() {
                                     return Err(#{InvalidEndpointError}::failed_to_construct_uri("$errorString").into());
                                 }
+                                if let Some((offset, c)) = $field
+                                    .char_indices()
+                                    .find(|(_, c)| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~')))
+                                {
+                                    return Err(#{InvalidEndpointError}::failed_to_construct_uri(format!(
+                                        "$field contains invalid character {c:?} at byte offset {offset} and cannot be used in the endpoint prefix"
+                                    )).into());
+                                }
                                 """
                             rustTemplate(
                                 contents,
@@ -5,6 +5,7 @@
 
 package software.amazon.smithy.rust.codegen.client.smithy.generators
 
+import software.amazon.smithy.aws.traits.ServiceTrait
 import software.amazon.smithy.model.shapes.OperationShape
 import software.amazon.smithy.rust.codegen.client.smithy.ClientCodegenContext
 import software.amazon.smithy.rust.codegen.client.smithy.customize.AuthSchemeOption
@@ -29,6 +30,8 @@ import software.amazon.smithy.rust.codegen.core.smithy.customize.writeCustomizat
 import software.amazon.smithy.rust.codegen.core.smithy.generators.protocol.ProtocolPayloadGenerator
 import software.amazon.smithy.rust.codegen.core.smithy.protocols.Protocol
 import software.amazon.smithy.rust.codegen.core.util.dq
+import software.amazon.smithy.rust.codegen.core.util.getTrait
+import software.amazon.smithy.rust.codegen.core.util.hasTrait
 import software.amazon.smithy.rust.codegen.core.util.inputShape
 import software.amazon.smithy.rust.codegen.core.util.outputShape
 import software.amazon.smithy.rust.codegen.core.util.sdkId
@@ -45,6 +48,24 @@ open class OperationGenerator(
     private val runtimeConfig = codegenContext.runtimeConfig
     private val symbolProvider = codegenContext.symbolProvider
 
+    /**
+     * The `rpc.system` of the operation spans, following the OpenTelemetry semantic conventions.
+     */
+    private fun rpcSystem(): String =
+        if (codegenContext.serviceShape.hasTrait<ServiceTrait>()) {
+            "aws-api"
+        } else {
+            "smithy"
+        }
+
+    /**
+     * The `rpc.service` of the operation spans, following the OpenTelemetry semantic conventions.
+     */
+    private fun rpcService(): String =
+        codegenContext.serviceShape.getTrait<ServiceTrait>()?.sdkId ?: codegenContext.serviceShape.id.name
+
+    private fun operationSpanName(operationShape: OperationShape): String = "${rpcService()}.${operationShape.id.name}"
+
     /**
      * Render the operation struct and its supporting code.
      */
@@ -130,7 +151,13 @@ open class OperationGenerator(
                             err.downcast::<#{OperationError}>().expect("correct error type")
                         })
                     };
-                    let context = Self::orchestrate_with_stop_point(runtime_plugins, input, #{StopPoint}::None)
+                    let span = #{debug_span}!(
+                        ${operationSpanName(operationShape).dq()},
+                        rpc.system = ${rpcSystem().dq()},
+                        rpc.service = ${rpcService().dq()},
+                        rpc.method = ${operationShape.id.name.dq()}
+                    );
+                    let context = #{Instrument}::instrument(Self::orchestrate_with_stop_point(runtime_plugins, input, #{StopPoint}::None), span)
                         .await
                         .map_err(map_err)?;
                     let output = context.finalize().map_err(map_err)?;
@@ -178,6 +205,8 @@ open class OperationGenerator(
                 "OrchestratorError" to
                     RuntimeType.smithyRuntimeApiClient(runtimeConfig)
                         .resolve("client::orchestrator::error::OrchestratorError"),
+                "Instrument" to RuntimeType.Tracing.resolve("Instrument"),
+                "debug_span" to RuntimeType.Tracing.resolve("debug_span"),
                 "RuntimePlugin" to RuntimeType.runtimePlugin(runtimeConfig),
                 "RuntimePlugins" to RuntimeType.runtimePlugins(runtimeConfig),
                 "StopPoint" to RuntimeType.smithyRuntime(runtimeConfig).resolve("client::orchestrator::StopPoint"),
@@ -15,8 +15,10 @@ import software.amazon.smithy.rust.codegen.client.smithy.traits.IsTruncatedPagin
 import software.amazon.smithy.rust.codegen.core.rustlang.InlineDependency
 import software.amazon.smithy.rust.codegen.core.rustlang.RustModule
 import software.amazon.smithy.rust.codegen.core.rustlang.RustType
+import software.amazon.smithy.rust.codegen.core.rustlang.RustWriter
 import software.amazon.smithy.rust.codegen.core.rustlang.Writable
 import software.amazon.smithy.rust.codegen.core.rustlang.render
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
 import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
 import software.amazon.smithy.rust.codegen.core.rustlang.stripOuter
 import software.amazon.smithy.rust.codegen.core.rustlang.writable
@@ -89,10 +91,16 @@ class PaginatorGenerator private constructor(
             generate(),
         )
 
+    private val pageSizeHandleType =
+        RuntimeType.smithyAsync(runtimeConfig).resolve("future::pagination_stream::PageSizeHandle")
+
     private val codegenScope =
         arrayOf(
             *preludeScope,
             "page_size_setter" to pageSizeSetter(),
+            "page_size_handle_field" to pageSizeHandle { rust("page_size_handle: #T<$it>,", pageSizeHandleType) },
+            "page_size_handle_init" to pageSizeHandle { rust("page_size_handle: #T::new(),", pageSizeHandleType) },
+            "apply_page_size" to applyPageSize(),
             // Operation Types
             "operation" to symbolProvider.toSymbol(operation),
             "Input" to inputType,
@@ -104,6 +112,8 @@ class PaginatorGenerator private constructor(
                 RuntimeType.smithyRuntimeApiClient(runtimeConfig)
                     .resolve("client::orchestrator::HttpResponse"),
             "SdkError" to RuntimeType.sdkError(runtimeConfig),
+            "AsyncSleep" to RuntimeType.smithyAsync(runtimeConfig).resolve("rt::sleep::AsyncSleep"),
+            "retry_after" to RuntimeType.smithyRuntime(runtimeConfig).resolve("client::retries::retry_after"),
             "pagination_stream" to RuntimeType.smithyAsync(runtimeConfig).resolve("future::pagination_stream"),
             // External Types
             "Stream" to RuntimeType.TokioStream.resolve("Stream"),
@@ -125,6 +135,8 @@ class PaginatorGenerator private constructor(
                     handle: std::sync::Arc<crate::client::Handle>,
                     builder: #{Builder},
                     stop_on_duplicate_token: bool,
+                    max_throttling_pauses: u32,
+                    #{page_size_handle_field:W}
                 }
 
                 impl $paginatorName {
@@ -134,6 +146,8 @@ class PaginatorGenerator private constructor(
                             handle,
                             builder,
                             stop_on_duplicate_token: true,
+                            max_throttling_pauses: 0,
+                            #{page_size_handle_init:W}
                         }
                     }
 
@@ -153,6 +167,19 @@ class PaginatorGenerator private constructor(
                         self
                     }
 
+                    /// Pause the stream when the service throttles a page request, instead of ending it with the error.
+                    ///
+                    /// Defaults to 0.
+                    ///
+                    /// When a page request fails with a response that has a `Retry-After` or `x-amz-retry-after` header,
+                    /// even after it was retried by the retry strategy, the paginator waits for the delay the service asked
+                    /// for, then requests the same page again. Up to `max_throttling_pauses` consecutive pauses are made before
+                    /// the error is returned. Pausing requires an async sleep implementation to be configured.
+                    pub fn max_throttling_pauses(mut self, max_throttling_pauses: u32) -> Self {
+                        self.max_throttling_pauses = max_throttling_pauses;
+                        self
+                    }
+
                     /// Create the pagination stream
                     ///
                     /// _Note:_ No requests will be dispatched until the stream is used
@@ -161,6 +188,7 @@ class PaginatorGenerator private constructor(
                         // Move individual fields out of self for the borrow checker
                         let builder = self.builder;
                         let handle = self.handle;
+                        let sleep_impl = handle.conf.sleep_impl();
                         #{runtime_plugin_init}
                         #{pagination_stream}::PaginationStream::new(#{pagination_stream}::fn_stream::FnStream::new(move |tx| #{Box}::pin(async move {
                             // Build the input for the first time. If required fields are missing, this is where we'll produce an early error.
@@ -168,8 +196,21 @@ class PaginatorGenerator private constructor(
                                 #{Ok}(input) => input,
                                 #{Err}(e) => { let _ = tx.send(#{Err}(e)).await; return; }
                             };
+                            let mut throttling_pauses = 0;
                             loop {
+                                #{apply_page_size:W}
                                 let resp = #{orchestrate};
+                                match (&resp, &sleep_impl) {
+                                    (#{Err}(err), #{Some}(sleep_impl)) if throttling_pauses < self.max_throttling_pauses => {
+                                        if let #{Some}(delay) = err.raw_response().and_then(#{retry_after}) {
+                                            throttling_pauses += 1;
+                                            #{AsyncSleep}::sleep(sleep_impl, delay).await;
+                                            continue;
+                                        }
+                                    }
+                                    (#{Ok}(_), _) => throttling_pauses = 0,
+                                    _ => {}
+                                }
                                 // If the input member is None or it was an error
                                 let done = match resp {
                                     #{Ok}(ref resp) => {
@@ -335,6 +376,30 @@ class PaginatorGenerator private constructor(
             }
         }
 
+    /** Renders [body] with the page size type if the page size is modeled */
+    private fun pageSizeHandle(body: RustWriter.(String) -> Unit) =
+        writable {
+            paginationInfo.pageSizeMember.orNull()?.also {
+                body(symbolProvider.toSymbol(it).rustType().stripOuter<RustType.Option>().render(true))
+            }
+        }
+
+    /** Applies the page size set through the page size handle to the next page request */
+    private fun applyPageSize() =
+        writable {
+            paginationInfo.pageSizeMember.orNull()?.also {
+                val memberName = symbolProvider.toMemberName(it)
+                rustTemplate(
+                    """
+                    if let #{Some}(page_size) = self.page_size_handle.take() {
+                        input.$memberName = #{Some}(page_size);
+                    }
+                    """,
+                    *preludeScope,
+                )
+            }
+        }
+
     private fun pageSizeSetter() =
         writable {
             paginationInfo.pageSizeMember.orNull()?.also {
@@ -350,8 +415,17 @@ class PaginatorGenerator private constructor(
                         self.builder.$memberName = #{Some}(limit);
                         self
                     }
+
+                    /// Returns a handle to change the page size while the pagination stream is being consumed
+                    ///
+                    /// A page size set through the handle applies to every page requested after it. The handle must be
+                    /// obtained before the paginator is consumed, e.g. by `send()`.
+                    pub fn page_size_handle(&self) -> #{PageSizeHandle}<$pageSizeT> {
+                        self.page_size_handle.clone()
+                    }
                     """,
                     *preludeScope,
+                    "PageSizeHandle" to pageSizeHandleType,
                 )
             }
         }
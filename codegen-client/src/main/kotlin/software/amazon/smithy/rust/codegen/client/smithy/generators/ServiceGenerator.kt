@@ -11,6 +11,7 @@ import software.amazon.smithy.rust.codegen.client.smithy.ClientRustModule
 import software.amazon.smithy.rust.codegen.client.smithy.customize.ClientCodegenDecorator
 import software.amazon.smithy.rust.codegen.client.smithy.customize.TestUtilFeature
 import software.amazon.smithy.rust.codegen.client.smithy.generators.config.ServiceConfigGenerator
+import software.amazon.smithy.rust.codegen.client.smithy.generators.error.ErrorCodeGenerator
 import software.amazon.smithy.rust.codegen.client.smithy.generators.error.ServiceErrorGenerator
 import software.amazon.smithy.rust.codegen.core.rustlang.Attribute
 import software.amazon.smithy.rust.codegen.core.smithy.RustCrate
@@ -39,6 +40,11 @@ class ServiceGenerator(
             operations,
             decorator.errorCustomizations(codegenContext, emptyList()),
         ).render(rustCrate)
+        ErrorCodeGenerator(
+            codegenContext,
+            operations,
+            decorator.additionalErrorCodes(codegenContext, emptyList()),
+        ).render(rustCrate)
 
         rustCrate.withModule(ClientRustModule.config) {
             val serviceConfigGenerator =
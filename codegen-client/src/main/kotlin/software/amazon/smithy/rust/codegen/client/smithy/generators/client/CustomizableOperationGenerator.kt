@@ -39,6 +39,7 @@ class CustomizableOperationGenerator(
                 "CustomizableSend" to
                     ClientRustModule.Client.customize.toType()
                         .resolve("internal::CustomizableSend"),
+                "Deadline" to RuntimeType.smithyTypes(runtimeConfig).resolve("timeout::Deadline"),
                 "HttpRequest" to
                     RuntimeType.smithyRuntimeApiClient(runtimeConfig)
                         .resolve("client::orchestrator::HttpRequest"),
@@ -49,6 +50,9 @@ class CustomizableOperationGenerator(
                 "MapRequestInterceptor" to
                     RuntimeType.smithyRuntime(runtimeConfig)
                         .resolve("client::interceptors::MapRequestInterceptor"),
+                "MapResponseBodyInterceptor" to
+                    RuntimeType.smithyRuntime(runtimeConfig)
+                        .resolve("client::interceptors::MapResponseBodyInterceptor"),
                 "MutateRequestInterceptor" to
                     RuntimeType.smithyRuntime(runtimeConfig)
                         .resolve("client::interceptors::MutateRequestInterceptor"),
@@ -63,6 +67,16 @@ class CustomizableOperationGenerator(
                 "SharedInterceptor" to
                     RuntimeType.smithyRuntimeApiClient(runtimeConfig)
                         .resolve("client::interceptors::SharedInterceptor"),
+                "Layer" to RuntimeType.smithyTypes(runtimeConfig).resolve("config_bag::Layer"),
+                "StaticRuntimePlugin" to
+                    RuntimeType.smithyRuntimeApiClient(runtimeConfig)
+                        .resolve("client::runtime_plugin::StaticRuntimePlugin"),
+                "TelemetryAttribute" to
+                    RuntimeType.smithyRuntimeApiClient(runtimeConfig)
+                        .resolve("client::telemetry::TelemetryAttribute"),
+                "TelemetryAttributesExt" to
+                    RuntimeType.smithyRuntimeApiClient(runtimeConfig)
+                        .resolve("client::telemetry::TelemetryAttributesExt"),
             )
 
         val customizeModule = ClientRustModule.Client.customize
@@ -109,7 +123,8 @@ class CustomizableOperationGenerator(
                     /// Adds an [interceptor](#{Intercept}) that runs at specific stages of the request execution pipeline.
                     ///
                     /// Note that interceptors can also be added to `CustomizableOperation` by `config_override`,
-                    /// `map_request`, and `mutate_request` (the last two are implemented via interceptors under the hood).
+                    /// `map_request`, `mutate_request`, and `map_response_body` (the last three are implemented via
+                    /// interceptors under the hood).
                     /// The order in which those user-specified operation interceptors are invoked should not be relied upon
                     /// as it is an implementation detail.
                     pub fn interceptor(mut self, interceptor: impl #{Intercept} + 'static) -> Self {
@@ -154,6 +169,57 @@ class CustomizableOperationGenerator(
                             self
                         }
 
+                    /// Wraps the operation's response body before it is deserialized.
+                    ///
+                    /// `f` is applied to the body of every response, including responses of streaming operations, before
+                    /// the body is read. Returning a body that wraps the given one, rather than reading it into memory,
+                    /// preserves streaming, so this can be used to tap the response body, e.g., to checksum it or scan
+                    /// it as it is read.
+                    pub fn map_response_body<F>(mut self, f: F) -> Self
+                    where
+                        F: #{Fn}(#{SdkBody}) -> #{SdkBody} + #{Send} + #{Sync} + 'static,
+                    {
+                        self.interceptors.push(
+                            #{SharedInterceptor}::new(
+                                #{MapResponseBodyInterceptor}::new(f),
+                            ),
+                        );
+                        self
+                    }
+
+                    /// Attaches a custom key/value attribute to this operation's telemetry.
+                    ///
+                    /// Attributes are recorded on the spans emitted for the operation, and interceptors can read them
+                    /// with [`TelemetryAttributesExt`](#{TelemetryAttributesExt}). If the same key is set more than once,
+                    /// the last value wins.
+                    pub fn telemetry_attribute(
+                        mut self,
+                        key: impl #{Into}<::std::borrow::Cow<'static, str>>,
+                        value: impl #{Into}<::std::borrow::Cow<'static, str>>,
+                    ) -> Self {
+                        let mut layer = #{Layer}::new("TelemetryAttribute");
+                        layer.store_append(#{TelemetryAttribute}::new(key, value));
+                        self.runtime_plugins.push(#{SharedRuntimePlugin}::new(
+                            #{StaticRuntimePlugin}::new().with_config(layer.freeze()),
+                        ));
+                        self
+                    }
+
+                    /// Sets an absolute deadline by which this operation must complete.
+                    ///
+                    /// The operation timeout and the timeout of every attempt are capped to the time remaining until
+                    /// the deadline, so a deadline received from an upstream caller can be propagated to this request.
+                    /// Once the deadline passes, the operation fails with a timeout error. The remaining time is
+                    /// measured with the client's time source, and enforced with its async sleep implementation.
+                    pub fn deadline(mut self, deadline: impl #{Into}<#{Deadline}>) -> Self {
+                        let mut layer = #{Layer}::new("Deadline");
+                        layer.store_put(deadline.into());
+                        self.runtime_plugins.push(#{SharedRuntimePlugin}::new(
+                            #{StaticRuntimePlugin}::new().with_config(layer.freeze()),
+                        ));
+                        self
+                    }
+
                     /// Overrides config for a single operation invocation.
                     ///
                     /// `config_override` is applied to the operation configuration level.
@@ -156,6 +156,25 @@ class FluentClientGenerator(
                         Ok(())
                     }
                 }
+
+                impl crate::Config {
+                    /// Returns a diagnostic report of the runtime components a client created from this config uses.
+                    ///
+                    /// The report includes the defaults that the client fills in. It names the HTTP client, the
+                    /// resolvers, the identity cache, the interceptors and the retry and time components, along
+                    /// with the runtime plugin that set each of them. Operations can still override some of these
+                    /// components with their own runtime plugins or config overrides.
+                    ///
+                    /// ## Panics
+                    ///
+                    /// This method will panic if no `behavior_version` is provided, like [`Client::from_conf`].
+                    pub fn runtime_components_report(&self) -> Result<#{RuntimeComponentsReport}, #{BoxError}> {
+                        let mut cfg = #{ConfigBag}::base();
+                        let runtime_components = #{base_client_runtime_plugins}(self.clone())
+                            .apply_client_configuration(&mut cfg)?;
+                        Ok(runtime_components.describe())
+                    }
+                }
                 """,
                 *preludeScope,
                 "Arc" to RuntimeType.Arc,
@@ -172,6 +191,9 @@ class FluentClientGenerator(
                         }
                     },
                 "ConfigBag" to RuntimeType.configBag(runtimeConfig),
+                "RuntimeComponentsReport" to
+                    RuntimeType.smithyRuntimeApiClient(runtimeConfig)
+                        .resolve("client::runtime_components::RuntimeComponentsReport"),
                 "RuntimePlugins" to RuntimeType.runtimePlugins(runtimeConfig),
                 "tracing" to CargoDependency.Tracing.toType(),
             )
@@ -27,11 +27,28 @@ class IdempotencyTokenProviderCustomization(codegenContext: ClientCodegenContext
 
     override fun section(section: ServiceConfig): Writable {
         return when (section) {
+            ServiceConfig.ConfigImpl ->
+                writable {
+                    rustTemplate(
+                        """
+                        /// Return a reference to the idempotency token provider contained in this config, if any.
+                        pub fn idempotency_token_provider(&self) -> #{Option}<&#{IdempotencyTokenProvider}> {
+                            self.config.load::<#{IdempotencyTokenProvider}>()
+                        }
+                        """,
+                        *codegenScope,
+                    )
+                }
+
             ServiceConfig.BuilderImpl ->
                 writable {
                     rustTemplate(
                         """
                         /// Sets the idempotency token provider to use for service calls that require tokens.
+                        ///
+                        /// Use `IdempotencyTokenProvider::with_seed` or `IdempotencyTokenProvider::sequential` for
+                        /// reproducible tokens in tests, or `IdempotencyTokenProvider::new` to plug in a custom
+                        /// token source.
                         pub fn idempotency_token_provider(mut self, idempotency_token_provider: impl #{Into}<#{IdempotencyTokenProvider}>) -> Self {
                             self.set_idempotency_token_provider(#{Some}(idempotency_token_provider.into()));
                             self
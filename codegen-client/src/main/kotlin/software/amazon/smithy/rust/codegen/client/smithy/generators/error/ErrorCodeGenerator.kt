@@ -0,0 +1,190 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.client.smithy.generators.error
+
+import software.amazon.smithy.codegen.core.CodegenException
+import software.amazon.smithy.model.shapes.OperationShape
+import software.amazon.smithy.rust.codegen.client.smithy.ClientCodegenContext
+import software.amazon.smithy.rust.codegen.client.smithy.ClientRustModule
+import software.amazon.smithy.rust.codegen.core.rustlang.RustModule
+import software.amazon.smithy.rust.codegen.core.rustlang.Visibility
+import software.amazon.smithy.rust.codegen.core.rustlang.docs
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
+import software.amazon.smithy.rust.codegen.core.rustlang.writable
+import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
+import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType.Companion.preludeScope
+import software.amazon.smithy.rust.codegen.core.smithy.RustCrate
+import software.amazon.smithy.rust.codegen.core.smithy.transformers.allErrors
+import software.amazon.smithy.rust.codegen.core.util.dq
+import software.amazon.smithy.rust.codegen.core.util.toPascalCase
+
+/**
+ * Generates a typed `ErrorCode` enum for a service so that errors can be matched without comparing strings:
+ * ```rust
+ * // s3/src/error.rs
+ * pub enum ErrorCode {
+ *     NoSuchBucket,
+ *     SlowDown,
+ *     ...,
+ *     Unknown(UnknownErrorCode),
+ * }
+ * ```
+ *
+ * Variants are derived from the error codes of the modeled errors, plus the [additionalCodes] that a service
+ * documents but does not model (e.g. S3's `SlowDown`). A `ProvideErrorCode` extension trait provides
+ * `code_enum()` for every type implementing `ProvideErrorMetadata`.
+ *
+ * Like the `Unknown` variant of [InfallibleEnumType][software.amazon.smithy.rust.codegen.client.smithy.generators.InfallibleEnumType]
+ * enums, `Unknown` holds an opaque value that users can't construct, so that matches on it don't silently
+ * stop matching once a code becomes known. Codes whose variant names would collide fail codegen.
+ */
+class ErrorCodeGenerator(
+    private val codegenContext: ClientCodegenContext,
+    private val operations: List<OperationShape>,
+    private val additionalCodes: List<String>,
+) {
+    private val codes: List<Pair<String, String>> by lazy {
+        val modeledCodes =
+            operations.flatMap { it.allErrors(codegenContext.model) }.map { error ->
+                codegenContext.protocolImpl?.httpBindingResolver?.errorCode(error) ?: error.id.name
+            }
+        (modeledCodes + additionalCodes)
+            .distinct()
+            .map { code -> code.toPascalCase() to code }
+            // `Unknown` is reserved for codes that aren't known to the SDK
+            .filterNot { (variant, _) -> variant == "Unknown" }
+            .groupBy({ (variant, _) -> variant }, { (_, code) -> code })
+            .map { (variant, codes) ->
+                if (codes.size > 1) {
+                    throw CodegenException(
+                        "Error codes ${codes.joinToString { it.dq() }} would all be named `ErrorCode::$variant`",
+                    )
+                }
+                variant to codes.single()
+            }
+            .sortedBy { (variant, _) -> variant }
+    }
+
+    private val unknownErrorCode: RuntimeType by lazy {
+        val module =
+            RustModule.new(
+                "sealed_enum_unknown",
+                visibility = Visibility.PUBCRATE,
+                parent = ClientRustModule.primitives,
+            )
+        RuntimeType.forInlineFun("UnknownErrorCode", module) {
+            docs(
+                """
+                Opaque struct used as inner data for the `Unknown` variant of `ErrorCode`.
+
+                This is not intended to be used directly.
+                """.trimIndent(),
+            )
+            rustTemplate(
+                """
+                ##[derive(#{Clone}, #{Debug}, #{PartialEq}, #{Eq}, Hash)]
+                pub struct UnknownErrorCode(pub(crate) #{String});
+                """,
+                *preludeScope,
+                "Debug" to RuntimeType.Debug,
+            )
+        }
+    }
+
+    fun render(crate: RustCrate) {
+        crate.withModule(ClientRustModule.Error) {
+            rustTemplate(
+                """
+                /// Error codes that can be returned by this service.
+                ///
+                /// Use [`ProvideErrorCode::code_enum`] to match on the code of an error without comparing strings.
+                /// Codes that this version of the SDK doesn't know about are represented by `ErrorCode::Unknown`.
+                /// Rather than matching on `Unknown`, match on [`ErrorCode::as_str`] in a catch-all arm, so that the
+                /// match keeps working when a later version of the SDK adds a variant for the code:
+                ///
+                /// ```text
+                /// match code {
+                ///     ErrorCode::SomeCode => { /* ... */ },
+                ///     other if other.as_str() == "NewCode" => { /* handles a code added later */ },
+                ///     _ => { /* ... */ },
+                /// }
+                /// ```
+                ##[non_exhaustive]
+                ##[derive(#{Clone}, #{Debug}, #{PartialEq}, #{Eq}, Hash)]
+                pub enum ErrorCode {
+                    #{variants}
+                    /// An error code that isn't known to this version of the SDK.
+                    ##[deprecated(note = "Don't directly match on `Unknown`. See the docs on this enum for the correct way to handle unknown error codes.")]
+                    Unknown(#{UnknownErrorCode}),
+                }
+
+                impl ErrorCode {
+                    /// Returns the error code as it appears on the wire.
+                    pub fn as_str(&self) -> &str {
+                        match self {
+                            #{as_str_arms}
+                            ##[allow(deprecated)]
+                            ErrorCode::Unknown(code) => code.0.as_str(),
+                        }
+                    }
+                }
+
+                impl #{From}<&str> for ErrorCode {
+                    fn from(code: &str) -> Self {
+                        match code {
+                            #{from_arms}
+                            ##[allow(deprecated)]
+                            other => ErrorCode::Unknown(#{UnknownErrorCode}(other.to_owned())),
+                        }
+                    }
+                }
+
+                impl #{Display} for ErrorCode {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        f.write_str(self.as_str())
+                    }
+                }
+
+                /// Provides the [`ErrorCode`] of an error returned by this service.
+                pub trait ProvideErrorCode {
+                    /// Returns the error code as an [`ErrorCode`], if one is available.
+                    fn code_enum(&self) -> #{Option}<ErrorCode>;
+                }
+
+                impl<T> ProvideErrorCode for T
+                where
+                    T: #{ProvideErrorMetadata} + ?Sized,
+                {
+                    fn code_enum(&self) -> #{Option}<ErrorCode> {
+                        #{ProvideErrorMetadata}::code(self).map(ErrorCode::from)
+                    }
+                }
+                """,
+                *preludeScope,
+                "Debug" to RuntimeType.Debug,
+                "Display" to RuntimeType.Display,
+                "ProvideErrorMetadata" to RuntimeType.provideErrorMetadataTrait(codegenContext.runtimeConfig),
+                "UnknownErrorCode" to unknownErrorCode,
+                "variants" to
+                    writable {
+                        codes.forEach { (variant, code) ->
+                            rust("/// The `$code` error code.")
+                            rust("$variant,")
+                        }
+                    },
+                "as_str_arms" to
+                    writable {
+                        codes.forEach { (variant, code) -> rust("ErrorCode::$variant => ${code.dq()},") }
+                    },
+                "from_arms" to
+                    writable {
+                        codes.forEach { (variant, code) -> rust("${code.dq()} => ErrorCode::$variant,") }
+                    },
+            )
+        }
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.client.smithy.customizations
+
+import io.kotest.matchers.shouldBe
+import io.kotest.matchers.string.shouldContain
+import org.junit.jupiter.api.Test
+import software.amazon.smithy.model.node.Node
+import software.amazon.smithy.model.node.ObjectNode
+import software.amazon.smithy.rust.codegen.client.testutil.clientIntegrationTest
+import software.amazon.smithy.rust.codegen.core.testutil.IntegrationTestParams
+import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
+import java.io.File
+
+class ApiReportDecoratorTest {
+    private val model =
+        """
+        namespace com.example
+        use aws.protocols#awsJson1_0
+        @awsJson1_0
+        service HelloService {
+            operations: [SayHello, SayGoodbye],
+            version: "1"
+        }
+
+        @optionalAuth
+        operation SayHello { input: TestInput, errors: [Rejected] }
+
+        @unstable
+        @optionalAuth
+        operation SayGoodbye {}
+
+        structure TestInput {
+           greeting: Greeting,
+           @deprecated
+           language: Language,
+        }
+
+        union Greeting {
+           message: String,
+           @unstable
+           emoji: String,
+        }
+
+        enum Language {
+           ENGLISH
+           @deprecated
+           KLINGON
+        }
+
+        @error("client")
+        structure Rejected {
+           reason: String,
+        }
+        """.asSmithyModel()
+
+    @Test
+    fun `generates an api report`() {
+        val params =
+            IntegrationTestParams(
+                additionalSettings =
+                    ObjectNode.builder().withMember(
+                        "codegen",
+                        ObjectNode.builder().withMember("generateApiReport", true).build(),
+                    ).build(),
+            )
+        var report: ApiReport? = null
+        val crateDir =
+            clientIntegrationTest(model, params) { codegenContext, _ ->
+                report = ApiReport.fromContext(codegenContext)
+            }
+        val items = report!!.items.associateBy { it.path }
+
+        items.getValue("crate::Client::say_hello").stability shouldBe ApiStability.STABLE
+        items.getValue("crate::Client::say_goodbye").stability shouldBe ApiStability.UNSTABLE
+        items.getValue("crate::operation::say_hello::SayHelloError").members.map { it.name } shouldBe listOf("Rejected")
+        items.getValue("crate::types::error::Rejected").kind shouldBe "error"
+
+        val input = items.getValue("crate::operation::say_hello::SayHelloInput")
+        input.kind shouldBe "struct"
+        input.members shouldBe
+            listOf(
+                ApiReport.Member(
+                    "greeting",
+                    "::std::option::Option<crate::types::Greeting>",
+                    ApiStability.STABLE,
+                ),
+                ApiReport.Member(
+                    "language",
+                    "::std::option::Option<crate::types::Language>",
+                    ApiStability.DEPRECATED,
+                ),
+            )
+        items.getValue("crate::types::Greeting").members.map { it.name to it.stability } shouldBe
+            listOf("Message" to ApiStability.STABLE, "Emoji" to ApiStability.UNSTABLE)
+        items.getValue("crate::types::Language").members.map { it.name to it.stability } shouldBe
+            listOf("English" to ApiStability.STABLE, "Klingon" to ApiStability.DEPRECATED)
+
+        Node.parse(File(crateDir.toFile(), "api.json").readText()) shouldBe report!!.toNode()
+        File(crateDir.toFile(), "API.md").readText() shouldContain
+            "| `crate::Client::say_goodbye` | operation | unstable |"
+    }
+}
@@ -0,0 +1,138 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.client.smithy.customizations
+
+import io.kotest.matchers.shouldBe
+import org.junit.jupiter.api.Test
+import org.junit.jupiter.api.assertThrows
+import software.amazon.smithy.codegen.core.CodegenException
+import software.amazon.smithy.model.node.Node
+import software.amazon.smithy.model.node.ObjectNode
+import software.amazon.smithy.rust.codegen.client.testutil.clientIntegrationTest
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.testutil.IntegrationTestParams
+import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
+import software.amazon.smithy.rust.codegen.core.testutil.testModule
+import software.amazon.smithy.rust.codegen.core.testutil.unitTest
+import java.nio.file.Files.createTempFile
+
+class CompatShimDecoratorTest {
+    private val model =
+        """
+        namespace com.example
+        use aws.protocols#awsJson1_0
+        @awsJson1_0
+        service HelloService {
+            operations: [SayHello],
+            version: "1"
+        }
+        @optionalAuth
+        operation SayHello { input: TestInput }
+
+        structure TestInput {
+           greeting: Greeting,
+        }
+
+        structure Greeting {
+           message: String,
+        }
+        """.asSmithyModel()
+
+    @Test
+    fun `parses the manifest`() {
+        val manifest =
+            CompatManifest.fromNode(
+                Node.parse(
+                    """
+                    {
+                        "previousVersion": "1.2.0",
+                        "renames": [
+                            { "old": "types::Salutation", "new": "crate::types::Greeting" }
+                        ]
+                    }
+                    """,
+                ).expectObjectNode(),
+            )
+        manifest shouldBe
+            CompatManifest(
+                "1.2.0",
+                listOf(CompatManifest.Rename(listOf("types", "Salutation"), listOf("types", "Greeting"))),
+            )
+    }
+
+    @Test
+    fun `rejects invalid manifests`() {
+        assertThrows<CodegenException> {
+            CompatManifest.fromNode(
+                Node.parse("""{ "renames": [{ "old": "types::Sa lutation", "new": "types::Greeting" }] }""")
+                    .expectObjectNode(),
+            )
+        }
+        assertThrows<CodegenException> {
+            CompatManifest.fromNode(
+                Node.parse(
+                    """
+                    {
+                        "renames": [
+                            { "old": "types::Salutation", "new": "types::Greeting" },
+                            { "old": "types::Salutation", "new": "types::Greeting2" }
+                        ]
+                    }
+                    """,
+                ).expectObjectNode(),
+            )
+        }
+    }
+
+    @Test
+    fun `generates deprecated aliases for renamed items`() {
+        val manifest = createTempFile("compat-manifest", ".json").toFile()
+        manifest.deleteOnExit()
+        manifest.writeText(
+            """
+            {
+                "previousVersion": "0.9.0",
+                "renames": [
+                    { "old": "types::Salutation", "new": "types::Greeting" },
+                    {
+                        "old": "operation::say_hello::builders::SayHelloRequestBuilder",
+                        "new": "operation::say_hello::builders::SayHelloFluentBuilder"
+                    }
+                ]
+            }
+            """,
+        )
+        val params =
+            IntegrationTestParams(
+                additionalSettings =
+                    ObjectNode.builder().withMember(
+                        "codegen",
+                        ObjectNode.builder().withMember("compatManifest", manifest.absolutePath).build(),
+                    ).build(),
+            )
+        clientIntegrationTest(model, params) { _, rustCrate ->
+            rustCrate.testModule {
+                unitTest("renamed_items_are_aliased") {
+                    rust(
+                        """
+                        ##[allow(deprecated)]
+                        let greeting: crate::compat::types::Salutation =
+                            crate::types::Greeting::builder().message("hello").build();
+                        assert_eq!(Some("hello"), greeting.message());
+
+                        ##[allow(deprecated)]
+                        fn _builder_is_aliased(
+                            builder: crate::compat::operation::say_hello::builders::SayHelloRequestBuilder,
+                        ) -> crate::operation::say_hello::builders::SayHelloFluentBuilder {
+                            builder
+                        }
+                        """,
+                    )
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.client.smithy.customizations
+
+import io.kotest.matchers.shouldBe
+import org.junit.jupiter.api.Test
+import org.junit.jupiter.api.assertThrows
+import software.amazon.smithy.codegen.core.CodegenException
+import software.amazon.smithy.model.node.Node
+import software.amazon.smithy.model.node.ObjectNode
+import software.amazon.smithy.model.shapes.ShapeId
+import software.amazon.smithy.rust.codegen.client.testutil.clientIntegrationTest
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.testutil.IntegrationTestParams
+import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
+import software.amazon.smithy.rust.codegen.core.testutil.testModule
+import software.amazon.smithy.rust.codegen.core.testutil.unitTest
+import java.nio.file.Files.createTempFile
+
+class ConvertDecoratorTest {
+    private fun model(
+        greetingMembers: String,
+        salutationMembers: String,
+    ) = """
+        namespace com.example
+        use aws.protocols#awsJson1_0
+        @awsJson1_0
+        service HelloService {
+            operations: [SayHello],
+            version: "1"
+        }
+        @optionalAuth
+        operation SayHello { input: TestInput }
+
+        structure TestInput {
+           card: Card,
+        }
+
+        structure Card {
+           greetings: Greetings,
+           salutation: Salutation,
+           languages: Languages,
+        }
+
+        list Greetings {
+           member: Greeting,
+        }
+
+        map Languages {
+           key: String,
+           value: Language,
+        }
+
+        structure Greeting {
+           $greetingMembers
+        }
+
+        union Salutation {
+           $salutationMembers
+        }
+
+        enum Language {
+           ENGLISH
+           SPANISH
+        }
+        """.asSmithyModel()
+
+    @Test
+    fun `parses the manifest`() {
+        val manifest =
+            ConvertManifest.fromNode(
+                Node.parse(
+                    """
+                    {
+                        "crate": "aws-sdk-example-v1",
+                        "version": "1.2.0",
+                        "shapes": [
+                            { "shape": "com.example#Greeting", "from": "types::Greeting", "newMembers": ["nickname"] }
+                        ]
+                    }
+                    """,
+                ).expectObjectNode(),
+            )
+        manifest shouldBe
+            ConvertManifest(
+                "aws-sdk-example-v1",
+                "1.2.0",
+                null,
+                mapOf(
+                    ShapeId.from("com.example#Greeting") to
+                        ConvertManifest.Conversion(listOf("types", "Greeting"), setOf("nickname")),
+                ),
+            )
+        manifest.dependency().toMap() shouldBe mapOf("version" to "1.2.0", "optional" to true)
+    }
+
+    @Test
+    fun `rejects invalid manifests`() {
+        assertThrows<CodegenException> {
+            ConvertManifest.fromNode(
+                Node.parse("""{ "crate": "v1", "shapes": [{ "shape": "com.example#Greeting", "from": "types::Greeting" }] }""")
+                    .expectObjectNode(),
+            )
+        }
+        assertThrows<CodegenException> {
+            ConvertManifest.fromNode(
+                Node.parse("""{ "crate": "v1", "path": "../v1", "shapes": [{ "shape": "com.example#Greeting", "from": "types::Gree ting" }] }""")
+                    .expectObjectNode(),
+            )
+        }
+        assertThrows<CodegenException> {
+            ConvertManifest.fromNode(
+                Node.parse(
+                    """
+                    {
+                        "crate": "v1",
+                        "path": "../v1",
+                        "shapes": [
+                            { "shape": "com.example#Greeting", "from": "types::Greeting" },
+                            { "shape": "com.example#Greeting", "from": "types::Salutation" }
+                        ]
+                    }
+                    """,
+                ).expectObjectNode(),
+            )
+        }
+    }
+
+    @Test
+    fun `rejects new members that can't be left unset`() {
+        val manifest = createTempFile("convert-manifest", ".json").toFile()
+        manifest.deleteOnExit()
+        val params =
+            IntegrationTestParams(
+                additionalSettings =
+                    ObjectNode.builder().withMember(
+                        "codegen",
+                        ObjectNode.builder().withMember("convertManifest", manifest.absolutePath).build(),
+                    ).build(),
+                command = {},
+            )
+        val model =
+            model(
+                greetingMembers = "message: String, @required nickname: String",
+                salutationMembers = "formal: String",
+            )
+        listOf("nickname", "emoji").forEach { newMember ->
+            manifest.writeText(
+                """
+                {
+                    "crate": "v1",
+                    "path": "../v1",
+                    "shapes": [
+                        { "shape": "com.example#Greeting", "from": "types::Greeting", "newMembers": ["$newMember"] }
+                    ]
+                }
+                """,
+            )
+            assertThrows<CodegenException> { clientIntegrationTest(model, params) }
+        }
+    }
+
+    @Test
+    fun `generates conversions from the types of another version`() {
+        val previousVersion =
+            clientIntegrationTest(
+                model(
+                    greetingMembers = "message: String, language: Language",
+                    salutationMembers = "formal: String, casual: Greeting",
+                ),
+                IntegrationTestParams(command = {}),
+            )
+        val dependencyName = previousVersion.fileName.toString()
+        val manifest = createTempFile("convert-manifest", ".json").toFile()
+        manifest.deleteOnExit()
+        manifest.writeText(
+            """
+            {
+                "crate": "test_$dependencyName",
+                "path": ${Node.printJson(Node.from(previousVersion.toString()))},
+                "shapes": [
+                    { "shape": "com.example#Card", "from": "types::Card" },
+                    { "shape": "com.example#Greeting", "from": "types::Greeting", "newMembers": ["nickname"] },
+                    { "shape": "com.example#Salutation", "from": "types::Salutation", "newMembers": ["emoji"] }
+                ]
+            }
+            """,
+        )
+        val params =
+            IntegrationTestParams(
+                additionalSettings =
+                    ObjectNode.builder().withMember(
+                        "codegen",
+                        ObjectNode.builder().withMember("convertManifest", manifest.absolutePath).build(),
+                    ).build(),
+                cargoCommand = "cargo test --lib --tests --features convert",
+            )
+        clientIntegrationTest(
+            model(
+                greetingMembers = "message: String, language: Language, nickname: String",
+                salutationMembers = "formal: String, casual: Greeting, emoji: String",
+            ),
+            params,
+        ) { _, rustCrate ->
+            rustCrate.testModule {
+                unitTest("converts_from_the_previous_version") {
+                    rust(
+                        """
+                        use ::${dependencyName.replace('-', '_')}::types as previous;
+
+                        let card = previous::Card::builder()
+                            .greetings(
+                                previous::Greeting::builder()
+                                    .message("hello")
+                                    .language(previous::Language::English)
+                                    .build(),
+                            )
+                            .salutation(previous::Salutation::Casual(
+                                previous::Greeting::builder().message("hey").build(),
+                            ))
+                            .languages("greeting", previous::Language::Spanish)
+                            .build();
+
+                        let card = crate::types::Card::from(card);
+                        let greeting = &card.greetings.as_ref().unwrap()[0];
+                        assert_eq!(Some("hello"), greeting.message());
+                        assert_eq!(Some(&crate::types::Language::English), greeting.language());
+                        assert_eq!(None, greeting.nickname());
+                        let salutation = card.salutation.as_ref().unwrap().as_casual().unwrap();
+                        assert_eq!(Some("hey"), salutation.message());
+                        assert_eq!(
+                            Some(&crate::types::Language::Spanish),
+                            card.languages.as_ref().unwrap().get("greeting"),
+                        );
+                        """,
+                    )
+                }
+            }
+        }
+    }
+}
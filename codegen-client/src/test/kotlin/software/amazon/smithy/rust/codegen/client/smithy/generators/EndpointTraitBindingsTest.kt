@@ -224,6 +224,11 @@ internal class EndpointTraitBindingsTest {
                             "expected '{}' to contain 'endpoint prefix could not be built'",
                             err_fmt
                         );
+                        assert!(
+                            err_fmt.contains("greeting contains invalid character ' ' at byte offset 3"),
+                            "expected '{}' to name the invalid character",
+                            err_fmt
+                        );
 
                         assert!(
                             interceptor.called.load(Ordering::Relaxed) == 0,
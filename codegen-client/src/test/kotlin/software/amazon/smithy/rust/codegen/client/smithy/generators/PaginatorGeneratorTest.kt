@@ -12,7 +12,10 @@ import software.amazon.smithy.model.transform.ModelTransformer
 import software.amazon.smithy.rust.codegen.client.smithy.traits.IsTruncatedPaginatorTrait
 import software.amazon.smithy.rust.codegen.client.testutil.clientIntegrationTest
 import software.amazon.smithy.rust.codegen.core.rustlang.Attribute
+import software.amazon.smithy.rust.codegen.core.rustlang.CargoDependency
 import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
+import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
 import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
 import software.amazon.smithy.rust.codegen.core.testutil.integrationTest
 import software.amazon.smithy.rust.codegen.core.util.letIf
@@ -103,4 +106,83 @@ internal class PaginatorGeneratorTest {
             }
         }
     }
+
+    @Test
+    fun `paginators pause on throttling and adjust the page size mid-stream`() {
+        clientIntegrationTest(model) { clientCodegenContext, rustCrate ->
+            val rc = clientCodegenContext.runtimeConfig
+            rustCrate.integrationTest("paginator_throttling_and_page_size") {
+                rustTemplate(
+                    """
+                    use ${clientCodegenContext.moduleUseName()}::{Client, Config};
+                    use std::collections::VecDeque;
+                    use std::sync::{Arc, Mutex};
+                    use std::time::{Duration, UNIX_EPOCH};
+
+                    ##[#{tokio}::test]
+                    async fn paginator_throttling_and_page_size() {
+                        let requests = Arc::new(Mutex::new(Vec::new()));
+                        let responses = Arc::new(Mutex::new(VecDeque::from(vec![
+                            (200, None, r##"{"inner":{"token":"a","items":["x"]}}"##),
+                            (429, Some("2"), r##"{"__type":"ThrottlingException"}"##),
+                            (200, None, r##"{"inner":{"token":"","items":["y"]}}"##),
+                        ])));
+                        let http_client = {
+                            let requests = requests.clone();
+                            #{infallible_client_fn}(move |request: http::Request<#{SdkBody}>| {
+                                let body = std::str::from_utf8(request.body().bytes().unwrap()).unwrap().to_string();
+                                requests.lock().unwrap().push(body);
+                                let (status, retry_after, body) = responses.lock().unwrap().pop_front().unwrap();
+                                let mut response = http::Response::builder().status(status);
+                                if let Some(retry_after) = retry_after {
+                                    response = response.header("retry-after", retry_after);
+                                }
+                                response.body(#{SdkBody}::from(body)).unwrap()
+                            })
+                        };
+                        let (_time_source, sleep_impl) = #{instant_time_and_sleep}(UNIX_EPOCH);
+                        let config = Config::builder()
+                            .http_client(http_client)
+                            .sleep_impl(sleep_impl.clone())
+                            .retry_config(#{RetryConfig}::disabled())
+                            .endpoint_url("http://localhost:1234")
+                            .build();
+                        let client = Client::from_conf(config);
+
+                        let paginator = client
+                            .paginated_list()
+                            .into_paginator()
+                            .page_size(10)
+                            .max_throttling_pauses(1);
+                        let page_size = paginator.page_size_handle();
+                        let mut items = paginator.items().send();
+                        assert_eq!("x", items.next().await.unwrap().unwrap());
+                        page_size.set(5);
+                        assert_eq!("y", items.next().await.unwrap().unwrap());
+                        assert!(items.next().await.is_none());
+
+                        assert_eq!(
+                            vec![
+                                r##"{"maxResults":10}"##,
+                                r##"{"maxResults":5,"nextToken":"a"}"##,
+                                r##"{"maxResults":5,"nextToken":"a"}"##,
+                            ],
+                            *requests.lock().unwrap()
+                        );
+                        assert_eq!(vec![Duration::from_secs(2)], sleep_impl.logs());
+                    }
+                    """,
+                    "infallible_client_fn" to
+                        CargoDependency.smithyRuntimeTestUtil(rc).toType()
+                            .resolve("client::http::test_util::infallible_client_fn"),
+                    "instant_time_and_sleep" to
+                        CargoDependency.smithyAsync(rc).withFeature("test-util").toType()
+                            .resolve("test_util::instant_time_and_sleep"),
+                    "RetryConfig" to RuntimeType.smithyTypes(rc).resolve("retry::RetryConfig"),
+                    "SdkBody" to RuntimeType.sdkBody(rc),
+                    "tokio" to CargoDependency.Tokio.toType(),
+                )
+            }
+        }
+    }
 }
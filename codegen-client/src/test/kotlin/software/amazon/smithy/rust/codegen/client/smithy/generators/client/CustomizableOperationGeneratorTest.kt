@@ -10,6 +10,7 @@ import software.amazon.smithy.rust.codegen.client.smithy.ClientCodegenContext
 import software.amazon.smithy.rust.codegen.client.testutil.clientIntegrationTest
 import software.amazon.smithy.rust.codegen.core.rustlang.CargoDependency
 import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
+import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
 import software.amazon.smithy.rust.codegen.core.smithy.RustCrate
 import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
 import software.amazon.smithy.rust.codegen.core.testutil.integrationTest
@@ -60,4 +61,43 @@ class CustomizableOperationGeneratorTest {
         }
         clientIntegrationTest(model, test = test)
     }
+
+    @Test
+    fun `operations fail with a timeout error once their deadline passes`() {
+        val test: (ClientCodegenContext, RustCrate) -> Unit = { codegenContext, rustCrate ->
+            rustCrate.integrationTest("customizable_operation_deadline") {
+                val moduleName = codegenContext.moduleUseName()
+                rustTemplate(
+                    """
+                    ##[#{tokio}::test]
+                    async fn test() {
+                        let config = $moduleName::Config::builder()
+                            .http_client(#{NeverClient}::new())
+                            .sleep_impl(#{TokioSleep}::new())
+                            .endpoint_url("http://localhost:1234")
+                            .build();
+                        let client = $moduleName::Client::from_conf(config);
+                        let err = client
+                            .say_hello()
+                            .customize()
+                            .deadline(::std::time::SystemTime::now() + ::std::time::Duration::from_millis(100))
+                            .send()
+                            .await
+                            .expect_err("the client never responds");
+                        assert!(matches!(err, #{SdkError}::TimeoutError(_)), "{err:?}");
+                    }
+                    """,
+                    "NeverClient" to
+                        CargoDependency.smithyRuntimeTestUtil(codegenContext.runtimeConfig).toType()
+                            .resolve("client::http::test_util::NeverClient"),
+                    "SdkError" to RuntimeType.sdkError(codegenContext.runtimeConfig),
+                    "TokioSleep" to
+                        CargoDependency.smithyAsync(codegenContext.runtimeConfig).withFeature("rt-tokio").toType()
+                            .resolve("rt::sleep::TokioSleep"),
+                    "tokio" to CargoDependency.Tokio.toType(),
+                )
+            }
+        }
+        clientIntegrationTest(model, test = test)
+    }
 }
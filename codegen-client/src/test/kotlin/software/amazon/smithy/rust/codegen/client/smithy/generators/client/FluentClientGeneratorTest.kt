@@ -12,6 +12,7 @@ import software.amazon.smithy.rust.codegen.client.testutil.clientIntegrationTest
 import software.amazon.smithy.rust.codegen.client.testutil.testSymbolProvider
 import software.amazon.smithy.rust.codegen.core.rustlang.CargoDependency
 import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
+import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
 import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
 import software.amazon.smithy.rust.codegen.core.testutil.integrationTest
 import software.amazon.smithy.rust.codegen.core.util.lookup
@@ -94,6 +95,37 @@ class FluentClientGeneratorTest {
         }
     }
 
+    @Test
+    fun `send() opens an operation span with rpc attributes`() {
+        clientIntegrationTest(model) { codegenContext, rustCrate ->
+            rustCrate.integrationTest("operation_span") {
+                val moduleName = codegenContext.moduleUseName()
+                rustTemplate(
+                    """
+                    ##[#{tokio}::test]
+                    ##[#{traced_test}]
+                    async fn test() {
+                        let (http_client, _rx) = #{capture_request}(None);
+                        let config = $moduleName::Config::builder()
+                            .endpoint_url("http://localhost:1234")
+                            .http_client(http_client)
+                            .build();
+                        let client = $moduleName::Client::from_conf(config);
+                        let _ = client.say_hello().send().await;
+                        assert!(logs_contain("HelloService.SayHello{"));
+                        assert!(logs_contain("rpc.system=\"smithy\""));
+                        assert!(logs_contain("rpc.service=\"HelloService\""));
+                        assert!(logs_contain("rpc.method=\"SayHello\""));
+                    }
+                    """,
+                    "capture_request" to RuntimeType.captureRequest(codegenContext.runtimeConfig),
+                    "tokio" to CargoDependency.Tokio.toType(),
+                    "traced_test" to CargoDependency.TracingTest.toType().resolve("traced_test"),
+                )
+            }
+        }
+    }
+
     @Test
     fun `generate inner builders`() {
         clientIntegrationTest(model) { codegenContext, rustCrate ->
@@ -123,6 +155,36 @@ class FluentClientGeneratorTest {
         }
     }
 
+    @Test
+    fun `config reports the runtime components in effect`() {
+        clientIntegrationTest(model) { codegenContext, rustCrate ->
+            rustCrate.integrationTest("runtime_components_report") {
+                val moduleName = codegenContext.moduleUseName()
+                rustTemplate(
+                    """
+                    ##[test]
+                    fn test() {
+                        let config = $moduleName::Config::builder()
+                            .endpoint_url("http://localhost:1234")
+                            .http_client(#{NeverClient}::new())
+                            .build();
+                        let client = $moduleName::Client::from_conf(config);
+
+                        let report = client.config().runtime_components_report().unwrap();
+                        assert_eq!("NeverClient", report.http_client().unwrap().name());
+                        assert!(report.retry_strategy().is_some());
+                        assert!(report.sleep_impl().is_some());
+                        assert!(report.to_string().contains("http_client: NeverClient (set by `"));
+                    }
+                    """,
+                    "NeverClient" to
+                        CargoDependency.smithyRuntimeTestUtil(codegenContext.runtimeConfig).toType()
+                            .resolve("client::http::test_util::NeverClient"),
+                )
+            }
+        }
+    }
+
     @Test
     fun `dead-code warning should not be issued when a service has no operations`() {
         val model =
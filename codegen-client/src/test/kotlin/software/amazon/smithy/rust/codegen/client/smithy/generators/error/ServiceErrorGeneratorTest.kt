@@ -5,10 +5,16 @@
 
 package software.amazon.smithy.rust.codegen.client.smithy.generators.error
 
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.string.shouldContain
 import org.junit.jupiter.api.Test
+import software.amazon.smithy.codegen.core.CodegenException
+import software.amazon.smithy.model.knowledge.TopDownIndex
 import software.amazon.smithy.model.shapes.StructureShape
 import software.amazon.smithy.rust.codegen.client.testutil.clientIntegrationTest
+import software.amazon.smithy.rust.codegen.client.testutil.testClientCodegenContext
 import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.testutil.TestWorkspace
 import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
 import software.amazon.smithy.rust.codegen.core.testutil.integrationTest
 import software.amazon.smithy.rust.codegen.core.testutil.unitTest
@@ -111,4 +117,50 @@ internal class ServiceErrorGeneratorTest {
             }
         }
     }
+
+    @Test
+    fun `provides typed error codes`() {
+        clientIntegrationTest(model) { _, rustCrate ->
+            rustCrate.moduleFor(model.lookup<StructureShape>("com.example#CanYouRepeatThat")) {
+                unitTest(
+                    name = "provides_typed_error_codes",
+                    test = """
+                        use crate::Error;
+                        use crate::error::{ErrorCode, ErrorMetadata, ProvideErrorCode};
+                        use crate::operation::say_hello::SayHelloError;
+                        use crate::types::error::*;
+
+                        let error = Error::from(SayHelloError::SorryBusy(
+                            SorryBusy::builder()
+                                .meta(ErrorMetadata::builder().code("SorryBusy").build())
+                                .build()
+                        ));
+                        assert_eq!(Some(ErrorCode::SorryBusy), error.code_enum());
+
+                        let error = SayHelloError::generic(ErrorMetadata::builder().code("SlowDown").build());
+                        let code = error.code_enum().unwrap();
+                        #[allow(deprecated)]
+                        let is_unknown = matches!(code, ErrorCode::Unknown(_));
+                        assert!(is_unknown);
+                        assert_eq!("SlowDown", code.as_str());
+
+                        let error = SayHelloError::generic(ErrorMetadata::builder().build());
+                        assert_eq!(None, error.code_enum());
+                    """,
+                )
+            }
+        }
+    }
+
+    @Test
+    fun `error codes with colliding variant names fail codegen`() {
+        val context = testClientCodegenContext(model)
+        val operations = TopDownIndex.of(model).getContainedOperations(context.serviceShape).toList()
+        val error =
+            shouldThrow<CodegenException> {
+                ErrorCodeGenerator(context, operations, listOf("SlowDown", "slowDown"))
+                    .render(TestWorkspace.testProject(context.symbolProvider))
+            }
+        error.message shouldContain "ErrorCode::SlowDown"
+    }
 }
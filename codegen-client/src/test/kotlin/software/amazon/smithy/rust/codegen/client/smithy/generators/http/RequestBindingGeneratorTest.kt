@@ -284,7 +284,7 @@ class RequestBindingGeneratorTest {
                         .prefix("valid-key".to_string(), "\n can't put a newline in a header value".to_string())
                         .build().unwrap();
                     let err = inp.test_request_builder_base().expect_err("can't make a header with a newline");
-                    assert_eq!(format!("{}", err), "invalid field in input: prefix (details: `\n can\'t put a newline in a header value` cannot be used as a header value: failed to parse header value)");
+                    assert_eq!(format!("{}", err), "invalid field in input: prefix (details: `\n can\'t put a newline in a header value` cannot be used as a header value: invalid character '\\n' (U+000A) at byte offset 0)");
                 """,
             )
 
@@ -300,7 +300,7 @@ class RequestBindingGeneratorTest {
                         .build().unwrap();
                     let err = inp.test_request_builder_base().expect_err("can't make a header with a newline");
                     // make sure we obey the sensitive trait
-                    assert_eq!(format!("{}", err), "invalid field in input: string_header (details: `*** Sensitive Data Redacted ***` cannot be used as a header value: failed to parse header value)");
+                    assert_eq!(format!("{}", err), "invalid field in input: string_header (details: `*** Sensitive Data Redacted ***` cannot be used as a header value: invalid character '\\n' (U+000A) at byte offset 0)");
                 """,
             )
 
@@ -25,6 +25,7 @@ import software.amazon.smithy.model.shapes.Shape
 import software.amazon.smithy.model.shapes.ShapeId
 import software.amazon.smithy.model.traits.DeprecatedTrait
 import software.amazon.smithy.model.traits.DocumentationTrait
+import software.amazon.smithy.model.traits.ExternalDocumentationTrait
 import software.amazon.smithy.rust.codegen.core.rustlang.Attribute.Companion.deprecated
 import software.amazon.smithy.rust.codegen.core.smithy.Default
 import software.amazon.smithy.rust.codegen.core.smithy.ModuleDocProvider
@@ -306,6 +307,24 @@ fun <T : AbstractCodeWriter<T>> T.documentShape(
     return docsOrFallback(docTrait?.value, autoSuppressMissingDocs, note)
 }
 
+/**
+ * Generate a RustDoc list of the links in [shape]'s `@externalDocumentation` trait, if it has one.
+ */
+fun <T : AbstractCodeWriter<T>> T.externalDocumentation(
+    shape: Shape,
+    model: Model,
+): T {
+    val urls = shape.getMemberTrait(model, ExternalDocumentationTrait::class.java).orNull()?.urls
+    if (urls.isNullOrEmpty()) {
+        return this
+    }
+    // Add a blank line between the docs and the links to visually differentiate
+    write("///")
+    docs("_See also:_")
+    urls.forEach { (name, url) -> docs(escape("- [$name]($url)")) }
+    return this
+}
+
 fun <T : AbstractCodeWriter<T>> T.docsOrFallback(
     docString: String? = null,
     autoSuppressMissingDocs: Boolean = true,
@@ -623,6 +642,7 @@ class RustWriter private constructor(
                             )
 
                         fileName == "package.json" -> rawWriter(fileName, debugMode = debugMode)
+                        fileName == "api.json" -> rawWriter(fileName, debugMode = debugMode)
                         fileName == "stubgen.sh" -> rawWriter(fileName, debugMode = debugMode)
                         else -> RustWriter(fileName, namespace, debugMode = debugMode)
                     }
@@ -130,6 +130,7 @@ class HttpBindingGenerator(
     private val model = codegenContext.model
     private val index = HttpBindingIndex.of(model)
     private val headerUtil = RuntimeType.smithyHttp(runtimeConfig).resolve("header")
+    private val validateHeaderValue = headerUtil.resolve("validate_header_value")
     private val defaultTimestampFormat = TimestampFormatTrait.Format.EPOCH_SECONDS
     private val protocolFunctions = ProtocolFunctions(codegenContext)
     private val serializerUtil = SerializerUtil(model, symbolProvider)
@@ -698,6 +699,9 @@ class HttpBindingGenerator(
                 rustTemplate(
                     """
                     let header_value = $safeName;
+                    #{validate_header_value}(&header_value).map_err(|err| {
+                        #{invalid_field_error:W}
+                    })?;
                     let header_value: #{HeaderValue} = header_value.parse().map_err(|err| {
                         #{invalid_field_error:W}
                     })?;
@@ -705,6 +709,7 @@ class HttpBindingGenerator(
 
                     """,
                     "HeaderValue" to RuntimeType.Http.resolve("HeaderValue"),
+                    "validate_header_value" to validateHeaderValue,
                     "invalid_field_error" to renderErrorMessage("header_value"),
                 )
             }
@@ -752,6 +757,9 @@ class HttpBindingGenerator(
                         isMultiValuedHeader = false,
                     )
                 };
+                    #{validate_header_value}(&header_value).map_err(|err| {
+                        #{invalid_header_value:W}
+                    })?;
                     let header_value: #{HeaderValue} = header_value.parse().map_err(|err| {
                         #{invalid_header_value:W}
                     })?;
@@ -760,6 +768,7 @@ class HttpBindingGenerator(
 
                 """,
                 "HeaderValue" to RuntimeType.Http.resolve("HeaderValue"),
+                "validate_header_value" to validateHeaderValue,
                 "invalid_header_name" to
                     OperationBuildError(runtimeConfig).invalidField(memberName) {
                         rust("""format!("`{k}` cannot be used as a header name: {err}")""")
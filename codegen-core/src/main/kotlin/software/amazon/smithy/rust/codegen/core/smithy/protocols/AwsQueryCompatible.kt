@@ -53,6 +53,7 @@ class AwsQueryCompatible(
         arrayOf(
             "Bytes" to RuntimeType.Bytes,
             "ErrorMetadataBuilder" to RuntimeType.errorMetadataBuilder(runtimeConfig),
+            "ErrorMetadataKeys" to RuntimeType.smithyTypes(runtimeConfig).resolve("error::metadata::keys"),
             "Headers" to RuntimeType.headers(runtimeConfig),
             "JsonError" to
                 CargoDependency.smithyJson(runtimeConfig).toType()
@@ -84,7 +85,7 @@ class AwsQueryCompatible(
                         #{aws_query_compatible_errors}::parse_aws_query_compatible_error(response_headers)
                     {
                         builder = builder.code(error_code);
-                        builder = builder.custom("type", error_type);
+                        builder = builder.custom(#{ErrorMetadataKeys}::ERROR_TYPE, error_type);
                     }
                     Ok(builder)
                 }
@@ -110,6 +110,11 @@ class ServerAdditionalSettings private constructor(settings: List<AdditionalSett
                 return this
             }
 
+            fun strictAcceptHeaderNegotiation(enabled: Boolean = true): Builder {
+                settings.add(StrictAcceptHeaderNegotiation(enabled))
+                return this
+            }
+
             override fun build(): ServerAdditionalSettings = ServerAdditionalSettings(settings)
         }
 
@@ -127,6 +132,13 @@ class ServerAdditionalSettings private constructor(settings: List<AdditionalSett
                     .build()
         }
 
+        private data class StrictAcceptHeaderNegotiation(val enabled: Boolean) : AdditionalSettings() {
+            override fun toObjectNode(): ObjectNode =
+                ObjectNode.builder()
+                    .withMember("strictAcceptHeaderNegotiation", enabled)
+                    .build()
+        }
+
         companion object {
             fun builder() = Builder()
         }
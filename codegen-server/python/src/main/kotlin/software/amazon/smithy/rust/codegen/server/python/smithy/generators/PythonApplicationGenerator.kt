@@ -256,6 +256,7 @@ class PythonApplicationGenerator(
             val middlewareNext = PythonType.Callable(listOf(middlewareRequest), PythonType.Awaitable(middlewareResponse))
             val middlewareFunc = PythonType.Callable(listOf(middlewareRequest, middlewareNext), PythonType.Awaitable(middlewareResponse))
             val tlsConfig = PythonType.Opaque("TlsConfig", libName, rustNamespace = "crate::tls")
+            val workerConfig = PythonType.Opaque("WorkerConfig", libName, rustNamespace = "crate::worker")
 
             rustTemplate(
                 """
@@ -297,8 +298,10 @@ class PythonApplicationGenerator(
                 /// :param backlog ${PythonType.Optional(PythonType.Int).renderAsDocstring()}:
                 /// :param workers ${PythonType.Optional(PythonType.Int).renderAsDocstring()}:
                 /// :param tls ${PythonType.Optional(tlsConfig).renderAsDocstring()}:
+                /// :param worker_config ${PythonType.Optional(workerConfig).renderAsDocstring()}:
                 /// :rtype ${PythonType.None.renderAsDocstring()}:
-                ##[pyo3(text_signature = "(${'$'}self, address=None, port=None, backlog=None, workers=None, tls=None)")]
+                ##[pyo3(text_signature = "(${'$'}self, address=None, port=None, backlog=None, workers=None, tls=None, worker_config=None)")]
+                ##[allow(clippy::too_many_arguments)]
                 pub fn run(
                     &mut self,
                     py: #{pyo3}::Python,
@@ -307,9 +310,10 @@ class PythonApplicationGenerator(
                     backlog: Option<i32>,
                     workers: Option<usize>,
                     tls: Option<#{SmithyPython}::tls::PyTlsConfig>,
+                    worker_config: Option<#{SmithyPython}::worker::PyWorkerConfig>,
                 ) -> #{pyo3}::PyResult<()> {
                     use #{SmithyPython}::PyApp;
-                    self.run_server(py, address, port, backlog, workers, tls)
+                    self.run_server(py, address, port, backlog, workers, tls, worker_config)
                 }
 
                 /// Lambda entrypoint: start the server on Lambda.
@@ -325,18 +329,20 @@ class PythonApplicationGenerator(
                 }
 
                 /// Build the service and start a single worker.
-                ##[pyo3(text_signature = "(${'$'}self, socket, worker_number, tls=None)")]
+                ##[pyo3(text_signature = "(${'$'}self, socket, worker_number, tls=None, worker_config=None)")]
                 pub fn start_worker(
                     &mut self,
                     py: pyo3::Python,
                     socket: &pyo3::PyCell<#{SmithyPython}::PySocket>,
                     worker_number: isize,
                     tls: Option<#{SmithyPython}::tls::PyTlsConfig>,
+                    worker_config: Option<#{SmithyPython}::worker::PyWorkerConfig>,
                 ) -> pyo3::PyResult<()> {
                     use #{SmithyPython}::PyApp;
-                    let event_loop = self.configure_python_event_loop(py)?;
+                    let worker_config = worker_config.unwrap_or_default();
+                    let event_loop = self.configure_python_event_loop(py, &worker_config)?;
                     let service = self.build_and_configure_service(py, event_loop)?;
-                    self.start_hyper_worker(py, socket, event_loop, service, worker_number, tls)
+                    self.start_hyper_worker(py, socket, event_loop, service, worker_number, tls, worker_config)
                 }
                 """,
                 *codegenScope,
@@ -52,6 +52,7 @@ class PythonServerModuleGenerator(
                 renderPyLogging()
                 renderPyMiddlewareTypes()
                 renderPyTlsTypes()
+                renderPyWorkerTypes()
                 renderPyLambdaTypes()
                 renderPyApplicationType()
                 renderCodegenVersion()
@@ -213,6 +214,22 @@ class PythonServerModuleGenerator(
         )
     }
 
+    private fun RustWriter.renderPyWorkerTypes() {
+        rustTemplate(
+            """
+            let worker = #{pyo3}::types::PyModule::new(py, "worker")?;
+            worker.add_class::<#{SmithyPython}::worker::PyWorkerConfig>()?;
+            pyo3::py_run!(
+                py,
+                worker,
+                "import sys; sys.modules['$libName.worker'] = worker"
+            );
+            m.add_submodule(worker)?;
+            """,
+            *codegenScope,
+        )
+    }
+
     private fun RustWriter.renderPyLambdaTypes() {
         rustTemplate(
             """
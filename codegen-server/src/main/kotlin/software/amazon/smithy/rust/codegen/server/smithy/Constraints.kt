@@ -25,6 +25,7 @@ import software.amazon.smithy.model.traits.LengthTrait
 import software.amazon.smithy.model.traits.PatternTrait
 import software.amazon.smithy.model.traits.RangeTrait
 import software.amazon.smithy.model.traits.RequiredTrait
+import software.amazon.smithy.model.traits.StreamingTrait
 import software.amazon.smithy.model.traits.UniqueItemsTrait
 import software.amazon.smithy.rust.codegen.core.rustlang.RustModule
 import software.amazon.smithy.rust.codegen.core.rustlang.RustReservedWords
@@ -101,7 +102,8 @@ fun Shape.isDirectlyConstrained(symbolProvider: SymbolProvider): Boolean =
         is StringShape -> this.hasTrait<EnumTrait>() || supportedStringConstraintTraits.any { this.hasTrait(it) }
         is CollectionShape -> supportedCollectionConstraintTraits.any { this.hasTrait(it) }
         is IntegerShape, is ShortShape, is LongShape, is ByteShape -> this.hasTrait<RangeTrait>()
-        is BlobShape -> this.hasTrait<LengthTrait>()
+        // The `@length` of streaming blobs is enforced on the request body by the protocol, not by a constrained type.
+        is BlobShape -> this.hasTrait<LengthTrait>() && !this.hasTrait<StreamingTrait>()
         else -> false
     }
 
@@ -88,6 +88,7 @@ import software.amazon.smithy.rust.codegen.server.smithy.protocols.ServerProtoco
 import software.amazon.smithy.rust.codegen.server.smithy.traits.isReachableFromOperationInput
 import software.amazon.smithy.rust.codegen.server.smithy.transformers.AttachValidationExceptionToConstrainedOperationInputs
 import software.amazon.smithy.rust.codegen.server.smithy.transformers.ConstrainedMemberTransform
+import software.amazon.smithy.rust.codegen.server.smithy.transformers.DocumentSyntheticInputsAndOutputs
 import software.amazon.smithy.rust.codegen.server.smithy.transformers.RecursiveConstraintViolationBoxer
 import software.amazon.smithy.rust.codegen.server.smithy.transformers.RemoveEbsModelValidationException
 import software.amazon.smithy.rust.codegen.server.smithy.transformers.ServerProtocolBasedTransformationFactory
@@ -199,6 +200,8 @@ open class ServerCodegenVisitor(
             .let(RecursiveConstraintViolationBoxer::transform)
             // Normalize operations by adding synthetic input and output shapes to every operation
             .let(OperationNormalizer::transform)
+            // Document the synthetic input and output shapes that don't have model documentation
+            .let(DocumentSyntheticInputsAndOutputs::transform)
             // Transforms constrained member shapes into non-constrained member shapes targeting a new shape that
             // has the member's constraints.
             .let(ConstrainedMemberTransform::transform)
@@ -658,6 +661,9 @@ open class ServerCodegenVisitor(
             ServerRuntimeTypesReExportsGenerator(codegenContext).render(this)
         }
 
+        // Generate the service overview, which only consists of the module's documentation.
+        rustCrate.withModule(ServerRustModule.Docs) { }
+
         // Generate service module.
         rustCrate.withModule(ServerRustModule.Service) {
             ServerServiceGenerator(
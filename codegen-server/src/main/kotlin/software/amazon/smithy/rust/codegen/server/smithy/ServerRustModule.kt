@@ -11,6 +11,7 @@ import software.amazon.smithy.model.shapes.OperationShape
 import software.amazon.smithy.model.shapes.Shape
 import software.amazon.smithy.model.shapes.StructureShape
 import software.amazon.smithy.model.shapes.UnionShape
+import software.amazon.smithy.model.traits.DocumentationTrait
 import software.amazon.smithy.model.traits.ErrorTrait
 import software.amazon.smithy.rust.codegen.core.rustlang.RustModule
 import software.amazon.smithy.rust.codegen.core.rustlang.RustReservedWords
@@ -18,6 +19,8 @@ import software.amazon.smithy.rust.codegen.core.rustlang.Visibility
 import software.amazon.smithy.rust.codegen.core.rustlang.Writable
 import software.amazon.smithy.rust.codegen.core.rustlang.docs
 import software.amazon.smithy.rust.codegen.core.rustlang.escape
+import software.amazon.smithy.rust.codegen.core.rustlang.externalDocumentation
+import software.amazon.smithy.rust.codegen.core.rustlang.normalizeHtml
 import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
 import software.amazon.smithy.rust.codegen.core.rustlang.writable
 import software.amazon.smithy.rust.codegen.core.smithy.ModuleDocProvider
@@ -26,7 +29,12 @@ import software.amazon.smithy.rust.codegen.core.smithy.ModuleProviderContext
 import software.amazon.smithy.rust.codegen.core.smithy.module
 import software.amazon.smithy.rust.codegen.core.smithy.traits.SyntheticInputTrait
 import software.amazon.smithy.rust.codegen.core.smithy.traits.SyntheticOutputTrait
+import software.amazon.smithy.rust.codegen.core.smithy.transformers.operationErrors
+import software.amazon.smithy.rust.codegen.core.util.getTrait
 import software.amazon.smithy.rust.codegen.core.util.hasTrait
+import software.amazon.smithy.rust.codegen.core.util.inputShape
+import software.amazon.smithy.rust.codegen.core.util.outputShape
+import software.amazon.smithy.rust.codegen.core.util.toPascalCase
 import software.amazon.smithy.rust.codegen.core.util.toSnakeCase
 import software.amazon.smithy.rust.codegen.server.smithy.generators.DocHandlerGenerator
 import software.amazon.smithy.rust.codegen.server.smithy.generators.handlerImports
@@ -43,6 +51,7 @@ object ServerRustModule {
     val Types = RustModule.public("types")
     val Service = RustModule.private("service")
     val Server = RustModule.public("server", inline = true)
+    val Docs = RustModule.public("docs", inline = true)
 
     val UnconstrainedModule =
         software.amazon.smithy.rust.codegen.core.smithy.UnconstrainedModule
@@ -62,6 +71,7 @@ class ServerModuleDocProvider(private val codegenContext: ServerCodegenContext)
             ServerRustModule.Output -> strDoc("Output structures for operations. Documentation on these types is copied from the model.")
             ServerRustModule.Types -> strDoc("Data primitives referenced by other data types.")
             ServerRustModule.Server -> strDoc("Contains the types that are re-exported from the `aws-smithy-http-server` crate.")
+            ServerRustModule.Docs -> serviceOverviewDoc()
             ServerRustModule.UnconstrainedModule -> strDoc("Unconstrained types for constrained shapes.")
             ServerRustModule.ConstrainedModule -> strDoc("Constrained types for constrained shapes.")
             else -> TODO("Document this module: $module")
@@ -93,6 +103,52 @@ class ServerModuleDocProvider(private val codegenContext: ServerCodegenContext)
                 "HandlerImports" to handlerImports(crateName, operations, commentToken = "///"),
             )
         }
+
+    /**
+     * An overview of the service, rendered from the model's documentation, listing each operation with links to its
+     * input, output and error types, and to the builder method used to register its handler.
+     */
+    private fun serviceOverviewDoc(): Writable =
+        writable {
+            val model = codegenContext.model
+            val symbolProvider = codegenContext.symbolProvider
+            val serviceShape = codegenContext.serviceShape
+            val serviceName = serviceShape.id.name.toPascalCase()
+            val operations = TopDownIndex.of(model).getContainedOperations(serviceShape).toSortedSet(compareBy { it.id })
+
+            docs("Documentation of the `$serviceName` service, as described by its Smithy model.")
+            serviceShape.getTrait<DocumentationTrait>()?.value?.takeIf { it.isNotBlank() }?.also {
+                docs("")
+                docs(normalizeHtml(escape(it)))
+            }
+            externalDocumentation(serviceShape, model)
+            if (operations.isEmpty()) {
+                return@writable
+            }
+            docs("")
+            docs("## Operations")
+            for (operation in operations) {
+                val operationName = symbolProvider.toSymbol(operation).name
+                val setterName = RustReservedWords.escapeIfNeeded(operationName.toSnakeCase())
+                docs("")
+                docs("### [`$operationName`](crate::operation_shape::$operationName)")
+                operation.getTrait<DocumentationTrait>()?.value?.takeIf { it.isNotBlank() }?.also {
+                    docs("")
+                    docs(normalizeHtml(escape(it)))
+                }
+                externalDocumentation(operation, model)
+                docs("")
+                val input = symbolProvider.toSymbol(operation.inputShape(model))
+                val output = symbolProvider.toSymbol(operation.outputShape(model))
+                docs("- Input: [`${input.name}`](${input.fullName})")
+                docs("- Output: [`${output.name}`](${output.fullName})")
+                if (operation.operationErrors(model).isNotEmpty()) {
+                    val error = symbolProvider.symbolForOperationError(operation)
+                    docs("- Errors: [`${error.name}`](${error.fullName})")
+                }
+                docs("- Handler: [`${serviceName}Builder::$setterName`](crate::${serviceName}Builder::$setterName)")
+            }
+        }
 }
 
 object ServerModuleProvider : ModuleProvider {
@@ -6,7 +6,6 @@
 package software.amazon.smithy.rust.codegen.server.smithy
 
 import software.amazon.smithy.model.Model
-import software.amazon.smithy.model.shapes.BlobShape
 import software.amazon.smithy.model.shapes.ByteShape
 import software.amazon.smithy.model.shapes.EnumShape
 import software.amazon.smithy.model.shapes.IntegerShape
@@ -20,9 +19,7 @@ import software.amazon.smithy.model.shapes.SetShape
 import software.amazon.smithy.model.shapes.Shape
 import software.amazon.smithy.model.shapes.ShapeId
 import software.amazon.smithy.model.shapes.ShortShape
-import software.amazon.smithy.model.traits.LengthTrait
 import software.amazon.smithy.model.traits.RangeTrait
-import software.amazon.smithy.model.traits.StreamingTrait
 import software.amazon.smithy.model.traits.Trait
 import software.amazon.smithy.model.traits.UniqueItemsTrait
 import software.amazon.smithy.rust.codegen.core.smithy.DirectedWalker
@@ -98,19 +95,6 @@ private sealed class UnsupportedConstraintMessageKind {
                     ),
                 )
 
-            is UnsupportedLengthTraitOnStreamingBlobShape ->
-                LogMessage(
-                    level,
-                    buildMessage(
-                        """
-                        The ${shape.type} shape `${shape.id}` has both the `${lengthTrait.toShapeId()}` and `${streamingTrait.toShapeId()}` constraint traits attached.
-                        It is unclear what the semantics for streaming blob shapes are.
-                        """.trimIndent().replace("\n", " "),
-                        willSupport = false,
-                        "https://github.com/awslabs/smithy/issues/1389",
-                    ),
-                )
-
             is UnsupportedRangeTraitOnShape ->
                 LogMessage(
                     level,
@@ -153,12 +137,6 @@ private data class UnsupportedConstraintOnMemberShape(val shape: MemberShape, va
 private data class UnsupportedConstraintOnShapeReachableViaAnEventStream(val shape: Shape, val constraintTrait: Trait) :
     UnsupportedConstraintMessageKind()
 
-private data class UnsupportedLengthTraitOnStreamingBlobShape(
-    val shape: BlobShape,
-    val lengthTrait: LengthTrait,
-    val streamingTrait: StreamingTrait,
-) : UnsupportedConstraintMessageKind()
-
 private data class UnsupportedRangeTraitOnShape(val shape: Shape, val rangeTrait: RangeTrait) :
     UnsupportedConstraintMessageKind()
 
@@ -248,18 +226,7 @@ fun validateUnsupportedConstraints(
     // Traverse the model and error out if:
     val walker = DirectedWalker(model)
 
-    // 1. Constraint traits on streaming blob shapes are used. Their semantics are unclear.
-    // TODO(https://github.com/awslabs/smithy/issues/1389)
-    val unsupportedLengthTraitOnStreamingBlobShapeSet =
-        walker
-            .walkShapes(service)
-            .asSequence()
-            .filterIsInstance<BlobShape>()
-            .filter { it.hasTrait<LengthTrait>() && it.hasTrait<StreamingTrait>() }
-            .map { UnsupportedLengthTraitOnStreamingBlobShape(it, it.expectTrait(), it.expectTrait()) }
-            .toSet()
-
-    // 2. Constraint traits in event streams are used. Their semantics are unclear.
+    // 1. Constraint traits in event streams are used. Their semantics are unclear.
     // TODO(https://github.com/awslabs/smithy/issues/1388)
     val eventStreamShapes =
         walker
@@ -286,7 +253,7 @@ fun validateUnsupportedConstraints(
     val unsupportedConstraintShapeReachableViaAnEventStreamSet =
         unsupportedConstraintOnNonErrorShapeReachableViaAnEventStreamSet + unsupportedConstraintErrorShapeReachableViaAnEventStreamSet
 
-    // 3. Range trait used on unsupported shapes.
+    // 2. Range trait used on unsupported shapes.
     // TODO(https://github.com/smithy-lang/smithy-rs/issues/2007)
     val unsupportedRangeTraitOnShapeSet =
         walker
@@ -297,7 +264,7 @@ fun validateUnsupportedConstraints(
             .map { (shape, rangeTrait) -> UnsupportedRangeTraitOnShape(shape, rangeTrait as RangeTrait) }
             .toSet()
 
-    // 4. `@uniqueItems` cannot reach a map shape.
+    // 3. `@uniqueItems` cannot reach a map shape.
     // See https://github.com/awslabs/smithy/issues/1567.
     val mapShapeReachableFromUniqueItemsListShapeSet =
         walker
@@ -317,12 +284,9 @@ fun validateUnsupportedConstraints(
 
     val messages =
         (
-            unsupportedLengthTraitOnStreamingBlobShapeSet.map {
+            unsupportedConstraintShapeReachableViaAnEventStreamSet.map {
                 it.intoLogMessage(codegenConfig.ignoreUnsupportedConstraints)
             } +
-                unsupportedConstraintShapeReachableViaAnEventStreamSet.map {
-                    it.intoLogMessage(codegenConfig.ignoreUnsupportedConstraints)
-                } +
                 unsupportedRangeTraitOnShapeSet.map { it.intoLogMessage(codegenConfig.ignoreUnsupportedConstraints) } +
                 mapShapeReachableFromUniqueItemsListShapeSet.map {
                     it.intoLogMessage(codegenConfig.ignoreUnsupportedConstraints)
@@ -16,6 +16,7 @@ import software.amazon.smithy.rust.codegen.core.smithy.generators.LibRsCustomiza
 import software.amazon.smithy.rust.codegen.server.smithy.ServerCodegenContext
 import software.amazon.smithy.rust.codegen.server.smithy.ServerRustModule
 import software.amazon.smithy.rust.codegen.server.smithy.customize.ServerCodegenDecorator
+import software.amazon.smithy.rust.codegen.server.smithy.traits.MultipartTrait
 
 /**
  * A set of customizations that are included in all protocols.
@@ -64,6 +65,25 @@ class ServerRequiredCustomizations : ServerCodegenDecorator {
             ),
         )
 
+        rustCrate.mergeFeature(
+            Feature(
+                "test-util",
+                false,
+                listOf("aws-smithy-http-server/test-util"),
+            ),
+        )
+
+        // Enabled by default when the model has operations that take a `multipart/form-data` body
+        val hasMultipartOperations =
+            codegenContext.model.getOperationShapesWithTrait(MultipartTrait::class.java).isNotEmpty()
+        rustCrate.mergeFeature(
+            Feature(
+                "multipart",
+                hasMultipartOperations,
+                listOf("aws-smithy-http-server/multipart"),
+            ),
+        )
+
         rustCrate.withModule(ServerRustModule.Types) {
             pubUseSmithyPrimitives(codegenContext, codegenContext.model, rustCrate)(this)
             rustTemplate(
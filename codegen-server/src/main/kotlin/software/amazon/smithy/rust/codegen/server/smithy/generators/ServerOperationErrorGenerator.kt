@@ -17,14 +17,18 @@ import software.amazon.smithy.rust.codegen.core.rustlang.Visibility
 import software.amazon.smithy.rust.codegen.core.rustlang.Writable
 import software.amazon.smithy.rust.codegen.core.rustlang.deprecatedShape
 import software.amazon.smithy.rust.codegen.core.rustlang.documentShape
+import software.amazon.smithy.rust.codegen.core.rustlang.externalDocumentation
 import software.amazon.smithy.rust.codegen.core.rustlang.rust
 import software.amazon.smithy.rust.codegen.core.rustlang.rustBlock
+import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
+import software.amazon.smithy.rust.codegen.core.rustlang.writable
 import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
 import software.amazon.smithy.rust.codegen.core.smithy.RustSymbolProvider
 import software.amazon.smithy.rust.codegen.core.smithy.transformers.eventStreamErrors
 import software.amazon.smithy.rust.codegen.core.smithy.transformers.operationErrors
 import software.amazon.smithy.rust.codegen.core.util.UNREACHABLE
 import software.amazon.smithy.rust.codegen.core.util.toSnakeCase
+import software.amazon.smithy.rust.codegen.server.smithy.ServerCargoDependency
 
 /**
  * Generates a unified error enum for [operation]. [ErrorGenerator] handles generating the individual variants,
@@ -66,6 +70,7 @@ open class ServerOperationErrorGenerator(
         writer.rustBlock("enum ${errorSymbol.name}") {
             errors.forEach { errorVariant ->
                 documentShape(errorVariant, model)
+                externalDocumentation(errorVariant, model)
                 deprecatedShape(errorVariant)
                 val errorVariantSymbol = symbolProvider.toSymbol(errorVariant)
                 write("${errorVariantSymbol.name}(#T),", errorVariantSymbol)
@@ -113,6 +118,48 @@ open class ServerOperationErrorGenerator(
                 }
             }
         }
+
+        if (operationOrEventStream is OperationShape) {
+            renderDowncastServiceError(writer, errorSymbol, errors)
+        }
+    }
+
+    /**
+     * Recovers the operation error from a boxed operation error, or from a boxed error structure of one of its
+     * variants, for handlers returning `Box<dyn Error + Send + Sync>`.
+     */
+    private fun renderDowncastServiceError(
+        writer: RustWriter,
+        errorSymbol: Symbol,
+        errors: List<StructureShape>,
+    ) {
+        val operation = ServerCargoDependency.smithyHttpServer(symbolProvider.config.runtimeConfig).toType().resolve("operation")
+        writer.rustTemplate(
+            """
+            impl #{DowncastServiceError} for ${errorSymbol.name} {
+                fn downcast_service_error(
+                    error: #{Box}<dyn #{StdError} + Send + Sync>,
+                ) -> #{Result}<Self, #{Box}<dyn #{StdError} + Send + Sync>> {
+                    #{downcast_into}::<Self, Self>(error)
+                        #{variants}
+                }
+            }
+            """,
+            *RuntimeType.preludeScope,
+            "DowncastServiceError" to operation.resolve("DowncastServiceError"),
+            "downcast_into" to operation.resolve("downcast_into"),
+            "StdError" to RuntimeType.StdError,
+            "variants" to
+                writable {
+                    errors.forEach { error ->
+                        rustTemplate(
+                            ".or_else(#{downcast_into}::<#{Variant}, Self>)",
+                            "downcast_into" to operation.resolve("downcast_into"),
+                            "Variant" to symbolProvider.toSymbol(error),
+                        )
+                    }
+                },
+        )
     }
 
     /**
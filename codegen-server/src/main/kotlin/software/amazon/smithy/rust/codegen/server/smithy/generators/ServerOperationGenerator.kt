@@ -9,6 +9,7 @@ import software.amazon.smithy.model.shapes.OperationShape
 import software.amazon.smithy.rust.codegen.core.rustlang.RustWriter
 import software.amazon.smithy.rust.codegen.core.rustlang.Writable
 import software.amazon.smithy.rust.codegen.core.rustlang.documentShape
+import software.amazon.smithy.rust.codegen.core.rustlang.externalDocumentation
 import software.amazon.smithy.rust.codegen.core.rustlang.rust
 import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
 import software.amazon.smithy.rust.codegen.core.rustlang.writable
@@ -46,6 +47,7 @@ class ServerOperationGenerator(
 
     fun render(writer: RustWriter) {
         writer.documentShape(operation, model)
+        writer.externalDocumentation(operation, model)
 
         val generator = ServerHttpSensitivityGenerator(model, operation, runtimeConfig)
         val requestFmt = generator.requestFmt()
@@ -258,6 +258,12 @@ open class ServerRootGenerator(
             } else {
                 ""
             }
+        val operationLayersReExport =
+            if (operations.isNotEmpty()) {
+                "${serviceName}OperationLayers,"
+            } else {
+                ""
+            }
         rustWriter.rust(
             """
             pub use crate::service::{
@@ -266,6 +272,7 @@ open class ServerRootGenerator(
                 ${serviceName}ConfigBuilder,
                 $configErrorReExport
                 ${serviceName}Builder,
+                $operationLayersReExport
                 MissingOperationsError
             };
             """,
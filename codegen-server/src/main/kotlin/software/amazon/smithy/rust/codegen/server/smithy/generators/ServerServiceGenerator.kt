@@ -9,16 +9,22 @@ import software.amazon.smithy.model.knowledge.TopDownIndex
 import software.amazon.smithy.model.neighbor.Walker
 import software.amazon.smithy.model.shapes.OperationShape
 import software.amazon.smithy.model.shapes.StringShape
+import software.amazon.smithy.model.traits.DocumentationTrait
 import software.amazon.smithy.model.traits.PatternTrait
 import software.amazon.smithy.rust.codegen.core.rustlang.RustReservedWords
 import software.amazon.smithy.rust.codegen.core.rustlang.RustWriter
 import software.amazon.smithy.rust.codegen.core.rustlang.Writable
+import software.amazon.smithy.rust.codegen.core.rustlang.docs
 import software.amazon.smithy.rust.codegen.core.rustlang.documentShape
+import software.amazon.smithy.rust.codegen.core.rustlang.escape
+import software.amazon.smithy.rust.codegen.core.rustlang.externalDocumentation
 import software.amazon.smithy.rust.codegen.core.rustlang.join
+import software.amazon.smithy.rust.codegen.core.rustlang.normalizeHtml
 import software.amazon.smithy.rust.codegen.core.rustlang.rust
 import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
 import software.amazon.smithy.rust.codegen.core.rustlang.writable
 import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
+import software.amazon.smithy.rust.codegen.core.util.getTrait
 import software.amazon.smithy.rust.codegen.core.util.hasTrait
 import software.amazon.smithy.rust.codegen.core.util.letIf
 import software.amazon.smithy.rust.codegen.core.util.toPascalCase
@@ -54,6 +60,7 @@ class ServerServiceGenerator(
     private val serviceId = service.id
     private val serviceName = serviceId.name.toPascalCase()
     private val builderName = "${serviceName}Builder"
+    private val operationLayersName = "${serviceName}OperationLayers"
 
     /** Calculate all `operationShape`s contained within the `ServiceShape`. */
     private val index = TopDownIndex.of(codegenContext.model)
@@ -120,6 +127,7 @@ class ServerServiceGenerator(
                 rustTemplate(
                     """
                     /// Sets the [`$structName`](crate::operation_shape::$structName) operation.
+                    #{OperationDocs:W}
                     ///
                     /// This should be an async function satisfying the [`Handler`](#{SmithyHttpServer}::operation::Handler) trait.
                     /// See the [operation module documentation](#{SmithyHttpServer}::operation) for more information.
@@ -183,6 +191,7 @@ class ServerServiceGenerator(
                     }
 
                     /// Sets the [`$structName`](crate::operation_shape::$structName) operation.
+                    #{OperationDocs:W}
                     ///
                     /// This should be an async function satisfying the [`Handler`](#{SmithyHttpServer}::operation::Handler) trait.
                     /// See the [operation module documentation](#{SmithyHttpServer}::operation) for more information.
@@ -256,12 +265,35 @@ class ServerServiceGenerator(
                         self.$fieldName = Some(#{SmithyHttpServer}::routing::Route::new(svc));
                         self
                     }
+
+                    /// Applies a [`Layer`](#{Tower}::Layer) to the [`$structName`](crate::operation_shape::$structName) operation only.
+                    ///
+                    /// The layer wraps the operation's handler or service after the model plugins, so it sees the
+                    /// operation's modeled input, output and error types rather than HTTP requests and responses.
+                    /// Layers set multiple times are applied in order, the last one being the outermost.
+                    ///
+                    /// This must be called before setting the operation with [`$builderName::$fieldName`] or
+                    /// [`$builderName::${fieldName}_service`]: it has no effect on an operation that has already been set.
+                    pub fn ${fieldName}_layer<NewLayer>(self, layer: NewLayer) -> $builderName<Body, L, HttpPl, ${operationLayersName}<ModelPl, #{LayerSlots:W}>> {
+                        $builderName {
+                            ${builderFieldNames.values.joinToString(", ") { "$it: self.$it" }},
+                            layer: self.layer,
+                            http_plugin: self.http_plugin,
+                            model_plugin: $operationLayersName {
+                                inner: self.model_plugin,
+                                #{LayerSlotValues:W}
+                            },
+                        }
+                    }
                     """,
+                    "LayerSlots" to layerSlotTypes(operationShape),
+                    "LayerSlotValues" to layerSlotValues(operationShape),
                     "Router" to protocol.routerType(),
                     "Protocol" to protocol.markerStruct(),
                     "Handler" to handler,
                     "HandlerFixed" to handlerFixed,
                     "HandlerImports" to handlerImports(crateName, operations),
+                    "OperationDocs" to operationDocs(operationShape),
                     *codegenScope,
                 )
 
@@ -270,6 +302,35 @@ class ServerServiceGenerator(
             }
         }
 
+    /** The generic arguments of the `OperationLayers` plugin returned by [operationShape]'s `_layer` builder method. */
+    private fun layerSlotTypes(operationShape: OperationShape): Writable =
+        writable {
+            val slots =
+                operations.map { if (it == operationShape) "NewLayer" else "#{Tower}::layer::util::Identity" }
+            rustTemplate(slots.joinToString(", "), *codegenScope)
+        }
+
+    /** The layer fields of the `OperationLayers` plugin constructed by [operationShape]'s `_layer` builder method. */
+    private fun layerSlotValues(operationShape: OperationShape): Writable =
+        writable {
+            val slots =
+                builderFieldNames.map { (shape, fieldName) ->
+                    val value = if (shape == operationShape) "layer" else "#{Tower}::layer::util::Identity::new()"
+                    "${fieldName}_layer: $value"
+                }
+            rustTemplate(slots.joinToString(", "), *codegenScope)
+        }
+
+    /** The model documentation of [operationShape], rendered below the first line of its setters' docs. */
+    private fun operationDocs(operationShape: OperationShape): Writable =
+        writable {
+            operationShape.getTrait<DocumentationTrait>()?.value?.takeIf { it.isNotBlank() }?.also {
+                rust("///")
+                docs(normalizeHtml(escape(it)))
+            }
+            externalDocumentation(operationShape, model)
+        }
+
     private fun buildMethod(): Writable =
         writable {
             val missingOperationsVariableName = "missing_operation_names"
@@ -593,6 +654,16 @@ class ServerServiceGenerator(
                     pub fn into_make_service_with_connect_info<C>(self) -> #{SmithyHttpServer}::routing::IntoMakeServiceWithConnectInfo<Self, C> {
                         #{SmithyHttpServer}::routing::IntoMakeServiceWithConnectInfo::new(self)
                     }
+
+                    /// Converts [`$serviceName`] into a [`MakeService`](tower::make::MakeService) that shuts down gracefully once `signal` resolves.
+                    ///
+                    /// See [`IntoMakeServiceWithShutdown`](#{SmithyHttpServer}::routing::IntoMakeServiceWithShutdown) for more information.
+                    pub fn into_make_service_with_shutdown<F>(self, signal: F) -> #{SmithyHttpServer}::routing::IntoMakeServiceWithShutdown<Self>
+                    where
+                        F: std::future::Future<Output = ()> + Send + 'static,
+                    {
+                        #{SmithyHttpServer}::routing::IntoMakeServiceWithShutdown::new(self, signal)
+                    }
                 }
 
                 impl<S>
@@ -603,6 +674,19 @@ class ServerServiceGenerator(
                         >,
                     >
                 {
+                    /// Registers a fallback route, used for requests that do not match any operation.
+                    ///
+                    /// This can be used to serve routes that are not part of the model, such as static assets, from the
+                    /// same service. Operations always take precedence over the fallback. Without a fallback, unmatched
+                    /// requests are rejected with the protocol's routing error response.
+                    ///
+                    /// The fallback is not wrapped in the plugins and layers registered on the `${serviceName}Config`.
+                    pub fn fallback(self, fallback: S) -> Self {
+                        $serviceName {
+                            svc: self.svc.map(|s| s.fallback(fallback)),
+                        }
+                    }
+
                     /// Applies a [`Layer`](#{Tower}::Layer) uniformly to all routes.
                     ##[deprecated(
                         since = "0.57.0",
@@ -714,6 +798,63 @@ class ServerServiceGenerator(
             )
         }
 
+    /**
+     * Returns a `Writable` containing the model plugin applying the layers set with the builder's
+     * `<operation>_layer` methods, which holds one layer per operation.
+     */
+    private fun operationLayers(): Writable =
+        writable {
+            if (operations.isEmpty()) {
+                return@writable
+            }
+            val slotGenerics = operationStructNames.values.joinToString(", ") { "${it}Layer" }
+            val slotFields =
+                operationStructNames.entries.joinToString("\n") { (shape, structName) ->
+                    "${builderFieldNames[shape]}_layer: ${structName}Layer,"
+                }
+            rustTemplate(
+                """
+                /// A [model plugin](#{SmithyHttpServer}::plugin) applying a [`Layer`](#{Tower}::Layer) to each operation of [`$serviceName`].
+                ///
+                /// Constructed via the `<operation>_layer` methods of [`$builderName`], such as
+                /// [`$builderName::${builderFieldNames.values.first()}_layer`]. Operations without a layer of their own use the
+                /// [`Identity`](#{Tower}::layer::util::Identity) layer.
+                ##[derive(Debug, Clone)]
+                pub struct $operationLayersName<Inner, $slotGenerics> {
+                    inner: Inner,
+                    $slotFields
+                }
+
+                impl<Inner, $slotGenerics> #{SmithyHttpServer}::plugin::ModelMarker for $operationLayersName<Inner, $slotGenerics>
+                where
+                    Inner: #{SmithyHttpServer}::plugin::ModelMarker,
+                {
+                }
+                """,
+                *codegenScope,
+            )
+
+            for ((shape, structName) in operationStructNames) {
+                rustTemplate(
+                    """
+                    impl<Ser, T, Inner, $slotGenerics> #{SmithyHttpServer}::plugin::Plugin<Ser, crate::operation_shape::$structName, T>
+                        for $operationLayersName<Inner, $slotGenerics>
+                    where
+                        Inner: #{SmithyHttpServer}::plugin::Plugin<Ser, crate::operation_shape::$structName, T>,
+                        ${structName}Layer: #{Tower}::Layer<Inner::Output>,
+                    {
+                        type Output = ${structName}Layer::Service;
+
+                        fn apply(&self, input: T) -> Self::Output {
+                            self.${builderFieldNames[shape]}_layer.layer(self.inner.apply(input))
+                        }
+                    }
+                    """,
+                    *codegenScope,
+                )
+            }
+        }
+
     private fun serviceShapeImpl(): Writable =
         writable {
             val namespace = serviceId.namespace
@@ -796,6 +937,8 @@ class ServerServiceGenerator(
 
             #{MissingOperationsError:W}
 
+            #{OperationLayers:W}
+
             #{RequestSpecs:W}
 
             #{Struct:W}
@@ -806,6 +949,7 @@ class ServerServiceGenerator(
             """,
             "Builder" to builder(),
             "MissingOperationsError" to missingOperationsError(),
+            "OperationLayers" to operationLayers(),
             "RequestSpecs" to requestSpecsModule(),
             "Struct" to serviceStruct(),
             "Operations" to operationEnum(),
@@ -10,6 +10,7 @@ import software.amazon.smithy.model.shapes.OperationShape
 import software.amazon.smithy.model.shapes.Shape
 import software.amazon.smithy.model.shapes.StringShape
 import software.amazon.smithy.model.shapes.StructureShape
+import software.amazon.smithy.model.traits.TimestampFormatTrait
 import software.amazon.smithy.rust.codegen.core.rustlang.CargoDependency
 import software.amazon.smithy.rust.codegen.core.rustlang.Writable
 import software.amazon.smithy.rust.codegen.core.rustlang.rust
@@ -40,6 +41,7 @@ import software.amazon.smithy.rust.codegen.core.smithy.protocols.parse.Structure
 import software.amazon.smithy.rust.codegen.core.smithy.protocols.restJsonFieldName
 import software.amazon.smithy.rust.codegen.core.smithy.protocols.serialize.CborSerializerGenerator
 import software.amazon.smithy.rust.codegen.core.smithy.protocols.serialize.StructuredDataSerializerGenerator
+import software.amazon.smithy.rust.codegen.core.util.UNREACHABLE
 import software.amazon.smithy.rust.codegen.core.util.dq
 import software.amazon.smithy.rust.codegen.server.smithy.ServerCargoDependency
 import software.amazon.smithy.rust.codegen.server.smithy.ServerCodegenContext
@@ -49,6 +51,9 @@ import software.amazon.smithy.rust.codegen.server.smithy.customizations.AddTypeF
 import software.amazon.smithy.rust.codegen.server.smithy.customizations.BeforeEncodingMapOrCollectionCborCustomization
 import software.amazon.smithy.rust.codegen.server.smithy.customizations.BeforeSerializingMemberCborCustomization
 import software.amazon.smithy.rust.codegen.server.smithy.generators.http.RestRequestSpecGenerator
+import software.amazon.smithy.rust.codegen.server.smithy.protocols.GrpcHttpBindingResolver
+import software.amazon.smithy.rust.codegen.server.smithy.protocols.ProtobufParserGenerator
+import software.amazon.smithy.rust.codegen.server.smithy.protocols.ProtobufSerializerGenerator
 import software.amazon.smithy.rust.codegen.server.smithy.protocols.ServerAwsJsonSerializerGenerator
 import software.amazon.smithy.rust.codegen.server.smithy.protocols.ServerRestJsonSerializerGenerator
 import software.amazon.smithy.rust.codegen.server.smithy.targetCanReachConstrainedShape
@@ -91,6 +96,15 @@ interface ServerProtocol : Protocol {
      */
     fun serverContentTypeCheckNoModeledInput(): Boolean = false
 
+    /**
+     * In RPC protocols, such as the AWS JSON 1.x protocols and `rpcv2Cbor`, event streams begin with an initial
+     * message, `initial-request` from the client and `initial-response` from the server, holding the operation input
+     * or output members that are not bound to the event stream.
+     * Returns a writable for the server's `initial-response` message, or `null` if the protocol does not use initial
+     * messages.
+     */
+    fun serverEventStreamInitialResponse(): Writable? = null
+
     /** The protocol-specific `RequestRejection` type. **/
     fun requestRejection(runtimeConfig: RuntimeConfig): RuntimeType =
         ServerCargoDependency.smithyHttpServer(runtimeConfig)
@@ -120,6 +134,22 @@ interface ServerProtocol : Protocol {
     fun deserializePayloadErrorType(binding: HttpBindingDescriptor): RuntimeType
 }
 
+/**
+ * The `initial-response` message of RPC protocols. Since these protocols only support event stream operations whose
+ * sole member is the event stream (see https://github.com/smithy-lang/smithy-rs/issues/2237), the message holds an
+ * empty document.
+ */
+private fun eventStreamInitialResponse(
+    runtimeConfig: RuntimeConfig,
+    contentType: String,
+    emptyDocument: String,
+) = writable {
+    rustTemplate(
+        "#{initial_message}(\"initial-response\", ${contentType.dq()}, $emptyDocument)",
+        "initial_message" to RuntimeType.smithyHttp(runtimeConfig).resolve("event_stream::initial_message"),
+    )
+}
+
 fun returnSymbolToParseFn(codegenContext: ServerCodegenContext): (Shape) -> ReturnSymbolToParse {
     fun returnSymbolToParse(shape: Shape): ReturnSymbolToParse =
         if (shape.canReachConstrainedShape(codegenContext.model, codegenContext.symbolProvider)) {
@@ -202,6 +232,9 @@ class ServerAwsJsonProtocol(
             AwsJsonVersion.Json11 -> "new_aws_json_11_router"
         }
 
+    override fun serverEventStreamInitialResponse() =
+        eventStreamInitialResponse(runtimeConfig, "application/x-amz-json-${version.value}", "&b\"{}\"[..]")
+
     override fun requestRejection(runtimeConfig: RuntimeConfig): RuntimeType =
         ServerCargoDependency.smithyHttpServer(runtimeConfig)
             .toType().resolve("protocol::aws_json::rejection::RequestRejection")
@@ -378,6 +411,10 @@ class ServerRpcV2CborProtocol(
 
     override fun serverRouterRuntimeConstructor() = "rpc_v2_router"
 
+    // An empty CBOR map.
+    override fun serverEventStreamInitialResponse() =
+        eventStreamInitialResponse(runtimeConfig, "application/cbor", "&b\"\\xa0\"[..]")
+
     override fun serverContentTypeCheckNoModeledInput() = false
 
     override fun deserializePayloadErrorType(binding: HttpBindingDescriptor): RuntimeType =
@@ -389,6 +426,59 @@ class ServerRpcV2CborProtocol(
         )
 }
 
+class ServerGrpcProtocol(
+    private val codegenContext: ServerCodegenContext,
+) : ServerProtocol {
+    val runtimeConfig = codegenContext.runtimeConfig
+
+    override val protocolModulePath = "grpc"
+
+    override val httpBindingResolver = GrpcHttpBindingResolver(codegenContext.model, codegenContext.serviceShape)
+
+    // Protobuf messages can't hold timestamps; `ProtobufParserGenerator` and `ProtobufSerializerGenerator` reject them.
+    override val defaultTimestampFormat: TimestampFormatTrait.Format = TimestampFormatTrait.Format.EPOCH_SECONDS
+
+    override fun structuredDataParser(): StructuredDataParserGenerator =
+        ProtobufParserGenerator(codegenContext, httpBindingResolver, requestRejection(runtimeConfig))
+
+    override fun structuredDataSerializer(): StructuredDataSerializerGenerator =
+        ProtobufSerializerGenerator(codegenContext, httpBindingResolver)
+
+    override fun parseHttpErrorMetadata(operationShape: OperationShape): RuntimeType =
+        UNREACHABLE("the `grpc` protocol is only supported by the server")
+
+    override fun parseEventStreamErrorMetadata(operationShape: OperationShape): RuntimeType =
+        UNREACHABLE("the `grpc` protocol does not support event streams")
+
+    override fun markerStruct() = ServerRuntimeType.protocol("Grpc", "grpc", runtimeConfig)
+
+    override fun routerType() =
+        ServerCargoDependency.smithyHttpServer(runtimeConfig).toType()
+            .resolve("protocol::grpc::router::GrpcRouter")
+
+    override fun serverRouterRequestSpec(
+        operationShape: OperationShape,
+        operationName: String,
+        serviceName: String,
+        requestSpecModule: RuntimeType,
+    ) = writable {
+        // The router looks operations up by the request path.
+        rust(httpBindingResolver.path(operationShape).dq())
+    }
+
+    override fun serverRouterRequestSpecType(requestSpecModule: RuntimeType): RuntimeType = RuntimeType.StaticStr
+
+    override fun serverRouterRuntimeConstructor() = "grpc_router"
+
+    override fun deserializePayloadErrorType(binding: HttpBindingDescriptor): RuntimeType =
+        deserializePayloadErrorType(
+            codegenContext,
+            binding,
+            requestRejection(runtimeConfig),
+            ServerCargoDependency.smithyHttpServer(runtimeConfig).toType().resolve("protocol::grpc::wire::DecodeError"),
+        )
+}
+
 /** Just a common function to keep things DRY. **/
 fun deserializePayloadErrorType(
     codegenContext: CodegenContext,
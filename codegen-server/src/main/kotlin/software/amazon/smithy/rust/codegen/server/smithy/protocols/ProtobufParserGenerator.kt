@@ -0,0 +1,231 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.protocols
+
+import software.amazon.smithy.codegen.core.CodegenException
+import software.amazon.smithy.model.shapes.BlobShape
+import software.amazon.smithy.model.shapes.BooleanShape
+import software.amazon.smithy.model.shapes.ByteShape
+import software.amazon.smithy.model.shapes.CollectionShape
+import software.amazon.smithy.model.shapes.DoubleShape
+import software.amazon.smithy.model.shapes.FloatShape
+import software.amazon.smithy.model.shapes.IntegerShape
+import software.amazon.smithy.model.shapes.LongShape
+import software.amazon.smithy.model.shapes.MemberShape
+import software.amazon.smithy.model.shapes.OperationShape
+import software.amazon.smithy.model.shapes.Shape
+import software.amazon.smithy.model.shapes.ShortShape
+import software.amazon.smithy.model.shapes.StringShape
+import software.amazon.smithy.model.shapes.StructureShape
+import software.amazon.smithy.rust.codegen.core.rustlang.Writable
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.rustlang.rustBlock
+import software.amazon.smithy.rust.codegen.core.rustlang.rustBlockTemplate
+import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
+import software.amazon.smithy.rust.codegen.core.rustlang.writable
+import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
+import software.amazon.smithy.rust.codegen.core.smithy.generators.setterName
+import software.amazon.smithy.rust.codegen.core.smithy.isOptional
+import software.amazon.smithy.rust.codegen.core.smithy.isRustBoxed
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.HttpBindingResolver
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.HttpLocation
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.ProtocolFunctions
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.parse.StructuredDataParserGenerator
+import software.amazon.smithy.rust.codegen.core.util.UNREACHABLE
+import software.amazon.smithy.rust.codegen.core.util.inputShape
+import software.amazon.smithy.rust.codegen.core.util.toSnakeCase
+import software.amazon.smithy.rust.codegen.server.smithy.ServerCargoDependency
+import software.amazon.smithy.rust.codegen.server.smithy.ServerCodegenContext
+import software.amazon.smithy.rust.codegen.server.smithy.generators.protocol.returnSymbolToParseFn
+
+/**
+ * Parses operation inputs from length-prefixed protobuf messages, for the `smithy.rust#grpc` protocol.
+ *
+ * Fields are matched to members by the field number given by [protobufFieldNumber], and fields with any other number
+ * are skipped. Repeated fields are accepted in both their packed and unpacked encodings. As in protobuf, the last
+ * occurrence of a non-repeated field wins.
+ */
+class ProtobufParserGenerator(
+    private val codegenContext: ServerCodegenContext,
+    private val httpBindingResolver: HttpBindingResolver,
+    private val requestRejection: RuntimeType,
+) : StructuredDataParserGenerator {
+    private val model = codegenContext.model
+    private val symbolProvider = codegenContext.symbolProvider
+    private val runtimeConfig = codegenContext.runtimeConfig
+    private val protocolFunctions = ProtocolFunctions(codegenContext)
+    private val returnSymbolToParse = returnSymbolToParseFn(codegenContext)
+
+    private val grpc = ServerCargoDependency.smithyHttpServer(runtimeConfig).toType().resolve("protocol::grpc")
+    private val codegenScope =
+        arrayOf(
+            *RuntimeType.preludeScope,
+            "Blob" to RuntimeType.blob(runtimeConfig),
+            "DecodeError" to grpc.resolve("wire::DecodeError"),
+            "Decoder" to grpc.resolve("wire::Decoder"),
+            "RequestRejection" to requestRejection,
+            "WireType" to grpc.resolve("wire::WireType"),
+            "decode_message" to grpc.resolve("framing::decode_message"),
+        )
+
+    override fun payloadParser(member: MemberShape): RuntimeType =
+        UNREACHABLE("the `grpc` protocol binds every member to the message")
+
+    override fun operationParser(operationShape: OperationShape): RuntimeType? =
+        UNREACHABLE("the `grpc` protocol is only supported by the server")
+
+    override fun errorParser(errorShape: StructureShape): RuntimeType? =
+        UNREACHABLE("the `grpc` protocol is only supported by the server")
+
+    override fun serverInputParser(operationShape: OperationShape): RuntimeType? {
+        val includedMembers = httpBindingResolver.requestMembers(operationShape, HttpLocation.DOCUMENT)
+        if (includedMembers.isEmpty()) {
+            return null
+        }
+        val inputShape = operationShape.inputShape(model)
+        return protocolFunctions.deserializeFn(operationShape) { fnName ->
+            rustTemplate(
+                """
+                ##[allow(clippy::single_match)]
+                pub(crate) fn $fnName(value: &[u8], mut builder: #{Builder}) -> #{Result}<#{Builder}, #{RequestRejection}> {
+                    let message = #{decode_message}(value)?;
+                    #{DecodeFields:W}
+                    #{Ok}(builder)
+                }
+                """,
+                *codegenScope,
+                "Builder" to symbolProvider.symbolForBuilder(inputShape),
+                "DecodeFields" to decodeFields(includedMembers),
+            )
+        }
+    }
+
+    private fun structureParser(structureShape: StructureShape): RuntimeType {
+        val returnSymbol = returnSymbolToParse(structureShape)
+        return protocolFunctions.deserializeFn(structureShape) { fnName ->
+            rust("##[allow(clippy::single_match)]")
+            rustBlockTemplate(
+                "pub(crate) fn $fnName(message: &[u8]) -> #{Result}<#{ReturnType}, #{DecodeError}>",
+                *codegenScope,
+                "ReturnType" to returnSymbol.symbol,
+            ) {
+                val builder = symbolProvider.symbolForBuilder(structureShape)
+                if (structureShape.members().isEmpty()) {
+                    rust("let _ = message;")
+                    rust("let builder = #T::default();", builder)
+                } else {
+                    rust("let mut builder = #T::default();", builder)
+                    decodeFields(structureShape.members().toList())(this)
+                }
+                // Only call `build()` if the builder is not fallible. Otherwise, return the builder.
+                if (returnSymbol.isUnconstrained) {
+                    rustTemplate("#{Ok}(builder)", *codegenScope)
+                } else {
+                    rustTemplate("#{Ok}(builder.build())", *codegenScope)
+                }
+            }
+        }
+    }
+
+    /** Decodes the fields of `message` and sets the [members] they are bound to on `builder`. */
+    private fun decodeFields(members: List<MemberShape>): Writable =
+        writable {
+            val repeatedMembers = members.filter { model.expectShape(it.target) is CollectionShape }
+            rustTemplate("let mut decoder = #{Decoder}::new(message);", *codegenScope)
+            // The elements of a repeated field may be spread over several occurrences of the field.
+            for (member in repeatedMembers) {
+                rustTemplate("let mut ${repeatedVariable(member)} = #{Vec}::new();", *codegenScope)
+            }
+            rustBlock("while let Some(field) = decoder.next_field()?") {
+                rustBlock("match field.number()") {
+                    for (member in members) {
+                        rustBlock("${member.protobufFieldNumber(model)} =>") {
+                            decodeMember(member)(this)
+                        }
+                    }
+                    // Fields the service doesn't know about are skipped.
+                    rust("_ => {}")
+                }
+            }
+            for (member in repeatedMembers) {
+                val value = repeatedVariable(member)
+                rust("builder = builder.${member.setterName()}(${wrapOptional(member, value)});")
+            }
+        }
+
+    private fun decodeMember(member: MemberShape): Writable =
+        writable {
+            if (symbolProvider.toSymbol(member).isRustBoxed()) {
+                throw CodegenException("The `smithy.rust#grpc` protocol does not support recursive shapes, but $member is recursive")
+            }
+            when (val target = model.expectShape(member.target)) {
+                is CollectionShape -> {
+                    val element = model.expectShape(target.member.target)
+                    checkRepeatedElement(model, member, target, element)
+                    if (returnSymbolToParse(target).isUnconstrained) {
+                        throw CodegenException(
+                            "The `smithy.rust#grpc` protocol does not support lists that reach constrained shapes in " +
+                                "operation input, but $member targets one",
+                        )
+                    }
+                    rustBlockTemplate(
+                        "for element in field.elements(#{WireType}::${wireType(member, element)})?",
+                        *codegenScope,
+                    ) {
+                        rust("${repeatedVariable(member)}.push(#W);", decodeValue(member, element, "element"))
+                    }
+                }
+                else -> {
+                    rust(
+                        "builder = builder.${member.setterName()}(${wrapOptional(member, "#W")});",
+                        decodeValue(member, target, "field"),
+                    )
+                }
+            }
+        }
+
+    /** Decodes the value of [field], a `Field` of a message. */
+    private fun decodeValue(
+        member: MemberShape,
+        target: Shape,
+        field: String,
+    ): Writable =
+        writable {
+            when (target) {
+                is BooleanShape -> rust("$field.as_bool()?")
+                is ByteShape -> rust("$field.as_i8()?")
+                is ShortShape -> rust("$field.as_i16()?")
+                is IntegerShape -> rust("$field.as_i32()?")
+                is LongShape -> rust("$field.as_i64()?")
+                is FloatShape -> rust("$field.as_f32()?")
+                is DoubleShape -> rust("$field.as_f64()?")
+                // Enums and constrained strings are parsed into their unconstrained `String`.
+                is StringShape -> rust("$field.as_str()?.to_owned()")
+                is BlobShape -> rustTemplate("#{Blob}::new($field.as_bytes()?)", *codegenScope)
+                is StructureShape -> rust("#T($field.as_bytes()?)?", structureParser(target))
+                else -> throw unsupportedShape(member, target)
+            }
+        }
+
+    private fun wireType(
+        member: MemberShape,
+        element: Shape,
+    ): String =
+        when (element) {
+            is BooleanShape, is ByteShape, is ShortShape, is IntegerShape, is LongShape -> "Varint"
+            is FloatShape -> "Fixed32"
+            is DoubleShape -> "Fixed64"
+            is StringShape, is BlobShape, is StructureShape -> "LengthDelimited"
+            else -> throw unsupportedShape(member, element)
+        }
+
+    private fun repeatedVariable(member: MemberShape) = "repeated_${member.memberName.toSnakeCase()}"
+
+    private fun wrapOptional(
+        member: MemberShape,
+        value: String,
+    ) = if (symbolProvider.toSymbol(member).isOptional()) "Some($value)" else value
+}
@@ -0,0 +1,187 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.protocols
+
+import software.amazon.smithy.model.shapes.BlobShape
+import software.amazon.smithy.model.shapes.BooleanShape
+import software.amazon.smithy.model.shapes.ByteShape
+import software.amazon.smithy.model.shapes.CollectionShape
+import software.amazon.smithy.model.shapes.DoubleShape
+import software.amazon.smithy.model.shapes.FloatShape
+import software.amazon.smithy.model.shapes.IntegerShape
+import software.amazon.smithy.model.shapes.LongShape
+import software.amazon.smithy.model.shapes.MemberShape
+import software.amazon.smithy.model.shapes.OperationShape
+import software.amazon.smithy.model.shapes.Shape
+import software.amazon.smithy.model.shapes.ShapeId
+import software.amazon.smithy.model.shapes.ShortShape
+import software.amazon.smithy.model.shapes.StringShape
+import software.amazon.smithy.model.shapes.StructureShape
+import software.amazon.smithy.model.shapes.UnionShape
+import software.amazon.smithy.rust.codegen.core.rustlang.Writable
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.rustlang.rustBlock
+import software.amazon.smithy.rust.codegen.core.rustlang.rustBlockTemplate
+import software.amazon.smithy.rust.codegen.core.rustlang.rustTemplate
+import software.amazon.smithy.rust.codegen.core.rustlang.writable
+import software.amazon.smithy.rust.codegen.core.smithy.RuntimeType
+import software.amazon.smithy.rust.codegen.core.smithy.generators.serializationError
+import software.amazon.smithy.rust.codegen.core.smithy.isOptional
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.HttpBindingResolver
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.HttpLocation
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.ProtocolFunctions
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.serialize.StructuredDataSerializerGenerator
+import software.amazon.smithy.rust.codegen.core.util.UNREACHABLE
+import software.amazon.smithy.rust.codegen.core.util.outputShape
+import software.amazon.smithy.rust.codegen.server.smithy.ServerCargoDependency
+import software.amazon.smithy.rust.codegen.server.smithy.ServerCodegenContext
+import software.amazon.smithy.rust.codegen.server.smithy.workingWithPublicConstrainedWrapperTupleType
+
+/**
+ * Serializes operation outputs and errors into length-prefixed protobuf messages, for the `smithy.rust#grpc` protocol.
+ *
+ * Each member is encoded with the field number given by [protobufFieldNumber]. Lists are encoded as unpacked repeated
+ * fields, which protobuf parsers accept for every element type.
+ */
+class ProtobufSerializerGenerator(
+    private val codegenContext: ServerCodegenContext,
+    private val httpBindingResolver: HttpBindingResolver,
+) : StructuredDataSerializerGenerator {
+    private val model = codegenContext.model
+    private val symbolProvider = codegenContext.symbolProvider
+    private val runtimeConfig = codegenContext.runtimeConfig
+    private val publicConstrainedTypes = codegenContext.settings.codegenConfig.publicConstrainedTypes
+    private val protocolFunctions = ProtocolFunctions(codegenContext)
+
+    private val grpc = ServerCargoDependency.smithyHttpServer(runtimeConfig).toType().resolve("protocol::grpc")
+    private val codegenScope =
+        arrayOf(
+            *RuntimeType.preludeScope,
+            "Error" to runtimeConfig.serializationError(),
+            "Encoder" to grpc.resolve("wire::Encoder"),
+            "encode_message" to grpc.resolve("framing::encode_message"),
+        )
+
+    override fun payloadSerializer(member: MemberShape): RuntimeType =
+        UNREACHABLE("the `grpc` protocol binds every member to the message")
+
+    override fun unsetStructure(structure: StructureShape): RuntimeType =
+        UNREACHABLE("the `grpc` protocol binds every member to the message")
+
+    override fun unsetUnion(union: UnionShape): RuntimeType =
+        UNREACHABLE("the `grpc` protocol does not support unions")
+
+    override fun operationInputSerializer(operationShape: OperationShape): RuntimeType? =
+        UNREACHABLE("the `grpc` protocol is only supported by the server")
+
+    override fun documentSerializer(): RuntimeType = UNREACHABLE("the `grpc` protocol does not support documents")
+
+    // Unlike other protocols, an output serializer is generated even for operations without modeled output, since
+    // every gRPC response carries a message.
+    override fun operationOutputSerializer(operationShape: OperationShape): RuntimeType =
+        messageSerializer(
+            operationShape.outputShape(model),
+            httpBindingResolver.responseMembers(operationShape, HttpLocation.DOCUMENT),
+            "output",
+        )
+
+    override fun serverErrorSerializer(shape: ShapeId): RuntimeType =
+        messageSerializer(
+            model.expectShape(shape, StructureShape::class.java),
+            httpBindingResolver.errorResponseBindings(shape).filter { it.location == HttpLocation.DOCUMENT }
+                .map { it.member },
+            "error",
+        )
+
+    private fun messageSerializer(
+        structureShape: StructureShape,
+        includedMembers: List<MemberShape>,
+        suffix: String,
+    ): RuntimeType =
+        protocolFunctions.serializeFn(structureShape, fnNameSuffix = suffix) { fnName ->
+            rustBlockTemplate(
+                "pub fn $fnName(value: &#{target}) -> #{Result}<#{Vec}<u8>, #{Error}>",
+                *codegenScope,
+                "target" to symbolProvider.toSymbol(structureShape),
+            ) {
+                if (includedMembers.isEmpty()) {
+                    rust("let _ = value;")
+                    rustTemplate("#{Ok}(#{encode_message}(&[]))", *codegenScope)
+                } else {
+                    rustTemplate("let mut encoder = #{Encoder}::new();", *codegenScope)
+                    includedMembers.forEach { serializeMember(it)(this) }
+                    rustTemplate("#{Ok}(#{encode_message}(&encoder.into_bytes()))", *codegenScope)
+                }
+            }
+        }
+
+    private fun structureSerializer(structureShape: StructureShape): RuntimeType =
+        protocolFunctions.serializeFn(structureShape) { fnName ->
+            rustBlockTemplate(
+                "pub fn $fnName(value: &#{target}) -> #{Encoder}",
+                *codegenScope,
+                "target" to symbolProvider.toSymbol(structureShape),
+            ) {
+                if (structureShape.members().isEmpty()) {
+                    rust("let _ = value;")
+                    rustTemplate("#{Encoder}::new()", *codegenScope)
+                } else {
+                    rustTemplate("let mut encoder = #{Encoder}::new();", *codegenScope)
+                    structureShape.members().forEach { serializeMember(it)(this) }
+                    rust("encoder")
+                }
+            }
+        }
+
+    private fun serializeMember(member: MemberShape): Writable =
+        writable {
+            val memberName = symbolProvider.toMemberName(member)
+            val field = member.protobufFieldNumber(model)
+            val target = model.expectShape(member.target)
+            if (symbolProvider.toSymbol(member).isOptional()) {
+                rustBlock("if let Some(v) = &value.$memberName") {
+                    serializeValue(member, target, field, "v")(this)
+                }
+            } else {
+                rustBlock("") {
+                    rust("let v = &value.$memberName;")
+                    serializeValue(member, target, field, "v")(this)
+                }
+            }
+        }
+
+    /** Encodes the value referenced by [value] as field [field]. */
+    private fun serializeValue(
+        member: MemberShape,
+        target: Shape,
+        field: Int,
+        value: String,
+    ): Writable =
+        writable {
+            val wrapped = workingWithPublicConstrainedWrapperTupleType(target, model, publicConstrainedTypes)
+            val inner = if (wrapped) "$value.0" else "*$value"
+            when (target) {
+                is BooleanShape -> rust("encoder.bool($field, $inner);")
+                is ByteShape, is ShortShape -> rust("encoder.int32($field, i32::from($inner));")
+                is IntegerShape -> rust("encoder.int32($field, $inner);")
+                is LongShape -> rust("encoder.int64($field, $inner);")
+                is FloatShape -> rust("encoder.float($field, $inner);")
+                is DoubleShape -> rust("encoder.double($field, $inner);")
+                // Covers enums and constrained strings too, which all have an `as_str` method.
+                is StringShape -> rust("encoder.string($field, $value.as_str());")
+                is BlobShape -> rust("encoder.bytes($field, ${if (wrapped) "$value.0" else value}.as_ref());")
+                is StructureShape -> rust("encoder.message($field, #T($value));", structureSerializer(target))
+                is CollectionShape -> {
+                    val element = model.expectShape(target.member.target)
+                    checkRepeatedElement(model, member, target, element)
+                    rustBlock("for item in ${if (wrapped) "$value.0" else value}.iter()") {
+                        serializeValue(member, element, field, "item")(this)
+                    }
+                }
+                else -> throw unsupportedShape(member, target)
+            }
+        }
+}
@@ -0,0 +1,155 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.protocols
+
+import software.amazon.smithy.codegen.core.CodegenException
+import software.amazon.smithy.model.Model
+import software.amazon.smithy.model.pattern.UriPattern
+import software.amazon.smithy.model.shapes.CollectionShape
+import software.amazon.smithy.model.shapes.MemberShape
+import software.amazon.smithy.model.shapes.OperationShape
+import software.amazon.smithy.model.shapes.ServiceShape
+import software.amazon.smithy.model.shapes.Shape
+import software.amazon.smithy.model.shapes.ToShapeId
+import software.amazon.smithy.model.traits.HttpTrait
+import software.amazon.smithy.model.traits.SparseTrait
+import software.amazon.smithy.rust.codegen.core.smithy.generators.protocol.ProtocolSupport
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.HttpBindingDescriptor
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.HttpBindingResolver
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.HttpLocation
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.Protocol
+import software.amazon.smithy.rust.codegen.core.smithy.protocols.ProtocolGeneratorFactory
+import software.amazon.smithy.rust.codegen.core.util.getTrait
+import software.amazon.smithy.rust.codegen.core.util.hasTrait
+import software.amazon.smithy.rust.codegen.core.util.isEventStream
+import software.amazon.smithy.rust.codegen.server.smithy.ServerCodegenContext
+import software.amazon.smithy.rust.codegen.server.smithy.generators.protocol.ServerGrpcProtocol
+import software.amazon.smithy.rust.codegen.server.smithy.traits.ProtobufFieldTrait
+
+/**
+ * Binds every member of an operation's input, output and errors to the protobuf message sent in the body.
+ *
+ * Operations are served at `/{namespace}.{ServiceName}/{OperationName}`, the path gRPC clients derive from the
+ * fully qualified name of a `service` in a `.proto` file.
+ */
+class GrpcHttpBindingResolver(
+    private val model: Model,
+    private val serviceShape: ServiceShape,
+) : HttpBindingResolver {
+    private fun bindings(shape: ToShapeId): List<HttpBindingDescriptor> {
+        val members = model.expectShape(shape.toShapeId()).members()
+        if (members.any { it.isEventStream(model) }) {
+            throw CodegenException(
+                "The `smithy.rust#grpc` protocol only supports unary operations, but ${shape.toShapeId()} " +
+                    "has an event stream member",
+            )
+        }
+        return members.map { HttpBindingDescriptor(it, HttpLocation.DOCUMENT, "document") }
+    }
+
+    override fun httpTrait(operationShape: OperationShape): HttpTrait =
+        HttpTrait.builder()
+            .code(200)
+            .method("POST")
+            .uri(UriPattern.parse(path(operationShape)))
+            .build()
+
+    /** The request path of [operationShape], which is also the key the router looks the operation up by. */
+    fun path(operationShape: OperationShape): String =
+        "/${serviceShape.id.namespace}.${serviceShape.id.name}/${operationShape.id.name}"
+
+    override fun requestBindings(operationShape: OperationShape) = bindings(operationShape.inputShape)
+
+    override fun responseBindings(operationShape: OperationShape) = bindings(operationShape.outputShape)
+
+    override fun errorResponseBindings(errorShape: ToShapeId) = bindings(errorShape)
+
+    // Every request and response carries a message, even if the operation has no modeled input or output.
+    override fun requestContentType(operationShape: OperationShape): String = CONTENT_TYPE
+
+    override fun responseContentType(operationShape: OperationShape): String = CONTENT_TYPE
+
+    override fun eventStreamMessageContentType(memberShape: MemberShape): String? = null
+
+    companion object {
+        const val CONTENT_TYPE = "application/grpc"
+    }
+}
+
+private val RESERVED_FIELD_NUMBERS = 19000..19999
+
+/**
+ * The protobuf field number of [this] member, given by its [ProtobufFieldTrait].
+ *
+ * Numbers aren't derived from the order members are declared in, because reordering them would then silently change
+ * the wire format. Codegen fails if a member doesn't have a number, or if it isn't unique within its structure.
+ */
+fun MemberShape.protobufFieldNumber(model: Model): Int {
+    val number =
+        getTrait<ProtobufFieldTrait>()?.number
+            ?: throw CodegenException(
+                "The `smithy.rust#grpc` protocol requires a `@protobufField` field number on every member, " +
+                    "but $id doesn't have one",
+            )
+    if (number in RESERVED_FIELD_NUMBERS) {
+        throw CodegenException("$id uses the protobuf field number $number, which is reserved by protobuf")
+    }
+    model.expectShape(container).members()
+        .firstOrNull { it != this && it.getTrait<ProtobufFieldTrait>()?.number == number }
+        ?.also { other ->
+            throw CodegenException("$id and ${other.id} use the same protobuf field number $number")
+        }
+    return number
+}
+
+/** Rejects lists that can't be encoded as a repeated field. */
+fun checkRepeatedElement(
+    model: Model,
+    member: MemberShape,
+    list: CollectionShape,
+    element: Shape,
+) {
+    if (list.hasTrait<SparseTrait>()) {
+        throw CodegenException("The `smithy.rust#grpc` protocol does not support sparse lists, but $member targets one")
+    }
+    if (element is CollectionShape) {
+        throw CodegenException("The `smithy.rust#grpc` protocol does not support nested lists, but $member targets one")
+    }
+}
+
+fun unsupportedShape(
+    member: MemberShape,
+    target: Shape,
+) = CodegenException("The `smithy.rust#grpc` protocol does not support ${target.type} shapes, but $member targets ${target.id}")
+
+class ServerGrpcFactory(
+    private val additionalServerHttpBoundProtocolCustomizations: List<ServerHttpBoundProtocolCustomization> =
+        emptyList(),
+) : ProtocolGeneratorFactory<ServerHttpBoundProtocolGenerator, ServerCodegenContext> {
+    override fun protocol(codegenContext: ServerCodegenContext): Protocol = ServerGrpcProtocol(codegenContext)
+
+    override fun buildProtocolGenerator(codegenContext: ServerCodegenContext): ServerHttpBoundProtocolGenerator =
+        ServerHttpBoundProtocolGenerator(
+            codegenContext,
+            ServerGrpcProtocol(codegenContext),
+            additionalServerHttpBoundProtocolCustomizations,
+        )
+
+    override fun support(): ProtocolSupport {
+        return ProtocolSupport(
+            // Client support
+            requestSerialization = false,
+            requestBodySerialization = false,
+            responseDeserialization = false,
+            errorDeserialization = false,
+            // Server support
+            requestDeserialization = true,
+            requestBodyDeserialization = true,
+            responseSerialization = true,
+            errorSerialization = true,
+        )
+    }
+}
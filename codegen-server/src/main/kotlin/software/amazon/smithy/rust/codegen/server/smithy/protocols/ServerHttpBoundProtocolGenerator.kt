@@ -21,7 +21,9 @@ import software.amazon.smithy.model.traits.ErrorTrait
 import software.amazon.smithy.model.traits.HttpErrorTrait
 import software.amazon.smithy.model.traits.HttpPayloadTrait
 import software.amazon.smithy.model.traits.HttpTrait
+import software.amazon.smithy.model.traits.LengthTrait
 import software.amazon.smithy.model.traits.MediaTypeTrait
+import software.amazon.smithy.model.traits.RequiresLengthTrait
 import software.amazon.smithy.rust.codegen.core.rustlang.Attribute
 import software.amazon.smithy.rust.codegen.core.rustlang.RustType
 import software.amazon.smithy.rust.codegen.core.rustlang.RustWriter
@@ -51,7 +53,6 @@ import software.amazon.smithy.rust.codegen.core.smithy.mapRustType
 import software.amazon.smithy.rust.codegen.core.smithy.protocols.HttpBindingDescriptor
 import software.amazon.smithy.rust.codegen.core.smithy.protocols.HttpBoundProtocolPayloadGenerator
 import software.amazon.smithy.rust.codegen.core.smithy.protocols.HttpLocation
-import software.amazon.smithy.rust.codegen.core.smithy.protocols.Protocol
 import software.amazon.smithy.rust.codegen.core.smithy.protocols.ProtocolFunctions
 import software.amazon.smithy.rust.codegen.core.smithy.protocols.parse.StructuredDataParserGenerator
 import software.amazon.smithy.rust.codegen.core.smithy.transformers.OperationNormalizer
@@ -64,6 +65,7 @@ import software.amazon.smithy.rust.codegen.core.util.getTrait
 import software.amazon.smithy.rust.codegen.core.util.hasStreamingMember
 import software.amazon.smithy.rust.codegen.core.util.hasTrait
 import software.amazon.smithy.rust.codegen.core.util.inputShape
+import software.amazon.smithy.rust.codegen.core.util.isEventStream
 import software.amazon.smithy.rust.codegen.core.util.isStreaming
 import software.amazon.smithy.rust.codegen.core.util.outputShape
 import software.amazon.smithy.rust.codegen.server.smithy.ServerCargoDependency
@@ -75,6 +77,7 @@ import software.amazon.smithy.rust.codegen.server.smithy.generators.http.ServerR
 import software.amazon.smithy.rust.codegen.server.smithy.generators.protocol.ServerProtocol
 import software.amazon.smithy.rust.codegen.server.smithy.generators.protocol.ServerProtocolGenerator
 import software.amazon.smithy.rust.codegen.server.smithy.generators.serverBuilderSymbol
+import software.amazon.smithy.rust.codegen.server.smithy.traits.MultipartTrait
 import java.util.logging.Logger
 
 data class StreamPayloadSerializerParams(
@@ -123,7 +126,7 @@ class ServerHttpBoundProtocolGenerator(
 
 class ServerHttpBoundProtocolPayloadGenerator(
     codegenContext: CodegenContext,
-    protocol: Protocol,
+    protocol: ServerProtocol,
 ) : ProtocolPayloadGenerator by HttpBoundProtocolPayloadGenerator(
         codegenContext, protocol, HttpMessageType.RESPONSE,
         renderEventStreamBody = { writer, params ->
@@ -135,13 +138,19 @@ class ServerHttpBoundProtocolPayloadGenerator(
                     let signer = #{NoOpSigner}{};
                     let adapter: #{aws_smithy_http}::event_stream::MessageStreamAdapter<_, _> =
                         ${params.outerName}.${params.memberName}.into_body_stream(marshaller, error_marshaller, signer);
-                    adapter
+                    adapter#{WithInitialResponse:W}
                 }
                 """,
                 "aws_smithy_http" to RuntimeType.smithyHttp(codegenContext.runtimeConfig),
                 "NoOpSigner" to RuntimeType.smithyEventStream(codegenContext.runtimeConfig).resolve("frame::NoOpSigner"),
                 "marshallerConstructorFn" to params.marshallerConstructorFn,
                 "errorMarshallerConstructorFn" to params.errorMarshallerConstructorFn,
+                "WithInitialResponse" to
+                    writable {
+                        protocol.serverEventStreamInitialResponse()?.also { initialResponse ->
+                            rustTemplate(".with_initial_message(#{InitialResponse:W})", "InitialResponse" to initialResponse)
+                        }
+                    },
             )
         },
     )
@@ -216,12 +225,18 @@ class ServerHttpBoundProtocolTraitImplGenerator(
     ) {
         val operationName = symbolProvider.toSymbol(operationShape).name
         val staticContentType = "CONTENT_TYPE_${operationName.uppercase()}"
+        val acceptHeaderClassifier =
+            if (codegenContext.settings.codegenConfig.strictAcceptHeaderNegotiation) {
+                "strict_accept_header_classifier"
+            } else {
+                "accept_header_classifier"
+            }
         val verifyAcceptHeader =
             writable {
                 httpBindingResolver.responseContentType(operationShape)?.also { contentType ->
                     rustTemplate(
                         """
-                        if !#{SmithyHttpServer}::protocol::accept_header_classifier(request.headers(), &$staticContentType) {
+                        if !#{SmithyHttpServer}::protocol::$acceptHeaderClassifier(request.headers(), &$staticContentType) {
                             return Err(#{RequestRejection}::NotAcceptable);
                         }
                         """,
@@ -827,13 +842,74 @@ class ServerHttpBoundProtocolTraitImplGenerator(
                 val deserializer =
                     httpBindingGenerator.generateDeserializePayloadFn(binding, structuredHandler = structureShapeHandler)
                 return writable {
-                    if (binding.member.isStreaming(model)) {
+                    if (binding.member.isEventStream(model) && protocol.serverEventStreamInitialResponse() != null) {
+                        // Clients of RPC protocols send an `initial-request` message before the events. It holds
+                        // no members, since the event stream is the sole member of the operation input, so it is
+                        // consumed here before handing the receiver to the handler.
+                        rustTemplate(
+                            """
+                            {
+                                let mut receiver = #{Deserializer}(&mut body.into().into_inner())?;
+                                receiver.try_recv_initial_request().await.map_err(#{BoxError}::from)?;
+                                Some(receiver)
+                            }
+                            """,
+                            "Deserializer" to deserializer,
+                            "BoxError" to RuntimeType.smithyHttp(runtimeConfig).resolve("event_stream::BoxError"),
+                        )
+                    } else if (binding.member.isStreaming(model)) {
+                        val target = model.expectShape(binding.member.target)
+                        val requiresLength = target.hasTrait<RequiresLengthTrait>()
+                        val maxLength =
+                            (binding.member.getTrait<LengthTrait>() ?: target.getTrait<LengthTrait>())?.max?.orElse(null)
+                        val body =
+                            if (maxLength != null) {
+                                "#{SmithyHttpServer}::protocol::limit_streaming_payload(body.into().into_inner(), $maxLength)"
+                            } else {
+                                "body.into().into_inner()"
+                            }
                         rustTemplate(
                             """
                             {
-                                Some(#{Deserializer}(&mut body.into().into_inner())?)
+                                #{CheckMultipartContentType:W}
+                                #{CheckStreamingPayloadLength:W}
+                                Some(#{Deserializer}(&mut $body)?)
                             }
                             """,
+                            "CheckMultipartContentType" to
+                                writable {
+                                    // Multipart payloads are streamed to the handler as-is, so only the media type
+                                    // is checked here; the boundary is parsed by the handler.
+                                    if (operationShape.hasTrait<MultipartTrait>()) {
+                                        rustTemplate(
+                                            """
+                                            #{SmithyHttpServer}::protocol::content_type_header_classifier_smithy(
+                                                &headers,
+                                                Some("multipart/form-data"),
+                                            )?;
+                                            """,
+                                            *codegenScope,
+                                        )
+                                    }
+                                },
+                            "CheckStreamingPayloadLength" to
+                                writable {
+                                    // A declared `Content-Length` is checked upfront; the payload is additionally
+                                    // limited above, since the header is absent from chunked requests.
+                                    if (requiresLength || maxLength != null) {
+                                        val max = maxLength?.let { "Some($it)" } ?: "None"
+                                        rustTemplate(
+                                            """
+                                            #{SmithyHttpServer}::protocol::streaming_payload_length_classifier(
+                                                &headers,
+                                                $requiresLength,
+                                                $max,
+                                            )?;
+                                            """,
+                                            *codegenScope,
+                                        )
+                                    }
+                                },
                             "Deserializer" to deserializer,
                             *codegenScope,
                         )
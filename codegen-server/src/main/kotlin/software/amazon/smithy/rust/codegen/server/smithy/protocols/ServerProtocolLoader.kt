@@ -20,6 +20,7 @@ import software.amazon.smithy.rust.codegen.core.smithy.protocols.ProtocolMap
 import software.amazon.smithy.rust.codegen.core.util.isOutputEventStream
 import software.amazon.smithy.rust.codegen.server.smithy.ServerCodegenContext
 import software.amazon.smithy.rust.codegen.server.smithy.generators.protocol.ServerProtocolGenerator
+import software.amazon.smithy.rust.codegen.server.smithy.traits.GrpcTrait
 
 class StreamPayloadSerializerCustomization : ServerHttpBoundProtocolCustomization() {
     override fun section(section: ServerHttpBoundProtocolSection): Writable =
@@ -87,6 +88,7 @@ class ServerProtocolLoader(supportedProtocols: ProtocolMap<ServerProtocolGenerat
                                 StreamPayloadSerializerCustomization(),
                             ),
                     ),
+                GrpcTrait.ID to ServerGrpcFactory(),
             )
     }
 }
@@ -0,0 +1,25 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.traits
+
+import software.amazon.smithy.model.node.ObjectNode
+import software.amazon.smithy.model.shapes.ShapeId
+import software.amazon.smithy.model.traits.AnnotationTrait
+
+/**
+ * Protocol trait for services served over gRPC, with protobuf-encoded messages.
+ *
+ * Only the server supports this protocol; see `aws_smithy_http_server::protocol::grpc`.
+ */
+class GrpcTrait(node: ObjectNode) : AnnotationTrait(ID, node) {
+    constructor() : this(ObjectNode.objectNode())
+
+    class Provider : AnnotationTrait.Provider<GrpcTrait>(ID, ::GrpcTrait)
+
+    companion object {
+        val ID: ShapeId = ShapeId.from("smithy.rust#grpc")
+    }
+}
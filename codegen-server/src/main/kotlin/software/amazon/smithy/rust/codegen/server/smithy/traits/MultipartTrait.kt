@@ -0,0 +1,26 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.traits
+
+import software.amazon.smithy.model.node.ObjectNode
+import software.amazon.smithy.model.shapes.ShapeId
+import software.amazon.smithy.model.traits.AnnotationTrait
+
+/**
+ * Marks an operation whose streaming `@httpPayload` input member is a `multipart/form-data` body.
+ *
+ * The generated server checks the request's `Content-Type` before binding the raw body to the payload member, and
+ * handlers read the parts with `aws_smithy_http_server::request::multipart::Multipart`.
+ */
+class MultipartTrait(node: ObjectNode) : AnnotationTrait(ID, node) {
+    constructor() : this(ObjectNode.objectNode())
+
+    class Provider : AnnotationTrait.Provider<MultipartTrait>(ID, ::MultipartTrait)
+
+    companion object {
+        val ID: ShapeId = ShapeId.from("smithy.rust#multipart")
+    }
+}
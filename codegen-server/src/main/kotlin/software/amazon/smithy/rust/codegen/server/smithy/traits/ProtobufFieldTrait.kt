@@ -0,0 +1,38 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.traits
+
+import software.amazon.smithy.model.SourceLocation
+import software.amazon.smithy.model.node.Node
+import software.amazon.smithy.model.shapes.ShapeId
+import software.amazon.smithy.model.traits.AbstractTrait
+import software.amazon.smithy.model.traits.Trait
+
+/**
+ * The protobuf field number of a structure member, for services using the `smithy.rust#grpc` protocol.
+ *
+ * Field numbers are part of the wire format, so they're given explicitly rather than derived from the order members
+ * are declared in.
+ */
+class ProtobufFieldTrait(val number: Int, sourceLocation: SourceLocation = SourceLocation.NONE) :
+    AbstractTrait(ID, sourceLocation) {
+    override fun createNode(): Node = Node.from(number)
+
+    class Provider : AbstractTrait.Provider(ID) {
+        override fun createTrait(
+            target: ShapeId,
+            value: Node,
+        ): Trait {
+            val result = ProtobufFieldTrait(value.expectNumberNode().value.toInt(), value.sourceLocation)
+            result.setNodeCache(value)
+            return result
+        }
+    }
+
+    companion object {
+        val ID: ShapeId = ShapeId.from("smithy.rust#protobufField")
+    }
+}
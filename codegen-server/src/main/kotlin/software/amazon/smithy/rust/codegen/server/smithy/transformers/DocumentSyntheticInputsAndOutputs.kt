@@ -0,0 +1,48 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.transformers
+
+import software.amazon.smithy.model.Model
+import software.amazon.smithy.model.shapes.ShapeId
+import software.amazon.smithy.model.shapes.StructureShape
+import software.amazon.smithy.model.traits.DocumentationTrait
+import software.amazon.smithy.model.transform.ModelTransformer
+import software.amazon.smithy.rust.codegen.core.smithy.traits.SyntheticInputTrait
+import software.amazon.smithy.rust.codegen.core.smithy.traits.SyntheticOutputTrait
+import software.amazon.smithy.rust.codegen.core.util.getTrait
+import software.amazon.smithy.rust.codegen.core.util.hasTrait
+import software.amazon.smithy.rust.codegen.core.util.toPascalCase
+
+/**
+ * Documents the synthetic input and output shapes added by `OperationNormalizer` that don't carry any model
+ * documentation, linking them back to the operation they belong to.
+ *
+ * Operations defined without an input or output, and inline `input := { ... }` structures without a doc comment,
+ * would otherwise render as undocumented `crate::input` and `crate::output` types.
+ */
+object DocumentSyntheticInputsAndOutputs {
+    fun transform(model: Model): Model =
+        ModelTransformer.create().mapShapes(model) { shape ->
+            if (shape !is StructureShape || shape.hasTrait<DocumentationTrait>()) {
+                return@mapShapes shape
+            }
+            val inputOf = shape.getTrait<SyntheticInputTrait>()?.operation
+            val outputOf = shape.getTrait<SyntheticOutputTrait>()?.operation
+            when {
+                inputOf != null -> shape.toBuilder().addTrait(DocumentationTrait(docs("Input", inputOf))).build()
+                outputOf != null -> shape.toBuilder().addTrait(DocumentationTrait(docs("Output", outputOf))).build()
+                else -> shape
+            }
+        }
+
+    private fun docs(
+        kind: String,
+        operation: ShapeId,
+    ): String {
+        val operationName = operation.name.toPascalCase()
+        return "$kind of the [`$operationName`](crate::operation_shape::$operationName) operation."
+    }
+}
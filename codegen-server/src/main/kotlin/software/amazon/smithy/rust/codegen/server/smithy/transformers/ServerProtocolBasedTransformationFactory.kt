@@ -19,6 +19,7 @@ import software.amazon.smithy.model.transform.ModelTransformer
 import software.amazon.smithy.protocol.traits.Rpcv2CborTrait
 import software.amazon.smithy.rust.codegen.core.util.hasTrait
 import software.amazon.smithy.rust.codegen.server.smithy.ServerRustSettings
+import software.amazon.smithy.rust.codegen.server.smithy.traits.GrpcTrait
 import software.amazon.smithy.utils.SmithyBuilder
 import software.amazon.smithy.utils.ToSmithyBuilder
 
@@ -37,11 +38,11 @@ object ServerProtocolBasedTransformationFactory {
         settings: ServerRustSettings,
     ): Model {
         val service = settings.getService(model)
-        if (!service.hasTrait<Rpcv2CborTrait>()) {
+        if (!service.hasTrait<Rpcv2CborTrait>() && !service.hasTrait<GrpcTrait>()) {
             return model
         }
 
-        // `rpcv2Cbor` does not support:
+        // `rpcv2Cbor` and `grpc` do not support:
         // 1. `@httpPayload` trait.
         // 2. `@httpLabel` trait.
         // 3. `@streaming` trait applied to a `Blob` (data streaming).
@@ -0,0 +1,84 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy
+
+import io.kotest.matchers.string.shouldContain
+import org.junit.jupiter.api.Test
+import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
+import software.amazon.smithy.rust.codegen.server.smithy.testutil.serverIntegrationTest
+
+internal class ServerModelDocumentationTest {
+    private val model =
+        """
+        ${'$'}version: "2"
+
+        namespace com.example
+
+        use aws.protocols#restJson1
+
+        /// Catalogues Pokémon.
+        @restJson1
+        @externalDocumentation("User guide": "https://example.com/guide")
+        service PokemonService {
+            operations: [GetPokemon, Ping]
+        }
+
+        /// Retrieves a Pokémon by name.
+        @externalDocumentation("API reference": "https://example.com/api/get-pokemon")
+        @http(method: "GET", uri: "/pokemon/{name}")
+        @readonly
+        operation GetPokemon {
+            input := {
+                @required
+                @httpLabel
+                name: String
+            }
+            output := {
+                /// The Pokémon's species.
+                species: String
+            }
+            errors: [ResourceNotFoundException]
+        }
+
+        @http(method: "GET", uri: "/ping")
+        @readonly
+        operation Ping {}
+
+        /// The requested Pokémon doesn't exist.
+        @externalDocumentation("Troubleshooting": "https://example.com/troubleshooting")
+        @error("client")
+        @httpError(404)
+        structure ResourceNotFoundException {
+            message: String
+        }
+        """.asSmithyModel()
+
+    @Test
+    fun `model documentation is rendered into the generated crate`() {
+        val path = serverIntegrationTest(model)
+
+        val lib = path.resolve("src/lib.rs").toFile().readText()
+        lib.shouldContain("pub mod docs")
+        lib.shouldContain("/// Catalogues Pokémon.")
+        lib.shouldContain("/// - [User guide](https://example.com/guide)")
+        lib.shouldContain("/// ### [`GetPokemon`](crate::operation_shape::GetPokemon)")
+        lib.shouldContain("/// - Input: [`GetPokemonInput`](crate::input::GetPokemonInput)")
+        lib.shouldContain("/// - Errors: [`GetPokemonError`](crate::error::GetPokemonError)")
+        lib.shouldContain("/// - Handler: [`PokemonServiceBuilder::get_pokemon`](crate::PokemonServiceBuilder::get_pokemon)")
+
+        val operationShape = path.resolve("src/operation_shape.rs").toFile().readText()
+        operationShape.shouldContain("/// - [API reference](https://example.com/api/get-pokemon)")
+
+        val service = path.resolve("src/service.rs").toFile().readText()
+        service.shouldContain("/// Retrieves a Pokémon by name.")
+
+        val input = path.resolve("src/input.rs").toFile().readText()
+        input.shouldContain("/// Input of the [`Ping`](crate::operation_shape::Ping) operation.")
+
+        val error = path.resolve("src/error.rs").toFile().readText()
+        error.shouldContain("/// - [Troubleshooting](https://example.com/troubleshooting)")
+    }
+}
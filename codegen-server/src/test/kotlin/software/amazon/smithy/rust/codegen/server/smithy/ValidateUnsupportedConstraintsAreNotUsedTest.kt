@@ -141,17 +141,24 @@ internal class ValidateUnsupportedConstraintsAreNotUsedTest {
         """.asSmithyModel()
 
     @Test
-    fun `it should detect when constraint traits on streaming blob shapes are used`() {
+    fun `it should not detect constraint traits on streaming blob shapes`() {
         val validationResult = validateModel(constraintTraitOnStreamingBlobShapeModel)
 
-        validationResult.messages shouldHaveSize 1
-        validationResult.messages[0].message shouldContain
-            """
-            The blob shape `test#StreamingBlob` has both the `smithy.api#length` and `smithy.api#streaming` constraint traits attached.
-            It is unclear what the semantics for streaming blob shapes are.
-            """.trimIndent().replace("\n", " ")
+        validationResult.messages shouldHaveSize 0
     }
 
+    private val rangeTraitOnFloatShapeModel =
+        """
+        $baseModel
+
+        structure TestInputOutput {
+            rangeFloat: RangeFloat
+        }
+
+        @range(min: 1)
+        float RangeFloat
+        """.asSmithyModel()
+
     private val constrainedShapesInEventStreamModel =
         """
         $baseModel
@@ -261,7 +268,7 @@ internal class ValidateUnsupportedConstraintsAreNotUsedTest {
 
     @Test
     fun `it should abort when ignoreUnsupportedConstraints is false and unsupported constraints are used`() {
-        val validationResult = validateModel(constraintTraitOnStreamingBlobShapeModel, ServerCodegenConfig())
+        val validationResult = validateModel(rangeTraitOnFloatShapeModel, ServerCodegenConfig())
 
         validationResult.messages shouldHaveAtLeastSize 1
         validationResult.shouldAbort shouldBe true
@@ -271,7 +278,7 @@ internal class ValidateUnsupportedConstraintsAreNotUsedTest {
     fun `it should not abort when ignoreUnsupportedConstraints is true and unsupported constraints are used`() {
         val validationResult =
             validateModel(
-                constraintTraitOnStreamingBlobShapeModel,
+                rangeTraitOnFloatShapeModel,
                 ServerCodegenConfig().copy(ignoreUnsupportedConstraints = true),
             )
 
@@ -281,7 +288,7 @@ internal class ValidateUnsupportedConstraintsAreNotUsedTest {
 
     @Test
     fun `it should set log level to error when ignoreUnsupportedConstraints is false and unsupported constraints are used`() {
-        val validationResult = validateModel(constraintTraitOnStreamingBlobShapeModel, ServerCodegenConfig())
+        val validationResult = validateModel(rangeTraitOnFloatShapeModel, ServerCodegenConfig())
 
         validationResult.messages shouldHaveAtLeastSize 1
         validationResult.messages.shouldForAll { it.level shouldBe Level.SEVERE }
@@ -291,7 +298,7 @@ internal class ValidateUnsupportedConstraintsAreNotUsedTest {
     fun `it should set log level to warn when ignoreUnsupportedConstraints is true and unsupported constraints are used`() {
         val validationResult =
             validateModel(
-                constraintTraitOnStreamingBlobShapeModel,
+                rangeTraitOnFloatShapeModel,
                 ServerCodegenConfig().copy(ignoreUnsupportedConstraints = true),
             )
 
@@ -103,6 +103,24 @@ class ServerOperationErrorGeneratorTest {
                     let error: GreetingError = variant.into();
                 """,
             )
+            unitTest(
+                name = "downcasts_boxed_errors_into_combined_error_enums",
+                test = """
+                    use aws_smithy_http_server::operation::IntoServiceError;
+                    type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+                    let variant = InvalidGreeting { message: String::from("an error") };
+                    let error: Result<GreetingError, BoxError> = BoxError::from(variant).into_service_error();
+                    assert!(error.unwrap().is_invalid_greeting());
+
+                    let error: Result<GreetingError, BoxError> =
+                        BoxError::from(GreetingError::FooException(FooException::builder().build())).into_service_error();
+                    assert!(error.unwrap().is_foo_exception());
+
+                    let error: Result<GreetingError, BoxError> = BoxError::from("not modeled").into_service_error();
+                    assert_eq!("not modeled", error.unwrap_err().to_string());
+                """,
+            )
             project.renderInlineMemoryModules()
             project.compileAndTest()
         }
@@ -9,6 +9,7 @@ import org.junit.jupiter.api.Test
 import software.amazon.smithy.rust.codegen.core.rustlang.rust
 import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
 import software.amazon.smithy.rust.codegen.core.testutil.testModule
+import software.amazon.smithy.rust.codegen.core.testutil.tokioTest
 import software.amazon.smithy.rust.codegen.server.smithy.testutil.serverIntegrationTest
 import java.io.File
 
@@ -36,4 +37,117 @@ internal class ServerServiceGeneratorTest {
             }
         }
     }
+
+    @Test
+    fun `layers can be applied to individual operations`() {
+        val model =
+            """
+            ${'$'}version: "2"
+
+            namespace com.example
+
+            use aws.protocols#restJson1
+
+            @restJson1
+            service PokemonService {
+                operations: [GetPokemon, Ping]
+            }
+
+            @http(method: "GET", uri: "/pokemon/{name}")
+            @readonly
+            operation GetPokemon {
+                input := {
+                    @required
+                    @httpLabel
+                    name: String
+                }
+            }
+
+            @http(method: "GET", uri: "/ping")
+            @readonly
+            operation Ping {}
+            """.asSmithyModel()
+
+        serverIntegrationTest(model) { _, rustCrate ->
+            rustCrate.testModule {
+                rust(
+                    """
+                    use std::sync::{Arc, Mutex};
+                    use std::task::{Context, Poll};
+                    use tower::ServiceExt;
+
+                    /// Records the names requested from `GetPokemon`, which only compiles for its input.
+                    ##[derive(Clone)]
+                    struct RecordNamesLayer(Arc<Mutex<Vec<String>>>);
+
+                    impl<S> tower::Layer<S> for RecordNamesLayer {
+                        type Service = RecordNames<S>;
+
+                        fn layer(&self, inner: S) -> Self::Service {
+                            RecordNames { inner, names: self.0.clone() }
+                        }
+                    }
+
+                    ##[derive(Clone)]
+                    struct RecordNames<S> {
+                        inner: S,
+                        names: Arc<Mutex<Vec<String>>>,
+                    }
+
+                    impl<S, Exts> tower::Service<(crate::input::GetPokemonInput, Exts)> for RecordNames<S>
+                    where
+                        S: tower::Service<(crate::input::GetPokemonInput, Exts)>,
+                    {
+                        type Response = S::Response;
+                        type Error = S::Error;
+                        type Future = S::Future;
+
+                        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+                            self.inner.poll_ready(cx)
+                        }
+
+                        fn call(&mut self, request: (crate::input::GetPokemonInput, Exts)) -> Self::Future {
+                            self.names.lock().unwrap().push(request.0.name.clone());
+                            self.inner.call(request)
+                        }
+                    }
+
+                    async fn get_pokemon(_input: crate::input::GetPokemonInput) -> crate::output::GetPokemonOutput {
+                        crate::output::GetPokemonOutput {}
+                    }
+
+                    async fn ping(_input: crate::input::PingInput) -> crate::output::PingOutput {
+                        crate::output::PingOutput {}
+                    }
+
+                    fn request(uri: &str) -> http::Request<hyper::Body> {
+                        http::Request::get(uri).body(hyper::Body::empty()).unwrap()
+                    }
+                    """,
+                )
+
+                tokioTest("the_layer_only_applies_to_its_operation") {
+                    rust(
+                        """
+                        let names = Arc::new(Mutex::new(Vec::new()));
+                        let config = crate::PokemonServiceConfig::builder().build();
+                        let service = crate::PokemonService::builder(config)
+                            .get_pokemon_layer(RecordNamesLayer(names.clone()))
+                            .get_pokemon(get_pokemon)
+                            .ping(ping)
+                            .build()
+                            .unwrap();
+
+                        let response = service.clone().oneshot(request("/pokemon/pikachu")).await.unwrap();
+                        assert_eq!(200, response.status());
+                        let response = service.oneshot(request("/ping")).await.unwrap();
+                        assert_eq!(200, response.status());
+
+                        assert_eq!(vec!["pikachu".to_owned()], *names.lock().unwrap());
+                        """,
+                    )
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,111 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.protocols
+
+import org.junit.jupiter.params.ParameterizedTest
+import org.junit.jupiter.params.provider.ValueSource
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.testutil.IntegrationTestParams
+import software.amazon.smithy.rust.codegen.core.testutil.ServerAdditionalSettings
+import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
+import software.amazon.smithy.rust.codegen.core.testutil.testModule
+import software.amazon.smithy.rust.codegen.core.testutil.tokioTest
+import software.amazon.smithy.rust.codegen.server.smithy.testutil.serverIntegrationTest
+
+/**
+ * Tests whether the server `codegen` flag `strictAcceptHeaderNegotiation` works as expected.
+ */
+internal class ServerAcceptHeaderNegotiationTest {
+    private val model =
+        """
+        ${'$'}version: "2"
+
+        namespace com.example
+
+        use aws.protocols#restJson1
+
+        @restJson1
+        service PokemonService {
+            operations: [GetPokemon]
+        }
+
+        @http(method: "GET", uri: "/pokemon")
+        @readonly
+        operation GetPokemon {
+            output := {
+                name: String
+            }
+        }
+        """.asSmithyModel()
+
+    @ParameterizedTest
+    @ValueSource(booleans = [true, false])
+    fun `accept headers are negotiated`(strict: Boolean) {
+        serverIntegrationTest(
+            model,
+            IntegrationTestParams(
+                additionalSettings =
+                    ServerAdditionalSettings.builder()
+                        .strictAcceptHeaderNegotiation(strict)
+                        .build(),
+            ),
+        ) { _, rustCrate ->
+            rustCrate.testModule {
+                rust(
+                    """
+                    use tower::ServiceExt;
+
+                    async fn get_pokemon(_input: crate::input::GetPokemonInput) -> crate::output::GetPokemonOutput {
+                        crate::output::GetPokemonOutput { name: None }
+                    }
+
+                    async fn status(accept: &'static str) -> http::StatusCode {
+                        let config = crate::PokemonServiceConfig::builder().build();
+                        let service = crate::PokemonService::builder(config)
+                            .get_pokemon(get_pokemon)
+                            .build()
+                            .unwrap();
+                        let request = http::Request::get("/pokemon")
+                            .header("accept", accept)
+                            .body(hyper::Body::empty())
+                            .unwrap();
+                        service.oneshot(request).await.unwrap().status()
+                    }
+                    """,
+                )
+
+                tokioTest("matching_media_ranges_are_accepted") {
+                    rust(
+                        """
+                        assert_eq!(200, status("application/json").await);
+                        assert_eq!(200, status("application/*, text/plain").await);
+                        assert_eq!(200, status("*/*").await);
+                        """,
+                    )
+                }
+
+                tokioTest("incompatible_media_ranges_are_not_acceptable") {
+                    rust(
+                        """
+                        assert_eq!(406, status("text/plain").await);
+                        assert_eq!(406, status("text/*").await);
+                        """,
+                    )
+                }
+
+                val q0Status = if (strict) 406 else 200
+                tokioTest("media_ranges_with_zero_quality_are_not_acceptable_when_strict") {
+                    rust(
+                        """
+                        assert_eq!($q0Status, status("application/json; q=0").await);
+                        assert_eq!($q0Status, status("application/json; q=0, */*").await);
+                        """,
+                    )
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,261 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.protocols
+
+import io.kotest.assertions.throwables.shouldThrow
+import io.kotest.matchers.string.shouldContain
+import org.junit.jupiter.api.Test
+import software.amazon.smithy.codegen.core.CodegenException
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
+import software.amazon.smithy.rust.codegen.core.testutil.testModule
+import software.amazon.smithy.rust.codegen.core.testutil.tokioTest
+import software.amazon.smithy.rust.codegen.server.smithy.testutil.serverIntegrationTest
+
+internal class ServerGrpcTest {
+    private val model =
+        """
+        ${'$'}version: "2"
+
+        namespace com.example
+
+        use smithy.rust#grpc
+        use smithy.rust#protobufField
+
+        @grpc
+        service PokemonService {
+            operations: [GetPokemon]
+        }
+
+        operation GetPokemon {
+            input := {
+                @required
+                @protobufField(1)
+                name: String
+                @protobufField(2)
+                level: Integer
+                @protobufField(3)
+                moves: MoveList
+            }
+            output := {
+                @required
+                @protobufField(1)
+                name: String
+                @protobufField(2)
+                species: Species
+                @protobufField(3)
+                levels: LevelList
+            }
+            errors: [ResourceNotFoundException]
+        }
+
+        list MoveList {
+            member: String
+        }
+
+        list LevelList {
+            member: Integer
+        }
+
+        structure Species {
+            @protobufField(1)
+            name: String
+            @protobufField(2)
+            weight: Double
+        }
+
+        @error("client")
+        @httpError(404)
+        structure ResourceNotFoundException {
+            @protobufField(1)
+            message: String
+        }
+        """.asSmithyModel()
+
+    @Test
+    fun `operations are served over gRPC`() {
+        serverIntegrationTest(model) { _, rustCrate ->
+            rustCrate.testModule {
+                rust(
+                    """
+                    use aws_smithy_http_server::protocol::grpc::framing;
+                    use aws_smithy_http_server::protocol::grpc::wire::{Decoder, Encoder, WireType};
+                    use hyper::body::HttpBody as _;
+                    use tower::ServiceExt;
+
+                    async fn get_pokemon(
+                        input: crate::input::GetPokemonInput,
+                    ) -> Result<crate::output::GetPokemonOutput, crate::error::GetPokemonError> {
+                        if input.name == "missingno" {
+                            return Err(crate::error::ResourceNotFoundException { message: None }.into());
+                        }
+                        let moves = input.moves.unwrap_or_default();
+                        Ok(crate::output::GetPokemonOutput {
+                            name: input.name,
+                            species: Some(crate::model::Species {
+                                name: Some("electric mouse".to_owned()),
+                                weight: Some(6.0),
+                            }),
+                            levels: Some(vec![input.level.unwrap_or_default(); moves.len()]),
+                        })
+                    }
+
+                    fn request(message: Encoder) -> http::Request<hyper::Body> {
+                        http::Request::post("/com.example.PokemonService/GetPokemon")
+                            .header("content-type", "application/grpc+proto")
+                            .body(hyper::Body::from(framing::encode_message(&message.into_bytes())))
+                            .unwrap()
+                    }
+
+                    fn service() -> crate::PokemonService<
+                        aws_smithy_http_server::routing::RoutingService<
+                            aws_smithy_http_server::protocol::grpc::router::GrpcRouter<
+                                aws_smithy_http_server::routing::Route<hyper::Body>,
+                            >,
+                            aws_smithy_http_server::protocol::grpc::Grpc,
+                        >,
+                    > {
+                        let config = crate::PokemonServiceConfig::builder().build();
+                        crate::PokemonService::builder(config).get_pokemon(get_pokemon).build().unwrap()
+                    }
+                    """,
+                )
+
+                tokioTest("successful_calls_return_a_message") {
+                    rust(
+                        """
+                        let mut message = Encoder::new();
+                        message
+                            .string(1, "pikachu")
+                            .int32(2, 25)
+                            .string(3, "thunderbolt")
+                            .string(3, "quick attack");
+                        let mut response = service().oneshot(request(message)).await.unwrap();
+                        assert_eq!(200, response.status());
+
+                        let body = hyper::body::to_bytes(response.body_mut()).await.unwrap();
+                        let mut decoder = Decoder::new(framing::decode_message(&body).unwrap());
+                        let (mut name, mut species, mut levels) = (None, None, Vec::new());
+                        while let Some(field) = decoder.next_field().unwrap() {
+                            match field.number() {
+                                1 => name = Some(field.as_str().unwrap()),
+                                2 => species = Some(field.as_bytes().unwrap()),
+                                3 => {
+                                    for level in field.elements(WireType::Varint).unwrap() {
+                                        levels.push(level.as_i32().unwrap());
+                                    }
+                                }
+                                other => panic!("unexpected field {other}"),
+                            }
+                        }
+                        assert_eq!(Some("pikachu"), name);
+                        assert_eq!(vec![25, 25], levels);
+                        let species = Decoder::new(species.unwrap()).next_field().unwrap().unwrap();
+                        assert_eq!("electric mouse", species.as_str().unwrap());
+
+                        let trailers = response.body_mut().trailers().await.unwrap().unwrap();
+                        assert_eq!("0", trailers["grpc-status"]);
+                        """,
+                    )
+                }
+
+                tokioTest("errors_are_sent_in_trailers_only_responses") {
+                    rust(
+                        """
+                        let mut message = Encoder::new();
+                        message.string(1, "missingno");
+                        let response = service().oneshot(request(message)).await.unwrap();
+                        assert_eq!(200, response.status());
+                        assert_eq!("5", response.headers()["grpc-status"]);
+                        assert_eq!("ResourceNotFoundException", response.headers()["grpc-message"]);
+                        """,
+                    )
+                }
+
+                tokioTest("malformed_messages_are_rejected") {
+                    rust(
+                        """
+                        let request = http::Request::post("/com.example.PokemonService/GetPokemon")
+                            .header("content-type", "application/grpc")
+                            .body(hyper::Body::from(vec![1, 0, 0, 0, 0]))
+                            .unwrap();
+                        let response = service().oneshot(request).await.unwrap();
+                        assert_eq!("3", response.headers()["grpc-status"]);
+                        assert_eq!("SerializationException", response.headers()["grpc-message"]);
+                        """,
+                    )
+                }
+            }
+        }
+    }
+
+    @Test
+    fun `shapes protobuf messages can't hold are rejected`() {
+        val model =
+            """
+            ${'$'}version: "2"
+
+            namespace com.example
+
+            use smithy.rust#grpc
+            use smithy.rust#protobufField
+
+            @grpc
+            service PokemonService {
+                operations: [GetPokemon]
+            }
+
+            operation GetPokemon {
+                input := {
+                    @protobufField(1)
+                    stats: Stats
+                }
+            }
+
+            map Stats {
+                key: String
+                value: Integer
+            }
+            """.asSmithyModel()
+
+        val codegenException =
+            shouldThrow<CodegenException> {
+                serverIntegrationTest(model) { _, _ -> }
+            }
+        codegenException.message.shouldContain("does not support map shapes")
+    }
+
+    @Test
+    fun `members without a unique field number are rejected`() {
+        fun model(members: String) =
+            """
+            ${'$'}version: "2"
+
+            namespace com.example
+
+            use smithy.rust#grpc
+            use smithy.rust#protobufField
+
+            @grpc
+            service PokemonService {
+                operations: [GetPokemon]
+            }
+
+            operation GetPokemon {
+                input := {
+                    $members
+                }
+            }
+            """.asSmithyModel()
+
+        shouldThrow<CodegenException> {
+            serverIntegrationTest(model("@protobufField(1) name: String, level: Integer")) { _, _ -> }
+        }.message.shouldContain("doesn't have one")
+        shouldThrow<CodegenException> {
+            serverIntegrationTest(model("@protobufField(1) name: String, @protobufField(1) level: Integer")) { _, _ -> }
+        }.message.shouldContain("use the same protobuf field number 1")
+    }
+}
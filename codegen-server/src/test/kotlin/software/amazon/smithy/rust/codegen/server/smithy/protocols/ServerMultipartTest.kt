@@ -0,0 +1,78 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.protocols
+
+import org.junit.jupiter.api.Test
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
+import software.amazon.smithy.rust.codegen.core.testutil.testModule
+import software.amazon.smithy.rust.codegen.server.smithy.testutil.serverIntegrationTest
+
+internal class ServerMultipartTest {
+    private val model =
+        """
+        ${'$'}version: "2"
+
+        namespace com.example
+
+        use aws.protocols#restJson1
+        use smithy.rust#multipart
+
+        @restJson1
+        service UploadService {
+            operations: [Upload]
+        }
+
+        @multipart
+        @http(uri: "/upload", method: "POST")
+        operation Upload {
+            input := {
+                @httpPayload
+                @required
+                body: StreamingBlob
+            }
+        }
+
+        @streaming
+        blob StreamingBlob
+        """.asSmithyModel()
+
+    @Test
+    fun `multipart operations can stream their parts`() {
+        serverIntegrationTest(model) { _, rustCrate ->
+            rustCrate.testModule {
+                // No actual tests: we just want to check that this compiles.
+                rust(
+                    """
+                    use aws_smithy_http_server::request::multipart::{
+                        Multipart, MultipartBoundary, MultipartError, MultipartLimits,
+                    };
+
+                    async fn _upload(
+                        input: crate::input::UploadInput,
+                        boundary: MultipartBoundary,
+                    ) -> crate::output::UploadOutput {
+                        let _: Result<(), MultipartError> = async {
+                            let mut multipart = Multipart::new(input.body, boundary, MultipartLimits::new().max_parts(2));
+                            while let Some(part) = multipart.next_part().await? {
+                                let _ = part.bytes().await?;
+                            }
+                            Ok(())
+                        }
+                        .await;
+                        crate::output::UploadOutput {}
+                    }
+
+                    fn _build_service() -> crate::UploadService {
+                        let config = crate::UploadServiceConfig::builder().build();
+                        crate::UploadService::builder(config).upload(_upload).build_unchecked().boxed()
+                    }
+                    """,
+                )
+            }
+        }
+    }
+}
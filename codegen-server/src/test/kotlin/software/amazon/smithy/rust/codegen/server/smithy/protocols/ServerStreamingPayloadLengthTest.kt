@@ -0,0 +1,128 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.protocols
+
+import org.junit.jupiter.api.Test
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
+import software.amazon.smithy.rust.codegen.core.testutil.testModule
+import software.amazon.smithy.rust.codegen.core.testutil.tokioTest
+import software.amazon.smithy.rust.codegen.server.smithy.testutil.serverIntegrationTest
+
+internal class ServerStreamingPayloadLengthTest {
+    private val model =
+        """
+        ${'$'}version: "2"
+
+        namespace com.example
+
+        use aws.protocols#restJson1
+
+        @restJson1
+        service UploadService {
+            operations: [Upload]
+        }
+
+        @http(uri: "/upload", method: "POST")
+        operation Upload {
+            input := {
+                @httpPayload
+                @required
+                body: StreamingBlob
+            }
+            output := {
+                @required
+                size: Long
+            }
+        }
+
+        @streaming
+        @requiresLength
+        @length(max: 8)
+        blob StreamingBlob
+        """.asSmithyModel()
+
+    @Test
+    fun `streaming payloads enforce requiresLength and length`() {
+        serverIntegrationTest(model) { _, rustCrate ->
+            rustCrate.testModule {
+                rust(
+                    """
+                    use tower::ServiceExt;
+
+                    async fn upload(input: crate::input::UploadInput) -> crate::output::UploadOutput {
+                        let size = match input.body.collect().await {
+                            Ok(bytes) => bytes.into_bytes().len() as i64,
+                            Err(_) => -1,
+                        };
+                        crate::output::UploadOutput { size }
+                    }
+
+                    async fn call(request: http::Request<hyper::Body>) -> (http::StatusCode, String) {
+                        let config = crate::UploadServiceConfig::builder().build();
+                        let service = crate::UploadService::builder(config).upload(upload).build().unwrap();
+                        let response = service.oneshot(request).await.unwrap();
+                        let status = response.status();
+                        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+                        (status, String::from_utf8(body.to_vec()).unwrap())
+                    }
+                    """,
+                )
+
+                tokioTest("payload_within_the_maximum_length_is_accepted") {
+                    rust(
+                        """
+                        let request = http::Request::post("/upload")
+                            .header("content-length", "5")
+                            .body(hyper::Body::from("hello"))
+                            .unwrap();
+                        let (status, body) = call(request).await;
+                        assert_eq!(200, status);
+                        assert_eq!("{\"size\":5}", body);
+                        """,
+                    )
+                }
+
+                tokioTest("payload_without_content_length_is_rejected") {
+                    rust(
+                        """
+                        let request = http::Request::post("/upload").body(hyper::Body::from("hello")).unwrap();
+                        let (status, _) = call(request).await;
+                        assert_eq!(400, status);
+                        """,
+                    )
+                }
+
+                tokioTest("payload_declaring_a_length_past_the_maximum_is_rejected") {
+                    rust(
+                        """
+                        let request = http::Request::post("/upload")
+                            .header("content-length", "9")
+                            .body(hyper::Body::from("123456789"))
+                            .unwrap();
+                        let (status, _) = call(request).await;
+                        assert_eq!(400, status);
+                        """,
+                    )
+                }
+
+                tokioTest("payload_longer_than_its_content_length_stops_at_the_maximum") {
+                    rust(
+                        """
+                        let request = http::Request::post("/upload")
+                            .header("content-length", "4")
+                            .body(hyper::Body::from("123456789"))
+                            .unwrap();
+                        let (status, body) = call(request).await;
+                        assert_eq!(200, status);
+                        assert_eq!("{\"size\":-1}", body);
+                        """,
+                    )
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+package software.amazon.smithy.rust.codegen.server.smithy.protocols.eventstream
+
+import org.junit.jupiter.api.Test
+import software.amazon.smithy.rust.codegen.core.rustlang.rust
+import software.amazon.smithy.rust.codegen.core.testutil.asSmithyModel
+import software.amazon.smithy.rust.codegen.core.testutil.testModule
+import software.amazon.smithy.rust.codegen.core.testutil.tokioTest
+import software.amazon.smithy.rust.codegen.server.smithy.testutil.serverIntegrationTest
+
+internal class ServerEventStreamInitialMessageTest {
+    private val model =
+        """
+        ${'$'}version: "2"
+
+        namespace com.example
+
+        use aws.protocols#awsJson1_0
+        use smithy.framework#ValidationException
+
+        @awsJson1_0
+        service ChatService {
+            operations: [Chat]
+        }
+
+        operation Chat {
+            input := {
+                @required
+                events: Events
+            }
+            output := {
+                @required
+                events: Events
+            }
+            errors: [ValidationException]
+        }
+
+        @streaming
+        union Events {
+            ChatMessage: ChatMessage
+        }
+
+        structure ChatMessage {
+            text: String
+        }
+        """.asSmithyModel()
+
+    @Test
+    fun `bidirectional event streams exchange initial messages`() {
+        serverIntegrationTest(model) { _, rustCrate ->
+            rustCrate.testModule {
+                rust(
+                    """
+                    use aws_smithy_eventstream::frame::{read_message_from, write_message_to};
+                    use aws_smithy_types::event_stream::{Header, HeaderValue, Message};
+                    use tower::ServiceExt;
+
+                    async fn chat(
+                        mut input: crate::input::ChatInput,
+                    ) -> Result<crate::output::ChatOutput, crate::error::ChatError> {
+                        let mut texts = Vec::new();
+                        while let Some(event) = input.events.recv().await.unwrap() {
+                            let crate::model::Events::ChatMessage(message) = event;
+                            texts.push(message.text.unwrap());
+                        }
+                        let (events, mut handle) =
+                            aws_smithy_http::event_stream::EventStreamSender::channel(texts.len().max(1));
+                        for text in texts {
+                            let reply = crate::model::ChatMessage { text: Some(text.to_uppercase()) };
+                            handle.send(crate::model::Events::ChatMessage(reply)).await.unwrap();
+                        }
+                        drop(handle);
+                        Ok(crate::output::ChatOutput { events })
+                    }
+
+                    fn encode(event_type: &'static str, payload: &'static str) -> Vec<u8> {
+                        let message = Message::new(payload.as_bytes())
+                            .add_header(Header::new(":message-type", HeaderValue::String("event".into())))
+                            .add_header(Header::new(":event-type", HeaderValue::String(event_type.into())))
+                            .add_header(Header::new(":content-type", HeaderValue::String("application/json".into())));
+                        let mut buffer = Vec::new();
+                        write_message_to(&message, &mut buffer).unwrap();
+                        buffer
+                    }
+
+                    fn header(message: &Message, name: &str) -> String {
+                        let header = message.headers().iter().find(|h| h.name().as_str() == name).unwrap();
+                        header.value().as_string().unwrap().as_str().to_owned()
+                    }
+                    """,
+                )
+
+                tokioTest("initial_request_is_consumed_and_initial_response_is_sent") {
+                    rust(
+                        """
+                        let mut body = encode("initial-request", "{}");
+                        body.extend(encode("ChatMessage", "{\"text\":\"hello\"}"));
+                        let request = http::Request::post("/")
+                            .header("content-type", "application/x-amz-json-1.0")
+                            .header("x-amz-target", "ChatService.Chat")
+                            .body(hyper::Body::from(body))
+                            .unwrap();
+
+                        let config = crate::ChatServiceConfig::builder().build();
+                        let service = crate::ChatService::builder(config).chat(chat).build().unwrap();
+                        let response = service.oneshot(request).await.unwrap();
+                        assert_eq!(200, response.status());
+
+                        let mut body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+                        let initial_response = read_message_from(&mut body).unwrap();
+                        assert_eq!("initial-response", header(&initial_response, ":event-type"));
+                        assert_eq!("application/x-amz-json-1.0", header(&initial_response, ":content-type"));
+                        assert_eq!(&b"{}"[..], &initial_response.payload()[..]);
+
+                        let reply = read_message_from(&mut body).unwrap();
+                        assert_eq!("ChatMessage", header(&reply, ":event-type"));
+                        assert_eq!("{\"text\":\"HELLO\"}", std::str::from_utf8(reply.payload()).unwrap());
+                        assert!(body.is_empty());
+                        """,
+                    )
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::sync::Arc;
+
+use pokemon_service_client::{
+    operation::get_pokemon_species::GetPokemonSpeciesInput, Client, Config,
+};
+use pokemon_service_common::{get_pokemon_species, State};
+use pokemon_service_server_sdk::{
+    server::{test_util::TestHarness, AddExtensionLayer},
+    PokemonService, PokemonServiceConfig,
+};
+
+#[tokio::test]
+async fn test_harness_sends_typed_inputs_and_returns_typed_outputs() {
+    let config = PokemonServiceConfig::builder()
+        .layer(AddExtensionLayer::new(Arc::new(State::default())))
+        .build();
+    let app = PokemonService::builder(config)
+        .get_pokemon_species(get_pokemon_species)
+        .build_unchecked();
+    let harness = TestHarness::new(app);
+    let client = harness.client(|http_client| {
+        let config = Config::builder()
+            .endpoint_url("http://localhost")
+            .http_client(http_client)
+            .build();
+        Client::from_conf(config)
+    });
+
+    let output = GetPokemonSpeciesInput::builder()
+        .name("pikachu")
+        .send_with(&client)
+        .await
+        .unwrap();
+    assert_eq!("pikachu", output.name());
+
+    let error = client
+        .get_pokemon_species()
+        .name("mewthree")
+        .send()
+        .await
+        .unwrap_err();
+    assert!(error.into_service_error().is_resource_not_found_exception());
+}
@@ -8,6 +8,7 @@
 use crate::future::pagination_stream::collect::sealed::Collectable;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 
 pub mod collect;
@@ -130,9 +131,48 @@ impl<Page, Err> TryFlatMap<Page, Err> {
     }
 }
 
+/// Handle to change the page size of a paginator while its stream is being consumed.
+///
+/// Paginators with a modeled page size hand out a `PageSizeHandle` that remains usable after the
+/// pagination stream was created. A page size set through the handle applies to every page requested
+/// after it, e.g. to request smaller pages when the consumer of the stream falls behind.
+#[derive(Debug)]
+pub struct PageSizeHandle<T>(Arc<Mutex<Option<T>>>);
+
+impl<T> PageSizeHandle<T> {
+    /// Creates a `PageSizeHandle` without a pending page size.
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(None)))
+    }
+
+    /// Sets the page size of the pages requested from now on.
+    pub fn set(&self, page_size: T) {
+        *self.0.lock().unwrap() = Some(page_size);
+    }
+
+    /// Takes the page size set since the last call to `take`, if any.
+    pub fn take(&self) -> Option<T> {
+        self.0.lock().unwrap().take()
+    }
+}
+
+impl<T> Clone for PageSizeHandle<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Default for PageSizeHandle<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::future::pagination_stream::{FnStream, PaginationStream, TryFlatMap};
+    use crate::future::pagination_stream::{
+        FnStream, PageSizeHandle, PaginationStream, TryFlatMap,
+    };
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
 
@@ -306,4 +346,15 @@ mod test {
                 .await
         )
     }
+
+    #[test]
+    fn page_size_handle() {
+        let handle = PageSizeHandle::new();
+        let paginator_handle = handle.clone();
+        assert_eq!(None, paginator_handle.take());
+        handle.set(10);
+        handle.set(20);
+        assert_eq!(Some(20), paginator_handle.take());
+        assert_eq!(None, paginator_handle.take());
+    }
 }
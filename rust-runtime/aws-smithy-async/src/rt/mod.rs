@@ -5,4 +5,5 @@
 
 //! Async runtime agnostic traits and implementations.
 
+pub mod periodic;
 pub mod sleep;
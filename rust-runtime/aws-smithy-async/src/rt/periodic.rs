@@ -0,0 +1,193 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Runs a task periodically, for components such as caches and background refreshers.
+//!
+//! This is runtime agnostic: [`Periodic::schedule`] returns a [`PeriodicTask`] future that must be spawned on an
+//! async runtime (for example, with `tokio::spawn`, or `wasm_bindgen_futures::spawn_local` on WASM), and a
+//! [`PeriodicTaskHandle`] that stops the task when dropped. Time is measured with the given [`AsyncSleep`].
+
+use crate::rt::sleep::{AsyncSleep, SharedAsyncSleep};
+use futures_util::future::{select, Either};
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+/// Runs a task every `interval`, optionally delayed by a random jitter.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "rt-tokio")]
+/// # async fn example() {
+/// use aws_smithy_async::rt::periodic::Periodic;
+/// use aws_smithy_async::rt::sleep::{SharedAsyncSleep, TokioSleep};
+/// use std::time::Duration;
+///
+/// let (handle, task) = Periodic::new(SharedAsyncSleep::new(TokioSleep::new()), Duration::from_secs(60))
+///     .jitter(Duration::from_secs(5))
+///     .schedule(|| async { /* refresh the cache */ });
+/// tokio::spawn(task);
+///
+/// // The task stops running once the handle is dropped.
+/// drop(handle);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Periodic {
+    sleep: SharedAsyncSleep,
+    interval: Duration,
+    jitter: Duration,
+}
+
+impl Periodic {
+    /// Creates a [`Periodic`] that runs a task every `interval`.
+    pub fn new(sleep: SharedAsyncSleep, interval: Duration) -> Self {
+        Self {
+            sleep,
+            interval,
+            jitter: Duration::ZERO,
+        }
+    }
+
+    /// Delays each run by a random duration of up to `jitter`, so tasks scheduled at the same time spread out.
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Schedules `task` to run after every interval.
+    ///
+    /// The first run happens after the first interval. Runs never overlap: the next interval starts once the
+    /// previous run completes. The returned [`PeriodicTask`] must be spawned for `task` to run.
+    pub fn schedule<F, Fut>(self, mut task: F) -> (PeriodicTaskHandle, PeriodicTask)
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (stop, mut stopped) = oneshot::channel::<()>();
+        let future = async move {
+            loop {
+                let delay = self.interval + self.next_jitter();
+                // The sender is never used, so `stopped` only resolves once the handle is dropped.
+                stopped = match select(stopped, self.sleep.sleep(delay)).await {
+                    Either::Left(_) => return,
+                    Either::Right((_, stopped)) => stopped,
+                };
+                stopped = match select(stopped, Box::pin(task())).await {
+                    Either::Left(_) => return,
+                    Either::Right((_, stopped)) => stopped,
+                };
+            }
+        };
+        (
+            PeriodicTaskHandle { _stop: stop },
+            PeriodicTask {
+                inner: Box::pin(future),
+            },
+        )
+    }
+
+    fn next_jitter(&self) -> Duration {
+        if self.jitter.is_zero() {
+            return Duration::ZERO;
+        }
+        let max_nanos = u64::try_from(self.jitter.as_nanos()).unwrap_or(u64::MAX);
+        Duration::from_nanos(fastrand::u64(..=max_nanos))
+    }
+}
+
+/// Stops a task scheduled with [`Periodic::schedule`] when dropped.
+///
+/// A run that is in progress when the handle is dropped is cancelled.
+#[derive(Debug)]
+pub struct PeriodicTaskHandle {
+    _stop: oneshot::Sender<()>,
+}
+
+impl PeriodicTaskHandle {
+    /// Stops the task. This is equivalent to dropping the handle.
+    pub fn stop(self) {}
+}
+
+/// Future that runs a task scheduled with [`Periodic::schedule`] until its [`PeriodicTaskHandle`] is dropped.
+#[must_use = "the task only runs once this future is spawned"]
+pub struct PeriodicTask {
+    inner: Pin<Box<dyn Future<Output = ()> + Send>>,
+}
+
+impl Debug for PeriodicTask {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PeriodicTask")
+    }
+}
+
+impl Future for PeriodicTask {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.as_mut().poll(cx)
+    }
+}
+
+#[cfg(all(test, feature = "rt-tokio"))]
+mod tests {
+    use super::Periodic;
+    use crate::rt::sleep::{SharedAsyncSleep, TokioSleep};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn counting_task(
+        runs: &Arc<AtomicUsize>,
+    ) -> impl FnMut() -> std::future::Ready<()> + Send + 'static {
+        let runs = runs.clone();
+        move || {
+            runs.fetch_add(1, Ordering::SeqCst);
+            std::future::ready(())
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn runs_every_interval_until_the_handle_is_dropped() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let (handle, task) = Periodic::new(
+            SharedAsyncSleep::new(TokioSleep::new()),
+            Duration::from_secs(10),
+        )
+        .schedule(counting_task(&runs));
+        let task = tokio::spawn(task);
+
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(0, runs.load(Ordering::SeqCst));
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        assert_eq!(3, runs.load(Ordering::SeqCst));
+
+        handle.stop();
+        task.await.unwrap();
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        assert_eq!(3, runs.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn jitter_delays_each_run_by_at_most_the_jitter() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let (_handle, task) = Periodic::new(
+            SharedAsyncSleep::new(TokioSleep::new()),
+            Duration::from_secs(10),
+        )
+        .jitter(Duration::from_secs(5))
+        .schedule(counting_task(&runs));
+        tokio::spawn(task);
+
+        tokio::time::sleep(Duration::from_secs(9)).await;
+        assert_eq!(0, runs.load(Ordering::SeqCst));
+        tokio::time::sleep(Duration::from_secs(7)).await;
+        assert_eq!(1, runs.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use aws_smithy_checksums::ChecksumAlgorithm;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Empty payloads, whose checksums are precomputed, and a tiny payload, whose checksum is still
+/// calculated, to check that it doesn't get slower.
+const PAYLOAD_SIZES: [usize; 2] = [0, 16];
+
+/// Compares the checksum of empty payloads calculated with a boxed checksum calculator, which is
+/// what the request checksum interceptor used to do, to `ChecksumAlgorithm::header_value`.
+fn bench_empty_payloads(c: &mut Criterion) {
+    for algorithm in [ChecksumAlgorithm::Crc32, ChecksumAlgorithm::Sha256] {
+        let mut group = c.benchmark_group(format!("{algorithm:?}"));
+        for size in PAYLOAD_SIZES {
+            let payload = vec![b'a'; size];
+            group.bench_with_input(BenchmarkId::new("into_impl", size), &payload, |b, p| {
+                b.iter(|| {
+                    let mut checksum = algorithm.into_impl();
+                    checksum.update(p);
+                    checksum.header_value()
+                })
+            });
+            group.bench_with_input(BenchmarkId::new("header_value", size), &payload, |b, p| {
+                b.iter(|| algorithm.header_value(p))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_empty_payloads);
+criterion_main!(benches);
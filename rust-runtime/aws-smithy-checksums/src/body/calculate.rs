@@ -36,6 +36,30 @@ impl ChecksumBody<SdkBody> {
     }
 }
 
+impl<InnerBody> ChecksumBody<InnerBody> {
+    /// The name of the trailer this body will emit, for example `x-amz-checksum-crc32`.
+    ///
+    /// This is the value to send in the `x-amz-trailer` header of an `aws-chunked` request.
+    /// Returns `None` once the trailer has been emitted.
+    pub fn trailer_name(&self) -> Option<&'static str> {
+        self.checksum
+            .as_ref()
+            .map(|checksum| checksum.header_name())
+    }
+
+    /// The length in bytes of the trailer this body will emit, including the trailer name,
+    /// the separator, and the base64-encoded checksum.
+    ///
+    /// This is needed up front to calculate the encoded length of an `aws-chunked` body.
+    /// Returns `0` once the trailer has been emitted.
+    pub fn trailer_len(&self) -> u64 {
+        self.checksum
+            .as_ref()
+            .map(|checksum| HttpChecksum::size(checksum.as_ref()))
+            .unwrap_or_default()
+    }
+}
+
 impl http_body::Body for ChecksumBody<SdkBody> {
     type Data = bytes::Bytes;
     type Error = aws_smithy_types::body::Error;
@@ -155,4 +179,27 @@ mod tests {
         // Known correct checksum for the input "This is some test text for an SdkBody"
         assert_eq!("0x99B01F72", checksum_trailer);
     }
+
+    #[tokio::test]
+    async fn test_trailer_metadata() {
+        let checksum = CRC_32_NAME
+            .parse::<ChecksumAlgorithm>()
+            .unwrap()
+            .into_impl();
+        let mut body = ChecksumBody::new(SdkBody::from("data"), checksum);
+        assert_eq!(Some(CRC_32_HEADER_NAME), body.trailer_name());
+        // "x-amz-checksum-crc32" + ":" + 8 bytes of base64 for a 4 byte checksum
+        assert_eq!(29, body.trailer_len());
+
+        while let Some(buf) = body.data().await {
+            buf.unwrap();
+        }
+        let trailers = body.trailers().await.unwrap().unwrap();
+        let (name, value) = trailers.iter().next().unwrap();
+        assert_eq!(29, name.as_str().len() + ":".len() + value.as_bytes().len());
+
+        // Once the trailer is emitted, there is nothing left to report
+        assert_eq!(None, body.trailer_name());
+        assert_eq!(0, body.trailer_len());
+    }
 }
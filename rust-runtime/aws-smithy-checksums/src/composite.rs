@@ -0,0 +1,186 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Composite ("checksum of checksums") calculation for multipart uploads.
+
+use crate::error::{InvalidPartChecksumError, UnsupportedCompositeChecksumError};
+use crate::http::HttpChecksum;
+use crate::{Checksum, ChecksumAlgorithm};
+use aws_smithy_types::base64;
+use std::fmt;
+
+/// Calculates the composite checksum of a multipart upload from the checksums of its parts.
+///
+/// The composite checksum is the checksum of the concatenated binary part checksums, followed
+/// by the number of parts, e.g. `AAAAAA==-3`. This is the value S3 returns for, and validates
+/// against, multipart uploads that use a composite checksum type.
+///
+/// Part checksums can be calculated in parallel, but they must be added in part order.
+///
+/// CRC64NVME isn't supported, because S3 only accepts full object CRC64NVME checksums for
+/// multipart uploads.
+///
+/// ```
+/// use aws_smithy_checksums::composite::CompositeChecksum;
+/// use aws_smithy_checksums::ChecksumAlgorithm;
+///
+/// let mut composite = CompositeChecksum::new(ChecksumAlgorithm::Crc32).unwrap();
+/// for part in [&b"part 1"[..], &b"part 2"[..]] {
+///     let mut checksum = ChecksumAlgorithm::Crc32.into_impl();
+///     checksum.update(part);
+///     composite.add_part(&checksum.finalize()).unwrap();
+/// }
+/// assert_eq!(2, composite.part_count());
+/// assert!(composite.finalize().ends_with("-2"));
+/// ```
+pub struct CompositeChecksum {
+    algorithm: ChecksumAlgorithm,
+    checksum: Box<dyn HttpChecksum>,
+    part_count: u64,
+}
+
+impl fmt::Debug for CompositeChecksum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompositeChecksum")
+            .field("algorithm", &self.algorithm)
+            .field("part_count", &self.part_count)
+            .finish()
+    }
+}
+
+impl CompositeChecksum {
+    /// Creates a new `CompositeChecksum` for part checksums calculated with `algorithm`.
+    ///
+    /// Returns an error for [`ChecksumAlgorithm::Crc64Nvme`], which S3 only supports as a full
+    /// object checksum.
+    pub fn new(algorithm: ChecksumAlgorithm) -> Result<Self, UnsupportedCompositeChecksumError> {
+        if algorithm == ChecksumAlgorithm::Crc64Nvme {
+            return Err(UnsupportedCompositeChecksumError::new(algorithm));
+        }
+        Ok(Self {
+            algorithm,
+            checksum: algorithm.into_impl(),
+            part_count: 0,
+        })
+    }
+
+    /// Adds the finalized checksum of the next part.
+    ///
+    /// Returns an error if the checksum isn't the size of a checksum calculated with this
+    /// composite checksum's algorithm.
+    pub fn add_part(&mut self, part_checksum: &[u8]) -> Result<(), InvalidPartChecksumError> {
+        if part_checksum.len() as u64 != Checksum::size(self.checksum.as_ref()) {
+            return Err(InvalidPartChecksumError::wrong_size(
+                self.algorithm,
+                part_checksum.len(),
+            ));
+        }
+        self.checksum.update(part_checksum);
+        self.part_count += 1;
+        Ok(())
+    }
+
+    /// Adds the base64-encoded checksum of the next part, as found in an `x-amz-checksum-*`
+    /// header or an `UploadPart` response.
+    pub fn add_encoded_part(
+        &mut self,
+        part_checksum: &str,
+    ) -> Result<(), InvalidPartChecksumError> {
+        let decoded = base64::decode(part_checksum)
+            .map_err(|_| InvalidPartChecksumError::not_base64(self.algorithm))?;
+        self.add_part(&decoded)
+    }
+
+    /// Returns the algorithm used to calculate the part checksums.
+    pub fn algorithm(&self) -> ChecksumAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the number of parts that have been added.
+    pub fn part_count(&self) -> u64 {
+        self.part_count
+    }
+
+    /// Returns the composite checksum in its `<base64>-<part count>` representation.
+    pub fn finalize(self) -> String {
+        let checksum = base64::encode(&self.checksum.finalize()[..]);
+        format!("{checksum}-{}", self.part_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompositeChecksum;
+    use crate::ChecksumAlgorithm;
+    use aws_smithy_types::base64;
+
+    fn part_checksum(algorithm: ChecksumAlgorithm, part: &[u8]) -> bytes::Bytes {
+        let mut checksum = algorithm.into_impl();
+        checksum.update(part);
+        checksum.finalize()
+    }
+
+    #[test]
+    fn composite_is_checksum_of_concatenated_part_checksums() {
+        let parts: [&[u8]; 3] = [b"first part", b"second part", b"third part"];
+        for algorithm in [
+            ChecksumAlgorithm::Crc32,
+            ChecksumAlgorithm::Crc32c,
+            ChecksumAlgorithm::Sha1,
+            ChecksumAlgorithm::Sha256,
+        ] {
+            let mut composite = CompositeChecksum::new(algorithm).unwrap();
+            let mut concatenated = Vec::new();
+            for part in parts {
+                let part_checksum = part_checksum(algorithm, part);
+                concatenated.extend_from_slice(&part_checksum);
+                composite
+                    .add_encoded_part(&base64::encode(&part_checksum[..]))
+                    .unwrap();
+            }
+
+            let expected = base64::encode(&part_checksum(algorithm, &concatenated)[..]);
+            assert_eq!(3, composite.part_count());
+            assert_eq!(format!("{expected}-3"), composite.finalize());
+        }
+    }
+
+    #[test]
+    fn known_crc32_composite() {
+        // Two parts, each containing "hello world"
+        let mut composite = CompositeChecksum::new(ChecksumAlgorithm::Crc32).unwrap();
+        composite.add_encoded_part("DUoRhQ==").unwrap();
+        composite.add_encoded_part("DUoRhQ==").unwrap();
+        assert_eq!("7ryKJw==-2", composite.finalize());
+    }
+
+    #[test]
+    fn part_checksums_of_the_wrong_size_are_rejected() {
+        let mut composite = CompositeChecksum::new(ChecksumAlgorithm::Crc32).unwrap();
+        let sha256 = part_checksum(ChecksumAlgorithm::Sha256, b"part");
+        let err = composite.add_part(&sha256).unwrap_err();
+        assert_eq!(
+            "invalid part checksum: expected a 4 byte crc32 checksum but got 32 bytes",
+            err.to_string()
+        );
+
+        let err = composite.add_encoded_part("not base64!").unwrap_err();
+        assert_eq!(
+            "invalid part checksum: expected a base64-encoded crc32 checksum",
+            err.to_string()
+        );
+        assert_eq!(0, composite.part_count());
+    }
+
+    #[test]
+    fn crc64nvme_is_rejected() {
+        let err = CompositeChecksum::new(ChecksumAlgorithm::Crc64Nvme).unwrap_err();
+        assert_eq!(ChecksumAlgorithm::Crc64Nvme, err.checksum_algorithm());
+        assert_eq!(
+            "crc64nvme can't be used for composite checksums, S3 only supports it as a full object checksum",
+            err.to_string()
+        );
+    }
+}
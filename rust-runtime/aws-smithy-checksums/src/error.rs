@@ -3,6 +3,7 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::{Checksum, ChecksumAlgorithm};
 use std::error::Error;
 use std::fmt;
 
@@ -36,3 +37,91 @@ impl fmt::Display for UnknownChecksumAlgorithmError {
 }
 
 impl Error for UnknownChecksumAlgorithmError {}
+
+/// A part checksum passed to a [`CompositeChecksum`](crate::composite::CompositeChecksum) was invalid
+#[derive(Debug)]
+pub struct InvalidPartChecksumError {
+    kind: InvalidPartChecksumErrorKind,
+}
+
+#[derive(Debug)]
+enum InvalidPartChecksumErrorKind {
+    WrongSize {
+        algorithm: ChecksumAlgorithm,
+        expected: u64,
+        actual: usize,
+    },
+    NotBase64 {
+        algorithm: ChecksumAlgorithm,
+    },
+}
+
+impl InvalidPartChecksumError {
+    pub(crate) fn wrong_size(algorithm: ChecksumAlgorithm, actual: usize) -> Self {
+        Self {
+            kind: InvalidPartChecksumErrorKind::WrongSize {
+                algorithm,
+                expected: Checksum::size(algorithm.into_impl().as_ref()),
+                actual,
+            },
+        }
+    }
+
+    pub(crate) fn not_base64(algorithm: ChecksumAlgorithm) -> Self {
+        Self {
+            kind: InvalidPartChecksumErrorKind::NotBase64 { algorithm },
+        }
+    }
+}
+
+impl fmt::Display for InvalidPartChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            InvalidPartChecksumErrorKind::WrongSize {
+                algorithm,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid part checksum: expected a {expected} byte {} checksum but got {actual} bytes",
+                algorithm.as_str()
+            ),
+            InvalidPartChecksumErrorKind::NotBase64 { algorithm } => write!(
+                f,
+                "invalid part checksum: expected a base64-encoded {} checksum",
+                algorithm.as_str()
+            ),
+        }
+    }
+}
+
+impl Error for InvalidPartChecksumError {}
+
+/// A checksum algorithm can't be used to calculate a [`CompositeChecksum`](crate::composite::CompositeChecksum)
+#[derive(Debug)]
+pub struct UnsupportedCompositeChecksumError {
+    algorithm: ChecksumAlgorithm,
+}
+
+impl UnsupportedCompositeChecksumError {
+    pub(crate) fn new(algorithm: ChecksumAlgorithm) -> Self {
+        Self { algorithm }
+    }
+
+    /// The checksum algorithm that was rejected
+    pub fn checksum_algorithm(&self) -> ChecksumAlgorithm {
+        self.algorithm
+    }
+}
+
+impl fmt::Display for UnsupportedCompositeChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} can't be used for composite checksums, S3 only supports it as a full object checksum",
+            self.algorithm.as_str()
+        )
+    }
+}
+
+impl Error for UnsupportedCompositeChecksumError {}
@@ -22,6 +22,7 @@ use bytes::Bytes;
 use std::{fmt::Debug, str::FromStr};
 
 pub mod body;
+pub mod composite;
 pub mod error;
 pub mod http;
 
@@ -104,6 +105,45 @@ impl ChecksumAlgorithm {
             Self::Sha256 => SHA_256_NAME,
         }
     }
+
+    /// Return the name of the header used to send checksums calculated with this algorithm
+    pub fn header_name(&self) -> &'static str {
+        match self {
+            Self::Crc32 => http::CRC_32_HEADER_NAME,
+            Self::Crc32c => http::CRC_32_C_HEADER_NAME,
+            Self::Crc64Nvme => http::CRC_64_NVME_HEADER_NAME,
+            // MD5 checksums are calculated with CRC32, see `into_impl`
+            #[allow(deprecated)]
+            Self::Md5 => http::CRC_32_HEADER_NAME,
+            Self::Sha1 => http::SHA_1_HEADER_NAME,
+            Self::Sha256 => http::SHA_256_HEADER_NAME,
+        }
+    }
+
+    /// Return the checksum of `data`, calculated with this algorithm, as a base64-encoded `HeaderValue`
+    ///
+    /// The checksums of empty payloads are precomputed, so this doesn't set up a checksum
+    /// calculator for them.
+    pub fn header_value(&self, data: &[u8]) -> ::http::HeaderValue {
+        if data.is_empty() {
+            return ::http::HeaderValue::from_static(self.empty_checksum());
+        }
+        let mut checksum = self.into_impl();
+        checksum.update(data);
+        checksum.header_value()
+    }
+
+    /// The base64-encoded checksum of an empty payload
+    fn empty_checksum(&self) -> &'static str {
+        match self {
+            Self::Crc32 | Self::Crc32c => "AAAAAA==",
+            Self::Crc64Nvme => "AAAAAAAAAAA=",
+            #[allow(deprecated)]
+            Self::Md5 => "AAAAAA==",
+            Self::Sha1 => "2jmj7l5rSw0yVb/vlWAYkK/YBwk=",
+            Self::Sha256 => "47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=",
+        }
+    }
 }
 
 /// Types implementing this trait can calculate checksums.
@@ -456,6 +496,30 @@ mod tests {
         assert_eq!(decoded_checksum, expected_checksum);
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn test_header_value_matches_calculated_checksum() {
+        for algorithm in [
+            ChecksumAlgorithm::Crc32,
+            ChecksumAlgorithm::Crc32c,
+            ChecksumAlgorithm::Crc64Nvme,
+            ChecksumAlgorithm::Md5,
+            ChecksumAlgorithm::Sha1,
+            ChecksumAlgorithm::Sha256,
+        ] {
+            for data in ["", TEST_DATA] {
+                let mut checksum = algorithm.into_impl();
+                checksum.update(data.as_bytes());
+                assert_eq!(algorithm.header_name(), checksum.header_name());
+                assert_eq!(
+                    checksum.header_value(),
+                    algorithm.header_value(data.as_bytes()),
+                    "{algorithm:?} checksum of {data:?}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_checksum_algorithm_returns_error_for_unknown() {
         let error = "some invalid checksum algorithm"
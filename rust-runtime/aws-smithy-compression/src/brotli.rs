@@ -0,0 +1,114 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::{Compress, CompressionOptions};
+use aws_smithy_runtime_api::box_error::BoxError;
+use brotli::enc::BrotliEncoderParams;
+use std::io::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Brotli {
+    // Brotli qualities go from 0 to 11, compression levels are used as is.
+    quality: u32,
+}
+
+impl Brotli {
+    fn compress_bytes(&self, mut bytes: &[u8], mut writer: impl Write) -> Result<(), BoxError> {
+        let params = BrotliEncoderParams {
+            quality: self.quality as i32,
+            ..Default::default()
+        };
+        brotli::BrotliCompress(&mut bytes, &mut writer, &params)?;
+
+        Ok(())
+    }
+}
+
+impl Compress for Brotli {
+    fn compress_bytes(&mut self, bytes: &[u8], writer: &mut dyn Write) -> Result<(), BoxError> {
+        Brotli::compress_bytes(self, bytes, writer).map_err(Into::into)
+    }
+}
+
+#[cfg(feature = "http-body-0-4-x")]
+mod http_body_0_4_x {
+    use crate::http::http_body_0_4_x::CompressRequest;
+
+    impl CompressRequest for super::Brotli {
+        fn header_value(&self) -> http_0_2::HeaderValue {
+            http_0_2::HeaderValue::from_static(crate::BROTLI_NAME)
+        }
+    }
+}
+
+#[cfg(feature = "http-body-1-x")]
+mod http_body_1_x {
+    use crate::http::http_body_1_x::CompressRequest;
+
+    impl CompressRequest for super::Brotli {
+        fn header_value(&self) -> http_1_0::HeaderValue {
+            http_1_0::HeaderValue::from_static(crate::BROTLI_NAME)
+        }
+    }
+}
+
+impl From<&CompressionOptions> for Brotli {
+    fn from(options: &CompressionOptions) -> Self {
+        Brotli {
+            quality: options.level,
+        }
+    }
+}
+
+impl From<CompressionOptions> for Brotli {
+    fn from(options: CompressionOptions) -> Self {
+        Brotli::from(&options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Brotli;
+    use crate::CompressionOptions;
+    use pretty_assertions::assert_eq;
+    use std::io::Read;
+
+    fn gettysburg_address() -> &'static [u8] {
+        include_bytes!("../test-data/gettysburg_address.txt")
+    }
+
+    fn decompress(compressed: &[u8]) -> Vec<u8> {
+        let mut decompressed = Vec::new();
+        brotli::Decompressor::new(compressed, 4096)
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        decompressed
+    }
+
+    #[test]
+    fn test_brotli_compression() {
+        let brotli = Brotli::from(&CompressionOptions::default());
+        let mut compressed_output = Vec::new();
+        brotli
+            .compress_bytes(gettysburg_address(), &mut compressed_output)
+            .expect("compression succeeds");
+
+        assert!(compressed_output.len() < gettysburg_address().len());
+        assert_eq!(gettysburg_address(), decompress(&compressed_output));
+    }
+
+    #[test]
+    fn test_brotli_compression_of_every_level() {
+        for level in 0..=9 {
+            let options = CompressionOptions::default().with_level(level).unwrap();
+            let mut compressed_output = Vec::new();
+            Brotli::from(options)
+                .compress_bytes(gettysburg_address(), &mut compressed_output)
+                .expect("compression succeeds");
+
+            assert_eq!(gettysburg_address(), decompress(&compressed_output));
+        }
+    }
+}
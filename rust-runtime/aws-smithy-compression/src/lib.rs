@@ -22,12 +22,15 @@ use std::io::Write;
 use std::str::FromStr;
 
 pub mod body;
+mod brotli;
 mod gzip;
 pub mod http;
 
 // Valid compression algorithm names
 /// The name of the `gzip` algorithm.
 pub const GZIP_NAME: &str = "gzip";
+/// The name of the `brotli` algorithm, as used in the `Content-Encoding` header.
+pub const BROTLI_NAME: &str = "br";
 
 /// The maximum-allowable value per internal standards is 10 Megabytes.
 const MAX_MIN_COMPRESSION_SIZE_BYTES: u32 = 10_485_760;
@@ -145,6 +148,8 @@ impl Storable for CompressionOptions {
 pub enum CompressionAlgorithm {
     /// The [gzip](https://en.wikipedia.org/wiki/Gzip) compression algorithm
     Gzip,
+    /// The [brotli](https://en.wikipedia.org/wiki/Brotli) compression algorithm
+    Brotli,
 }
 
 impl FromStr for CompressionAlgorithm {
@@ -154,11 +159,14 @@ impl FromStr for CompressionAlgorithm {
     ///
     /// Valid algorithm names are:
     /// - "gzip"
+    /// - "br"
     ///
     /// Passing an invalid name will return an error.
     fn from_str(compression_algorithm: &str) -> Result<Self, Self::Err> {
         if compression_algorithm.eq_ignore_ascii_case(GZIP_NAME) {
             Ok(Self::Gzip)
+        } else if compression_algorithm.eq_ignore_ascii_case(BROTLI_NAME) {
+            Ok(Self::Brotli)
         } else {
             Err(format!("unknown compression algorithm `{compression_algorithm}`").into())
         }
@@ -174,6 +182,7 @@ impl CompressionAlgorithm {
     ) -> Box<dyn http::http_body_0_4_x::CompressRequest> {
         match self {
             Self::Gzip => Box::new(gzip::Gzip::from(options)),
+            Self::Brotli => Box::new(brotli::Brotli::from(options)),
         }
     }
 
@@ -185,6 +194,7 @@ impl CompressionAlgorithm {
     ) -> Box<dyn http::http_body_1_x::CompressRequest> {
         match self {
             Self::Gzip => Box::new(gzip::Gzip::from(options)),
+            Self::Brotli => Box::new(brotli::Brotli::from(options)),
         }
     }
 
@@ -192,6 +202,7 @@ impl CompressionAlgorithm {
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Gzip { .. } => GZIP_NAME,
+            Self::Brotli => BROTLI_NAME,
         }
     }
 }
@@ -217,4 +228,11 @@ mod tests {
         let algo = "gzip".parse::<CompressionAlgorithm>().unwrap();
         assert_eq!("gzip", algo.as_str());
     }
+
+    #[test]
+    fn test_compression_algorithm_from_str_brotli() {
+        let algo = "br".parse::<CompressionAlgorithm>().unwrap();
+        assert_eq!(CompressionAlgorithm::Brotli, algo);
+        assert_eq!("br", algo.as_str());
+    }
 }
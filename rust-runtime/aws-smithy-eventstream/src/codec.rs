@@ -0,0 +1,211 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! [`tokio_util::codec`] implementation of the `application/vnd.amazon.eventstream` binary framing.
+//!
+//! [`MessageCodec`] can be used with [`Framed`](tokio_util::codec::Framed),
+//! [`FramedRead`](tokio_util::codec::FramedRead), and [`FramedWrite`](tokio_util::codec::FramedWrite)
+//! to exchange event stream messages over any transport implementing `AsyncRead` or `AsyncWrite`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aws_smithy_eventstream::codec::MessageCodec;
+//! use aws_smithy_eventstream::message::EventMessage;
+//! use futures_util::{SinkExt, StreamExt};
+//! use tokio::io::{AsyncRead, AsyncWrite};
+//! use tokio_util::codec::Framed;
+//!
+//! async fn echo(io: impl AsyncRead + AsyncWrite + Unpin) -> Result<(), aws_smithy_eventstream::error::Error> {
+//!     let mut framed = Framed::new(io, MessageCodec::new());
+//!     while let Some(message) = framed.next().await {
+//!         let message = EventMessage::from(message?);
+//!         framed.send(message).await?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::error::{Error, ErrorKind};
+use crate::frame::{read_message_from, write_message_to, PRELUDE_LENGTH_BYTES_USIZE};
+use crate::message::EventMessage;
+use aws_smithy_types::event_stream::Message;
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+/// The default maximum length of a message accepted by [`MessageCodec`], including its prelude and CRCs.
+///
+/// This is the 16 MiB limit on the size of a message defined by the event stream encoding.
+pub const DEFAULT_MAX_MESSAGE_LENGTH: usize = 16 * 1024 * 1024;
+
+/// Encoder and decoder of event stream messages.
+///
+/// Decoding validates the prelude and message CRCs of every frame. Frames longer than the
+/// [maximum message length](MessageCodec::with_max_message_length) are rejected before they are
+/// buffered, so that a peer can't exhaust memory by announcing a very large frame.
+#[derive(Clone, Debug)]
+pub struct MessageCodec {
+    max_message_length: usize,
+}
+
+impl Default for MessageCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageCodec {
+    /// Creates a codec accepting messages up to [`DEFAULT_MAX_MESSAGE_LENGTH`] long.
+    pub fn new() -> Self {
+        Self {
+            max_message_length: DEFAULT_MAX_MESSAGE_LENGTH,
+        }
+    }
+
+    /// Sets the maximum length of a decoded message, including its prelude and CRCs.
+    pub fn with_max_message_length(mut self, max_message_length: usize) -> Self {
+        self.max_message_length = max_message_length;
+        self
+    }
+
+    /// Returns the maximum length of a decoded message, including its prelude and CRCs.
+    pub fn max_message_length(&self) -> usize {
+        self.max_message_length
+    }
+}
+
+impl Decoder for MessageCodec {
+    type Item = Message;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if src.len() < PRELUDE_LENGTH_BYTES_USIZE {
+            return Ok(None);
+        }
+        let total_len = (&src[..]).get_u32() as usize;
+        if total_len < PRELUDE_LENGTH_BYTES_USIZE || total_len > self.max_message_length {
+            return Err(ErrorKind::InvalidMessageLength.into());
+        }
+        if src.len() < total_len {
+            src.reserve(total_len - src.len());
+            return Ok(None);
+        }
+        read_message_from(src.split_to(total_len).freeze()).map(Some)
+    }
+}
+
+impl Encoder<Message> for MessageCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: Message, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.encode(&item, dst)
+    }
+}
+
+impl Encoder<&Message> for MessageCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: &Message, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        write_message_to(item, dst)
+    }
+}
+
+impl Encoder<EventMessage> for MessageCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: EventMessage, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        item.write_to(dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MessageCodec;
+    use crate::message::EventMessage;
+    use aws_smithy_types::event_stream::Message;
+    use bytes::BytesMut;
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_util::codec::{Decoder, Encoder, FramedRead, FramedWrite};
+
+    fn message(event_type: &'static str, payload: &'static str) -> Message {
+        EventMessage::builder()
+            .message_type("event")
+            .event_type(event_type)
+            .payload(payload)
+            .build()
+            .into_message()
+    }
+
+    #[test]
+    fn decode_waits_for_complete_frames() {
+        let mut codec = MessageCodec::new();
+        let mut encoded = BytesMut::new();
+        codec.encode(message("First", "one"), &mut encoded).unwrap();
+        codec
+            .encode(message("Second", "two"), &mut encoded)
+            .unwrap();
+
+        let mut src = BytesMut::new();
+        let mut decoded = Vec::new();
+        for byte in encoded.iter() {
+            src.extend_from_slice(&[*byte]);
+            if let Some(message) = codec.decode(&mut src).unwrap() {
+                decoded.push(message);
+            }
+        }
+        assert_eq!(
+            vec![message("First", "one"), message("Second", "two")],
+            decoded
+        );
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn decode_rejects_messages_over_the_max_length() {
+        let mut codec = MessageCodec::new().with_max_message_length(32);
+        let mut src = BytesMut::new();
+        codec
+            .encode(message("Event", "a payload that is too long"), &mut src)
+            .unwrap();
+        let err = codec.decode(&mut src).unwrap_err();
+        assert_eq!("invalid message length", err.to_string());
+    }
+
+    #[test]
+    fn decode_validates_crc() {
+        let mut codec = MessageCodec::new();
+        let mut src = BytesMut::new();
+        codec.encode(message("Event", "payload"), &mut src).unwrap();
+        let last = src.len() - 1;
+        src[last] ^= 0xff;
+        assert!(codec.decode(&mut src).is_err());
+    }
+
+    #[tokio::test]
+    async fn framed_round_trip() {
+        let (client, server) = tokio::io::duplex(16);
+        let writer = tokio::spawn(async move {
+            let mut framed = FramedWrite::new(client, MessageCodec::new());
+            for i in 0..3 {
+                framed.send(message("Event", "payload")).await.unwrap();
+                framed
+                    .send(EventMessage::builder().header("index", i).build())
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let framed = FramedRead::new(server, MessageCodec::new());
+        let messages: Vec<_> = framed.map(Result::unwrap).collect().await;
+        writer.await.unwrap();
+        assert_eq!(6, messages.len());
+        assert_eq!(
+            Some(2),
+            EventMessage::from(messages[5].clone())
+                .get::<i32>("index")
+                .unwrap()
+        );
+    }
+}
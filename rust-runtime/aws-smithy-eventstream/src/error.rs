@@ -5,7 +5,7 @@
 
 use aws_smithy_types::DateTime;
 use std::error::Error as StdError;
-use std::fmt;
+use std::{fmt, io};
 
 #[derive(Debug)]
 pub(crate) enum ErrorKind {
@@ -24,6 +24,7 @@ pub(crate) enum ErrorKind {
     TimestampValueTooLarge(DateTime),
     Marshalling(String),
     Unmarshalling(String),
+    Io(io::Error),
 }
 
 #[derive(Debug)]
@@ -73,7 +74,20 @@ impl From<ErrorKind> for Error {
     }
 }
 
-impl StdError for Error {}
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        ErrorKind::Io(err).into()
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -106,6 +120,7 @@ impl fmt::Display for Error {
             ),
             Marshalling(error) => write!(f, "failed to marshall message: {}", error),
             Unmarshalling(error) => write!(f, "failed to unmarshall message: {}", error),
+            Io(_) => write!(f, "I/O error while reading or writing event stream frames"),
         }
     }
 }
@@ -19,6 +19,9 @@
 #[cfg(feature = "derive-arbitrary")]
 pub mod arbitrary;
 mod buf;
+#[cfg(feature = "codec")]
+pub mod codec;
 pub mod error;
 pub mod frame;
+pub mod message;
 pub mod smithy;
@@ -0,0 +1,482 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Builder-style API for constructing and inspecting event stream messages.
+//!
+//! [`EventMessage`] wraps a [`Message`] with typed header accessors and handles the framing
+//! (prelude, lengths, and CRCs) when reading and writing messages. Custom event types can be mapped
+//! to and from messages with the [`event_message!`](crate::event_message) macro.
+//!
+//! # Example
+//!
+//! ```
+//! use aws_smithy_eventstream::message::EventMessage;
+//!
+//! let message = EventMessage::builder()
+//!     .message_type("event")
+//!     .event_type("Progress")
+//!     .header("bytes-scanned", 512_i64)
+//!     .payload("scanning")
+//!     .build();
+//! let bytes = message.to_bytes().unwrap();
+//!
+//! let decoded = EventMessage::read_from(bytes).unwrap();
+//! assert_eq!(Some("Progress"), decoded.event_type());
+//! assert_eq!(512, decoded.require::<i64>("bytes-scanned").unwrap());
+//! ```
+
+use crate::error::Error;
+use crate::frame::{read_message_from, write_message_to};
+use aws_smithy_types::event_stream::{Header, HeaderValue, Message};
+use aws_smithy_types::str_bytes::StrBytes;
+use aws_smithy_types::{Blob, DateTime};
+use bytes::{Buf, BufMut, Bytes};
+
+const MESSAGE_TYPE: &str = ":message-type";
+const EVENT_TYPE: &str = ":event-type";
+const CONTENT_TYPE: &str = ":content-type";
+
+/// Conversion of a value into a [`HeaderValue`].
+pub trait IntoHeaderValue {
+    /// Converts this value into a [`HeaderValue`].
+    fn into_header_value(self) -> HeaderValue;
+}
+
+/// Conversion of a [`HeaderValue`] into a value of a specific type.
+pub trait FromHeaderValue: Sized {
+    /// Converts the header value, or returns `None` if it is of a different type.
+    fn from_header_value(value: &HeaderValue) -> Option<Self>;
+}
+
+macro_rules! header_value_conversions {
+    ($($typ:ty: $variant:ident { $into:expr, $from:expr })*) => {
+        $(
+            impl IntoHeaderValue for $typ {
+                fn into_header_value(self) -> HeaderValue {
+                    HeaderValue::$variant($into(self))
+                }
+            }
+
+            impl FromHeaderValue for $typ {
+                fn from_header_value(value: &HeaderValue) -> Option<Self> {
+                    match value {
+                        HeaderValue::$variant(value) => Some($from(value)),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+header_value_conversions! {
+    bool: Bool { |v| v, |v: &bool| *v }
+    i8: Byte { |v| v, |v: &i8| *v }
+    i16: Int16 { |v| v, |v: &i16| *v }
+    i32: Int32 { |v| v, |v: &i32| *v }
+    i64: Int64 { |v| v, |v: &i64| *v }
+    Bytes: ByteArray { |v| v, |v: &Bytes| v.clone() }
+    Blob: ByteArray { |v: Blob| Bytes::from(v.into_inner()), |v: &Bytes| Blob::new(v.as_ref()) }
+    StrBytes: String { |v| v, |v: &StrBytes| v.clone() }
+    String: String { StrBytes::from, |v: &StrBytes| v.as_str().to_owned() }
+    DateTime: Timestamp { |v| v, |v: &DateTime| *v }
+}
+
+impl IntoHeaderValue for &'static str {
+    fn into_header_value(self) -> HeaderValue {
+        HeaderValue::String(self.into())
+    }
+}
+
+impl IntoHeaderValue for HeaderValue {
+    fn into_header_value(self) -> HeaderValue {
+        self
+    }
+}
+
+/// An event stream message with typed header accessors.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventMessage {
+    message: Message,
+}
+
+impl EventMessage {
+    /// Returns a builder for an `EventMessage`.
+    pub fn builder() -> EventMessageBuilder {
+        EventMessageBuilder::default()
+    }
+
+    /// Reads a single framed message from the given `buffer`, validating its prelude and CRCs.
+    pub fn read_from<B: Buf>(buffer: B) -> Result<Self, Error> {
+        read_message_from(buffer).map(Self::from)
+    }
+
+    /// Writes this message, including its prelude and CRCs, to the given `buffer`.
+    pub fn write_to(&self, buffer: &mut dyn BufMut) -> Result<(), Error> {
+        write_message_to(&self.message, buffer)
+    }
+
+    /// Returns this message framed with its prelude and CRCs.
+    pub fn to_bytes(&self) -> Result<Bytes, Error> {
+        let mut buffer = Vec::new();
+        self.write_to(&mut buffer)?;
+        Ok(buffer.into())
+    }
+
+    /// Returns the value of the header with the given `name`, if any.
+    pub fn header(&self, name: &str) -> Option<&HeaderValue> {
+        self.message
+            .headers()
+            .iter()
+            .find(|header| header.name().as_str() == name)
+            .map(Header::value)
+    }
+
+    /// Returns the value of the header with the given `name` as a `T`, if the header is present.
+    ///
+    /// Returns an error if the header value is not a `T`.
+    pub fn get<T: FromHeaderValue>(&self, name: &str) -> Result<Option<T>, Error> {
+        self.header(name)
+            .map(|value| {
+                T::from_header_value(value).ok_or_else(|| {
+                    Error::unmarshalling(format!(
+                        "unexpected type for the '{name}' header value: {value:?}"
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    /// Returns the value of the header with the given `name` as a `T`.
+    ///
+    /// Returns an error if the header is missing, or if its value is not a `T`.
+    pub fn require<T: FromHeaderValue>(&self, name: &str) -> Result<T, Error> {
+        self.get(name)?.ok_or_else(|| {
+            Error::unmarshalling(format!("expected the message to include a '{name}' header"))
+        })
+    }
+
+    /// Returns the value of the `:message-type` header, if it is a string.
+    pub fn message_type(&self) -> Option<&str> {
+        self.str_header(MESSAGE_TYPE)
+    }
+
+    /// Returns the value of the `:event-type` header, if it is a string.
+    pub fn event_type(&self) -> Option<&str> {
+        self.str_header(EVENT_TYPE)
+    }
+
+    /// Returns the value of the `:content-type` header, if it is a string.
+    pub fn content_type(&self) -> Option<&str> {
+        self.str_header(CONTENT_TYPE)
+    }
+
+    fn str_header(&self, name: &str) -> Option<&str> {
+        self.header(name)
+            .and_then(|value| value.as_string().ok())
+            .map(StrBytes::as_str)
+    }
+
+    /// Returns the headers of this message.
+    pub fn headers(&self) -> &[Header] {
+        self.message.headers()
+    }
+
+    /// Returns the payload of this message.
+    pub fn payload(&self) -> &Bytes {
+        self.message.payload()
+    }
+
+    /// Converts this into the underlying [`Message`].
+    pub fn into_message(self) -> Message {
+        self.message
+    }
+}
+
+impl From<Message> for EventMessage {
+    fn from(message: Message) -> Self {
+        Self { message }
+    }
+}
+
+impl From<EventMessage> for Message {
+    fn from(message: EventMessage) -> Self {
+        message.message
+    }
+}
+
+/// Builder for [`EventMessage`].
+#[derive(Clone, Debug, Default)]
+pub struct EventMessageBuilder {
+    headers: Vec<Header>,
+    payload: Bytes,
+}
+
+impl EventMessageBuilder {
+    /// Sets the header with the given `name`, replacing any previous value.
+    pub fn header(mut self, name: impl Into<StrBytes>, value: impl IntoHeaderValue) -> Self {
+        let name = name.into();
+        self.headers.retain(|header| header.name() != &name);
+        self.headers
+            .push(Header::new(name, value.into_header_value()));
+        self
+    }
+
+    /// Sets the `:message-type` header, e.g. `event` or `exception`.
+    pub fn message_type(self, message_type: impl Into<StrBytes>) -> Self {
+        self.header(MESSAGE_TYPE, message_type.into())
+    }
+
+    /// Sets the `:event-type` header.
+    pub fn event_type(self, event_type: impl Into<StrBytes>) -> Self {
+        self.header(EVENT_TYPE, event_type.into())
+    }
+
+    /// Sets the `:content-type` header.
+    pub fn content_type(self, content_type: impl Into<StrBytes>) -> Self {
+        self.header(CONTENT_TYPE, content_type.into())
+    }
+
+    /// Sets the payload of the message.
+    pub fn payload(mut self, payload: impl Into<Bytes>) -> Self {
+        self.payload = payload.into();
+        self
+    }
+
+    /// Builds the [`EventMessage`].
+    pub fn build(self) -> EventMessage {
+        Message::new_from_parts(self.headers, self.payload).into()
+    }
+}
+
+/// Conversion of a custom event type into an [`EventMessage`].
+pub trait IntoEventMessage {
+    /// Converts this event into an [`EventMessage`].
+    fn into_event_message(self) -> EventMessage;
+}
+
+/// Conversion of an [`EventMessage`] into a custom event type.
+pub trait FromEventMessage: Sized {
+    /// Converts the message into this event, or returns an error if the message is not a valid event of this type.
+    fn from_event_message(message: &EventMessage) -> Result<Self, Error>;
+}
+
+/// Defines a struct for a custom event type, along with its [`IntoEventMessage`] and [`FromEventMessage`]
+/// implementations.
+///
+/// The struct is given an event type, used as the `:event-type` header of its messages. Fields marked with
+/// `#[header("name")]` map to message headers and must implement both [`IntoHeaderValue`] and [`FromHeaderValue`].
+/// The field marked with `#[payload]` maps to the message payload and must convert to and from [`Bytes`].
+/// Other field attributes, including doc comments, must follow the `#[header]` and `#[payload]` markers.
+///
+/// Messages are written with an `:message-type` of `event`. When reading messages, the message type, the event type,
+/// and every header are required.
+///
+/// # Example
+///
+/// ```
+/// use aws_smithy_eventstream::event_message;
+/// use aws_smithy_eventstream::message::{EventMessage, FromEventMessage, IntoEventMessage};
+/// use bytes::Bytes;
+///
+/// event_message! {
+///     /// Reports the progress of a scan.
+///     #[derive(Debug, PartialEq)]
+///     pub struct Progress: "Progress" {
+///         #[header("bytes-scanned")]
+///         /// The number of bytes scanned so far.
+///         pub bytes_scanned: i64,
+///         #[header("stage")]
+///         pub stage: String,
+///         #[payload]
+///         pub details: Bytes,
+///     }
+/// }
+///
+/// let progress = Progress {
+///     bytes_scanned: 512,
+///     stage: "scanning".into(),
+///     details: Bytes::from_static(b"{}"),
+/// };
+/// let bytes = progress.into_event_message().to_bytes().unwrap();
+///
+/// let message = EventMessage::read_from(bytes).unwrap();
+/// let progress = Progress::from_event_message(&message).unwrap();
+/// assert_eq!(512, progress.bytes_scanned);
+/// ```
+#[macro_export]
+macro_rules! event_message {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident : $event_type:literal {
+            $(
+                #[header($header:literal)]
+                $(#[$header_meta:meta])*
+                $header_vis:vis $header_field:ident : $header_ty:ty,
+            )*
+            #[payload]
+            $(#[$payload_meta:meta])*
+            $payload_vis:vis $payload_field:ident : $payload_ty:ty $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $(
+                $(#[$header_meta])*
+                $header_vis $header_field: $header_ty,
+            )*
+            $(#[$payload_meta])*
+            $payload_vis $payload_field: $payload_ty,
+        }
+
+        impl $crate::message::IntoEventMessage for $name {
+            fn into_event_message(self) -> $crate::message::EventMessage {
+                $crate::message::EventMessage::builder()
+                    .message_type("event")
+                    .event_type($event_type)
+                    $(.header($header, self.$header_field))*
+                    .payload(self.$payload_field)
+                    .build()
+            }
+        }
+
+        impl $crate::message::FromEventMessage for $name {
+            fn from_event_message(
+                message: &$crate::message::EventMessage,
+            ) -> ::std::result::Result<Self, $crate::error::Error> {
+                if message.message_type() != Some("event") || message.event_type() != Some($event_type) {
+                    return Err($crate::error::Error::unmarshalling(format!(
+                        "expected a `{}` event, but received message type {:?} and event type {:?}",
+                        $event_type,
+                        message.message_type(),
+                        message.event_type(),
+                    )));
+                }
+                Ok(Self {
+                    $($header_field: message.require::<$header_ty>($header)?,)*
+                    $payload_field: message.payload().clone().into(),
+                })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventMessage, FromEventMessage, IntoEventMessage};
+    use aws_smithy_types::event_stream::{Header, HeaderValue, Message};
+    use aws_smithy_types::DateTime;
+    use bytes::Bytes;
+
+    crate::event_message! {
+        #[derive(Debug, PartialEq)]
+        struct Progress: "Progress" {
+            #[header("bytes-scanned")]
+            bytes_scanned: i64,
+            #[header("stage")]
+            stage: String,
+            #[header("finished")]
+            finished: bool,
+            #[payload]
+            details: Bytes,
+        }
+    }
+
+    #[test]
+    fn builder_round_trip() {
+        let timestamp = DateTime::from_secs(1_700_000_000);
+        let message = EventMessage::builder()
+            .message_type("event")
+            .event_type("Progress")
+            .content_type("application/json")
+            .header("count", 3_i32)
+            .header("updated", timestamp)
+            .header("count", 4_i32)
+            .payload(&b"{}"[..])
+            .build();
+        let decoded = EventMessage::read_from(message.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(message, decoded);
+        assert_eq!(Some("event"), decoded.message_type());
+        assert_eq!(Some("Progress"), decoded.event_type());
+        assert_eq!(Some("application/json"), decoded.content_type());
+        assert_eq!(4, decoded.require::<i32>("count").unwrap());
+        assert_eq!(timestamp, decoded.require::<DateTime>("updated").unwrap());
+        assert_eq!(5, decoded.headers().len());
+        assert_eq!(&b"{}"[..], &decoded.payload()[..]);
+    }
+
+    #[test]
+    fn typed_header_accessors() {
+        let message: EventMessage = Message::new(&b""[..])
+            .add_header(Header::new("count", HeaderValue::Int32(3)))
+            .into();
+        assert_eq!(Some(3), message.get::<i32>("count").unwrap());
+        assert_eq!(None, message.get::<i32>("missing").unwrap());
+        assert!(message
+            .get::<String>("count")
+            .unwrap_err()
+            .to_string()
+            .contains("unexpected type for the 'count' header value"));
+        assert!(message
+            .require::<i32>("missing")
+            .unwrap_err()
+            .to_string()
+            .contains("expected the message to include a 'missing' header"));
+    }
+
+    #[test]
+    fn read_from_validates_crc() {
+        let mut bytes = EventMessage::builder()
+            .event_type("Progress")
+            .payload("hello")
+            .build()
+            .to_bytes()
+            .unwrap()
+            .to_vec();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        assert!(EventMessage::read_from(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn custom_event_round_trip() {
+        let progress = Progress {
+            bytes_scanned: 512,
+            stage: "scanning".into(),
+            finished: false,
+            details: Bytes::from_static(b"{}"),
+        };
+        let message = Progress {
+            stage: progress.stage.clone(),
+            details: progress.details.clone(),
+            ..progress
+        }
+        .into_event_message();
+        assert_eq!(Some("event"), message.message_type());
+        assert_eq!(Some("Progress"), message.event_type());
+
+        let decoded = EventMessage::read_from(message.to_bytes().unwrap()).unwrap();
+        assert_eq!(progress, Progress::from_event_message(&decoded).unwrap());
+    }
+
+    #[test]
+    fn custom_event_rejects_other_events() {
+        let message = EventMessage::builder()
+            .message_type("event")
+            .event_type("Stats")
+            .build();
+        let err = Progress::from_event_message(&message).unwrap_err();
+        assert!(err.to_string().contains("expected a `Progress` event"));
+
+        let message = EventMessage::builder()
+            .message_type("event")
+            .event_type("Progress")
+            .header("bytes-scanned", 512_i64)
+            .build();
+        let err = Progress::from_event_message(&message).unwrap_err();
+        assert!(err.to_string().contains("'stage' header"));
+    }
+}
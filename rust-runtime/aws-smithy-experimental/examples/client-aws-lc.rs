@@ -10,11 +10,13 @@ async fn main() {
     // feature = crypto-aws-lc
     let _client = HyperClientBuilder::new()
         .crypto_mode(CryptoMode::AwsLc)
-        .build_https();
+        .build_https()
+        .expect("valid TLS configuration");
 
     // feature = crypto-aws-lc-fips
     // A FIPS client can also be created. Note that this has a more complex build environment required.
     let _client = HyperClientBuilder::new()
         .crypto_mode(CryptoMode::AwsLcFips)
-        .build_https();
+        .build_https()
+        .expect("valid TLS configuration");
 }
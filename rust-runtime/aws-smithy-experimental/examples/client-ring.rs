@@ -8,5 +8,6 @@ use aws_smithy_experimental::hyper_1_0::{CryptoMode, HyperClientBuilder};
 fn main() {
     let _client = HyperClientBuilder::new()
         .crypto_mode(CryptoMode::Ring)
-        .build_https();
+        .build_https()
+        .expect("valid TLS configuration");
 }
@@ -19,5 +19,6 @@ impl ResolveDns for StaticResolver {
 fn main() {
     let _client = HyperClientBuilder::new()
         .crypto_mode(CryptoMode::Ring)
-        .build_with_resolver(StaticResolver);
+        .build_with_resolver(StaticResolver)
+        .expect("valid TLS configuration");
 }
@@ -11,6 +11,7 @@
 
 use aws_smithy_async::time::SystemTimeSource;
 use aws_smithy_experimental::hyper_1_0::{CryptoMode, HyperClientBuilder};
+use aws_smithy_runtime_api::client::connection::ConnectionMetadata;
 use aws_smithy_runtime_api::client::dns::{DnsFuture, ResolveDns, ResolveDnsError};
 use aws_smithy_runtime_api::client::http::{HttpClient, HttpConnector, HttpConnectorSettings};
 use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
@@ -26,7 +27,8 @@ use tower::Service;
 async fn ring_client() {
     let client = HyperClientBuilder::new()
         .crypto_mode(CryptoMode::Ring)
-        .build_https();
+        .build_https()
+        .unwrap();
     smoke_test_client(&client).await.unwrap();
 }
 
@@ -35,7 +37,8 @@ async fn ring_client() {
 async fn aws_lc_fips_client() {
     let client = HyperClientBuilder::new()
         .crypto_mode(CryptoMode::AwsLcFips)
-        .build_https();
+        .build_https()
+        .unwrap();
     smoke_test_client(&client).await.unwrap();
 }
 
@@ -44,7 +47,8 @@ async fn aws_lc_fips_client() {
 async fn aws_lc_client() {
     let client = HyperClientBuilder::new()
         .crypto_mode(CryptoMode::AwsLc)
-        .build_https();
+        .build_https()
+        .unwrap();
     smoke_test_client(&client).await.unwrap();
 }
 
@@ -78,7 +82,8 @@ async fn custom_dns_client() {
     };
     let client = HyperClientBuilder::new()
         .crypto_mode(CryptoMode::Ring)
-        .build_with_resolver(resolver.clone());
+        .build_with_resolver(resolver.clone())
+        .unwrap();
     smoke_test_client(&client).await.unwrap();
     assert_eq!(resolver.count.load(Ordering::Relaxed), 1);
 }
@@ -91,8 +96,14 @@ async fn smoke_test_client(client: &dyn HttpClient) -> Result<(), Box<dyn Error>
         .build()
         .unwrap();
     let connector = client.http_connector(&connector_settings, &runtime_components);
-    let _response = connector
+    let response = connector
         .call(HttpRequest::get("https://amazon.com").unwrap())
         .await?;
+    let connection = response
+        .extension::<ConnectionMetadata>()
+        .expect("connection metadata is set on responses");
+    assert!(connection.remote_addr().is_some());
+    assert!(connection.tls_version().is_some());
+    assert!(connection.cipher_suite().is_some());
     Ok(())
 }
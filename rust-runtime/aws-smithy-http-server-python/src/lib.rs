@@ -27,6 +27,7 @@ mod socket;
 pub mod tls;
 pub mod types;
 mod util;
+pub mod worker;
 
 #[doc(inline)]
 pub use error::{PyError, PyMiddlewareException};
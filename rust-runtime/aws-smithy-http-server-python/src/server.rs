@@ -5,11 +5,14 @@
 
 use std::collections::HashMap;
 use std::convert::Infallible;
+use std::future::Future;
 use std::net::TcpListener as StdTcpListener;
 use std::ops::Deref;
+use std::pin::Pin;
 use std::process;
 use std::sync::{mpsc, Arc};
 use std::thread;
+use std::time::{Duration, Instant};
 
 use aws_smithy_http_server::{
     body::{Body, BoxBody},
@@ -21,7 +24,12 @@ use parking_lot::Mutex;
 use pyo3::{prelude::*, types::IntoPyDict};
 use signal_hook::{consts::*, iterator::Signals};
 use socket2::Socket;
-use tokio::{net::TcpListener, runtime};
+use tokio::{
+    net::TcpListener,
+    runtime,
+    signal::unix::{signal as unix_signal, SignalKind},
+    sync::Notify,
+};
 use tokio_rustls::TlsAcceptor;
 use tower::{util::BoxCloneService, ServiceBuilder};
 
@@ -29,6 +37,7 @@ use crate::{
     context::{layer::AddPyContextLayer, PyContext},
     tls::{listener::Listener as TlsListener, PyTlsConfig},
     util::{error::rich_py_err, func_metadata},
+    worker::{EventLoop, PyWorkerConfig},
     PySocket,
 };
 
@@ -88,7 +97,14 @@ pub trait PyApp: Clone + pyo3::IntoPy<PyObject> {
     /// Handle the graceful termination of Python workers by looping through all the
     /// active workers and calling `terminate()` on them. If termination fails, this
     /// method will try to `kill()` any failed worker.
-    fn graceful_termination(&self, workers: &Mutex<Vec<PyObject>>) -> ! {
+    ///
+    /// When a `shutdown_timeout` is given, workers are given this long to drain their
+    /// in-flight requests and exit before the ones still alive are killed.
+    fn graceful_termination(
+        &self,
+        workers: &Mutex<Vec<PyObject>>,
+        shutdown_timeout: Option<Duration>,
+    ) -> ! {
         let workers = workers.lock();
         for (idx, worker) in workers.iter().enumerate() {
             let idx = idx + 1;
@@ -114,6 +130,34 @@ pub trait PyApp: Clone + pyo3::IntoPy<PyObject> {
                 }
             });
         }
+        if let Some(shutdown_timeout) = shutdown_timeout {
+            // The shutdown deadline is wall-clock time that has to be shared by every worker
+            #[allow(clippy::disallowed_methods)]
+            let deadline = Instant::now() + shutdown_timeout;
+            for (idx, worker) in workers.iter().enumerate() {
+                let idx = idx + 1;
+                Python::with_gil(|py| {
+                    #[allow(clippy::disallowed_methods)]
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if let Err(e) = worker.call_method1(py, "join", (remaining.as_secs_f64(),)) {
+                        tracing::error!(error = ?rich_py_err(e), idx, "error waiting for worker to exit");
+                    }
+                    let alive: bool = worker
+                        .call_method0(py, "is_alive")
+                        .and_then(|alive| alive.extract(py))
+                        .unwrap_or(false);
+                    if alive {
+                        tracing::warn!(
+                            idx,
+                            "worker did not exit within the shutdown timeout, killing it"
+                        );
+                        if let Err(e) = worker.call_method0(py, "kill") {
+                            tracing::error!(error = ?rich_py_err(e), idx, "unable to kill worker");
+                        }
+                    }
+                });
+            }
+        }
         process::exit(0);
     }
 
@@ -148,7 +192,9 @@ pub trait PyApp: Clone + pyo3::IntoPy<PyObject> {
     ///   * SIGINT - immediate termination of all workers.
     ///
     /// Other signals are NOOP.
-    fn block_on_rust_signals(&self) {
+    ///
+    /// See [graceful_termination](#method.graceful_termination) for the meaning of `shutdown_timeout`.
+    fn block_on_rust_signals(&self, shutdown_timeout: Option<Duration>) {
         let mut signals =
             Signals::new([SIGINT, SIGHUP, SIGQUIT, SIGTERM, SIGUSR1, SIGUSR2, SIGWINCH])
                 .expect("Unable to register signals");
@@ -165,7 +211,7 @@ pub trait PyApp: Clone + pyo3::IntoPy<PyObject> {
                     tracing::info!(
                         sig = %sig, "termination signal received, all workers will be gracefully terminated"
                     );
-                    self.graceful_termination(self.workers());
+                    self.graceful_termination(self.workers(), shutdown_timeout);
                 }
                 _ => {
                     tracing::debug!(sig = %sig, "signal is ignored by this application");
@@ -177,35 +223,7 @@ pub trait PyApp: Clone + pyo3::IntoPy<PyObject> {
     /// Register and handle termination of all the tasks on the Python asynchronous event loop.
     /// We only register SIGQUIT and SIGINT since the main signal handling is done by Rust.
     fn register_python_signals(&self, py: Python, event_loop: PyObject) -> PyResult<()> {
-        let locals = [("event_loop", event_loop)].into_py_dict(py);
-        py.run(
-            r#"
-import asyncio
-import logging
-import functools
-import signal
-
-async def shutdown(sig, event_loop):
-    # reimport asyncio and logging to be sure they are available when
-    # this handler runs on signal catching.
-    import asyncio
-    import logging
-    logging.info(f"Caught signal {sig.name}, cancelling tasks registered on this loop")
-    tasks = [task for task in asyncio.all_tasks() if task is not
-             asyncio.current_task()]
-    list(map(lambda task: task.cancel(), tasks))
-    results = await asyncio.gather(*tasks, return_exceptions=True)
-    logging.debug(f"Finished awaiting cancelled tasks, results: {results}")
-    event_loop.stop()
-
-event_loop.add_signal_handler(signal.SIGTERM,
-    functools.partial(asyncio.ensure_future, shutdown(signal.SIGTERM, event_loop)))
-event_loop.add_signal_handler(signal.SIGINT,
-    functools.partial(asyncio.ensure_future, shutdown(signal.SIGINT, event_loop)))
-"#,
-            None,
-            Some(locals),
-        )?;
+        register_python_shutdown(py, event_loop, &["SIGTERM", "SIGINT"])?;
         Ok(())
     }
 
@@ -225,7 +243,12 @@ event_loop.add_signal_handler(signal.SIGINT,
     /// thread on Hyper serve() method.
     /// The main process continues and at the end it is blocked on Python `loop.run_forever()`.
     ///
+    /// If the `config` has a shutdown timeout, SIGTERM stops the Hyper server from accepting new
+    /// connections and waits for the in-flight requests to complete before stopping the Python
+    /// event loop. Otherwise, SIGTERM cancels all the tasks on the Python event loop immediately.
+    ///
     /// [uvloop]: https://github.com/MagicStack/uvloop
+    #[allow(clippy::too_many_arguments)]
     fn start_hyper_worker(
         &mut self,
         py: Python,
@@ -234,14 +257,23 @@ event_loop.add_signal_handler(signal.SIGINT,
         service: Service,
         worker_number: isize,
         tls: Option<PyTlsConfig>,
+        config: PyWorkerConfig,
     ) -> PyResult<()> {
         // Clone the socket.
         let borrow = socket.try_borrow_mut()?;
         let held_socket: &PySocket = &borrow;
         let raw_socket = held_socket.get_socket()?;
 
-        // Register signals on the Python event loop.
-        self.register_python_signals(py, event_loop.to_object(py))?;
+        // Register signals on the Python event loop. When shutting down gracefully, SIGTERM
+        // is handled by the Tokio runtime, which stops the Python event loop once it is done.
+        let graceful_shutdown = config.shutdown_timeout().map(GracefulShutdown::new);
+        let python_shutdown = if graceful_shutdown.is_some() {
+            let shutdown = register_python_shutdown(py, event_loop.to_object(py), &["SIGINT"])?;
+            Some((shutdown, event_loop.to_object(py)))
+        } else {
+            self.register_python_signals(py, event_loop.to_object(py))?;
+            None
+        };
 
         // Spawn a new background [std::thread] to run the application.
         // This is needed because `asyncio` doesn't work properly if it doesn't control the main thread.
@@ -251,32 +283,57 @@ event_loop.add_signal_handler(signal.SIGINT,
         tracing::trace!("start the tokio runtime in a background task");
         thread::spawn(move || {
             // The thread needs a new [tokio] runtime.
-            let rt = runtime::Builder::new_multi_thread()
+            let mut builder = runtime::Builder::new_multi_thread();
+            if let Some(worker_threads) = config.tokio_worker_threads() {
+                builder.worker_threads(worker_threads);
+            }
+            let rt = builder
                 .enable_all()
                 .thread_name(format!("smithy-rs-tokio[{worker_number}]"))
                 .build()
                 .expect("unable to start a new tokio runtime for this process");
             rt.block_on(async move {
                 let addr = addr_incoming_from_socket(raw_socket);
-
-                if let Some(config) = tls {
-                    let (acceptor, acceptor_rx) = tls_config_reloader(config);
-                    let listener = TlsListener::new(acceptor, addr, acceptor_rx);
-                    let server =
-                        hyper::Server::builder(listener).serve(IntoMakeService::new(service));
-
-                    tracing::trace!("started tls hyper server from shared socket");
-                    // Run forever-ish...
-                    if let Err(err) = server.await {
-                        tracing::error!(error = ?err, "server error");
+                let shutdown_signal = graceful_shutdown.clone();
+                let shutdown_signal = async move {
+                    match shutdown_signal {
+                        Some(shutdown) => shutdown.signal().await,
+                        None => std::future::pending().await,
+                    }
+                };
+
+                let server: Pin<Box<dyn Future<Output = Result<(), hyper::Error>>>> =
+                    if let Some(config) = tls {
+                        let (acceptor, acceptor_rx) = tls_config_reloader(config);
+                        let listener = TlsListener::new(acceptor, addr, acceptor_rx);
+                        tracing::trace!("started tls hyper server from shared socket");
+                        Box::pin(
+                            hyper::Server::builder(listener)
+                                .serve(IntoMakeService::new(service))
+                                .with_graceful_shutdown(shutdown_signal),
+                        )
+                    } else {
+                        tracing::trace!("started hyper server from shared socket");
+                        Box::pin(
+                            hyper::Server::builder(addr)
+                                .serve(IntoMakeService::new(service))
+                                .with_graceful_shutdown(shutdown_signal),
+                        )
+                    };
+
+                match graceful_shutdown {
+                    Some(graceful_shutdown) => {
+                        graceful_shutdown.run(server).await;
+                        if let Some((shutdown, event_loop)) = python_shutdown {
+                            tracing::debug!("server stopped, stopping the python event loop");
+                            stop_python_event_loop(&shutdown, &event_loop);
+                        }
                     }
-                } else {
-                    let server = hyper::Server::builder(addr).serve(IntoMakeService::new(service));
-
-                    tracing::trace!("started hyper server from shared socket");
                     // Run forever-ish...
-                    if let Err(err) = server.await {
-                        tracing::error!(error = ?err, "server error");
+                    None => {
+                        if let Err(err) = server.await {
+                            tracing::error!(error = ?err, "server error");
+                        }
                     }
                 }
             });
@@ -313,21 +370,34 @@ event_loop.add_signal_handler(signal.SIGINT,
 
     /// Configure the Python asyncio event loop.
     ///
-    /// First of all we install [uvloop] as the main Python event loop. Thanks to libuv, uvloop
+    /// By default, we install [uvloop] as the main Python event loop. Thanks to libuv, uvloop
     /// performs ~20% better than Python standard event loop in most benchmarks, while being 100%
     /// compatible. If [uvloop] is not available as a dependency, we just fall back to the standard
-    /// Python event loop.
+    /// Python event loop. The [EventLoop] of the `config` can require either of them instead.
     ///
     /// [uvloop]: https://github.com/MagicStack/uvloop
-    fn configure_python_event_loop<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+    fn configure_python_event_loop<'py>(
+        &self,
+        py: Python<'py>,
+        config: &PyWorkerConfig,
+    ) -> PyResult<&'py PyAny> {
         let asyncio = py.import("asyncio")?;
-        match py.import("uvloop") {
-            Ok(uvloop) => {
-                uvloop.call_method0("install")?;
+        match config.event_loop() {
+            EventLoop::Auto => match py.import("uvloop") {
+                Ok(uvloop) => {
+                    uvloop.call_method0("install")?;
+                    tracing::trace!("setting up uvloop for current process");
+                }
+                Err(_) => {
+                    tracing::warn!("uvloop not found, using python standard event loop, which could have worse performance than uvloop");
+                }
+            },
+            EventLoop::Uvloop => {
+                py.import("uvloop")?.call_method0("install")?;
                 tracing::trace!("setting up uvloop for current process");
             }
-            Err(_) => {
-                tracing::warn!("uvloop not found, using python standard event loop, which could have worse performance than uvloop");
+            EventLoop::Asyncio => {
+                tracing::trace!("using python standard event loop for current process");
             }
         }
         let event_loop = asyncio.call_method0("new_event_loop")?;
@@ -372,22 +442,29 @@ event_loop.add_signal_handler(signal.SIGINT,
     ///
     ///     #[pymethods]
     ///     impl App {
-    ///     #[pyo3(text_signature = "($self, socket, worker_number, tls)")]
+    ///     #[pyo3(text_signature = "($self, socket, worker_number, tls, config)")]
     ///         pub fn start_worker(
     ///             &mut self,
     ///             py: pyo3::Python,
     ///             socket: &pyo3::PyCell<aws_smithy_http_server_python::PySocket>,
     ///             worker_number: isize,
     ///             tls: Option<aws_smithy_http_server_python::tls::PyTlsConfig>,
+    ///             config: Option<aws_smithy_http_server_python::worker::PyWorkerConfig>,
     ///         ) -> pyo3::PyResult<()> {
-    ///             let event_loop = self.configure_python_event_loop(py)?;
+    ///             let config = config.unwrap_or_default();
+    ///             let event_loop = self.configure_python_event_loop(py, &config)?;
     ///             let service = self.build_service(event_loop)?;
-    ///             self.start_hyper_worker(py, socket, event_loop, service, worker_number, tls)
+    ///             self.start_hyper_worker(py, socket, event_loop, service, worker_number, tls, config)
     ///         }
     ///     }
     /// ```
     ///
+    /// By default, all the workers share the same listening socket. If the `config` enables `reuse_port`,
+    /// each worker gets its own socket bound to the same address with `SO_REUSEPORT`, and the kernel
+    /// balances the incoming connections between them.
+    ///
     /// [multiprocessing::Process]: https://docs.python.org/3/library/multiprocessing.html
+    #[allow(clippy::too_many_arguments)]
     fn run_server(
         &mut self,
         py: Python,
@@ -396,6 +473,7 @@ event_loop.add_signal_handler(signal.SIGINT,
         backlog: Option<i32>,
         workers: Option<usize>,
         tls: Option<PyTlsConfig>,
+        config: Option<PyWorkerConfig>,
     ) -> PyResult<()> {
         // Setup multiprocessing environment, allowing connections and socket
         // sharing between processes.
@@ -420,21 +498,34 @@ event_loop.add_signal_handler(signal.SIGINT,
 
         let address = address.unwrap_or_else(|| String::from("127.0.0.1"));
         let port = port.unwrap_or(13734);
-        let socket = PySocket::new(address, port, backlog)?;
+        let config = config.unwrap_or_default();
+        let socket = PySocket::new(address.clone(), port, backlog)?;
+        // Bind the other `SO_REUSEPORT` sockets to the port actually bound by the first one,
+        // in case the operating system picked it.
+        let bound_port = socket.port()?;
         // Lock the workers mutex.
         let mut active_workers = self.workers().lock();
         // Register the main signal handler.
         // TODO(move from num_cpus to thread::available_parallelism after MSRV is 1.60)
         // Start all the workers as new Python processes and store the in the `workers` attribute.
         for idx in 1..workers.unwrap_or_else(num_cpus::get) + 1 {
-            let sock = socket.try_clone()?;
+            let sock = if config.reuse_port() && idx > 1 {
+                PySocket::new(address.clone(), bound_port, backlog)?
+            } else {
+                socket.try_clone()?
+            };
             let tls = tls.clone();
             let process = mp.getattr("Process")?;
             let handle = process.call1((
                 py.None(),
                 self.clone().into_py(py).getattr(py, "start_worker")?,
                 format!("smithy-rs-worker[{idx}]"),
-                (sock.into_py(py), idx, tls.into_py(py)),
+                (
+                    sock.into_py(py),
+                    idx,
+                    tls.into_py(py),
+                    config.clone().into_py(py),
+                ),
             ))?;
             handle.call_method0("start")?;
             active_workers.push(handle.to_object(py));
@@ -442,7 +533,7 @@ event_loop.add_signal_handler(signal.SIGINT,
         // Unlock the workers mutex.
         drop(active_workers);
         tracing::trace!("rust python server started successfully");
-        self.block_on_rust_signals();
+        self.block_on_rust_signals(config.shutdown_timeout());
         Ok(())
     }
 
@@ -450,7 +541,7 @@ event_loop.add_signal_handler(signal.SIGINT,
     fn run_lambda_handler(&mut self, py: Python) -> PyResult<()> {
         use aws_smithy_http_server::routing::LambdaHandler;
 
-        let event_loop = self.configure_python_event_loop(py)?;
+        let event_loop = self.configure_python_event_loop(py, &PyWorkerConfig::default())?;
         // Register signals on the Python event loop.
         self.register_python_signals(py, event_loop.to_object(py))?;
 
@@ -498,6 +589,119 @@ event_loop.add_signal_handler(signal.SIGINT,
     }
 }
 
+// Registers a handler cancelling all the tasks and stopping the Python asynchronous `event_loop` for
+// each of the given `signals`, and returns the `shutdown(sig, event_loop)` coroutine function doing so.
+fn register_python_shutdown(
+    py: Python,
+    event_loop: PyObject,
+    signals: &[&str],
+) -> PyResult<PyObject> {
+    let locals = [
+        ("event_loop", event_loop),
+        ("signals", signals.to_object(py)),
+    ]
+    .into_py_dict(py);
+    py.run(
+        r#"
+import asyncio
+import logging
+import functools
+import signal
+
+async def shutdown(sig, event_loop):
+    # reimport asyncio and logging to be sure they are available when
+    # this handler runs on signal catching.
+    import asyncio
+    import logging
+    logging.info(f"Caught signal {sig.name}, cancelling tasks registered on this loop")
+    tasks = [task for task in asyncio.all_tasks() if task is not
+             asyncio.current_task()]
+    list(map(lambda task: task.cancel(), tasks))
+    results = await asyncio.gather(*tasks, return_exceptions=True)
+    logging.debug(f"Finished awaiting cancelled tasks, results: {results}")
+    event_loop.stop()
+
+for name in signals:
+    sig = getattr(signal, name)
+    event_loop.add_signal_handler(sig,
+        functools.partial(asyncio.ensure_future, shutdown(sig, event_loop)))
+"#,
+        None,
+        Some(locals),
+    )?;
+    let shutdown = locals
+        .get_item("shutdown")
+        .expect("`shutdown` is defined by the script above");
+    Ok(shutdown.to_object(py))
+}
+
+// Stops the Python asynchronous `event_loop` from another thread, once the worker finished
+// draining its in-flight requests after receiving SIGTERM.
+fn stop_python_event_loop(shutdown: &PyObject, event_loop: &PyObject) {
+    let result = Python::with_gil(|py| -> PyResult<()> {
+        let sigterm = py.import("signal")?.getattr("SIGTERM")?;
+        let coroutine = shutdown.call1(py, (sigterm, event_loop.clone_ref(py)))?;
+        py.import("asyncio")?.call_method1(
+            "run_coroutine_threadsafe",
+            (coroutine, event_loop.clone_ref(py)),
+        )?;
+        Ok(())
+    });
+    if let Err(err) = result {
+        tracing::error!(error = ?rich_py_err(err), "unable to stop the python event loop");
+    }
+}
+
+/// Drains the in-flight requests of a worker on SIGTERM, giving up after a timeout.
+#[derive(Debug, Clone)]
+struct GracefulShutdown {
+    timeout: Duration,
+    draining: Arc<Notify>,
+}
+
+impl GracefulShutdown {
+    fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            draining: Arc::new(Notify::new()),
+        }
+    }
+
+    // Resolves when SIGTERM is received, triggering the graceful shutdown of the Hyper server.
+    async fn signal(self) {
+        match unix_signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(err) => {
+                tracing::error!(error = ?err, "unable to register SIGTERM handler, graceful shutdown is disabled");
+                std::future::pending::<()>().await;
+            }
+        }
+        tracing::info!(timeout = ?self.timeout, "SIGTERM received, draining in-flight requests");
+        self.draining.notify_one();
+    }
+
+    // Runs the gracefully shutting down Hyper `server` until it drained all the in-flight requests
+    // or the shutdown timeout elapsed.
+    async fn run(&self, server: impl Future<Output = Result<(), hyper::Error>>) {
+        let timeout = async {
+            self.draining.notified().await;
+            tokio::time::sleep(self.timeout).await;
+        };
+        tokio::select! {
+            result = server => {
+                if let Err(err) = result {
+                    tracing::error!(error = ?err, "server error");
+                }
+            }
+            _ = timeout => {
+                tracing::warn!(timeout = ?self.timeout, "in-flight requests were not drained within the shutdown timeout");
+            }
+        }
+    }
+}
+
 fn addr_incoming_from_socket(socket: Socket) -> AddrIncoming {
     let std_listener: StdTcpListener = socket.into();
     // StdTcpListener::from_std doesn't set O_NONBLOCK
@@ -68,6 +68,20 @@ impl PySocket {
         self.inner.try_clone()
     }
 
+    /// Get the port the socket is bound to.
+    pub fn port(&self) -> Result<i32, std::io::Error> {
+        self.inner
+            .local_addr()?
+            .as_socket()
+            .map(|address| address.port().into())
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "socket is not bound to an IP address",
+                )
+            })
+    }
+
     /// Find the socket domain
     fn socket_domain(address: SocketAddr) -> (Domain, &'static str) {
         if address.is_ipv6() {
@@ -102,4 +116,13 @@ mod tests {
         let cloned_socket = socket.try_clone().unwrap();
         assert!(cloned_socket.inner.is_listener().is_ok());
     }
+
+    #[test]
+    fn sockets_can_share_a_port() {
+        let socket = PySocket::new("127.0.0.1".to_owned(), 0, None).unwrap();
+        let port = socket.port().unwrap();
+        assert_ne!(0, port);
+        let other_socket = PySocket::new("127.0.0.1".to_owned(), port, None).unwrap();
+        assert_eq!(port, other_socket.port().unwrap());
+    }
 }
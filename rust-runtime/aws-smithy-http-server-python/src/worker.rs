@@ -0,0 +1,150 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Runtime configuration of the Python server workers.
+
+use std::time::Duration;
+
+use pyo3::{exceptions::PyValueError, pyclass, pymethods, PyResult};
+
+/// The Python asyncio event loop used by a worker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventLoop {
+    /// Use [uvloop] if it is installed, falling back to the standard asyncio event loop otherwise.
+    ///
+    /// [uvloop]: https://github.com/MagicStack/uvloop
+    #[default]
+    Auto,
+    /// Use [uvloop], failing to start the worker if it is not installed.
+    ///
+    /// [uvloop]: https://github.com/MagicStack/uvloop
+    Uvloop,
+    /// Use the standard asyncio event loop.
+    Asyncio,
+}
+
+impl EventLoop {
+    fn parse(value: &str) -> PyResult<Self> {
+        match value {
+            "auto" => Ok(Self::Auto),
+            "uvloop" => Ok(Self::Uvloop),
+            "asyncio" => Ok(Self::Asyncio),
+            other => Err(PyValueError::new_err(format!(
+                "invalid event loop `{other}`, expected one of `auto`, `uvloop` or `asyncio`"
+            ))),
+        }
+    }
+}
+
+/// PyWorkerConfig represents the runtime configuration of the server workers created from Python.
+///
+/// :param event_loop str:
+/// :param tokio_worker_threads typing.Optional\[int\]:
+/// :param reuse_port bool:
+/// :param shutdown_timeout typing.Optional\[int\]:
+/// :rtype None:
+#[pyclass(
+    name = "WorkerConfig",
+    text_signature = "($self, *, event_loop=\"auto\", tokio_worker_threads=None, reuse_port=False, shutdown_timeout=None)"
+)]
+#[derive(Debug, Clone, Default)]
+pub struct PyWorkerConfig {
+    /// Python event loop to run in each worker: `auto`, `uvloop` or `asyncio`.
+    ///
+    /// :type str:
+    event_loop: EventLoop,
+
+    /// Number of Tokio worker threads of each worker, defaulting to the number of CPUs.
+    ///
+    /// :type typing.Optional\[int\]:
+    tokio_worker_threads: Option<usize>,
+
+    /// Bind a separate `SO_REUSEPORT` socket for each worker, letting the kernel balance
+    /// connections between them, instead of sharing a single listening socket.
+    ///
+    /// :type bool:
+    reuse_port: bool,
+
+    /// Seconds workers are given to drain in-flight requests after receiving SIGTERM.
+    /// When unset, SIGTERM cancels all the tasks of the worker immediately.
+    ///
+    /// :type typing.Optional\[int\]:
+    shutdown_timeout: Option<u64>,
+}
+
+impl PyWorkerConfig {
+    /// Returns the Python event loop to run in each worker.
+    pub fn event_loop(&self) -> EventLoop {
+        self.event_loop
+    }
+
+    /// Returns the number of Tokio worker threads of each worker, if configured.
+    pub fn tokio_worker_threads(&self) -> Option<usize> {
+        self.tokio_worker_threads
+    }
+
+    /// Returns `true` if each worker binds its own `SO_REUSEPORT` socket.
+    pub fn reuse_port(&self) -> bool {
+        self.reuse_port
+    }
+
+    /// Returns the time workers are given to drain in-flight requests after receiving SIGTERM, if configured.
+    pub fn shutdown_timeout(&self) -> Option<Duration> {
+        self.shutdown_timeout.map(Duration::from_secs)
+    }
+}
+
+#[pymethods]
+impl PyWorkerConfig {
+    #[new]
+    #[pyo3(signature = (event_loop="auto", tokio_worker_threads=None, reuse_port=false, shutdown_timeout=None))]
+    fn py_new(
+        event_loop: &str,
+        tokio_worker_threads: Option<usize>,
+        reuse_port: bool,
+        shutdown_timeout: Option<u64>,
+    ) -> PyResult<Self> {
+        if tokio_worker_threads == Some(0) {
+            return Err(PyValueError::new_err(
+                "`tokio_worker_threads` must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            event_loop: EventLoop::parse(event_loop)?,
+            tokio_worker_threads,
+            reuse_port,
+            shutdown_timeout,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn worker_config_defaults() {
+        let config = PyWorkerConfig::default();
+        assert_eq!(EventLoop::Auto, config.event_loop());
+        assert_eq!(None, config.tokio_worker_threads());
+        assert!(!config.reuse_port());
+        assert_eq!(None, config.shutdown_timeout());
+    }
+
+    #[test]
+    fn worker_config_from_python_arguments() {
+        let config = PyWorkerConfig::py_new("uvloop", Some(2), true, Some(30)).unwrap();
+        assert_eq!(EventLoop::Uvloop, config.event_loop());
+        assert_eq!(Some(2), config.tokio_worker_threads());
+        assert!(config.reuse_port());
+        assert_eq!(Some(Duration::from_secs(30)), config.shutdown_timeout());
+    }
+
+    #[test]
+    fn worker_config_rejects_invalid_arguments() {
+        assert!(PyWorkerConfig::py_new("trio", None, false, None).is_err());
+        assert!(PyWorkerConfig::py_new("asyncio", Some(0), false, None).is_err());
+    }
+}
@@ -11,7 +11,11 @@ pub use http_body::Body as HttpBody;
 
 pub use hyper::body::Body;
 
-use bytes::Bytes;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use http_body::SizeHint;
 
 use crate::error::{BoxError, Error};
 
@@ -55,3 +59,154 @@ where
 {
     boxed(Body::from(body))
 }
+
+/// A request body that has been read into memory once, so that it can be inspected before it is
+/// handed to the deserializer.
+///
+/// The body yields its bytes in a single chunk, after which it is exhausted; [`BufferedOnce::bytes`]
+/// remains available until then. Cloning a `BufferedOnce` is cheap and the clone can be read again,
+/// which allows the same body to be replayed. Trailers of the original body are discarded.
+///
+/// See [`InspectBodyLayer`](crate::layer::inspect_body::InspectBodyLayer) to inspect the body of
+/// every request before it is deserialized.
+#[derive(Clone, Debug, Default)]
+pub struct BufferedOnce {
+    data: Bytes,
+}
+
+impl BufferedOnce {
+    /// Reads `body` into memory, failing if it is longer than `limit` bytes.
+    ///
+    /// When the size hint of `body` already exceeds `limit` (e.g. because of the request's
+    /// `Content-Length`), the body is not read at all.
+    pub async fn buffer<B>(body: B, limit: usize) -> Result<Self, BufferError>
+    where
+        B: http_body::Body,
+        B::Error: Into<BoxError>,
+    {
+        if body.size_hint().lower() > limit as u64 {
+            return Err(BufferError::TooLarge { limit });
+        }
+        let mut body = Box::pin(body);
+        let mut buffer = BytesMut::new();
+        while let Some(chunk) = body.data().await {
+            let chunk = chunk.map_err(|err| BufferError::Read(Error::new(err)))?;
+            if buffer.len() + chunk.remaining() > limit {
+                return Err(BufferError::TooLarge { limit });
+            }
+            buffer.put(chunk);
+        }
+        Ok(Self { data: buffer.freeze() })
+    }
+
+    /// The bytes of the body that have not been read yet.
+    pub fn bytes(&self) -> &Bytes {
+        &self.data
+    }
+
+    /// Consumes the body, returning its bytes.
+    pub fn into_bytes(self) -> Bytes {
+        self.data
+    }
+}
+
+impl From<Bytes> for BufferedOnce {
+    fn from(data: Bytes) -> Self {
+        Self { data }
+    }
+}
+
+impl From<BufferedOnce> for Body {
+    fn from(body: BufferedOnce) -> Self {
+        Body::from(body.data)
+    }
+}
+
+impl http_body::Body for BufferedOnce {
+    type Data = Bytes;
+    type Error = std::convert::Infallible;
+
+    fn poll_data(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        if self.data.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Ready(Some(Ok(std::mem::take(&mut self.data))))
+        }
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        Poll::Ready(Ok(None))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::with_exact(self.data.len() as u64)
+    }
+}
+
+/// An error that occurred while reading a body into a [`BufferedOnce`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BufferError {
+    /// The body is longer than the limit.
+    #[error("the body exceeds the limit of {limit} bytes")]
+    TooLarge {
+        /// The maximum number of bytes that could be buffered.
+        limit: usize,
+    },
+    /// The body could not be read.
+    #[error("failed to read the body")]
+    Read(#[source] Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use http_body::Body as _;
+
+    use super::{Body, BufferError, BufferedOnce};
+
+    #[tokio::test]
+    async fn buffered_body_can_be_replayed() {
+        let body = BufferedOnce::buffer(
+            Body::wrap_stream(futures_util::stream::iter(vec![
+                Ok::<_, std::io::Error>("hello "),
+                Ok("world"),
+            ])),
+            11,
+        )
+        .await
+        .unwrap();
+        assert_eq!(&Bytes::from_static(b"hello world"), body.bytes());
+
+        let mut replay = body.clone();
+        assert_eq!(
+            Some(Bytes::from_static(b"hello world")),
+            replay.data().await.transpose().unwrap()
+        );
+        assert!(replay.is_end_stream());
+        assert_eq!(None, replay.data().await.transpose().unwrap());
+
+        let body = hyper::body::to_bytes(Body::from(body)).await.unwrap();
+        assert_eq!(Bytes::from_static(b"hello world"), body);
+    }
+
+    #[tokio::test]
+    async fn body_over_limit_is_rejected() {
+        let err = BufferedOnce::buffer(Body::from("hello world"), 10).await.unwrap_err();
+        assert!(matches!(err, BufferError::TooLarge { limit: 10 }), "{err:?}");
+
+        let streamed = Body::wrap_stream(futures_util::stream::iter(vec![
+            Ok::<_, std::io::Error>("hello "),
+            Ok("world"),
+        ]));
+        let err = BufferedOnce::buffer(streamed, 10).await.unwrap_err();
+        assert!(matches!(err, BufferError::TooLarge { limit: 10 }), "{err:?}");
+    }
+}
@@ -62,6 +62,8 @@
 mod plugin;
 pub mod sensitivity;
 mod service;
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
 
 use std::fmt::{Debug, Display};
 
@@ -6,6 +6,7 @@
 //! A [`Service`] and it's associated [`Future`] providing sensitivity aware logging.
 
 use std::{
+    fmt,
     future::Future,
     pin::Pin,
     task::{Context, Poll},
@@ -14,8 +15,9 @@ use std::{
 use futures_util::{ready, TryFuture};
 use http::{HeaderMap, Request, Response, StatusCode, Uri};
 use tower::Service;
-use tracing::{debug, debug_span, instrument::Instrumented, Instrument};
+use tracing::{debug, debug_span, instrument::Instrumented, Instrument, Span};
 
+use crate::layer::sampling::Sampled;
 use crate::shape_id::ShapeId;
 
 use super::{MakeDebug, MakeDisplay, MakeIdentity};
@@ -25,7 +27,9 @@ pin_project_lite::pin_project! {
     struct InnerFuture<Fut, ResponseMakeFmt> {
         #[pin]
         inner: Fut,
-        make: ResponseMakeFmt
+        make: ResponseMakeFmt,
+        uri: Option<RedactedUri>,
+        log: bool,
     }
 }
 
@@ -41,13 +45,16 @@ where
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.project();
-        let response = ready!(this.inner.poll(cx))?;
+        let mut response = ready!(this.inner.poll(cx))?;
 
-        {
+        if *this.log {
             let headers = this.make.make_debug(response.headers());
             let status_code = this.make.make_display(response.status());
             debug!(?headers, %status_code, "response");
         }
+        if let Some(uri) = this.uri.take() {
+            response.extensions_mut().insert(uri);
+        }
 
         Poll::Ready(Ok(response))
     }
@@ -77,10 +84,50 @@ where
     }
 }
 
+/// The request [`Uri`], with the parts marked as sensitive redacted.
+///
+/// [`InstrumentOperation`] inserts it into the response extensions. Operations are only known once the request has
+/// been routed, so access logging layers applied to the whole service can't redact URIs themselves: they can log this
+/// URI instead of the one in the request, which could contain sensitive labels or query parameters.
+///
+/// # Example
+///
+/// ```
+/// # use aws_smithy_http_server::instrumentation::RedactedUri;
+/// # use http::Response;
+/// fn uri_to_log(response: &Response<()>) -> &str {
+///     response
+///         .extensions()
+///         .get::<RedactedUri>()
+///         .map(RedactedUri::as_str)
+///         // The request wasn't routed to an operation, e.g. because no operation matched the URI.
+///         .unwrap_or("{unknown}")
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedactedUri(String);
+
+impl RedactedUri {
+    /// Returns the redacted URI.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for RedactedUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 /// A middleware [`Service`] responsible for:
 ///   - Opening a [`tracing::debug_span`] for the lifetime of the request, which includes the operation name, the
-///     [`Uri`], and the request headers.
+///     [`Uri`], the request headers, and, with the `request-id` feature, the `ServerRequestId` generated by the
+///     `ServerRequestIdProviderLayer`.
 ///   - A [`tracing::debug`] during response, which includes the response status code and headers.
+///   - Inserting the redacted [`Uri`] into the response extensions as a [`RedactedUri`].
+///
+/// Requests that aren't [`Sampled`] are neither given a span nor logged.
 ///
 /// The [`Display`](std::fmt::Display) and [`Debug`] of the request and response components can be modified using
 /// [`request_fmt`](InstrumentOperation::request_fmt) and [`response_fmt`](InstrumentOperation::response_fmt).
@@ -171,18 +218,81 @@ where
     }
 
     fn call(&mut self, request: Request<U>) -> Self::Future {
-        let span = {
+        let uri = RedactedUri(self.make_request.make_display(request.uri()).to_string());
+        let log = Sampled::is_sampled(request.extensions());
+        let span = if !log {
+            Span::none()
+        } else {
             let headers = self.make_request.make_debug(request.headers());
-            let uri = self.make_request.make_display(request.uri());
-            debug_span!("request", operation = %self.operation_id.absolute(), method = %request.method(), %uri, ?headers)
+            debug_span!(
+                "request",
+                operation = %self.operation_id.absolute(),
+                method = %request.method(),
+                %uri,
+                ?headers,
+                request_id = tracing::field::Empty,
+            )
         };
+        #[cfg(feature = "request-id")]
+        if let Some(request_id) = request
+            .extensions()
+            .get::<crate::request::request_id::ServerRequestId>()
+        {
+            span.record("request_id", tracing::field::display(request_id));
+        }
 
         InstrumentedFuture {
             inner: InnerFuture {
                 inner: self.inner.call(request),
                 make: self.make_response.clone(),
+                uri: Some(uri),
+                log,
             }
             .instrument(span),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use http::{Request, Response};
+    use tower::{service_fn, ServiceExt};
+
+    use crate::instrumentation::sensitivity::{uri::QueryMarker, RequestFmt};
+    use crate::shape_id::ShapeId;
+
+    use super::{InstrumentOperation, RedactedUri};
+
+    const ID: ShapeId = ShapeId::new("namespace#Operation", "namespace", "Operation");
+
+    #[tokio::test]
+    async fn redacted_uri_is_inserted_into_the_response_extensions() {
+        let request_fmt = RequestFmt::new()
+            .label(|index| index == 1, None)
+            .query(|name| QueryMarker {
+                key: false,
+                value: name == "secret",
+            });
+        let svc = InstrumentOperation::new(
+            service_fn(|_: Request<()>| async { Ok::<_, Infallible>(Response::new(())) }),
+            ID,
+        )
+        .request_fmt(request_fmt);
+
+        let request = Request::get("http://localhost/a/b/c?secret=value&other=value")
+            .body(())
+            .unwrap();
+        let response = svc.oneshot(request).await.unwrap();
+        let uri = response.extensions().get::<RedactedUri>().unwrap();
+
+        #[cfg(not(feature = "unredacted-logging"))]
+        assert_eq!(
+            "http://localhost/a/{redacted}/c?secret={redacted}&other=value",
+            uri.as_str()
+        );
+        #[cfg(feature = "unredacted-logging")]
+        assert_eq!("http://localhost/a/b/c?secret=value&other=value", uri.as_str());
+    }
+}
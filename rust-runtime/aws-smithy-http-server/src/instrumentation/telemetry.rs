@@ -0,0 +1,453 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! OpenTelemetry compatible server spans and metrics.
+//!
+//! The [`TelemetryPlugin`] applies [`TelemetryOperation`] to every operation, which:
+//!
+//! - Extracts the [W3C `traceparent`](https://www.w3.org/TR/trace-context/#traceparent-header) of the request, and
+//!   inserts it into the request extensions as a [`TraceParent`].
+//! - Opens a `smithy.server.operation` [`tracing::info_span`] for the lifetime of the request, with the
+//!   [semantic convention attributes](aws_smithy_observability::semconv) of an RPC server span, and the trace and
+//!   parent span IDs of the `traceparent`. The `otel.name` and `otel.kind` fields name the span `{service}/{operation}`
+//!   and make it a server span when it is exported with `tracing-opentelemetry`. With the `opentelemetry` feature, the
+//!   caller's span becomes the OpenTelemetry parent of this span, so it's exported as part of the caller's trace.
+//! - Records the duration of the request, in milliseconds, in the `http.server.duration` histogram of a
+//!   [`TelemetryProvider`], with the service, operation and response status code as attributes.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use aws_smithy_http_server::plugin::HttpPlugins;
+//! use aws_smithy_http_server::instrumentation::telemetry::TelemetryExt;
+//!
+//! // The histogram is created with the meter provider of the global `TelemetryProvider`.
+//! let http_plugins = HttpPlugins::new().telemetry();
+//! ```
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use aws_smithy_observability::global::get_telemetry_provider;
+use aws_smithy_observability::instruments::Histogram;
+use aws_smithy_observability::{semconv, AttributeValue, Attributes, TelemetryProvider};
+use http::{HeaderMap, Request, Response};
+use tokio::time::Instant;
+use tower::Service;
+use tracing::instrument::Instrumented;
+use tracing::{Instrument, Span};
+
+use crate::operation::OperationShape;
+use crate::plugin::{HttpMarker, HttpPlugins, Plugin, PluginStack};
+use crate::service::ServiceShape;
+
+const SCOPE: &str = "aws-smithy-http-server";
+const TRACEPARENT: &str = "traceparent";
+
+/// The [W3C `traceparent`](https://www.w3.org/TR/trace-context/#traceparent-header) of a request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TraceParent {
+    trace_id: u128,
+    parent_id: u64,
+    flags: u8,
+}
+
+impl TraceParent {
+    /// Parses the `traceparent` header in `headers`.
+    ///
+    /// Returns `None` if there is no `traceparent` header, or if it is invalid.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        Self::parse(headers.get(TRACEPARENT)?.to_str().ok()?)
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        fn hex(value: &str, len: usize) -> Option<&str> {
+            let is_hex = value
+                .bytes()
+                .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte));
+            (value.len() == len && is_hex).then_some(value)
+        }
+
+        let mut parts = value.trim().split('-');
+        let version = u8::from_str_radix(hex(parts.next()?, 2)?, 16).ok()?;
+        let trace_id = u128::from_str_radix(hex(parts.next()?, 32)?, 16).ok()?;
+        let parent_id = u64::from_str_radix(hex(parts.next()?, 16)?, 16).ok()?;
+        let flags = u8::from_str_radix(hex(parts.next()?, 2)?, 16).ok()?;
+        // Later versions may add fields, but version 00 has exactly four
+        let valid_fields = parts.next().is_none() || version > 0;
+        if version == 0xff || !valid_fields || trace_id == 0 || parent_id == 0 {
+            return None;
+        }
+        Some(Self {
+            trace_id,
+            parent_id,
+            flags,
+        })
+    }
+
+    /// Returns the ID of the trace the request is part of.
+    pub fn trace_id(&self) -> u128 {
+        self.trace_id
+    }
+
+    /// Returns the ID of the caller's span.
+    pub fn parent_id(&self) -> u64 {
+        self.parent_id
+    }
+
+    /// Returns `true` if the caller may have recorded its span.
+    pub fn sampled(&self) -> bool {
+        self.flags & 0x01 == 0x01
+    }
+
+    /// Returns an OpenTelemetry context whose span is the caller's remote span.
+    #[cfg(feature = "opentelemetry")]
+    fn otel_context(&self) -> opentelemetry::Context {
+        use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
+
+        let span_context = SpanContext::new(
+            TraceId::from_bytes(self.trace_id.to_be_bytes()),
+            SpanId::from_bytes(self.parent_id.to_be_bytes()),
+            TraceFlags::new(self.flags),
+            true,
+            TraceState::default(),
+        );
+        opentelemetry::Context::new().with_remote_span_context(span_context)
+    }
+}
+
+impl fmt::Display for TraceParent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "00-{:032x}-{:016x}-{:02x}",
+            self.trace_id, self.parent_id, self.flags
+        )
+    }
+}
+
+/// A [`Plugin`] which applies [`TelemetryOperation`] to every operation.
+///
+/// See the [module](crate::instrumentation::telemetry) documentation for more information.
+#[derive(Clone, Debug)]
+pub struct TelemetryPlugin {
+    duration: Arc<dyn Histogram>,
+}
+
+impl TelemetryPlugin {
+    /// Creates a plugin that records metrics with the meter provider of the global [`TelemetryProvider`].
+    pub fn new() -> Self {
+        let provider = get_telemetry_provider().unwrap_or_else(|err| {
+            tracing::debug!(err = %err, "failed to get the global telemetry provider; server metrics are disabled");
+            Arc::new(TelemetryProvider::noop())
+        });
+        Self::from_provider(&provider)
+    }
+
+    /// Creates a plugin that records metrics with the meter provider of `provider`.
+    pub fn from_provider(provider: &TelemetryProvider) -> Self {
+        let duration = provider
+            .meter_provider()
+            .get_meter(SCOPE, None)
+            .create_histogram("http.server.duration")
+            .set_description("The duration of inbound HTTP requests")
+            .set_units("ms")
+            .build();
+        Self { duration }
+    }
+}
+
+impl Default for TelemetryPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Ser, Op, T> Plugin<Ser, Op, T> for TelemetryPlugin
+where
+    Ser: ServiceShape,
+    Op: OperationShape,
+{
+    type Output = TelemetryOperation<T>;
+
+    fn apply(&self, inner: T) -> Self::Output {
+        let service = Ser::ID.name();
+        let method = Op::ID.name();
+        TelemetryOperation {
+            inner,
+            service,
+            method,
+            span_name: format!("{service}/{method}").into(),
+            duration: self.duration.clone(),
+        }
+    }
+}
+
+impl HttpMarker for TelemetryPlugin {}
+
+/// An extension trait for applying [`TelemetryPlugin`].
+pub trait TelemetryExt<CurrentPlugin> {
+    /// Applies a [`TelemetryOperation`] to every operation, recording metrics with the meter provider of the global
+    /// [`TelemetryProvider`]. See the [module](crate::instrumentation::telemetry) documentation for more information.
+    fn telemetry(self) -> HttpPlugins<PluginStack<TelemetryPlugin, CurrentPlugin>>;
+}
+
+impl<CurrentPlugin> TelemetryExt<CurrentPlugin> for HttpPlugins<CurrentPlugin> {
+    fn telemetry(self) -> HttpPlugins<PluginStack<TelemetryPlugin, CurrentPlugin>> {
+        self.push(TelemetryPlugin::new())
+    }
+}
+
+/// A middleware [`Service`] that opens a server span for the request and records its duration.
+///
+/// See the [module](crate::instrumentation::telemetry) documentation for more information.
+#[derive(Clone, Debug)]
+pub struct TelemetryOperation<S> {
+    inner: S,
+    service: &'static str,
+    method: &'static str,
+    span_name: Arc<str>,
+    duration: Arc<dyn Histogram>,
+}
+
+impl<S, B, RespB> Service<Request<B>> for TelemetryOperation<S>
+where
+    S: Service<Request<B>, Response = Response<RespB>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = TelemetryFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> Self::Future {
+        let span = tracing::info_span!(
+            "smithy.server.operation",
+            otel.name = %self.span_name,
+            otel.kind = "server",
+            rpc.system = "smithy",
+            rpc.service = self.service,
+            rpc.method = self.method,
+            http.request.method = %request.method(),
+            http.status_code = tracing::field::Empty,
+            trace_id = tracing::field::Empty,
+            parent_id = tracing::field::Empty,
+        );
+        if let Some(trace_parent) = TraceParent::from_headers(request.headers()) {
+            span.record(
+                "trace_id",
+                tracing::field::display(format_args!("{:032x}", trace_parent.trace_id())),
+            );
+            span.record(
+                "parent_id",
+                tracing::field::display(format_args!("{:016x}", trace_parent.parent_id())),
+            );
+            #[cfg(feature = "opentelemetry")]
+            {
+                use tracing_opentelemetry::OpenTelemetrySpanExt;
+                span.set_parent(trace_parent.otel_context());
+            }
+            request.extensions_mut().insert(trace_parent);
+        }
+
+        TelemetryFuture {
+            inner: self.inner.call(request).instrument(span.clone()),
+            span,
+            start: Instant::now(),
+            service: self.service,
+            method: self.method,
+            duration: self.duration.clone(),
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// The [`Future`] of a [`TelemetryOperation`], which records the response status code and request duration.
+    pub struct TelemetryFuture<Fut> {
+        #[pin]
+        inner: Instrumented<Fut>,
+        span: Span,
+        start: Instant,
+        service: &'static str,
+        method: &'static str,
+        duration: Arc<dyn Histogram>,
+    }
+}
+
+impl<Fut, RespB, E> Future for TelemetryFuture<Fut>
+where
+    Fut: Future<Output = Result<Response<RespB>, E>>,
+{
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let result = futures_util::ready!(this.inner.poll(cx));
+        let elapsed = this.start.elapsed();
+
+        let mut attributes = Attributes::new();
+        attributes.set(semconv::RPC_SERVICE, AttributeValue::String((*this.service).to_owned()));
+        attributes.set(semconv::RPC_METHOD, AttributeValue::String((*this.method).to_owned()));
+        if let Ok(response) = &result {
+            let status_code = response.status().as_u16();
+            this.span.record(semconv::HTTP_STATUS_CODE, status_code);
+            attributes.set(semconv::HTTP_STATUS_CODE, AttributeValue::I64(status_code.into()));
+        }
+        this.duration
+            .record(elapsed.as_secs_f64() * 1000.0, Some(&attributes), None);
+
+        Poll::Ready(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+    use std::sync::Arc;
+
+    use aws_smithy_observability::test_util::RecordingMeter;
+    use aws_smithy_observability::{AttributeValue, TelemetryProvider};
+    use http::{HeaderMap, HeaderValue, Request, Response, StatusCode};
+    use tower::{service_fn, ServiceExt};
+    use tracing_test::traced_test;
+
+    use super::{TelemetryPlugin, TraceParent};
+    use crate::operation::OperationShape;
+    use crate::plugin::Plugin;
+    use crate::service::ServiceShape;
+    use crate::shape_id::ShapeId;
+
+    struct PokemonService;
+    impl ServiceShape for PokemonService {
+        const ID: ShapeId = ShapeId::new("com.example#PokemonService", "com.example", "PokemonService");
+        const VERSION: Option<&'static str> = None;
+        type Protocol = ();
+        type Operations = ();
+    }
+
+    struct GetPokemon;
+    impl OperationShape for GetPokemon {
+        const ID: ShapeId = ShapeId::new("com.example#GetPokemon", "com.example", "GetPokemon");
+        type Input = ();
+        type Output = ();
+        type Error = ();
+    }
+
+    #[test]
+    fn parses_traceparent() {
+        let parse = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert("traceparent", HeaderValue::from_str(value).unwrap());
+            TraceParent::from_headers(&headers)
+        };
+
+        let trace_parent = parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").unwrap();
+        assert_eq!(0x4bf92f3577b34da6a3ce929d0e0e4736, trace_parent.trace_id());
+        assert_eq!(0x00f067aa0ba902b7, trace_parent.parent_id());
+        assert!(trace_parent.sampled());
+        assert_eq!(
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            trace_parent.to_string()
+        );
+        assert!(!parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00")
+            .unwrap()
+            .sampled());
+        // Later versions may have more fields
+        assert!(parse("01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra").is_some());
+
+        for invalid in [
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e473-00f067aa0ba902b7-01",
+            "00-+bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+        ] {
+            assert_eq!(None, parse(invalid), "{invalid}");
+        }
+        assert_eq!(None, TraceParent::from_headers(&HeaderMap::new()));
+    }
+
+    #[cfg(feature = "opentelemetry")]
+    #[test]
+    fn traceparent_is_the_remote_otel_parent() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "traceparent",
+            HeaderValue::from_static("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+        );
+        let context = TraceParent::from_headers(&headers).unwrap().otel_context();
+
+        use opentelemetry::trace::TraceContextExt;
+        let span_context = context.span().span_context().clone();
+        assert!(span_context.is_remote());
+        assert!(span_context.trace_flags().is_sampled());
+        assert_eq!(
+            0x4bf92f3577b34da6a3ce929d0e0e4736,
+            u128::from_be_bytes(span_context.trace_id().to_bytes())
+        );
+        assert_eq!(
+            0x00f067aa0ba902b7,
+            u64::from_be_bytes(span_context.span_id().to_bytes())
+        );
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn opens_server_spans_and_records_request_durations() {
+        let meter = RecordingMeter::new();
+        let provider = TelemetryProvider::builder()
+            .meter_provider(Arc::new(meter.clone()))
+            .build();
+        let plugin = TelemetryPlugin::from_provider(&provider);
+        let svc = Plugin::<PokemonService, GetPokemon, _>::apply(
+            &plugin,
+            service_fn(|request: Request<()>| async move {
+                let trace_parent = request.extensions().get::<TraceParent>().unwrap();
+                assert_eq!(0x00f067aa0ba902b7, trace_parent.parent_id());
+                tracing::info!("handling the request");
+                let mut response = Response::new(());
+                *response.status_mut() = StatusCode::NOT_FOUND;
+                Ok::<_, Infallible>(response)
+            }),
+        );
+
+        let request = Request::post("http://localhost/pokemon")
+            .header("traceparent", "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+            .body(())
+            .unwrap();
+        let response = svc.oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+
+        assert!(logs_contain(
+            "smithy.server.operation{otel.name=PokemonService/GetPokemon otel.kind=\"server\" \
+             rpc.system=\"smithy\" rpc.service=\"PokemonService\" rpc.method=\"GetPokemon\" \
+             http.request.method=POST trace_id=4bf92f3577b34da6a3ce929d0e0e4736 parent_id=00f067aa0ba902b7}: \
+             aws_smithy_http_server::instrumentation::telemetry::tests: handling the request"
+        ));
+
+        let measurements = meter.measurements();
+        assert_eq!(1, measurements.len());
+        assert_eq!("http.server.duration", measurements[0].instrument());
+        assert!(measurements[0].value() >= 0.0);
+        let attributes = measurements[0].attributes();
+        assert_eq!(
+            Some(&AttributeValue::String("PokemonService".into())),
+            attributes.get("rpc.service")
+        );
+        assert_eq!(
+            Some(&AttributeValue::String("GetPokemon".into())),
+            attributes.get("rpc.method")
+        );
+        assert_eq!(Some(&AttributeValue::I64(404)), attributes.get("http.status_code"));
+    }
+}
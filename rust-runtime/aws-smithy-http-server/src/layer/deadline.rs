@@ -0,0 +1,338 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Middleware for propagating request deadlines.
+//!
+//! Callers communicate how much time they are willing to wait for a response through a header
+//! (`x-request-deadline-ms` by default) holding the remaining budget in milliseconds. A relative budget
+//! is used rather than an absolute timestamp so that clock skew between hosts does not matter.
+//!
+//! [`DeadlineLayer`] converts that header into a [`Deadline`] and inserts it into the request extensions,
+//! where handlers can access it using [`Extension<Deadline>`](crate::Extension) and forward the remaining
+//! budget to downstream clients. When [enforcement](DeadlineLayer::enforce) is enabled, handlers still
+//! running once the deadline has passed are cancelled and a `504 Gateway Timeout` response is returned.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aws_smithy_http_server::layer::deadline::DeadlineLayer;
+//! use std::time::Duration;
+//! use tower::Layer;
+//!
+//! // Cancel requests exceeding their deadline, and never allow more than 30 seconds per request.
+//! let deadline_layer = DeadlineLayer::new()
+//!     .max_budget(Duration::from_secs(30))
+//!     .enforce(true);
+//! # async fn handle() { }
+//! let app = tower::service_fn(handle);
+//! let app = deadline_layer.layer(app);
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use http::header::HeaderName;
+use http::{Request, Response, StatusCode};
+use pin_project_lite::pin_project;
+use tokio::time::{Instant, Sleep};
+use tower::{Layer, Service};
+
+use crate::body::{empty, BoxBody};
+
+/// The default name of the header carrying the remaining budget of a request, in milliseconds.
+pub const DEFAULT_DEADLINE_HEADER: HeaderName = HeaderName::from_static("x-request-deadline-ms");
+
+/// The point in time by which a response to the request is expected.
+///
+/// Inserted into the request extensions by [`DeadlineLayer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Deadline {
+    instant: Instant,
+}
+
+impl Deadline {
+    /// Create a deadline `budget` from now.
+    pub fn after(budget: Duration) -> Self {
+        Self {
+            instant: Instant::now() + budget,
+        }
+    }
+
+    /// Create a deadline at the given instant.
+    pub fn at(instant: Instant) -> Self {
+        Self { instant }
+    }
+
+    /// Return the instant of the deadline.
+    pub fn instant(&self) -> Instant {
+        self.instant
+    }
+
+    /// Return the time remaining until the deadline, or zero if it has already passed.
+    ///
+    /// This is the budget to forward to downstream services.
+    pub fn remaining(&self) -> Duration {
+        self.instant.saturating_duration_since(Instant::now())
+    }
+
+    /// Return `true` if the deadline has passed.
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+}
+
+/// A [`tower::Layer`] used to apply [`DeadlineService`].
+///
+/// See the [module](crate::layer::deadline) documentation for more information.
+#[derive(Clone, Debug)]
+pub struct DeadlineLayer {
+    header_name: HeaderName,
+    max_budget: Option<Duration>,
+    enforce: bool,
+}
+
+impl Default for DeadlineLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeadlineLayer {
+    /// Create a layer reading the deadline from the [`DEFAULT_DEADLINE_HEADER`] without enforcing it.
+    pub fn new() -> Self {
+        Self {
+            header_name: DEFAULT_DEADLINE_HEADER,
+            max_budget: None,
+            enforce: false,
+        }
+    }
+
+    /// Set the name of the header carrying the remaining budget of a request, in milliseconds.
+    pub fn header_name(mut self, header_name: HeaderName) -> Self {
+        self.header_name = header_name;
+        self
+    }
+
+    /// Cap the budget a caller can request.
+    ///
+    /// Requests without a deadline header are given this budget. By default, budgets are not capped and
+    /// requests without a deadline header have no [`Deadline`].
+    pub fn max_budget(mut self, max_budget: Duration) -> Self {
+        self.max_budget = Some(max_budget);
+        self
+    }
+
+    /// Set whether handlers exceeding the deadline are cancelled. Disabled by default.
+    pub fn enforce(mut self, enforce: bool) -> Self {
+        self.enforce = enforce;
+        self
+    }
+}
+
+impl<S> Layer<S> for DeadlineLayer {
+    type Service = DeadlineService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        DeadlineService {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// A [`Service`] inserting the [`Deadline`] of each request into its extensions, and optionally enforcing it.
+///
+/// See the [module](crate::layer::deadline) documentation for more information.
+#[derive(Clone, Debug)]
+pub struct DeadlineService<S> {
+    inner: S,
+    layer: DeadlineLayer,
+}
+
+impl<S> DeadlineService<S> {
+    fn deadline<B>(&self, req: &Request<B>) -> Option<Deadline> {
+        let requested = req.headers().get(&self.layer.header_name).and_then(|value| {
+            let millis = value.to_str().ok().and_then(|value| value.trim().parse::<u64>().ok());
+            if millis.is_none() {
+                tracing::debug!(header = %self.layer.header_name, ?value, "ignoring invalid deadline header");
+            }
+            millis.map(Duration::from_millis)
+        });
+        let budget = match (requested, self.layer.max_budget) {
+            (Some(requested), Some(max)) => Some(requested.min(max)),
+            (requested, max) => requested.or(max),
+        };
+        budget.map(Deadline::after)
+    }
+}
+
+impl<S, B> Service<Request<B>> for DeadlineService<S>
+where
+    S: Service<Request<B>, Response = Response<BoxBody>>,
+{
+    type Response = Response<BoxBody>;
+    type Error = S::Error;
+    type Future = DeadlineFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<B>) -> Self::Future {
+        let deadline = self.deadline(&req);
+        if let Some(deadline) = deadline {
+            req.extensions_mut().insert(deadline);
+        }
+        let sleep = deadline
+            .filter(|_| self.layer.enforce)
+            .map(|deadline| tokio::time::sleep_until(deadline.instant()));
+        DeadlineFuture {
+            inner: self.inner.call(req),
+            sleep,
+        }
+    }
+}
+
+pin_project! {
+    /// Response future for [`DeadlineService`].
+    pub struct DeadlineFuture<F> {
+        #[pin]
+        inner: F,
+        #[pin]
+        sleep: Option<Sleep>,
+    }
+}
+
+impl<F, E> Future for DeadlineFuture<F>
+where
+    F: Future<Output = Result<Response<BoxBody>, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if let Poll::Ready(result) = this.inner.poll(cx) {
+            return Poll::Ready(result);
+        }
+        match this.sleep.as_pin_mut().map(|sleep| sleep.poll(cx)) {
+            Some(Poll::Ready(())) => {
+                tracing::debug!("request exceeded its deadline, cancelling the handler");
+                let mut response = Response::new(empty());
+                *response.status_mut() = StatusCode::GATEWAY_TIMEOUT;
+                Poll::Ready(Ok(response))
+            }
+            _ => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use tower::ServiceExt;
+
+    async fn deadline_of(layer: DeadlineLayer, request: Request<()>) -> Option<Deadline> {
+        let app = tower::service_fn(|request: Request<()>| async move {
+            let deadline = request.extensions().get::<Deadline>().copied();
+            let mut response = Response::new(empty());
+            response.extensions_mut().insert(deadline);
+            Ok::<_, Infallible>(response)
+        });
+        let response = layer.layer(app).oneshot(request).await.unwrap();
+        *response.extensions().get::<Option<Deadline>>().unwrap()
+    }
+
+    fn request_with_deadline(header: &str, value: &str) -> Request<()> {
+        Request::builder().header(header, value).body(()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn deadline_is_inserted_into_request_extensions() {
+        let deadline = deadline_of(
+            DeadlineLayer::new(),
+            request_with_deadline("x-request-deadline-ms", "5000"),
+        )
+        .await
+        .expect("`Deadline` should be inserted");
+        assert!(deadline.remaining() <= Duration::from_secs(5));
+        assert!(deadline.remaining() > Duration::from_secs(4));
+        assert!(!deadline.is_expired());
+    }
+
+    #[tokio::test]
+    async fn deadline_header_name_is_configurable() {
+        let layer = DeadlineLayer::new().header_name(HeaderName::from_static("x-custom-deadline"));
+        assert!(
+            deadline_of(layer.clone(), request_with_deadline("x-custom-deadline", "5000"))
+                .await
+                .is_some()
+        );
+        assert!(
+            deadline_of(layer, request_with_deadline("x-request-deadline-ms", "5000"))
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_or_invalid_deadline_header_is_ignored() {
+        assert!(deadline_of(DeadlineLayer::new(), Request::new(())).await.is_none());
+        assert!(deadline_of(
+            DeadlineLayer::new(),
+            request_with_deadline("x-request-deadline-ms", "soon")
+        )
+        .await
+        .is_none());
+        assert!(deadline_of(
+            DeadlineLayer::new(),
+            request_with_deadline("x-request-deadline-ms", "-1")
+        )
+        .await
+        .is_none());
+    }
+
+    #[tokio::test]
+    async fn max_budget_caps_and_defaults_the_deadline() {
+        let layer = DeadlineLayer::new().max_budget(Duration::from_secs(1));
+        let capped = deadline_of(layer.clone(), request_with_deadline("x-request-deadline-ms", "60000"))
+            .await
+            .unwrap();
+        assert!(capped.remaining() <= Duration::from_secs(1));
+
+        let defaulted = deadline_of(layer, Request::new(())).await.unwrap();
+        assert!(defaulted.remaining() <= Duration::from_secs(1));
+        assert!(defaulted.remaining() > Duration::ZERO);
+    }
+
+    fn slow_app() -> impl Service<Request<()>, Response = Response<BoxBody>, Error = Infallible, Future = impl Send> {
+        tower::service_fn(|_request: Request<()>| async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            Ok::<_, Infallible>(Response::new(empty()))
+        })
+    }
+
+    #[tokio::test]
+    async fn enforced_deadline_cancels_slow_handlers() {
+        let app = DeadlineLayer::new().enforce(true).layer(slow_app());
+        let response = app
+            .oneshot(request_with_deadline("x-request-deadline-ms", "10"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::GATEWAY_TIMEOUT, response.status());
+    }
+
+    #[tokio::test]
+    async fn deadline_is_not_enforced_by_default() {
+        let app = DeadlineLayer::new().layer(slow_app());
+        let response = app
+            .oneshot(request_with_deadline("x-request-deadline-ms", "10"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+    }
+}
@@ -0,0 +1,582 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Middleware applying operational settings that can be changed while the server is running.
+//!
+//! [`DynamicServerConfig`] is a cheaply cloneable handle holding the current [`ServerSettings`]. Operators can
+//! replace the settings at any time, e.g. from their own control plane, using [`DynamicServerConfig::store`] or
+//! [`DynamicServerConfig::update`]. The swap is atomic: every request sees either the old or the new settings,
+//! never a mix of both.
+//!
+//! [`DynamicServerConfigLayer`] reads the settings once per request and:
+//! - rejects requests whose body is larger than the [maximum body size](ServerSettings::with_max_body_size) with a
+//!   `413 Payload Too Large`: up front when their `Content-Length` is larger, and once the handler returns when a
+//!   body without a `Content-Length` turns out to be larger, since reading the rest of it then fails,
+//! - cancels requests still running after the [request timeout](ServerSettings::with_request_timeout) and returns a
+//!   `503 Service Unavailable`,
+//! - removes the body of framework rejections, along with its `Content-Type` and `Content-Length`, when the
+//!   [rejection detail](ServerSettings::with_rejection_detail) is [`RejectionDetail::Minimal`],
+//! - decides whether requests are [sampled](crate::layer::sampling) with the
+//!   [log sampler](ServerSettings::with_log_sampler), which, among others, selects the requests logged by
+//!   [`InstrumentOperation`](crate::instrumentation::InstrumentOperation).
+//!
+//! The settings used for a request are inserted into its extensions as a [`ServerSettingsExtension`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aws_smithy_http_server::layer::dynamic_config::{DynamicServerConfig, ServerSettings};
+//! use std::time::Duration;
+//! use tower::Layer;
+//!
+//! let config = DynamicServerConfig::new(ServerSettings::new().with_max_body_size(Some(1024 * 1024)));
+//! # async fn handle(_req: hyper::Request<hyper::Body>) -> Result<http::Response<aws_smithy_http_server::body::BoxBody>, std::convert::Infallible> { todo!() }
+//! let app = tower::service_fn(handle);
+//! let app = config.layer().layer(app);
+//!
+//! // Later, e.g. when the control plane pushes new settings:
+//! config.update(|settings| settings.clone().with_request_timeout(Some(Duration::from_secs(5))));
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use http::header::{CONTENT_LENGTH, CONTENT_TYPE};
+use http::{Request, Response, StatusCode};
+use hyper::Body;
+use pin_project_lite::pin_project;
+use tokio::time::Sleep;
+use tower::{Layer, Service};
+
+use crate::body::{empty, BoxBody};
+use crate::error::BoxError;
+use crate::extension::RuntimeErrorExtension;
+use crate::layer::sampling::{Sampled, Sampler};
+
+/// How much detail the responses to requests rejected by the framework contain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RejectionDetail {
+    /// Responses contain the body serialized by the protocol, e.g. the reason a request failed validation.
+    #[default]
+    Full,
+    /// Responses only contain the status code and the headers, such as the error type.
+    Minimal,
+}
+
+/// Operational settings applied by [`DynamicServerConfigLayer`].
+///
+/// By default, no body size limit or request timeout is applied, rejections are returned in full and every request
+/// is logged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerSettings {
+    max_body_size: Option<u64>,
+    request_timeout: Option<Duration>,
+    rejection_detail: RejectionDetail,
+    log_sampler: Option<Sampler>,
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ServerSettings {
+    /// Creates the default settings.
+    pub fn new() -> Self {
+        Self {
+            max_body_size: None,
+            request_timeout: None,
+            rejection_detail: RejectionDetail::Full,
+            log_sampler: None,
+        }
+    }
+
+    /// Sets the maximum size of request bodies, in bytes, or removes the limit with `None`.
+    pub fn with_max_body_size(mut self, max_body_size: Option<u64>) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Returns the maximum size of request bodies, in bytes.
+    pub fn max_body_size(&self) -> Option<u64> {
+        self.max_body_size
+    }
+
+    /// Sets the time after which requests are cancelled, or removes the timeout with `None`.
+    pub fn with_request_timeout(mut self, request_timeout: Option<Duration>) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Returns the time after which requests are cancelled.
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    /// Sets how much detail the responses to requests rejected by the framework contain.
+    pub fn with_rejection_detail(mut self, rejection_detail: RejectionDetail) -> Self {
+        self.rejection_detail = rejection_detail;
+        self
+    }
+
+    /// Returns how much detail the responses to requests rejected by the framework contain.
+    pub fn rejection_detail(&self) -> RejectionDetail {
+        self.rejection_detail
+    }
+
+    /// Sets the [`Sampler`] deciding which requests are [`Sampled`], and therefore logged, or stops sampling
+    /// requests with `None`.
+    ///
+    /// The decision replaces the one of any [`SamplingLayer`](crate::layer::sampling::SamplingLayer) applied around
+    /// the [`DynamicServerConfigLayer`]. Use [`Sampler::interval`] to log one request out of every few.
+    pub fn with_log_sampler(mut self, log_sampler: Option<Sampler>) -> Self {
+        self.log_sampler = log_sampler;
+        self
+    }
+
+    /// Returns the [`Sampler`] deciding which requests are logged.
+    pub fn log_sampler(&self) -> Option<&Sampler> {
+        self.log_sampler.as_ref()
+    }
+}
+
+/// A handle to [`ServerSettings`] that can be replaced while the server is running.
+///
+/// Clones share the same settings. See the [module](crate::layer::dynamic_config) documentation for more information.
+#[derive(Clone, Debug)]
+pub struct DynamicServerConfig {
+    settings: Arc<RwLock<Arc<ServerSettings>>>,
+}
+
+impl Default for DynamicServerConfig {
+    fn default() -> Self {
+        Self::new(ServerSettings::new())
+    }
+}
+
+impl DynamicServerConfig {
+    /// Creates a handle holding `settings`.
+    pub fn new(settings: ServerSettings) -> Self {
+        Self {
+            settings: Arc::new(RwLock::new(Arc::new(settings))),
+        }
+    }
+
+    /// Returns the current settings.
+    pub fn load(&self) -> Arc<ServerSettings> {
+        self.settings
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Replaces the current settings, returning the previous ones.
+    ///
+    /// Requests already in flight keep using the settings they started with.
+    pub fn store(&self, settings: ServerSettings) -> Arc<ServerSettings> {
+        let mut current = self.settings.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        std::mem::replace(&mut *current, Arc::new(settings))
+    }
+
+    /// Replaces the current settings with the ones returned by `update`, returning the previous ones.
+    ///
+    /// Concurrent updates are serialized, so none of them is lost.
+    pub fn update(&self, update: impl FnOnce(&ServerSettings) -> ServerSettings) -> Arc<ServerSettings> {
+        let mut current = self.settings.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let updated = Arc::new(update(&current));
+        std::mem::replace(&mut *current, updated)
+    }
+
+    /// Returns a layer applying the settings of this handle.
+    pub fn layer(&self) -> DynamicServerConfigLayer {
+        DynamicServerConfigLayer::new(self.clone())
+    }
+}
+
+/// The settings a request is handled with, inserted into its extensions by [`DynamicServerConfigLayer`].
+#[derive(Clone, Debug)]
+pub struct ServerSettingsExtension {
+    settings: Arc<ServerSettings>,
+}
+
+impl ServerSettingsExtension {
+    /// Returns the settings the request is handled with.
+    pub fn settings(&self) -> &ServerSettings {
+        &self.settings
+    }
+}
+
+/// A [`tower::Layer`] used to apply [`DynamicServerConfigService`].
+///
+/// See the [module](crate::layer::dynamic_config) documentation for more information.
+#[derive(Clone, Debug)]
+pub struct DynamicServerConfigLayer {
+    config: DynamicServerConfig,
+}
+
+impl DynamicServerConfigLayer {
+    /// Creates a layer applying the settings of `config`.
+    pub fn new(config: DynamicServerConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl<S> Layer<S> for DynamicServerConfigLayer {
+    type Service = DynamicServerConfigService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        DynamicServerConfigService {
+            inner,
+            config: self.config.clone(),
+        }
+    }
+}
+
+/// A [`Service`] applying the current [`ServerSettings`] of a [`DynamicServerConfig`] to each request.
+///
+/// See the [module](crate::layer::dynamic_config) documentation for more information.
+#[derive(Clone, Debug)]
+pub struct DynamicServerConfigService<S> {
+    inner: S,
+    config: DynamicServerConfig,
+}
+
+#[derive(Debug)]
+struct BodyTooLarge {
+    max_body_size: u64,
+}
+
+impl std::fmt::Display for BodyTooLarge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "request body is larger than {} bytes", self.max_body_size)
+    }
+}
+
+impl std::error::Error for BodyTooLarge {}
+
+/// Makes reading `body` fail once it's longer than `max_body_size`, and records it in `too_large`.
+fn limit_body(body: Body, max_body_size: u64, too_large: Arc<AtomicBool>) -> Body {
+    let mut remaining = max_body_size;
+    Body::wrap_stream(body.map(move |chunk| {
+        let chunk = chunk?;
+        remaining = remaining.checked_sub(chunk.len() as u64).ok_or_else(|| {
+            too_large.store(true, Ordering::Relaxed);
+            BoxError::from(BodyTooLarge { max_body_size })
+        })?;
+        Ok::<_, BoxError>(chunk)
+    }))
+}
+
+fn payload_too_large() -> Response<BoxBody> {
+    let mut response = Response::new(empty());
+    *response.status_mut() = StatusCode::PAYLOAD_TOO_LARGE;
+    response
+}
+
+impl<S> Service<Request<Body>> for DynamicServerConfigService<S>
+where
+    S: Service<Request<Body>, Response = Response<BoxBody>>,
+{
+    type Response = Response<BoxBody>;
+    type Error = S::Error;
+    type Future = DynamicServerConfigFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<Body>) -> Self::Future {
+        let settings = self.config.load();
+        let mut body_too_large = None;
+
+        if let Some(max_body_size) = settings.max_body_size {
+            let content_length = req
+                .headers()
+                .get(CONTENT_LENGTH)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok());
+            match content_length {
+                Some(content_length) if content_length > max_body_size => {
+                    tracing::debug!(
+                        content_length,
+                        max_body_size,
+                        "rejecting request with a body that is too large"
+                    );
+                    return DynamicServerConfigFuture::Rejected {
+                        response: Some(payload_too_large()),
+                    };
+                }
+                // The `Content-Length` is enforced by hyper, so the body can't be longer than it.
+                Some(_) => {}
+                None => {
+                    let too_large = Arc::new(AtomicBool::new(false));
+                    let body = std::mem::take(req.body_mut());
+                    *req.body_mut() = limit_body(body, max_body_size, too_large.clone());
+                    body_too_large = Some(too_large);
+                }
+            }
+        }
+
+        if let Some(log_sampler) = &settings.log_sampler {
+            let sampled = log_sampler.sample(&req);
+            req.extensions_mut().insert(Sampled::new(sampled));
+        }
+        let rejection_detail = settings.rejection_detail;
+        let sleep = settings.request_timeout.map(tokio::time::sleep);
+        req.extensions_mut().insert(ServerSettingsExtension { settings });
+        DynamicServerConfigFuture::Inner {
+            inner: self.inner.call(req),
+            sleep,
+            rejection_detail,
+            body_too_large,
+        }
+    }
+}
+
+pin_project! {
+    /// Response future for [`DynamicServerConfigService`].
+    #[project = DynamicServerConfigFutureProj]
+    pub enum DynamicServerConfigFuture<F> {
+        /// The request was rejected before reaching the inner service.
+        Rejected {
+            response: Option<Response<BoxBody>>,
+        },
+        /// The request is handled by the inner service.
+        Inner {
+            #[pin]
+            inner: F,
+            #[pin]
+            sleep: Option<Sleep>,
+            rejection_detail: RejectionDetail,
+            body_too_large: Option<Arc<AtomicBool>>,
+        },
+    }
+}
+
+impl<F, E> Future for DynamicServerConfigFuture<F>
+where
+    F: Future<Output = Result<Response<BoxBody>, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project() {
+            DynamicServerConfigFutureProj::Rejected { response } => Poll::Ready(Ok(response
+                .take()
+                .expect("futures must not be polled after completion"))),
+            DynamicServerConfigFutureProj::Inner {
+                inner,
+                sleep,
+                rejection_detail,
+                body_too_large,
+            } => {
+                if let Poll::Ready(result) = inner.poll(cx) {
+                    if body_too_large
+                        .as_ref()
+                        .map_or(false, |too_large| too_large.load(Ordering::Relaxed))
+                    {
+                        tracing::debug!("rejecting request with a body that turned out to be too large");
+                        return Poll::Ready(Ok(payload_too_large()));
+                    }
+                    return Poll::Ready(result.map(|response| redact_rejection(response, *rejection_detail)));
+                }
+                match sleep.as_pin_mut().map(|sleep| sleep.poll(cx)) {
+                    Some(Poll::Ready(())) => {
+                        tracing::debug!("request exceeded the request timeout, cancelling the handler");
+                        let mut response = Response::new(empty());
+                        *response.status_mut() = StatusCode::SERVICE_UNAVAILABLE;
+                        Poll::Ready(Ok(response))
+                    }
+                    _ => Poll::Pending,
+                }
+            }
+        }
+    }
+}
+
+fn redact_rejection(mut response: Response<BoxBody>, rejection_detail: RejectionDetail) -> Response<BoxBody> {
+    if rejection_detail == RejectionDetail::Minimal && response.extensions().get::<RuntimeErrorExtension>().is_some() {
+        response.headers_mut().remove(CONTENT_LENGTH);
+        response.headers_mut().remove(CONTENT_TYPE);
+        *response.body_mut() = empty();
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use tower::ServiceExt;
+
+    /// Responds with the size of the request body, or a runtime error if it can't be read or is `invalid`.
+    fn app() -> impl Service<Request<Body>, Response = Response<BoxBody>, Error = Infallible, Future = impl Send> + Clone
+    {
+        tower::service_fn(|request: Request<Body>| async move {
+            let extension = request.extensions().get::<ServerSettingsExtension>().cloned();
+            let mut response = match hyper::body::to_bytes(request.into_body()).await {
+                Ok(bytes) if bytes != "invalid" => Response::new(crate::body::to_boxed(bytes.len().to_string())),
+                _ => {
+                    let mut response = Response::new(crate::body::to_boxed("invalid body"));
+                    *response.status_mut() = StatusCode::BAD_REQUEST;
+                    response
+                        .headers_mut()
+                        .insert(CONTENT_TYPE, http::HeaderValue::from_static("application/json"));
+                    response
+                        .extensions_mut()
+                        .insert(RuntimeErrorExtension::new("SerializationException".to_string()));
+                    response
+                }
+            };
+            response.extensions_mut().insert(extension);
+            Ok::<_, Infallible>(response)
+        })
+    }
+
+    async fn body_of(response: Response<BoxBody>) -> String {
+        String::from_utf8(hyper::body::to_bytes(response.into_body()).await.unwrap().to_vec()).unwrap()
+    }
+
+    fn chunked(body: &'static str) -> Request<Body> {
+        Request::new(Body::wrap_stream(futures_util::stream::iter(
+            body.as_bytes()
+                .chunks(2)
+                .map(|chunk| Ok::<_, Infallible>(bytes::Bytes::from_static(chunk))),
+        )))
+    }
+
+    #[tokio::test]
+    async fn settings_are_inserted_into_request_extensions() {
+        let config = DynamicServerConfig::default();
+        let response = config
+            .layer()
+            .layer(app())
+            .oneshot(Request::new(Body::empty()))
+            .await
+            .unwrap();
+        let extension = response
+            .extensions()
+            .get::<Option<ServerSettingsExtension>>()
+            .unwrap()
+            .clone()
+            .expect("`ServerSettingsExtension` should be inserted");
+        assert_eq!(&ServerSettings::new(), extension.settings());
+    }
+
+    #[tokio::test]
+    async fn max_body_size_is_enforced() {
+        let config = DynamicServerConfig::new(ServerSettings::new().with_max_body_size(Some(4)));
+        let app = config.layer().layer(app());
+
+        let request = Request::builder()
+            .header("content-length", "5")
+            .body(Body::from("hello"))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+
+        let request = Request::builder()
+            .header("content-length", "4")
+            .body(Body::from("hell"))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!("4", body_of(response).await);
+
+        let response = app.clone().oneshot(chunked("hell")).await.unwrap();
+        assert_eq!("4", body_of(response).await);
+        let response = app.oneshot(chunked("hello")).await.unwrap();
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+    }
+
+    #[tokio::test]
+    async fn settings_can_be_swapped_at_runtime() {
+        let config = DynamicServerConfig::default();
+        let app = config.layer().layer(app());
+
+        let response = app.clone().oneshot(chunked("hello")).await.unwrap();
+        assert_eq!("5", body_of(response).await);
+
+        let previous = config.update(|settings| settings.clone().with_max_body_size(Some(4)));
+        assert_eq!(None, previous.max_body_size());
+        assert_eq!(Some(4), config.load().max_body_size());
+        let response = app.clone().oneshot(chunked("hello")).await.unwrap();
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+
+        config.store(ServerSettings::new());
+        let response = app.oneshot(chunked("hello")).await.unwrap();
+        assert_eq!("5", body_of(response).await);
+    }
+
+    #[tokio::test]
+    async fn rejection_detail_can_be_minimal() {
+        let config = DynamicServerConfig::default();
+        let app = config.layer().layer(app());
+
+        let response = app.clone().oneshot(chunked("invalid")).await.unwrap();
+        assert_eq!("invalid body", body_of(response).await);
+
+        config.update(|settings| settings.clone().with_rejection_detail(RejectionDetail::Minimal));
+        let response = app.clone().oneshot(chunked("invalid")).await.unwrap();
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+        assert!(response.headers().get(CONTENT_TYPE).is_none());
+        assert_eq!("", body_of(response).await);
+
+        // Responses that aren't rejections are left untouched.
+        let response = app.oneshot(chunked("hell")).await.unwrap();
+        assert_eq!("4", body_of(response).await);
+    }
+
+    #[tokio::test]
+    async fn request_timeout_cancels_slow_handlers() {
+        let slow_app = tower::service_fn(|_request: Request<Body>| async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            Ok::<_, Infallible>(Response::new(empty()))
+        });
+        let config =
+            DynamicServerConfig::new(ServerSettings::new().with_request_timeout(Some(Duration::from_millis(10))));
+        let app = config.layer().layer(slow_app);
+
+        let response = app.clone().oneshot(Request::new(Body::empty())).await.unwrap();
+        assert_eq!(StatusCode::SERVICE_UNAVAILABLE, response.status());
+
+        config.update(|settings| settings.clone().with_request_timeout(None));
+        let response = app.oneshot(Request::new(Body::empty())).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
+    #[tokio::test]
+    async fn log_sampler_overrides_sampling_layer() {
+        use crate::layer::sampling::SamplingLayer;
+        use std::num::NonZeroU32;
+
+        let is_sampled = tower::service_fn(|request: Request<Body>| async move {
+            let sampled = Sampled::is_sampled(request.extensions());
+            Ok::<_, Infallible>(Response::new(crate::body::to_boxed(sampled.to_string())))
+        });
+        let config = DynamicServerConfig::default();
+        let app = SamplingLayer::new(Sampler::random(0.0)).layer(config.layer().layer(is_sampled));
+
+        let response = app.clone().oneshot(Request::new(Body::empty())).await.unwrap();
+        assert_eq!("false", body_of(response).await);
+
+        config.update(|settings| {
+            let log_sampler = Sampler::interval(NonZeroU32::new(3).unwrap());
+            settings.clone().with_log_sampler(Some(log_sampler))
+        });
+        let mut sampled = Vec::new();
+        for _ in 0..6 {
+            let response = app.clone().oneshot(Request::new(Body::empty())).await.unwrap();
+            sampled.push(body_of(response).await);
+        }
+        assert_eq!(vec!["true", "false", "false", "true", "false", "false"], sampled);
+    }
+}
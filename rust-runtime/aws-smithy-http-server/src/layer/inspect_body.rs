@@ -0,0 +1,199 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Middleware for inspecting the request body before it is deserialized.
+//!
+//! Some handlers must read the request body before the operation does, e.g. to verify a signature
+//! over it. Once read, the body can't be read again, so [`InspectBodyLayer`] buffers the body into
+//! a [`BufferedOnce`], up to a size limit, hands it to a hook and then restores it into the request
+//! that is passed to the inner service.
+//!
+//! Requests whose body exceeds the limit are rejected with a `413 Payload Too Large`, and requests
+//! whose body can't be read are rejected with a `400 Bad Request`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aws_smithy_http_server::body::BufferedOnce;
+//! use aws_smithy_http_server::layer::inspect_body::InspectBodyLayer;
+//! use hyper::{Body, Request, Response, StatusCode};
+//! use tower::Layer;
+//!
+//! # fn verify(_signature: &[u8], _body: &[u8]) -> bool { true }
+//! // Reject requests whose body doesn't match their signature.
+//! let verify_layer = InspectBodyLayer::new(64 * 1024, |req: &Request<BufferedOnce>| {
+//!     let signature = req.headers().get("x-signature").map(|value| value.as_bytes()).unwrap_or_default();
+//!     if verify(signature, req.body().bytes()) {
+//!         Ok(())
+//!     } else {
+//!         Err(Response::builder()
+//!             .status(StatusCode::FORBIDDEN)
+//!             .body(aws_smithy_http_server::body::to_boxed(""))
+//!             .unwrap())
+//!     }
+//! });
+//! # async fn handle(_req: Request<Body>) -> Result<Response<aws_smithy_http_server::body::BoxBody>, std::convert::Infallible> { todo!() }
+//! let app = tower::service_fn(handle);
+//! let app = verify_layer.layer(app);
+//! ```
+//!
+//! The layer can also be applied to some operations only, as an HTTP plugin, by wrapping it in a
+//! [`LayerPlugin`](crate::plugin::LayerPlugin).
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use http::StatusCode;
+use hyper::{Body, Request, Response};
+use tower::{Layer, Service, ServiceExt};
+
+use crate::body::{BoxBody, BufferError, BufferedOnce};
+use crate::error::BoxError;
+
+/// A [`tower::Layer`] used to apply [`InspectBody`].
+#[derive(Clone, Debug)]
+pub struct InspectBodyLayer<F> {
+    limit: usize,
+    inspect: F,
+}
+
+impl<F> InspectBodyLayer<F>
+where
+    F: Fn(&Request<BufferedOnce>) -> Result<(), Response<BoxBody>>,
+{
+    /// Buffers request bodies of up to `limit` bytes and passes the requests to `inspect`.
+    ///
+    /// When `inspect` returns an error, its response is returned without calling the inner service.
+    pub fn new(limit: usize, inspect: F) -> Self {
+        Self { limit, inspect }
+    }
+}
+
+impl<S, F: Clone> Layer<S> for InspectBodyLayer<F> {
+    type Service = InspectBody<S, F>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        InspectBody {
+            inner,
+            limit: self.limit,
+            inspect: self.inspect.clone(),
+        }
+    }
+}
+
+/// A middleware [`Service`] that inspects the buffered request body before calling the inner
+/// service.
+#[derive(Clone, Debug)]
+pub struct InspectBody<S, F> {
+    inner: S,
+    limit: usize,
+    inspect: F,
+}
+
+/// Future for [`InspectBody`].
+pub type InspectBodyFuture<E> = Pin<Box<dyn Future<Output = Result<Response<BoxBody>, E>> + Send>>;
+
+impl<S, F, B> Service<Request<B>> for InspectBody<S, F>
+where
+    S: Service<Request<Body>, Response = Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send,
+    F: Fn(&Request<BufferedOnce>) -> Result<(), Response<BoxBody>> + Clone + Send + 'static,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = InspectBodyFuture<S::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // The check that the service is ready is done by `Oneshot` below.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let clone = self.inner.clone();
+        let service = std::mem::replace(&mut self.inner, clone);
+        let (limit, inspect) = (self.limit, self.inspect.clone());
+        Box::pin(async move {
+            let (parts, body) = req.into_parts();
+            let body = match BufferedOnce::buffer(body, limit).await {
+                Ok(body) => body,
+                Err(err) => {
+                    tracing::debug!(error = %err, "failed to buffer the request body");
+                    let status = match err {
+                        BufferError::TooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+                        _ => StatusCode::BAD_REQUEST,
+                    };
+                    return Ok(Response::builder().status(status).body(crate::body::empty()).unwrap());
+                }
+            };
+            let req = Request::from_parts(parts, body);
+            if let Err(response) = inspect(&req) {
+                return Ok(response);
+            }
+            service.oneshot(req.map(Body::from)).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use http::StatusCode;
+    use hyper::{Body, Request, Response};
+    use tower::{Layer, ServiceExt};
+
+    use super::InspectBodyLayer;
+    use crate::body::{to_boxed, BoxBody, BufferedOnce};
+
+    fn echo_layer(
+        limit: usize,
+    ) -> impl tower::Service<Request<Body>, Response = Response<BoxBody>, Error = Infallible> {
+        let layer = InspectBodyLayer::new(limit, |req: &Request<BufferedOnce>| {
+            if req.body().bytes().starts_with(b"forbidden") {
+                Err(Response::builder()
+                    .status(StatusCode::FORBIDDEN)
+                    .body(to_boxed(""))
+                    .unwrap())
+            } else {
+                Ok(())
+            }
+        });
+        layer.layer(tower::service_fn(|req: Request<Body>| async move {
+            // The operation can still read the whole body.
+            let body = hyper::body::to_bytes(req.into_body()).await.unwrap();
+            Ok::<_, Infallible>(Response::new(to_boxed(body)))
+        }))
+    }
+
+    #[tokio::test]
+    async fn body_is_restored_after_inspection() {
+        let response = echo_layer(1024)
+            .oneshot(Request::new(Body::from("hello")))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!("hello", body);
+    }
+
+    #[tokio::test]
+    async fn inspection_can_reject_requests() {
+        let response = echo_layer(1024)
+            .oneshot(Request::new(Body::from("forbidden")))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::FORBIDDEN, response.status());
+    }
+
+    #[tokio::test]
+    async fn bodies_over_the_limit_are_rejected() {
+        let response = echo_layer(4).oneshot(Request::new(Body::from("hello"))).await.unwrap();
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+    }
+}
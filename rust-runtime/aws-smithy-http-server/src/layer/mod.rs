@@ -7,3 +7,8 @@
 //! [`Router`](crate::routing::Router), so they are enacted before a request is routed.
 
 pub mod alb_health_check;
+pub mod deadline;
+pub mod dynamic_config;
+pub mod inspect_body;
+pub mod sampling;
+pub mod tls_connect_info;
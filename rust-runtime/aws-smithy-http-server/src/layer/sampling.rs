@@ -0,0 +1,316 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Middleware for sampling requests for expensive diagnostics.
+//!
+//! Diagnostics such as wire logging, full body capture or deep validation are too expensive to
+//! run on every request in production, but are most useful when they are left enabled there.
+//! [`SamplingLayer`] decides once per request whether it is sampled, and inserts the decision into
+//! the request extensions as a [`Sampled`]. Diagnostics consult it with [`Sampled::is_sampled`]
+//! before doing expensive work, so that they can be left enabled at, e.g., a 0.1% sampling rate.
+//!
+//! Requests are sampled at random, one out of every few requests, or deterministically based on a
+//! hash of their request ID, so that every service that handles a request makes the same decision
+//! for it.
+//!
+//! The [`InstrumentOperation`](crate::instrumentation::InstrumentOperation) middleware skips the
+//! logging of requests that aren't sampled. A [`Sampler`] can also be set in the
+//! [`ServerSettings`](crate::layer::dynamic_config::ServerSettings) of a
+//! [`DynamicServerConfigLayer`](crate::layer::dynamic_config::DynamicServerConfigLayer), which then
+//! makes the decision instead of a [`SamplingLayer`], so that it can be changed at runtime.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aws_smithy_http_server::layer::sampling::{Sampled, Sampler, SamplingLayer};
+//! use hyper::{Body, Request, Response};
+//! use tower::Layer;
+//!
+//! async fn handle(req: Request<Body>) -> Result<Response<Body>, std::convert::Infallible> {
+//!     if Sampled::is_sampled(req.extensions()) {
+//!         // Capture the full request body...
+//!     }
+//!     Ok(Response::new(Body::empty()))
+//! }
+//!
+//! // Sample 0.1% of the requests, based on their `x-request-id` header.
+//! let layer = SamplingLayer::new(Sampler::header("x-request-id", 0.001));
+//! let app = layer.layer(tower::service_fn(handle));
+//! ```
+
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use http::header::HeaderName;
+use http::{Extensions, Request};
+use tower::{Layer, Service};
+
+/// Whether a request was selected by a [`SamplingLayer`] for expensive diagnostics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sampled(bool);
+
+impl Sampled {
+    pub(crate) fn new(sampled: bool) -> Self {
+        Self(sampled)
+    }
+
+    /// Returns `true` if the request was sampled.
+    pub fn get(&self) -> bool {
+        self.0
+    }
+
+    /// Returns `true` if the request with the given `extensions` was sampled.
+    ///
+    /// Requests that didn't go through a [`SamplingLayer`] are considered sampled, so that
+    /// diagnostics keep running on every request when sampling isn't set up.
+    pub fn is_sampled(extensions: &Extensions) -> bool {
+        extensions.get::<Sampled>().map_or(true, Sampled::get)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum SampleBy {
+    Random,
+    Interval {
+        interval: NonZeroU32,
+        requests: Arc<AtomicU64>,
+    },
+    #[cfg(feature = "request-id")]
+    RequestId,
+    Header(HeaderName),
+}
+
+/// Decides whether requests are sampled.
+///
+/// Clones share their state, so a clone of an [interval](Sampler::interval) sampler keeps counting
+/// where the original left off.
+#[derive(Clone, Debug)]
+pub struct Sampler {
+    by: SampleBy,
+    rate: f64,
+}
+
+impl Sampler {
+    /// Samples requests at random, with a probability of `rate`.
+    ///
+    /// `rate` is clamped to between `0.0` (no request is sampled) and `1.0` (every request is sampled).
+    pub fn random(rate: f64) -> Self {
+        Self::new(SampleBy::Random, rate)
+    }
+
+    /// Samples one request out of every `interval`, starting with the first one.
+    pub fn interval(interval: NonZeroU32) -> Self {
+        Self::new(
+            SampleBy::Interval {
+                interval,
+                requests: Arc::new(AtomicU64::new(0)),
+            },
+            1.0 / f64::from(interval.get()),
+        )
+    }
+
+    /// Samples requests based on a hash of their [`ServerRequestId`](crate::request::request_id::ServerRequestId).
+    ///
+    /// The request ID must be added to requests before they are sampled, with a
+    /// [`ServerRequestIdProviderLayer`](crate::request::request_id::ServerRequestIdProviderLayer) applied
+    /// _around_ the [`SamplingLayer`]. Requests without one are sampled at random.
+    #[cfg(feature = "request-id")]
+    pub fn request_id(rate: f64) -> Self {
+        Self::new(SampleBy::RequestId, rate)
+    }
+
+    /// Samples requests based on a hash of the value of the `header` they were sent with.
+    ///
+    /// Services that sample requests based on a request ID header propagated between them make the
+    /// same decision for a given request. Requests without the header are sampled at random.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `header` isn't a valid header name.
+    pub fn header(header: &'static str, rate: f64) -> Self {
+        Self::new(SampleBy::Header(HeaderName::from_static(header)), rate)
+    }
+
+    fn new(by: SampleBy, rate: f64) -> Self {
+        let rate = if rate.is_nan() { 0.0 } else { rate.clamp(0.0, 1.0) };
+        Self { by, rate }
+    }
+
+    /// Returns the sampling rate.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Decides whether `request` is sampled.
+    pub fn sample<B>(&self, request: &Request<B>) -> bool {
+        if self.rate >= 1.0 {
+            return true;
+        }
+        let value = match &self.by {
+            SampleBy::Random => None,
+            SampleBy::Interval { interval, requests } => {
+                return requests.fetch_add(1, Ordering::Relaxed) % u64::from(interval.get()) == 0;
+            }
+            #[cfg(feature = "request-id")]
+            SampleBy::RequestId => request
+                .extensions()
+                .get::<crate::request::request_id::ServerRequestId>()
+                .map(|request_id| hash(request_id.to_string().as_bytes())),
+            SampleBy::Header(header) => request.headers().get(header).map(|value| hash(value.as_bytes())),
+        }
+        .unwrap_or_else(|| fastrand::u64(..));
+        // Lossy, but precise enough for a sampling rate
+        value < (self.rate * u64::MAX as f64) as u64
+    }
+}
+
+impl PartialEq for Sampler {
+    /// Samplers are equal if they make decisions the same way, whatever requests they have seen.
+    fn eq(&self, other: &Self) -> bool {
+        let same_by = match (&self.by, &other.by) {
+            (SampleBy::Random, SampleBy::Random) => true,
+            (SampleBy::Interval { interval: a, .. }, SampleBy::Interval { interval: b, .. }) => a == b,
+            #[cfg(feature = "request-id")]
+            (SampleBy::RequestId, SampleBy::RequestId) => true,
+            (SampleBy::Header(a), SampleBy::Header(b)) => a == b,
+            _ => false,
+        };
+        same_by && self.rate == other.rate
+    }
+}
+
+// The rate is never NaN
+impl Eq for Sampler {}
+
+/// A stable hash, so that every process makes the same decision for a given request ID
+///
+/// FNV-1a, followed by the SplitMix64 finalizer to spread similar request IDs across the whole range.
+fn hash(bytes: &[u8]) -> u64 {
+    let hash = bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    });
+    let hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    let hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d049bb133111eb);
+    hash ^ (hash >> 31)
+}
+
+/// A [`tower::Layer`] used to apply [`Sampling`].
+///
+/// See the [module](crate::layer::sampling) documentation for more information.
+#[derive(Clone, Debug)]
+pub struct SamplingLayer {
+    sampler: Sampler,
+}
+
+impl SamplingLayer {
+    /// Creates a layer that samples requests with `sampler`.
+    pub fn new(sampler: Sampler) -> Self {
+        Self { sampler }
+    }
+}
+
+impl<S> Layer<S> for SamplingLayer {
+    type Service = Sampling<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Sampling {
+            inner,
+            sampler: self.sampler.clone(),
+        }
+    }
+}
+
+/// A middleware [`Service`] that inserts a [`Sampled`] decision into the request extensions.
+#[derive(Clone, Debug)]
+pub struct Sampling<S> {
+    inner: S,
+    sampler: Sampler,
+}
+
+impl<S, B> Service<Request<B>> for Sampling<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<B>) -> Self::Future {
+        let sampled = self.sampler.sample(&req);
+        req.extensions_mut().insert(Sampled(sampled));
+        self.inner.call(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+    use std::num::NonZeroU32;
+
+    use hyper::{Body, Request, Response};
+    use tower::{Layer, ServiceExt};
+
+    use super::{Sampled, Sampler, SamplingLayer};
+
+    async fn is_sampled(sampler: Sampler, req: Request<Body>) -> bool {
+        let service = SamplingLayer::new(sampler).layer(tower::service_fn(|req: Request<Body>| async move {
+            Ok::<_, Infallible>(Response::new(Sampled::is_sampled(req.extensions())))
+        }));
+        service.oneshot(req).await.unwrap().into_body()
+    }
+
+    fn with_request_id(request_id: &str) -> Request<Body> {
+        Request::builder()
+            .header("x-request-id", request_id)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn rate_bounds() {
+        assert!(is_sampled(Sampler::random(1.0), Request::new(Body::empty())).await);
+        assert!(!is_sampled(Sampler::random(0.0), Request::new(Body::empty())).await);
+        assert!(!is_sampled(Sampler::random(f64::NAN), Request::new(Body::empty())).await);
+        assert_eq!(1.0, Sampler::random(2.0).rate());
+    }
+
+    #[tokio::test]
+    async fn header_sampling_is_deterministic() {
+        let sampler = Sampler::header("x-request-id", 0.5);
+        let mut sampled = 0;
+        for i in 0..1000 {
+            let request_id = format!("request-{i}");
+            let decision = is_sampled(sampler.clone(), with_request_id(&request_id)).await;
+            assert_eq!(
+                decision,
+                is_sampled(sampler.clone(), with_request_id(&request_id)).await
+            );
+            sampled += decision as u32;
+        }
+        assert!((350..650).contains(&sampled), "{sampled} requests were sampled");
+    }
+
+    #[tokio::test]
+    async fn interval_sampling() {
+        let sampler = Sampler::interval(NonZeroU32::new(3).unwrap());
+        let mut sampled = Vec::new();
+        for _ in 0..6 {
+            sampled.push(is_sampled(sampler.clone(), Request::new(Body::empty())).await);
+        }
+        assert_eq!(vec![true, false, false, true, false, false], sampled);
+        assert!(is_sampled(Sampler::interval(NonZeroU32::MIN), Request::new(Body::empty())).await);
+    }
+
+    #[test]
+    fn requests_are_sampled_without_a_decision() {
+        assert!(Sampled::is_sampled(Request::new(()).extensions()));
+    }
+}
@@ -0,0 +1,264 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Middleware exposing information about a client's TLS connection, such as the certificates presented for mutual
+//! TLS (mTLS), to handlers.
+//!
+//! [`TlsConnectInfoLayer`] wraps the [`MakeService`](tower::make::MakeService) passed to [`hyper::Server::serve`] and
+//! inserts a [`TlsConnectInfo`] into the extensions of every request received on a connection, so handlers can
+//! extract it with [`Extension<TlsConnectInfo>`](crate::Extension).
+//!
+//! Connections from any TLS implementation can be used by implementing [`TlsConnection`]. With the `tls-rustls`
+//! feature, it is implemented for `tokio_rustls::server::TlsStream`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # #[cfg(feature = "tls-rustls")]
+//! # async fn example(
+//! #     listener: impl hyper::server::accept::Accept<
+//! #         Conn = tokio_rustls::server::TlsStream<hyper::server::conn::AddrStream>,
+//! #         Error = std::io::Error,
+//! #     >,
+//! # ) {
+//! use aws_smithy_http_server::layer::tls_connect_info::{TlsConnectInfo, TlsConnectInfoLayer};
+//! use aws_smithy_http_server::Extension;
+//! use tower::Layer;
+//!
+//! async fn handler(Extension(tls): Extension<TlsConnectInfo>) {
+//!     let certificates = tls.peer_certificates().unwrap_or_default();
+//!     let server_name = tls.server_name();
+//!     /* Authorize the client */
+//! }
+//! # let app = tower::service_fn(|_request: http::Request<hyper::Body>| async {
+//! #     Ok::<_, std::convert::Infallible>(http::Response::new(hyper::Body::empty()))
+//! # });
+//!
+//! let make_app = TlsConnectInfoLayer::new().layer(tower::make::Shared::new(app));
+//! hyper::Server::builder(listener).serve(make_app).await.unwrap();
+//! # }
+//! ```
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use pin_project_lite::pin_project;
+use tower::{Layer, Service};
+use tower_http::add_extension::AddExtension;
+
+/// Information about a client's TLS connection.
+///
+/// Inserted into request extensions by [`TlsConnectInfoLayer`]. Cloning is cheap.
+#[derive(Clone, Debug, Default)]
+pub struct TlsConnectInfo {
+    peer_certificates: Option<Arc<[Bytes]>>,
+    server_name: Option<Arc<str>>,
+    alpn_protocol: Option<Bytes>,
+}
+
+impl TlsConnectInfo {
+    /// Creates a [`TlsConnectInfo`] for a connection without peer certificates, SNI, or ALPN.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the DER-encoded certificate chain presented by the client, starting with its end-entity certificate.
+    pub fn with_peer_certificates(mut self, peer_certificates: impl IntoIterator<Item = impl Into<Bytes>>) -> Self {
+        self.peer_certificates = Some(peer_certificates.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the server name the client requested with Server Name Indication (SNI).
+    pub fn with_server_name(mut self, server_name: impl Into<Arc<str>>) -> Self {
+        self.server_name = Some(server_name.into());
+        self
+    }
+
+    /// Sets the application protocol negotiated with Application-Layer Protocol Negotiation (ALPN).
+    pub fn with_alpn_protocol(mut self, alpn_protocol: impl Into<Bytes>) -> Self {
+        self.alpn_protocol = Some(alpn_protocol.into());
+        self
+    }
+
+    /// Returns the DER-encoded certificate chain presented by the client, starting with its end-entity certificate.
+    ///
+    /// This is `None` if the client didn't authenticate itself with a certificate.
+    pub fn peer_certificates(&self) -> Option<&[Bytes]> {
+        self.peer_certificates.as_deref()
+    }
+
+    /// Returns the server name the client requested with Server Name Indication (SNI), if any.
+    pub fn server_name(&self) -> Option<&str> {
+        self.server_name.as_deref()
+    }
+
+    /// Returns the application protocol negotiated with Application-Layer Protocol Negotiation (ALPN), such as
+    /// `b"h2"`, if any.
+    pub fn alpn_protocol(&self) -> Option<&[u8]> {
+        self.alpn_protocol.as_deref()
+    }
+}
+
+/// A TLS connection that [`TlsConnectInfoLayer`] can describe with a [`TlsConnectInfo`].
+///
+/// Implement this trait to use [`TlsConnectInfoLayer`] with connections from a TLS implementation that isn't
+/// supported out of the box.
+pub trait TlsConnection {
+    /// Returns information about the TLS session, once the handshake has completed.
+    fn tls_connect_info(&self) -> TlsConnectInfo;
+}
+
+#[cfg(feature = "tls-rustls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls-rustls")))]
+impl<IO> TlsConnection for tokio_rustls::server::TlsStream<IO> {
+    fn tls_connect_info(&self) -> TlsConnectInfo {
+        let (_, session) = self.get_ref();
+        let mut info = TlsConnectInfo::new();
+        if let Some(certificates) = session.peer_certificates() {
+            info = info.with_peer_certificates(certificates.iter().map(|certificate| certificate.0.clone()));
+        }
+        if let Some(server_name) = session.server_name() {
+            info = info.with_server_name(server_name);
+        }
+        if let Some(alpn_protocol) = session.alpn_protocol() {
+            info = info.with_alpn_protocol(alpn_protocol.to_vec());
+        }
+        info
+    }
+}
+
+/// A [`Layer`] applying [`TlsConnectInfoMakeService`] to a [`MakeService`](tower::make::MakeService).
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct TlsConnectInfoLayer;
+
+impl TlsConnectInfoLayer {
+    /// Creates a new [`TlsConnectInfoLayer`].
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<M> Layer<M> for TlsConnectInfoLayer {
+    type Service = TlsConnectInfoMakeService<M>;
+
+    fn layer(&self, inner: M) -> Self::Service {
+        TlsConnectInfoMakeService { inner }
+    }
+}
+
+/// A [`MakeService`](tower::make::MakeService) inserting the [`TlsConnectInfo`] of each connection into the
+/// extensions of the requests received on it.
+///
+/// See the [module](crate::layer::tls_connect_info) documentation for more information.
+#[derive(Clone, Debug)]
+pub struct TlsConnectInfoMakeService<M> {
+    inner: M,
+}
+
+impl<'a, M, T> Service<&'a T> for TlsConnectInfoMakeService<M>
+where
+    M: Service<&'a T>,
+    T: TlsConnection,
+{
+    type Response = AddExtension<M::Response, TlsConnectInfo>;
+    type Error = M::Error;
+    type Future = MakeTlsConnectInfoFuture<M::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, target: &'a T) -> Self::Future {
+        MakeTlsConnectInfoFuture {
+            tls_connect_info: Some(target.tls_connect_info()),
+            inner: self.inner.call(target),
+        }
+    }
+}
+
+pin_project! {
+    /// Response future for [`TlsConnectInfoMakeService`].
+    pub struct MakeTlsConnectInfoFuture<F> {
+        #[pin]
+        inner: F,
+        tls_connect_info: Option<TlsConnectInfo>,
+    }
+}
+
+impl<F, S, E> Future for MakeTlsConnectInfoFuture<F>
+where
+    F: Future<Output = Result<S, E>>,
+{
+    type Output = Result<AddExtension<S, TlsConnectInfo>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let svc = match this.inner.poll(cx) {
+            Poll::Ready(result) => result?,
+            Poll::Pending => return Poll::Pending,
+        };
+        let tls_connect_info = this
+            .tls_connect_info
+            .take()
+            .expect("`MakeTlsConnectInfoFuture` polled after completion");
+        Poll::Ready(Ok(AddExtension::new(svc, tls_connect_info)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use tower::ServiceExt;
+
+    struct TestConnection(TlsConnectInfo);
+
+    impl TlsConnection for TestConnection {
+        fn tls_connect_info(&self) -> TlsConnectInfo {
+            self.0.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn tls_connect_info_is_inserted_into_request_extensions() {
+        let app = tower::service_fn(|request: http::Request<()>| async move {
+            let info = request.extensions().get::<TlsConnectInfo>().cloned();
+            Ok::<_, Infallible>(http::Response::new(info))
+        });
+        let make_app = TlsConnectInfoLayer::new().layer(tower::make::Shared::new(app));
+
+        let connection = TestConnection(
+            TlsConnectInfo::new()
+                .with_peer_certificates([Bytes::from_static(b"certificate")])
+                .with_server_name("pokemon.example.com")
+                .with_alpn_protocol(Bytes::from_static(b"h2")),
+        );
+        let svc = make_app.oneshot(&connection).await.unwrap();
+        let response = svc.oneshot(http::Request::new(())).await.unwrap();
+        let info = response.into_body().expect("`TlsConnectInfo` should be inserted");
+
+        assert_eq!(
+            Some(&[Bytes::from_static(b"certificate")][..]),
+            info.peer_certificates()
+        );
+        assert_eq!(Some("pokemon.example.com"), info.server_name());
+        assert_eq!(Some(&b"h2"[..]), info.alpn_protocol());
+    }
+
+    #[test]
+    fn tls_connect_info_without_client_authentication() {
+        let info = TlsConnectInfo::new().with_server_name("pokemon.example.com");
+
+        assert_eq!(None, info.peer_certificates());
+        assert_eq!(Some("pokemon.example.com"), info.server_name());
+        assert_eq!(None, info.alpn_protocol());
+    }
+}
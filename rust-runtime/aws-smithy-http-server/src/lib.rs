@@ -32,14 +32,20 @@ pub mod response;
 pub mod routing;
 #[doc(hidden)]
 pub mod runtime_error;
+pub mod serve;
 pub mod service;
 pub mod shape_id;
+#[cfg(feature = "test-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+pub mod test_util;
 
 #[doc(inline)]
 pub(crate) use self::error::Error;
 #[doc(inline)]
 pub use self::request::extension::Extension;
 #[doc(inline)]
+pub use self::serve::serve;
+#[doc(inline)]
 pub use tower_http::add_extension::{AddExtension, AddExtensionLayer};
 
 #[cfg(test)]
@@ -82,6 +82,9 @@
 //! }
 //! ```
 //!
+//! Handlers returning errors that aren't modelled, such as `Box<dyn Error + Send + Sync>`, can be wrapped with
+//! [`MapUnhandledErrors`] to replace those errors with a fallback modelled error.
+//!
 //! ## [`OperationService`]
 //!
 //! Similarly, the [`OperationService`] trait is implemented by all `Service<(Op::Input, ...)>` with
@@ -163,9 +166,11 @@
 mod handler;
 mod operation_service;
 mod shape;
+mod unhandled_error;
 mod upgrade;
 
 pub use handler::*;
 pub use operation_service::*;
 pub use shape::*;
+pub use unhandled_error::*;
 pub use upgrade::*;
@@ -0,0 +1,373 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::{
+    error::Error as StdError,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+use tower::{Layer, Service};
+
+use super::{Handler, OperationShape};
+
+/// An error that wasn't mapped to a modeled error.
+type UnhandledError = Box<dyn StdError + Send + Sync>;
+
+/// Converts an error returned by a handler or service into the modeled error `E`.
+///
+/// [`MapUnhandledErrors`] uses this to tell modeled errors apart from the errors that weren't mapped to one,
+/// which it replaces with a fallback modeled error.
+///
+/// It's implemented for `Box<dyn Error + Send + Sync>`, which is recovered with [`DowncastServiceError`]. Handlers
+/// can therefore return `Result<Output, Box<dyn Error + Send + Sync>>` and use `?` with both modeled errors and
+/// other errors, such as [`anyhow::Error`](https://docs.rs/anyhow). Implement this trait to do the same with
+/// your own error types.
+pub trait IntoServiceError<E> {
+    /// Returns the modeled error, or the unhandled error if `self` isn't one.
+    fn into_service_error(self) -> Result<E, Box<dyn StdError + Send + Sync>>;
+}
+
+impl<E> IntoServiceError<E> for Box<dyn StdError + Send + Sync>
+where
+    E: DowncastServiceError,
+{
+    fn into_service_error(self) -> Result<E, Box<dyn StdError + Send + Sync>> {
+        E::downcast_service_error(self)
+    }
+}
+
+/// A modeled error that can be recovered from a `Box<dyn Error + Send + Sync>`.
+///
+/// The generated error of every operation implements it. It is recovered from a boxed operation error, as well as
+/// from a boxed error structure of one of its variants, so that `?` can be used on both in a handler returning
+/// `Box<dyn Error + Send + Sync>`.
+pub trait DowncastServiceError: Sized {
+    /// Returns the modeled error, or `error` if it isn't one.
+    fn downcast_service_error(error: Box<dyn StdError + Send + Sync>) -> Result<Self, Box<dyn StdError + Send + Sync>>;
+}
+
+/// Downcasts `error` to `T`, and converts it into `E`.
+///
+/// This is used by the generated implementations of [`DowncastServiceError`].
+#[doc(hidden)]
+pub fn downcast_into<T, E>(error: Box<dyn StdError + Send + Sync>) -> Result<E, Box<dyn StdError + Send + Sync>>
+where
+    T: StdError + Into<E> + 'static,
+{
+    error.downcast::<T>().map(|error| (*error).into())
+}
+
+fn map_error<Err, E, F>(error: Err, fallback: &F) -> E
+where
+    Err: IntoServiceError<E>,
+    F: Fn(UnhandledError) -> E,
+{
+    error.into_service_error().unwrap_or_else(|error| {
+        tracing::error!(error = %error, "unhandled error was mapped to the fallback modeled error");
+        fallback(error)
+    })
+}
+
+/// Maps the errors returned by a handler or service that aren't modeled errors into a fallback modeled error.
+///
+/// Errors are converted with [`IntoServiceError`], and the ones that aren't modeled errors are logged and
+/// passed to the `fallback` function, which returns the modeled error to respond with instead. This keeps the
+/// errors of an operation typed, without having to convert every error of the business logic by hand.
+///
+/// Use [`MapUnhandledErrors::new`] to wrap a handler, or [`MapUnhandledErrorsLayer`] to wrap a service.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use aws_smithy_http_server::operation::{MapUnhandledErrors, OperationShape, OperationShapeExt};
+/// # use aws_smithy_http_server::shape_id::ShapeId;
+/// # pub struct GetPokemonInput;
+/// # pub struct GetPokemonOutput;
+/// # #[derive(Debug)]
+/// # pub enum GetPokemonError { InternalServerError(String) }
+/// # impl std::fmt::Display for GetPokemonError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { todo!() }
+/// # }
+/// # impl std::error::Error for GetPokemonError {}
+/// # impl aws_smithy_http_server::operation::DowncastServiceError for GetPokemonError {
+/// #     fn downcast_service_error(
+/// #         error: Box<dyn std::error::Error + Send + Sync>,
+/// #     ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> { todo!() }
+/// # }
+/// # pub struct GetPokemon;
+/// # impl OperationShape for GetPokemon {
+/// #    const ID: ShapeId = ShapeId::new("namespace#GetPokemon", "namespace", "GetPokemon");
+/// #    type Input = GetPokemonInput;
+/// #    type Output = GetPokemonOutput;
+/// #    type Error = GetPokemonError;
+/// # }
+/// # fn load_pokemon() -> Result<GetPokemonOutput, std::io::Error> { todo!() }
+/// async fn get_pokemon(
+///     input: GetPokemonInput,
+/// ) -> Result<GetPokemonOutput, Box<dyn std::error::Error + Send + Sync>> {
+///     // Both modeled errors and other errors can be returned with `?`
+///     let output = load_pokemon()?;
+///     Ok(output)
+/// }
+///
+/// let handler = MapUnhandledErrors::new(get_pokemon, |_error| {
+///     GetPokemonError::InternalServerError("something went wrong".to_owned())
+/// });
+/// let operation = GetPokemon::from_handler(handler);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MapUnhandledErrors<Inner, F> {
+    inner: Inner,
+    fallback: F,
+}
+
+impl<Inner, F> MapUnhandledErrors<Inner, F> {
+    /// Wraps a handler or service, replacing its errors that aren't modeled errors with the error returned by
+    /// `fallback`.
+    pub fn new(inner: Inner, fallback: F) -> Self {
+        Self { inner, fallback }
+    }
+}
+
+pin_project! {
+    /// Response future for [`MapUnhandledErrors`].
+    pub struct MapUnhandledErrorsFuture<Fut, F> {
+        #[pin]
+        inner: Fut,
+        fallback: F,
+    }
+}
+
+impl<Fut, F, T, Err, E> Future for MapUnhandledErrorsFuture<Fut, F>
+where
+    Fut: Future<Output = Result<T, Err>>,
+    Err: IntoServiceError<E>,
+    F: Fn(UnhandledError) -> E,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        this.inner
+            .poll(cx)
+            .map(|result| result.map_err(|error| map_error(error, this.fallback)))
+    }
+}
+
+impl<S, F, R, E> Service<R> for MapUnhandledErrors<S, F>
+where
+    S: Service<R>,
+    S::Error: IntoServiceError<E>,
+    F: Fn(UnhandledError) -> E + Clone,
+{
+    type Response = S::Response;
+    type Error = E;
+    type Future = MapUnhandledErrorsFuture<S::Future, F>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner
+            .poll_ready(cx)
+            .map_err(|error| map_error(error, &self.fallback))
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        MapUnhandledErrorsFuture {
+            inner: self.inner.call(request),
+            fallback: self.fallback.clone(),
+        }
+    }
+}
+
+// fn(Input) -> Result<Output, Error>
+impl<Op, H, F, Fut, Err> Handler<Op, ()> for MapUnhandledErrors<H, F>
+where
+    Op: OperationShape,
+    H: Fn(Op::Input) -> Fut,
+    Fut: Future<Output = Result<Op::Output, Err>>,
+    Err: IntoServiceError<Op::Error>,
+    F: Fn(UnhandledError) -> Op::Error + Clone,
+{
+    type Future = MapUnhandledErrorsFuture<Fut, F>;
+
+    fn call(&mut self, input: Op::Input, _exts: ()) -> Self::Future {
+        MapUnhandledErrorsFuture {
+            inner: (self.inner)(input),
+            fallback: self.fallback.clone(),
+        }
+    }
+}
+
+// fn(Input, Ext_i) -> Result<Output, Error>
+macro_rules! impl_handler {
+    ($($var:ident),+) => (
+        impl<Op, H, F, Fut, Err, $($var,)*> Handler<Op, ($($var,)*)> for MapUnhandledErrors<H, F>
+        where
+            Op: OperationShape,
+            H: Fn(Op::Input, $($var,)*) -> Fut,
+            Fut: Future<Output = Result<Op::Output, Err>>,
+            Err: IntoServiceError<Op::Error>,
+            F: Fn(UnhandledError) -> Op::Error + Clone,
+        {
+            type Future = MapUnhandledErrorsFuture<Fut, F>;
+
+            fn call(&mut self, input: Op::Input, exts: ($($var,)*)) -> Self::Future {
+                #[allow(non_snake_case)]
+                let ($($var,)*) = exts;
+                MapUnhandledErrorsFuture {
+                    inner: (self.inner)(input, $($var,)*),
+                    fallback: self.fallback.clone(),
+                }
+            }
+        }
+    )
+}
+
+impl_handler!(Exts0);
+impl_handler!(Exts0, Exts1);
+impl_handler!(Exts0, Exts1, Exts2);
+impl_handler!(Exts0, Exts1, Exts2, Exts3);
+impl_handler!(Exts0, Exts1, Exts2, Exts3, Exts4);
+impl_handler!(Exts0, Exts1, Exts2, Exts3, Exts4, Exts5);
+impl_handler!(Exts0, Exts1, Exts2, Exts3, Exts4, Exts5, Exts6);
+impl_handler!(Exts0, Exts1, Exts2, Exts3, Exts4, Exts5, Exts6, Exts7);
+impl_handler!(Exts0, Exts1, Exts2, Exts3, Exts4, Exts5, Exts6, Exts7, Exts8);
+
+/// A [`Layer`] applying [`MapUnhandledErrors`] to a service.
+#[derive(Clone, Debug)]
+pub struct MapUnhandledErrorsLayer<F> {
+    fallback: F,
+}
+
+impl<F> MapUnhandledErrorsLayer<F> {
+    /// Creates a new [`MapUnhandledErrorsLayer`] replacing errors that aren't modeled errors with the error
+    /// returned by `fallback`.
+    pub fn new(fallback: F) -> Self {
+        Self { fallback }
+    }
+}
+
+impl<S, F> Layer<S> for MapUnhandledErrorsLayer<F>
+where
+    F: Clone,
+{
+    type Service = MapUnhandledErrors<S, F>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MapUnhandledErrors::new(inner, self.fallback.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::shape_id::ShapeId;
+
+    #[derive(Debug, PartialEq)]
+    struct ResourceNotFoundException;
+
+    impl fmt::Display for ResourceNotFoundException {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("ResourceNotFoundException")
+        }
+    }
+
+    impl StdError for ResourceNotFoundException {}
+
+    #[derive(Debug, PartialEq)]
+    enum GetPokemonError {
+        ResourceNotFound(ResourceNotFoundException),
+        InternalServerError(String),
+    }
+
+    impl From<ResourceNotFoundException> for GetPokemonError {
+        fn from(error: ResourceNotFoundException) -> Self {
+            Self::ResourceNotFound(error)
+        }
+    }
+
+    // As generated for operation errors
+    impl DowncastServiceError for GetPokemonError {
+        fn downcast_service_error(error: UnhandledError) -> Result<Self, UnhandledError> {
+            downcast_into::<Self, Self>(error).or_else(downcast_into::<ResourceNotFoundException, Self>)
+        }
+    }
+
+    impl fmt::Display for GetPokemonError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{self:?}")
+        }
+    }
+
+    impl StdError for GetPokemonError {}
+
+    struct GetPokemon;
+
+    impl OperationShape for GetPokemon {
+        const ID: ShapeId = ShapeId::new("namespace#GetPokemon", "namespace", "GetPokemon");
+
+        type Input = &'static str;
+        type Output = &'static str;
+        type Error = GetPokemonError;
+    }
+
+    async fn get_pokemon(name: &'static str) -> Result<&'static str, UnhandledError> {
+        match name {
+            "pikachu" => Ok("electric"),
+            "missingno" => Err(GetPokemonError::ResourceNotFound(ResourceNotFoundException))?,
+            "ditto" => Err(ResourceNotFoundException)?,
+            _ => Err(std::io::Error::new(std::io::ErrorKind::Other, "database unavailable"))?,
+        }
+    }
+
+    fn fallback(error: UnhandledError) -> GetPokemonError {
+        GetPokemonError::InternalServerError(error.to_string())
+    }
+
+    #[tokio::test]
+    async fn handler_errors_are_mapped() {
+        let mut handler = MapUnhandledErrors::new(get_pokemon, fallback);
+        let mut call = |name| Handler::<GetPokemon, ()>::call(&mut handler, name, ());
+
+        assert_eq!(Ok("electric"), call("pikachu").await);
+        assert_eq!(
+            Err(GetPokemonError::ResourceNotFound(ResourceNotFoundException)),
+            call("missingno").await
+        );
+        assert_eq!(
+            Err(GetPokemonError::ResourceNotFound(ResourceNotFoundException)),
+            call("ditto").await
+        );
+        assert_eq!(
+            Err(GetPokemonError::InternalServerError("database unavailable".to_owned())),
+            call("agumon").await
+        );
+    }
+
+    #[tokio::test]
+    async fn service_errors_are_mapped() {
+        let service = MapUnhandledErrorsLayer::new(fallback).layer(tower::service_fn(get_pokemon));
+
+        assert_eq!(Ok("electric"), service.clone().oneshot("pikachu").await);
+        assert_eq!(
+            Err(GetPokemonError::ResourceNotFound(ResourceNotFoundException)),
+            service.clone().oneshot("missingno").await
+        );
+        assert_eq!(
+            Err(GetPokemonError::ResourceNotFound(ResourceNotFoundException)),
+            service.clone().oneshot("ditto").await
+        );
+        assert_eq!(
+            Err(GetPokemonError::InternalServerError("database unavailable".to_owned())),
+            service.oneshot("agumon").await
+        );
+    }
+}
@@ -48,9 +48,22 @@ pub(crate) const ROUTE_CUTOFF: usize = 15;
 #[derive(Debug, Clone)]
 pub struct AwsJsonRouter<S> {
     routes: TinyMap<&'static str, S, ROUTE_CUTOFF>,
+    fallback: Option<S>,
 }
 
 impl<S> AwsJsonRouter<S> {
+    /// Registers a fallback route, used for requests that do not match any operation.
+    ///
+    /// The fallback only engages once the request could not be routed to an operation, for
+    /// example because it is not a `POST` to `/`. Without a fallback, such requests are rejected
+    /// with the protocol's routing [`Error`] response.
+    pub fn fallback(self, fallback: S) -> Self {
+        Self {
+            fallback: Some(fallback),
+            ..self
+        }
+    }
+
     /// Applies a [`Layer`] uniformly to all routes.
     pub fn layer<L>(self, layer: L) -> AwsJsonRouter<L::Service>
     where
@@ -62,6 +75,7 @@ impl<S> AwsJsonRouter<S> {
                 .into_iter()
                 .map(|(key, route)| (key, layer.layer(route)))
                 .collect(),
+            fallback: self.fallback.map(|fallback| layer.layer(fallback)),
         }
     }
 
@@ -74,18 +88,11 @@ impl<S> AwsJsonRouter<S> {
     {
         AwsJsonRouter {
             routes: self.routes.into_iter().map(|(key, s)| (key, Route::new(s))).collect(),
+            fallback: self.fallback.map(Route::new),
         }
     }
-}
-
-impl<B, S> Router<B> for AwsJsonRouter<S>
-where
-    S: Clone,
-{
-    type Service = S;
-    type Error = Error;
 
-    fn match_route(&self, request: &http::Request<B>) -> Result<S, Self::Error> {
+    fn match_operation<B>(&self, request: &http::Request<B>) -> Result<&S, Error> {
         // The URI must be root,
         if request.uri() != "/" {
             return Err(Error::NotRootUrl);
@@ -101,8 +108,22 @@ where
         let target = target.to_str().map_err(Error::InvalidHeader)?;
 
         // Lookup in the `TinyMap` for a route for the target.
-        let route = self.routes.get(target).ok_or(Error::NotFound)?;
-        Ok(route.clone())
+        self.routes.get(target).ok_or(Error::NotFound)
+    }
+}
+
+impl<B, S> Router<B> for AwsJsonRouter<S>
+where
+    S: Clone,
+{
+    type Service = S;
+    type Error = Error;
+
+    fn match_route(&self, request: &http::Request<B>) -> Result<S, Self::Error> {
+        self.match_operation(request)
+            .cloned()
+            // No operation matched, use the fallback if one was registered.
+            .or_else(|error| self.fallback.clone().ok_or(error))
     }
 }
 
@@ -111,6 +132,7 @@ impl<S> FromIterator<(&'static str, S)> for AwsJsonRouter<S> {
     fn from_iter<T: IntoIterator<Item = (&'static str, S)>>(iter: T) -> Self {
         Self {
             routes: iter.into_iter().collect(),
+            fallback: None,
         }
     }
 }
@@ -148,4 +170,21 @@ mod tests {
         let res = router.match_route(&req(&Method::POST, "/something", Some(headers)));
         assert_eq!(res.unwrap_err().to_string(), Error::NotRootUrl.to_string());
     }
+
+    #[tokio::test]
+    async fn fallback_routing() {
+        let router: AwsJsonRouter<_> = [("Service.Operation", "Operation")].into_iter().collect();
+        let router = router.fallback("Fallback");
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-amz-target", HeaderValue::from_static("Service.Operation"));
+
+        // Operations take precedence over the fallback.
+        let res = router.match_route(&req(&Method::POST, "/", Some(headers)));
+        assert_eq!(res.unwrap(), "Operation");
+
+        // Requests that cannot be routed to an operation reach the fallback.
+        let res = router.match_route(&req(&Method::GET, "/static/index.html", None));
+        assert_eq!(res.unwrap(), "Fallback");
+    }
 }
@@ -0,0 +1,87 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Length-prefixed message framing of gRPC request and response bodies.
+//!
+//! Each message is preceded by a 5 byte prefix: a compression flag, followed by the length of the message as a
+//! big-endian `u32`. Only unary operations are supported, so a body holds exactly one message.
+
+use thiserror::Error;
+
+/// The length of the prefix preceding each message.
+pub const PREFIX_LEN: usize = 5;
+
+/// An error that occurs when a request body is not a single length-prefixed message.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FramingError {
+    /// The body is shorter than the message prefix.
+    #[error("expected a {PREFIX_LEN} byte message prefix but the body is only {0} bytes long")]
+    Truncated(usize),
+    /// The message is compressed. Compression is not supported.
+    #[error("the message is compressed, but compressed messages are not supported")]
+    Compressed,
+    /// The length in the message prefix doesn't match the rest of the body.
+    #[error("the message prefix declares a {declared} byte message but {actual} bytes follow it")]
+    LengthMismatch {
+        /// The length declared in the message prefix.
+        declared: usize,
+        /// The number of bytes that follow the message prefix.
+        actual: usize,
+    },
+}
+
+/// Prefixes an uncompressed `message` for use as a response body.
+pub fn encode_message(message: &[u8]) -> Vec<u8> {
+    let len = u32::try_from(message.len()).expect("gRPC messages can't be larger than 4 GiB");
+    let mut body = Vec::with_capacity(PREFIX_LEN + message.len());
+    body.push(0);
+    body.extend_from_slice(&len.to_be_bytes());
+    body.extend_from_slice(message);
+    body
+}
+
+/// Returns the message in a request `body` holding a single uncompressed, length-prefixed message.
+pub fn decode_message(body: &[u8]) -> Result<&[u8], FramingError> {
+    if body.len() < PREFIX_LEN {
+        return Err(FramingError::Truncated(body.len()));
+    }
+    let (prefix, message) = body.split_at(PREFIX_LEN);
+    if prefix[0] != 0 {
+        return Err(FramingError::Compressed);
+    }
+    let declared = u32::from_be_bytes([prefix[1], prefix[2], prefix[3], prefix[4]]) as usize;
+    if declared != message.len() {
+        return Err(FramingError::LengthMismatch {
+            declared,
+            actual: message.len(),
+        });
+    }
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_message, encode_message, FramingError};
+
+    #[test]
+    fn round_trips_messages() {
+        let body = encode_message(b"\x08\x96\x01");
+        assert_eq!(&[0, 0, 0, 0, 3, 0x08, 0x96, 0x01][..], &body[..]);
+        assert_eq!(b"\x08\x96\x01", decode_message(&body).unwrap());
+
+        assert_eq!(&[0, 0, 0, 0, 0][..], &encode_message(b"")[..]);
+        assert_eq!(b"", decode_message(&[0, 0, 0, 0, 0]).unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_frames() {
+        assert_eq!(Err(FramingError::Truncated(3)), decode_message(&[0, 0, 0]));
+        assert_eq!(Err(FramingError::Compressed), decode_message(&[1, 0, 0, 0, 1, 0]));
+        assert_eq!(
+            Err(FramingError::LengthMismatch { declared: 2, actual: 3 }),
+            decode_message(&[0, 0, 0, 0, 2, 1, 2, 3])
+        );
+    }
+}
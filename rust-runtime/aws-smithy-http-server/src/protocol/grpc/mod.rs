@@ -0,0 +1,155 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Support for serving Smithy services over [gRPC], with protobuf-encoded messages.
+//!
+//! Operations are served at `/{namespace}.{ServiceName}/{OperationName}`. Requests and responses carry a single
+//! length-prefixed message (see [`framing`]), so only unary operations are supported. Operation errors are sent as
+//! "Trailers-Only" responses, with a `grpc-status` derived from the error's HTTP status code (see
+//! [`GrpcStatus::from_http_status`]) and the name of the error as the `grpc-message`.
+//!
+//! [gRPC]: https://github.com/grpc/grpc/blob/master/doc/PROTOCOL-HTTP2.md
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http::{HeaderMap, HeaderValue};
+
+use crate::body::{empty, BoxBody};
+
+pub mod framing;
+pub mod rejection;
+pub mod router;
+pub mod runtime_error;
+mod status;
+pub mod wire;
+
+pub use status::GrpcStatus;
+
+/// [gRPC](https://github.com/grpc/grpc/blob/master/doc/PROTOCOL-HTTP2.md) protocol.
+pub struct Grpc;
+
+pub(crate) const CONTENT_TYPE_GRPC: &str = "application/grpc";
+const GRPC_STATUS: &str = "grpc-status";
+const GRPC_MESSAGE: &str = "grpc-message";
+
+/// Percent-encodes a `grpc-message`, as required for any byte outside of printable ASCII and for `%`.
+fn encode_grpc_message(message: &str) -> HeaderValue {
+    let mut encoded = String::with_capacity(message.len());
+    for byte in message.bytes() {
+        if (0x20..=0x7e).contains(&byte) && byte != b'%' {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    HeaderValue::try_from(encoded).expect("percent-encoded messages are valid header values")
+}
+
+fn status_headers(status: GrpcStatus, message: Option<&str>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(GRPC_STATUS, HeaderValue::from(u16::from(status.code())));
+    if let Some(message) = message {
+        headers.insert(GRPC_MESSAGE, encode_grpc_message(message));
+    }
+    headers
+}
+
+/// Builds a "Trailers-Only" response, which carries the status of a failed call in its headers and has no body.
+pub(crate) fn trailers_only_response(status: GrpcStatus, message: Option<&str>) -> http::Response<BoxBody> {
+    let mut response = http::Response::new(empty());
+    let headers = response.headers_mut();
+    headers.insert(http::header::CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE_GRPC));
+    headers.extend(status_headers(status, message));
+    response
+}
+
+/// A response body that is followed by `grpc-status` trailers.
+struct GrpcBody {
+    inner: BoxBody,
+    trailers: Option<HeaderMap>,
+}
+
+impl GrpcBody {
+    fn new(inner: BoxBody, status: GrpcStatus) -> Self {
+        Self {
+            inner,
+            trailers: Some(status_headers(status, None)),
+        }
+    }
+}
+
+impl http_body::Body for GrpcBody {
+    type Data = Bytes;
+    type Error = crate::Error;
+
+    fn poll_data(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        Pin::new(&mut self.inner).poll_data(cx)
+    }
+
+    fn poll_trailers(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        let inner_trailers = match Pin::new(&mut self.inner).poll_trailers(cx) {
+            Poll::Ready(Ok(trailers)) => trailers,
+            Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+            Poll::Pending => return Poll::Pending,
+        };
+        let trailers = match (self.trailers.take(), inner_trailers) {
+            (Some(mut trailers), Some(inner_trailers)) => {
+                // The status can't be overridden by the body.
+                for (name, value) in inner_trailers.iter() {
+                    if !trailers.contains_key(name) {
+                        trailers.append(name, value.clone());
+                    }
+                }
+                Some(trailers)
+            }
+            (trailers, inner_trailers) => trailers.or(inner_trailers),
+        };
+        Poll::Ready(Ok(trailers))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.trailers.is_none() && self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http_body::Body as _;
+
+    use super::{encode_grpc_message, trailers_only_response, GrpcBody, GrpcStatus};
+    use crate::body::to_boxed;
+
+    #[test]
+    fn percent_encodes_messages() {
+        assert_eq!("ResourceNotFound", encode_grpc_message("ResourceNotFound"));
+        assert_eq!("100%25 caf%C3%A9%0A", encode_grpc_message("100% café\n"));
+    }
+
+    #[test]
+    fn trailers_only_responses_carry_the_status() {
+        let response = trailers_only_response(GrpcStatus::NotFound, Some("ResourceNotFound"));
+        assert_eq!(200, response.status());
+        assert_eq!("application/grpc", response.headers()["content-type"]);
+        assert_eq!("5", response.headers()["grpc-status"]);
+        assert_eq!("ResourceNotFound", response.headers()["grpc-message"]);
+    }
+
+    #[tokio::test]
+    async fn body_is_followed_by_trailers() {
+        let mut body = GrpcBody::new(to_boxed("message"), GrpcStatus::Ok);
+        assert_eq!(&b"message"[..], &body.data().await.unwrap().unwrap()[..]);
+        assert!(body.data().await.is_none());
+        assert!(!body.is_end_stream());
+        let trailers = body.trailers().await.unwrap().unwrap();
+        assert_eq!("0", trailers["grpc-status"]);
+        assert!(body.is_end_stream());
+    }
+}
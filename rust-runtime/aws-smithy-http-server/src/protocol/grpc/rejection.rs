@@ -0,0 +1,52 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::num::TryFromIntError;
+
+use crate::rejection::MissingContentTypeReason;
+use aws_smithy_runtime_api::http::HttpError;
+use thiserror::Error;
+
+use super::framing::FramingError;
+use super::wire::DecodeError;
+
+#[derive(Debug, Error)]
+pub enum ResponseRejection {
+    #[error("invalid bound HTTP status code; status codes must be inside the 100-999 range: {0}")]
+    InvalidHttpStatusCode(TryFromIntError),
+    #[error("error serializing protobuf-encoded body: {0}")]
+    Serialization(#[from] aws_smithy_types::error::operation::SerializationError),
+    #[error("error building HTTP response: {0}")]
+    HttpBuild(#[from] http::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum RequestRejection {
+    #[error("error converting non-streaming body to bytes: {0}")]
+    BufferHttpBodyBytes(crate::Error),
+    #[error("request contains invalid value for `Accept` header")]
+    NotAcceptable,
+    #[error("expected `Content-Type` header not found: {0}")]
+    MissingContentType(#[from] MissingContentTypeReason),
+    #[error("request body is not a single length-prefixed message: {0}")]
+    Framing(#[from] FramingError),
+    #[error("error deserializing request message as protobuf: {0}")]
+    ProtobufDeserialize(#[from] DecodeError),
+    #[error("request does not adhere to modeled constraints")]
+    ConstraintViolation(Vec<u8>),
+
+    /// Typically happens when the request has headers that are not valid UTF-8.
+    #[error("failed to convert request: {0}")]
+    HttpConversion(#[from] HttpError),
+}
+
+impl From<std::convert::Infallible> for RequestRejection {
+    fn from(_err: std::convert::Infallible) -> Self {
+        match _err {}
+    }
+}
+
+convert_to_request_rejection!(hyper::Error, BufferHttpBodyBytes);
+convert_to_request_rejection!(Box<dyn std::error::Error + Send + Sync + 'static>, BufferHttpBodyBytes);
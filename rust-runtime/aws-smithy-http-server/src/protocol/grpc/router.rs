@@ -0,0 +1,334 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::convert::Infallible;
+use std::task::{Context, Poll};
+
+use futures_util::future::{MapOk, TryFutureExt};
+use http::header::CONTENT_TYPE;
+use http::HeaderValue;
+use thiserror::Error;
+use tower::Layer;
+use tower::Service;
+
+use crate::body::{boxed, BoxBody};
+use crate::extension::{ModeledErrorExtension, RuntimeErrorExtension};
+use crate::protocol::aws_json::router::ROUTE_CUTOFF;
+use crate::response::IntoResponse;
+use crate::routing::tiny_map::TinyMap;
+use crate::routing::Route;
+use crate::routing::Router;
+use crate::routing::{method_disallowed, UNKNOWN_OPERATION_EXCEPTION};
+
+use super::{trailers_only_response, Grpc, GrpcBody, GrpcStatus, CONTENT_TYPE_GRPC, GRPC_STATUS};
+
+/// A gRPC routing error.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Method was not `POST`.
+    #[error("method not POST")]
+    MethodNotAllowed,
+    /// The `content-type` header is missing or doesn't start with `application/grpc`.
+    #[error("unsupported media type")]
+    UnsupportedMediaType,
+    /// Operation not found.
+    #[error("operation not found")]
+    NotFound,
+}
+
+/// A [`Router`] supporting the [gRPC] protocol.
+///
+/// Operations are keyed by their request path, `/{namespace}.{ServiceName}/{OperationName}`. The services returned
+/// by the router are wrapped in a [`GrpcService`], which turns the responses of the operations into gRPC responses.
+///
+/// [gRPC]: https://github.com/grpc/grpc/blob/master/doc/PROTOCOL-HTTP2.md
+#[derive(Debug, Clone)]
+pub struct GrpcRouter<S> {
+    routes: TinyMap<&'static str, S, ROUTE_CUTOFF>,
+    fallback: Option<S>,
+}
+
+impl<S> GrpcRouter<S> {
+    /// Registers a fallback route, used for requests that do not match any operation.
+    ///
+    /// The fallback only engages once the request could not be routed to an operation. Like operations, its
+    /// responses are turned into gRPC responses. Without a fallback, such requests are rejected with the protocol's
+    /// routing [`Error`] response.
+    pub fn fallback(self, fallback: S) -> Self {
+        Self {
+            fallback: Some(fallback),
+            ..self
+        }
+    }
+
+    pub fn boxed<B>(self) -> GrpcRouter<Route<B>>
+    where
+        S: Service<http::Request<B>, Response = http::Response<BoxBody>, Error = Infallible>,
+        S: Send + Clone + 'static,
+        S::Future: Send + 'static,
+    {
+        GrpcRouter {
+            routes: self.routes.into_iter().map(|(key, s)| (key, Route::new(s))).collect(),
+            fallback: self.fallback.map(Route::new),
+        }
+    }
+
+    /// Applies a [`Layer`] uniformly to all routes.
+    pub fn layer<L>(self, layer: L) -> GrpcRouter<L::Service>
+    where
+        L: Layer<S>,
+    {
+        GrpcRouter {
+            routes: self
+                .routes
+                .into_iter()
+                .map(|(key, route)| (key, layer.layer(route)))
+                .collect(),
+            fallback: self.fallback.map(|fallback| layer.layer(fallback)),
+        }
+    }
+
+    fn match_operation<B>(&self, request: &http::Request<B>) -> Result<&S, Error> {
+        // Only `Method::POST` is allowed.
+        if request.method() != http::Method::POST {
+            return Err(Error::MethodNotAllowed);
+        }
+
+        if !is_grpc_content_type(request.headers().get(CONTENT_TYPE)) {
+            return Err(Error::UnsupportedMediaType);
+        }
+
+        let request_path = request.uri().path();
+        tracing::trace!(%request_path, "looking up gRPC method");
+        self.routes.get(request_path).ok_or(Error::NotFound)
+    }
+}
+
+/// Returns whether `content_type` is `application/grpc`, optionally followed by a `+` suffix or parameters, such as
+/// `application/grpc+proto`.
+fn is_grpc_content_type(content_type: Option<&HeaderValue>) -> bool {
+    let Some(content_type) = content_type.and_then(|value| value.to_str().ok()) else {
+        return false;
+    };
+    match content_type.strip_prefix(CONTENT_TYPE_GRPC) {
+        Some(rest) => rest.is_empty() || rest.starts_with('+') || rest.starts_with(';'),
+        None => false,
+    }
+}
+
+impl IntoResponse<Grpc> for Error {
+    fn into_response(self) -> http::Response<BoxBody> {
+        match self {
+            Error::MethodNotAllowed => method_disallowed(),
+            // gRPC servers SHOULD respond with `415 Unsupported Media Type` to requests that aren't gRPC requests.
+            Error::UnsupportedMediaType => {
+                let mut response = http::Response::new(crate::body::empty());
+                *response.status_mut() = http::StatusCode::UNSUPPORTED_MEDIA_TYPE;
+                response
+            }
+            Error::NotFound => {
+                let mut response = trailers_only_response(GrpcStatus::Unimplemented, Some(UNKNOWN_OPERATION_EXCEPTION));
+                response
+                    .extensions_mut()
+                    .insert(RuntimeErrorExtension::new(UNKNOWN_OPERATION_EXCEPTION.to_string()));
+                response
+            }
+        }
+    }
+}
+
+impl<S: Clone, B> Router<B> for GrpcRouter<S> {
+    type Service = GrpcService<S>;
+
+    type Error = Error;
+
+    fn match_route(&self, request: &http::Request<B>) -> Result<Self::Service, Self::Error> {
+        self.match_operation(request)
+            .cloned()
+            // No operation matched, use the fallback if one was registered.
+            .or_else(|error| self.fallback.clone().ok_or(error))
+            .map(GrpcService::new)
+    }
+}
+
+impl<S> FromIterator<(&'static str, S)> for GrpcRouter<S> {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = (&'static str, S)>>(iter: T) -> Self {
+        Self {
+            routes: iter.into_iter().collect(),
+            fallback: None,
+        }
+    }
+}
+
+/// Wraps an operation's service to turn its HTTP responses into gRPC responses.
+///
+/// Successful responses are followed by a `grpc-status: 0` trailer. Error responses, which the operation sends with
+/// the HTTP status code bound to the error, are replaced by "Trailers-Only" responses carrying the corresponding
+/// [`GrpcStatus`] and the name of the error. Responses that already have a `grpc-status` are left unchanged.
+///
+/// The `content-type` of requests is normalized to `application/grpc`, so that operations accept
+/// `application/grpc+proto` requests too.
+#[derive(Debug, Clone)]
+pub struct GrpcService<S> {
+    inner: S,
+}
+
+impl<S> GrpcService<S> {
+    /// Creates a new `GrpcService` wrapping `inner`.
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+}
+
+type IntoGrpcResponse = fn(http::Response<BoxBody>) -> http::Response<BoxBody>;
+
+impl<S, B> Service<http::Request<B>> for GrpcService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = S::Error;
+    type Future = MapOk<S::Future, IntoGrpcResponse>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<B>) -> Self::Future {
+        if is_grpc_content_type(req.headers().get(CONTENT_TYPE)) {
+            req.headers_mut()
+                .insert(CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE_GRPC));
+        }
+        self.inner.call(req).map_ok(into_grpc_response as IntoGrpcResponse)
+    }
+}
+
+fn into_grpc_response(response: http::Response<BoxBody>) -> http::Response<BoxBody> {
+    if response.headers().contains_key(GRPC_STATUS) {
+        return response;
+    }
+
+    let status = GrpcStatus::from_http_status(response.status());
+    let (mut parts, body) = response.into_parts();
+    if status == GrpcStatus::Ok {
+        parts.status = http::StatusCode::OK;
+        return http::Response::from_parts(parts, boxed(GrpcBody::new(body, status)));
+    }
+
+    let error_name = parts
+        .extensions
+        .get::<ModeledErrorExtension>()
+        .map(|name| **name)
+        .or_else(|| {
+            parts
+                .extensions
+                .get::<RuntimeErrorExtension>()
+                .map(|name| name.as_str())
+        });
+    let mut grpc_response = trailers_only_response(status, error_name);
+    // Keep the extensions, so that layers outside of the router can still tell which error was returned.
+    *grpc_response.extensions_mut() = parts.extensions;
+    grpc_response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use http::{HeaderMap, HeaderValue, Method, StatusCode};
+    use http_body::Body as _;
+    use tower::{service_fn, ServiceExt};
+
+    use crate::body::{to_boxed, Body, BoxBody};
+    use crate::extension::ModeledErrorExtension;
+    use crate::protocol::test_helpers::req;
+
+    use super::{Error, GrpcRouter, GrpcService, Router};
+
+    fn grpc_headers() -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type", HeaderValue::from_static("application/grpc+proto"));
+        Some(headers)
+    }
+
+    #[test]
+    fn routes_by_path() {
+        let router: GrpcRouter<_> = ["/com.example.Pokemon/GetPokemon", "/com.example.Pokemon/ListPokemon"]
+            .into_iter()
+            .map(|path| (path, path))
+            .collect();
+
+        let route = router
+            .match_route(&req(&Method::POST, "/com.example.Pokemon/ListPokemon", grpc_headers()))
+            .unwrap();
+        assert_eq!("/com.example.Pokemon/ListPokemon", route.inner);
+
+        let cases = [
+            (
+                Method::GET,
+                "/com.example.Pokemon/GetPokemon",
+                grpc_headers(),
+                "MethodNotAllowed",
+            ),
+            (
+                Method::POST,
+                "/com.example.Pokemon/GetPokemon",
+                None,
+                "UnsupportedMediaType",
+            ),
+            (Method::POST, "/com.example.Pokemon/Unknown", grpc_headers(), "NotFound"),
+            (Method::POST, "/Pokemon/GetPokemon", grpc_headers(), "NotFound"),
+        ];
+        for (method, uri, headers, expected) in cases {
+            let err = router.match_route(&req(&method, uri, headers)).unwrap_err();
+            let actual = match err {
+                Error::MethodNotAllowed => "MethodNotAllowed",
+                Error::UnsupportedMediaType => "UnsupportedMediaType",
+                Error::NotFound => "NotFound",
+            };
+            assert_eq!(expected, actual, "{uri}");
+        }
+    }
+
+    async fn respond(response: http::Response<BoxBody>) -> http::Response<BoxBody> {
+        let response = std::sync::Mutex::new(Some(response));
+        let service = service_fn(move |request: http::Request<Body>| {
+            assert_eq!("application/grpc", request.headers()["content-type"]);
+            let response = response.lock().unwrap().take().unwrap();
+            async move { Ok::<_, Infallible>(response) }
+        });
+        let request = http::Request::post("/com.example.Pokemon/GetPokemon")
+            .header("content-type", "application/grpc+proto")
+            .body(Body::empty())
+            .unwrap();
+        GrpcService::new(service).oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn successful_responses_end_with_a_status_trailer() {
+        let mut response = respond(http::Response::new(to_boxed("message"))).await;
+        assert_eq!(StatusCode::OK, response.status());
+        let body = response.body_mut();
+        assert_eq!(&b"message"[..], &body.data().await.unwrap().unwrap()[..]);
+        assert!(body.data().await.is_none());
+        assert_eq!("0", body.trailers().await.unwrap().unwrap()["grpc-status"]);
+    }
+
+    #[tokio::test]
+    async fn errors_become_trailers_only_responses() {
+        let error = http::Response::builder()
+            .status(404)
+            .extension(ModeledErrorExtension::new("ResourceNotFoundException"))
+            .body(to_boxed("error details"))
+            .unwrap();
+        let mut response = respond(error).await;
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("5", response.headers()["grpc-status"]);
+        assert_eq!("ResourceNotFoundException", response.headers()["grpc-message"]);
+        assert!(response.extensions().get::<ModeledErrorExtension>().is_some());
+        assert!(response.body_mut().data().await.is_none());
+    }
+}
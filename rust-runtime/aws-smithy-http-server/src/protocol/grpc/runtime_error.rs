@@ -0,0 +1,86 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::extension::RuntimeErrorExtension;
+use crate::protocol::grpc::{trailers_only_response, Grpc, GrpcStatus};
+use crate::response::IntoResponse;
+use crate::runtime_error::InternalFailureException;
+
+use super::rejection::{RequestRejection, ResponseRejection};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RuntimeError {
+    /// See: [`crate::protocol::rest_json_1::runtime_error::RuntimeError::Serialization`]
+    #[error("request failed to deserialize or response failed to serialize: {0}")]
+    Serialization(crate::Error),
+    /// See: [`crate::protocol::rest_json_1::runtime_error::RuntimeError::InternalFailure`]
+    #[error("internal failure: {0}")]
+    InternalFailure(crate::Error),
+    /// See: [`crate::protocol::rest_json_1::runtime_error::RuntimeError::NotAcceptable`]
+    #[error("not acceptable request: request contains an `Accept` header with a MIME type, and the server cannot return a response body adhering to that MIME type")]
+    NotAcceptable,
+    /// See: [`crate::protocol::rest_json_1::runtime_error::RuntimeError::UnsupportedMediaType`]
+    #[error("unsupported media type: request does not contain the expected `Content-Type` header value")]
+    UnsupportedMediaType,
+    /// See: [`crate::protocol::rest_json_1::runtime_error::RuntimeError::Validation`]
+    #[error(
+        "validation failure: operation input contains data that does not adhere to the modeled constraints: {0:?}"
+    )]
+    Validation(Vec<u8>),
+}
+
+impl RuntimeError {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Serialization(_) => "SerializationException",
+            Self::InternalFailure(_) => "InternalFailureException",
+            Self::NotAcceptable => "NotAcceptableException",
+            Self::UnsupportedMediaType => "UnsupportedMediaTypeException",
+            Self::Validation(_) => "ValidationException",
+        }
+    }
+
+    pub fn grpc_status(&self) -> GrpcStatus {
+        match self {
+            Self::InternalFailure(_) => GrpcStatus::Internal,
+            Self::Serialization(_) | Self::NotAcceptable | Self::UnsupportedMediaType | Self::Validation(_) => {
+                GrpcStatus::InvalidArgument
+            }
+        }
+    }
+}
+
+impl IntoResponse<Grpc> for InternalFailureException {
+    fn into_response(self) -> http::Response<crate::body::BoxBody> {
+        IntoResponse::<Grpc>::into_response(RuntimeError::InternalFailure(crate::Error::new(String::new())))
+    }
+}
+
+impl IntoResponse<Grpc> for RuntimeError {
+    fn into_response(self) -> http::Response<crate::body::BoxBody> {
+        // Errors are sent as "Trailers-Only" responses, which can't have a body, so the details of the error are
+        // only logged and its name is sent as the `grpc-message`.
+        let mut response = trailers_only_response(self.grpc_status(), Some(self.name()));
+        response
+            .extensions_mut()
+            .insert(RuntimeErrorExtension::new(self.name().to_string()));
+        response
+    }
+}
+
+impl From<ResponseRejection> for RuntimeError {
+    fn from(err: ResponseRejection) -> Self {
+        Self::Serialization(crate::Error::new(err))
+    }
+}
+
+impl From<RequestRejection> for RuntimeError {
+    fn from(err: RequestRejection) -> Self {
+        match err {
+            RequestRejection::ConstraintViolation(reason) => Self::Validation(reason),
+            _ => Self::Serialization(crate::Error::new(err)),
+        }
+    }
+}
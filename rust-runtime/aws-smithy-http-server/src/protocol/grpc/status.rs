@@ -0,0 +1,140 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::fmt;
+
+use http::StatusCode;
+
+/// A [gRPC status code], sent to clients in the `grpc-status` trailer.
+///
+/// [gRPC status code]: https://grpc.github.io/grpc/core/md_doc_statuscodes.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GrpcStatus {
+    /// Not an error; returned on success.
+    Ok,
+    /// The operation was cancelled, typically by the caller.
+    Cancelled,
+    /// Unknown error.
+    Unknown,
+    /// The client specified an invalid argument.
+    InvalidArgument,
+    /// The deadline expired before the operation could complete.
+    DeadlineExceeded,
+    /// Some requested entity was not found.
+    NotFound,
+    /// The entity that a client attempted to create already exists.
+    AlreadyExists,
+    /// The caller does not have permission to execute the specified operation.
+    PermissionDenied,
+    /// Some resource has been exhausted.
+    ResourceExhausted,
+    /// The system is not in a state required for the operation's execution.
+    FailedPrecondition,
+    /// The operation was aborted, typically due to a concurrency issue.
+    Aborted,
+    /// The operation was attempted past the valid range.
+    OutOfRange,
+    /// The operation is not implemented or is not supported.
+    Unimplemented,
+    /// Internal error.
+    Internal,
+    /// The service is currently unavailable.
+    Unavailable,
+    /// Unrecoverable data loss or corruption.
+    DataLoss,
+    /// The request does not have valid authentication credentials for the operation.
+    Unauthenticated,
+}
+
+impl GrpcStatus {
+    /// Returns the numeric value of the status code, as sent in the `grpc-status` trailer.
+    pub fn code(self) -> u8 {
+        match self {
+            GrpcStatus::Ok => 0,
+            GrpcStatus::Cancelled => 1,
+            GrpcStatus::Unknown => 2,
+            GrpcStatus::InvalidArgument => 3,
+            GrpcStatus::DeadlineExceeded => 4,
+            GrpcStatus::NotFound => 5,
+            GrpcStatus::AlreadyExists => 6,
+            GrpcStatus::PermissionDenied => 7,
+            GrpcStatus::ResourceExhausted => 8,
+            GrpcStatus::FailedPrecondition => 9,
+            GrpcStatus::Aborted => 10,
+            GrpcStatus::OutOfRange => 11,
+            GrpcStatus::Unimplemented => 12,
+            GrpcStatus::Internal => 13,
+            GrpcStatus::Unavailable => 14,
+            GrpcStatus::DataLoss => 15,
+            GrpcStatus::Unauthenticated => 16,
+        }
+    }
+
+    /// Maps the HTTP status code of an operation's response to a gRPC status.
+    ///
+    /// Modeled errors are bound to HTTP status codes with the `@httpError` and `@error` traits. This maps the status
+    /// codes commonly used by Smithy services to the gRPC status with the same meaning, following the mapping used by
+    /// [Google APIs]; any other `4xx` status is mapped to [`GrpcStatus::FailedPrecondition`] and any other `5xx`
+    /// status to [`GrpcStatus::Internal`].
+    ///
+    /// [Google APIs]: https://github.com/googleapis/googleapis/blob/master/google/rpc/code.proto
+    pub fn from_http_status(status: StatusCode) -> Self {
+        match status.as_u16() {
+            200..=299 => GrpcStatus::Ok,
+            400 => GrpcStatus::InvalidArgument,
+            401 => GrpcStatus::Unauthenticated,
+            403 => GrpcStatus::PermissionDenied,
+            404 => GrpcStatus::NotFound,
+            409 => GrpcStatus::Aborted,
+            412 => GrpcStatus::FailedPrecondition,
+            416 => GrpcStatus::OutOfRange,
+            429 => GrpcStatus::ResourceExhausted,
+            499 => GrpcStatus::Cancelled,
+            501 => GrpcStatus::Unimplemented,
+            503 => GrpcStatus::Unavailable,
+            504 => GrpcStatus::DeadlineExceeded,
+            _ if status.is_client_error() => GrpcStatus::FailedPrecondition,
+            _ if status.is_server_error() => GrpcStatus::Internal,
+            _ => GrpcStatus::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for GrpcStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use super::GrpcStatus;
+
+    #[test]
+    fn maps_http_status_codes() {
+        let cases = [
+            (200, GrpcStatus::Ok),
+            (400, GrpcStatus::InvalidArgument),
+            (404, GrpcStatus::NotFound),
+            (409, GrpcStatus::Aborted),
+            (418, GrpcStatus::FailedPrecondition),
+            (429, GrpcStatus::ResourceExhausted),
+            (500, GrpcStatus::Internal),
+            (503, GrpcStatus::Unavailable),
+            (302, GrpcStatus::Unknown),
+        ];
+        for (http_status, expected) in cases {
+            assert_eq!(
+                expected,
+                GrpcStatus::from_http_status(StatusCode::from_u16(http_status).unwrap()),
+                "{http_status}"
+            );
+        }
+        assert_eq!("14", GrpcStatus::Unavailable.to_string());
+    }
+}
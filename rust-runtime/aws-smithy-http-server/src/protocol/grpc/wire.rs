@@ -0,0 +1,466 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Encoding and decoding of the [protobuf wire format], used by generated gRPC serializers and parsers.
+//!
+//! [protobuf wire format]: https://protobuf.dev/programming-guides/encoding/
+
+use thiserror::Error;
+
+/// The wire type of a field, which determines how its value is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireType {
+    /// `int32`, `int64`, `bool` and enums.
+    Varint,
+    /// `double`.
+    Fixed64,
+    /// `string`, `bytes`, embedded messages and packed repeated fields.
+    LengthDelimited,
+    /// `float`.
+    Fixed32,
+}
+
+impl WireType {
+    fn from_tag(tag: u64) -> Result<Self, DecodeError> {
+        match tag & 0x7 {
+            0 => Ok(WireType::Varint),
+            1 => Ok(WireType::Fixed64),
+            2 => Ok(WireType::LengthDelimited),
+            5 => Ok(WireType::Fixed32),
+            other => Err(DecodeError::UnsupportedWireType(other as u8)),
+        }
+    }
+
+    fn tag_bits(self) -> u64 {
+        match self {
+            WireType::Varint => 0,
+            WireType::Fixed64 => 1,
+            WireType::LengthDelimited => 2,
+            WireType::Fixed32 => 5,
+        }
+    }
+}
+
+/// An error that occurs when a message can't be decoded.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The message ended in the middle of a field.
+    #[error("unexpected end of message")]
+    UnexpectedEof,
+    /// A varint was longer than 10 bytes.
+    #[error("varint is too long")]
+    VarintTooLong,
+    /// A field used a wire type that isn't supported, such as the deprecated groups.
+    #[error("unsupported wire type {0}")]
+    UnsupportedWireType(u8),
+    /// A field number was 0 or too large.
+    #[error("invalid field number {0}")]
+    InvalidFieldNumber(u64),
+    /// A field was encoded with a different wire type than its type in the message.
+    #[error("field {field} has wire type {found:?} but {expected:?} was expected")]
+    UnexpectedWireType {
+        /// The number of the field.
+        field: u32,
+        /// The wire type expected for the field's type.
+        expected: WireType,
+        /// The wire type of the field in the message.
+        found: WireType,
+    },
+    /// A `string` field was not valid UTF-8.
+    #[error("field {0} is not valid UTF-8")]
+    InvalidUtf8(u32),
+    /// An integer field's value doesn't fit in its type.
+    #[error("field {0} is out of range for its type")]
+    OutOfRange(u32),
+}
+
+fn encode_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Encodes the fields of a message.
+///
+/// Fields are written in the order they are added. Every field is written, even when its value is the default value
+/// for its type.
+#[derive(Debug, Default, Clone)]
+pub struct Encoder {
+    buf: Vec<u8>,
+}
+
+impl Encoder {
+    /// Creates an `Encoder` for an empty message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn tag(&mut self, field: u32, wire_type: WireType) {
+        encode_varint(&mut self.buf, (u64::from(field) << 3) | wire_type.tag_bits());
+    }
+
+    /// Writes an `int32` field.
+    pub fn int32(&mut self, field: u32, value: i32) -> &mut Self {
+        // Negative values are sign-extended to 64 bits, as they are for `int64`.
+        self.int64(field, i64::from(value))
+    }
+
+    /// Writes an `int64` field.
+    pub fn int64(&mut self, field: u32, value: i64) -> &mut Self {
+        self.tag(field, WireType::Varint);
+        encode_varint(&mut self.buf, value as u64);
+        self
+    }
+
+    /// Writes a `bool` field.
+    pub fn bool(&mut self, field: u32, value: bool) -> &mut Self {
+        self.tag(field, WireType::Varint);
+        self.buf.push(value as u8);
+        self
+    }
+
+    /// Writes a `float` field.
+    pub fn float(&mut self, field: u32, value: f32) -> &mut Self {
+        self.tag(field, WireType::Fixed32);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Writes a `double` field.
+    pub fn double(&mut self, field: u32, value: f64) -> &mut Self {
+        self.tag(field, WireType::Fixed64);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Writes a `string` field.
+    pub fn string(&mut self, field: u32, value: &str) -> &mut Self {
+        self.bytes(field, value.as_bytes())
+    }
+
+    /// Writes a `bytes` field.
+    pub fn bytes(&mut self, field: u32, value: &[u8]) -> &mut Self {
+        self.tag(field, WireType::LengthDelimited);
+        encode_varint(&mut self.buf, value.len() as u64);
+        self.buf.extend_from_slice(value);
+        self
+    }
+
+    /// Writes an embedded message field.
+    pub fn message(&mut self, field: u32, message: Encoder) -> &mut Self {
+        self.bytes(field, &message.buf)
+    }
+
+    /// Returns the encoded message.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+/// The value of a field, as read from the wire.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WireValue<'a> {
+    /// A [`WireType::Varint`] value.
+    Varint(u64),
+    /// A [`WireType::Fixed64`] value.
+    Fixed64(u64),
+    /// A [`WireType::LengthDelimited`] value.
+    LengthDelimited(&'a [u8]),
+    /// A [`WireType::Fixed32`] value.
+    Fixed32(u32),
+}
+
+/// A field read from a message by a [`Decoder`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Field<'a> {
+    number: u32,
+    value: WireValue<'a>,
+}
+
+impl<'a> Field<'a> {
+    /// Returns the field number.
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Returns the value of the field.
+    pub fn value(&self) -> WireValue<'a> {
+        self.value
+    }
+
+    fn wire_type(&self) -> WireType {
+        match self.value {
+            WireValue::Varint(_) => WireType::Varint,
+            WireValue::Fixed64(_) => WireType::Fixed64,
+            WireValue::LengthDelimited(_) => WireType::LengthDelimited,
+            WireValue::Fixed32(_) => WireType::Fixed32,
+        }
+    }
+
+    fn unexpected(&self, expected: WireType) -> DecodeError {
+        DecodeError::UnexpectedWireType {
+            field: self.number,
+            expected,
+            found: self.wire_type(),
+        }
+    }
+
+    fn varint(&self) -> Result<u64, DecodeError> {
+        match self.value {
+            WireValue::Varint(value) => Ok(value),
+            _ => Err(self.unexpected(WireType::Varint)),
+        }
+    }
+
+    /// Reads the value of an `int32` field.
+    ///
+    /// As in other protobuf implementations, 64-bit values are truncated to 32 bits.
+    pub fn as_i32(&self) -> Result<i32, DecodeError> {
+        Ok(self.varint()? as i32)
+    }
+
+    /// Reads the value of an `int32` field that must fit in an `i8`.
+    pub fn as_i8(&self) -> Result<i8, DecodeError> {
+        i8::try_from(self.as_i32()?).map_err(|_| DecodeError::OutOfRange(self.number))
+    }
+
+    /// Reads the value of an `int32` field that must fit in an `i16`.
+    pub fn as_i16(&self) -> Result<i16, DecodeError> {
+        i16::try_from(self.as_i32()?).map_err(|_| DecodeError::OutOfRange(self.number))
+    }
+
+    /// Reads the value of an `int64` field.
+    pub fn as_i64(&self) -> Result<i64, DecodeError> {
+        Ok(self.varint()? as i64)
+    }
+
+    /// Reads the value of a `bool` field.
+    pub fn as_bool(&self) -> Result<bool, DecodeError> {
+        Ok(self.varint()? != 0)
+    }
+
+    /// Reads the value of a `float` field.
+    pub fn as_f32(&self) -> Result<f32, DecodeError> {
+        match self.value {
+            WireValue::Fixed32(bits) => Ok(f32::from_bits(bits)),
+            _ => Err(self.unexpected(WireType::Fixed32)),
+        }
+    }
+
+    /// Reads the value of a `double` field.
+    pub fn as_f64(&self) -> Result<f64, DecodeError> {
+        match self.value {
+            WireValue::Fixed64(bits) => Ok(f64::from_bits(bits)),
+            _ => Err(self.unexpected(WireType::Fixed64)),
+        }
+    }
+
+    /// Reads the value of a `bytes` or embedded message field.
+    pub fn as_bytes(&self) -> Result<&'a [u8], DecodeError> {
+        match self.value {
+            WireValue::LengthDelimited(bytes) => Ok(bytes),
+            _ => Err(self.unexpected(WireType::LengthDelimited)),
+        }
+    }
+
+    /// Reads the value of a `string` field.
+    pub fn as_str(&self) -> Result<&'a str, DecodeError> {
+        std::str::from_utf8(self.as_bytes()?).map_err(|_| DecodeError::InvalidUtf8(self.number))
+    }
+
+    /// Returns the elements of a repeated scalar field with wire type `element_type`.
+    ///
+    /// Parsers must accept repeated scalar fields in both their packed and unpacked encodings: a packed field holds
+    /// all of its elements, while an unpacked field is repeated once for each of its elements.
+    pub fn elements(&self, element_type: WireType) -> Result<Vec<Field<'a>>, DecodeError> {
+        match (self.value, element_type) {
+            (WireValue::LengthDelimited(packed), WireType::Varint | WireType::Fixed64 | WireType::Fixed32) => {
+                let mut decoder = Decoder::new(packed);
+                let mut elements = Vec::new();
+                while !decoder.is_empty() {
+                    elements.push(Field {
+                        number: self.number,
+                        value: decoder.read_value(element_type)?,
+                    });
+                }
+                Ok(elements)
+            }
+            _ => Ok(vec![*self]),
+        }
+    }
+}
+
+/// Decodes the fields of a message.
+#[derive(Debug, Clone)]
+pub struct Decoder<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    /// Creates a `Decoder` for `message`.
+    pub fn new(message: &'a [u8]) -> Self {
+        Self { buf: message }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        let mut value = 0u64;
+        for (i, byte) in self.buf.iter().enumerate().take(10) {
+            value |= u64::from(byte & 0x7f) << (7 * i);
+            if byte & 0x80 == 0 {
+                self.buf = &self.buf[i + 1..];
+                return Ok(value);
+            }
+        }
+        if self.buf.len() < 10 {
+            Err(DecodeError::UnexpectedEof)
+        } else {
+            Err(DecodeError::VarintTooLong)
+        }
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if self.buf.len() < len {
+            return Err(DecodeError::UnexpectedEof);
+        }
+        let (bytes, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(bytes)
+    }
+
+    fn read_value(&mut self, wire_type: WireType) -> Result<WireValue<'a>, DecodeError> {
+        Ok(match wire_type {
+            WireType::Varint => WireValue::Varint(self.read_varint()?),
+            WireType::Fixed64 => {
+                WireValue::Fixed64(u64::from_le_bytes(self.read_bytes(8)?.try_into().expect("8 bytes")))
+            }
+            WireType::LengthDelimited => {
+                let len = self.read_varint()?;
+                let len = usize::try_from(len).map_err(|_| DecodeError::UnexpectedEof)?;
+                WireValue::LengthDelimited(self.read_bytes(len)?)
+            }
+            WireType::Fixed32 => {
+                WireValue::Fixed32(u32::from_le_bytes(self.read_bytes(4)?.try_into().expect("4 bytes")))
+            }
+        })
+    }
+
+    /// Reads the next field of the message, or returns `None` at the end of the message.
+    ///
+    /// Fields can appear in any order and more than once. Fields that the parser doesn't know about should be
+    /// skipped, so that messages produced by newer clients can still be read.
+    pub fn next_field(&mut self) -> Result<Option<Field<'a>>, DecodeError> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let tag = self.read_varint()?;
+        let number = tag >> 3;
+        if number == 0 || number > u64::from(u32::MAX >> 3) {
+            return Err(DecodeError::InvalidFieldNumber(number));
+        }
+        let value = self.read_value(WireType::from_tag(tag)?)?;
+        Ok(Some(Field {
+            number: number as u32,
+            value,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodeError, Decoder, Encoder, WireType};
+
+    #[test]
+    fn encodes_the_protobuf_wire_format() {
+        let mut inner = Encoder::new();
+        inner.string(1, "testing");
+        let mut encoder = Encoder::new();
+        encoder
+            .int32(1, 150)
+            .int32(2, -1)
+            .bool(3, true)
+            .double(4, 1.5)
+            .message(5, inner);
+
+        let mut expected = vec![0x08, 0x96, 0x01];
+        expected.extend([0x10, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]);
+        expected.extend([0x18, 0x01]);
+        expected.extend([0x21, 0, 0, 0, 0, 0, 0, 0xf8, 0x3f]);
+        expected.extend([0x2a, 0x09, 0x0a, 0x07]);
+        expected.extend(b"testing");
+        assert_eq!(expected, encoder.into_bytes());
+    }
+
+    #[test]
+    fn decodes_what_it_encodes() {
+        let mut encoder = Encoder::new();
+        encoder
+            .int32(1, -7)
+            .int64(2, i64::MAX)
+            .float(3, 0.25)
+            .string(4, "hello")
+            .bytes(5, &[0, 1]);
+        let message = encoder.into_bytes();
+
+        let mut decoder = Decoder::new(&message);
+        let mut next = || decoder.next_field().unwrap().unwrap();
+        assert_eq!(-7, next().as_i32().unwrap());
+        assert_eq!(i64::MAX, next().as_i64().unwrap());
+        assert_eq!(0.25, next().as_f32().unwrap());
+        assert_eq!("hello", next().as_str().unwrap());
+        let field = next();
+        assert_eq!(5, field.number());
+        assert_eq!(&[0, 1][..], field.as_bytes().unwrap());
+        assert_eq!(None, decoder.next_field().unwrap());
+    }
+
+    #[test]
+    fn decodes_packed_and_unpacked_repeated_fields() {
+        // Field 4 holding [3, 270, 86942], packed
+        let packed = [0x22, 0x06, 0x03, 0x8e, 0x02, 0x9e, 0xa7, 0x05];
+        let field = Decoder::new(&packed).next_field().unwrap().unwrap();
+        let elements: Vec<i32> = field
+            .elements(WireType::Varint)
+            .unwrap()
+            .iter()
+            .map(|element| element.as_i32().unwrap())
+            .collect();
+        assert_eq!(vec![3, 270, 86942], elements);
+
+        let unpacked = [0x20, 0x03];
+        let field = Decoder::new(&unpacked).next_field().unwrap().unwrap();
+        assert_eq!(1, field.elements(WireType::Varint).unwrap().len());
+    }
+
+    #[test]
+    fn rejects_invalid_messages() {
+        let next = |message: &[u8]| Decoder::new(message).next_field().map(|_| ());
+        assert_eq!(Err(DecodeError::UnexpectedEof), next(&[0x08]));
+        assert_eq!(Err(DecodeError::UnexpectedEof), next(&[0x0a, 0x05, b'a']));
+        assert_eq!(Err(DecodeError::UnsupportedWireType(3)), next(&[0x0b]));
+        assert_eq!(Err(DecodeError::InvalidFieldNumber(0)), next(&[0x00, 0x01]));
+        assert_eq!(
+            Err(DecodeError::VarintTooLong),
+            next(&[0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01])
+        );
+
+        let field = Decoder::new(&[0x08, 0x01]).next_field().unwrap().unwrap();
+        assert_eq!(
+            Err(DecodeError::UnexpectedWireType {
+                field: 1,
+                expected: WireType::LengthDelimited,
+                found: WireType::Varint,
+            }),
+            field.as_str()
+        );
+        let field = Decoder::new(&[0x08, 0xac, 0x02]).next_field().unwrap().unwrap();
+        assert_eq!(Err(DecodeError::OutOfRange(1)), field.as_i8());
+    }
+}
@@ -6,15 +6,21 @@
 pub mod aws_json;
 pub mod aws_json_10;
 pub mod aws_json_11;
+pub mod grpc;
 pub mod rest;
 pub mod rest_json_1;
 pub mod rest_xml;
 pub mod rpc_v2_cbor;
 
-use crate::rejection::MissingContentTypeReason;
+use crate::rejection::{MissingContentTypeReason, StreamingPayloadLengthReason};
 use aws_smithy_runtime_api::http::Headers as SmithyHeaders;
-use http::header::CONTENT_TYPE;
+use aws_smithy_types::body::SdkBody;
+use bytes::Bytes;
+use http::header::{CONTENT_LENGTH, CONTENT_TYPE};
 use http::HeaderMap;
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
 
 #[cfg(test)]
 pub mod test_helpers {
@@ -106,6 +112,88 @@ fn content_type_header_classifier(
     }
 }
 
+/// Checks the `Content-Length` header of a request with a streaming payload.
+///
+/// When `requires_length` is set, which is the case for payloads targeting a shape with the `@requiresLength` trait,
+/// requests without a `Content-Length` header, such as chunked uploads, are rejected. Requests declaring a length
+/// greater than `max_length`, taken from the `@length` trait of the payload, are rejected too. Use
+/// [`limit_streaming_payload`] to also enforce the maximum length on requests without a `Content-Length` header.
+#[allow(clippy::result_large_err)]
+pub fn streaming_payload_length_classifier(
+    headers: &SmithyHeaders,
+    requires_length: bool,
+    max_length: Option<u64>,
+) -> Result<(), StreamingPayloadLengthReason> {
+    let content_length = headers
+        .get(CONTENT_LENGTH)
+        .map(|value| {
+            value
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| StreamingPayloadLengthReason::InvalidContentLength)
+        })
+        .transpose()?;
+    match (content_length, max_length) {
+        (None, _) if requires_length => Err(StreamingPayloadLengthReason::MissingContentLength),
+        (Some(length), Some(max_length)) if length > max_length => {
+            Err(StreamingPayloadLengthReason::TooLarge { max_length })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Wraps a streaming payload so that reading it fails once more than `max_length` bytes have been received.
+pub fn limit_streaming_payload(body: SdkBody, max_length: u64) -> SdkBody {
+    SdkBody::from_body_0_4(LimitedStreamingPayload {
+        inner: body,
+        remaining: max_length,
+        max_length,
+    })
+}
+
+pin_project! {
+    struct LimitedStreamingPayload {
+        #[pin]
+        inner: SdkBody,
+        remaining: u64,
+        max_length: u64,
+    }
+}
+
+impl http_body::Body for LimitedStreamingPayload {
+    type Data = Bytes;
+    type Error = aws_smithy_types::body::Error;
+
+    fn poll_data(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        let this = self.project();
+        match ready!(this.inner.poll_data(cx)) {
+            Some(Ok(data)) => match this.remaining.checked_sub(data.len() as u64) {
+                Some(remaining) => {
+                    *this.remaining = remaining;
+                    Poll::Ready(Some(Ok(data)))
+                }
+                None => Poll::Ready(Some(Err(StreamingPayloadLengthReason::TooLarge {
+                    max_length: *this.max_length,
+                }
+                .into()))),
+            },
+            other => Poll::Ready(other),
+        }
+    }
+
+    fn poll_trailers(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        self.project().inner.poll_trailers(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.inner.size_hint()
+    }
+}
+
 pub fn accept_header_classifier(headers: &HeaderMap, content_type: &mime::Mime) -> bool {
     if !headers.contains_key(http::header::ACCEPT) {
         return true;
@@ -140,10 +228,93 @@ pub fn accept_header_classifier(headers: &HeaderMap, content_type: &mime::Mime)
         })
 }
 
+/// Returns whether a response with the given `content_type` is acceptable to the client, following the
+/// content negotiation rules for the `Accept` header of [RFC 9110].
+///
+/// Unlike [`accept_header_classifier`], the most specific media range matching `content_type` decides: a
+/// `type/subtype` range takes precedence over a `type/*` range, which takes precedence over `*/*`. If that media
+/// range has a quality value of `q=0`, `content_type` is not acceptable. Media type parameters other than `q` are
+/// ignored. Media ranges that can't be parsed are ignored, and requests without any valid media range accept any
+/// content type.
+///
+/// [RFC 9110]: https://www.rfc-editor.org/rfc/rfc9110#section-12.5.1
+pub fn strict_accept_header_classifier(headers: &HeaderMap, content_type: &mime::Mime) -> bool {
+    let mut media_ranges = headers
+        .get_all(http::header::ACCEPT)
+        .into_iter()
+        .filter_map(|header| header.to_str().ok())
+        .flat_map(split_media_ranges)
+        .filter_map(|media_range| {
+            let media_range = media_range.parse::<mime::Mime>().ok()?;
+            let quality = quality(&media_range)?;
+            Some((media_range, quality))
+        })
+        .peekable();
+    if media_ranges.peek().is_none() {
+        return true;
+    }
+
+    let typ = content_type.type_();
+    let subtype = content_type.subtype();
+    media_ranges
+        .filter_map(|(media_range, quality)| {
+            let specificity = match (media_range.type_(), media_range.subtype()) {
+                (t, s) if t == typ && s == subtype => 2,
+                (t, mime::STAR) if t == typ => 1,
+                (mime::STAR, mime::STAR) => 0,
+                _ => return None,
+            };
+            Some((specificity, quality))
+        })
+        .max()
+        .map_or(false, |(_, quality)| quality > 0)
+}
+
+/// Splits an `Accept` header value into its media ranges, ignoring commas within quoted parameter values.
+fn split_media_ranges(header: &str) -> impl Iterator<Item = &str> {
+    let mut media_ranges = Vec::new();
+    let (mut start, mut quoted, mut escaped) = (0, false, false);
+    for (i, c) in header.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                media_ranges.push(&header[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    media_ranges.push(&header[start..]);
+    media_ranges
+        .into_iter()
+        .map(str::trim)
+        .filter(|media_range| !media_range.is_empty())
+}
+
+/// Returns the quality value of a media range in thousandths, or `None` if it's invalid.
+fn quality(media_range: &mime::Mime) -> Option<u16> {
+    let q = match media_range.get_param("q") {
+        Some(q) => q.as_str(),
+        None => return Some(1000),
+    };
+    let (integer, fraction) = q.split_once('.').unwrap_or((q, ""));
+    if fraction.len() > 3 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let thousandths = format!("{fraction:0<3}").parse::<u16>().ok()?;
+    match integer {
+        "0" => Some(thousandths),
+        "1" if thousandths == 0 => Some(1000),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use http::header::{HeaderValue, ACCEPT, CONTENT_TYPE};
+    use http::header::{HeaderValue, ACCEPT, CONTENT_LENGTH, CONTENT_TYPE};
 
     fn req_content_type_smithy(content_type: &'static str) -> SmithyHeaders {
         let mut headers = SmithyHeaders::new();
@@ -181,6 +352,49 @@ mod tests {
         }
     }
 
+    fn req_content_length_smithy(content_length: &'static str) -> SmithyHeaders {
+        let mut headers = SmithyHeaders::new();
+        headers.insert(CONTENT_LENGTH, HeaderValue::from_static(content_length));
+        headers
+    }
+
+    #[test]
+    fn check_streaming_payload_length() {
+        let sized = req_content_length_smithy("1024");
+        assert!(streaming_payload_length_classifier(&sized, true, None).is_ok());
+        assert!(streaming_payload_length_classifier(&sized, true, Some(1024)).is_ok());
+        assert!(matches!(
+            streaming_payload_length_classifier(&sized, false, Some(1023)),
+            Err(StreamingPayloadLengthReason::TooLarge { max_length: 1023 })
+        ));
+
+        let chunked = SmithyHeaders::new();
+        assert!(streaming_payload_length_classifier(&chunked, false, Some(1)).is_ok());
+        assert!(matches!(
+            streaming_payload_length_classifier(&chunked, true, None),
+            Err(StreamingPayloadLengthReason::MissingContentLength)
+        ));
+
+        let invalid = req_content_length_smithy("lots");
+        assert!(matches!(
+            streaming_payload_length_classifier(&invalid, false, None),
+            Err(StreamingPayloadLengthReason::InvalidContentLength)
+        ));
+    }
+
+    #[tokio::test]
+    async fn limit_streaming_payload_fails_past_the_maximum_length() {
+        let within = limit_streaming_payload(SdkBody::from("hello"), 5);
+        assert_eq!(&b"hello"[..], &hyper::body::to_bytes(within).await.unwrap()[..]);
+
+        let beyond = limit_streaming_payload(SdkBody::from("hello world"), 5);
+        let err = hyper::body::to_bytes(beyond).await.unwrap_err();
+        assert_eq!(
+            "streaming payload exceeds the maximum length of 5 bytes",
+            err.to_string()
+        );
+    }
+
     #[test]
     fn check_valid_content_type() {
         let headers = req_content_type_smithy("application/json");
@@ -310,4 +524,45 @@ mod tests {
             &"application/json".parse().unwrap()
         ));
     }
+
+    fn strict_accepts(accept: &'static str) -> bool {
+        strict_accept_header_classifier(&req_accept(accept), &"application/json".parse().unwrap())
+    }
+
+    #[test]
+    fn strict_accept_header_classifier_matches_media_ranges() {
+        assert!(strict_accept_header_classifier(
+            &HeaderMap::new(),
+            &"application/json".parse().unwrap()
+        ));
+        assert!(strict_accepts("application/json"));
+        assert!(strict_accepts("application/*"));
+        assert!(strict_accepts("*/*"));
+        assert!(strict_accepts("text/strings, application/json; charset=utf-8, invalid"));
+        assert!(!strict_accepts("text/plain, application/xml"));
+        assert!(!strict_accepts("text/*"));
+    }
+
+    #[test]
+    fn strict_accept_header_classifier_honors_quality_values() {
+        assert!(strict_accepts("application/json; q=0.5"));
+        assert!(strict_accepts("application/json;q=1.000"));
+        assert!(!strict_accepts("application/json; q=0"));
+        assert!(!strict_accepts("application/json; q=0.000, */*"));
+        assert!(!strict_accepts("application/*; q=0, */*"));
+        assert!(strict_accepts("application/json, application/*; q=0"));
+        assert!(strict_accepts("*/*; q=0, application/json; q=0.001"));
+        // Invalid quality values invalidate the media range
+        assert!(strict_accepts("application/json; q=30, */*"));
+        assert!(!strict_accepts("application/json; q=30, text/plain"));
+        assert!(!strict_accepts("application/json; q=0.0001, text/plain"));
+    }
+
+    #[test]
+    fn strict_accept_header_classifier_ignores_malformed_headers() {
+        assert!(strict_accepts("invalid"));
+        assert!(strict_accepts(" , "));
+        assert!(strict_accepts(r#"text/plain; foo="a, b\", q=0", application/json"#));
+        assert!(!strict_accepts(r#"text/plain; foo="a, application/json", text/html"#));
+    }
 }
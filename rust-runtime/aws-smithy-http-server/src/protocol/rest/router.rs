@@ -33,9 +33,23 @@ pub enum Error {
 #[derive(Debug, Clone)]
 pub struct RestRouter<S> {
     routes: Vec<(RequestSpec, S)>,
+    fallback: Option<S>,
 }
 
 impl<S> RestRouter<S> {
+    /// Registers a fallback route, used for requests that do not match any operation.
+    ///
+    /// The fallback only engages once every operation route has been tried and none of them
+    /// matched the request's path; requests to a modeled path with a disallowed method are still
+    /// rejected with [`Error::MethodNotAllowed`]. Without a fallback, unmatched requests are
+    /// rejected with the protocol's [`Error::NotFound`] response.
+    pub fn fallback(self, fallback: S) -> Self {
+        Self {
+            fallback: Some(fallback),
+            ..self
+        }
+    }
+
     /// Applies a [`Layer`] uniformly to all routes.
     pub fn layer<L>(self, layer: L) -> RestRouter<L::Service>
     where
@@ -47,6 +61,7 @@ impl<S> RestRouter<S> {
                 .into_iter()
                 .map(|(request_spec, route)| (request_spec, layer.layer(route)))
                 .collect(),
+            fallback: self.fallback.map(|fallback| layer.layer(fallback)),
         }
     }
 
@@ -59,24 +74,17 @@ impl<S> RestRouter<S> {
     {
         RestRouter {
             routes: self.routes.into_iter().map(|(spec, s)| (spec, Route::new(s))).collect(),
+            fallback: self.fallback.map(Route::new),
         }
     }
-}
 
-impl<B, S> Router<B> for RestRouter<S>
-where
-    S: Clone,
-{
-    type Service = S;
-    type Error = Error;
-
-    fn match_route(&self, request: &http::Request<B>) -> Result<S, Self::Error> {
+    fn match_operation<B>(&self, request: &http::Request<B>) -> Result<&S, Error> {
         let mut method_allowed = true;
 
         for (request_spec, route) in &self.routes {
             match request_spec.matches(request) {
                 // Match found.
-                Match::Yes => return Ok(route.clone()),
+                Match::Yes => return Ok(route),
                 // Match found, but method disallowed.
                 Match::MethodNotAllowed => method_allowed = false,
                 // Continue looping to see if another route matches.
@@ -92,6 +100,23 @@ where
     }
 }
 
+impl<B, S> Router<B> for RestRouter<S>
+where
+    S: Clone,
+{
+    type Service = S;
+    type Error = Error;
+
+    fn match_route(&self, request: &http::Request<B>) -> Result<S, Self::Error> {
+        match self.match_operation(request) {
+            Ok(route) => Ok(route.clone()),
+            // No operation matched, use the fallback if one was registered.
+            Err(Error::NotFound) => self.fallback.clone().ok_or(Error::NotFound),
+            Err(error) => Err(error),
+        }
+    }
+}
+
 impl<S> FromIterator<(RequestSpec, S)> for RestRouter<S> {
     #[inline]
     fn from_iter<T: IntoIterator<Item = (RequestSpec, S)>>(iter: T) -> Self {
@@ -102,7 +127,7 @@ impl<S> FromIterator<(RequestSpec, S)> for RestRouter<S> {
         // and pick the first one that matches.
         routes.sort_by_key(|(request_spec, _route)| std::cmp::Reverse(request_spec.rank()));
 
-        Self { routes }
+        Self { routes, fallback: None }
     }
 }
 
@@ -261,4 +286,32 @@ mod tests {
             assert_eq!(router.match_route(&req(&method, uri, None)).unwrap(), svc_name);
         }
     }
+
+    #[test]
+    fn fallback_routing() {
+        let request_specs: Vec<(RequestSpec, &'static str)> = vec![(
+            RequestSpec::from_parts(
+                Method::GET,
+                vec![PathSegment::Literal(String::from("a")), PathSegment::Label],
+                Vec::new(),
+            ),
+            "A",
+        )];
+
+        let router: RestRouter<_> = request_specs.into_iter().collect();
+        let res = router.match_route(&req(&Method::GET, "/static/index.html", None));
+        assert_eq!(res.unwrap_err(), Error::NotFound);
+
+        let router = router.fallback("Fallback");
+
+        // Operations take precedence over the fallback.
+        assert_eq!(router.match_route(&req(&Method::GET, "/a/b", None)).unwrap(), "A");
+        // A modeled path with a disallowed method does not reach the fallback.
+        let res = router.match_route(&req(&Method::POST, "/a/b", None));
+        assert_eq!(res.unwrap_err(), Error::MethodNotAllowed);
+
+        for uri in ["/static/index.html", "/static/js/app.js", "/a", "/"] {
+            assert_eq!(router.match_route(&req(&Method::GET, uri, None)).unwrap(), "Fallback");
+        }
+    }
 }
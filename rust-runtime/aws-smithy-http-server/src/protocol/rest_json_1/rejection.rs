@@ -47,7 +47,7 @@
 //!
 //! Consult `crate::protocol::$protocolName::rejection` for rejection types for other protocols.
 
-use crate::rejection::MissingContentTypeReason;
+use crate::rejection::{MissingContentTypeReason, StreamingPayloadLengthReason};
 use aws_smithy_runtime_api::http::HttpError;
 use std::num::TryFromIntError;
 use thiserror::Error;
@@ -121,6 +121,12 @@ pub enum RequestRejection {
     #[error("expected `Content-Type` header not found: {0}")]
     MissingContentType(#[from] MissingContentTypeReason),
 
+    /// Used when the `Content-Length` of a streaming payload is missing or exceeds the modeled maximum length.
+    /// This is bubbled up in the generated SDK when calling
+    /// [`crate::protocol::streaming_payload_length_classifier`] in `from_request`.
+    #[error("streaming payload length check failed: {0}")]
+    StreamingPayloadLength(#[from] StreamingPayloadLengthReason),
+
     /// Used when failing to deserialize the HTTP body's bytes into a JSON document conforming to
     /// the modeled input it should represent.
     #[error("error deserializing request HTTP body as JSON: {0}")]
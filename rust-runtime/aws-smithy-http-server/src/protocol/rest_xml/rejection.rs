@@ -7,7 +7,7 @@
 //! [`crate::protocol::rest_json_1::rejection::RequestRejection::JsonDeserialize`] is swapped for
 //! [`RequestRejection::XmlDeserialize`].
 
-use crate::rejection::MissingContentTypeReason;
+use crate::rejection::{MissingContentTypeReason, StreamingPayloadLengthReason};
 use aws_smithy_runtime_api::http::HttpError;
 use std::num::TryFromIntError;
 use thiserror::Error;
@@ -35,6 +35,12 @@ pub enum RequestRejection {
     #[error("expected `Content-Type` header not found: {0}")]
     MissingContentType(#[from] MissingContentTypeReason),
 
+    /// Used when the `Content-Length` of a streaming payload is missing or exceeds the modeled maximum length.
+    /// This is bubbled up in the generated SDK when calling
+    /// [`crate::protocol::streaming_payload_length_classifier`] in `from_request`.
+    #[error("streaming payload length check failed: {0}")]
+    StreamingPayloadLength(#[from] StreamingPayloadLengthReason),
+
     /// Used when failing to deserialize the HTTP body's bytes into a XML conforming to the modeled
     /// input it should represent.
     #[error("error deserializing request HTTP body as XML: {0}")]
@@ -52,6 +52,7 @@ pub enum Error {
 #[derive(Debug, Clone)]
 pub struct RpcV2CborRouter<S> {
     routes: TinyMap<&'static str, S, ROUTE_CUTOFF>,
+    fallback: Option<S>,
 }
 
 /// Requests for the `rpcv2Cbor` protocol MUST NOT contain an `x-amz-target` or `x-amzn-target`
@@ -100,6 +101,19 @@ impl<S> RpcV2CborRouter<S> {
         &SMITHY_PROTOCOL_REGEX
     }
 
+    /// Registers a fallback route, used for requests that do not match any operation.
+    ///
+    /// The fallback only engages once the request could not be routed to an operation, for
+    /// example because it is not a `POST` or its URI is not of the form
+    /// `{prefix?}/service/{serviceName}/operation/{operationName}`. Without a fallback, such
+    /// requests are rejected with the protocol's routing [`Error`] response.
+    pub fn fallback(self, fallback: S) -> Self {
+        Self {
+            fallback: Some(fallback),
+            ..self
+        }
+    }
+
     pub fn boxed<B>(self) -> RpcV2CborRouter<Route<B>>
     where
         S: Service<http::Request<B>, Response = http::Response<BoxBody>, Error = Infallible>,
@@ -108,6 +122,7 @@ impl<S> RpcV2CborRouter<S> {
     {
         RpcV2CborRouter {
             routes: self.routes.into_iter().map(|(key, s)| (key, Route::new(s))).collect(),
+            fallback: self.fallback.map(Route::new),
         }
     }
 
@@ -122,7 +137,40 @@ impl<S> RpcV2CborRouter<S> {
                 .into_iter()
                 .map(|(key, route)| (key, layer.layer(route)))
                 .collect(),
+            fallback: self.fallback.map(|fallback| layer.layer(fallback)),
+        }
+    }
+
+    fn match_operation<B>(&self, request: &http::Request<B>) -> Result<&S, Error> {
+        // Only `Method::POST` is allowed.
+        if request.method() != http::Method::POST {
+            return Err(Error::MethodNotAllowed);
+        }
+
+        // Some headers are not allowed.
+        let request_has_forbidden_header = FORBIDDEN_HEADERS
+            .iter()
+            .any(|&forbidden_header| request.headers().contains_key(forbidden_header));
+        if request_has_forbidden_header {
+            return Err(Error::ForbiddenHeaders);
         }
+
+        // Wire format has to be specified and supported.
+        let _wire_format = parse_wire_format_from_header(request.headers())?;
+
+        // Extract the service name and the operation name from the request URI.
+        let request_path = request.uri().path();
+        let regex = Self::uri_path_regex();
+
+        tracing::trace!(%request_path, "capturing service and operation from URI");
+        let captures = regex.captures(request_path).ok_or(Error::NotFound)?;
+        let (service, operation) = (&captures["service"], &captures["operation"]);
+        tracing::trace!(%service, %operation, "captured service and operation from URI");
+
+        // Lookup in the `TinyMap` for a route for the target.
+        self.routes
+            .get((format!("{service}.{operation}")).as_str())
+            .ok_or(Error::NotFound)
     }
 }
 
@@ -206,37 +254,10 @@ impl<S: Clone, B> Router<B> for RpcV2CborRouter<S> {
     type Error = Error;
 
     fn match_route(&self, request: &http::Request<B>) -> Result<Self::Service, Self::Error> {
-        // Only `Method::POST` is allowed.
-        if request.method() != http::Method::POST {
-            return Err(Error::MethodNotAllowed);
-        }
-
-        // Some headers are not allowed.
-        let request_has_forbidden_header = FORBIDDEN_HEADERS
-            .iter()
-            .any(|&forbidden_header| request.headers().contains_key(forbidden_header));
-        if request_has_forbidden_header {
-            return Err(Error::ForbiddenHeaders);
-        }
-
-        // Wire format has to be specified and supported.
-        let _wire_format = parse_wire_format_from_header(request.headers())?;
-
-        // Extract the service name and the operation name from the request URI.
-        let request_path = request.uri().path();
-        let regex = Self::uri_path_regex();
-
-        tracing::trace!(%request_path, "capturing service and operation from URI");
-        let captures = regex.captures(request_path).ok_or(Error::NotFound)?;
-        let (service, operation) = (&captures["service"], &captures["operation"]);
-        tracing::trace!(%service, %operation, "captured service and operation from URI");
-
-        // Lookup in the `TinyMap` for a route for the target.
-        let route = self
-            .routes
-            .get((format!("{service}.{operation}")).as_str())
-            .ok_or(Error::NotFound)?;
-        Ok(route.clone())
+        self.match_operation(request)
+            .cloned()
+            // No operation matched, use the fallback if one was registered.
+            .or_else(|error| self.fallback.clone().ok_or(error))
     }
 }
 
@@ -245,6 +266,7 @@ impl<S> FromIterator<(&'static str, S)> for RpcV2CborRouter<S> {
     fn from_iter<T: IntoIterator<Item = (&'static str, S)>>(iter: T) -> Self {
         Self {
             routes: iter.into_iter().collect(),
+            fallback: None,
         }
     }
 }
@@ -20,6 +20,17 @@ pub enum MissingContentTypeReason {
     },
 }
 
+// This is used across different protocol-specific `rejection` modules.
+#[derive(Debug, Error)]
+pub enum StreamingPayloadLengthReason {
+    #[error("`Content-Length` header is required for this streaming payload")]
+    MissingContentLength,
+    #[error("invalid `Content-Length` header value")]
+    InvalidContentLength,
+    #[error("streaming payload exceeds the maximum length of {max_length} bytes")]
+    TooLarge { max_length: u64 },
+}
+
 pub mod any_rejections {
     //! This module hosts enums, up to size 8, which implement [`IntoResponse`] when their variants implement
     //! [`IntoResponse`].
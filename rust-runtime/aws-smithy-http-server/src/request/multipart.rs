@@ -0,0 +1,369 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! # Multipart requests
+//!
+//! `aws-smithy-http-server` can stream the parts of a `multipart/form-data` request body.
+//!
+//! Operations marked with the `smithy.rust#multipart` trait bind their streaming `@httpPayload` member to the
+//! raw multipart body, after the framework has checked that the request has a `multipart/form-data` content type.
+//! Your handler takes the [`MultipartBoundary`] extractor alongside its input, and uses it to read the payload as a
+//! [`Multipart`] body, one [`Part`] at a time:
+//!
+//! ```rust,ignore
+//! use aws_smithy_http_server::request::multipart::{Multipart, MultipartBoundary, MultipartLimits};
+//!
+//! pub async fn upload(input: UploadInput, boundary: MultipartBoundary) -> Result<UploadOutput, UploadError> {
+//!     let limits = MultipartLimits::new()
+//!         .max_parts(16)
+//!         .max_part_size(8 * 1024 * 1024);
+//!     let mut multipart = Multipart::new(input.body, boundary, limits);
+//!     while let Some(mut part) = multipart.next_part().await? {
+//!         let name = part.name().map(ToOwned::to_owned);
+//!         while let Some(chunk) = part.chunk().await? {
+//!             /* Stream `chunk` somewhere */
+//!         }
+//!     }
+//!     todo!()
+//! }
+//! ```
+
+use std::fmt;
+
+use aws_smithy_types::byte_stream::ByteStream;
+use bytes::Bytes;
+use http::request::Parts;
+use http::StatusCode;
+use thiserror::Error;
+
+use crate::{
+    body::{empty, BoxBody},
+    request::FromParts,
+    response::IntoResponse,
+};
+
+/// The boundary delimiting the parts of a `multipart/form-data` request body, as found in the request's
+/// `content-type` header.
+///
+/// If the `content-type` header is missing or has no boundary, the request will be rejected with a
+/// `400 Bad Request` response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultipartBoundary(String);
+
+impl MultipartBoundary {
+    /// Parses the boundary from the value of a `content-type` header.
+    pub fn from_content_type(content_type: &str) -> Result<Self, MissingMultipartBoundary> {
+        multer::parse_boundary(content_type)
+            .map(Self)
+            .map_err(|_| MissingMultipartBoundary)
+    }
+
+    /// Returns the boundary.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The request's `content-type` header is missing or has no multipart boundary.
+#[non_exhaustive]
+#[derive(Debug, Error)]
+#[error("the `content-type` header does not specify a multipart boundary")]
+pub struct MissingMultipartBoundary;
+
+impl<Protocol> IntoResponse<Protocol> for MissingMultipartBoundary {
+    fn into_response(self) -> http::Response<BoxBody> {
+        let mut response = http::Response::new(empty());
+        *response.status_mut() = StatusCode::BAD_REQUEST;
+        response
+    }
+}
+
+impl<Protocol> FromParts<Protocol> for MultipartBoundary {
+    type Rejection = MissingMultipartBoundary;
+
+    fn from_parts(parts: &mut Parts) -> Result<Self, Self::Rejection> {
+        let content_type = parts
+            .headers
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(MissingMultipartBoundary)?;
+        Self::from_content_type(content_type)
+    }
+}
+
+/// Limits applied while reading a [`Multipart`] body.
+///
+/// No limits are applied by default.
+#[derive(Clone, Debug, Default)]
+pub struct MultipartLimits {
+    max_parts: Option<usize>,
+    max_part_size: Option<u64>,
+    max_body_size: Option<u64>,
+}
+
+impl MultipartLimits {
+    /// Creates a new `MultipartLimits` without any limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of parts in the body.
+    pub fn max_parts(mut self, max_parts: usize) -> Self {
+        self.max_parts = Some(max_parts);
+        self
+    }
+
+    /// Sets the maximum size, in bytes, of the data of a single part.
+    pub fn max_part_size(mut self, max_part_size: u64) -> Self {
+        self.max_part_size = Some(max_part_size);
+        self
+    }
+
+    /// Sets the maximum size, in bytes, of the whole body.
+    pub fn max_body_size(mut self, max_body_size: u64) -> Self {
+        self.max_body_size = Some(max_body_size);
+        self
+    }
+
+    fn constraints(&self) -> multer::Constraints {
+        let mut size_limit = multer::SizeLimit::new();
+        if let Some(max_part_size) = self.max_part_size {
+            size_limit = size_limit.per_field(max_part_size);
+        }
+        if let Some(max_body_size) = self.max_body_size {
+            size_limit = size_limit.whole_stream(max_body_size);
+        }
+        multer::Constraints::new().size_limit(size_limit)
+    }
+}
+
+/// A streaming `multipart/form-data` body.
+///
+/// Parts are read in order with [`Multipart::next_part`]. A [`Part`] must be dropped before the next one is read.
+pub struct Multipart {
+    inner: multer::Multipart<'static>,
+    max_parts: Option<usize>,
+    part_count: usize,
+}
+
+impl fmt::Debug for Multipart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Multipart")
+            .field("max_parts", &self.max_parts)
+            .field("part_count", &self.part_count)
+            .finish()
+    }
+}
+
+impl Multipart {
+    /// Creates a new `Multipart` that reads the parts of `body`, delimited by `boundary`.
+    pub fn new(body: ByteStream, boundary: MultipartBoundary, limits: MultipartLimits) -> Self {
+        let stream =
+            futures_util::stream::unfold(
+                body,
+                |mut body| async move { body.next().await.map(|chunk| (chunk, body)) },
+            );
+        Self {
+            inner: multer::Multipart::with_constraints(stream, boundary.0, limits.constraints()),
+            max_parts: limits.max_parts,
+            part_count: 0,
+        }
+    }
+
+    /// Returns the next part of the body, or `None` once all the parts have been read.
+    pub async fn next_part(&mut self) -> Result<Option<Part>, MultipartError> {
+        let Some(field) = self.inner.next_field().await.map_err(MultipartError::from)? else {
+            return Ok(None);
+        };
+        self.part_count += 1;
+        if let Some(max_parts) = self.max_parts {
+            if self.part_count > max_parts {
+                return Err(MultipartError::TooManyParts { limit: max_parts });
+            }
+        }
+        Ok(Some(Part { inner: field }))
+    }
+}
+
+/// A single part of a [`Multipart`] body.
+pub struct Part {
+    inner: multer::Field<'static>,
+}
+
+impl fmt::Debug for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Part")
+            .field("name", &self.name())
+            .field("file_name", &self.file_name())
+            .field("content_type", &self.content_type())
+            .finish()
+    }
+}
+
+impl Part {
+    /// Returns the name of the part, from its `content-disposition` header.
+    pub fn name(&self) -> Option<&str> {
+        self.inner.name()
+    }
+
+    /// Returns the file name of the part, from its `content-disposition` header.
+    pub fn file_name(&self) -> Option<&str> {
+        self.inner.file_name()
+    }
+
+    /// Returns the content type of the part, from its `content-type` header.
+    pub fn content_type(&self) -> Option<&mime::Mime> {
+        self.inner.content_type()
+    }
+
+    /// Returns the headers of the part.
+    pub fn headers(&self) -> &http::HeaderMap {
+        self.inner.headers()
+    }
+
+    /// Returns the next chunk of the part's data, or `None` once all of it has been read.
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>, MultipartError> {
+        self.inner.chunk().await.map_err(MultipartError::from)
+    }
+
+    /// Reads all of the part's data into memory.
+    pub async fn bytes(self) -> Result<Bytes, MultipartError> {
+        self.inner.bytes().await.map_err(MultipartError::from)
+    }
+}
+
+/// An error encountered while reading a [`Multipart`] body.
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum MultipartError {
+    /// The body has more parts than allowed by [`MultipartLimits::max_parts`].
+    #[error("the multipart body has more than {limit} parts")]
+    TooManyParts {
+        /// The maximum number of parts.
+        limit: usize,
+    },
+    /// A part is larger than allowed by [`MultipartLimits::max_part_size`].
+    #[error("a part of the multipart body exceeds the size limit of {limit} bytes")]
+    PartTooLarge {
+        /// The maximum size of a part, in bytes.
+        limit: u64,
+    },
+    /// The body is larger than allowed by [`MultipartLimits::max_body_size`].
+    #[error("the multipart body exceeds the size limit of {limit} bytes")]
+    BodyTooLarge {
+        /// The maximum size of the body, in bytes.
+        limit: u64,
+    },
+    /// The body could not be read or is not a valid `multipart/form-data` body.
+    #[error("failed to read the multipart body")]
+    Malformed(#[source] Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl From<multer::Error> for MultipartError {
+    fn from(err: multer::Error) -> Self {
+        match err {
+            multer::Error::FieldSizeExceeded { limit, .. } => Self::PartTooLarge { limit },
+            multer::Error::StreamSizeExceeded { limit } => Self::BodyTooLarge { limit },
+            // Size limits on the whole body are reported as a failure to read the underlying stream
+            multer::Error::StreamReadFailed(source) => match source.downcast::<multer::Error>() {
+                Ok(err) => Self::from(*err),
+                Err(source) => Self::Malformed(source),
+            },
+            other => Self::Malformed(other.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT_TYPE: &str = "multipart/form-data; boundary=X-BOUNDARY";
+
+    fn body() -> ByteStream {
+        ByteStream::from_static(
+            b"--X-BOUNDARY\r\n\
+            Content-Disposition: form-data; name=\"description\"\r\n\
+            \r\n\
+            a cat picture\r\n\
+            --X-BOUNDARY\r\n\
+            Content-Disposition: form-data; name=\"picture\"; filename=\"cat.png\"\r\n\
+            Content-Type: image/png\r\n\
+            \r\n\
+            not really a png\r\n\
+            --X-BOUNDARY--\r\n",
+        )
+    }
+
+    fn new_multipart(limits: MultipartLimits) -> Multipart {
+        let boundary = MultipartBoundary::from_content_type(CONTENT_TYPE).unwrap();
+        Multipart::new(body(), boundary, limits)
+    }
+
+    #[tokio::test]
+    async fn reads_parts() {
+        let mut multipart = new_multipart(MultipartLimits::new());
+
+        let part = multipart.next_part().await.unwrap().unwrap();
+        assert_eq!(Some("description"), part.name());
+        assert_eq!(None, part.file_name());
+        assert_eq!(&b"a cat picture"[..], part.bytes().await.unwrap());
+
+        let mut part = multipart.next_part().await.unwrap().unwrap();
+        assert_eq!(Some("picture"), part.name());
+        assert_eq!(Some("cat.png"), part.file_name());
+        assert_eq!(Some(&mime::IMAGE_PNG), part.content_type());
+        let mut data = Vec::new();
+        while let Some(chunk) = part.chunk().await.unwrap() {
+            data.extend_from_slice(&chunk);
+        }
+        assert_eq!(b"not really a png", &data[..]);
+        drop(part);
+
+        assert!(multipart.next_part().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn enforces_limits() {
+        let mut multipart = new_multipart(MultipartLimits::new().max_parts(1));
+        multipart.next_part().await.unwrap().unwrap();
+        let err = multipart.next_part().await.unwrap_err();
+        assert!(matches!(err, MultipartError::TooManyParts { limit: 1 }), "{err:?}");
+
+        let mut multipart = new_multipart(MultipartLimits::new().max_part_size(4));
+        let part = multipart.next_part().await.unwrap().unwrap();
+        let err = part.bytes().await.unwrap_err();
+        assert!(matches!(err, MultipartError::PartTooLarge { limit: 4 }), "{err:?}");
+
+        let mut multipart = new_multipart(MultipartLimits::new().max_body_size(16));
+        let err = async {
+            while let Some(part) = multipart.next_part().await? {
+                part.bytes().await?;
+            }
+            Ok::<_, MultipartError>(())
+        }
+        .await
+        .unwrap_err();
+        assert!(matches!(err, MultipartError::BodyTooLarge { limit: 16 }), "{err:?}");
+    }
+
+    #[test]
+    fn boundary_from_parts() {
+        let (mut parts, _) = http::Request::builder()
+            .header(http::header::CONTENT_TYPE, CONTENT_TYPE)
+            .body(())
+            .unwrap()
+            .into_parts();
+        let boundary = <MultipartBoundary as FromParts<()>>::from_parts(&mut parts).unwrap();
+        assert_eq!("X-BOUNDARY", boundary.as_str());
+
+        let (mut parts, _) = http::Request::builder()
+            .header(http::header::CONTENT_TYPE, "multipart/form-data")
+            .body(())
+            .unwrap()
+            .into_parts();
+        assert!(<MultipartBoundary as FromParts<()>>::from_parts(&mut parts).is_err());
+    }
+}
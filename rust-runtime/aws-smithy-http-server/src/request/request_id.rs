@@ -17,6 +17,9 @@
 //! The [`ServerRequestId`] can be returned to the caller, who can in turn share the [`ServerRequestId`] to help the service owner in troubleshooting issues related to their usage of the service.
 //! Use [`ServerRequestIdProviderLayer::new_with_response_header`] to use [`ServerRequestId`] in your handler and add it to the response headers.
 //!
+//! The [`ServerRequestId`] is recorded as the `request_id` field of the span opened by the operation
+//! [instrumentation](crate::instrumentation), so all the logs emitted while handling a request can be correlated.
+//!
 //! The [`ServerRequestId`] is not meant to be propagated to downstream dependencies of the service. You should rely on a distributed tracing implementation for correlation purposes (e.g. OpenTelemetry).
 //!
 //! ## Examples
@@ -232,7 +235,9 @@ where
 mod tests {
     use super::*;
     use crate::body::{Body, BoxBody};
+    use crate::instrumentation::InstrumentOperation;
     use crate::request::Request;
+    use crate::shape_id::ShapeId;
     use http::HeaderValue;
     use std::convert::Infallible;
     use tower::{service_fn, ServiceBuilder, ServiceExt};
@@ -260,6 +265,25 @@ mod tests {
         assert!(HeaderValue::from_str(request_id).is_ok());
     }
 
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_request_id_recorded_in_operation_span() {
+        const ID: ShapeId = ShapeId::new("namespace#Operation", "namespace", "Operation");
+        let svc = ServiceBuilder::new()
+            .layer(&ServerRequestIdProviderLayer::new_with_response_header(
+                HeaderName::from_static("x-request-id"),
+            ))
+            .service(InstrumentOperation::new(
+                service_fn(|_req: Request<Body>| async move { Ok::<_, Infallible>(Response::new(BoxBody::default())) }),
+                ID,
+            ));
+
+        let res = svc.oneshot(Request::new(Body::empty())).await.unwrap();
+        let request_id = res.headers().get("x-request-id").unwrap().to_str().unwrap();
+
+        assert!(logs_contain(&format!("request_id={request_id}")));
+    }
+
     #[tokio::test]
     async fn test_request_id_not_in_response_header() {
         let svc = ServiceBuilder::new()
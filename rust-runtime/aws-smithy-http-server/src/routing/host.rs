@@ -0,0 +1,274 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::{
+    convert::Infallible,
+    fmt,
+    task::{Context, Poll},
+};
+
+use http::{Request, Response, StatusCode};
+use tower::Service;
+
+use crate::body::{empty, Body, BoxBody};
+
+use super::{
+    request_host, IntoMakeService, IntoMakeServiceWithConnectInfo, IntoMakeServiceWithShutdown, Route, RoutingFuture,
+};
+
+/// A host name pattern: either an exact host, or a wildcard matching any subdomain of a host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum HostPattern {
+    /// `api.example.com`
+    Exact(String),
+    /// `*.example.com`, stored as `.example.com`
+    Wildcard(String),
+}
+
+impl HostPattern {
+    fn parse(pattern: &str) -> Self {
+        let pattern = pattern.to_ascii_lowercase();
+        let (wildcard, host) = match pattern.strip_prefix('*') {
+            Some(suffix) if suffix.starts_with('.') => (true, &suffix[1..]),
+            Some(_) => panic!("invalid host pattern `{pattern}`: a wildcard must be followed by `.`"),
+            None => (false, pattern.as_str()),
+        };
+        if host.is_empty() || host.contains(['*', '/', ':']) {
+            panic!(
+                "invalid host pattern `{pattern}`: expected a host name such as `api.example.com` or `*.example.com`"
+            );
+        }
+        if wildcard {
+            HostPattern::Wildcard(format!(".{host}"))
+        } else {
+            HostPattern::Exact(host.to_owned())
+        }
+    }
+
+    fn matches(&self, host: &str) -> bool {
+        match self {
+            HostPattern::Exact(exact) => host.eq_ignore_ascii_case(exact),
+            HostPattern::Wildcard(suffix) => {
+                host.len() > suffix.len()
+                    && host.is_char_boundary(host.len() - suffix.len())
+                    && host[host.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+            }
+        }
+    }
+}
+
+/// A [`Service`] that dispatches requests to other services by the host they were sent to.
+///
+/// This allows multiple generated services to be served from a single listener, for example in a multi-tenant
+/// deployment. The host is taken from the `Host` header, or the request URI for HTTP/2 requests, and compared
+/// case-insensitively and without the port against each host pattern, in the order they were added. Requests for
+/// hosts that don't match any pattern are sent to the [default service](HostRouter::default_service), or are rejected
+/// with a `404 Not Found` response without a body if there isn't one.
+///
+/// ```rust,ignore
+/// use aws_smithy_http_server::routing::HostRouter;
+///
+/// let app = HostRouter::new()
+///     .merge_with_host("pokemon.example.com", pokemon_service)
+///     .merge_with_host("*.storage.example.com", storage_service);
+///
+/// let server = hyper::Server::bind(&bind).serve(app.into_make_service());
+/// ```
+pub struct HostRouter<B = Body> {
+    routes: Vec<(HostPattern, Route<B>)>,
+    default: Option<Route<B>>,
+}
+
+impl<B> Default for HostRouter<B> {
+    fn default() -> Self {
+        Self {
+            routes: Vec::new(),
+            default: None,
+        }
+    }
+}
+
+impl<B> Clone for HostRouter<B> {
+    fn clone(&self) -> Self {
+        Self {
+            routes: self.routes.clone(),
+            default: self.default.clone(),
+        }
+    }
+}
+
+impl<B> fmt::Debug for HostRouter<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HostRouter")
+            .field(
+                "hosts",
+                &self.routes.iter().map(|(pattern, _)| pattern).collect::<Vec<_>>(),
+            )
+            .field("has_default", &self.default.is_some())
+            .finish()
+    }
+}
+
+impl<B> HostRouter<B> {
+    /// Creates a [`HostRouter`] without any routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends requests for hosts matching `host_pattern` to `service`.
+    ///
+    /// `host_pattern` is either an exact host, like `api.example.com`, or a wildcard matching any of its
+    /// subdomains, like `*.example.com`. Patterns are matched in the order they were added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `host_pattern` is not a valid host pattern.
+    pub fn merge_with_host<S>(mut self, host_pattern: &str, service: S) -> Self
+    where
+        S: Service<Request<B>, Response = Response<BoxBody>, Error = Infallible> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+    {
+        self.routes
+            .push((HostPattern::parse(host_pattern), Route::new(service)));
+        self
+    }
+
+    /// Sends requests for hosts that don't match any pattern to `service`.
+    pub fn default_service<S>(mut self, service: S) -> Self
+    where
+        S: Service<Request<B>, Response = Response<BoxBody>, Error = Infallible> + Clone + Send + 'static,
+        S::Future: Send + 'static,
+    {
+        self.default = Some(Route::new(service));
+        self
+    }
+
+    /// Converts [`HostRouter`] into a [`MakeService`](tower::make::MakeService).
+    pub fn into_make_service(self) -> IntoMakeService<Self> {
+        IntoMakeService::new(self)
+    }
+
+    /// Converts [`HostRouter`] into a [`MakeService`](tower::make::MakeService) with
+    /// [`ConnectInfo`](crate::request::connect_info::ConnectInfo).
+    pub fn into_make_service_with_connect_info<C>(self) -> IntoMakeServiceWithConnectInfo<Self, C> {
+        IntoMakeServiceWithConnectInfo::new(self)
+    }
+
+    /// Converts [`HostRouter`] into a [`MakeService`](tower::make::MakeService) that shuts down gracefully once
+    /// `signal` resolves. See [`IntoMakeServiceWithShutdown`] for more information.
+    pub fn into_make_service_with_shutdown<F>(self, signal: F) -> IntoMakeServiceWithShutdown<Self>
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        IntoMakeServiceWithShutdown::new(self, signal)
+    }
+
+    fn match_host(&self, request: &Request<B>) -> Option<&Route<B>> {
+        let route = request_host(request).and_then(|host| {
+            self.routes
+                .iter()
+                .find(|(pattern, _)| pattern.matches(host))
+                .map(|(_, route)| route)
+        });
+        route.or(self.default.as_ref())
+    }
+}
+
+impl<B> Service<Request<B>> for HostRouter<B>
+where
+    B: Send + 'static,
+{
+    type Response = Response<BoxBody>;
+    type Error = Infallible;
+    type Future = RoutingFuture<Route<B>, B>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        match self.match_host(&req) {
+            Some(route) => RoutingFuture::from_oneshot(tower::ServiceExt::oneshot(route.clone(), req)),
+            None => {
+                tracing::debug!(host = ?request_host(&req), "no service matches the request host");
+                let mut response = Response::new(empty());
+                *response.status_mut() = StatusCode::NOT_FOUND;
+                RoutingFuture::from_response(response)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use tower::{service_fn, ServiceExt};
+
+    use super::*;
+    use crate::body::{boxed, Body};
+    use crate::protocol::test_helpers::get_body_as_string;
+
+    fn named(name: &'static str) -> Route<Body> {
+        Route::new(service_fn(move |_: Request<Body>| async move {
+            Ok::<_, Infallible>(Response::new(boxed(Body::from(name))))
+        }))
+    }
+
+    async fn call(router: &HostRouter, host: Option<&str>, uri: &str) -> (StatusCode, String) {
+        let mut request = Request::builder().uri(uri);
+        if let Some(host) = host {
+            request = request.header(http::header::HOST, host);
+        }
+        let response = router
+            .clone()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        (status, get_body_as_string(response.into_body()).await)
+    }
+
+    #[tokio::test]
+    async fn routes_by_host() {
+        let router = HostRouter::new()
+            .merge_with_host("pokemon.example.com", named("pokemon"))
+            .merge_with_host("*.storage.example.com", named("storage"));
+
+        let cases = [
+            (Some("pokemon.example.com"), "/", "pokemon"),
+            (Some("POKEMON.example.com:8080"), "/", "pokemon"),
+            (Some("tenant.storage.example.com"), "/", "storage"),
+            (None, "http://a.b.storage.example.com/object", "storage"),
+        ];
+        for (host, uri, expected) in cases {
+            assert_eq!((StatusCode::OK, expected.to_owned()), call(&router, host, uri).await);
+        }
+
+        for host in ["storage.example.com", "example.com", "pokemon.example.com.evil"] {
+            assert_eq!(StatusCode::NOT_FOUND, call(&router, Some(host), "/").await.0, "{host}");
+        }
+        assert_eq!(StatusCode::NOT_FOUND, call(&router, None, "/").await.0);
+    }
+
+    #[tokio::test]
+    async fn unmatched_hosts_use_the_default_service() {
+        let router = HostRouter::new()
+            .merge_with_host("pokemon.example.com", named("pokemon"))
+            .default_service(named("default"));
+
+        assert_eq!(
+            (StatusCode::OK, "default".to_owned()),
+            call(&router, Some("other.example.com"), "/").await
+        );
+        assert_eq!((StatusCode::OK, "default".to_owned()), call(&router, None, "/").await);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid host pattern")]
+    fn invalid_host_patterns_panic() {
+        let _ = HostRouter::<Body>::new().merge_with_host("api.*.example.com", named("api"));
+    }
+}
@@ -0,0 +1,444 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! The [`IntoMakeServiceWithShutdown`] is a service factory which lets a server shut down gracefully.
+
+use std::{
+    fmt,
+    future::{ready, Future},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use pin_project_lite::pin_project;
+use thiserror::Error;
+use tokio::sync::Notify;
+use tower::Service;
+
+/// How long [`GracefulShutdown::drain`] waits for in-flight operations by default.
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+type Signal = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Whether the server is shutting down, and how many operations are still in flight.
+///
+/// [`IntoMakeServiceWithShutdown`] inserts the [`ShutdownState`] into every [`http::Request`], so handlers can
+/// extract it with [`Extension<ShutdownState>`](crate::Extension), for example to stop long-running work early.
+#[derive(Clone)]
+pub struct ShutdownState {
+    inner: Arc<State>,
+}
+
+struct State {
+    shutting_down: AtomicBool,
+    started: Notify,
+    in_flight: AtomicUsize,
+}
+
+impl ShutdownState {
+    fn new() -> Self {
+        Self {
+            inner: Arc::new(State {
+                shutting_down: AtomicBool::new(false),
+                started: Notify::new(),
+                in_flight: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// Returns `true` once the server has started shutting down.
+    pub fn is_shutting_down(&self) -> bool {
+        self.inner.shutting_down.load(Ordering::Acquire)
+    }
+
+    /// Waits until the server starts shutting down.
+    pub async fn shutting_down(&self) {
+        // `Notified` futures receive `notify_waiters` wakeups from the moment they are created, so shutdown can't
+        // begin unnoticed between the check and the `await`.
+        let started = self.inner.started.notified();
+        if self.is_shutting_down() {
+            return;
+        }
+        started.await
+    }
+
+    /// Returns the number of operations currently in flight.
+    pub fn in_flight(&self) -> usize {
+        self.inner.in_flight.load(Ordering::Acquire)
+    }
+
+    fn begin(&self) {
+        if !self.inner.shutting_down.swap(true, Ordering::AcqRel) {
+            self.inner.started.notify_waiters();
+        }
+    }
+}
+
+impl fmt::Debug for ShutdownState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShutdownState")
+            .field("shutting_down", &self.is_shutting_down())
+            .field("in_flight", &self.in_flight())
+            .finish()
+    }
+}
+
+/// The server did not finish draining within the drain timeout.
+#[derive(Debug, Error)]
+#[error("server did not drain within {timeout:?}: {in_flight} operation(s) still in flight")]
+pub struct DrainTimeout {
+    timeout: Duration,
+    in_flight: usize,
+}
+
+impl DrainTimeout {
+    /// Returns the number of operations that were still in flight when the drain timeout elapsed.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight
+    }
+}
+
+/// A handle used to coordinate the graceful shutdown of a server built from an [`IntoMakeServiceWithShutdown`].
+#[derive(Clone)]
+pub struct GracefulShutdown {
+    state: ShutdownState,
+    signal: Arc<Mutex<Option<Signal>>>,
+    drain_timeout: Duration,
+}
+
+impl GracefulShutdown {
+    /// Returns the [`ShutdownState`] shared with handlers.
+    pub fn state(&self) -> ShutdownState {
+        self.state.clone()
+    }
+
+    /// Starts shutting down immediately, without waiting for the shutdown signal.
+    pub fn begin(&self) {
+        self.state.begin()
+    }
+
+    /// Returns a future that resolves once the server should stop accepting new connections.
+    ///
+    /// This is either when the shutdown signal resolves or when [`GracefulShutdown::begin`] is called. Pass it to
+    /// [`hyper::Server::with_graceful_shutdown`].
+    pub fn signal(&self) -> impl Future<Output = ()> + Send + 'static {
+        let signal = self.signal.lock().unwrap().take();
+        let state = self.state.clone();
+        async move {
+            match signal {
+                Some(signal) => tokio::select! {
+                    _ = signal => {}
+                    _ = state.shutting_down() => {}
+                },
+                // The signal was already handed out: wait for whoever is polling it.
+                None => state.shutting_down().await,
+            }
+            state.begin();
+        }
+    }
+
+    /// Drives `server` to completion, giving it at most the drain timeout to finish once shutdown starts.
+    pub async fn drain<F>(self, server: F) -> Result<F::Output, DrainTimeout>
+    where
+        F: Future,
+    {
+        tokio::pin!(server);
+        tokio::select! {
+            output = &mut server => return Ok(output),
+            _ = self.state.shutting_down() => {}
+        }
+        tokio::time::timeout(self.drain_timeout, server).await.map_err(|_| {
+            let in_flight = self.state.in_flight();
+            tracing::warn!(in_flight, timeout = ?self.drain_timeout, "server did not drain in time");
+            DrainTimeout {
+                timeout: self.drain_timeout,
+                in_flight,
+            }
+        })
+    }
+}
+
+impl fmt::Debug for GracefulShutdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GracefulShutdown")
+            .field("state", &self.state)
+            .field("drain_timeout", &self.drain_timeout)
+            .finish()
+    }
+}
+
+/// A [`MakeService`] used to insert [`ShutdownState`] into [`http::Request`]s and to track in-flight operations, so
+/// the server can shut down gracefully.
+///
+/// Once the shutdown signal resolves, the server stops accepting new connections and waits for the operations that
+/// are in flight to complete, up to a drain timeout. Handlers can observe that the server is shutting down by
+/// extracting [`Extension<ShutdownState>`](crate::Extension).
+///
+/// ```rust,no_run
+/// # use std::{net::SocketAddr, time::Duration};
+/// # use aws_smithy_http_server::routing::IntoMakeServiceWithShutdown;
+/// # async fn run<S>(app: S, addr: SocketAddr)
+/// # where
+/// #     S: tower::Service<http::Request<hyper::Body>, Response = http::Response<aws_smithy_http_server::body::BoxBody>, Error = std::convert::Infallible>
+/// #         + Clone + Send + 'static,
+/// #     S::Future: Send + 'static,
+/// # {
+/// let signal = async {
+///     tokio::signal::ctrl_c().await.expect("failed to listen for ctrl-c");
+/// };
+/// let make_app = IntoMakeServiceWithShutdown::new(app, signal).drain_timeout(Duration::from_secs(10));
+/// let shutdown = make_app.shutdown();
+/// let server = hyper::Server::bind(&addr)
+///     .serve(make_app)
+///     .with_graceful_shutdown(shutdown.signal());
+/// if let Err(err) = shutdown.drain(server).await {
+///     eprintln!("{err}");
+/// }
+/// # }
+/// ```
+///
+/// [`MakeService`]: tower::make::MakeService
+pub struct IntoMakeServiceWithShutdown<S> {
+    inner: S,
+    shutdown: GracefulShutdown,
+}
+
+impl<S> IntoMakeServiceWithShutdown<S> {
+    /// Creates an [`IntoMakeServiceWithShutdown`] that starts shutting down when `signal` resolves.
+    pub fn new<F>(svc: S, signal: F) -> Self
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            inner: svc,
+            shutdown: GracefulShutdown {
+                state: ShutdownState::new(),
+                signal: Arc::new(Mutex::new(Some(Box::pin(signal)))),
+                drain_timeout: DEFAULT_DRAIN_TIMEOUT,
+            },
+        }
+    }
+
+    /// Sets how long to wait for in-flight operations once shutdown starts. Defaults to [`DEFAULT_DRAIN_TIMEOUT`].
+    pub fn drain_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown.drain_timeout = timeout;
+        self
+    }
+
+    /// Returns the [`GracefulShutdown`] handle used to shut the server down.
+    pub fn shutdown(&self) -> GracefulShutdown {
+        self.shutdown.clone()
+    }
+}
+
+impl<S> fmt::Debug for IntoMakeServiceWithShutdown<S>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoMakeServiceWithShutdown")
+            .field("inner", &self.inner)
+            .field("shutdown", &self.shutdown)
+            .finish()
+    }
+}
+
+impl<S> Clone for IntoMakeServiceWithShutdown<S>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            shutdown: self.shutdown.clone(),
+        }
+    }
+}
+
+impl<S, T> Service<T> for IntoMakeServiceWithShutdown<S>
+where
+    S: Clone,
+{
+    type Response = ShutdownService<S>;
+    type Error = std::convert::Infallible;
+    type Future = MakeShutdownServiceFuture<S>;
+
+    #[inline]
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _target: T) -> Self::Future {
+        let svc = ShutdownService {
+            inner: self.inner.clone(),
+            state: self.shutdown.state(),
+        };
+        MakeShutdownServiceFuture::new(ready(Ok(svc)))
+    }
+}
+
+opaque_future! {
+    /// Response future for [`IntoMakeServiceWithShutdown`].
+    pub type MakeShutdownServiceFuture<S> =
+        std::future::Ready<Result<ShutdownService<S>, std::convert::Infallible>>;
+}
+
+/// The service [`IntoMakeServiceWithShutdown`] produces for each connection.
+///
+/// Inserts the [`ShutdownState`] into [`http::Request`]s, and counts the operations in flight.
+#[derive(Debug, Clone)]
+pub struct ShutdownService<S> {
+    inner: S,
+    state: ShutdownState,
+}
+
+impl<B, S> Service<http::Request<B>> for ShutdownService<S>
+where
+    S: Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = ShutdownFuture<S::Future>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<B>) -> Self::Future {
+        req.extensions_mut().insert(self.state.clone());
+        let in_flight = InFlight::new(self.state.clone());
+        ShutdownFuture {
+            inner: self.inner.call(req),
+            _in_flight: in_flight,
+        }
+    }
+}
+
+/// Counts an operation as in flight for as long as it's alive.
+struct InFlight(ShutdownState);
+
+impl InFlight {
+    fn new(state: ShutdownState) -> Self {
+        state.inner.in_flight.fetch_add(1, Ordering::AcqRel);
+        Self(state)
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.inner.in_flight.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+pin_project! {
+    /// Response future for [`ShutdownService`].
+    pub struct ShutdownFuture<F> {
+        #[pin]
+        inner: F,
+        _in_flight: InFlight,
+    }
+}
+
+impl<F> fmt::Debug for ShutdownFuture<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShutdownFuture").finish_non_exhaustive()
+    }
+}
+
+impl<F> Future for ShutdownFuture<F>
+where
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::Infallible, time::Duration};
+
+    use tokio::sync::oneshot;
+    use tower::{service_fn, Service, ServiceExt};
+
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::test_helpers::*;
+
+        assert_send::<IntoMakeServiceWithShutdown<()>>();
+        assert_sync::<IntoMakeServiceWithShutdown<()>>();
+        assert_send::<ShutdownService<()>>();
+        assert_sync::<ShutdownService<()>>();
+    }
+
+    #[tokio::test]
+    async fn handlers_observe_shutdown_and_in_flight_operations_are_counted() {
+        let (release, released) = oneshot::channel::<()>();
+        let released = Arc::new(Mutex::new(Some(released)));
+        let handler = service_fn(move |req: http::Request<()>| {
+            let released = released.lock().unwrap().take();
+            async move {
+                let state = req.extensions().get::<ShutdownState>().unwrap().clone();
+                if let Some(released) = released {
+                    released.await.unwrap();
+                }
+                Ok::<_, Infallible>(state.is_shutting_down())
+            }
+        });
+        let mut make_service = IntoMakeServiceWithShutdown::new(handler, std::future::pending());
+        let shutdown = make_service.shutdown();
+        let state = shutdown.state();
+        let svc = make_service.call(()).await.unwrap();
+
+        let in_flight = tokio::spawn(svc.clone().oneshot(http::Request::new(())));
+        tokio::task::yield_now().await;
+        assert_eq!(1, state.in_flight());
+
+        shutdown.begin();
+        shutdown.signal().await;
+        assert!(state.is_shutting_down());
+        release.send(()).unwrap();
+        assert!(in_flight.await.unwrap().unwrap());
+        assert_eq!(0, state.in_flight());
+    }
+
+    #[tokio::test]
+    async fn the_signal_starts_the_shutdown() {
+        let (trigger, signal) = oneshot::channel::<()>();
+        let make_service = IntoMakeServiceWithShutdown::new((), async move {
+            let _ = signal.await;
+        });
+        let shutdown = make_service.shutdown();
+        let hyper_signal = tokio::spawn(shutdown.signal());
+        trigger.send(()).unwrap();
+        hyper_signal.await.unwrap();
+        assert!(shutdown.state().is_shutting_down());
+    }
+
+    #[tokio::test]
+    async fn drain_gives_up_after_the_drain_timeout() {
+        let make_service =
+            IntoMakeServiceWithShutdown::new((), std::future::pending()).drain_timeout(Duration::from_millis(10));
+        let shutdown = make_service.shutdown();
+        let drained = tokio::spawn(shutdown.clone().drain(std::future::pending::<()>()));
+        shutdown.begin();
+        let err = drained.await.unwrap().unwrap_err();
+        assert_eq!(0, err.in_flight());
+
+        let make_service = IntoMakeServiceWithShutdown::new((), std::future::pending());
+        let drained = make_service.shutdown().drain(ready(42)).await.unwrap();
+        assert_eq!(42, drained);
+    }
+}
@@ -7,8 +7,10 @@
 //!
 //! [Smithy specification]: https://smithy.io/2.0/spec/http-bindings.html
 
+mod host;
 mod into_make_service;
 mod into_make_service_with_connect_info;
+mod into_make_service_with_shutdown;
 #[cfg(feature = "aws-lambda")]
 #[cfg_attr(docsrs, doc(cfg(feature = "aws-lambda")))]
 mod lambda_handler;
@@ -50,8 +52,13 @@ pub use self::lambda_handler::LambdaHandler;
 
 #[allow(deprecated)]
 pub use self::{
+    host::HostRouter,
     into_make_service::IntoMakeService,
     into_make_service_with_connect_info::{Connected, IntoMakeServiceWithConnectInfo},
+    into_make_service_with_shutdown::{
+        DrainTimeout, GracefulShutdown, IntoMakeServiceWithShutdown, MakeShutdownServiceFuture, ShutdownFuture,
+        ShutdownService, ShutdownState, DEFAULT_DRAIN_TIMEOUT,
+    },
     route::Route,
 };
 
@@ -64,6 +71,24 @@ pub(crate) fn method_disallowed() -> http::Response<BoxBody> {
     responses
 }
 
+/// Returns the host a request was sent to, without its port.
+///
+/// The `Host` header is used if present, since HTTP/1.1 requests usually have a relative URI; otherwise the host is
+/// taken from the request URI, which is where HTTP/2 requests carry the `:authority` pseudo-header.
+pub(crate) fn request_host<B>(request: &http::Request<B>) -> Option<&str> {
+    let authority = request
+        .headers()
+        .get(http::header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .or_else(|| request.uri().authority().map(|authority| authority.as_str()))?;
+    // Strip the userinfo and port, leaving the host. IPv6 literals keep their brackets.
+    let host = authority.rsplit('@').next().unwrap_or(authority);
+    match host.rfind(':') {
+        Some(colon) if !host[colon..].contains(']') => Some(&host[..colon]),
+        _ => Some(host),
+    }
+}
+
 /// An interface for retrieving an inner [`Service`] given a [`http::Request`].
 pub trait Router<B> {
     type Service;
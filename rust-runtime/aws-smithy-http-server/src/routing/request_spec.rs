@@ -67,14 +67,21 @@ pub struct UriSpec {
 }
 
 impl UriSpec {
-    // TODO(https://github.com/smithy-lang/smithy-rs/issues/950): When we add support for the endpoint
-    // trait, this constructor will take in a first argument `host_prefix`.
     pub fn new(path_and_query: PathAndQuerySpec) -> Self {
         UriSpec {
             host_prefix: None,
             path_and_query,
         }
     }
+
+    /// Creates a `UriSpec` that only matches requests whose host starts with `host_prefix`, as
+    /// modeled with the `@endpoint` trait.
+    pub fn with_host_prefix(host_prefix: Vec<HostPrefixSegment>, path_and_query: PathAndQuerySpec) -> Self {
+        UriSpec {
+            host_prefix: Some(host_prefix),
+            path_and_query,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -82,6 +89,7 @@ pub struct RequestSpec {
     method: http::Method,
     uri_spec: UriSpec,
     uri_path_regex: Regex,
+    host_prefix_regex: Option<Regex>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -119,13 +127,29 @@ impl From<&PathSpec> for Regex {
     }
 }
 
+fn host_prefix_regex(host_prefix: &[HostPrefixSegment]) -> Regex {
+    let re: String = host_prefix
+        .iter()
+        .map(|segment| match segment {
+            HostPrefixSegment::Literal(literal) => Cow::Owned(regex::escape(literal)),
+            // Labels are bound to a single DNS label.
+            HostPrefixSegment::Label => Cow::Borrowed("[^.]+"),
+        })
+        .collect();
+
+    // Host names are case-insensitive.
+    Regex::new(&format!("(?i)^{}", re)).expect("invalid `Regex` from host prefix; please file a bug report under https://github.com/smithy-lang/smithy-rs/issues")
+}
+
 impl RequestSpec {
     pub fn new(method: http::Method, uri_spec: UriSpec) -> Self {
         let uri_path_regex = (&uri_spec.path_and_query.path_segments).into();
+        let host_prefix_regex = uri_spec.host_prefix.as_deref().map(host_prefix_regex);
         RequestSpec {
             method,
             uri_spec,
             uri_path_regex,
+            host_prefix_regex,
         }
     }
 
@@ -162,8 +186,11 @@ impl RequestSpec {
     }
 
     pub(crate) fn matches<B>(&self, req: &Request<B>) -> Match {
-        if let Some(_host_prefix) = &self.uri_spec.host_prefix {
-            todo!("Look at host prefix");
+        if let Some(host_prefix_regex) = &self.host_prefix_regex {
+            match super::request_host(req) {
+                Some(host) if host_prefix_regex.is_match(host) => {}
+                _ => return Match::No,
+            }
         }
 
         if !self.uri_path_regex.is_match(req.uri().path()) {
@@ -505,4 +532,43 @@ mod tests {
             spec.matches(&req(&Method::GET, "/ReDosLiteral/abc/(a+)+", None))
         );
     }
+
+    #[test]
+    fn host_prefixes_must_match() {
+        let spec = RequestSpec::new(
+            Method::GET,
+            UriSpec::with_host_prefix(
+                vec![
+                    HostPrefixSegment::Label,
+                    HostPrefixSegment::Literal(String::from(".data.")),
+                ],
+                PathAndQuerySpec::new(
+                    PathSpec::from_vector_unchecked(vec![PathSegment::Literal(String::from("path"))]),
+                    QuerySpec::default(),
+                ),
+            ),
+        );
+
+        let hits = vec![
+            "http://tenant.data.example.com/path",
+            "http://Tenant.DATA.example.com:8080/path",
+        ];
+        for uri in &hits {
+            assert_eq!(Match::Yes, spec.matches(&req(&Method::GET, uri, None)));
+        }
+
+        let misses = vec![
+            "http://data.example.com/path",
+            "http://tenant.control.example.com/path",
+            "http://tenant.data.example.com/other",
+            "/path",
+        ];
+        for uri in &misses {
+            assert_eq!(Match::No, spec.matches(&req(&Method::GET, uri, None)));
+        }
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert(http::header::HOST, "tenant.data.example.com".parse().unwrap());
+        assert_eq!(Match::Yes, spec.matches(&req(&Method::GET, "/path", Some(headers))));
+    }
 }
@@ -0,0 +1,482 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Serve a service over HTTP/1.1 and HTTP/2.
+//!
+//! [`serve`] runs a [`MakeService`](tower::make::MakeService), such as the one returned by a generated service's
+//! `into_make_service`, on a [`TcpListener`]:
+//!
+//! ```rust,ignore
+//! let app = PokemonService::builder(config)
+//!     .get_pokemon_species(get_pokemon_species)
+//!     /* ... */
+//!     .build()?;
+//! let listener = tokio::net::TcpListener::bind("127.0.0.1:13734").await?;
+//! aws_smithy_http_server::serve(listener, app.into_make_service()).await?;
+//! ```
+//!
+//! Each connection is served with HTTP/1.1, or with HTTP/2 when the client opens it with the HTTP/2 connection
+//! preface. The latter is known as _h2c with prior knowledge_. Use a [`Server`] to change these defaults, e.g. to
+//! accept only HTTP/2 connections or to tune HTTP/2 settings:
+//!
+//! ```rust,ignore
+//! use aws_smithy_http_server::serve::Server;
+//! use std::time::Duration;
+//!
+//! let server = Server::builder()
+//!     .http2_only(true)
+//!     .http2_max_concurrent_streams(100)
+//!     .http2_keep_alive_interval(Duration::from_secs(20))
+//!     .build();
+//! server.serve(listener, app.into_make_service()).await?;
+//! ```
+//!
+//! # TLS
+//!
+//! With the `tls-rustls` feature, [`Server::serve_tls`] terminates TLS with a [`rustls::ServerConfig`]. The protocol
+//! is negotiated with ALPN: unless the configuration already lists its own ALPN protocols, the server offers `h2` and
+//! `http/1.1` (or only the protocol allowed by [`ServerBuilder::http1_only`] and [`ServerBuilder::http2_only`]).
+//! Handshakes run concurrently, so a slow client doesn't hold up other connections, and are abandoned after
+//! [`ServerBuilder::tls_handshake_timeout`].
+//!
+//! ```rust,ignore
+//! let tls_config = rustls::ServerConfig::builder()
+//!     .with_safe_defaults()
+//!     .with_no_client_auth()
+//!     .with_single_cert(certs, key)?;
+//! Server::builder()
+//!     .build()
+//!     .serve_tls(listener, tls_config, app.into_make_service())
+//!     .await?;
+//! ```
+//!
+//! Connections are handed to the make service as [`AddrStream`]s, or as `TlsStream<AddrStream>`s when serving TLS,
+//! so `into_make_service_with_connect_info` and
+//! [`TlsConnectInfoLayer`](crate::layer::tls_connect_info::TlsConnectInfoLayer) work as usual.
+//!
+//! [`rustls::ServerConfig`]: tokio_rustls::rustls::ServerConfig
+
+use std::{error::Error as StdError, future::Future, time::Duration};
+
+use http_body::Body as HttpBody;
+use hyper::server::conn::{AddrIncoming, AddrStream};
+use tokio::net::TcpListener;
+use tower::Service;
+
+use crate::body::Body;
+
+type BoxError = Box<dyn StdError + Send + Sync>;
+
+/// How long [`Server::serve_tls`] waits for a TLS handshake to complete by default.
+pub const DEFAULT_TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Serves `make_service` on `listener` with the default [`Server`] settings.
+///
+/// See the [module docs](self) for more details.
+pub async fn serve<M, ME, MF, S, B>(listener: TcpListener, make_service: M) -> Result<(), hyper::Error>
+where
+    M: for<'a> Service<&'a AddrStream, Response = S, Error = ME, Future = MF>,
+    ME: Into<BoxError>,
+    MF: Future<Output = Result<S, ME>> + Send + 'static,
+    S: Service<http::Request<Body>, Response = http::Response<B>> + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    Server::builder().build().serve(listener, make_service).await
+}
+
+/// A configured HTTP server.
+///
+/// Create one with [`Server::builder`]. See the [module docs](self) for more details.
+#[derive(Clone, Debug)]
+pub struct Server {
+    http1_only: bool,
+    http2_only: bool,
+    http1_keep_alive: bool,
+    http2_max_concurrent_streams: Option<u32>,
+    http2_keep_alive_interval: Option<Duration>,
+    http2_keep_alive_timeout: Option<Duration>,
+    #[cfg_attr(not(feature = "tls-rustls"), allow(dead_code))]
+    tls_handshake_timeout: Duration,
+}
+
+impl Server {
+    /// Returns a builder for a `Server`.
+    pub fn builder() -> ServerBuilder {
+        ServerBuilder::default()
+    }
+
+    /// Serves `make_service` on `listener` until an error occurs.
+    pub async fn serve<M, ME, MF, S, B>(&self, listener: TcpListener, make_service: M) -> Result<(), hyper::Error>
+    where
+        M: for<'a> Service<&'a AddrStream, Response = S, Error = ME, Future = MF>,
+        ME: Into<BoxError>,
+        MF: Future<Output = Result<S, ME>> + Send + 'static,
+        S: Service<http::Request<Body>, Response = http::Response<B>> + Send + 'static,
+        S::Error: Into<BoxError>,
+        S::Future: Send + 'static,
+        B: HttpBody + Send + 'static,
+        B::Data: Send,
+        B::Error: Into<BoxError>,
+    {
+        let incoming = AddrIncoming::from_listener(listener)?;
+        self.configure(hyper::Server::builder(incoming))
+            .serve(make_service)
+            .await
+    }
+
+    fn configure<I>(&self, builder: hyper::server::Builder<I>) -> hyper::server::Builder<I> {
+        let mut builder = builder
+            .http1_keepalive(self.http1_keep_alive)
+            .http2_max_concurrent_streams(self.http2_max_concurrent_streams)
+            .http2_keep_alive_interval(self.http2_keep_alive_interval);
+        // hyper tracks a single protocol mode, and disabling either "only" setting resets it, so only enable them
+        if self.http1_only {
+            builder = builder.http1_only(true);
+        }
+        if self.http2_only {
+            builder = builder.http2_only(true);
+        }
+        if let Some(timeout) = self.http2_keep_alive_timeout {
+            builder = builder.http2_keep_alive_timeout(timeout);
+        }
+        builder
+    }
+}
+
+/// Builder for [`Server`].
+#[derive(Clone, Debug)]
+pub struct ServerBuilder {
+    http1_only: bool,
+    http2_only: bool,
+    http1_keep_alive: bool,
+    http2_max_concurrent_streams: Option<u32>,
+    http2_keep_alive_interval: Option<Duration>,
+    http2_keep_alive_timeout: Option<Duration>,
+    tls_handshake_timeout: Duration,
+}
+
+impl Default for ServerBuilder {
+    fn default() -> Self {
+        Self {
+            http1_only: false,
+            http2_only: false,
+            http1_keep_alive: true,
+            http2_max_concurrent_streams: None,
+            http2_keep_alive_interval: None,
+            http2_keep_alive_timeout: None,
+            tls_handshake_timeout: DEFAULT_TLS_HANDSHAKE_TIMEOUT,
+        }
+    }
+}
+
+impl ServerBuilder {
+    /// Only accept HTTP/1.1 connections.
+    ///
+    /// Defaults to `false`.
+    pub fn http1_only(mut self, http1_only: bool) -> Self {
+        self.http1_only = http1_only;
+        self
+    }
+
+    /// Only accept HTTP/2 connections.
+    ///
+    /// Without TLS, clients must use HTTP/2 with prior knowledge (h2c). Defaults to `false`.
+    pub fn http2_only(mut self, http2_only: bool) -> Self {
+        self.http2_only = http2_only;
+        self
+    }
+
+    /// Whether HTTP/1.1 connections are kept alive between requests.
+    ///
+    /// Defaults to `true`.
+    pub fn http1_keep_alive(mut self, keep_alive: bool) -> Self {
+        self.http1_keep_alive = keep_alive;
+        self
+    }
+
+    /// Sets the maximum number of concurrent streams a client may open on an HTTP/2 connection.
+    ///
+    /// Not limited by default.
+    pub fn http2_max_concurrent_streams(mut self, max: u32) -> Self {
+        self.http2_max_concurrent_streams = Some(max);
+        self
+    }
+
+    /// Sets the interval at which HTTP/2 PING frames are sent to keep connections alive.
+    ///
+    /// Disabled by default.
+    pub fn http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Sets how long to wait for the acknowledgement of an HTTP/2 keep-alive PING before closing the connection.
+    ///
+    /// Only used when [`http2_keep_alive_interval`](Self::http2_keep_alive_interval) is set. Defaults to hyper's
+    /// default, which is currently 20 seconds.
+    pub fn http2_keep_alive_timeout(mut self, timeout: Duration) -> Self {
+        self.http2_keep_alive_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how long to wait for a client to complete the TLS handshake.
+    ///
+    /// Defaults to [`DEFAULT_TLS_HANDSHAKE_TIMEOUT`].
+    pub fn tls_handshake_timeout(mut self, timeout: Duration) -> Self {
+        self.tls_handshake_timeout = timeout;
+        self
+    }
+
+    /// Builds the [`Server`].
+    pub fn build(self) -> Server {
+        Server {
+            http1_only: self.http1_only,
+            http2_only: self.http2_only,
+            http1_keep_alive: self.http1_keep_alive,
+            http2_max_concurrent_streams: self.http2_max_concurrent_streams,
+            http2_keep_alive_interval: self.http2_keep_alive_interval,
+            http2_keep_alive_timeout: self.http2_keep_alive_timeout,
+            tls_handshake_timeout: self.tls_handshake_timeout,
+        }
+    }
+}
+
+#[cfg(feature = "tls-rustls")]
+mod tls {
+    use std::{
+        future::Future,
+        io,
+        pin::Pin,
+        sync::Arc,
+        task::{Context, Poll},
+        time::Duration,
+    };
+
+    use futures_util::stream::{FuturesUnordered, StreamExt};
+    use http_body::Body as HttpBody;
+    use hyper::server::{
+        accept::Accept,
+        conn::{AddrIncoming, AddrStream},
+    };
+    use tokio::{net::TcpListener, time::Timeout};
+    use tokio_rustls::{rustls::ServerConfig, server::TlsStream, TlsAcceptor};
+    use tower::Service;
+
+    use super::{BoxError, Server};
+    use crate::body::Body;
+
+    impl Server {
+        /// Serves `make_service` on `listener` over TLS until an error occurs.
+        ///
+        /// Unless `tls_config` already lists ALPN protocols, the protocols allowed by this server are offered.
+        pub async fn serve_tls<M, ME, MF, S, B>(
+            &self,
+            listener: TcpListener,
+            mut tls_config: ServerConfig,
+            make_service: M,
+        ) -> Result<(), hyper::Error>
+        where
+            M: for<'a> Service<&'a TlsStream<AddrStream>, Response = S, Error = ME, Future = MF>,
+            ME: Into<BoxError>,
+            MF: Future<Output = Result<S, ME>> + Send + 'static,
+            S: Service<http::Request<Body>, Response = http::Response<B>> + Send + 'static,
+            S::Error: Into<BoxError>,
+            S::Future: Send + 'static,
+            B: HttpBody + Send + 'static,
+            B::Data: Send,
+            B::Error: Into<BoxError>,
+        {
+            if tls_config.alpn_protocols.is_empty() {
+                tls_config.alpn_protocols = self.alpn_protocols();
+            }
+            let incoming = TlsIncoming {
+                incoming: AddrIncoming::from_listener(listener)?,
+                acceptor: TlsAcceptor::from(Arc::new(tls_config)),
+                handshake_timeout: self.tls_handshake_timeout,
+                handshakes: FuturesUnordered::new(),
+            };
+            self.configure(hyper::Server::builder(incoming))
+                .serve(make_service)
+                .await
+        }
+
+        fn alpn_protocols(&self) -> Vec<Vec<u8>> {
+            let mut protocols = Vec::new();
+            if !self.http1_only {
+                protocols.push(b"h2".to_vec());
+            }
+            if !self.http2_only {
+                protocols.push(b"http/1.1".to_vec());
+            }
+            protocols
+        }
+    }
+
+    /// Accepts TCP connections and completes their TLS handshakes concurrently.
+    struct TlsIncoming {
+        incoming: AddrIncoming,
+        acceptor: TlsAcceptor,
+        handshake_timeout: Duration,
+        handshakes: FuturesUnordered<Timeout<tokio_rustls::Accept<AddrStream>>>,
+    }
+
+    impl Accept for TlsIncoming {
+        type Conn = TlsStream<AddrStream>;
+        type Error = io::Error;
+
+        fn poll_accept(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Self::Conn, Self::Error>>> {
+            let this = self.get_mut();
+            loop {
+                match Pin::new(&mut this.incoming).poll_accept(cx) {
+                    Poll::Ready(Some(Ok(stream))) => {
+                        let handshake = this.acceptor.accept(stream);
+                        this.handshakes
+                            .push(tokio::time::timeout(this.handshake_timeout, handshake));
+                    }
+                    Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                    Poll::Ready(None) => return Poll::Ready(None),
+                    Poll::Pending => break,
+                }
+            }
+            loop {
+                match this.handshakes.poll_next_unpin(cx) {
+                    Poll::Ready(Some(Ok(Ok(stream)))) => return Poll::Ready(Some(Ok(stream))),
+                    Poll::Ready(Some(Ok(Err(err)))) => {
+                        tracing::debug!(error = %err, "TLS handshake failed");
+                    }
+                    Poll::Ready(Some(Err(_elapsed))) => {
+                        tracing::debug!("TLS handshake timed out");
+                    }
+                    // Woken up again when a connection is accepted or a handshake makes progress
+                    Poll::Ready(None) | Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::Infallible, net::SocketAddr};
+
+    use hyper::Client;
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    async fn echo_version(request: http::Request<Body>) -> Result<http::Response<Body>, Infallible> {
+        Ok(http::Response::new(Body::from(format!("{:?}", request.version()))))
+    }
+
+    async fn spawn(server: Server) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let make_service = tower::make::Shared::new(tower::service_fn(echo_version));
+        tokio::spawn(async move { server.serve(listener, make_service).await });
+        addr
+    }
+
+    async fn get<C>(client: &Client<C>, addr: SocketAddr) -> Result<String, hyper::Error>
+    where
+        C: hyper::client::connect::Connect + Clone + Send + Sync + 'static,
+    {
+        let response = client.get(format!("http://{addr}/").parse().unwrap()).await?;
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        Ok(String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn serves_http1_and_h2c_prior_knowledge() {
+        let addr = spawn(Server::builder().build()).await;
+
+        let http1 = Client::new();
+        assert_eq!("HTTP/1.1", get(&http1, addr).await.unwrap());
+        let h2c = Client::builder().http2_only(true).build_http();
+        assert_eq!("HTTP/2.0", get(&h2c, addr).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn http2_only_rejects_http1() {
+        let addr = spawn(
+            Server::builder()
+                .http2_only(true)
+                .http2_max_concurrent_streams(10)
+                .http2_keep_alive_interval(Duration::from_secs(5))
+                .build(),
+        )
+        .await;
+
+        let h2c = Client::builder().http2_only(true).build_http();
+        assert_eq!("HTTP/2.0", get(&h2c, addr).await.unwrap());
+        let http1 = Client::new();
+        get(&http1, addr).await.expect_err("HTTP/1.1 is not accepted");
+    }
+
+    #[tokio::test]
+    async fn http1_only_rejects_h2c() {
+        let addr = spawn(Server::builder().http1_only(true).build()).await;
+
+        let http1 = Client::new();
+        assert_eq!("HTTP/1.1", get(&http1, addr).await.unwrap());
+        let h2c = Client::builder().http2_only(true).build_http();
+        get(&h2c, addr).await.expect_err("HTTP/2 is not accepted");
+    }
+
+    #[cfg(feature = "tls-rustls")]
+    #[tokio::test]
+    async fn negotiates_http2_with_alpn() {
+        use std::sync::Arc;
+        use tokio_rustls::rustls::{Certificate, ClientConfig, PrivateKey, RootCertStore, ServerConfig, ServerName};
+
+        let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_owned()]).unwrap();
+        let cert_der = Certificate(cert.serialize_der().unwrap());
+        let tls_config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(vec![cert_der.clone()], PrivateKey(cert.serialize_private_key_der()))
+            .unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let make_service = tower::make::Shared::new(tower::service_fn(echo_version));
+        tokio::spawn(async move {
+            Server::builder()
+                .build()
+                .serve_tls(listener, tls_config, make_service)
+                .await
+        });
+
+        let mut roots = RootCertStore::empty();
+        roots.add(&cert_der).unwrap();
+        let mut client_config = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        client_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+        let tcp = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let tls = tokio_rustls::TlsConnector::from(Arc::new(client_config))
+            .connect(ServerName::try_from("localhost").unwrap(), tcp)
+            .await
+            .unwrap();
+        assert_eq!(Some(&b"h2"[..]), tls.get_ref().1.alpn_protocol());
+
+        let (mut send_request, connection) = hyper::client::conn::Builder::new()
+            .http2_only(true)
+            .handshake::<_, Body>(tls)
+            .await
+            .unwrap();
+        tokio::spawn(connection);
+        let response = send_request
+            .send_request(http::Request::get("https://localhost/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!("HTTP/2.0", body);
+    }
+}
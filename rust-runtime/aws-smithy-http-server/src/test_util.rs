@@ -0,0 +1,362 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Utilities for testing services built with `aws-smithy-http-server`.
+//!
+//! [`TestHarness`] dispatches operations through a built service, including its routing, plugins and layers. Its
+//! [`client`](TestHarness::client) is a generated client of the service, over a [`LoopbackClient`], so tests send
+//! typed operation inputs, which are serialized by the client's protocol codegen, and get typed outputs and errors
+//! back:
+//!
+//! ```rust,ignore
+//! use aws_smithy_http_server::test_util::TestHarness;
+//! use pokemon_service_client::operation::get_pokemon_species::GetPokemonSpeciesInput;
+//!
+//! let app = PokemonService::builder(config)
+//!     .get_pokemon_species(get_pokemon_species)
+//!     /* ... */
+//!     .build_unchecked();
+//! let harness = TestHarness::new(app);
+//! let client = harness.client(|http_client| {
+//!     let config = pokemon_service_client::Config::builder()
+//!         // The endpoint is only used to build the request URI.
+//!         .endpoint_url("http://localhost")
+//!         .http_client(http_client)
+//!         .build();
+//!     pokemon_service_client::Client::from_conf(config)
+//! });
+//!
+//! let output = GetPokemonSpeciesInput::builder()
+//!     .name("pikachu")
+//!     .send_with(&client)
+//!     .await
+//!     .unwrap();
+//! assert_eq!("pikachu", output.name());
+//!
+//! let error = client.get_pokemon_species().name("mewthree").send().await.unwrap_err();
+//! assert!(error.into_service_error().is_resource_not_found_exception());
+//! ```
+//!
+//! Requests that a generated client can't produce, such as malformed ones, can be sent as they are with
+//! [`send`](TestHarness::send), which returns the buffered [`TestResponse`]:
+//!
+//! ```rust,ignore
+//! let response = harness
+//!     .send(http::Request::get("/pokemon-species/pikachu").body(Body::empty()).unwrap())
+//!     .await;
+//! assert_eq!(200, response.status());
+//! assert_eq!(None, response.error_type());
+//! ```
+//!
+//! [`LoopbackClient`] is an HTTP client for generated clients that dispatches their requests to a built service
+//! in-process, without opening sockets. Integration tests can then exercise the serialization of requests and
+//! responses on both the client and the server:
+//!
+//! ```rust,ignore
+//! use aws_smithy_http_server::test_util::LoopbackClient;
+//!
+//! let app = PokemonService::builder(config)
+//!     .get_pokemon_species(get_pokemon_species)
+//!     /* ... */
+//!     .build_unchecked();
+//! let config = pokemon_service_client::Config::builder()
+//!     // The endpoint is only used to build the request URI.
+//!     .endpoint_url("http://localhost")
+//!     .http_client(LoopbackClient::new(app))
+//!     .build();
+//! let client = pokemon_service_client::Client::from_conf(config);
+//!
+//! let output = client.get_pokemon_species().name("pikachu").send().await.unwrap();
+//! assert_eq!("pikachu", output.name());
+//! ```
+
+use std::convert::Infallible;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use aws_smithy_runtime_api::client::http::{
+    HttpClient, HttpConnector, HttpConnectorFuture, HttpConnectorSettings, SharedHttpConnector,
+};
+use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
+use aws_smithy_runtime_api::client::result::ConnectorError;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_runtime_api::shared::IntoShared;
+use aws_smithy_types::body::SdkBody;
+use bytes::Bytes;
+use http::{HeaderMap, StatusCode};
+use http_body::Body as _;
+use tower::{Service, ServiceExt};
+
+use crate::body::{Body, BoxBody};
+
+/// Dispatches operations through a service with a generated client, or raw requests with [`send`](Self::send).
+///
+/// See the [module docs](self) for an example.
+#[derive(Clone, Debug)]
+pub struct TestHarness<S> {
+    service: S,
+}
+
+impl<S> TestHarness<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<BoxBody>, Error = Infallible> + Clone,
+{
+    /// Creates a new `TestHarness` for `service`.
+    pub fn new(service: S) -> Self {
+        Self { service }
+    }
+
+    /// Sends `request` to the service and returns the buffered response.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the response body fails to be read.
+    pub async fn send(&self, request: http::Request<Body>) -> TestResponse {
+        let response = match self.service.clone().oneshot(request).await {
+            Ok(response) => response,
+            Err(infallible) => match infallible {},
+        };
+        let (parts, body) = response.into_parts();
+        let body = hyper::body::to_bytes(body)
+            .await
+            .expect("failed to read the response body");
+        TestResponse {
+            status: parts.status,
+            headers: parts.headers,
+            body,
+        }
+    }
+}
+
+impl<S> TestHarness<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<BoxBody>, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    /// Returns a [`LoopbackClient`] that dispatches requests to the service.
+    pub fn http_client(&self) -> LoopbackClient<S> {
+        LoopbackClient::new(self.service.clone())
+    }
+
+    /// Builds a generated client whose requests are dispatched to the service.
+    ///
+    /// `make_client` is given the [`LoopbackClient`] of the service, to set as the HTTP client of the generated
+    /// client's config. Operations sent with the client are serialized, routed and handled by the service, and their
+    /// outputs and errors are deserialized into the client's types.
+    pub fn client<C>(&self, make_client: impl FnOnce(LoopbackClient<S>) -> C) -> C {
+        make_client(self.http_client())
+    }
+}
+
+/// A response buffered by a [`TestHarness`].
+#[derive(Clone, Debug)]
+pub struct TestResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl TestResponse {
+    /// Returns the status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the headers of the response.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Returns the value of the header `name`, if it's present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    /// Returns the name of the error in the response, for protocols that set it in the `X-Amzn-Errortype` header.
+    pub fn error_type(&self) -> Option<&str> {
+        self.header("X-Amzn-Errortype")
+    }
+
+    /// Returns the body of the response.
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// Returns the body of the response as a string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the body is not valid UTF-8.
+    pub fn body_str(&self) -> &str {
+        std::str::from_utf8(&self.body).expect("the response body is not valid UTF-8")
+    }
+}
+
+/// An HTTP client that dispatches requests to a service in-process.
+///
+/// `LoopbackClient` can be used as the HTTP client of a generated client to send its requests to a service built with
+/// `aws-smithy-http-server`, including its routing, plugins and layers. Request and response bodies are streamed
+/// between the client and the service. See the [module docs](self) for an example.
+pub struct LoopbackClient<S> {
+    // Routers aren't `Sync`, so the service is cloned under a lock for every request.
+    service: Arc<Mutex<S>>,
+}
+
+impl<S> LoopbackClient<S> {
+    /// Creates a new `LoopbackClient` that dispatches requests to `service`.
+    pub fn new(service: S) -> Self {
+        Self {
+            service: Arc::new(Mutex::new(service)),
+        }
+    }
+}
+
+impl<S> Clone for LoopbackClient<S> {
+    fn clone(&self) -> Self {
+        Self {
+            service: self.service.clone(),
+        }
+    }
+}
+
+impl<S> fmt::Debug for LoopbackClient<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoopbackClient").finish_non_exhaustive()
+    }
+}
+
+impl<S> HttpClient for LoopbackClient<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<BoxBody>, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    fn http_connector(
+        &self,
+        _settings: &HttpConnectorSettings,
+        _components: &RuntimeComponents,
+    ) -> SharedHttpConnector {
+        self.clone().into_shared()
+    }
+}
+
+impl<S> HttpConnector for LoopbackClient<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<BoxBody>, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    fn call(&self, request: HttpRequest) -> HttpConnectorFuture {
+        let request = match request.try_into_http02x() {
+            Ok(request) => request.map(|body| Body::wrap_stream(into_stream(body))),
+            Err(err) => return HttpConnectorFuture::ready(Err(ConnectorError::other(err.into(), None))),
+        };
+        let service = self.service.lock().unwrap().clone();
+        HttpConnectorFuture::new(async move {
+            let response = match service.oneshot(request).await {
+                Ok(response) => response,
+                Err(infallible) => match infallible {},
+            };
+            let response = response.map(|body| SdkBody::from(Body::wrap_stream(into_stream(body))));
+            response
+                .try_into()
+                .map_err(|err: aws_smithy_runtime_api::http::HttpError| ConnectorError::other(err.into(), None))
+        })
+    }
+}
+
+/// Converts a body into a stream of its data frames. Trailers are dropped.
+fn into_stream<B>(body: B) -> impl futures_util::Stream<Item = Result<Bytes, B::Error>> + Send + 'static
+where
+    B: http_body::Body<Data = Bytes> + Send + 'static,
+{
+    futures_util::stream::unfold(Box::pin(body), |mut body: Pin<Box<B>>| async move {
+        body.data().await.map(|data| (data, body))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use aws_smithy_runtime_api::client::http::{HttpClient, HttpConnector};
+    use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
+    use aws_smithy_runtime_api::client::runtime_components::RuntimeComponentsBuilder;
+    use aws_smithy_types::body::SdkBody;
+    use tower::service_fn;
+
+    use super::{LoopbackClient, TestHarness};
+    use crate::body::{to_boxed, Body};
+
+    #[tokio::test]
+    async fn buffers_responses() {
+        let service = service_fn(|request: http::Request<Body>| async move {
+            let response = http::Response::builder()
+                .status(404)
+                .header("X-Amzn-Errortype", "ResourceNotFoundException")
+                .body(to_boxed(format!("{{\"path\":\"{}\"}}", request.uri().path())))
+                .unwrap();
+            Ok::<_, Infallible>(response)
+        });
+        let harness = TestHarness::new(service);
+
+        let response = harness
+            .send(http::Request::get("/missing").body(Body::empty()).unwrap())
+            .await;
+        assert_eq!(404, response.status());
+        assert_eq!(Some("ResourceNotFoundException"), response.error_type());
+        assert_eq!(r#"{"path":"/missing"}"#, response.body_str());
+    }
+
+    #[tokio::test]
+    async fn builds_clients_over_a_loopback_client() {
+        let service = service_fn(|_request: http::Request<Body>| async move {
+            Ok::<_, Infallible>(http::Response::new(to_boxed("pikachu")))
+        });
+        let harness = TestHarness::new(service);
+        let components = RuntimeComponentsBuilder::for_tests().build().unwrap();
+        let connector = harness.client(|http_client| http_client.http_connector(&Default::default(), &components));
+
+        let request: HttpRequest = http::Request::get("http://localhost/pokemon")
+            .body(SdkBody::empty())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let response = connector.call(request).await.unwrap();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!("pikachu", body);
+    }
+
+    #[tokio::test]
+    async fn loopback_client_dispatches_requests_to_the_service() {
+        let service = service_fn(|request: http::Request<Body>| async move {
+            let (parts, body) = request.into_parts();
+            let body = hyper::body::to_bytes(body).await.unwrap();
+            let response = http::Response::builder()
+                .status(201)
+                .header("x-method", parts.method.as_str())
+                .body(to_boxed(format!(
+                    "{}:{}",
+                    parts.uri.path(),
+                    std::str::from_utf8(&body).unwrap()
+                )))
+                .unwrap();
+            Ok::<_, Infallible>(response)
+        });
+        let client = LoopbackClient::new(service);
+        let components = RuntimeComponentsBuilder::for_tests().build().unwrap();
+        let connector = client.http_connector(&Default::default(), &components);
+
+        let request: HttpRequest = http::Request::put("http://localhost/pokemon")
+            .body(SdkBody::from("pikachu"))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let response = connector.call(request).await.unwrap();
+        assert_eq!(201, response.status().as_u16());
+        assert_eq!(Some("PUT"), response.headers().get("x-method"));
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!("/pokemon:pikachu", body);
+    }
+}
@@ -14,7 +14,10 @@ mod sender;
 pub type BoxError = Box<dyn StdError + Send + Sync + 'static>;
 
 #[doc(inline)]
-pub use sender::{EventStreamSender, MessageStreamAdapter, MessageStreamError};
+pub use sender::{
+    initial_message, EventStreamSendError, EventStreamSender, EventStreamSenderHandle,
+    MessageStreamAdapter, MessageStreamError,
+};
 
 #[doc(inline)]
 pub use receiver::{Receiver, ReceiverError};
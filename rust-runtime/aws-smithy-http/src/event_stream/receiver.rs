@@ -209,24 +209,35 @@ impl<T, E> Receiver<T, E> {
     /// If a different event type is received, then it is buffered and `Ok(None)` is returned.
     #[doc(hidden)]
     pub async fn try_recv_initial(&mut self) -> Result<Option<Message>, SdkError<E, RawMessage>> {
+        self.try_recv_initial_message("initial-response").await
+    }
+
+    /// Tries to receive the initial request message that has `:event-type` of `initial-request`.
+    /// If a different event type is received, then it is buffered and `Ok(None)` is returned.
+    #[doc(hidden)]
+    pub async fn try_recv_initial_request(
+        &mut self,
+    ) -> Result<Option<Message>, SdkError<E, RawMessage>> {
+        self.try_recv_initial_message("initial-request").await
+    }
+
+    async fn try_recv_initial_message(
+        &mut self,
+        initial_event_type: &str,
+    ) -> Result<Option<Message>, SdkError<E, RawMessage>> {
         if let Some(message) = self.next_message().await? {
-            if let Some(event_type) = message
+            let is_initial_message = message
                 .headers()
                 .iter()
                 .find(|h| h.name().as_str() == ":event-type")
-            {
-                if event_type
-                    .value()
-                    .as_string()
-                    .map(|s| s.as_str() == "initial-response")
-                    .unwrap_or(false)
-                {
-                    return Ok(Some(message));
-                }
-            } else {
-                // Buffer the message so that it can be returned by the next call to `recv()`
-                self.buffered_message = Some(message);
+                .and_then(|h| h.value().as_string().ok())
+                .map(|s| s.as_str() == initial_event_type)
+                .unwrap_or(false);
+            if is_initial_message {
+                return Ok(Some(message));
             }
+            // Buffer the message so that it can be returned by the next call to `recv()`
+            self.buffered_message = Some(message);
         }
         Ok(None)
     }
@@ -273,15 +284,19 @@ mod tests {
     use std::io::{Error as IOError, ErrorKind};
 
     fn encode_initial_response() -> Bytes {
+        encode_event("initial-response", "")
+    }
+
+    fn encode_event(event_type: &'static str, message: &str) -> Bytes {
         let mut buffer = Vec::new();
-        let message = Message::new(Bytes::new())
+        let message = Message::new(Bytes::copy_from_slice(message.as_bytes()))
             .add_header(Header::new(
                 ":message-type",
                 HeaderValue::String("event".into()),
             ))
             .add_header(Header::new(
                 ":event-type",
-                HeaderValue::String("initial-response".into()),
+                HeaderValue::String(event_type.into()),
             ));
         write_message_to(&message, &mut buffer).unwrap();
         buffer.into()
@@ -533,6 +548,43 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn receive_initial_request() {
+        let chunks: Vec<Result<_, IOError>> = vec![
+            Ok(encode_event("initial-request", "{}")),
+            Ok(encode_message("one")),
+        ];
+        let chunk_stream = futures_util::stream::iter(chunks);
+        let body = SdkBody::from_body_0_4(Body::wrap_stream(chunk_stream));
+        let mut receiver = Receiver::<TestMessage, EventStreamError>::new(Unmarshaller, body);
+        let initial = receiver.try_recv_initial_request().await.unwrap().unwrap();
+        assert_eq!(&b"{}"[..], &initial.payload()[..]);
+        assert_eq!(
+            TestMessage("one".into()),
+            receiver.recv().await.unwrap().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn event_received_instead_of_initial_message_is_buffered() {
+        let chunks: Vec<Result<_, IOError>> = vec![
+            Ok(encode_event("Foo", "one")),
+            Ok(encode_event("Foo", "two")),
+        ];
+        let chunk_stream = futures_util::stream::iter(chunks);
+        let body = SdkBody::from_body_0_4(Body::wrap_stream(chunk_stream));
+        let mut receiver = Receiver::<TestMessage, EventStreamError>::new(Unmarshaller, body);
+        assert!(receiver.try_recv_initial_request().await.unwrap().is_none());
+        assert_eq!(
+            TestMessage("one".into()),
+            receiver.recv().await.unwrap().unwrap()
+        );
+        assert_eq!(
+            TestMessage("two".into()),
+            receiver.recv().await.unwrap().unwrap()
+        );
+    }
+
     fn assert_send_and_sync<T: Send + Sync>() {}
 
     #[tokio::test]
@@ -6,11 +6,14 @@
 use aws_smithy_eventstream::frame::{write_message_to, MarshallMessage, SignMessage};
 use aws_smithy_runtime_api::client::result::SdkError;
 use aws_smithy_types::error::ErrorMetadata;
+use aws_smithy_types::event_stream::{Header, HeaderValue, Message};
 use bytes::Bytes;
+use futures_channel::{mpsc, oneshot};
 use futures_core::Stream;
 use std::error::Error as StdError;
 use std::fmt;
 use std::fmt::Debug;
+use std::future::poll_fn;
 use std::marker::PhantomData;
 use std::pin::Pin;
 use std::task::{Context, Poll};
@@ -52,6 +55,139 @@ where
     }
 }
 
+impl<T, E> EventStreamSender<T, E>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    /// Creates an `EventStreamSender` along with an [`EventStreamSenderHandle`] for sending
+    /// events into it.
+    ///
+    /// Up to `buffer` events can be queued ahead of the transport before
+    /// [`EventStreamSenderHandle::send`] waits for capacity.
+    pub fn channel(buffer: usize) -> (Self, EventStreamSenderHandle<T, E>) {
+        let (sender, receiver) = mpsc::channel(buffer);
+        let (drained_tx, drained_rx) = oneshot::channel();
+        let stream = ChannelStream {
+            receiver,
+            drained: Some(drained_tx),
+        };
+        let handle = EventStreamSenderHandle {
+            sender,
+            drained: drained_rx,
+        };
+        (stream.into(), handle)
+    }
+}
+
+/// Yields events from a channel, and signals once the channel has been drained.
+struct ChannelStream<T, E> {
+    receiver: mpsc::Receiver<Result<T, E>>,
+    drained: Option<oneshot::Sender<()>>,
+}
+
+impl<T, E> Stream for ChannelStream<T, E> {
+    type Item = Result<T, E>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let next = Pin::new(&mut self.receiver).poll_next(cx);
+        if let Poll::Ready(None) = next {
+            if let Some(drained) = self.drained.take() {
+                let _ = drained.send(());
+            }
+        }
+        next
+    }
+}
+
+/// Handle for sending events into an [`EventStreamSender`] created with
+/// [`EventStreamSender::channel`].
+///
+/// Events are sent in order. The event stream ends once [`close`](Self::close) or
+/// [`send_error`](Self::send_error) is called, or the handle is dropped. At that point, the
+/// end-of-stream frame is sent to the service.
+pub struct EventStreamSenderHandle<T, E> {
+    sender: mpsc::Sender<Result<T, E>>,
+    drained: oneshot::Receiver<()>,
+}
+
+impl<T, E> Debug for EventStreamSenderHandle<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name_t = std::any::type_name::<T>();
+        let name_e = std::any::type_name::<E>();
+        write!(f, "EventStreamSenderHandle<{name_t}, {name_e}>")
+    }
+}
+
+impl<T, E> EventStreamSenderHandle<T, E> {
+    /// Sends an event, waiting for buffer capacity if necessary.
+    pub async fn send(&mut self, event: T) -> Result<(), EventStreamSendError> {
+        self.send_message(Ok(event)).await
+    }
+
+    /// Sends a modeled error event to the service and then closes the event stream.
+    ///
+    /// The error is marshalled as an exception frame, followed by the end-of-stream frame. Like
+    /// [`close`](Self::close), this waits until both have been handed to the transport.
+    pub async fn send_error(mut self, error: E) -> Result<(), EventStreamSendError> {
+        self.send_message(Err(error)).await?;
+        self.close().await
+    }
+
+    /// Closes the event stream, waiting until every event sent so far has been handed to the
+    /// transport.
+    ///
+    /// Since events are only consumed while the operation is in flight, this must be awaited
+    /// concurrently with the operation's `send()` future.
+    pub async fn close(mut self) -> Result<(), EventStreamSendError> {
+        self.sender.close_channel();
+        self.drained
+            .await
+            .map_err(|_| EventStreamSendError::closed())
+    }
+
+    async fn send_message(&mut self, message: Result<T, E>) -> Result<(), EventStreamSendError> {
+        poll_fn(|cx| self.sender.poll_ready(cx))
+            .await
+            .map_err(|_| EventStreamSendError::closed())?;
+        self.sender
+            .start_send(message)
+            .map_err(|_| EventStreamSendError::closed())
+    }
+}
+
+/// An error that occurs when sending events through an [`EventStreamSenderHandle`].
+#[derive(Debug)]
+pub struct EventStreamSendError {
+    kind: EventStreamSendErrorKind,
+}
+
+#[derive(Debug)]
+enum EventStreamSendErrorKind {
+    Closed,
+}
+
+impl EventStreamSendError {
+    fn closed() -> Self {
+        Self {
+            kind: EventStreamSendErrorKind::Closed,
+        }
+    }
+}
+
+impl StdError for EventStreamSendError {}
+
+impl fmt::Display for EventStreamSendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            EventStreamSendErrorKind::Closed => write!(
+                f,
+                "the event stream was dropped before all events were sent, which can happen when the operation fails"
+            ),
+        }
+    }
+}
+
 /// An error that occurs within a message stream.
 #[derive(Debug)]
 pub struct MessageStreamError {
@@ -115,6 +251,7 @@ pub struct MessageStreamAdapter<T, E: StdError + Send + Sync + 'static> {
     error_marshaller: Box<dyn MarshallMessage<Input = E> + Send + Sync>,
     signer: Box<dyn SignMessage + Send + Sync>,
     stream: Pin<Box<dyn Stream<Item = Result<T, E>> + Send>>,
+    initial_message: Option<Message>,
     end_signal_sent: bool,
     _phantom: PhantomData<E>,
 }
@@ -134,10 +271,64 @@ impl<T, E: StdError + Send + Sync + 'static> MessageStreamAdapter<T, E> {
             error_marshaller: Box::new(error_marshaller),
             signer: Box::new(signer),
             stream,
+            initial_message: None,
             end_signal_sent: false,
             _phantom: Default::default(),
         }
     }
+
+    /// Sends the given message before the first event of the stream.
+    ///
+    /// RPC protocols use it to send the `initial-request` or `initial-response` message (see
+    /// [`initial_message`]).
+    #[doc(hidden)]
+    pub fn with_initial_message(mut self, message: Message) -> Self {
+        self.initial_message = Some(message);
+        self
+    }
+
+    fn sign_and_encode(
+        &mut self,
+        message: Message,
+    ) -> Result<Bytes, SdkError<E, aws_smithy_runtime_api::client::orchestrator::HttpResponse>>
+    {
+        trace!(unsigned_message = ?message, "signing event stream message");
+        let message = self
+            .signer
+            .sign(message)
+            .map_err(SdkError::construction_failure)?;
+
+        let mut buffer = Vec::new();
+        write_message_to(&message, &mut buffer).map_err(SdkError::construction_failure)?;
+        trace!(signed_message = ?buffer, "sending signed event stream message");
+        Ok(Bytes::from(buffer))
+    }
+}
+
+/// Creates the initial message of an event stream with the given `:event-type`, which is either
+/// `initial-request` or `initial-response`.
+///
+/// In RPC protocols, the initial message carries the members of the operation input or output
+/// that aren't bound to the event stream, serialized with the protocol's `content_type`.
+#[doc(hidden)]
+pub fn initial_message(
+    event_type: &'static str,
+    content_type: &'static str,
+    payload: impl Into<Bytes>,
+) -> Message {
+    Message::new(payload)
+        .add_header(Header::new(
+            ":message-type",
+            HeaderValue::String("event".into()),
+        ))
+        .add_header(Header::new(
+            ":event-type",
+            HeaderValue::String(event_type.into()),
+        ))
+        .add_header(Header::new(
+            ":content-type",
+            HeaderValue::String(content_type.into()),
+        ))
 }
 
 impl<T, E: StdError + Send + Sync + 'static> Stream for MessageStreamAdapter<T, E> {
@@ -145,6 +336,9 @@ impl<T, E: StdError + Send + Sync + 'static> Stream for MessageStreamAdapter<T,
         Result<Bytes, SdkError<E, aws_smithy_runtime_api::client::orchestrator::HttpResponse>>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(initial_message) = self.initial_message.take() {
+            return Poll::Ready(Some(self.sign_and_encode(initial_message)));
+        }
         match self.stream.as_mut().poll_next(cx) {
             Poll::Ready(message_option) => {
                 if let Some(message_result) = message_option {
@@ -158,18 +352,7 @@ impl<T, E: StdError + Send + Sync + 'static> Stream for MessageStreamAdapter<T,
                             .marshall(message)
                             .map_err(SdkError::construction_failure)?,
                     };
-
-                    trace!(unsigned_message = ?message, "signing event stream message");
-                    let message = self
-                        .signer
-                        .sign(message)
-                        .map_err(SdkError::construction_failure)?;
-
-                    let mut buffer = Vec::new();
-                    write_message_to(&message, &mut buffer)
-                        .map_err(SdkError::construction_failure)?;
-                    trace!(signed_message = ?buffer, "sending signed event stream message");
-                    Poll::Ready(Some(Ok(Bytes::from(buffer))))
+                    Poll::Ready(Some(self.sign_and_encode(message)))
                 } else if !self.end_signal_sent {
                     self.end_signal_sent = true;
                     let mut buffer = Vec::new();
@@ -195,7 +378,7 @@ impl<T, E: StdError + Send + Sync + 'static> Stream for MessageStreamAdapter<T,
 #[cfg(test)]
 mod tests {
     use super::MarshallMessage;
-    use crate::event_stream::{EventStreamSender, MessageStreamAdapter};
+    use crate::event_stream::{initial_message, EventStreamSender, MessageStreamAdapter};
     use async_stream::stream;
     use aws_smithy_eventstream::error::Error as EventStreamError;
     use aws_smithy_eventstream::frame::{
@@ -304,6 +487,43 @@ mod tests {
         assert_eq!(0, end_signal.payload().len());
     }
 
+    #[tokio::test]
+    async fn message_stream_adapter_sends_initial_message_first() {
+        let stream = stream! {
+            yield Ok(TestMessage("test".into()));
+        };
+        let mut adapter = MessageStreamAdapter::<TestMessage, TestServiceError>::new(
+            Marshaller,
+            ErrorMarshaller,
+            NoOpSigner {},
+            Box::pin(stream),
+        )
+        .with_initial_message(initial_message(
+            "initial-response",
+            "application/json",
+            &b"{}"[..],
+        ));
+
+        let mut initial_bytes = adapter.next().await.unwrap().unwrap();
+        let initial = read_message_from(&mut initial_bytes).unwrap();
+        let header = |name: &str| {
+            initial
+                .headers()
+                .iter()
+                .find(|h| h.name().as_str() == name)
+                .map(|h| h.value().as_string().unwrap().as_str().to_owned())
+        };
+        assert_eq!(Some("event".to_owned()), header(":message-type"));
+        assert_eq!(Some("initial-response".to_owned()), header(":event-type"));
+        assert_eq!(Some("application/json".to_owned()), header(":content-type"));
+        assert_eq!(&b"{}"[..], &initial.payload()[..]);
+
+        let mut sent_bytes = adapter.next().await.unwrap().unwrap();
+        let sent = read_message_from(&mut sent_bytes).unwrap();
+        assert_eq!(&b"test"[..], &sent.payload()[..]);
+        assert!(adapter.next().await.is_none());
+    }
+
     #[tokio::test]
     async fn message_stream_adapter_construction_failure() {
         let stream = stream! {
@@ -327,6 +547,87 @@ mod tests {
         ));
     }
 
+    #[derive(Debug)]
+    struct ExceptionMarshaller;
+    impl MarshallMessage for ExceptionMarshaller {
+        type Input = TestServiceError;
+
+        fn marshall(&self, _input: Self::Input) -> Result<Message, EventStreamError> {
+            Ok(Message::new(&b"error"[..]).add_header(Header::new(
+                ":message-type",
+                HeaderValue::String("exception".into()),
+            )))
+        }
+    }
+
+    fn read_inner_message(bytes: &mut Bytes) -> Message {
+        let sent = read_message_from(bytes).unwrap();
+        read_message_from(&mut (&sent.payload()[..])).unwrap()
+    }
+
+    #[test]
+    fn event_stream_sender_handle_send_sync() {
+        let (sender, handle) = EventStreamSender::<TestMessage, TestServiceError>::channel(1);
+        check_send_sync(sender);
+        check_send_sync(handle);
+    }
+
+    #[tokio::test]
+    async fn channel_send_and_close() {
+        let (sender, mut handle) = EventStreamSender::<TestMessage, TestServiceError>::channel(4);
+        let mut adapter = sender.into_body_stream(Marshaller, ErrorMarshaller, TestSigner);
+
+        handle.send(TestMessage("one".into())).await.unwrap();
+        handle.send(TestMessage("two".into())).await.unwrap();
+        let close = tokio::spawn(handle.close());
+
+        let mut sent = adapter.next().await.unwrap().unwrap();
+        assert_eq!(&b"one"[..], &read_inner_message(&mut sent).payload()[..]);
+        let mut sent = adapter.next().await.unwrap().unwrap();
+        assert_eq!(&b"two"[..], &read_inner_message(&mut sent).payload()[..]);
+
+        // The end-of-stream frame is sent once the handle is closed
+        let mut end_signal_bytes = adapter.next().await.unwrap().unwrap();
+        let end_signal = read_message_from(&mut end_signal_bytes).unwrap();
+        assert_eq!(0, end_signal.payload().len());
+        assert!(adapter.next().await.is_none());
+
+        close.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn channel_send_error() {
+        let (sender, handle) = EventStreamSender::<TestMessage, TestServiceError>::channel(4);
+        let mut adapter = sender.into_body_stream(Marshaller, ExceptionMarshaller, TestSigner);
+
+        let send_error = tokio::spawn(handle.send_error(TestServiceError));
+
+        let mut sent = adapter.next().await.unwrap().unwrap();
+        let exception = read_inner_message(&mut sent);
+        assert_eq!(":message-type", exception.headers()[0].name().as_str());
+        assert_eq!(
+            &HeaderValue::String("exception".into()),
+            exception.headers()[0].value()
+        );
+
+        let mut end_signal_bytes = adapter.next().await.unwrap().unwrap();
+        let end_signal = read_message_from(&mut end_signal_bytes).unwrap();
+        assert_eq!(0, end_signal.payload().len());
+        assert!(adapter.next().await.is_none());
+
+        send_error.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn channel_send_after_stream_dropped() {
+        let (sender, mut handle) = EventStreamSender::<TestMessage, TestServiceError>::channel(4);
+        drop(sender);
+
+        let err = handle.send(TestMessage("test".into())).await.unwrap_err();
+        assert!(err.to_string().contains("event stream was dropped"));
+        assert!(handle.close().await.is_err());
+    }
+
     // Verify the developer experience for this compiles
     #[allow(unused)]
     fn event_stream_input_ergonomics() {
@@ -302,6 +302,50 @@ pub fn quote_header_value<'a>(value: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
     }
 }
 
+/// A string could not be used as a header value because it contains a character that isn't allowed.
+#[derive(Debug)]
+pub struct InvalidHeaderValue {
+    character: char,
+    offset: usize,
+}
+
+impl InvalidHeaderValue {
+    /// The first character that isn't allowed in a header value.
+    pub fn character(&self) -> char {
+        self.character
+    }
+
+    /// The byte offset of [`character`](InvalidHeaderValue::character) in the value.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for InvalidHeaderValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid character {:?} (U+{:04X}) at byte offset {}",
+            self.character, self.character as u32, self.offset
+        )
+    }
+}
+
+impl Error for InvalidHeaderValue {}
+
+/// Checks that `value` can be used as a header value, reporting the first character that can't be.
+///
+/// Header values may contain any character other than ASCII control characters, except for horizontal tabs.
+pub fn validate_header_value(value: &str) -> Result<(), InvalidHeaderValue> {
+    match value
+        .char_indices()
+        .find(|(_, c)| c.is_ascii_control() && *c != '\t')
+    {
+        Some((offset, character)) => Err(InvalidHeaderValue { character, offset }),
+        None => Ok(()),
+    }
+}
+
 /// Given two [`HeaderMap`]s, merge them together and return the merged `HeaderMap`. If the
 /// two `HeaderMap`s share any keys, values from the right `HeaderMap` be appended to the left `HeaderMap`.
 pub fn append_merge_header_maps(
@@ -335,7 +379,7 @@ mod test {
     use crate::header::{
         append_merge_header_maps, headers_for_prefix, many_dates, read_many_from_str,
         read_many_primitive, set_request_header_if_absent, set_response_header_if_absent,
-        ParseError,
+        validate_header_value, ParseError,
     };
     use aws_smithy_runtime_api::http::Request;
     use aws_smithy_types::error::display::DisplayErrorContext;
@@ -759,4 +803,27 @@ mod test {
 
         assert_eq!(actual_merged_values, expected_merged_values);
     }
+
+    #[test]
+    fn validate_header_value_reports_the_invalid_character() {
+        assert!(validate_header_value("a valid\tvalue, with ünicode").is_ok());
+
+        let err = validate_header_value("line one\nline two").unwrap_err();
+        assert_eq!('\n', err.character());
+        assert_eq!(8, err.offset());
+        assert_eq!(
+            "invalid character '\\n' (U+000A) at byte offset 8",
+            err.to_string()
+        );
+
+        // Agrees with `HeaderValue` on which characters are allowed
+        for c in (0..=0x80).filter_map(char::from_u32) {
+            let value = format!("a{c}b");
+            assert_eq!(
+                HeaderValue::from_str(&value).is_ok(),
+                validate_header_value(&value).is_ok(),
+                "{c:?}"
+            );
+        }
+    }
 }
@@ -331,22 +331,44 @@ impl<'a> JsonTokenIterator<'a> {
                 )
             } else if negative {
                 // If the negative value overflows, then stuff it into an f64
-                let positive = u64::from_str(&number_str[1..])
-                    .map_err(|_| self.error_at(start, InvalidNumber))?;
-                let negative = positive.wrapping_neg() as i64;
-                if negative > 0 {
-                    Number::Float(-(positive as f64))
-                } else {
-                    Number::NegInt(negative)
+                match u64::from_str(&number_str[1..]) {
+                    Ok(positive) => {
+                        let negative = positive.wrapping_neg() as i64;
+                        if negative > 0 {
+                            Number::Float(-(positive as f64))
+                        } else {
+                            Number::NegInt(negative)
+                        }
+                    }
+                    Err(err) => self.overflowing_integer(start, number_str, err)?,
                 }
             } else {
-                Number::PosInt(
-                    u64::from_str(number_str).map_err(|_| self.error_at(start, InvalidNumber))?,
-                )
+                match u64::from_str(number_str) {
+                    Ok(positive) => Number::PosInt(positive),
+                    Err(err) => self.overflowing_integer(start, number_str, err)?,
+                }
             },
+            raw: number_str,
         })
     }
 
+    /// Approximates an integer too large for a `u64` with an `f64`. The exact value remains
+    /// available from the raw text of the [Token::ValueNumber].
+    fn overflowing_integer(
+        &self,
+        start: usize,
+        number_str: &str,
+        err: std::num::ParseIntError,
+    ) -> Result<Number, Error> {
+        match err.kind() {
+            std::num::IntErrorKind::PosOverflow => number_str
+                .parse()
+                .map(Number::Float)
+                .map_err(|_| self.error_at(start, InvalidNumber)),
+            _ => Err(self.error_at(start, InvalidNumber)),
+        }
+    }
+
     /// Reads a value from the stream and returns the next token. For objects and arrays,
     /// the entire object or array will not be ready, but rather, a [Token::StartObject]/[Token::StartArray]
     /// will be returned.
@@ -618,7 +640,7 @@ mod tests {
             } else {
                 Number::PosInt(input as u64)
             };
-            expect_token(value_number(0, expected), iter.next());
+            expect_token(value_number(0, &json, expected), iter.next());
             expect_token(None, iter.next());
         }
 
@@ -626,7 +648,7 @@ mod tests {
         fn float_prop_test(input: f64) {
             let json = serde_json::to_string(&input).unwrap();
             let mut iter = json_token_iter(json.as_bytes());
-            expect_token(value_number(0, Number::Float(input)), iter.next());
+            expect_token(value_number(0, &json, Number::Float(input)), iter.next());
             expect_token(None, iter.next());
         }
     }
@@ -634,7 +656,8 @@ mod tests {
     #[test]
     fn valid_numbers() {
         let expect = |number, input| {
-            expect_token(value_number(0, number), json_token_iter(input).next());
+            let raw = std::str::from_utf8(input).unwrap();
+            expect_token(value_number(0, raw, number), json_token_iter(input).next());
         };
         expect(Number::Float(0.0), b"0.");
         expect(Number::Float(0.0), b"0e0");
@@ -650,12 +673,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn integers_overflowing_u64_are_approximated() {
+        let input = b"123456789012345678901234567890";
+        expect_token(
+            value_number(
+                0,
+                "123456789012345678901234567890",
+                Number::Float(1.2345678901234568e29),
+            ),
+            json_token_iter(input).next(),
+        );
+        let input = b"-123456789012345678901234567890";
+        expect_token(
+            value_number(
+                0,
+                "-123456789012345678901234567890",
+                Number::Float(-1.2345678901234568e29),
+            ),
+            json_token_iter(input).next(),
+        );
+    }
+
     // These cases actually shouldn't parse according to the spec, but it's easier
     // to be lenient on these, and it doesn't really impact the SDK use-case.
     #[test]
     fn invalid_numbers_we_are_intentionally_accepting() {
         let expect = |number, input| {
-            expect_token(value_number(0, number), json_token_iter(input).next());
+            let raw = std::str::from_utf8(input).unwrap();
+            expect_token(value_number(0, raw, number), json_token_iter(input).next());
         };
 
         expect(Number::NegInt(-1), b"-01");
@@ -758,13 +804,16 @@ mod tests {
         );
         expect_token(start_object(0), tokens.next());
         expect_token(object_key(2, "some_int"), tokens.next());
-        expect_token(value_number(14, Number::PosInt(5)), tokens.next());
+        expect_token(value_number(14, "5", Number::PosInt(5)), tokens.next());
         expect_token(object_key(35, "some_float"), tokens.next());
-        expect_token(value_number(49, Number::Float(5.2)), tokens.next());
+        expect_token(value_number(49, "5.2", Number::Float(5.2)), tokens.next());
         expect_token(object_key(72, "some_negative"), tokens.next());
-        expect_token(value_number(89, Number::NegInt(-5)), tokens.next());
+        expect_token(value_number(89, "-5", Number::NegInt(-5)), tokens.next());
         expect_token(object_key(111, "some_negative_float"), tokens.next());
-        expect_token(value_number(134, Number::Float(-2.4)), tokens.next());
+        expect_token(
+            value_number(134, "-2.4", Number::Float(-2.4)),
+            tokens.next(),
+        );
         expect_token(object_key(158, "some_string"), tokens.next());
         expect_token(value_string(173, "test"), tokens.next());
         expect_token(object_key(199, "some_struct"), tokens.next());
@@ -9,7 +9,7 @@ use crate::escape::unescape_string;
 pub use crate::escape::EscapeError;
 use aws_smithy_types::date_time::Format;
 use aws_smithy_types::primitive::Parse;
-use aws_smithy_types::{base64, Blob, DateTime, Document, Number};
+use aws_smithy_types::{base64, BigDecimal, BigInteger, Blob, DateTime, Document, Number};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::iter::Peekable;
@@ -76,7 +76,10 @@ pub enum Token<'a> {
     },
     ValueNumber {
         offset: Offset,
+        /// The number, approximated by a [Number::Float] for integers too large for a `u64`.
         value: Number,
+        /// The number exactly as it appears in the JSON input.
+        raw: &'a str,
     },
     ValueString {
         offset: Offset,
@@ -161,7 +164,9 @@ pub fn expect_number_or_null(
 ) -> Result<Option<Number>, Error> {
     match token.transpose()? {
         Some(Token::ValueNull { .. }) => Ok(None),
-        Some(Token::ValueNumber { value, .. }) => Ok(Some(value)),
+        Some(Token::ValueNumber { value, raw, offset }) => {
+            exact_number(value, raw, offset).map(Some)
+        }
         Some(Token::ValueString { value, offset }) => match value.to_unescaped() {
             Err(err) => Err(Error::custom_source( "expected a valid string, escape was invalid", err).with_offset(offset.0)),
             Ok(v) => f64::parse_smithy_primitive(v.as_ref())
@@ -185,6 +190,52 @@ pub fn expect_number_or_null(
     }
 }
 
+/// Returns the `value` of a [Token::ValueNumber], unless it only approximates an integer too large
+/// for a `u64`, which can only be read exactly with [expect_big_integer_or_null].
+fn exact_number(value: Number, raw: &str, offset: Offset) -> Result<Number, Error> {
+    let overflows =
+        |magnitude: &str| !magnitude.contains(['.', 'e', 'E']) && magnitude.parse::<u64>().is_err();
+    if matches!(value, Number::Float(_)) && overflows(raw.trim_start_matches('-')) {
+        return Err(
+            Error::custom("integer is too large for a 64-bit integer").with_offset(offset.0)
+        );
+    }
+    Ok(value)
+}
+
+/// Expects a [Token::ValueNumber] or [Token::ValueNull], and returns the number as a [BigInteger]
+/// if it's not null.
+///
+/// Unlike [expect_number_or_null], the number is not limited to the range of a 64-bit integer.
+pub fn expect_big_integer_or_null(
+    token: Option<Result<Token<'_>, Error>>,
+) -> Result<Option<BigInteger>, Error> {
+    match token.transpose()? {
+        Some(Token::ValueNull { .. }) => Ok(None),
+        Some(Token::ValueNumber { raw, offset, .. }) => raw
+            .parse()
+            .map(Some)
+            .map_err(|err| Error::custom_source("expected an integer", err).with_offset(offset.0)),
+        _ => Err(Error::custom("expected ValueNumber or ValueNull")),
+    }
+}
+
+/// Expects a [Token::ValueNumber] or [Token::ValueNull], and returns the number as a [BigDecimal]
+/// if it's not null.
+///
+/// Unlike [expect_number_or_null], the number keeps the exact precision of the JSON input.
+pub fn expect_big_decimal_or_null(
+    token: Option<Result<Token<'_>, Error>>,
+) -> Result<Option<BigDecimal>, Error> {
+    match token.transpose()? {
+        Some(Token::ValueNull { .. }) => Ok(None),
+        Some(Token::ValueNumber { raw, offset, .. }) => raw.parse().map(Some).map_err(|err| {
+            Error::custom_source("expected a decimal number", err).with_offset(offset.0)
+        }),
+        _ => Err(Error::custom("expected ValueNumber or ValueNull")),
+    }
+}
+
 /// Expects a [Token::ValueString] or [Token::ValueNull]. If the value is a string, it interprets it as a base64 encoded [Blob] value.
 pub fn expect_blob_or_null(token: Option<Result<Token<'_>, Error>>) -> Result<Option<Blob>, Error> {
     Ok(match expect_string_or_null(token)? {
@@ -247,7 +298,9 @@ where
     match tokens.next().transpose()? {
         Some(Token::ValueNull { .. }) => Ok(Document::Null),
         Some(Token::ValueBool { value, .. }) => Ok(Document::Bool(value)),
-        Some(Token::ValueNumber { value, .. }) => Ok(Document::Number(value)),
+        Some(Token::ValueNumber { value, raw, offset }) => {
+            Ok(Document::Number(exact_number(value, raw, offset)?))
+        }
         Some(Token::ValueString { value, .. }) => {
             Ok(Document::String(value.to_unescaped()?.into_owned()))
         }
@@ -378,10 +431,15 @@ pub mod test {
         }))
     }
 
-    pub fn value_number<'a>(offset: usize, number: Number) -> Option<Result<Token<'a>, Error>> {
+    pub fn value_number(
+        offset: usize,
+        raw: &str,
+        number: Number,
+    ) -> Option<Result<Token<'_>, Error>> {
         Some(Ok(Token::ValueNumber {
             offset: Offset(offset),
             value: number,
+            raw,
         }))
     }
 
@@ -544,7 +602,7 @@ pub mod test {
         assert_eq!(None, expect_number_or_null(value_null(0)).unwrap());
         assert_eq!(
             Some(Number::PosInt(5)),
-            expect_number_or_null(value_number(0, Number::PosInt(5))).unwrap()
+            expect_number_or_null(value_number(0, "5", Number::PosInt(5))).unwrap()
         );
         expect_err_custom(
             "expected ValueString, ValueNumber, or ValueNull",
@@ -570,6 +628,71 @@ pub mod test {
         }
     }
 
+    #[test]
+    fn test_expect_big_numbers_or_null() {
+        let mut tokens = json_token_iter(
+            b"[123456789012345678901234567890, -0.1000000000000000000001, 1e10, null, true]",
+        );
+        assert!(matches!(tokens.next(), Some(Ok(Token::StartArray { .. }))));
+        assert_eq!(
+            "123456789012345678901234567890",
+            expect_big_integer_or_null(tokens.next())
+                .unwrap()
+                .unwrap()
+                .as_str()
+        );
+        assert_eq!(
+            "-0.1000000000000000000001",
+            expect_big_decimal_or_null(tokens.next())
+                .unwrap()
+                .unwrap()
+                .as_str()
+        );
+        expect_err_custom(
+            "expected an integer",
+            Some(60),
+            expect_big_integer_or_null(tokens.next()),
+        );
+        assert_eq!(None, expect_big_decimal_or_null(tokens.next()).unwrap());
+        expect_err_custom(
+            "expected ValueNumber or ValueNull",
+            None,
+            expect_big_integer_or_null(tokens.next()),
+        );
+
+        assert_eq!(
+            "1e10",
+            expect_big_decimal_or_null(value_number(0, "1e10", Number::Float(1e10)))
+                .unwrap()
+                .unwrap()
+                .as_str()
+        );
+        assert_eq!(None, expect_big_integer_or_null(value_null(0)).unwrap());
+    }
+
+    #[test]
+    fn integers_overflowing_u64_are_rejected_outside_big_integers() {
+        let big = "123456789012345678901234567890";
+        expect_err_custom(
+            "integer is too large for a 64-bit integer",
+            Some(0),
+            expect_number_or_null(json_token_iter(big.as_bytes()).next()),
+        );
+        expect_err_custom(
+            "integer is too large for a 64-bit integer",
+            Some(1),
+            expect_document(&mut json_token_iter(format!("[{big}]").as_bytes()).peekable()),
+        );
+        assert_eq!(
+            Some(Number::Float(-9223372036854775809.0)),
+            expect_number_or_null(json_token_iter(b"-9223372036854775809").next()).unwrap()
+        );
+        assert_eq!(
+            Some(Number::Float(1.2345678901234568e29)),
+            expect_number_or_null(json_token_iter(b"1.2345678901234568e29").next()).unwrap()
+        );
+    }
+
     #[test]
     fn test_expect_blob_or_null() {
         assert_eq!(None, expect_blob_or_null(value_null(0)).unwrap());
@@ -603,8 +726,11 @@ pub mod test {
         }
         assert_eq!(
             Some(DateTime::from_secs_f64(2048.0)),
-            expect_timestamp_or_null(value_number(0, Number::Float(2048.0)), Format::EpochSeconds)
-                .unwrap()
+            expect_timestamp_or_null(
+                value_number(0, "2048.0", Number::Float(2048.0)),
+                Format::EpochSeconds
+            )
+            .unwrap()
         );
         assert_eq!(
             Some(DateTime::from_secs_f64(1445412480.0)),
@@ -627,7 +753,7 @@ pub mod test {
         expect_err_custom(
             "expected ValueString or ValueNull",
             None,
-            expect_timestamp_or_null(value_number(0, Number::Float(0.0)), Format::DateTime),
+            expect_timestamp_or_null(value_number(0, "0.0", Number::Float(0.0)), Format::DateTime),
         );
     }
 
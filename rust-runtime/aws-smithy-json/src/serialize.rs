@@ -6,7 +6,7 @@
 use crate::escape::escape_string;
 use aws_smithy_types::date_time::{DateTimeFormatError, Format};
 use aws_smithy_types::primitive::Encoder;
-use aws_smithy_types::{DateTime, Document, Number};
+use aws_smithy_types::{BigDecimal, BigInteger, DateTime, Document, Number};
 use std::borrow::Cow;
 
 pub struct JsonValueWriter<'a> {
@@ -94,6 +94,16 @@ impl<'a> JsonValueWriter<'a> {
         }
     }
 
+    /// Writes an arbitrary-precision integer `value`.
+    pub fn big_integer(self, value: &BigInteger) {
+        self.output.push_str(value.as_str());
+    }
+
+    /// Writes an arbitrary-precision decimal `value`.
+    pub fn big_decimal(self, value: &BigDecimal) {
+        self.output.push_str(value.as_str());
+    }
+
     /// Writes a date-time `value` with the given `format`.
     pub fn date_time(
         self,
@@ -426,6 +436,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn big_numbers() {
+        let mut output = String::new();
+        let mut array = JsonArrayWriter::new(&mut output);
+        array
+            .value()
+            .big_integer(&"-123456789012345678901234567890".parse().unwrap());
+        array
+            .value()
+            .big_decimal(&"3.14159265358979323846264338327950288".parse().unwrap());
+        array.finish();
+        assert_eq!(
+            "[-123456789012345678901234567890,3.14159265358979323846264338327950288]",
+            output
+        );
+    }
+
     proptest! {
         #[test]
         fn matches_serde_json_pos_int_format(value: u64) {
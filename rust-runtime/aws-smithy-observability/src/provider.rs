@@ -13,6 +13,7 @@ use crate::{meter::ProvideMeter, noop::NoopMeterProvider};
 #[non_exhaustive]
 pub struct TelemetryProvider {
     meter_provider: Arc<dyn ProvideMeter + Send + Sync>,
+    rpc_span_attributes: bool,
 }
 
 impl TelemetryProvider {
@@ -20,6 +21,7 @@ impl TelemetryProvider {
     pub fn builder() -> TelemetryProviderBuilder {
         TelemetryProviderBuilder {
             meter_provider: Arc::new(NoopMeterProvider),
+            rpc_span_attributes: true,
         }
     }
 
@@ -27,6 +29,7 @@ impl TelemetryProvider {
     pub fn noop() -> TelemetryProvider {
         Self {
             meter_provider: Arc::new(NoopMeterProvider),
+            rpc_span_attributes: true,
         }
     }
 
@@ -34,6 +37,12 @@ impl TelemetryProvider {
     pub fn meter_provider(&self) -> &(dyn ProvideMeter + Send + Sync) {
         self.meter_provider.as_ref()
     }
+
+    /// Returns whether the [semantic convention attributes](crate::semconv) are recorded on the
+    /// spans of request attempts.
+    pub fn rpc_span_attributes_enabled(&self) -> bool {
+        self.rpc_span_attributes
+    }
 }
 
 // If we choose to expand our Telemetry provider and make Logging and Tracing
@@ -44,6 +53,7 @@ impl Default for TelemetryProvider {
     fn default() -> Self {
         Self {
             meter_provider: Arc::new(NoopMeterProvider),
+            rpc_span_attributes: true,
         }
     }
 }
@@ -52,6 +62,7 @@ impl Default for TelemetryProvider {
 #[non_exhaustive]
 pub struct TelemetryProviderBuilder {
     meter_provider: Arc<dyn ProvideMeter + Send + Sync>,
+    rpc_span_attributes: bool,
 }
 
 impl TelemetryProviderBuilder {
@@ -61,10 +72,18 @@ impl TelemetryProviderBuilder {
         self
     }
 
+    /// Set whether the [semantic convention attributes](crate::semconv) are recorded on the spans
+    /// of request attempts. Defaults to `true`.
+    pub fn rpc_span_attributes(mut self, enabled: bool) -> Self {
+        self.rpc_span_attributes = enabled;
+        self
+    }
+
     /// Build the [TelemetryProvider].
     pub fn build(self) -> TelemetryProvider {
         TelemetryProvider {
             meter_provider: self.meter_provider,
+            rpc_span_attributes: self.rpc_span_attributes,
         }
     }
 }
@@ -0,0 +1,27 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Names of the [OpenTelemetry semantic convention] attributes recorded on the spans and metrics
+//! of requests, so that OpenTelemetry backends can interpret them as RPC calls.
+//!
+//! [OpenTelemetry semantic convention]: https://opentelemetry.io/docs/specs/semconv/rpc/rpc-spans/
+
+/// The name of the service being called, e.g. `s3`.
+pub const RPC_SERVICE: &str = "rpc.service";
+
+/// The name of the operation being called, e.g. `GetObject`.
+pub const RPC_METHOD: &str = "rpc.method";
+
+/// The HTTP status code of the response.
+pub const HTTP_STATUS_CODE: &str = "http.status_code";
+
+/// The number of times the request was resent before this attempt. Only recorded on retries.
+pub const HTTP_REQUEST_RESEND_COUNT: &str = "http.request.resend_count";
+
+/// The host of the endpoint the request is sent to.
+pub const SERVER_ADDRESS: &str = "server.address";
+
+/// The type of error an operation failed with.
+pub const ERROR_TYPE: &str = "error.type";
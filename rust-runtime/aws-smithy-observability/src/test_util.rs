@@ -0,0 +1,186 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Test utilities for checking the metrics recorded through a [`TelemetryProvider`](crate::TelemetryProvider)
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::instruments::{
+    AsyncInstrumentBuilder, AsyncMeasure, Histogram, InstrumentBuilder, MonotonicCounter,
+    ProvideInstrument, UpDownCounter,
+};
+use crate::meter::{Meter, ProvideMeter};
+use crate::noop::NoopMeter;
+use crate::{AttributeValue, Attributes, Context};
+
+/// A value recorded by one of the instruments of a [`RecordingMeter`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    scope: &'static str,
+    instrument: String,
+    value: f64,
+    attributes: HashMap<String, AttributeValue>,
+}
+
+impl Measurement {
+    /// The scope of the meter the instrument was created from.
+    pub fn scope(&self) -> &'static str {
+        self.scope
+    }
+
+    /// The name of the instrument that recorded the value.
+    pub fn instrument(&self) -> &str {
+        &self.instrument
+    }
+
+    /// The recorded value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The attributes recorded along with the value.
+    pub fn attributes(&self) -> &HashMap<String, AttributeValue> {
+        &self.attributes
+    }
+}
+
+/// A meter provider that records every value of its histograms and counters.
+///
+/// Asynchronous instruments record nothing.
+#[derive(Clone, Debug, Default)]
+pub struct RecordingMeter {
+    measurements: Arc<Mutex<Vec<Measurement>>>,
+}
+
+impl RecordingMeter {
+    /// Creates a new `RecordingMeter` that hasn't recorded anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns everything recorded so far, in order.
+    pub fn measurements(&self) -> Vec<Measurement> {
+        self.measurements.lock().unwrap().clone()
+    }
+
+    /// Returns the values recorded so far by the instrument named `instrument`, in order.
+    pub fn values(&self, instrument: &str) -> Vec<f64> {
+        self.measurements
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|measurement| measurement.instrument == instrument)
+            .map(|measurement| measurement.value)
+            .collect()
+    }
+}
+
+impl ProvideMeter for RecordingMeter {
+    fn get_meter(&self, scope: &'static str, _attributes: Option<&Attributes>) -> Meter {
+        Meter::new(Arc::new(ScopedMeter {
+            scope,
+            measurements: self.measurements.clone(),
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct ScopedMeter {
+    scope: &'static str,
+    measurements: Arc<Mutex<Vec<Measurement>>>,
+}
+
+impl ScopedMeter {
+    fn instrument<T>(&self, builder: &InstrumentBuilder<'_, T>) -> Arc<RecordingInstrument> {
+        Arc::new(RecordingInstrument {
+            scope: self.scope,
+            name: builder.get_name().to_string(),
+            measurements: self.measurements.clone(),
+        })
+    }
+}
+
+impl ProvideInstrument for ScopedMeter {
+    fn create_gauge(
+        &self,
+        builder: AsyncInstrumentBuilder<'_, Arc<dyn AsyncMeasure<Value = f64>>, f64>,
+    ) -> Arc<dyn AsyncMeasure<Value = f64>> {
+        NoopMeter.create_gauge(builder)
+    }
+
+    fn create_up_down_counter(
+        &self,
+        builder: InstrumentBuilder<'_, Arc<dyn UpDownCounter>>,
+    ) -> Arc<dyn UpDownCounter> {
+        self.instrument(&builder)
+    }
+
+    fn create_async_up_down_counter(
+        &self,
+        builder: AsyncInstrumentBuilder<'_, Arc<dyn AsyncMeasure<Value = i64>>, i64>,
+    ) -> Arc<dyn AsyncMeasure<Value = i64>> {
+        NoopMeter.create_async_up_down_counter(builder)
+    }
+
+    fn create_monotonic_counter(
+        &self,
+        builder: InstrumentBuilder<'_, Arc<dyn MonotonicCounter>>,
+    ) -> Arc<dyn MonotonicCounter> {
+        self.instrument(&builder)
+    }
+
+    fn create_async_monotonic_counter(
+        &self,
+        builder: AsyncInstrumentBuilder<'_, Arc<dyn AsyncMeasure<Value = u64>>, u64>,
+    ) -> Arc<dyn AsyncMeasure<Value = u64>> {
+        NoopMeter.create_async_monotonic_counter(builder)
+    }
+
+    fn create_histogram(
+        &self,
+        builder: InstrumentBuilder<'_, Arc<dyn Histogram>>,
+    ) -> Arc<dyn Histogram> {
+        self.instrument(&builder)
+    }
+}
+
+#[derive(Debug)]
+struct RecordingInstrument {
+    scope: &'static str,
+    name: String,
+    measurements: Arc<Mutex<Vec<Measurement>>>,
+}
+
+impl RecordingInstrument {
+    fn push(&self, value: f64, attributes: Option<&Attributes>) {
+        self.measurements.lock().unwrap().push(Measurement {
+            scope: self.scope,
+            instrument: self.name.clone(),
+            value,
+            attributes: attributes
+                .map(|attributes| attributes.attributes().clone())
+                .unwrap_or_default(),
+        });
+    }
+}
+
+impl Histogram for RecordingInstrument {
+    fn record(&self, value: f64, attributes: Option<&Attributes>, _context: Option<&dyn Context>) {
+        self.push(value, attributes);
+    }
+}
+
+impl MonotonicCounter for RecordingInstrument {
+    fn add(&self, value: u64, attributes: Option<&Attributes>, _context: Option<&dyn Context>) {
+        self.push(value as f64, attributes);
+    }
+}
+
+impl UpDownCounter for RecordingInstrument {
+    fn add(&self, value: i64, attributes: Option<&Attributes>, _context: Option<&dyn Context>) {
+        self.push(value as f64, attributes);
+    }
+}
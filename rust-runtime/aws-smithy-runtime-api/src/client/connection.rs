@@ -10,14 +10,27 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 /// Metadata that tracks the state of an active connection.
+///
+/// Besides the addresses of the connection, this includes the parameters negotiated during the
+/// TLS handshake, when the connection uses TLS and the HTTP client records them. This makes it
+/// possible to audit which TLS version and cipher suite were used by each request.
 #[derive(Clone)]
 pub struct ConnectionMetadata {
     is_proxied: bool,
     remote_addr: Option<SocketAddr>,
     local_addr: Option<SocketAddr>,
+    // Boxed to keep the size of `ConnectorError` down, since it contains the connection metadata
+    tls: Option<Box<TlsMetadata>>,
     poison_fn: Arc<dyn Fn() + Send + Sync>,
 }
 
+#[derive(Clone, Debug, Default)]
+struct TlsMetadata {
+    alpn_protocol: Option<String>,
+    tls_version: Option<String>,
+    cipher_suite: Option<String>,
+}
+
 impl ConnectionMetadata {
     /// Poison this connection, ensuring that it won't be reused.
     pub fn poison(&self) {
@@ -41,8 +54,9 @@ impl ConnectionMetadata {
         Self {
             is_proxied,
             remote_addr,
-            // need to use builder to set this field
+            // need to use builder to set these fields
             local_addr: None,
+            tls: None,
             poison_fn: Arc::new(poison),
         }
     }
@@ -61,6 +75,21 @@ impl ConnectionMetadata {
     pub fn local_addr(&self) -> Option<SocketAddr> {
         self.local_addr
     }
+
+    /// Get the protocol negotiated with ALPN during the TLS handshake, e.g. `h2` or `http/1.1`.
+    pub fn alpn_protocol(&self) -> Option<&str> {
+        self.tls.as_ref()?.alpn_protocol.as_deref()
+    }
+
+    /// Get the negotiated TLS version, e.g. `TLSv1_3`.
+    pub fn tls_version(&self) -> Option<&str> {
+        self.tls.as_ref()?.tls_version.as_deref()
+    }
+
+    /// Get the negotiated TLS cipher suite, e.g. `TLS13_AES_256_GCM_SHA384`.
+    pub fn cipher_suite(&self) -> Option<&str> {
+        self.tls.as_ref()?.cipher_suite.as_deref()
+    }
 }
 
 impl Debug for ConnectionMetadata {
@@ -69,6 +98,9 @@ impl Debug for ConnectionMetadata {
             .field("is_proxied", &self.is_proxied)
             .field("remote_addr", &self.remote_addr)
             .field("local_addr", &self.local_addr)
+            .field("alpn_protocol", &self.alpn_protocol())
+            .field("tls_version", &self.tls_version())
+            .field("cipher_suite", &self.cipher_suite())
             .finish()
     }
 }
@@ -79,6 +111,7 @@ pub struct ConnectionMetadataBuilder {
     is_proxied: Option<bool>,
     remote_addr: Option<SocketAddr>,
     local_addr: Option<SocketAddr>,
+    tls: TlsMetadata,
     poison_fn: Option<Arc<dyn Fn() + Send + Sync>>,
 }
 
@@ -88,6 +121,9 @@ impl Debug for ConnectionMetadataBuilder {
             .field("is_proxied", &self.is_proxied)
             .field("remote_addr", &self.remote_addr)
             .field("local_addr", &self.local_addr)
+            .field("alpn_protocol", &self.tls.alpn_protocol)
+            .field("tls_version", &self.tls.tls_version)
+            .field("cipher_suite", &self.tls.cipher_suite)
             .finish()
     }
 }
@@ -134,6 +170,42 @@ impl ConnectionMetadataBuilder {
         self
     }
 
+    /// Set the protocol negotiated with ALPN during the TLS handshake.
+    pub fn alpn_protocol(mut self, alpn_protocol: impl Into<String>) -> Self {
+        self.set_alpn_protocol(Some(alpn_protocol.into()));
+        self
+    }
+
+    /// Set the protocol negotiated with ALPN during the TLS handshake.
+    pub fn set_alpn_protocol(&mut self, alpn_protocol: Option<String>) -> &mut Self {
+        self.tls.alpn_protocol = alpn_protocol;
+        self
+    }
+
+    /// Set the negotiated TLS version.
+    pub fn tls_version(mut self, tls_version: impl Into<String>) -> Self {
+        self.set_tls_version(Some(tls_version.into()));
+        self
+    }
+
+    /// Set the negotiated TLS version.
+    pub fn set_tls_version(&mut self, tls_version: Option<String>) -> &mut Self {
+        self.tls.tls_version = tls_version;
+        self
+    }
+
+    /// Set the negotiated TLS cipher suite.
+    pub fn cipher_suite(mut self, cipher_suite: impl Into<String>) -> Self {
+        self.set_cipher_suite(Some(cipher_suite.into()));
+        self
+    }
+
+    /// Set the negotiated TLS cipher suite.
+    pub fn set_cipher_suite(&mut self, cipher_suite: Option<String>) -> &mut Self {
+        self.tls.cipher_suite = cipher_suite;
+        self
+    }
+
     /// Set a closure which will poison the associated connection.
     ///
     /// A poisoned connection will not be reused for subsequent requests by the pool
@@ -166,6 +238,10 @@ impl ConnectionMetadataBuilder {
                 .expect("is_proxied should be set for ConnectionMetadata"),
             remote_addr: self.remote_addr,
             local_addr: self.local_addr,
+            tls: (self.tls.alpn_protocol.is_some()
+                || self.tls.tls_version.is_some()
+                || self.tls.cipher_suite.is_some())
+            .then(|| Box::new(self.tls)),
             poison_fn: self
                 .poison_fn
                 .expect("poison_fn should be set for ConnectionMetadata"),
@@ -238,6 +314,9 @@ mod tests {
 
         assert_eq!(metadata1.local_addr(), None);
         assert_eq!(metadata1.remote_addr(), None);
+        assert_eq!(metadata1.alpn_protocol(), None);
+        assert_eq!(metadata1.tls_version(), None);
+        assert_eq!(metadata1.cipher_suite(), None);
 
         let metadata2 = ConnectionMetadataBuilder::new()
             .proxied(true)
@@ -257,4 +336,19 @@ mod tests {
         assert_eq!(metadata3.local_addr(), None);
         assert_eq!(metadata3.remote_addr(), Some(TEST_SOCKET_ADDR));
     }
+
+    #[test]
+    fn builder_tls_fields() {
+        let metadata = ConnectionMetadataBuilder::new()
+            .proxied(false)
+            .poison_fn(|| {})
+            .alpn_protocol("h2")
+            .tls_version("TLSv1_3")
+            .cipher_suite("TLS13_AES_256_GCM_SHA384")
+            .build();
+
+        assert_eq!(metadata.alpn_protocol(), Some("h2"));
+        assert_eq!(metadata.tls_version(), Some("TLSv1_3"));
+        assert_eq!(metadata.cipher_suite(), Some("TLS13_AES_256_GCM_SHA384"));
+    }
 }
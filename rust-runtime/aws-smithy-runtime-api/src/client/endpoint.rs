@@ -6,6 +6,7 @@
 //! APIs needed to configure endpoint resolution for clients.
 
 use crate::box_error::BoxError;
+use crate::client::identity::Identity;
 use crate::client::runtime_components::sealed::ValidateConfig;
 use crate::impl_shared_conversions;
 use aws_smithy_types::config_bag::{Storable, StoreReplace};
@@ -50,6 +51,38 @@ impl Storable for EndpointResolverParams {
 pub trait ResolveEndpoint: Send + Sync + fmt::Debug {
     /// Asynchronously resolves an endpoint to use from the given endpoint parameters.
     fn resolve_endpoint<'a>(&'a self, params: &'a EndpointResolverParams) -> EndpointFuture<'a>;
+
+    /// Returns the endpoint parameters to use with the identity resolved for the request.
+    ///
+    /// Some endpoint parameters, such as the AWS account ID, come from the identity that the
+    /// request is signed with. The orchestrator resolves the endpoint before the identity so
+    /// that auth schemes the endpoint doesn't support can be skipped. Once the identity is
+    /// resolved, the orchestrator calls this method, and resolves the endpoint again if it returns
+    /// new parameters.
+    ///
+    /// The default implementation returns `None`, meaning that the parameters don't depend on
+    /// the identity. Resolvers that override it must also override
+    /// [`params_depend_on_identity`](ResolveEndpoint::params_depend_on_identity).
+    fn finalize_params(
+        &self,
+        params: &EndpointResolverParams,
+        identity: &Identity,
+    ) -> Result<Option<EndpointResolverParams>, BoxError> {
+        let _ = (params, identity);
+        Ok(None)
+    }
+
+    /// Returns true if [`finalize_params`](ResolveEndpoint::finalize_params) can change the
+    /// endpoint parameters for an identity.
+    ///
+    /// When it returns false, the orchestrator fails the request as soon as the endpoint can't be
+    /// resolved, without resolving an identity. Otherwise, it resolves the identity first, since
+    /// the parameters of the identity may resolve the endpoint.
+    ///
+    /// The default implementation returns false.
+    fn params_depend_on_identity(&self) -> bool {
+        false
+    }
 }
 
 /// Shared endpoint resolver.
@@ -69,6 +102,18 @@ impl ResolveEndpoint for SharedEndpointResolver {
     fn resolve_endpoint<'a>(&'a self, params: &'a EndpointResolverParams) -> EndpointFuture<'a> {
         self.0.resolve_endpoint(params)
     }
+
+    fn finalize_params(
+        &self,
+        params: &EndpointResolverParams,
+        identity: &Identity,
+    ) -> Result<Option<EndpointResolverParams>, BoxError> {
+        self.0.finalize_params(params, identity)
+    }
+
+    fn params_depend_on_identity(&self) -> bool {
+        self.0.params_depend_on_identity()
+    }
 }
 
 impl ValidateConfig for SharedEndpointResolver {}
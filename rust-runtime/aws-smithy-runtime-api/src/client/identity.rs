@@ -7,8 +7,9 @@ use crate::box_error::BoxError;
 use crate::client::runtime_components::sealed::ValidateConfig;
 use crate::client::runtime_components::{RuntimeComponents, RuntimeComponentsBuilder};
 use crate::impl_shared_conversions;
-use aws_smithy_types::config_bag::ConfigBag;
-use std::any::Any;
+use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -255,12 +256,27 @@ impl_shared_conversions!(convert SharedIdentityResolver from ResolveIdentity usi
 /// an expiration time on the identity data. This is because an `Arc<dyn Any>`
 /// can't be downcast to any arbitrary trait, and expiring identities are
 /// common enough to be built-in.
+///
+/// Properties of the identity that are relevant beyond signing, e.g. to endpoint resolution,
+/// can be attached with [`Identity::with_property`] so that they can be read without knowing
+/// the type of the identity data.
 #[derive(Clone)]
 pub struct Identity {
     data: Arc<dyn Any + Send + Sync>,
     #[allow(clippy::type_complexity)]
     data_debug: Arc<dyn (Fn(&Arc<dyn Any + Send + Sync>) -> &dyn Debug) + Send + Sync>,
     expiration: Option<SystemTime>,
+    properties: HashMap<TypeId, Arc<dyn Property>>,
+}
+
+trait Property: Debug + Send + Sync {
+    fn as_any(&self) -> &(dyn Any + Send + Sync);
+}
+
+impl<T: Any + Debug + Send + Sync> Property for T {
+    fn as_any(&self) -> &(dyn Any + Send + Sync) {
+        self
+    }
 }
 
 impl Identity {
@@ -273,9 +289,17 @@ impl Identity {
             data: Arc::new(data),
             data_debug: Arc::new(|d| d.downcast_ref::<T>().expect("type-checked") as _),
             expiration,
+            properties: HashMap::new(),
         }
     }
 
+    /// Attaches a property to this identity, replacing any previous property of the same type.
+    pub fn with_property<T: Any + Debug + Send + Sync>(mut self, property: T) -> Self {
+        self.properties
+            .insert(TypeId::of::<T>(), Arc::new(property));
+        self
+    }
+
     /// Returns the raw identity data.
     pub fn data<T: Any + Debug + Send + Sync + 'static>(&self) -> Option<&T> {
         self.data.downcast_ref()
@@ -285,17 +309,34 @@ impl Identity {
     pub fn expiration(&self) -> Option<SystemTime> {
         self.expiration
     }
+
+    /// Returns the property of type `T` attached with [`Identity::with_property`], if any.
+    pub fn property<T: Any + Debug + Send + Sync>(&self) -> Option<&T> {
+        self.properties
+            .get(&TypeId::of::<T>())
+            .and_then(|property| property.as_ref().as_any().downcast_ref())
+    }
 }
 
 impl Debug for Identity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Identity")
+        let mut debug = f.debug_struct("Identity");
+        debug
             .field("data", (self.data_debug)(&self.data))
-            .field("expiration", &self.expiration)
-            .finish()
+            .field("expiration", &self.expiration);
+        if !self.properties.is_empty() {
+            debug.field("properties", &self.properties.values().collect::<Vec<_>>());
+        }
+        debug.finish()
     }
 }
 
+/// The orchestrator stores the identity resolved for the current request attempt in the config
+/// bag before the `modify_before_signing` interceptors run, so that interceptors can inspect it.
+impl Storable for Identity {
+    type Storer = StoreReplace<Self>;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -329,4 +370,30 @@ mod tests {
         assert_eq!("bar", identity.data::<MyIdentityData>().unwrap().last);
         assert_eq!(Some(expiration), identity.expiration());
     }
+
+    #[test]
+    fn identity_properties() {
+        #[derive(Debug, PartialEq)]
+        struct AccountId(&'static str);
+
+        let identity = Identity::new("foo", None);
+        assert_eq!(None, identity.property::<AccountId>());
+        assert_eq!(
+            r#"Identity { data: "foo", expiration: None }"#,
+            format!("{identity:?}")
+        );
+
+        let identity = identity
+            .with_property(AccountId("111122223333"))
+            .with_property(AccountId("444455556666"));
+        assert_eq!(
+            Some(&AccountId("444455556666")),
+            identity.clone().property::<AccountId>()
+        );
+        assert_eq!(None, identity.property::<String>());
+        assert_eq!(
+            r#"Identity { data: "foo", expiration: None, properties: [AccountId("444455556666")] }"#,
+            format!("{identity:?}")
+        );
+    }
 }
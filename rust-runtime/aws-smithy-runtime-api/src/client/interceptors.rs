@@ -15,6 +15,7 @@ use crate::client::interceptors::context::{
     BeforeTransmitInterceptorContextRef, FinalizerInterceptorContextMut,
     FinalizerInterceptorContextRef,
 };
+use crate::client::orchestrator::Metadata;
 use crate::client::runtime_components::sealed::ValidateConfig;
 use crate::client::runtime_components::RuntimeComponents;
 use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
@@ -613,6 +614,38 @@ impl SharedInterceptor {
         }
     }
 
+    /// Restricts this interceptor to operations whose name matches `operation_filter`.
+    ///
+    /// The operation name is read from the [`Metadata`] in the config bag. The interceptor is
+    /// disabled when no `Metadata` is present. Any existing conditions for enabling the interceptor,
+    /// such as [`disable_interceptor`], continue to apply.
+    ///
+    /// ```rust
+    /// # use aws_smithy_runtime_api::client::interceptors::{Intercept, SharedInterceptor};
+    /// # #[derive(Debug)]
+    /// # struct MyInterceptor;
+    /// # impl Intercept for MyInterceptor {
+    /// #     fn name(&self) -> &'static str { "MyInterceptor" }
+    /// # }
+    /// let interceptor = SharedInterceptor::new(MyInterceptor)
+    ///     .with_operation_filter(|operation| operation == "PutObject");
+    /// ```
+    pub fn with_operation_filter(
+        self,
+        operation_filter: impl Fn(&str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let check_enabled = self.check_enabled;
+        Self {
+            interceptor: self.interceptor,
+            check_enabled: Arc::new(move |conf: &ConfigBag| {
+                check_enabled(conf)
+                    && conf
+                        .load::<Metadata>()
+                        .map_or(false, |metadata| operation_filter(metadata.name()))
+            }),
+        }
+    }
+
     /// Checks if this interceptor is enabled in the given config.
     pub fn enabled(&self, conf: &ConfigBag) -> bool {
         (self.check_enabled)(conf)
@@ -15,6 +15,7 @@ use crate::client::auth::{
     AuthScheme, AuthSchemeId, ResolveAuthSchemeOptions, SharedAuthScheme,
     SharedAuthSchemeOptionResolver,
 };
+use crate::client::connector_metadata::ConnectorMetadata;
 use crate::client::endpoint::{ResolveEndpoint, SharedEndpointResolver};
 use crate::client::http::{HttpClient, SharedHttpClient};
 use crate::client::identity::{
@@ -28,6 +29,7 @@ use crate::shared::IntoShared;
 use aws_smithy_async::rt::sleep::{AsyncSleep, SharedAsyncSleep};
 use aws_smithy_async::time::{SharedTimeSource, TimeSource};
 use aws_smithy_types::config_bag::ConfigBag;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
@@ -399,6 +401,15 @@ impl RuntimeComponents {
         self.auth_scheme_option_resolver.value.clone()
     }
 
+    /// Returns a diagnostic report of these runtime components.
+    ///
+    /// The report names the HTTP client (including its connector metadata), resolvers, identity cache,
+    /// interceptors, retry and time components in effect, along with the runtime plugin that set each of
+    /// them. This is useful to answer questions like "which HTTP client is this client actually using?".
+    pub fn describe(&self) -> RuntimeComponentsReport {
+        self.to_builder().describe()
+    }
+
     /// Returns the HTTP client.
     pub fn http_client(&self) -> Option<SharedHttpClient> {
         self.http_client.as_ref().map(|s| s.value.clone())
@@ -703,6 +714,34 @@ impl RuntimeComponentsBuilder {
         self
     }
 
+    /// Adds an interceptor that only runs for operations whose name matches `operation_filter`.
+    ///
+    /// See [`SharedInterceptor::with_operation_filter`] for details.
+    pub fn push_filtered_interceptor(
+        &mut self,
+        operation_filter: impl Fn(&str) -> bool + Send + Sync + 'static,
+        interceptor: impl Intercept + 'static,
+    ) -> &mut Self {
+        let interceptor: SharedInterceptor = interceptor.into_shared();
+        self.interceptors.push(Tracked::new(
+            self.builder_name,
+            interceptor.with_operation_filter(operation_filter),
+        ));
+        self
+    }
+
+    /// Adds an interceptor that only runs for operations whose name matches `operation_filter`.
+    ///
+    /// See [`SharedInterceptor::with_operation_filter`] for details.
+    pub fn with_filtered_interceptor(
+        mut self,
+        operation_filter: impl Fn(&str) -> bool + Send + Sync + 'static,
+        interceptor: impl Intercept + 'static,
+    ) -> Self {
+        self.push_filtered_interceptor(operation_filter, interceptor);
+        self
+    }
+
     /// Directly sets the interceptors and clears out any that were previously pushed.
     pub fn set_interceptors(
         &mut self,
@@ -898,6 +937,51 @@ impl RuntimeComponentsBuilder {
         }
     }
 
+    /// Returns a diagnostic report of the components set on this builder.
+    ///
+    /// See [`RuntimeComponents::describe`].
+    pub fn describe(&self) -> RuntimeComponentsReport {
+        let mut identity_resolvers: Vec<_> = self
+            .identity_resolvers
+            .iter()
+            .flatten()
+            .map(|(scheme_id, resolver)| (*scheme_id, resolver.describe()))
+            .collect();
+        identity_resolvers.sort_by_key(|(scheme_id, _)| scheme_id.as_str());
+        RuntimeComponentsReport {
+            http_client: self.http_client.as_ref().map(Tracked::describe),
+            connector_metadata: self
+                .http_client
+                .as_ref()
+                .and_then(|client| client.value.connector_metadata()),
+            endpoint_resolver: self.endpoint_resolver.as_ref().map(Tracked::describe),
+            auth_scheme_option_resolver: self
+                .auth_scheme_option_resolver
+                .as_ref()
+                .map(Tracked::describe),
+            auth_schemes: self
+                .auth_schemes
+                .iter()
+                .map(|scheme| scheme.describe_as(scheme.value.scheme_id().as_str()))
+                .collect(),
+            identity_cache: self.identity_cache.as_ref().map(Tracked::describe),
+            identity_resolvers,
+            interceptors: self
+                .interceptors
+                .iter()
+                .map(|interceptor| interceptor.describe_as(interceptor.value.name()))
+                .collect(),
+            retry_classifiers: self
+                .retry_classifiers
+                .iter()
+                .map(|classifier| classifier.describe_as(classifier.value.name()))
+                .collect(),
+            retry_strategy: self.retry_strategy.as_ref().map(Tracked::describe),
+            sleep_impl: self.sleep_impl.as_ref().map(Tracked::describe),
+            time_source: self.time_source.as_ref().map(Tracked::describe),
+        }
+    }
+
     /// Wraps `v` in tracking associated with this builder
     fn tracked<T>(&self, v: Option<T>) -> Option<Tracked<T>> {
         v.map(|v| Tracked::new(self.builder_name, v))
@@ -923,25 +1007,233 @@ impl TimeComponents {
     }
 }
 
+/// A diagnostic snapshot of the runtime components, as returned by [`RuntimeComponents::describe`].
+///
+/// Each component is described by its name and the name of the [`RuntimeComponentsBuilder`] that set it,
+/// which is typically the runtime plugin that configured it. The [`Display`](fmt::Display) implementation
+/// renders a human-readable report suitable for logging.
+#[derive(Clone, Debug)]
+pub struct RuntimeComponentsReport {
+    http_client: Option<ComponentDescription>,
+    connector_metadata: Option<ConnectorMetadata>,
+    endpoint_resolver: Option<ComponentDescription>,
+    auth_scheme_option_resolver: Option<ComponentDescription>,
+    auth_schemes: Vec<ComponentDescription>,
+    identity_cache: Option<ComponentDescription>,
+    identity_resolvers: Vec<(AuthSchemeId, ComponentDescription)>,
+    interceptors: Vec<ComponentDescription>,
+    retry_classifiers: Vec<ComponentDescription>,
+    retry_strategy: Option<ComponentDescription>,
+    sleep_impl: Option<ComponentDescription>,
+    time_source: Option<ComponentDescription>,
+}
+
+impl RuntimeComponentsReport {
+    /// Returns the HTTP client, if one is set.
+    pub fn http_client(&self) -> Option<&ComponentDescription> {
+        self.http_client.as_ref()
+    }
+
+    /// Returns the metadata of the connector backing the HTTP client, if it provides any.
+    pub fn connector_metadata(&self) -> Option<&ConnectorMetadata> {
+        self.connector_metadata.as_ref()
+    }
+
+    /// Returns the endpoint resolver, if one is set.
+    pub fn endpoint_resolver(&self) -> Option<&ComponentDescription> {
+        self.endpoint_resolver.as_ref()
+    }
+
+    /// Returns the auth scheme option resolver, if one is set.
+    pub fn auth_scheme_option_resolver(&self) -> Option<&ComponentDescription> {
+        self.auth_scheme_option_resolver.as_ref()
+    }
+
+    /// Returns the auth schemes, named after their scheme IDs.
+    pub fn auth_schemes(&self) -> &[ComponentDescription] {
+        &self.auth_schemes
+    }
+
+    /// Returns the identity cache, if one is set.
+    pub fn identity_cache(&self) -> Option<&ComponentDescription> {
+        self.identity_cache.as_ref()
+    }
+
+    /// Returns the identity resolvers along with the auth scheme they resolve identities for.
+    pub fn identity_resolvers(&self) -> &[(AuthSchemeId, ComponentDescription)] {
+        &self.identity_resolvers
+    }
+
+    /// Returns the interceptors, in the order they run.
+    pub fn interceptors(&self) -> &[ComponentDescription] {
+        &self.interceptors
+    }
+
+    /// Returns the retry classifiers.
+    pub fn retry_classifiers(&self) -> &[ComponentDescription] {
+        &self.retry_classifiers
+    }
+
+    /// Returns the retry strategy, if one is set.
+    pub fn retry_strategy(&self) -> Option<&ComponentDescription> {
+        self.retry_strategy.as_ref()
+    }
+
+    /// Returns the async sleep implementation, if one is set.
+    pub fn sleep_impl(&self) -> Option<&ComponentDescription> {
+        self.sleep_impl.as_ref()
+    }
+
+    /// Returns the time source, if one is set.
+    pub fn time_source(&self) -> Option<&ComponentDescription> {
+        self.time_source.as_ref()
+    }
+}
+
+impl fmt::Display for RuntimeComponentsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn single(
+            f: &mut fmt::Formatter<'_>,
+            label: &str,
+            component: &Option<ComponentDescription>,
+        ) -> fmt::Result {
+            match component {
+                Some(component) => writeln!(f, "{label}: {component}"),
+                None => writeln!(f, "{label}: <none>"),
+            }
+        }
+        fn list<'a>(
+            f: &mut fmt::Formatter<'_>,
+            label: &str,
+            components: impl ExactSizeIterator<Item = &'a ComponentDescription>,
+        ) -> fmt::Result {
+            if components.len() == 0 {
+                return writeln!(f, "{label}: <none>");
+            }
+            writeln!(f, "{label}:")?;
+            for component in components {
+                writeln!(f, "  - {component}")?;
+            }
+            Ok(())
+        }
+
+        single(f, "http_client", &self.http_client)?;
+        if let Some(connector_metadata) = &self.connector_metadata {
+            writeln!(f, "connector: {connector_metadata}")?;
+        }
+        single(f, "endpoint_resolver", &self.endpoint_resolver)?;
+        single(
+            f,
+            "auth_scheme_option_resolver",
+            &self.auth_scheme_option_resolver,
+        )?;
+        list(f, "auth_schemes", self.auth_schemes.iter())?;
+        single(f, "identity_cache", &self.identity_cache)?;
+        if self.identity_resolvers.is_empty() {
+            writeln!(f, "identity_resolvers: <none>")?;
+        } else {
+            writeln!(f, "identity_resolvers:")?;
+            for (scheme_id, resolver) in &self.identity_resolvers {
+                writeln!(f, "  - {}: {resolver}", scheme_id.as_str())?;
+            }
+        }
+        list(f, "interceptors", self.interceptors.iter())?;
+        list(f, "retry_classifiers", self.retry_classifiers.iter())?;
+        single(f, "retry_strategy", &self.retry_strategy)?;
+        single(f, "sleep_impl", &self.sleep_impl)?;
+        single(f, "time_source", &self.time_source)
+    }
+}
+
+/// Describes a single runtime component in a [`RuntimeComponentsReport`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentDescription {
+    name: Cow<'static, str>,
+    origin: &'static str,
+}
+
+impl ComponentDescription {
+    /// Returns the name of the component.
+    ///
+    /// This is the name reported by the component when it has one (e.g. [`Intercept::name`]),
+    /// and the name of the type implementing it otherwise.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the name of the [`RuntimeComponentsBuilder`] that set this component.
+    pub fn origin(&self) -> &'static str {
+        self.origin
+    }
+}
+
+impl fmt::Display for ComponentDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (set by `{}`)", self.name, self.origin)
+    }
+}
+
+/// Returns the name of the type implementing a component, based on its `Debug` output.
+///
+/// `Shared*` wrappers are skipped, so that `SharedEndpointResolver(DefaultResolver { .. })`
+/// is named `DefaultResolver`.
+fn component_name(component: &dyn fmt::Debug) -> String {
+    let debug = format!("{component:?}");
+    let mut rest = debug.as_str();
+    loop {
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+            .unwrap_or(rest.len());
+        let (name, after) = rest.split_at(end);
+        if name.starts_with("Shared") {
+            if let Some(inner) = after.strip_prefix('(') {
+                rest = inner;
+                continue;
+            }
+            if let Some((_, inner)) = after
+                .strip_prefix(" { ")
+                .and_then(|fields| fields.split_once(": "))
+            {
+                rest = inner;
+                continue;
+            }
+        }
+        if name.is_empty() {
+            return debug;
+        }
+        return name.to_owned();
+    }
+}
+
 #[derive(Clone, Debug)]
 #[cfg_attr(test, derive(Eq, PartialEq))]
 pub(crate) struct Tracked<T> {
-    _origin: &'static str,
+    origin: &'static str,
     value: T,
 }
 
 impl<T> Tracked<T> {
     fn new(origin: &'static str, value: T) -> Self {
-        Self {
-            _origin: origin,
-            value,
-        }
+        Self { origin, value }
     }
 
     #[cfg(debug_assertions)]
     pub(crate) fn value(&self) -> &T {
         &self.value
     }
+
+    fn describe_as(&self, name: impl Into<Cow<'static, str>>) -> ComponentDescription {
+        ComponentDescription {
+            name: name.into(),
+            origin: self.origin,
+        }
+    }
+}
+
+impl<T: fmt::Debug> Tracked<T> {
+    fn describe(&self) -> ComponentDescription {
+        self.describe_as(component_name(&self.value))
+    }
 }
 
 impl RuntimeComponentsBuilder {
@@ -1268,6 +1560,72 @@ mod tests {
         let _: Vec<Tracked<TestComponent>> = rc._some_optional_vec;
     }
 
+    #[test]
+    fn component_names_skip_shared_wrappers() {
+        use super::component_name;
+
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct SharedThing(Inner);
+        #[derive(Debug)]
+        struct SharedOtherThing {
+            _inner: Inner,
+        }
+        #[derive(Debug)]
+        struct Inner {
+            _field: u8,
+        }
+
+        assert_eq!("Inner", component_name(&SharedThing(Inner { _field: 1 })));
+        assert_eq!(
+            "Inner",
+            component_name(&SharedOtherThing {
+                _inner: Inner { _field: 1 }
+            })
+        );
+        assert_eq!("Inner", component_name(&Inner { _field: 1 }));
+    }
+
+    #[test]
+    fn describe_reports_components_and_their_origin() {
+        use crate::client::interceptors::Intercept;
+
+        #[derive(Debug)]
+        struct TestInterceptor;
+        impl Intercept for TestInterceptor {
+            fn name(&self) -> &'static str {
+                "TestInterceptor"
+            }
+        }
+
+        let rc = RuntimeComponentsBuilder::for_tests()
+            .merge_from(&RuntimeComponentsBuilder::new("custom").with_interceptor(TestInterceptor))
+            .build()
+            .unwrap();
+        let report = rc.describe();
+
+        let retry_strategy = report.retry_strategy().unwrap();
+        assert_eq!("FakeRetryStrategy", retry_strategy.name());
+        assert!(retry_strategy.origin().ends_with("::for_tests"));
+        assert_eq!("FakeClient", report.http_client().unwrap().name());
+        assert_eq!(None, report.connector_metadata());
+        assert_eq!(
+            vec!["fake"],
+            report
+                .auth_schemes()
+                .iter()
+                .map(|s| s.name())
+                .collect::<Vec<_>>()
+        );
+        let interceptor = &report.interceptors()[0];
+        assert_eq!("TestInterceptor", interceptor.name());
+        assert_eq!("custom", interceptor.origin());
+
+        let rendered = report.to_string();
+        assert!(rendered.contains("retry_strategy: FakeRetryStrategy (set by `"));
+        assert!(rendered.contains("interceptors:\n  - TestInterceptor (set by `custom`)"));
+    }
+
     #[test]
     fn building_test_builder_should_not_panic() {
         let _ = RuntimeComponentsBuilder::for_tests().build(); // should not panic
@@ -196,10 +196,11 @@ impl RuntimePlugin for StaticRuntimePlugin {
 macro_rules! insert_plugin {
     ($vec:expr, $plugin:expr) => {{
         // Insert the plugin in the correct order
+        let vec = $vec;
         let plugin = $plugin;
         let mut insert_index = 0;
         let order = plugin.order();
-        for (index, other_plugin) in $vec.iter().enumerate() {
+        for (index, other_plugin) in vec.iter().enumerate() {
             let other_order = other_plugin.order();
             if other_order <= order {
                 insert_index = index + 1;
@@ -207,7 +208,7 @@ macro_rules! insert_plugin {
                 break;
             }
         }
-        $vec.insert(insert_index, plugin);
+        vec.insert(insert_index, plugin);
     }};
 }
 
@@ -216,7 +217,7 @@ macro_rules! apply_plugins {
         tracing::trace!(concat!("applying ", stringify!($name), " runtime plugins"));
         let mut merged =
             RuntimeComponentsBuilder::new(concat!("apply_", stringify!($name), "_configuration"));
-        for plugin in &$plugins {
+        for plugin in $plugins.iter() {
             if let Some(layer) = plugin.config() {
                 $cfg.push_shared_layer(layer);
             }
@@ -228,10 +229,14 @@ macro_rules! apply_plugins {
 }
 
 /// Used internally in the orchestrator implementation and in the generated code. Not intended to be used elsewhere.
+///
+/// Cloning runtime plugins is cheap: clones share the lists of plugins, and a list is only copied
+/// when a plugin is added to a clone that shares it. The operations of a client therefore share
+/// its client-level plugins.
 #[derive(Default, Clone, Debug)]
 pub struct RuntimePlugins {
-    client_plugins: Vec<SharedRuntimePlugin>,
-    operation_plugins: Vec<SharedRuntimePlugin>,
+    client_plugins: Arc<Vec<SharedRuntimePlugin>>,
+    operation_plugins: Arc<Vec<SharedRuntimePlugin>>,
 }
 
 impl RuntimePlugins {
@@ -254,7 +259,7 @@ impl RuntimePlugins {
     /// Adds a client-level runtime plugin.
     pub fn with_client_plugin(mut self, plugin: impl RuntimePlugin + 'static) -> Self {
         insert_plugin!(
-            self.client_plugins,
+            Arc::make_mut(&mut self.client_plugins),
             IntoShared::<SharedRuntimePlugin>::into_shared(plugin)
         );
         self
@@ -274,7 +279,7 @@ impl RuntimePlugins {
     /// Adds an operation-level runtime plugin.
     pub fn with_operation_plugin(mut self, plugin: impl RuntimePlugin + 'static) -> Self {
         insert_plugin!(
-            self.operation_plugins,
+            Arc::make_mut(&mut self.operation_plugins),
             IntoShared::<SharedRuntimePlugin>::into_shared(plugin)
         );
         self
@@ -311,6 +316,7 @@ mod tests {
     use aws_smithy_types::config_bag::ConfigBag;
     use http_02x::HeaderValue;
     use std::borrow::Cow;
+    use std::sync::Arc;
 
     #[derive(Debug)]
     struct SomeStruct;
@@ -381,6 +387,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn clones_share_plugins_until_modified() {
+        let client = RuntimePlugins::new()
+            .with_client_plugin(SomeStruct)
+            .with_client_plugin(SomeStruct);
+        let other_client = client.clone();
+        assert!(Arc::ptr_eq(
+            &client.client_plugins,
+            &other_client.client_plugins
+        ));
+
+        // Adding operation plugins doesn't copy the client plugins
+        let operation = other_client.clone().with_operation_plugin(SomeStruct);
+        assert!(Arc::ptr_eq(
+            &client.client_plugins,
+            &operation.client_plugins
+        ));
+        assert_eq!(1, operation.operation_plugins.len());
+        assert!(other_client.operation_plugins.is_empty());
+
+        // Adding a client plugin to a clone copies its plugins, and leaves the original untouched
+        let modified = other_client.with_client_plugin(SomeStruct);
+        assert!(!Arc::ptr_eq(
+            &client.client_plugins,
+            &modified.client_plugins
+        ));
+        assert_eq!(2, client.client_plugins.len());
+        assert_eq!(3, modified.client_plugins.len());
+    }
+
     #[tokio::test]
     async fn components_can_wrap_components() {
         // Connector1, the inner connector, creates a response with a `rp1` header
@@ -0,0 +1,102 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Custom attributes for an operation's telemetry.
+//!
+//! Telemetry attributes are key/value pairs that callers attach to an operation invocation, for
+//! example to attribute cost per tenant. The orchestrator records them on the spans it emits, and
+//! interceptors can read them from the config bag with [`TelemetryAttributesExt`].
+
+use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreAppend};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A custom key/value attribute attached to an operation's telemetry.
+///
+/// Attributes are stored in the config bag with [`Layer::store_append`](aws_smithy_types::config_bag::Layer::store_append).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TelemetryAttribute {
+    key: Cow<'static, str>,
+    value: Cow<'static, str>,
+}
+
+impl TelemetryAttribute {
+    /// Creates a new `TelemetryAttribute`.
+    pub fn new(key: impl Into<Cow<'static, str>>, value: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Returns the attribute's key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the attribute's value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for TelemetryAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.key, self.value)
+    }
+}
+
+impl Storable for TelemetryAttribute {
+    type Storer = StoreAppend<Self>;
+}
+
+/// Extension trait for loading [`TelemetryAttribute`]s from a [`ConfigBag`].
+pub trait TelemetryAttributesExt {
+    /// Returns the telemetry attributes for the current operation, ordered by key.
+    ///
+    /// If a key was stored more than once, the most recently stored value wins, so attributes
+    /// set for a single operation take precedence over those set on the client.
+    fn telemetry_attributes(&self) -> Vec<&TelemetryAttribute>;
+}
+
+impl TelemetryAttributesExt for ConfigBag {
+    fn telemetry_attributes(&self) -> Vec<&TelemetryAttribute> {
+        let mut attributes = BTreeMap::new();
+        // `load` yields the most recently stored attributes first
+        for attribute in self.load::<TelemetryAttribute>() {
+            attributes.entry(attribute.key()).or_insert(attribute);
+        }
+        attributes.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_smithy_types::config_bag::Layer;
+
+    #[test]
+    fn most_recent_attribute_wins() {
+        let mut client = Layer::new("client");
+        client.store_append(TelemetryAttribute::new("tenant", "default"));
+        client.store_append(TelemetryAttribute::new("team", "storage"));
+        let mut operation = Layer::new("operation");
+        operation.store_append(TelemetryAttribute::new("tenant", "acme"));
+
+        let cfg = ConfigBag::of_layers(vec![client, operation]);
+        let attributes: Vec<_> = cfg
+            .telemetry_attributes()
+            .into_iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(vec!["team=storage", "tenant=acme"], attributes);
+    }
+
+    #[test]
+    fn no_attributes() {
+        assert!(ConfigBag::base().telemetry_attributes().is_empty());
+    }
+}
@@ -313,6 +313,11 @@ impl<B> Request<B> {
     pub fn add_extension<T: Send + Sync + Clone + 'static>(&mut self, extension: T) {
         self.extensions.insert(extension.clone());
     }
+
+    /// Returns a reference to the extension of type `T` in the request extensions, if there is one
+    pub fn extension<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.extensions.get::<T>()
+    }
 }
 
 impl Request<SdkBody> {
@@ -94,6 +94,7 @@ pub struct Response<B = SdkBody> {
     status: StatusCode,
     headers: Headers,
     body: B,
+    trailers: Option<Headers>,
     extensions: Extensions,
 }
 
@@ -101,7 +102,7 @@ impl<B> Response<B> {
     /// Converts this response into an http 0.x response.
     ///
     /// Depending on the internal storage type, this operation may be free or it may have an internal
-    /// cost.
+    /// cost. Trailers aren't part of an http 0.x response, so they're dropped.
     #[cfg(feature = "http-02x")]
     pub fn try_into_http02x(self) -> Result<http_02x::Response<B>, HttpError> {
         let mut res = http_02x::Response::builder()
@@ -119,7 +120,7 @@ impl<B> Response<B> {
     /// Converts this response into an http 1.x response.
     ///
     /// Depending on the internal storage type, this operation may be free or it may have an internal
-    /// cost.
+    /// cost. Trailers aren't part of an http 1.x response, so they're dropped.
     #[cfg(feature = "http-1x")]
     pub fn try_into_http1x(self) -> Result<http_1x::Response<B>, HttpError> {
         let mut res = http_1x::Response::builder()
@@ -139,6 +140,7 @@ impl<B> Response<B> {
         Response {
             status: self.status,
             body: f(self.body),
+            trailers: self.trailers,
             extensions: self.extensions,
             headers: self.headers,
         }
@@ -149,6 +151,7 @@ impl<B> Response<B> {
         Self {
             status,
             body,
+            trailers: None,
             extensions: Default::default(),
             headers: Default::default(),
         }
@@ -189,10 +192,29 @@ impl<B> Response<B> {
         self.body
     }
 
+    /// Returns the trailers sent after the response body, if there were any
+    ///
+    /// Trailers are only known once the body has been read. They're set when the orchestrator
+    /// buffers a response body. The trailers of a streaming output are read with
+    /// `ByteStream::trailers` once its data has been consumed.
+    pub fn trailers(&self) -> Option<&Headers> {
+        self.trailers.as_ref()
+    }
+
+    /// Sets the trailers sent after the response body
+    pub fn set_trailers(&mut self, trailers: Headers) {
+        self.trailers = Some(trailers);
+    }
+
     /// Adds an extension to the response extensions
     pub fn add_extension<T: Send + Sync + Clone + 'static>(&mut self, extension: T) {
         self.extensions.insert(extension);
     }
+
+    /// Returns a reference to the extension of type `T` in the response extensions, if there is one
+    pub fn extension<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.extensions.get::<T>()
+    }
 }
 
 impl Response<SdkBody> {
@@ -212,6 +234,7 @@ impl<B> TryFrom<http_02x::Response<B>> for Response<B> {
         Ok(Self {
             status: StatusCode::try_from(parts.status.as_u16()).expect("validated by http 0.x"),
             body,
+            trailers: None,
             extensions: parts.extensions.into(),
             headers,
         })
@@ -228,6 +251,7 @@ impl<B> TryFrom<http_1x::Response<B>> for Response<B> {
         Ok(Self {
             status: StatusCode::try_from(parts.status.as_u16()).expect("validated by http 1.x"),
             body,
+            trailers: None,
             extensions: parts.extensions.into(),
             headers,
         })
@@ -267,6 +291,37 @@ mod test {
         assert_eq!(200, http0.status().as_u16());
     }
 
+    #[test]
+    fn extensions_can_be_read() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Marker(&'static str);
+
+        let mut rsp = Response::new(StatusCode::try_from(200).unwrap(), SdkBody::empty());
+        assert_eq!(None, rsp.extension::<Marker>());
+        rsp.add_extension(Marker("added"));
+        assert_eq!(Some(&Marker("added")), rsp.extension::<Marker>());
+
+        let mut http1 = http_1x::Response::new(SdkBody::empty());
+        http1.extensions_mut().insert(Marker("converted"));
+        let rsp = Response::try_from(http1).unwrap();
+        assert_eq!(Some(&Marker("converted")), rsp.extension::<Marker>());
+    }
+
+    #[test]
+    fn trailers_are_kept_when_the_body_is_mapped() {
+        let mut rsp = Response::new(StatusCode::try_from(200).unwrap(), SdkBody::empty());
+        assert!(rsp.trailers().is_none());
+        let mut trailers = Headers::new();
+        trailers.insert("x-amz-checksum-crc32", "sOO8/Q==");
+        rsp.set_trailers(trailers);
+
+        let rsp = rsp.map(|_| ());
+        assert_eq!(
+            Some("sOO8/Q=="),
+            rsp.trailers().unwrap().get("x-amz-checksum-crc32")
+        );
+    }
+
     macro_rules! resp_eq {
         ($a: expr, $b: expr) => {{
             assert_eq!($a.status(), $b.status(), "status code mismatch");
@@ -47,6 +47,9 @@ pub mod interceptors;
 /// Stalled stream protection for clients
 pub mod stalled_stream_protection;
 
+/// Telemetry for clients.
+pub mod telemetry;
+
 /// Generic Smithy SDK feature identifies.
 #[doc(hidden)]
 pub mod sdk_feature;
@@ -3,6 +3,8 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+pub mod fallback;
+
 pub mod no_auth;
 
 #[cfg(feature = "http-auth")]
@@ -0,0 +1,66 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Auth scheme fallback for services that support several auth schemes.
+//!
+//! When a service models more than one auth scheme, e.g. SigV4 and bearer auth, the orchestrator
+//! signs requests with the first auth scheme option it has an identity for. If the service then
+//! rejects the request with a `401` or `403` status code, auth scheme fallback makes the
+//! orchestrator retry the request once with the next eligible auth scheme option.
+//!
+//! Fallback happens at most once per operation invocation: if the request is rejected again with
+//! the second auth scheme, the error is returned. Fallback attempts don't consume the retry quota
+//! of the retry strategy.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aws_smithy_runtime::client::auth::fallback::AuthSchemeFallback;
+//! use aws_smithy_runtime_api::client::runtime_plugin::RuntimePlugins;
+//!
+//! let runtime_plugins = RuntimePlugins::new()
+//!     .with_client_plugin(AuthSchemeFallback::enabled());
+//! ```
+
+use aws_smithy_runtime_api::client::runtime_plugin::RuntimePlugin;
+use aws_smithy_types::config_bag::{FrozenLayer, Layer, Storable, StoreReplace};
+
+/// Configures whether requests rejected with a `401` or `403` status code are retried with the
+/// next eligible auth scheme.
+///
+/// Auth scheme fallback is disabled by default. See the [module docs](self) for more details.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuthSchemeFallback {
+    enabled: bool,
+}
+
+impl AuthSchemeFallback {
+    /// Enables auth scheme fallback.
+    pub fn enabled() -> Self {
+        Self { enabled: true }
+    }
+
+    /// Disables auth scheme fallback.
+    pub fn disabled() -> Self {
+        Self { enabled: false }
+    }
+
+    /// Returns true if auth scheme fallback is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+impl Storable for AuthSchemeFallback {
+    type Storer = StoreReplace<Self>;
+}
+
+impl RuntimePlugin for AuthSchemeFallback {
+    fn config(&self) -> Option<FrozenLayer> {
+        let mut layer = Layer::new("AuthSchemeFallback");
+        layer.store_put(self.clone());
+        Some(layer.freeze())
+    }
+}
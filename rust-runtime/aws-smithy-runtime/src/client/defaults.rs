@@ -14,6 +14,7 @@ use crate::client::identity::IdentityCache;
 use crate::client::retries::strategy::standard::TokenBucketProvider;
 use crate::client::retries::strategy::StandardRetryStrategy;
 use crate::client::retries::RetryPartition;
+use crate::client::telemetry::{MetricsInterceptor, TelemetryInterceptor};
 use aws_smithy_async::rt::sleep::default_async_sleep;
 use aws_smithy_async::time::SystemTimeSource;
 use aws_smithy_runtime_api::box_error::BoxError;
@@ -207,6 +208,17 @@ fn default_stalled_stream_protection_config_plugin_v2(
     )
 }
 
+fn default_telemetry_plugin() -> Option<SharedRuntimePlugin> {
+    Some(
+        default_plugin("default_telemetry_plugin", |components| {
+            components
+                .with_interceptor(TelemetryInterceptor::new())
+                .with_interceptor(MetricsInterceptor::new())
+        })
+        .into_shared(),
+    )
+}
+
 fn enforce_content_length_runtime_plugin() -> Option<SharedRuntimePlugin> {
     Some(EnforceContentLengthRuntimePlugin::new().into_shared())
 }
@@ -288,6 +300,7 @@ pub fn default_plugins(
         default_time_source_plugin(),
         default_timeout_config_plugin(),
         enforce_content_length_runtime_plugin(),
+        default_telemetry_plugin(),
         default_stalled_stream_protection_config_plugin_v2(behavior_version),
     ]
     .into_iter()
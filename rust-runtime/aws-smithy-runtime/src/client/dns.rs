@@ -28,8 +28,13 @@ mod tokio {
     impl ResolveDns for TokioDnsResolver {
         fn resolve_dns<'a>(&'a self, name: &'a str) -> DnsFuture<'a> {
             let name = name.to_string();
+            let span = tracing::debug_span!("smithy.dns", name = %name);
             DnsFuture::new(async move {
-                let result = tokio::task::spawn_blocking(move || (name, 0).to_socket_addrs()).await;
+                let result = tokio::task::spawn_blocking(move || {
+                    let _entered = span.entered();
+                    (name, 0).to_socket_addrs()
+                })
+                .await;
                 match result {
                     Err(join_failure) => Err(ResolveDnsError::new(IoError::new(
                         IoErrorKind::Other,
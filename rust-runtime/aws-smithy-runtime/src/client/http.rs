@@ -6,6 +6,9 @@
 /// Interceptor for connection poisoning.
 pub mod connection_poisoning;
 
+/// Hedged requests for latency-sensitive operations.
+pub mod hedging;
+
 #[cfg(feature = "test-util")]
 pub mod test_util;
 
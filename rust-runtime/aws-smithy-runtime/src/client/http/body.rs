@@ -4,4 +4,6 @@
  */
 
 pub mod content_length_enforcement;
+#[cfg(feature = "response-decompression")]
+pub mod decompression;
 pub mod minimum_throughput;
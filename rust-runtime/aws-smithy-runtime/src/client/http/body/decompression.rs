@@ -0,0 +1,383 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! RuntimePlugin to transparently decompress `gzip` and `deflate` encoded response bodies
+
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::context::{
+    BeforeDeserializationInterceptorContextMut, BeforeTransmitInterceptorContextMut,
+};
+use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::runtime_components::{
+    RuntimeComponents, RuntimeComponentsBuilder,
+};
+use aws_smithy_runtime_api::client::runtime_plugin::RuntimePlugin;
+use aws_smithy_types::body::SdkBody;
+use aws_smithy_types::config_bag::ConfigBag;
+use bytes::{Buf, Bytes};
+use flate2::write::{GzDecoder, ZlibDecoder};
+use http_body_1x::{Frame, SizeHint};
+use pin_project_lite::pin_project;
+use std::borrow::Cow;
+use std::io::Write;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+const ACCEPT_ENCODING: &str = "accept-encoding";
+const CONTENT_ENCODING: &str = "content-encoding";
+const CONTENT_LENGTH: &str = "content-length";
+const SUPPORTED_ENCODINGS: &str = "gzip, deflate";
+
+/// A content coding that can be decompressed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Encoding {
+    Gzip,
+    /// The `deflate` content coding is the zlib format (RFC 1950).
+    Deflate,
+}
+
+impl Encoding {
+    fn from_header(value: &str) -> Option<Self> {
+        match value.trim() {
+            v if v.eq_ignore_ascii_case("gzip") || v.eq_ignore_ascii_case("x-gzip") => {
+                Some(Self::Gzip)
+            }
+            v if v.eq_ignore_ascii_case("deflate") => Some(Self::Deflate),
+            _ => None,
+        }
+    }
+
+    fn decoder(self) -> Decoder {
+        match self {
+            Self::Gzip => Decoder::Gzip(GzDecoder::new(Vec::new())),
+            Self::Deflate => Decoder::Deflate(ZlibDecoder::new(Vec::new())),
+        }
+    }
+}
+
+/// Incremental decoder: compressed data is written in, and decompressed data is taken out as soon
+/// as it is available, so the body never needs to be buffered.
+enum Decoder {
+    Gzip(GzDecoder<Vec<u8>>),
+    Deflate(ZlibDecoder<Vec<u8>>),
+}
+
+impl Decoder {
+    fn decompress(&mut self, data: &[u8]) -> std::io::Result<Bytes> {
+        let output = match self {
+            Self::Gzip(decoder) => {
+                decoder.write_all(data)?;
+                decoder.get_mut()
+            }
+            Self::Deflate(decoder) => {
+                decoder.write_all(data)?;
+                decoder.get_mut()
+            }
+        };
+        Ok(std::mem::take(output).into())
+    }
+
+    fn finish(&mut self) -> std::io::Result<Bytes> {
+        let output = match self {
+            Self::Gzip(decoder) => {
+                decoder.try_finish()?;
+                decoder.get_mut()
+            }
+            Self::Deflate(decoder) => {
+                decoder.try_finish()?;
+                decoder.get_mut()
+            }
+        };
+        Ok(std::mem::take(output).into())
+    }
+}
+
+pin_project! {
+    /// A body-wrapper that decompresses the `InnerBody` as it is read.
+    struct DecompressingBody<InnerBody> {
+        #[pin]
+        body: InnerBody,
+        decoder: Decoder,
+        is_end_stream: bool,
+    }
+}
+
+impl DecompressingBody<SdkBody> {
+    /// Wraps an existing [`SdkBody`] in a decompression layer
+    fn wrap(body: SdkBody, encoding: Encoding) -> SdkBody {
+        // The contents change, so `map_preserve_contents` can't be used here
+        body.map(move |body| {
+            SdkBody::from_body_1_x(DecompressingBody {
+                body,
+                decoder: encoding.decoder(),
+                is_end_stream: false,
+            })
+        })
+    }
+}
+
+impl<
+        E: Into<aws_smithy_types::body::Error>,
+        Data: Buf,
+        InnerBody: http_body_1x::Body<Error = E, Data = Data>,
+    > http_body_1x::Body for DecompressingBody<InnerBody>
+{
+    type Data = Bytes;
+    type Error = aws_smithy_types::body::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        loop {
+            let this = self.as_mut().project();
+            if *this.is_end_stream {
+                return Poll::Ready(None);
+            }
+            match ready!(this.body.poll_frame(cx)) {
+                None => {
+                    *this.is_end_stream = true;
+                    let data = this.decoder.finish()?;
+                    if !data.is_empty() {
+                        return Poll::Ready(Some(Ok(Frame::data(data))));
+                    }
+                }
+                Some(Err(err)) => return Poll::Ready(Some(Err(err.into()))),
+                Some(Ok(frame)) => match frame.into_data() {
+                    Ok(mut data) => {
+                        let data = data.copy_to_bytes(data.remaining());
+                        let data = this.decoder.decompress(&data)?;
+                        // Poll the inner body again when this chunk didn't complete a block
+                        if !data.is_empty() {
+                            return Poll::Ready(Some(Ok(Frame::data(data))));
+                        }
+                    }
+                    Err(trailers) => {
+                        return Poll::Ready(Some(Ok(
+                            trailers.map_data(|mut data| data.copy_to_bytes(data.remaining()))
+                        )))
+                    }
+                },
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.is_end_stream
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        // The decompressed length is unknown
+        SizeHint::default()
+    }
+}
+
+#[derive(Debug, Default)]
+struct DecompressResponseInterceptor {}
+
+impl Intercept for DecompressResponseInterceptor {
+    fn name(&self) -> &'static str {
+        "DecompressResponse"
+    }
+
+    fn modify_before_transmit(
+        &self,
+        context: &mut BeforeTransmitInterceptorContextMut<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let headers = context.request_mut().headers_mut();
+        if !headers.contains_key(ACCEPT_ENCODING) {
+            headers.insert(ACCEPT_ENCODING, SUPPORTED_ENCODINGS);
+        }
+        Ok(())
+    }
+
+    fn modify_before_deserialization(
+        &self,
+        context: &mut BeforeDeserializationInterceptorContextMut<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let headers = context.response().headers();
+        // Multiple codings, e.g. `gzip, gzip`, aren't supported and are passed through as is
+        if headers.get_all(CONTENT_ENCODING).count() != 1 {
+            return Ok(());
+        }
+        let Some(encoding) = headers
+            .get(CONTENT_ENCODING)
+            .and_then(Encoding::from_header)
+        else {
+            return Ok(());
+        };
+
+        tracing::trace!(encoding = ?encoding, "Wrapping response body in decompression.");
+
+        let response = context.response_mut();
+        // The body is no longer encoded, and its length is unknown
+        response.headers_mut().remove(CONTENT_ENCODING);
+        response.headers_mut().remove(CONTENT_LENGTH);
+        let body = response.take_body();
+        *response.body_mut() = DecompressingBody::wrap(body, encoding);
+        Ok(())
+    }
+}
+
+/// Runtime plugin that transparently decompresses `gzip` and `deflate` encoded response bodies
+///
+/// Requests are sent with an `Accept-Encoding: gzip, deflate` header unless one is already set.
+/// When the response has a `Content-Encoding` of `gzip` or `deflate`, its body is decompressed
+/// as it is read, before deserialization, so streaming response bodies don't need to be buffered.
+/// The `Content-Encoding` and `Content-Length` headers are removed from decompressed responses.
+#[derive(Debug, Default)]
+pub struct DecompressResponseRuntimePlugin {}
+
+impl DecompressResponseRuntimePlugin {
+    /// Creates a runtime plugin which installs decompression middleware for response bodies
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl RuntimePlugin for DecompressResponseRuntimePlugin {
+    fn runtime_components(
+        &self,
+        _current_components: &RuntimeComponentsBuilder,
+    ) -> Cow<'_, RuntimeComponentsBuilder> {
+        Cow::Owned(
+            RuntimeComponentsBuilder::new("DecompressResponse")
+                .with_interceptor(DecompressResponseInterceptor {}),
+        )
+    }
+}
+
+#[cfg(all(feature = "test-util", test))]
+mod test {
+    use super::{DecompressResponseRuntimePlugin, DecompressingBody, Encoding};
+    use crate::client::http::test_util::{ReplayEvent, StaticReplayClient};
+    use crate::client::orchestrator::operation::Operation;
+    use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
+    use aws_smithy_types::body::SdkBody;
+    use aws_smithy_types::byte_stream::ByteStream;
+    use aws_smithy_types::timeout::TimeoutConfig;
+    use bytes::Bytes;
+    use flate2::write::{GzEncoder, ZlibEncoder};
+    use flate2::Compression;
+    use http_body_1x::Frame;
+    use std::convert::Infallible;
+    use std::io::Write;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    const DATA: &[u8] =
+        b"Four score and seven years ago our fathers brought forth on this continent";
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn zlib(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    /// Body for tests so we ensure our code works on a body split across multiple frames
+    struct ManyFrameBody {
+        data: Vec<u8>,
+    }
+
+    impl ManyFrameBody {
+        #[allow(clippy::new_ret_no_self)]
+        fn new(mut data: Vec<u8>) -> SdkBody {
+            data.reverse();
+            SdkBody::from_body_1_x(Self { data })
+        }
+    }
+
+    impl http_body_1x::Body for ManyFrameBody {
+        type Data = Bytes;
+        type Error = aws_smithy_types::body::Error;
+
+        fn poll_frame(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+            match self.data.pop() {
+                Some(next) => Poll::Ready(Some(Ok(Frame::data(Bytes::from(vec![next]))))),
+                None => Poll::Ready(None),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn streaming_bodies_are_decompressed() {
+        for (encoding, compressed) in [
+            (Encoding::Gzip, gzip(DATA)),
+            (Encoding::Deflate, zlib(DATA)),
+        ] {
+            let body = DecompressingBody::wrap(ManyFrameBody::new(compressed), encoding);
+            let data = ByteStream::new(body).collect().await.unwrap().into_bytes();
+            assert_eq!(DATA, data.as_ref(), "{encoding:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn truncated_bodies_fail() {
+        let mut compressed = gzip(DATA);
+        compressed.truncate(compressed.len() / 2);
+        let body = DecompressingBody::wrap(SdkBody::from(compressed), Encoding::Gzip);
+        ByteStream::new(body)
+            .collect()
+            .await
+            .expect_err("the gzip stream is incomplete");
+    }
+
+    #[test]
+    fn content_encoding_header() {
+        assert_eq!(Some(Encoding::Gzip), Encoding::from_header("gzip"));
+        assert_eq!(Some(Encoding::Gzip), Encoding::from_header("X-GZIP"));
+        assert_eq!(Some(Encoding::Deflate), Encoding::from_header(" deflate "));
+        assert_eq!(None, Encoding::from_header("br"));
+        assert_eq!(None, Encoding::from_header("identity"));
+    }
+
+    #[tokio::test]
+    async fn responses_are_decompressed_before_deserialization() {
+        let http_client = StaticReplayClient::new(vec![ReplayEvent::new(
+            http_02x::Request::builder()
+                .uri("http://localhost:1234/")
+                .header("accept-encoding", "gzip, deflate")
+                .body(SdkBody::empty())
+                .unwrap(),
+            http_02x::Response::builder()
+                .status(200)
+                .header("content-encoding", "gzip")
+                .body(SdkBody::from(gzip(DATA)))
+                .unwrap(),
+        )]);
+        let operation = Operation::builder()
+            .service_name("test")
+            .operation_name("test")
+            .http_client(http_client.clone())
+            .endpoint_url("http://localhost:1234")
+            .no_auth()
+            .no_retry()
+            .timeout_config(TimeoutConfig::disabled())
+            .runtime_plugin(DecompressResponseRuntimePlugin::new())
+            .serializer(|_: ()| Ok(HttpRequest::new(SdkBody::empty())))
+            .deserializer::<_, Infallible>(|response| {
+                assert!(response.headers().get("content-encoding").is_none());
+                Ok(response.body().bytes().unwrap().to_vec())
+            })
+            .build();
+
+        let output = operation.invoke(()).await.expect("success");
+        assert_eq!(DATA, output.as_slice());
+        http_client.assert_requests_match(&[]);
+    }
+}
@@ -0,0 +1,367 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Hedged requests for latency-sensitive operations.
+//!
+//! When a request doesn't get a response within a latency threshold, a second copy of the request
+//! is sent, and whichever response arrives first is used. The other request is canceled.
+//!
+//! Hedging happens in the HTTP client, once the request has been signed, so the second request is
+//! an exact copy of the first one, including its checksums and signature. Interceptors run once,
+//! and only see the response that was used. Requests whose body can't be cloned, e.g. streaming
+//! uploads, are never hedged.
+//!
+//! Hedged requests are sent twice, so hedging must only be used for idempotent requests. By
+//! default, only requests with a safe HTTP method (`GET`, `HEAD` or `OPTIONS`) are hedged.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aws_smithy_runtime::client::http::hedging::HedgingConfig;
+//! use aws_smithy_runtime_api::client::runtime_plugin::RuntimePlugins;
+//! use std::time::Duration;
+//!
+//! // Send a second request when the first one didn't get a response within 200 milliseconds.
+//! let runtime_plugins = RuntimePlugins::new()
+//!     .with_client_plugin(HedgingConfig::new(Duration::from_millis(200)));
+//! ```
+
+use aws_smithy_async::rt::sleep::{AsyncSleep, SharedAsyncSleep};
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::connector_metadata::ConnectorMetadata;
+use aws_smithy_runtime_api::client::http::{
+    HttpClient, HttpConnector, HttpConnectorFuture, HttpConnectorSettings, SharedHttpClient,
+    SharedHttpConnector,
+};
+use aws_smithy_runtime_api::client::orchestrator::{HttpRequest, HttpResponse};
+use aws_smithy_runtime_api::client::result::ConnectorError;
+use aws_smithy_runtime_api::client::runtime_components::{
+    RuntimeComponents, RuntimeComponentsBuilder,
+};
+use aws_smithy_runtime_api::client::runtime_plugin::{Order, RuntimePlugin};
+use aws_smithy_runtime_api::shared::IntoShared;
+use aws_smithy_types::config_bag::ConfigBag;
+use std::borrow::Cow;
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::Duration;
+
+const SAFE_METHODS: &[&str] = &["GET", "HEAD", "OPTIONS"];
+
+/// Configuration for hedged requests.
+///
+/// `HedgingConfig` is a runtime plugin that wraps the configured HTTP client, so it must be
+/// registered as a client runtime plugin. See the [module docs](self) for more details.
+#[derive(Clone, Debug)]
+pub struct HedgingConfig {
+    delay: Duration,
+    hedge_all_methods: bool,
+}
+
+impl HedgingConfig {
+    /// Creates a `HedgingConfig` that sends a second request when the first one didn't get a
+    /// response after `delay`.
+    ///
+    /// Only requests with a safe HTTP method are hedged.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            hedge_all_methods: false,
+        }
+    }
+
+    /// Hedge requests regardless of their HTTP method.
+    ///
+    /// Protocols such as AWS JSON send every request with `POST`, including requests that are
+    /// idempotent. This must only be enabled for clients whose operations are all idempotent.
+    pub fn hedge_all_methods(mut self) -> Self {
+        self.hedge_all_methods = true;
+        self
+    }
+
+    /// Returns how long to wait for a response before sending a second request.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    fn applies_to(&self, request: &HttpRequest) -> bool {
+        self.hedge_all_methods || SAFE_METHODS.contains(&request.method())
+    }
+}
+
+impl RuntimePlugin for HedgingConfig {
+    fn order(&self) -> Order {
+        Order::NestedComponents
+    }
+
+    fn runtime_components(
+        &self,
+        current_components: &RuntimeComponentsBuilder,
+    ) -> Cow<'_, RuntimeComponentsBuilder> {
+        let mut components = RuntimeComponentsBuilder::new("Hedging");
+        if let Some(inner) = current_components.http_client() {
+            components.set_http_client(Some(HedgingHttpClient {
+                inner,
+                config: self.clone(),
+            }));
+        }
+        Cow::Owned(components)
+    }
+}
+
+#[derive(Debug)]
+struct HedgingHttpClient {
+    inner: SharedHttpClient,
+    config: HedgingConfig,
+}
+
+impl HttpClient for HedgingHttpClient {
+    fn http_connector(
+        &self,
+        settings: &HttpConnectorSettings,
+        components: &RuntimeComponents,
+    ) -> SharedHttpConnector {
+        let inner = self.inner.http_connector(settings, components);
+        match components.sleep_impl() {
+            Some(sleep_impl) => HedgingConnector {
+                inner,
+                sleep_impl,
+                config: self.config.clone(),
+            }
+            .into_shared(),
+            None => {
+                tracing::debug!("requests won't be hedged since no sleep impl is configured");
+                inner
+            }
+        }
+    }
+
+    fn validate_base_client_config(
+        &self,
+        runtime_components: &RuntimeComponentsBuilder,
+        cfg: &ConfigBag,
+    ) -> Result<(), BoxError> {
+        self.inner
+            .validate_base_client_config(runtime_components, cfg)
+    }
+
+    fn validate_final_config(
+        &self,
+        runtime_components: &RuntimeComponents,
+        cfg: &ConfigBag,
+    ) -> Result<(), BoxError> {
+        self.inner.validate_final_config(runtime_components, cfg)
+    }
+
+    fn connector_metadata(&self) -> Option<ConnectorMetadata> {
+        self.inner.connector_metadata()
+    }
+}
+
+#[derive(Debug)]
+struct HedgingConnector {
+    inner: SharedHttpConnector,
+    sleep_impl: SharedAsyncSleep,
+    config: HedgingConfig,
+}
+
+impl HttpConnector for HedgingConnector {
+    fn call(&self, request: HttpRequest) -> HttpConnectorFuture {
+        let hedged_request = if self.config.applies_to(&request) {
+            request.try_clone()
+        } else {
+            None
+        };
+        let first = self.inner.call(request);
+        let Some(hedged_request) = hedged_request else {
+            return first;
+        };
+
+        let inner = self.inner.clone();
+        let delay = self.config.delay;
+        let sleep = self.sleep_impl.sleep(delay);
+        HttpConnectorFuture::new(async move {
+            let mut first = Box::pin(first);
+            let mut sleep = sleep;
+            let response = poll_fn(|cx| match first.as_mut().poll(cx) {
+                Poll::Ready(result) => Poll::Ready(Some(result)),
+                Poll::Pending => Pin::new(&mut sleep).poll(cx).map(|_| None),
+            })
+            .await;
+            if let Some(response) = response {
+                return response;
+            }
+
+            tracing::debug!(
+                delay = ?delay,
+                "no response was received within the hedging delay, sending a hedged request"
+            );
+            first_success(first, Box::pin(inner.call(hedged_request))).await
+        })
+    }
+}
+
+type BoxedConnectorFuture = Pin<Box<HttpConnectorFuture>>;
+
+/// Returns the first successful response, or the error of the first request when both fail.
+///
+/// The request that loses the race is canceled when its future is dropped.
+async fn first_success(
+    first: BoxedConnectorFuture,
+    hedged: BoxedConnectorFuture,
+) -> Result<HttpResponse, ConnectorError> {
+    let mut requests = [Some(first), Some(hedged)];
+    let mut errors: [Option<ConnectorError>; 2] = [None, None];
+    poll_fn(|cx| {
+        for (request, error) in requests.iter_mut().zip(errors.iter_mut()) {
+            if let Some(future) = request {
+                if let Poll::Ready(result) = future.as_mut().poll(cx) {
+                    *request = None;
+                    match result {
+                        Ok(response) => return Poll::Ready(Ok(response)),
+                        Err(err) => *error = Some(err),
+                    }
+                }
+            }
+        }
+        if requests.iter().all(Option::is_none) {
+            let [first_error, _] = &mut errors;
+            Poll::Ready(Err(first_error.take().expect("both requests failed")))
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::HedgingConfig;
+    use aws_smithy_async::rt::sleep::TokioSleep;
+    use aws_smithy_runtime_api::client::http::{
+        http_client_fn, HttpClient, HttpConnector, HttpConnectorFuture, SharedHttpConnector,
+    };
+    use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
+    use aws_smithy_runtime_api::client::runtime_components::RuntimeComponentsBuilder;
+    use aws_smithy_runtime_api::client::runtime_plugin::RuntimePlugins;
+    use aws_smithy_runtime_api::http::Response;
+    use aws_smithy_types::body::SdkBody;
+    use aws_smithy_types::config_bag::ConfigBag;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Connector whose first response takes one second, and the following ones are immediate.
+    /// Responses have a `request-number` header.
+    #[derive(Clone, Debug, Default)]
+    struct SlowFirstResponse {
+        requests: Arc<AtomicUsize>,
+    }
+
+    impl HttpConnector for SlowFirstResponse {
+        fn call(&self, _request: HttpRequest) -> HttpConnectorFuture {
+            let request_number = self.requests.fetch_add(1, Ordering::SeqCst) + 1;
+            HttpConnectorFuture::new(async move {
+                if request_number == 1 {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+                let mut response = Response::new(200.try_into().unwrap(), SdkBody::empty());
+                response
+                    .headers_mut()
+                    .insert("request-number", request_number.to_string());
+                Ok(response)
+            })
+        }
+    }
+
+    async fn send(
+        config: HedgingConfig,
+        connector: &SlowFirstResponse,
+        request: HttpRequest,
+    ) -> String {
+        let connector = connector.clone();
+        let plugins = RuntimePlugins::new()
+            .with_client_plugin(config)
+            .with_client_plugin(
+                aws_smithy_runtime_api::client::runtime_plugin::StaticRuntimePlugin::new()
+                    .with_runtime_components(
+                        RuntimeComponentsBuilder::new("test").with_http_client(Some(
+                            http_client_fn(move |_, _| SharedHttpConnector::new(connector.clone())),
+                        )),
+                    ),
+            );
+        let http_client = plugins
+            .apply_client_configuration(&mut ConfigBag::base())
+            .unwrap()
+            .http_client()
+            .unwrap();
+        let components = RuntimeComponentsBuilder::for_tests()
+            .with_sleep_impl(Some(TokioSleep::new()))
+            .build()
+            .unwrap();
+        let response = http_client
+            .http_connector(&Default::default(), &components)
+            .call(request)
+            .await
+            .unwrap();
+        response.headers().get("request-number").unwrap().to_owned()
+    }
+
+    fn request(method: &str, body: SdkBody) -> HttpRequest {
+        http_02x::Request::builder()
+            .method(method)
+            .uri("https://example.com")
+            .body(body)
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn slow_requests_are_hedged() {
+        let connector = SlowFirstResponse::default();
+        let config = HedgingConfig::new(Duration::from_millis(100));
+        let response = send(config, &connector, request("GET", SdkBody::empty())).await;
+        assert_eq!("2", response);
+        assert_eq!(2, connector.requests.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fast_requests_are_not_hedged() {
+        let connector = SlowFirstResponse::default();
+        let config = HedgingConfig::new(Duration::from_secs(2));
+        let response = send(config, &connector, request("GET", SdkBody::empty())).await;
+        assert_eq!("1", response);
+        assert_eq!(1, connector.requests.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn only_safe_methods_are_hedged_by_default() {
+        let connector = SlowFirstResponse::default();
+        let config = HedgingConfig::new(Duration::from_millis(100));
+        let response = send(
+            config.clone(),
+            &connector,
+            request("POST", SdkBody::empty()),
+        )
+        .await;
+        assert_eq!("1", response);
+
+        let connector = SlowFirstResponse::default();
+        let config = config.hedge_all_methods();
+        let response = send(config, &connector, request("POST", SdkBody::empty())).await;
+        assert_eq!("2", response);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn requests_with_bodies_that_cannot_be_cloned_are_not_hedged() {
+        let connector = SlowFirstResponse::default();
+        let config = HedgingConfig::new(Duration::from_millis(100));
+        let body = SdkBody::from_body_0_4(SdkBody::from("streaming"));
+        let response = send(config, &connector, request("GET", body)).await;
+        assert_eq!("1", response);
+    }
+}
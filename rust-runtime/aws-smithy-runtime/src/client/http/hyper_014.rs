@@ -88,8 +88,145 @@ mod default_connector {
     ///
     /// It requires a minimum TLS version of 1.2.
     /// It allows you to connect to both `http` and `https` URLs.
-    pub(super) fn https() -> hyper_rustls::HttpsConnector<hyper_0_14::client::HttpConnector> {
-        HTTPS_NATIVE_ROOTS.clone()
+    pub(super) fn https() -> tls_info::TlsInfoConnector {
+        tls_info::TlsInfoConnector::new(HTTPS_NATIVE_ROOTS.clone())
+    }
+
+    pub(super) mod tls_info {
+        use aws_smithy_runtime_api::box_error::BoxError;
+        use hyper_0_14::client::connect::{Connected, Connection};
+        use hyper_0_14::client::HttpConnector;
+        use hyper_rustls::{HttpsConnector, MaybeHttpsStream};
+        use std::future::Future;
+        use std::io::IoSlice;
+        use std::pin::Pin;
+        use std::task::{Context, Poll};
+        use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+        use tokio::net::TcpStream;
+
+        /// The parameters negotiated during the TLS handshake, recorded in the extras of the
+        /// hyper connection.
+        #[derive(Clone, Debug)]
+        pub(crate) struct TlsInfo {
+            pub(crate) alpn_protocol: Option<String>,
+            pub(crate) tls_version: Option<String>,
+            pub(crate) cipher_suite: Option<String>,
+        }
+
+        impl From<&rustls::ClientConnection> for TlsInfo {
+            fn from(connection: &rustls::ClientConnection) -> Self {
+                Self {
+                    alpn_protocol: connection
+                        .alpn_protocol()
+                        .map(|protocol| String::from_utf8_lossy(protocol).into_owned()),
+                    tls_version: connection.protocol_version().map(|version| {
+                        version
+                            .as_str()
+                            .map(str::to_owned)
+                            .unwrap_or_else(|| format!("{version:?}"))
+                    }),
+                    cipher_suite: connection.negotiated_cipher_suite().map(|suite| {
+                        let suite = suite.suite();
+                        suite
+                            .as_str()
+                            .map(str::to_owned)
+                            .unwrap_or_else(|| format!("{suite:?}"))
+                    }),
+                }
+            }
+        }
+
+        /// HTTPS connector that records the parameters of the TLS handshake in the connections it
+        /// makes, so that they are included in the
+        /// [`ConnectionMetadata`](aws_smithy_runtime_api::client::connection::ConnectionMetadata).
+        #[derive(Clone)]
+        pub(crate) struct TlsInfoConnector {
+            inner: HttpsConnector<HttpConnector>,
+        }
+
+        impl TlsInfoConnector {
+            pub(crate) fn new(inner: HttpsConnector<HttpConnector>) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl hyper_0_14::service::Service<http_02x::Uri> for TlsInfoConnector {
+            type Response = TlsInfoStream;
+            type Error = BoxError;
+            #[allow(clippy::type_complexity)]
+            type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+            fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+                self.inner.poll_ready(cx)
+            }
+
+            fn call(&mut self, uri: http_02x::Uri) -> Self::Future {
+                let connecting = self.inner.call(uri);
+                Box::pin(async move { Ok(TlsInfoStream(connecting.await?)) })
+            }
+        }
+
+        /// Stream of a [`TlsInfoConnector`] connection.
+        pub(crate) struct TlsInfoStream(MaybeHttpsStream<TcpStream>);
+
+        impl Connection for TlsInfoStream {
+            fn connected(&self) -> Connected {
+                let connected = self.0.connected();
+                match &self.0 {
+                    MaybeHttpsStream::Https(stream) => {
+                        let (_, tls) = stream.get_ref();
+                        connected.extra(TlsInfo::from(tls))
+                    }
+                    MaybeHttpsStream::Http(_) => connected,
+                }
+            }
+        }
+
+        impl AsyncRead for TlsInfoStream {
+            fn poll_read(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &mut ReadBuf<'_>,
+            ) -> Poll<std::io::Result<()>> {
+                Pin::new(&mut self.0).poll_read(cx, buf)
+            }
+        }
+
+        impl AsyncWrite for TlsInfoStream {
+            fn poll_write(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &[u8],
+            ) -> Poll<std::io::Result<usize>> {
+                Pin::new(&mut self.0).poll_write(cx, buf)
+            }
+
+            fn poll_write_vectored(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                bufs: &[IoSlice<'_>],
+            ) -> Poll<std::io::Result<usize>> {
+                Pin::new(&mut self.0).poll_write_vectored(cx, bufs)
+            }
+
+            fn is_write_vectored(&self) -> bool {
+                self.0.is_write_vectored()
+            }
+
+            fn poll_flush(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+            ) -> Poll<std::io::Result<()>> {
+                Pin::new(&mut self.0).poll_flush(cx)
+            }
+
+            fn poll_shutdown(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+            ) -> Poll<std::io::Result<()>> {
+                Pin::new(&mut self.0).poll_shutdown(cx)
+            }
+        }
     }
 }
 
@@ -294,6 +431,13 @@ fn extract_smithy_connection(capture_conn: &CaptureConnection) -> Option<Connect
         builder
             .set_local_addr(http_info.map(|info| info.local_addr()))
             .set_remote_addr(http_info.map(|info| info.remote_addr()));
+        #[cfg(feature = "tls-rustls")]
+        if let Some(tls_info) = extensions.get::<default_connector::tls_info::TlsInfo>() {
+            builder
+                .set_alpn_protocol(tls_info.alpn_protocol.clone())
+                .set_tls_version(tls_info.tls_version.clone())
+                .set_cipher_suite(tls_info.cipher_suite.clone());
+        }
 
         let smithy_connection = builder.build();
 
@@ -324,6 +468,7 @@ where
         if let Some(capture_smithy_connection) =
             request.extensions().get::<CaptureSmithyConnection>()
         {
+            let capture_connection = capture_connection.clone();
             capture_smithy_connection
                 .set_connection_retriever(move || extract_smithy_connection(&capture_connection));
         }
@@ -335,7 +480,13 @@ where
                 .map_err(downcast_error)?
                 .map(SdkBody::from_body_0_4);
             match HttpResponse::try_from(response) {
-                Ok(response) => Ok(response),
+                Ok(mut response) => {
+                    // Expose the metadata of the connection the response was received on
+                    if let Some(connection) = extract_smithy_connection(&capture_connection) {
+                        response.add_extension(connection);
+                    }
+                    Ok(response)
+                }
                 Err(err) => Err(ConnectorError::other(err.into(), None)),
             }
         })
@@ -631,6 +782,8 @@ mod timeout_middleware {
     use std::pin::Pin;
     use std::task::{Context, Poll};
     use std::time::Duration;
+    use tracing::instrument::Instrumented;
+    use tracing::{debug_span, Instrument};
 
     #[derive(Debug)]
     pub(crate) struct HttpTimeoutError {
@@ -766,25 +919,25 @@ mod timeout_middleware {
     {
         type Response = I::Response;
         type Error = BoxError;
-        type Future = MaybeTimeoutFuture<I::Future>;
+        type Future = MaybeTimeoutFuture<Instrumented<I::Future>>;
 
         fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
             self.inner.poll_ready(cx).map_err(|err| err.into())
         }
 
         fn call(&mut self, req: http_02x::Uri) -> Self::Future {
+            let span = debug_span!("smithy.connect", uri = %req);
+            let future = self.inner.call(req).instrument(span);
             match &self.timeout {
                 Some((sleep, duration)) => {
                     let sleep = sleep.sleep(*duration);
                     MaybeTimeoutFuture::Timeout {
-                        timeout: Timeout::new(self.inner.call(req), sleep),
+                        timeout: Timeout::new(future, sleep),
                         error_type: "HTTP connect",
                         duration: *duration,
                     }
                 }
-                None => MaybeTimeoutFuture::NoTimeout {
-                    future: self.inner.call(req),
-                },
+                None => MaybeTimeoutFuture::NoTimeout { future },
             }
         }
     }
@@ -1080,6 +1233,96 @@ mod test {
         assert!(err.is_io(), "{:?}", err);
     }
 
+    #[cfg(feature = "tls-rustls")]
+    #[tokio::test]
+    async fn connection_metadata_is_exposed_on_responses() {
+        use crate::client::http::hyper_014::default_connector::tls_info::TlsInfo;
+        use aws_smithy_runtime_api::client::connection::ConnectionMetadata;
+
+        let connector = TestConnection {
+            inner: CannedResponseStream {
+                response: b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n",
+                request_written: false,
+                read_waker: None,
+                tls_info: TlsInfo {
+                    alpn_protocol: Some("http/1.1".into()),
+                    tls_version: Some("TLSv1_3".into()),
+                    cipher_suite: Some("TLS13_AES_256_GCM_SHA384".into()),
+                },
+            },
+        };
+        let adapter = HyperConnector::builder().build(connector).adapter;
+        let response = adapter
+            .call(HttpRequest::get("https://example.com").unwrap())
+            .await
+            .expect("success");
+        let connection = response
+            .extension::<ConnectionMetadata>()
+            .expect("connection metadata is set");
+        assert_eq!(Some("http/1.1"), connection.alpn_protocol());
+        assert_eq!(Some("TLSv1_3"), connection.tls_version());
+        assert_eq!(Some("TLS13_AES_256_GCM_SHA384"), connection.cipher_suite());
+    }
+
+    // ---- machinery to make a Hyper connector that responds with a canned response over TLS
+    #[cfg(feature = "tls-rustls")]
+    #[derive(Clone)]
+    struct CannedResponseStream {
+        response: &'static [u8],
+        // The response is only sent once the request was written
+        request_written: bool,
+        read_waker: Option<std::task::Waker>,
+        tls_info: crate::client::http::hyper_014::default_connector::tls_info::TlsInfo,
+    }
+
+    #[cfg(feature = "tls-rustls")]
+    impl Connection for CannedResponseStream {
+        fn connected(&self) -> Connected {
+            Connected::new().extra(self.tls_info.clone())
+        }
+    }
+
+    #[cfg(feature = "tls-rustls")]
+    impl AsyncRead for CannedResponseStream {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            if !self.request_written || self.response.is_empty() {
+                self.read_waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+            let len = self.response.len().min(buf.remaining());
+            buf.put_slice(&self.response[..len]);
+            self.response = &self.response[len..];
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[cfg(feature = "tls-rustls")]
+    impl AsyncWrite for CannedResponseStream {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<Result<usize, Error>> {
+            self.request_written = true;
+            if let Some(waker) = self.read_waker.take() {
+                waker.wake();
+            }
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
     // ---- machinery to make a Hyper connector that responds with an IO Error
     #[derive(Clone)]
     struct HangupStream;
@@ -18,8 +18,8 @@
 //! [`RecordingClient`](dvr::RecordingClient) and [`ReplayingClient`](dvr::ReplayingClient)
 //! can accomplish this, and the recorded traffic can be saved to JSON and checked in. Note: if
 //! the traffic recording has sensitive information in it, such as signatures or authorization,
-//! you will need to manually scrub this out if you intend to store the recording alongside
-//! your tests.
+//! you will need to scrub this out if you intend to store the recording alongside your tests,
+//! either manually or with the recording client's header redaction hooks.
 //! - [`StaticReplayClient`]: If you want to have a set list of requests and their responses in a test,
 //! then the static replay client will be useful. On construction, it takes a list of request/response
 //! pairs that represent each expected request and the response for that test. At the end of the test,
@@ -27,6 +27,8 @@
 //! - [`infallible_client_fn`]: Allows you to create a client from an infallible function
 //! that takes a request and returns a response.
 //! - [`NeverClient`]: Useful for testing timeouts, where you want the client to never respond.
+//! - [`FaultInjectionClient`]: Injects network faults, such as connection resets, latency or truncated
+//! responses, into the responses of another client on given attempts. Useful for testing retries and timeouts.
 //!
 #![cfg_attr(
     feature = "connector-hyper-0-14-x",
@@ -45,6 +47,9 @@ pub mod dvr;
 mod replay;
 pub use replay::{ReplayEvent, StaticReplayClient};
 
+mod fault_injection;
+pub use fault_injection::{Fault, FaultInjectionClient};
+
 mod infallible;
 pub use infallible::infallible_client_fn;
 
@@ -21,7 +21,7 @@ use std::path::Path;
 mod record;
 mod replay;
 
-pub use record::RecordingClient;
+pub use record::{RecordingClient, AUTH_HEADERS, REDACTED};
 pub use replay::ReplayingClient;
 
 /// A complete traffic recording
@@ -377,4 +377,54 @@ mod tests {
         );
         Ok(())
     }
+
+    #[tokio::test]
+    async fn redacts_recorded_headers() -> Result<(), Box<dyn Error>> {
+        let network_traffic = fs::read_to_string("test-data/example.com.json")?;
+        let network_traffic: NetworkTraffic = serde_json::from_str(&network_traffic)?;
+        let inner = ReplayingClient::new(network_traffic.events.clone());
+        let connection = RecordingClient::new(SharedHttpConnector::new(inner))
+            .redact_auth_headers()
+            .redact_headers(["Content-Type"]);
+        let req = http_02x::Request::post("https://www.example.com")
+            .header("authorization", "AWS4-HMAC-SHA256 Credential=secret")
+            .header("x-amz-user-agent", "dvr")
+            .body(SdkBody::from("hello world"))
+            .unwrap();
+        let mut resp = connection.call(req.try_into().unwrap()).await.expect("ok");
+        // the sent request and the returned response aren't redacted
+        assert_eq!(
+            Some("text/html; charset=UTF-8"),
+            resp.headers().get("content-type")
+        );
+        let body = std::mem::replace(resp.body_mut(), SdkBody::taken());
+        ByteStream::new(body).collect().await.unwrap();
+
+        let events = connection.events();
+        let Action::Request { request } = &events[0].action else {
+            panic!("unexpected event: {:?}", events[0].action);
+        };
+        assert_eq!(
+            Some(&vec![REDACTED.to_string()]),
+            request.headers.get("authorization")
+        );
+        assert_eq!(
+            Some(&vec!["dvr".to_string()]),
+            request.headers.get("x-amz-user-agent")
+        );
+        let Some(Action::Response {
+            response: Ok(response),
+        }) = events
+            .iter()
+            .map(|event| &event.action)
+            .find(|action| matches!(action, Action::Response { .. }))
+        else {
+            panic!("no response was recorded: {events:?}");
+        };
+        assert_eq!(
+            Some(&vec![REDACTED.to_string()]),
+            response.headers.get("content-type")
+        );
+        Ok(())
+    }
 }
@@ -16,10 +16,11 @@ use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
 use aws_smithy_runtime_api::shared::IntoShared;
 use aws_smithy_types::body::SdkBody;
 use http_body_04x::Body;
+use indexmap::IndexMap;
 use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, MutexGuard};
-use std::{fs, io};
+use std::{fmt, fs, io};
 use tokio::task::JoinHandle;
 
 /// Recording client
@@ -67,11 +68,68 @@ use tokio::task::JoinHandle;
 ///     http_client.dump_to_file("tests/data/content-length-enforcement/head-object.json").unwrap();
 /// }
 /// ```
+///
+/// # Redacting credentials
+///
+/// Requests sent with real credentials are signed, so recordings of them contain secrets that
+/// shouldn't be checked in. Use [`RecordingClient::redact_auth_headers`] to replace the values of the
+/// headers carrying them with [`REDACTED`] in the recording. Other headers can be redacted by name with
+/// [`RecordingClient::redact_headers`], or with a custom hook with [`RecordingClient::redact_headers_with`]:
+///
+/// ```rust,ignore
+/// let http_client = RecordingClient::new(http_client)
+///     .redact_auth_headers()
+///     .redact_headers(["x-amz-request-id"]);
+/// ```
+///
+/// Since authorization headers differ between runs anyway, replay the recording with
+/// [`ReplayingClient::relaxed_validate`](super::ReplayingClient::relaxed_validate), which doesn't
+/// check them.
 #[derive(Clone, Debug)]
 pub struct RecordingClient {
     pub(crate) data: Arc<Mutex<Vec<Event>>>,
     pub(crate) num_events: Arc<AtomicUsize>,
     pub(crate) inner: SharedHttpConnector,
+    pub(crate) redactions: Redactions,
+}
+
+/// The value recorded in place of redacted header values
+pub const REDACTED: &str = "** REDACTED **";
+
+/// Headers that carry credentials, redacted by [`RecordingClient::redact_auth_headers`]
+pub const AUTH_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "x-amz-security-token",
+    "cookie",
+    "set-cookie",
+];
+
+type RedactHeader = Arc<dyn Fn(&str, &mut String) + Send + Sync>;
+
+#[derive(Clone, Default)]
+pub(crate) struct Redactions {
+    hooks: Vec<RedactHeader>,
+}
+
+impl fmt::Debug for Redactions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Redactions")
+            .field("hooks", &self.hooks.len())
+            .finish()
+    }
+}
+
+impl Redactions {
+    fn apply(&self, headers: &mut IndexMap<String, Vec<String>>) {
+        for (name, values) in headers.iter_mut() {
+            for value in values.iter_mut() {
+                for hook in &self.hooks {
+                    hook(name, value);
+                }
+            }
+        }
+    }
 }
 
 #[cfg(feature = "tls-rustls")]
@@ -83,6 +141,7 @@ impl RecordingClient {
             data: Default::default(),
             num_events: Arc::new(AtomicUsize::new(0)),
             inner: SharedHttpConnector::new(HyperConnector::builder().build_https()),
+            redactions: Default::default(),
         }
     }
 }
@@ -94,9 +153,44 @@ impl RecordingClient {
             data: Default::default(),
             num_events: Arc::new(AtomicUsize::new(0)),
             inner: underlying_connector.into_shared(),
+            redactions: Default::default(),
         }
     }
 
+    /// Redact the values of the headers that carry credentials, listed in [`AUTH_HEADERS`], in
+    /// recorded requests and responses
+    pub fn redact_auth_headers(self) -> Self {
+        self.redact_headers(AUTH_HEADERS.iter().copied())
+    }
+
+    /// Redact the values of the given headers in recorded requests and responses
+    ///
+    /// Header names are matched case-insensitively.
+    pub fn redact_headers(self, headers: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let headers: Vec<String> = headers
+            .into_iter()
+            .map(|header| header.into().to_ascii_lowercase())
+            .collect();
+        self.redact_headers_with(move |name, value| {
+            if headers.iter().any(|header| header == name) {
+                *value = REDACTED.to_string();
+            }
+        })
+    }
+
+    /// Redact recorded header values with a hook
+    ///
+    /// The hook is called with the (lowercase) name and the value of every header of the recorded
+    /// requests and responses, and can modify the value that's recorded. It doesn't modify the
+    /// requests sent or the responses returned by this client.
+    pub fn redact_headers_with(
+        mut self,
+        hook: impl Fn(&str, &mut String) + Send + Sync + 'static,
+    ) -> Self {
+        self.redactions.hooks.push(Arc::new(hook));
+        self
+    }
+
     /// Return the traffic recorded by this connection
     pub fn events(&self) -> MutexGuard<'_, Vec<Event>> {
         self.data.lock().unwrap()
@@ -196,10 +290,12 @@ impl HttpConnector for RecordingClient {
         // the channel should be closed.
 
         // Phase 1: the initial http request
+        let mut recorded_request = Request::from(&request);
+        self.redactions.apply(&mut recorded_request.headers);
         self.data.lock().unwrap().push(Event {
             connection_id: event_id,
             action: Action::Request {
-                request: Request::from(&request),
+                request: recorded_request,
             },
         });
 
@@ -213,6 +309,7 @@ impl HttpConnector for RecordingClient {
             self.data.clone(),
         );
         let events = self.data.clone();
+        let redactions = self.redactions.clone();
         // create a channel we'll use to stream the data while reading it
         let resp_fut = self.inner.call(request);
         let fut = async move {
@@ -220,10 +317,12 @@ impl HttpConnector for RecordingClient {
             match resp {
                 Ok(mut resp) => {
                     // push the initial response event
+                    let mut recorded_response = Response::from(&resp);
+                    redactions.apply(&mut recorded_response.headers);
                     events.lock().unwrap().push(Event {
                         connection_id: event_id,
                         action: Action::Response {
-                            response: Ok(Response::from(&resp)),
+                            response: Ok(recorded_response),
                         },
                     });
 
@@ -0,0 +1,360 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Test client that injects network faults into the responses of another client
+
+use aws_smithy_async::rt::sleep::{AsyncSleep, SharedAsyncSleep};
+use aws_smithy_runtime_api::client::connector_metadata::ConnectorMetadata;
+use aws_smithy_runtime_api::client::http::{
+    HttpClient, HttpConnector, HttpConnectorFuture, HttpConnectorSettings, SharedHttpConnector,
+};
+use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
+use aws_smithy_runtime_api::client::result::ConnectorError;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_runtime_api::shared::IntoShared;
+use aws_smithy_types::body::SdkBody;
+use bytes::Bytes;
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A network fault injected by a [`FaultInjectionClient`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Fault {
+    /// The connection is reset before a response is received.
+    ///
+    /// The attempt fails with an IO [`ConnectorError`].
+    ConnectionReset,
+
+    /// The response is delayed by the given duration.
+    ///
+    /// This can be combined with other faults, and is applied before them.
+    Latency(Duration),
+
+    /// The connection is reset after the given number of bytes of the response body were received.
+    ///
+    /// Reading the body fails with an [`io::ErrorKind::ConnectionReset`] error.
+    ResetMidBody {
+        /// Number of bytes of the body received before the reset
+        after_bytes: usize,
+    },
+
+    /// The response body ends, without error, after the given number of bytes.
+    ///
+    /// The headers of the response, including its `content-length`, are unchanged.
+    TruncatedResponse {
+        /// Number of bytes of the body received before it ends
+        after_bytes: usize,
+    },
+
+    /// The response body is malformed.
+    ///
+    /// Reading the body fails with an [`io::ErrorKind::InvalidData`] error, like reading a body with
+    /// an invalid chunk size in its chunked transfer encoding does.
+    MalformedChunkedEncoding,
+}
+
+/// A client that injects network faults into the responses of another client.
+///
+/// Faults are injected on given attempts, counted from 1 across every request made with the client,
+/// so that the retry and timeout behavior of a generated client can be tested deterministically.
+/// The faults are emulated at the level of the [`HttpConnector`], and surface as the errors the default
+/// HTTPS client would return for them.
+///
+/// # Examples
+///
+/// ```rust
+/// use aws_smithy_runtime::client::http::test_util::{
+///     Fault, FaultInjectionClient, StaticReplayClient,
+/// };
+/// use std::time::Duration;
+///
+/// let responses = StaticReplayClient::new(vec![/* ... */]);
+/// let http_client = FaultInjectionClient::new(responses)
+///     // the first attempt is reset, and the retry takes 2 seconds
+///     .inject(1, Fault::ConnectionReset)
+///     .inject(2, Fault::Latency(Duration::from_secs(2)))
+///     // the third attempt's response is cut off after 10 bytes
+///     .inject(3, Fault::ResetMidBody { after_bytes: 10 });
+/// ```
+///
+/// Latency faults sleep with the sleep implementation of the runtime components of the client the
+/// `FaultInjectionClient` is configured on, so they can be tested without waiting with tokio's
+/// paused time.
+#[derive(Clone, Debug)]
+pub struct FaultInjectionClient {
+    inner: SharedHttpConnector,
+    faults: Arc<Vec<(Option<usize>, Fault)>>,
+    attempts: Arc<AtomicUsize>,
+    sleep_impl: Option<SharedAsyncSleep>,
+}
+
+impl FaultInjectionClient {
+    /// Creates a client that injects faults into the responses of `inner`.
+    pub fn new(inner: impl HttpConnector + 'static) -> Self {
+        Self {
+            inner: inner.into_shared(),
+            faults: Default::default(),
+            attempts: Default::default(),
+            sleep_impl: None,
+        }
+    }
+
+    /// Injects `fault` on the given attempt, counted from 1.
+    pub fn inject(self, attempt: usize, fault: Fault) -> Self {
+        self.push_fault(Some(attempt), fault)
+    }
+
+    /// Injects `fault` on every attempt, for example to add latency to every response.
+    pub fn inject_on_every_attempt(self, fault: Fault) -> Self {
+        self.push_fault(None, fault)
+    }
+
+    /// Sets the sleep implementation used for latency faults when this client is used as an
+    /// [`HttpConnector`] directly, rather than through the runtime components of a client.
+    pub fn sleep_impl(mut self, sleep_impl: impl Into<SharedAsyncSleep>) -> Self {
+        self.sleep_impl = Some(sleep_impl.into());
+        self
+    }
+
+    /// Returns the number of attempts made with this client.
+    pub fn num_calls(&self) -> usize {
+        self.attempts.load(Ordering::SeqCst)
+    }
+
+    fn push_fault(mut self, attempt: Option<usize>, fault: Fault) -> Self {
+        Arc::make_mut(&mut self.faults).push((attempt, fault));
+        self
+    }
+}
+
+impl HttpConnector for FaultInjectionClient {
+    fn call(&self, request: HttpRequest) -> HttpConnectorFuture {
+        let attempt = self.attempts.fetch_add(1, Ordering::SeqCst) + 1;
+        let faults: Vec<Fault> = self
+            .faults
+            .iter()
+            .filter(|(fault_attempt, _)| fault_attempt.map_or(true, |a| a == attempt))
+            .map(|(_, fault)| fault.clone())
+            .collect();
+        let latency: Duration = faults
+            .iter()
+            .filter_map(|fault| match fault {
+                Fault::Latency(latency) => Some(*latency),
+                _ => None,
+            })
+            .sum();
+        let sleep_impl = self.sleep_impl.clone();
+        let inner = self.inner.clone();
+        HttpConnectorFuture::new(async move {
+            if !latency.is_zero() {
+                let sleep_impl = sleep_impl
+                    .expect("a sleep implementation is required to inject latency faults");
+                sleep_impl.sleep(latency).await;
+            }
+            if faults.contains(&Fault::ConnectionReset) {
+                return Err(ConnectorError::io(
+                    io::Error::new(
+                        io::ErrorKind::ConnectionReset,
+                        format!("connection reset on attempt {attempt} (injected fault)"),
+                    )
+                    .into(),
+                ));
+            }
+            let mut response = inner.call(request).await?;
+            let body_fault = faults.into_iter().find_map(|fault| match fault {
+                Fault::ResetMidBody { after_bytes } => Some((after_bytes, BodyEnd::Reset)),
+                Fault::TruncatedResponse { after_bytes } => Some((after_bytes, BodyEnd::Eof)),
+                Fault::MalformedChunkedEncoding => Some((0, BodyEnd::Malformed)),
+                _ => None,
+            });
+            if let Some((after_bytes, end)) = body_fault {
+                let body = std::mem::replace(response.body_mut(), SdkBody::taken());
+                *response.body_mut() = SdkBody::from_body_0_4(FaultyBody {
+                    inner: body,
+                    remaining: after_bytes,
+                    end: Some(end),
+                });
+            }
+            Ok(response)
+        })
+    }
+}
+
+impl HttpClient for FaultInjectionClient {
+    fn http_connector(
+        &self,
+        _: &HttpConnectorSettings,
+        components: &RuntimeComponents,
+    ) -> SharedHttpConnector {
+        let mut client = self.clone();
+        if client.sleep_impl.is_none() {
+            client.sleep_impl = components.sleep_impl();
+        }
+        client.into_shared()
+    }
+
+    fn connector_metadata(&self) -> Option<ConnectorMetadata> {
+        Some(ConnectorMetadata::new("fault-injection-client", None))
+    }
+}
+
+#[derive(Debug)]
+enum BodyEnd {
+    Reset,
+    Eof,
+    Malformed,
+}
+
+/// A body that ends with the given fault after `remaining` bytes of the inner body
+#[derive(Debug)]
+struct FaultyBody {
+    inner: SdkBody,
+    remaining: usize,
+    end: Option<BodyEnd>,
+}
+
+impl FaultyBody {
+    fn end(&mut self) -> Poll<Option<Result<Bytes, io::Error>>> {
+        Poll::Ready(match self.end.take() {
+            Some(BodyEnd::Reset) => Some(Err(io::Error::new(
+                io::ErrorKind::ConnectionReset,
+                "connection reset while reading the response body (injected fault)",
+            ))),
+            Some(BodyEnd::Malformed) => Some(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid chunk size in the response body (injected fault)",
+            ))),
+            Some(BodyEnd::Eof) | None => None,
+        })
+    }
+}
+
+impl http_body_04x::Body for FaultyBody {
+    type Data = Bytes;
+    type Error = io::Error;
+
+    fn poll_data(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        if self.remaining == 0 {
+            return self.end();
+        }
+        match Pin::new(&mut self.inner).poll_data(cx) {
+            Poll::Ready(Some(Ok(mut data))) => {
+                data.truncate(self.remaining);
+                self.remaining -= data.len();
+                Poll::Ready(Some(Ok(data)))
+            }
+            Poll::Ready(Some(Err(err))) => {
+                self.end = None;
+                Poll::Ready(Some(Err(io::Error::new(io::ErrorKind::Other, err))))
+            }
+            // The fault happens at the end of bodies shorter than `remaining`
+            Poll::Ready(None) => self.end(),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<http_02x::HeaderMap>, Self::Error>> {
+        Poll::Ready(Ok(None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fault, FaultInjectionClient};
+    use crate::client::http::test_util::{ReplayEvent, StaticReplayClient};
+    use aws_smithy_async::rt::sleep::{SharedAsyncSleep, TokioSleep};
+    use aws_smithy_runtime_api::client::http::HttpConnector;
+    use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
+    use aws_smithy_types::body::SdkBody;
+    use aws_smithy_types::byte_stream::ByteStream;
+    use std::io;
+    use std::time::Duration;
+
+    const BODY: &str = "hello from the other side";
+
+    fn replay(attempts: usize) -> StaticReplayClient {
+        StaticReplayClient::new(
+            (0..attempts)
+                .map(|_| {
+                    ReplayEvent::new(
+                        http_02x::Request::new(SdkBody::empty()),
+                        http_02x::Response::builder()
+                            .header("content-length", BODY.len())
+                            .body(SdkBody::from(BODY))
+                            .unwrap(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    async fn read_body(client: &FaultInjectionClient) -> Result<String, io::ErrorKind> {
+        let response = client
+            .call(HttpRequest::new(SdkBody::empty()))
+            .await
+            .map_err(|err| {
+                assert!(err.is_io(), "{err:?}");
+                io::ErrorKind::ConnectionAborted
+            })?;
+        ByteStream::new(response.into_body())
+            .collect()
+            .await
+            .map(|data| String::from_utf8(data.to_vec()).unwrap())
+            .map_err(|err| {
+                std::error::Error::source(&err)
+                    .and_then(|source| source.downcast_ref::<io::Error>())
+                    .expect("io error")
+                    .kind()
+            })
+    }
+
+    #[tokio::test]
+    async fn injects_faults_on_the_given_attempts() {
+        let client = FaultInjectionClient::new(replay(4))
+            .inject(1, Fault::ConnectionReset)
+            .inject(2, Fault::ResetMidBody { after_bytes: 5 })
+            .inject(3, Fault::TruncatedResponse { after_bytes: 5 })
+            .inject(4, Fault::MalformedChunkedEncoding)
+            .inject(6, Fault::TruncatedResponse { after_bytes: 100 });
+
+        assert_eq!(
+            Err(io::ErrorKind::ConnectionAborted),
+            read_body(&client).await
+        );
+        assert_eq!(
+            Err(io::ErrorKind::ConnectionReset),
+            read_body(&client).await
+        );
+        assert_eq!(Ok("hello".to_string()), read_body(&client).await);
+        assert_eq!(Err(io::ErrorKind::InvalidData), read_body(&client).await);
+        assert_eq!(Ok(BODY.to_string()), read_body(&client).await);
+        assert_eq!(5, client.num_calls());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn injects_latency() {
+        let client = FaultInjectionClient::new(replay(2))
+            .inject_on_every_attempt(Fault::Latency(Duration::from_secs(1)))
+            .inject(2, Fault::Latency(Duration::from_secs(2)))
+            .sleep_impl(SharedAsyncSleep::new(TokioSleep::new()));
+
+        let start = tokio::time::Instant::now();
+        assert_eq!(Ok(BODY.to_string()), read_body(&client).await);
+        assert_eq!(Duration::from_secs(1), start.elapsed());
+        assert_eq!(Ok(BODY.to_string()), read_body(&client).await);
+        assert_eq!(Duration::from_secs(4), start.elapsed());
+    }
+}
@@ -19,7 +19,7 @@ use aws_smithy_types::DateTime;
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::RwLock;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 use tracing::Instrument;
 
 const DEFAULT_LOAD_TIMEOUT: Duration = Duration::from_secs(5);
@@ -36,6 +36,7 @@ pub struct LazyCacheBuilder {
     buffer_time: Option<Duration>,
     buffer_time_jitter_fraction: Option<fn() -> f64>,
     default_expiration: Option<Duration>,
+    clock_skew_tolerance: Option<Duration>,
 }
 
 impl LazyCacheBuilder {
@@ -161,6 +162,36 @@ impl LazyCacheBuilder {
         self
     }
 
+    /// Amount of time for which an identity is used even though it appears to be expired.
+    ///
+    /// The expiration time of an identity is set by the identity provider, and the local clock
+    /// can be ahead of the provider's clock. Providers may also round expiration times down.
+    /// Either way, a freshly loaded identity can appear to be expired, or to expire within the
+    /// [buffer time](Self::buffer_time), and it would be loaded again for every request. With a
+    /// clock skew tolerance, a freshly loaded identity is used for at least that long before it's
+    /// loaded again.
+    ///
+    /// Defaults to zero.
+    pub fn clock_skew_tolerance(mut self, tolerance: Duration) -> Self {
+        self.set_clock_skew_tolerance(Some(tolerance));
+        self
+    }
+
+    /// Amount of time for which an identity is used even though it appears to be expired.
+    ///
+    /// The expiration time of an identity is set by the identity provider, and the local clock
+    /// can be ahead of the provider's clock. Providers may also round expiration times down.
+    /// Either way, a freshly loaded identity can appear to be expired, or to expire within the
+    /// [buffer time](Self::buffer_time), and it would be loaded again for every request. With a
+    /// clock skew tolerance, a freshly loaded identity is used for at least that long before it's
+    /// loaded again.
+    ///
+    /// Defaults to zero.
+    pub fn set_clock_skew_tolerance(&mut self, tolerance: Option<Duration>) -> &mut Self {
+        self.clock_skew_tolerance = tolerance;
+        self
+    }
+
     /// Builds a [`SharedIdentityCache`] from this builder.
     ///
     /// # Panics
@@ -178,6 +209,7 @@ impl LazyCacheBuilder {
             self.buffer_time_jitter_fraction
                 .unwrap_or(DEFAULT_BUFFER_TIME_JITTER_FRACTION),
             default_expiration,
+            self.clock_skew_tolerance.unwrap_or_default(),
         )
         .into_shared()
     }
@@ -223,6 +255,7 @@ struct LazyCache {
     buffer_time: Duration,
     buffer_time_jitter_fraction: fn() -> f64,
     default_expiration: Duration,
+    clock_skew_tolerance: Duration,
 }
 
 impl LazyCache {
@@ -231,6 +264,7 @@ impl LazyCache {
         buffer_time: Duration,
         buffer_time_jitter_fraction: fn() -> f64,
         default_expiration: Duration,
+        clock_skew_tolerance: Duration,
     ) -> Self {
         Self {
             partitions: CachePartitions::new(buffer_time),
@@ -238,8 +272,22 @@ impl LazyCache {
             buffer_time,
             buffer_time_jitter_fraction,
             default_expiration,
+            clock_skew_tolerance,
         }
     }
+
+    /// Returns the expiration time of an identity loaded at `loaded_at` in the cache.
+    ///
+    /// The cache considers the identity expired `buffer_time` before the returned time.
+    fn cache_expiration(&self, expiration: SystemTime, loaded_at: SystemTime) -> SystemTime {
+        let jitter = self
+            .buffer_time
+            .mul_f64((self.buffer_time_jitter_fraction)());
+        // Don't consider the identity expired before `clock_skew_tolerance` has passed, even if
+        // it appears to be expired already.
+        let earliest = loaded_at + self.clock_skew_tolerance + self.buffer_time;
+        (expiration + jitter).max(earliest)
+    }
 }
 
 macro_rules! required_err {
@@ -345,10 +393,6 @@ impl ResolveCachedIdentity for LazyCache {
                             let expiration =
                                 identity.expiration().unwrap_or(now + default_expiration);
 
-                            let jitter = self
-                                .buffer_time
-                                .mul_f64((self.buffer_time_jitter_fraction)());
-
                             // Logging for cache miss should be emitted here as opposed to after the call to
                             // `cache.get_or_load` above. In the case of multiple threads concurrently executing
                             // `cache.get_or_load`, logging inside `cache.get_or_load` ensures that it is emitted
@@ -362,7 +406,7 @@ impl ResolveCachedIdentity for LazyCache {
                                 time_source.now().duration_since(start_time).unwrap_or_default()
                             );
 
-                            Ok((identity, expiration + jitter))
+                            Ok((identity, self.cache_expiration(expiration, time_source.now())))
                         }
                         // Only instrument the the actual load future so that no span
                         // is opened if the cache decides not to execute it.
@@ -458,6 +502,7 @@ mod tests {
             DEFAULT_BUFFER_TIME,
             buffer_time_jitter_fraction,
             DEFAULT_EXPIRATION,
+            Duration::ZERO,
         );
         (cache, identity_resolver)
     }
@@ -503,6 +548,7 @@ mod tests {
             DEFAULT_BUFFER_TIME,
             BUFFER_TIME_NO_JITTER,
             DEFAULT_EXPIRATION,
+            Duration::ZERO,
         );
         assert_eq!(
             epoch_secs(1000),
@@ -641,6 +687,7 @@ mod tests {
             DEFAULT_BUFFER_TIME,
             BUFFER_TIME_NO_JITTER,
             DEFAULT_EXPIRATION,
+            Duration::ZERO,
         );
 
         let err: BoxError = cache
@@ -685,6 +732,36 @@ mod tests {
         expect_identity(2000, &cache, &components, resolver.clone()).await;
     }
 
+    #[tokio::test]
+    async fn clock_skew_tolerance() {
+        let time = ManualTimeSource::new(epoch_secs(1000));
+        let components = RuntimeComponentsBuilder::for_tests()
+            .with_time_source(Some(time.clone()))
+            .with_sleep_impl(Some(TokioSleep::new()))
+            .build()
+            .unwrap();
+
+        // Identities that expire within the buffer time are loaded again on every request
+        let (cache, resolver) = test_cache(
+            BUFFER_TIME_NO_JITTER,
+            vec![Ok(test_identity(1005)), Ok(test_identity(1006))],
+        );
+        expect_identity(1005, &cache, &components, resolver.clone()).await;
+        expect_identity(1006, &cache, &components, resolver.clone()).await;
+
+        // ...unless they're within the clock skew tolerance
+        let (mut cache, resolver) = test_cache(
+            BUFFER_TIME_NO_JITTER,
+            vec![Ok(test_identity(1005)), Ok(test_identity(1006))],
+        );
+        cache.clock_skew_tolerance = Duration::from_secs(60);
+        expect_identity(1005, &cache, &components, resolver.clone()).await;
+        time.set_time(epoch_secs(1059));
+        expect_identity(1005, &cache, &components, resolver.clone()).await;
+        time.set_time(epoch_secs(1060));
+        expect_identity(1006, &cache, &components, resolver.clone()).await;
+    }
+
     #[tokio::test]
     async fn cache_partitioning() {
         let time = ManualTimeSource::new(epoch_secs(0));
@@ -5,8 +5,9 @@
 
 use aws_smithy_runtime_api::box_error::BoxError;
 use aws_smithy_runtime_api::client::interceptors::context::{
-    BeforeSerializationInterceptorContextRef, BeforeTransmitInterceptorContextMut,
-    FinalizerInterceptorContextMut, FinalizerInterceptorContextRef,
+    BeforeDeserializationInterceptorContextMut, BeforeSerializationInterceptorContextRef,
+    BeforeTransmitInterceptorContextMut, FinalizerInterceptorContextMut,
+    FinalizerInterceptorContextRef,
 };
 use aws_smithy_runtime_api::client::interceptors::context::{
     Error, Input, InterceptorContext, Output,
@@ -370,6 +371,50 @@ where
     }
 }
 
+/// Interceptor that wraps the response body with a given function before it is deserialized.
+///
+/// The function receives the body before the response deserializer reads it, for both streaming and
+/// non-streaming operations. Wrapping the body rather than reading it into memory preserves streaming, so
+/// this can be used to tap the response body, e.g., to checksum or scan it as it is read.
+pub struct MapResponseBodyInterceptor<F> {
+    f: F,
+}
+
+impl<F> fmt::Debug for MapResponseBodyInterceptor<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MapResponseBodyInterceptor")
+    }
+}
+
+impl<F> MapResponseBodyInterceptor<F> {
+    /// Creates a new `MapResponseBodyInterceptor`.
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F> Intercept for MapResponseBodyInterceptor<F>
+where
+    F: Fn(SdkBody) -> SdkBody + Send + Sync + 'static,
+{
+    fn name(&self) -> &'static str {
+        "MapResponseBodyInterceptor"
+    }
+
+    fn modify_before_deserialization(
+        &self,
+        context: &mut BeforeDeserializationInterceptorContextMut<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let response = context.response_mut();
+        let body = response.take_body();
+        *response.body_mut() = (self.f)(body);
+
+        Ok(())
+    }
+}
+
 #[cfg(all(test, feature = "test-util"))]
 mod tests {
     use super::*;
@@ -452,4 +497,60 @@ mod tests {
             )
             .expect("interceptor is now disabled");
     }
+
+    #[test]
+    fn test_filtered_interceptors() {
+        use aws_smithy_runtime_api::client::orchestrator::Metadata;
+
+        let rc = RuntimeComponentsBuilder::for_tests()
+            .with_filtered_interceptor(|operation| operation == "PutObject", TestInterceptor)
+            .build()
+            .unwrap();
+        let enabled_count = |cfg: &ConfigBag| {
+            Interceptors::new(rc.interceptors())
+                .into_iter()
+                .filter(|i| i.if_enabled(cfg).is_some())
+                .count()
+        };
+
+        let mut cfg = ConfigBag::base();
+        assert_eq!(0, enabled_count(&cfg), "no operation metadata");
+
+        cfg.interceptor_state()
+            .store_put(Metadata::new("GetObject", "S3"));
+        assert_eq!(0, enabled_count(&cfg));
+
+        cfg.interceptor_state()
+            .store_put(Metadata::new("PutObject", "S3"));
+        assert_eq!(1, enabled_count(&cfg));
+
+        cfg.interceptor_state()
+            .store_put(disable_interceptor::<TestInterceptor>("test"));
+        assert_eq!(0, enabled_count(&cfg));
+    }
+
+    #[test]
+    fn test_map_response_body() {
+        use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
+
+        let interceptor = MapResponseBodyInterceptor::new(|body: SdkBody| {
+            let tapped = body.bytes().map(|bytes| bytes.to_ascii_uppercase());
+            SdkBody::from(tapped.expect("body is in memory"))
+        });
+        let rc = RuntimeComponentsBuilder::for_tests().build().unwrap();
+        let mut cfg = ConfigBag::base();
+        let mut ctx = InterceptorContext::new(Input::doesnt_matter());
+        ctx.set_response(HttpResponse::new(
+            200.try_into().unwrap(),
+            SdkBody::from("pikachu"),
+        ));
+
+        interceptor
+            .modify_before_deserialization(&mut (&mut ctx).into(), &rc, &mut cfg)
+            .expect("interceptor succeeds");
+        assert_eq!(
+            Some(&b"PIKACHU"[..]),
+            ctx.response().expect("response is set").body().bytes()
+        );
+    }
 }
@@ -3,16 +3,36 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use self::auth::orchestrate_auth;
+//! # Tracing spans
+//!
+//! The orchestrator records its progress in [`tracing`] spans with stable names, so that
+//! observability tooling can rely on them. Every operation invocation is recorded in this hierarchy:
+//!
+//! - `smithy.operation`: the whole invocation, with the `service`, `operation`, and
+//!   `sdk_invocation_id` fields, and `telemetry_attributes` when any are configured.
+//!   - `smithy.attempt`: each attempt to send the request, with the `attempt` number, starting at 1.
+//!     The [`TelemetryInterceptor`](crate::client::telemetry::TelemetryInterceptor) records the
+//!     [semantic convention attributes](aws_smithy_observability::semconv) of the attempt on it.
+//!     - `smithy.transmit`: sending the request and receiving the response headers.
+//!       - `smithy.connect`: establishing a new connection, when the HTTP client needs one and
+//!         supports it.
+//!
+//! Other spans, such as the ones for serialization and deserialization, are nested within these,
+//! but their names may change.
+
+use self::auth::{auth_scheme_fallback, resolve_identity, sign_request, AttemptAuthScheme};
 use crate::client::interceptors::Interceptors;
 use crate::client::orchestrator::http::{log_response_body, read_body};
-use crate::client::timeout::{MaybeTimeout, MaybeTimeoutConfig, TimeoutKind};
+use crate::client::telemetry::AttemptSpan;
+use crate::client::timeout::{AttemptActivity, MaybeTimeout, MaybeTimeoutConfig, TimeoutKind};
 use crate::client::{
     http::body::minimum_throughput::MaybeUploadThroughputCheckFuture,
-    orchestrator::endpoints::orchestrate_endpoint,
+    orchestrator::endpoints::{orchestrate_endpoint, resolve_endpoint},
 };
 use aws_smithy_async::rt::sleep::AsyncSleep;
 use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::auth::AuthSchemeId;
+use aws_smithy_runtime_api::client::endpoint::ResolveEndpoint;
 use aws_smithy_runtime_api::client::http::{HttpClient, HttpConnector, HttpConnectorSettings};
 use aws_smithy_runtime_api::client::interceptors::context::{
     Error, Input, InterceptorContext, Output, RewindResult,
@@ -27,6 +47,7 @@ use aws_smithy_runtime_api::client::runtime_plugin::RuntimePlugins;
 use aws_smithy_runtime_api::client::ser_de::{
     DeserializeResponse, SerializeRequest, SharedRequestSerializer, SharedResponseDeserializer,
 };
+use aws_smithy_runtime_api::client::telemetry::TelemetryAttributesExt;
 use aws_smithy_types::body::SdkBody;
 use aws_smithy_types::byte_stream::ByteStream;
 use aws_smithy_types::config_bag::ConfigBag;
@@ -148,6 +169,7 @@ pub async fn invoke_with_stop_point(
         let runtime_components = apply_configuration(&mut ctx, cfg, runtime_plugins)
             .map_err(SdkError::construction_failure)?;
         trace!(runtime_components = ?runtime_components);
+        record_telemetry_attributes(cfg);
 
         let operation_timeout_config =
             MaybeTimeoutConfig::new(&runtime_components, cfg, TimeoutKind::Operation);
@@ -169,10 +191,24 @@ pub async fn invoke_with_stop_point(
         .await
     }
     // Include a random, internal-only, seven-digit ID for the operation invocation so that it can be correlated in the logs.
-    .instrument(debug_span!("invoke", service = %service_name, operation = %operation_name, sdk_invocation_id = fastrand::u32(1_000_000..10_000_000)))
+    .instrument(debug_span!("smithy.operation", service = %service_name, operation = %operation_name, sdk_invocation_id = fastrand::u32(1_000_000..10_000_000), telemetry_attributes = tracing::field::Empty))
     .await
 }
 
+/// Records the custom telemetry attributes for this operation on the current `smithy.operation` span.
+fn record_telemetry_attributes(cfg: &ConfigBag) {
+    let attributes = cfg.telemetry_attributes();
+    if !attributes.is_empty() {
+        let attributes = attributes
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        tracing::Span::current()
+            .record("telemetry_attributes", tracing::field::display(attributes));
+    }
+}
+
 /// Apply configuration is responsible for apply runtime plugins to the config bag, as well as running
 /// `read_before_execution` interceptors. If a failure occurs due to config construction, `invoke`
 /// will raise it to the user. If an interceptor fails, then `invoke`
@@ -261,6 +297,8 @@ async fn try_op(
     let should_attempt = runtime_components
         .retry_strategy()
         .should_attempt_initial_request(runtime_components, cfg);
+    // The delay before the current attempt, recorded on its span
+    let mut attempt_delay = None;
     match should_attempt {
         // Yes, let's make a request
         Ok(ShouldAttempt::Yes) => debug!("retry strategy has OKed initial request"),
@@ -277,6 +315,7 @@ async fn try_op(
             )));
             debug!("retry strategy has OKed initial request after a {delay:?} delay");
             sleep_impl.sleep(delay).await;
+            attempt_delay = Some(delay);
         }
     }
 
@@ -299,16 +338,33 @@ async fn try_op(
         if let Some((delay, sleep)) = retry_delay.take() {
             debug!("delaying for {delay:?}");
             sleep.await;
+            attempt_delay = Some(delay);
         }
         let attempt_timeout_config =
             MaybeTimeoutConfig::new(runtime_components, cfg, TimeoutKind::OperationAttempt);
         trace!(attempt_timeout_config = ?attempt_timeout_config);
+        // Activity-based attempt timeouts are restarted by the body data of this attempt
+        if let Some(activity) = attempt_timeout_config.attempt_activity() {
+            cfg.interceptor_state().store_put(activity.clone());
+        }
+        // The semantic convention fields are recorded by the `TelemetryInterceptor`
+        let attempt_span = debug_span!(
+            "smithy.attempt",
+            attempt = i,
+            retry_delay = attempt_delay.take().map(tracing::field::debug),
+            http.request.resend_count = tracing::field::Empty,
+            server.address = tracing::field::Empty,
+            http.status_code = tracing::field::Empty,
+        );
+        cfg.interceptor_state()
+            .store_put(AttemptSpan(attempt_span.clone()));
         let maybe_timeout = async {
             debug!("beginning attempt #{i}");
             try_attempt(ctx, cfg, runtime_components, stop_point).await;
             finally_attempt(ctx, cfg, runtime_components).await;
             Result::<_, SdkError<Error, HttpResponse>>::Ok(())
         }
+        .instrument(attempt_span)
         .maybe_timeout(attempt_timeout_config)
         .await
         .map_err(|err| OrchestratorError::timeout(err.into_source().unwrap()));
@@ -316,6 +372,12 @@ async fn try_op(
         // We continue when encountering a timeout error. The retry classifier will decide what to do with it.
         continue_on_err!([ctx] => maybe_timeout);
 
+        // Retry with the next eligible auth scheme if the service rejected this one and auth
+        // scheme fallback is enabled. This doesn't go through the retry strategy.
+        if auth_scheme_fallback(ctx, runtime_components, cfg) {
+            continue;
+        }
+
         // If we got a retry strategy from the bag, ask it what to do.
         // If no strategy was set, we won't retry.
         let should_attempt = halt_on_err!([ctx] => runtime_components
@@ -341,7 +403,7 @@ async fn try_op(
     }
 }
 
-#[instrument(skip_all, level = "debug")]
+#[instrument(skip_all, level = "debug", fields(auth_scheme_id = tracing::field::Empty))]
 async fn try_attempt(
     ctx: &mut InterceptorContext,
     cfg: &mut ConfigBag,
@@ -350,14 +412,42 @@ async fn try_attempt(
 ) {
     run_interceptors!(halt_on_err: read_before_attempt(ctx, runtime_components, cfg));
 
-    halt_on_err!([ctx] => orchestrate_endpoint(ctx, runtime_components, cfg).await.map_err(OrchestratorError::other));
+    // The endpoint is resolved before the identity, and made available to identity resolvers. If
+    // the endpoint parameters depend on the identity, e.g. on its account ID, the endpoint may
+    // only be resolved once the identity is, so the error is deferred until then.
+    let endpoint = match resolve_endpoint(runtime_components, cfg).await {
+        Ok(endpoint) => {
+            cfg.interceptor_state().store_put(endpoint.clone());
+            Ok(endpoint)
+        }
+        Err(err)
+            if runtime_components
+                .endpoint_resolver()
+                .params_depend_on_identity() =>
+        {
+            Err(err)
+        }
+        Err(err) => halt!([ctx] => OrchestratorError::other(err)),
+    };
+    // The identity is resolved before the `modify_before_signing` interceptors run so that they
+    // can inspect it, e.g. to report the credentials provider in the user agent.
+    let (auth_scheme_id, identity, identity_endpoint) = halt_on_err!([ctx] => resolve_identity(runtime_components, cfg, endpoint.as_ref().ok()).await.map_err(OrchestratorError::other));
+    let endpoint = match identity_endpoint {
+        Some(endpoint) => endpoint,
+        None => halt_on_err!([ctx] => endpoint.map_err(OrchestratorError::other)),
+    };
+    halt_on_err!([ctx] => orchestrate_endpoint(endpoint, ctx, cfg).map_err(OrchestratorError::other));
+    record_attempt_attributes(auth_scheme_id);
+    cfg.interceptor_state()
+        .store_put(AttemptAuthScheme(auth_scheme_id));
+    cfg.interceptor_state().store_put(identity.clone());
 
     run_interceptors!(halt_on_err: {
         modify_before_signing(ctx, runtime_components, cfg);
         read_before_signing(ctx, runtime_components, cfg);
     });
 
-    halt_on_err!([ctx] => orchestrate_auth(ctx, runtime_components, cfg).await.map_err(OrchestratorError::other));
+    halt_on_err!([ctx] => sign_request(auth_scheme_id, &identity, ctx, runtime_components, cfg).map_err(OrchestratorError::other));
 
     run_interceptors!(halt_on_err: {
         read_after_signing(ctx, runtime_components, cfg);
@@ -374,9 +464,13 @@ async fn try_attempt(
     // The connection consumes the request but we need to keep a copy of it
     // within the interceptor context, so we clone it here.
     ctx.enter_transmit_phase();
-    let response = halt_on_err!([ctx] => {
-        let request = ctx.take_request().expect("set during serialization");
+    let attempt_activity = cfg.load::<AttemptActivity>().cloned();
+    let mut response = halt_on_err!([ctx] => {
+        let mut request = ctx.take_request().expect("set during serialization");
         trace!(request = ?request, "transmitting request");
+        if let Some(activity) = &attempt_activity {
+            activity.track(request.body_mut());
+        }
         let http_client = halt_on_err!([ctx] => runtime_components.http_client().ok_or_else(||
             OrchestratorError::other("No HTTP client was available to send this request. \
                 Enable the `rustls` crate feature or configure a HTTP client to fix this.")
@@ -394,8 +488,14 @@ async fn try_attempt(
             runtime_components,
             connector.call(request),
         );
-        response_future.await.map_err(OrchestratorError::connector)
+        response_future
+            .instrument(debug_span!("smithy.transmit"))
+            .await
+            .map_err(OrchestratorError::connector)
     });
+    if let Some(activity) = &attempt_activity {
+        activity.track(response.body_mut());
+    }
     trace!(response = ?response, "received response from service");
     ctx.set_response(response);
     ctx.enter_before_deserialization_phase();
@@ -438,6 +538,16 @@ async fn try_attempt(
     run_interceptors!(halt_on_err: read_after_deserialization(ctx, runtime_components, cfg));
 }
 
+/// Records the selected auth scheme on the current attempt's span
+///
+/// The host of the resolved endpoint is recorded as `server.address` by the `TelemetryInterceptor`.
+fn record_attempt_attributes(auth_scheme_id: AuthSchemeId) {
+    tracing::Span::current().record(
+        "auth_scheme_id",
+        tracing::field::display(auth_scheme_id.as_str()),
+    );
+}
+
 #[instrument(skip_all, level = "debug")]
 async fn finally_attempt(
     ctx: &mut InterceptorContext,
@@ -465,49 +575,63 @@ async fn finally_op(
 #[cfg(all(test, feature = "test-util"))]
 mod tests {
     use crate::client::auth::no_auth::{NoAuthRuntimePlugin, NO_AUTH_SCHEME_ID};
-    use crate::client::http::test_util::NeverClient;
+    use crate::client::http::test_util::{infallible_client_fn, NeverClient};
     use crate::client::orchestrator::endpoints::StaticUriEndpointResolver;
     use crate::client::orchestrator::{invoke, invoke_with_stop_point, StopPoint};
     use crate::client::retries::strategy::NeverRetryStrategy;
     use crate::client::test_util::{
         deserializer::CannedResponseDeserializer, serializer::CannedRequestSerializer,
     };
+    use aws_smithy_async::test_util::instant_time_and_sleep;
     use aws_smithy_runtime_api::box_error::BoxError;
     use aws_smithy_runtime_api::client::auth::static_resolver::StaticAuthSchemeOptionResolver;
     use aws_smithy_runtime_api::client::auth::{
         AuthSchemeOptionResolverParams, SharedAuthSchemeOptionResolver,
     };
     use aws_smithy_runtime_api::client::endpoint::{
-        EndpointResolverParams, SharedEndpointResolver,
+        EndpointFuture, EndpointResolverParams, ResolveEndpoint, SharedEndpointResolver,
     };
     use aws_smithy_runtime_api::client::http::{
         http_client_fn, HttpConnector, HttpConnectorFuture,
     };
+    use aws_smithy_runtime_api::client::identity::{
+        Identity, IdentityFuture, ResolveIdentity, SharedIdentityResolver,
+    };
+    use aws_smithy_runtime_api::client::interceptors::context::Error;
     use aws_smithy_runtime_api::client::interceptors::context::{
         AfterDeserializationInterceptorContextRef, BeforeDeserializationInterceptorContextMut,
         BeforeDeserializationInterceptorContextRef, BeforeSerializationInterceptorContextMut,
         BeforeSerializationInterceptorContextRef, BeforeTransmitInterceptorContextMut,
         BeforeTransmitInterceptorContextRef, FinalizerInterceptorContextMut,
-        FinalizerInterceptorContextRef, Input, Output,
+        FinalizerInterceptorContextRef, Input, InterceptorContext, Output,
     };
     use aws_smithy_runtime_api::client::interceptors::{Intercept, SharedInterceptor};
-    use aws_smithy_runtime_api::client::orchestrator::{HttpRequest, OrchestratorError};
-    use aws_smithy_runtime_api::client::retries::SharedRetryStrategy;
+    use aws_smithy_runtime_api::client::orchestrator::{
+        HttpRequest, HttpResponse, OrchestratorError,
+    };
+    use aws_smithy_runtime_api::client::retries::{
+        RequestAttempts, RetryStrategy, SharedRetryStrategy, ShouldAttempt,
+    };
     use aws_smithy_runtime_api::client::runtime_components::{
         RuntimeComponents, RuntimeComponentsBuilder,
     };
-    use aws_smithy_runtime_api::client::runtime_plugin::{RuntimePlugin, RuntimePlugins};
+    use aws_smithy_runtime_api::client::runtime_plugin::{
+        RuntimePlugin, RuntimePlugins, StaticRuntimePlugin,
+    };
     use aws_smithy_runtime_api::client::ser_de::{
-        SharedRequestSerializer, SharedResponseDeserializer,
+        DeserializeResponse, SharedRequestSerializer, SharedResponseDeserializer,
     };
     use aws_smithy_runtime_api::shared::IntoShared;
     use aws_smithy_types::body::SdkBody;
     use aws_smithy_types::config_bag::{ConfigBag, FrozenLayer, Layer};
+    use aws_smithy_types::endpoint::Endpoint;
+    use aws_smithy_types::error::display::DisplayErrorContext;
     use aws_smithy_types::timeout::TimeoutConfig;
     use http_02x::{Response, StatusCode};
     use std::borrow::Cow;
     use std::sync::atomic::{AtomicBool, Ordering};
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, UNIX_EPOCH};
     use tracing_test::traced_test;
 
     fn new_request_serializer() -> CannedRequestSerializer {
@@ -1345,4 +1469,475 @@ mod tests {
             .read_after_execution_called
             .load(Ordering::Relaxed));
     }
+
+    fn runtime_plugins_with_response(
+        response: fn() -> SdkBody,
+        deserializer: impl DeserializeResponse + 'static,
+    ) -> RuntimePlugins {
+        #[derive(Debug)]
+        struct ResponsePlugin {
+            builder: RuntimeComponentsBuilder,
+            config: FrozenLayer,
+        }
+
+        impl RuntimePlugin for ResponsePlugin {
+            fn config(&self) -> Option<FrozenLayer> {
+                Some(self.config.clone())
+            }
+
+            fn runtime_components(
+                &self,
+                _: &RuntimeComponentsBuilder,
+            ) -> Cow<'_, RuntimeComponentsBuilder> {
+                Cow::Borrowed(&self.builder)
+            }
+        }
+
+        let http_client = infallible_client_fn(move |_| {
+            http_02x::Response::builder()
+                .status(200)
+                .body(response())
+                .unwrap()
+        });
+        RuntimePlugins::new()
+            .with_operation_plugin(TestOperationRuntimePlugin::new())
+            .with_operation_plugin(NoAuthRuntimePlugin::new())
+            .with_operation_plugin(ResponsePlugin {
+                builder: RuntimeComponentsBuilder::new("test").with_http_client(Some(http_client)),
+                config: {
+                    let mut layer = Layer::new("ResponsePlugin");
+                    layer.store_put(SharedResponseDeserializer::new(deserializer));
+                    layer.freeze()
+                },
+            })
+    }
+
+    #[derive(Debug)]
+    struct FailingDeserializer;
+
+    impl DeserializeResponse for FailingDeserializer {
+        fn deserialize_nonstreaming(
+            &self,
+            _response: &HttpResponse,
+        ) -> Result<Output, OrchestratorError<Error>> {
+            Err(OrchestratorError::response("failed to parse".into()))
+        }
+    }
+
+    #[tokio::test]
+    async fn deserialization_errors_retain_response_body() {
+        let runtime_plugins = runtime_plugins_with_response(
+            || SdkBody::from("{\"unexpected\": true}"),
+            FailingDeserializer,
+        );
+        let err = invoke("test", "test", Input::doesnt_matter(), &runtime_plugins)
+            .await
+            .expect_err("deserialization fails");
+        let raw = err
+            .raw_response()
+            .expect("response error has a raw response");
+        assert_eq!(Some(&b"{\"unexpected\": true}"[..]), raw.body().bytes());
+    }
+
+    #[tokio::test]
+    async fn body_read_errors_retain_partial_response_body() {
+        #[derive(Debug)]
+        struct TruncatedBody {
+            sent: bool,
+        }
+
+        impl http_body_04x::Body for TruncatedBody {
+            type Data = bytes::Bytes;
+            type Error = std::io::Error;
+
+            fn poll_data(
+                mut self: std::pin::Pin<&mut Self>,
+                _cx: &mut std::task::Context<'_>,
+            ) -> std::task::Poll<Option<Result<Self::Data, Self::Error>>> {
+                if self.sent {
+                    std::task::Poll::Ready(Some(Err(std::io::Error::new(
+                        std::io::ErrorKind::ConnectionReset,
+                        "connection reset",
+                    ))))
+                } else {
+                    self.sent = true;
+                    std::task::Poll::Ready(Some(Ok(bytes::Bytes::from_static(b"<Partial"))))
+                }
+            }
+
+            fn poll_trailers(
+                self: std::pin::Pin<&mut Self>,
+                _cx: &mut std::task::Context<'_>,
+            ) -> std::task::Poll<Result<Option<http_02x::HeaderMap>, Self::Error>> {
+                std::task::Poll::Ready(Ok(None))
+            }
+        }
+
+        let runtime_plugins = runtime_plugins_with_response(
+            || SdkBody::from_body_0_4(TruncatedBody { sent: false }),
+            FailingDeserializer,
+        );
+        let err = invoke("test", "test", Input::doesnt_matter(), &runtime_plugins)
+            .await
+            .expect_err("reading the body fails");
+        let raw = err
+            .raw_response()
+            .expect("response error has a raw response");
+        assert_eq!(Some(&b"<Partial"[..]), raw.body().bytes());
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn telemetry_attributes_are_recorded_and_visible_to_interceptors() {
+        use aws_smithy_runtime_api::client::telemetry::{
+            TelemetryAttribute, TelemetryAttributesExt,
+        };
+
+        #[derive(Debug)]
+        struct TelemetryAttributesInterceptor;
+        impl Intercept for TelemetryAttributesInterceptor {
+            fn name(&self) -> &'static str {
+                "TelemetryAttributesInterceptor"
+            }
+
+            fn read_before_transmit(
+                &self,
+                _ctx: &BeforeTransmitInterceptorContextRef<'_>,
+                _rc: &RuntimeComponents,
+                cfg: &mut ConfigBag,
+            ) -> Result<(), BoxError> {
+                let attributes: Vec<_> = cfg
+                    .telemetry_attributes()
+                    .into_iter()
+                    .map(ToString::to_string)
+                    .collect();
+                assert_eq!(vec!["tenant=acme"], attributes);
+                tracing::debug!("TelemetryAttributesInterceptor called!");
+                Ok(())
+            }
+        }
+
+        let mut client_layer = Layer::new("client");
+        client_layer.store_append(TelemetryAttribute::new("tenant", "default"));
+        let mut operation_layer = Layer::new("operation");
+        operation_layer.store_append(TelemetryAttribute::new("tenant", "acme"));
+
+        let runtime_plugins = RuntimePlugins::new()
+            .with_client_plugin(StaticRuntimePlugin::new().with_config(client_layer.freeze()))
+            .with_operation_plugin(TestOperationRuntimePlugin::new())
+            .with_operation_plugin(NoAuthRuntimePlugin::new())
+            .with_operation_plugin(
+                StaticRuntimePlugin::new()
+                    .with_config(operation_layer.freeze())
+                    .with_runtime_components(
+                        RuntimeComponentsBuilder::new("test").with_interceptor(
+                            SharedInterceptor::new(TelemetryAttributesInterceptor),
+                        ),
+                    ),
+            );
+        invoke("test", "test", Input::doesnt_matter(), &runtime_plugins)
+            .await
+            .expect("success");
+
+        assert!(logs_contain("TelemetryAttributesInterceptor called!"));
+        assert!(logs_contain("telemetry_attributes=tenant=acme"));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn orchestrator_spans_have_stable_names() {
+        let runtime_plugins = RuntimePlugins::new()
+            .with_operation_plugin(TestOperationRuntimePlugin::new())
+            .with_operation_plugin(NoAuthRuntimePlugin::new());
+        invoke("test", "test", Input::doesnt_matter(), &runtime_plugins)
+            .await
+            .expect("success");
+
+        assert!(logs_contain(
+            "smithy.operation{service=test operation=test sdk_invocation_id="
+        ));
+        assert!(logs_contain(
+            ":try_op:smithy.attempt{attempt=1}:try_attempt:"
+        ));
+    }
+
+    /// Retry strategy that delays the initial request by one second, and retries once after two seconds
+    #[derive(Debug)]
+    struct DelayingRetryStrategy;
+
+    impl RetryStrategy for DelayingRetryStrategy {
+        fn should_attempt_initial_request(
+            &self,
+            _runtime_components: &RuntimeComponents,
+            _cfg: &ConfigBag,
+        ) -> Result<ShouldAttempt, BoxError> {
+            Ok(ShouldAttempt::YesAfterDelay(Duration::from_secs(1)))
+        }
+
+        fn should_attempt_retry(
+            &self,
+            _context: &InterceptorContext,
+            _runtime_components: &RuntimeComponents,
+            cfg: &ConfigBag,
+        ) -> Result<ShouldAttempt, BoxError> {
+            let attempts = cfg.load::<RequestAttempts>().unwrap().attempts();
+            Ok(match attempts {
+                1 => ShouldAttempt::YesAfterDelay(Duration::from_secs(2)),
+                _ => ShouldAttempt::No,
+            })
+        }
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn attempt_spans_record_auth_scheme_and_retry_delay() {
+        let (_time_source, sleep_impl) = instant_time_and_sleep(UNIX_EPOCH);
+        let runtime_plugins = runtime_plugins_with_response(SdkBody::empty, FailingDeserializer)
+            .with_operation_plugin(
+                StaticRuntimePlugin::new().with_runtime_components(
+                    RuntimeComponentsBuilder::new("test")
+                        .with_retry_strategy(Some(SharedRetryStrategy::new(DelayingRetryStrategy)))
+                        .with_sleep_impl(Some(sleep_impl)),
+                ),
+            );
+        invoke("test", "test", Input::doesnt_matter(), &runtime_plugins)
+            .await
+            .expect_err("deserialization fails on every attempt");
+
+        assert!(logs_contain("smithy.attempt{attempt=1 retry_delay=1s}"));
+        assert!(logs_contain("smithy.attempt{attempt=2 retry_delay=2s}"));
+        assert!(logs_contain("try_attempt{auth_scheme_id=no_auth}"));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn telemetry_interceptor_records_semantic_convention_attributes() {
+        use crate::client::telemetry::TelemetryInterceptor;
+        use aws_smithy_runtime_api::client::orchestrator::Metadata;
+
+        let (_time_source, sleep_impl) = instant_time_and_sleep(UNIX_EPOCH);
+        let mut layer = Layer::new("metadata");
+        layer.store_put(Metadata::new("GetThing", "things"));
+        let runtime_plugins = runtime_plugins_with_response(SdkBody::empty, FailingDeserializer)
+            .with_operation_plugin(
+                StaticRuntimePlugin::new()
+                    .with_config(layer.freeze())
+                    .with_runtime_components(
+                        RuntimeComponentsBuilder::new("test")
+                            .with_retry_strategy(Some(SharedRetryStrategy::new(
+                                DelayingRetryStrategy,
+                            )))
+                            .with_sleep_impl(Some(sleep_impl))
+                            .with_interceptor(SharedInterceptor::new(TelemetryInterceptor::new())),
+                    ),
+            );
+        invoke(
+            "things",
+            "GetThing",
+            Input::doesnt_matter(),
+            &runtime_plugins,
+        )
+        .await
+        .expect_err("deserialization fails on every attempt");
+
+        assert!(logs_contain(
+            "smithy.attempt{attempt=1 retry_delay=1s server.address=localhost http.status_code=200}"
+        ));
+        assert!(logs_contain(
+            "smithy.attempt{attempt=2 retry_delay=2s http.request.resend_count=1 \
+             server.address=localhost http.status_code=200}"
+        ));
+    }
+
+    /// Whether an identity was resolved, and the URL of the endpoint that was available to it.
+    #[derive(Debug, Default)]
+    struct IdentityResolution {
+        resolved: AtomicBool,
+        endpoint_url: Mutex<Option<String>>,
+    }
+
+    #[derive(Debug)]
+    struct RecordingIdentityResolver(Arc<IdentityResolution>);
+
+    impl ResolveIdentity for RecordingIdentityResolver {
+        fn resolve_identity<'a>(
+            &'a self,
+            _runtime_components: &'a RuntimeComponents,
+            config_bag: &'a ConfigBag,
+        ) -> IdentityFuture<'a> {
+            self.0.resolved.store(true, Ordering::Relaxed);
+            *self.0.endpoint_url.lock().unwrap() = config_bag
+                .load::<Endpoint>()
+                .map(|endpoint| endpoint.url().to_owned());
+            IdentityFuture::ready(Ok(Identity::new("doesntmatter", None)))
+        }
+    }
+
+    #[derive(Debug)]
+    struct FailingEndpointResolver {
+        params_depend_on_identity: bool,
+    }
+
+    impl ResolveEndpoint for FailingEndpointResolver {
+        fn resolve_endpoint<'a>(&'a self, _: &'a EndpointResolverParams) -> EndpointFuture<'a> {
+            EndpointFuture::ready(Err("no endpoint for these parameters".into()))
+        }
+
+        fn params_depend_on_identity(&self) -> bool {
+            self.params_depend_on_identity
+        }
+    }
+
+    async fn invoke_with_endpoint_resolver(
+        endpoint_resolver: impl ResolveEndpoint + 'static,
+    ) -> (Arc<IdentityResolution>, Result<(), String>) {
+        let resolution = Arc::new(IdentityResolution::default());
+        let runtime_plugins = RuntimePlugins::new()
+            .with_operation_plugin(TestOperationRuntimePlugin::new())
+            .with_operation_plugin(NoAuthRuntimePlugin::new())
+            .with_operation_plugin(
+                StaticRuntimePlugin::new().with_runtime_components(
+                    RuntimeComponentsBuilder::new("test")
+                        .with_endpoint_resolver(Some(SharedEndpointResolver::new(
+                            endpoint_resolver,
+                        )))
+                        .with_identity_resolver(
+                            NO_AUTH_SCHEME_ID,
+                            SharedIdentityResolver::new(RecordingIdentityResolver(
+                                resolution.clone(),
+                            )),
+                        ),
+                ),
+            );
+        let result = invoke("test", "test", Input::doesnt_matter(), &runtime_plugins)
+            .await
+            .map(|_| ())
+            .map_err(|err| DisplayErrorContext(&err).to_string());
+        (resolution, result)
+    }
+
+    #[tokio::test]
+    async fn identity_resolvers_can_read_the_endpoint() {
+        let (resolution, result) =
+            invoke_with_endpoint_resolver(StaticUriEndpointResolver::http_localhost(8080)).await;
+        result.expect("success");
+        assert_eq!(
+            Some("http://localhost:8080"),
+            resolution.endpoint_url.lock().unwrap().as_deref()
+        );
+    }
+
+    #[tokio::test]
+    async fn endpoint_errors_fail_attempts_before_resolving_identities() {
+        let (resolution, result) = invoke_with_endpoint_resolver(FailingEndpointResolver {
+            params_depend_on_identity: false,
+        })
+        .await;
+        let err = result.expect_err("the endpoint can't be resolved");
+        assert!(err.contains("no endpoint for these parameters"), "{err}");
+        assert!(!resolution.resolved.load(Ordering::Relaxed));
+
+        // The identity may resolve the endpoint when the parameters depend on it
+        let (resolution, result) = invoke_with_endpoint_resolver(FailingEndpointResolver {
+            params_depend_on_identity: true,
+        })
+        .await;
+        let err = result.expect_err("the identity doesn't change the parameters");
+        assert!(err.contains("no endpoint for these parameters"), "{err}");
+        assert!(resolution.resolved.load(Ordering::Relaxed));
+        assert_eq!(None, *resolution.endpoint_url.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn metrics_interceptor_records_call_metrics() {
+        use crate::client::telemetry::MetricsInterceptor;
+        use aws_smithy_observability::test_util::RecordingMeter;
+        use aws_smithy_observability::{AttributeValue, TelemetryProvider};
+        use aws_smithy_runtime_api::client::orchestrator::Metadata;
+
+        let meter = RecordingMeter::new();
+        let provider = TelemetryProvider::builder()
+            .meter_provider(Arc::new(meter.clone()))
+            .build();
+
+        let (time_source, sleep_impl) = instant_time_and_sleep(UNIX_EPOCH);
+        let mut layer = Layer::new("metadata");
+        layer.store_put(Metadata::new("GetThing", "things"));
+        let runtime_plugins =
+            runtime_plugins_with_response(|| SdkBody::from("thing"), FailingDeserializer)
+                .with_operation_plugin(
+                    StaticRuntimePlugin::new()
+                        .with_config(layer.freeze())
+                        .with_runtime_components(
+                            RuntimeComponentsBuilder::new("test")
+                                .with_retry_strategy(Some(SharedRetryStrategy::new(
+                                    DelayingRetryStrategy,
+                                )))
+                                .with_sleep_impl(Some(sleep_impl))
+                                .with_time_source(Some(time_source))
+                                .with_interceptor(SharedInterceptor::new(
+                                    MetricsInterceptor::from_provider(&provider),
+                                )),
+                        ),
+                );
+        invoke(
+            "things",
+            "GetThing",
+            Input::doesnt_matter(),
+            &runtime_plugins,
+        )
+        .await
+        .expect_err("deserialization fails on every attempt");
+
+        let measurements = meter.measurements();
+        for measurement in &measurements {
+            assert_eq!("aws-smithy-runtime", measurement.scope());
+            assert_eq!(
+                Some(&AttributeValue::String("things".into())),
+                measurement.attributes().get("rpc.service")
+            );
+            assert_eq!(
+                Some(&AttributeValue::String("GetThing".into())),
+                measurement.attributes().get("rpc.method")
+            );
+        }
+        let values = |name: &str| meter.values(&format!("smithy.client.call.{name}"));
+        assert_eq!(vec![1.0, 1.0], values("attempts"));
+        assert_eq!(vec![5.0, 5.0], values("response_payload_size"));
+        // The request body is empty
+        assert_eq!(vec![0.0, 0.0], values("request_payload_size"));
+        // The initial request and the retry are delayed by one and two seconds
+        assert_eq!(vec![3.0], values("duration"));
+        assert_eq!(vec![1.0], values("errors"));
+        let errors = measurements
+            .iter()
+            .find(|measurement| measurement.instrument() == "smithy.client.call.errors")
+            .unwrap();
+        assert_eq!(
+            Some(&AttributeValue::String("response".into())),
+            errors.attributes().get("error.type")
+        );
+    }
+
+    #[tokio::test]
+    async fn retry_strategies_can_delay_initial_requests_and_retries() {
+        let (_time_source, sleep_impl) = instant_time_and_sleep(UNIX_EPOCH);
+        let runtime_plugins = runtime_plugins_with_response(SdkBody::empty, FailingDeserializer)
+            .with_operation_plugin(
+                StaticRuntimePlugin::new().with_runtime_components(
+                    RuntimeComponentsBuilder::new("test")
+                        .with_retry_strategy(Some(SharedRetryStrategy::new(DelayingRetryStrategy)))
+                        .with_sleep_impl(Some(sleep_impl.clone())),
+                ),
+            );
+        invoke("test", "test", Input::doesnt_matter(), &runtime_plugins)
+            .await
+            .expect_err("deserialization fails on every attempt");
+
+        // The retry delay is only slept through when the second attempt is made
+        assert_eq!(
+            vec![Duration::from_secs(1), Duration::from_secs(2)],
+            sleep_impl.logs()
+        );
+    }
 }
@@ -3,24 +3,26 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::client::auth::fallback::AuthSchemeFallback;
 use crate::client::auth::no_auth::NO_AUTH_SCHEME_ID;
 use crate::client::identity::IdentityCache;
+use crate::client::orchestrator::endpoints::resolve_endpoint_for_identity;
 use aws_smithy_runtime_api::box_error::BoxError;
 use aws_smithy_runtime_api::client::auth::{
     AuthScheme, AuthSchemeEndpointConfig, AuthSchemeId, AuthSchemeOptionResolverParams,
     ResolveAuthSchemeOptions,
 };
-use aws_smithy_runtime_api::client::identity::ResolveIdentity;
+use aws_smithy_runtime_api::client::identity::{Identity, ResolveIdentity};
 use aws_smithy_runtime_api::client::identity::{IdentityCacheLocation, ResolveCachedIdentity};
 use aws_smithy_runtime_api::client::interceptors::context::InterceptorContext;
 use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
-use aws_smithy_types::config_bag::ConfigBag;
+use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
 use aws_smithy_types::endpoint::Endpoint;
 use aws_smithy_types::Document;
 use std::borrow::Cow;
 use std::error::Error as StdError;
 use std::fmt;
-use tracing::trace;
+use tracing::{debug, trace};
 
 #[derive(Debug)]
 struct NoMatchingAuthSchemeError(ExploredList);
@@ -109,23 +111,47 @@ impl fmt::Display for AuthOrchestrationError {
 
 impl StdError for AuthOrchestrationError {}
 
-pub(super) async fn orchestrate_auth(
-    ctx: &mut InterceptorContext,
+/// The auth scheme that signed the request of the current attempt.
+#[derive(Clone, Debug)]
+pub(super) struct AttemptAuthScheme(pub(super) AuthSchemeId);
+
+impl Storable for AttemptAuthScheme {
+    type Storer = StoreReplace<Self>;
+}
+
+/// The auth scheme that the service rejected, which is skipped for the remaining attempts.
+#[derive(Clone, Debug)]
+struct RejectedAuthScheme(AuthSchemeId);
+
+impl Storable for RejectedAuthScheme {
+    type Storer = StoreReplace<Self>;
+}
+
+/// Selects an auth scheme, and resolves an identity for it.
+///
+/// `endpoint` is the endpoint resolved before the identity, or `None` if it couldn't be resolved
+/// without one. Auth schemes that it doesn't support are skipped without resolving an identity
+/// for them.
+///
+/// Returns the ID of the selected auth scheme and the resolved identity, which are used to sign
+/// the request with [`sign_request`], and the endpoint resolved again for the identity if its
+/// endpoint parameters depend on it.
+pub(super) async fn resolve_identity(
     runtime_components: &RuntimeComponents,
     cfg: &ConfigBag,
-) -> Result<(), BoxError> {
+    endpoint: Option<&Endpoint>,
+) -> Result<(AuthSchemeId, Identity, Option<Endpoint>), BoxError> {
     let params = cfg
         .load::<AuthSchemeOptionResolverParams>()
         .expect("auth scheme option resolver params must be set");
     let option_resolver = runtime_components.auth_scheme_option_resolver();
     let options = option_resolver.resolve_auth_scheme_options(params)?;
-    let endpoint = cfg
-        .load::<Endpoint>()
-        .expect("endpoint added to config bag by endpoint orchestrator");
+    let rejected = cfg.load::<RejectedAuthScheme>();
 
     trace!(
         auth_scheme_option_resolver_params = ?params,
         auth_scheme_options = ?options,
+        rejected_auth_scheme = ?rejected,
         "orchestrating auth",
     );
 
@@ -133,6 +159,10 @@ pub(super) async fn orchestrate_auth(
 
     // Iterate over IDs of possibly-supported auth schemes
     for &scheme_id in options.as_ref() {
+        // Skip the auth scheme that the service rejected when falling back to the next one.
+        if rejected.map_or(false, |rejected| rejected.0 == scheme_id) {
+            continue;
+        }
         // For each ID, try to resolve the corresponding auth scheme.
         if let Some(auth_scheme) = runtime_components.auth_scheme(scheme_id) {
             // Use the resolved auth scheme to resolve an identity
@@ -144,41 +174,35 @@ pub(super) async fn orchestrate_auth(
                 } else {
                     IdentityCache::no_cache()
                 };
-                let signer = auth_scheme.signer();
                 trace!(
                     auth_scheme = ?auth_scheme,
                     identity_cache = ?identity_cache,
                     identity_resolver = ?identity_resolver,
-                    signer = ?signer,
-                    "resolved auth scheme, identity cache, identity resolver, and signing implementation"
+                    "resolved auth scheme, identity cache, and identity resolver"
                 );
 
-                match extract_endpoint_auth_scheme_config(endpoint, scheme_id) {
-                    Ok(auth_scheme_endpoint_config) => {
-                        trace!(auth_scheme_endpoint_config = ?auth_scheme_endpoint_config, "extracted auth scheme endpoint config");
-
-                        let identity = identity_cache
-                            .resolve_cached_identity(identity_resolver, runtime_components, cfg)
-                            .await?;
-                        trace!(identity = ?identity, "resolved identity");
-
-                        trace!("signing request");
-                        let request = ctx.request_mut().expect("set during serialization");
-                        signer.sign_http_request(
-                            request,
-                            &identity,
-                            auth_scheme_endpoint_config,
-                            runtime_components,
-                            cfg,
-                        )?;
-                        return Ok(());
+                if let Some(endpoint) = endpoint {
+                    if !supports_auth_scheme(endpoint, scheme_id)? {
+                        explored.push(scheme_id, ExploreResult::MissingEndpointConfig);
+                        continue;
                     }
-                    Err(AuthOrchestrationError::MissingEndpointConfig) => {
+                }
+
+                let identity = identity_cache
+                    .resolve_cached_identity(identity_resolver, runtime_components, cfg)
+                    .await?;
+                trace!(identity = ?identity, "resolved identity");
+
+                let endpoint =
+                    resolve_endpoint_for_identity(&identity, runtime_components, cfg).await?;
+                // The endpoint of the identity may not support this auth scheme
+                if let Some(endpoint) = &endpoint {
+                    if !supports_auth_scheme(endpoint, scheme_id)? {
                         explored.push(scheme_id, ExploreResult::MissingEndpointConfig);
                         continue;
                     }
-                    Err(other_err) => return Err(other_err.into()),
                 }
+                return Ok((scheme_id, identity, endpoint));
             } else {
                 explored.push(scheme_id, ExploreResult::NoIdentityResolver);
             }
@@ -190,6 +214,135 @@ pub(super) async fn orchestrate_auth(
     Err(NoMatchingAuthSchemeError(explored).into())
 }
 
+/// Returns true if the endpoint has a configuration for the auth scheme, or doesn't list its auth schemes.
+fn supports_auth_scheme(endpoint: &Endpoint, scheme_id: AuthSchemeId) -> Result<bool, BoxError> {
+    match extract_endpoint_auth_scheme_config(endpoint, scheme_id) {
+        Ok(auth_scheme_endpoint_config) => {
+            trace!(auth_scheme_endpoint_config = ?auth_scheme_endpoint_config, "extracted auth scheme endpoint config");
+            Ok(true)
+        }
+        Err(AuthOrchestrationError::MissingEndpointConfig) => Ok(false),
+        Err(other_err) => Err(other_err.into()),
+    }
+}
+
+/// Signs the request with the auth scheme and identity returned by [`resolve_identity`].
+pub(super) fn sign_request(
+    scheme_id: AuthSchemeId,
+    identity: &Identity,
+    ctx: &mut InterceptorContext,
+    runtime_components: &RuntimeComponents,
+    cfg: &ConfigBag,
+) -> Result<(), BoxError> {
+    let auth_scheme = runtime_components
+        .auth_scheme(scheme_id)
+        .expect("the auth scheme was selected from the runtime components");
+    let signer = auth_scheme.signer();
+    let endpoint = cfg
+        .load::<Endpoint>()
+        .expect("endpoint added to config bag by endpoint orchestrator");
+    let auth_scheme_endpoint_config = extract_endpoint_auth_scheme_config(endpoint, scheme_id)?;
+
+    trace!(signer = ?signer, "signing request");
+    let request = ctx.request_mut().expect("set during serialization");
+    signer.sign_http_request(
+        request,
+        identity,
+        auth_scheme_endpoint_config,
+        runtime_components,
+        cfg,
+    )?;
+    debug!(auth_scheme = scheme_id.as_str(), "signed request");
+    Ok(())
+}
+
+/// Checks whether the service rejected the auth scheme of the last attempt, and if so, whether the
+/// request should be retried with the next eligible auth scheme.
+///
+/// This only happens when [`AuthSchemeFallback`] is enabled, and at most once per operation
+/// invocation to avoid cycling through the auth schemes.
+pub(super) fn auth_scheme_fallback(
+    ctx: &InterceptorContext,
+    runtime_components: &RuntimeComponents,
+    cfg: &mut ConfigBag,
+) -> bool {
+    if !cfg
+        .load::<AuthSchemeFallback>()
+        .map_or(false, AuthSchemeFallback::is_enabled)
+    {
+        return false;
+    }
+    let (Some(attempt_scheme), Some(status)) = (
+        cfg.load::<AttemptAuthScheme>(),
+        ctx.response().map(|response| response.status()),
+    ) else {
+        return false;
+    };
+    let attempt_scheme = attempt_scheme.0;
+
+    if let Some(rejected) = cfg.load::<RejectedAuthScheme>() {
+        if status.is_success() {
+            debug!(
+                auth_scheme = attempt_scheme.as_str(),
+                rejected_auth_scheme = rejected.0.as_str(),
+                "request succeeded with the fallback auth scheme"
+            );
+        }
+        // Only fall back once so that the request never cycles through the auth schemes.
+        return false;
+    }
+    let failed = matches!(ctx.output_or_error(), Some(Err(_)));
+    if !failed || !matches!(status.as_u16(), 401 | 403) {
+        return false;
+    }
+
+    let option_resolver = runtime_components.auth_scheme_option_resolver();
+    let next_scheme = cfg
+        .load::<AuthSchemeOptionResolverParams>()
+        .and_then(|params| option_resolver.resolve_auth_scheme_options(params).ok())
+        .zip(cfg.load::<Endpoint>())
+        .and_then(|(options, endpoint)| {
+            options.iter().copied().find(|&scheme_id| {
+                scheme_id != attempt_scheme && is_eligible(scheme_id, runtime_components, endpoint)
+            })
+        });
+    match next_scheme {
+        Some(next_scheme) => {
+            debug!(
+                status = status.as_u16(),
+                rejected_auth_scheme = attempt_scheme.as_str(),
+                auth_scheme = next_scheme.as_str(),
+                "the service rejected the auth scheme; retrying with the next eligible auth scheme"
+            );
+            cfg.interceptor_state()
+                .store_put(RejectedAuthScheme(attempt_scheme));
+            true
+        }
+        None => {
+            debug!(
+                status = status.as_u16(),
+                rejected_auth_scheme = attempt_scheme.as_str(),
+                "the service rejected the auth scheme, and there is no other eligible auth scheme to fall back to"
+            );
+            false
+        }
+    }
+}
+
+/// Returns true if the request could be signed with the given auth scheme.
+fn is_eligible(
+    scheme_id: AuthSchemeId,
+    runtime_components: &RuntimeComponents,
+    endpoint: &Endpoint,
+) -> bool {
+    runtime_components
+        .auth_scheme(scheme_id)
+        .map_or(false, |auth_scheme| {
+            auth_scheme.identity_resolver(runtime_components).is_some()
+        })
+        && extract_endpoint_auth_scheme_config(endpoint, scheme_id).is_ok()
+}
+
 fn extract_endpoint_auth_scheme_config(
     endpoint: &Endpoint,
     scheme_id: AuthSchemeId,
@@ -281,11 +434,15 @@ impl fmt::Debug for ExploredList {
 #[cfg(all(test, feature = "test-util"))]
 mod tests {
     use super::*;
+    use crate::client::orchestrator::endpoints::{
+        StaticUriEndpointResolver, StaticUriEndpointResolverParams,
+    };
     use aws_smithy_runtime_api::client::auth::static_resolver::StaticAuthSchemeOptionResolver;
     use aws_smithy_runtime_api::client::auth::{
         AuthScheme, AuthSchemeId, AuthSchemeOptionResolverParams, SharedAuthScheme,
         SharedAuthSchemeOptionResolver, Sign,
     };
+    use aws_smithy_runtime_api::client::endpoint::EndpointResolverParams;
     use aws_smithy_runtime_api::client::identity::{
         Identity, IdentityFuture, ResolveIdentity, SharedIdentityResolver,
     };
@@ -297,6 +454,26 @@ mod tests {
     use aws_smithy_types::config_bag::Layer;
     use std::collections::HashMap;
 
+    async fn orchestrate_auth(
+        ctx: &mut InterceptorContext,
+        runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<AuthSchemeId, BoxError> {
+        // Resolve the endpoint that the test put into the config bag
+        let endpoint = cfg.load::<Endpoint>().expect("set by the test").clone();
+        let runtime_components = runtime_components
+            .to_builder()
+            .with_endpoint_resolver(Some(StaticUriEndpointResolver::uri(endpoint.url())))
+            .build()?;
+        cfg.interceptor_state()
+            .store_put(EndpointResolverParams::new(StaticUriEndpointResolverParams));
+
+        let (scheme_id, identity, _) =
+            resolve_identity(&runtime_components, cfg, Some(&endpoint)).await?;
+        sign_request(scheme_id, &identity, ctx, &runtime_components, cfg)?;
+        Ok(scheme_id)
+    }
+
     #[tokio::test]
     async fn basic_case() {
         #[derive(Debug)]
@@ -374,9 +551,9 @@ mod tests {
         let mut layer: Layer = Layer::new("test");
         layer.store_put(AuthSchemeOptionResolverParams::new("doesntmatter"));
         layer.store_put(Endpoint::builder().url("dontcare").build());
-        let cfg = ConfigBag::of_layers(vec![layer]);
+        let mut cfg = ConfigBag::of_layers(vec![layer]);
 
-        orchestrate_auth(&mut ctx, &runtime_components, &cfg)
+        orchestrate_auth(&mut ctx, &runtime_components, &mut cfg)
             .await
             .expect("success");
 
@@ -430,9 +607,9 @@ mod tests {
         }
 
         // First, test the presence of a basic auth login and absence of a bearer token
-        let (runtime_components, cfg) =
+        let (runtime_components, mut cfg) =
             config_with_identity(HTTP_BASIC_AUTH_SCHEME_ID, Login::new("a", "b", None));
-        orchestrate_auth(&mut ctx, &runtime_components, &cfg)
+        orchestrate_auth(&mut ctx, &runtime_components, &mut cfg)
             .await
             .expect("success");
         assert_eq!(
@@ -446,14 +623,14 @@ mod tests {
         );
 
         // Next, test the presence of a bearer token and absence of basic auth
-        let (runtime_components, cfg) =
+        let (runtime_components, mut cfg) =
             config_with_identity(HTTP_BEARER_AUTH_SCHEME_ID, Token::new("t", None));
         let mut ctx = InterceptorContext::new(Input::erase("doesnt-matter"));
         ctx.enter_serialization_phase();
         ctx.set_request(HttpRequest::empty());
         let _ = ctx.take_input();
         ctx.enter_before_transmit_phase();
-        orchestrate_auth(&mut ctx, &runtime_components, &cfg)
+        orchestrate_auth(&mut ctx, &runtime_components, &mut cfg)
             .await
             .expect("success");
         assert_eq!(
@@ -606,9 +783,9 @@ mod tests {
         let mut layer = Layer::new("test");
         layer.store_put(Endpoint::builder().url("dontcare").build());
         layer.store_put(AuthSchemeOptionResolverParams::new("doesntmatter"));
-        let config_bag = ConfigBag::of_layers(vec![layer]);
+        let mut config_bag = ConfigBag::of_layers(vec![layer]);
 
-        orchestrate_auth(&mut ctx, &runtime_components, &config_bag)
+        orchestrate_auth(&mut ctx, &runtime_components, &mut config_bag)
             .await
             .expect("success");
         assert_eq!(
@@ -621,6 +798,94 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn endpoint_is_resolved_again_for_the_identity() {
+        use crate::client::auth::no_auth::NoAuthScheme;
+        use aws_smithy_runtime_api::client::endpoint::{EndpointFuture, ResolveEndpoint};
+
+        #[derive(Debug)]
+        struct AccountId(&'static str);
+
+        #[derive(Debug)]
+        struct TestIdentityResolver(Option<&'static str>);
+        impl ResolveIdentity for TestIdentityResolver {
+            fn resolve_identity<'a>(
+                &'a self,
+                _runtime_components: &'a RuntimeComponents,
+                _config_bag: &'a ConfigBag,
+            ) -> IdentityFuture<'a> {
+                let identity = Identity::new("doesntmatter", None);
+                IdentityFuture::ready(Ok(match self.0 {
+                    Some(account_id) => identity.with_property(AccountId(account_id)),
+                    None => identity,
+                }))
+            }
+        }
+
+        /// Requires an account ID, which it only gets from the identity
+        #[derive(Debug)]
+        struct TestEndpointResolver;
+        impl ResolveEndpoint for TestEndpointResolver {
+            fn resolve_endpoint<'a>(
+                &'a self,
+                params: &'a EndpointResolverParams,
+            ) -> EndpointFuture<'a> {
+                EndpointFuture::ready(match params.get::<Option<&'static str>>() {
+                    Some(Some(account_id)) => Ok(Endpoint::builder()
+                        .url(format!("https://{account_id}.example.com"))
+                        .build()),
+                    _ => Err("an account ID is required".into()),
+                })
+            }
+
+            fn finalize_params(
+                &self,
+                _params: &EndpointResolverParams,
+                identity: &Identity,
+            ) -> Result<Option<EndpointResolverParams>, BoxError> {
+                Ok(identity
+                    .property::<AccountId>()
+                    .map(|account_id| EndpointResolverParams::new(Some(account_id.0))))
+            }
+
+            fn params_depend_on_identity(&self) -> bool {
+                true
+            }
+        }
+
+        let resolve = |account_id| async move {
+            let runtime_components = RuntimeComponentsBuilder::for_tests()
+                .with_auth_scheme(SharedAuthScheme::new(NoAuthScheme::new()))
+                .with_auth_scheme_option_resolver(Some(SharedAuthSchemeOptionResolver::new(
+                    StaticAuthSchemeOptionResolver::new(vec![NO_AUTH_SCHEME_ID]),
+                )))
+                .with_identity_resolver(
+                    NO_AUTH_SCHEME_ID,
+                    SharedIdentityResolver::new(TestIdentityResolver(account_id)),
+                )
+                .with_endpoint_resolver(Some(TestEndpointResolver))
+                .build()
+                .unwrap();
+            let mut layer = Layer::new("test");
+            layer.store_put(AuthSchemeOptionResolverParams::new("doesntmatter"));
+            layer.store_put(EndpointResolverParams::new(None::<&'static str>));
+            let cfg = ConfigBag::of_layers(vec![layer]);
+            // Without an account ID, the endpoint can't be resolved before the identity
+            resolve_identity(&runtime_components, &cfg, None)
+                .await
+                .map(|(_, _, endpoint)| endpoint)
+        };
+
+        let endpoint = resolve(Some("111122223333")).await.expect("success");
+        assert_eq!(
+            "https://111122223333.example.com",
+            endpoint.expect("resolved for the identity").url()
+        );
+
+        let endpoint = resolve(None).await.expect("success");
+        assert!(endpoint.is_none(), "{endpoint:?}");
+    }
+
     #[test]
     fn friendly_error_messages() {
         let err = NoMatchingAuthSchemeError(ExploredList::default());
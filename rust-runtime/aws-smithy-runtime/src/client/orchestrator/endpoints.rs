@@ -6,6 +6,7 @@
 use aws_smithy_runtime_api::client::endpoint::{
     error::ResolveEndpointError, EndpointFuture, EndpointResolverParams, ResolveEndpoint,
 };
+use aws_smithy_runtime_api::client::identity::Identity;
 use aws_smithy_runtime_api::client::interceptors::context::InterceptorContext;
 use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
 use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
@@ -67,11 +68,11 @@ impl From<StaticUriEndpointResolverParams> for EndpointResolverParams {
     }
 }
 
-pub(super) async fn orchestrate_endpoint(
-    ctx: &mut InterceptorContext,
+/// Resolves the endpoint from the endpoint parameters in the config bag.
+pub(super) async fn resolve_endpoint(
     runtime_components: &RuntimeComponents,
-    cfg: &mut ConfigBag,
-) -> Result<(), BoxError> {
+    cfg: &ConfigBag,
+) -> Result<Endpoint, BoxError> {
     trace!("orchestrating endpoint resolution");
 
     let params = cfg
@@ -79,13 +80,45 @@ pub(super) async fn orchestrate_endpoint(
         .expect("endpoint resolver params must be set");
     let endpoint_prefix = cfg.load::<EndpointPrefix>();
     tracing::debug!(endpoint_params = ?params, endpoint_prefix = ?endpoint_prefix, "resolving endpoint");
-    let request = ctx.request_mut().expect("set during serialization");
 
     let endpoint = runtime_components
         .endpoint_resolver()
         .resolve_endpoint(params)
         .await?;
     tracing::debug!("will use endpoint {:?}", endpoint);
+    Ok(endpoint)
+}
+
+/// Resolves the endpoint again if the endpoint parameters depend on the resolved `identity`.
+///
+/// Returns `None` if the endpoint resolver didn't change the endpoint parameters for the identity.
+pub(super) async fn resolve_endpoint_for_identity(
+    identity: &Identity,
+    runtime_components: &RuntimeComponents,
+    cfg: &ConfigBag,
+) -> Result<Option<Endpoint>, BoxError> {
+    let params = cfg
+        .load::<EndpointResolverParams>()
+        .expect("endpoint resolver params must be set");
+    let endpoint_resolver = runtime_components.endpoint_resolver();
+    let Some(params) = endpoint_resolver.finalize_params(params, identity)? else {
+        return Ok(None);
+    };
+    tracing::debug!(endpoint_params = ?params, "resolving endpoint with the parameters of the identity");
+
+    let endpoint = endpoint_resolver.resolve_endpoint(&params).await?;
+    tracing::debug!("will use endpoint {:?}", endpoint);
+    Ok(Some(endpoint))
+}
+
+/// Applies the resolved endpoint to the request, and makes it available to interceptors.
+pub(super) fn orchestrate_endpoint(
+    endpoint: Endpoint,
+    ctx: &mut InterceptorContext,
+    cfg: &mut ConfigBag,
+) -> Result<(), BoxError> {
+    let endpoint_prefix = cfg.load::<EndpointPrefix>();
+    let request = ctx.request_mut().expect("set during serialization");
     apply_endpoint(request, &endpoint, endpoint_prefix)?;
 
     // Make the endpoint config available to interceptors
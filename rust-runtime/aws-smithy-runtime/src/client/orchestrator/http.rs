@@ -4,37 +4,121 @@
  */
 
 use aws_smithy_runtime_api::client::orchestrator::{HttpResponse, SensitiveOutput};
+use aws_smithy_runtime_api::http::Headers;
 use aws_smithy_types::body::SdkBody;
 use aws_smithy_types::config_bag::ConfigBag;
+use aws_smithy_types::error::display::DisplayErrorContext;
 use bytes::{Buf, Bytes};
 use http_body_04x::Body;
 use pin_utils::pin_mut;
-use tracing::trace;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tracing::{debug, trace};
 
 const LOG_SENSITIVE_BODIES: &str = "LOG_SENSITIVE_BODIES";
 
-async fn body_to_bytes(body: SdkBody) -> Result<Bytes, <SdkBody as Body>::Error> {
-    let mut output = Vec::new();
+/// Number of bytes copied while reading a body before yielding to other tasks.
+const BYTES_BETWEEN_YIELDS: usize = 1024 * 1024;
+
+/// Largest number of bytes of a body that failed to be read that are kept for error reporting.
+const MAX_RETAINED_PARTIAL_BODY: usize = 64 * 1024;
+
+/// Future that returns `Pending` once, giving other tasks on the executor a chance to run.
+///
+/// This doesn't depend on an async runtime, unlike `tokio::task::yield_now`.
+#[derive(Debug, Default)]
+struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Reads `body` into `output`, leaving whatever was read in `output` if reading fails, and
+/// returns the body's valid trailers.
+///
+/// When the body's data is always ready, such as when a large response has already been
+/// received, reading it would otherwise never yield, so it periodically yields to avoid
+/// starving other tasks on the executor.
+async fn read_body_into(
+    body: SdkBody,
+    output: &mut Vec<u8>,
+) -> Result<Option<Headers>, <SdkBody as Body>::Error> {
     pin_mut!(body);
+    let mut copied_since_yield = 0;
     while let Some(buf) = body.data().await {
         let mut buf = buf?;
         while buf.has_remaining() {
             output.extend_from_slice(buf.chunk());
+            copied_since_yield += buf.chunk().len();
             buf.advance(buf.chunk().len())
         }
+        if copied_since_yield >= BYTES_BETWEEN_YIELDS {
+            copied_since_yield = 0;
+            YieldNow::default().await;
+        }
     }
+    // The data was received in full, so trailers that can't be read don't fail the response
+    match body.trailers().await {
+        Ok(trailers) => Ok(trailers.map(valid_trailers)),
+        Err(err) => {
+            debug!(error = %DisplayErrorContext(&*err), "dropping unreadable response trailers");
+            Ok(None)
+        }
+    }
+}
 
-    Ok(Bytes::from(output))
+/// Converts `trailers` into [`Headers`], dropping the trailers that aren't valid headers.
+fn valid_trailers(trailers: http_02x::HeaderMap) -> Headers {
+    let mut headers = Headers::new();
+    for (name, value) in &trailers {
+        match std::str::from_utf8(value.as_bytes()) {
+            Ok(value) => {
+                if let Err(err) = headers.try_append(name.as_str().to_owned(), value.to_owned()) {
+                    debug!(trailer = %name, error = %DisplayErrorContext(&err), "dropping an invalid response trailer");
+                }
+            }
+            Err(_) => debug!(trailer = %name, "dropping a response trailer that isn't valid UTF-8"),
+        }
+    }
+    headers
 }
 
+/// Buffers the response body in memory, along with its trailers.
+///
+/// If reading the body fails, the first bytes received before the failure, up to
+/// [`MAX_RETAINED_PARTIAL_BODY`], are left in the response so that they're available from the
+/// raw response of the resulting error.
 pub(crate) async fn read_body(response: &mut HttpResponse) -> Result<(), <SdkBody as Body>::Error> {
     let mut body = SdkBody::taken();
     std::mem::swap(&mut body, response.body_mut());
 
-    let bytes = body_to_bytes(body).await?;
-    let mut body = SdkBody::from(bytes);
-    std::mem::swap(&mut body, response.body_mut());
-
+    let mut output = Vec::new();
+    let result = read_body_into(body, &mut output).await;
+    if result.is_err() && output.len() > MAX_RETAINED_PARTIAL_BODY {
+        debug!(
+            received = output.len(),
+            retained = MAX_RETAINED_PARTIAL_BODY,
+            "truncating the partially read response body kept for error reporting"
+        );
+        output.truncate(MAX_RETAINED_PARTIAL_BODY);
+        output.shrink_to_fit();
+    }
+    *response.body_mut() = SdkBody::from(Bytes::from(output));
+    if let Some(trailers) = result? {
+        response.set_trailers(trailers);
+    }
     Ok(())
 }
 
@@ -52,3 +136,147 @@ pub(crate) fn log_response_body(response: &HttpResponse, cfg: &ConfigBag) {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Every chunk of a [`ReadyBody`], shared so that the body itself doesn't allocate.
+    static CHUNK: [u8; 64 * 1024] = [0; 64 * 1024];
+
+    /// A body whose chunks are always ready, like a large response that's already been received.
+    struct ReadyBody {
+        remaining_chunks: usize,
+        trailers: Option<http_02x::HeaderMap>,
+        fails_after_chunks: bool,
+    }
+
+    impl Body for ReadyBody {
+        type Data = Bytes;
+        type Error = std::io::Error;
+
+        fn poll_data(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+            if self.remaining_chunks == 0 {
+                if self.fails_after_chunks {
+                    return Poll::Ready(Some(Err(std::io::ErrorKind::ConnectionReset.into())));
+                }
+                return Poll::Ready(None);
+            }
+            self.remaining_chunks -= 1;
+            Poll::Ready(Some(Ok(Bytes::from_static(&CHUNK))))
+        }
+
+        fn poll_trailers(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<Option<http_02x::HeaderMap>, Self::Error>> {
+            Poll::Ready(Ok(self.trailers.take()))
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn reading_large_bodies_doesnt_starve_other_tasks() {
+        // Every time the reads stall the executor, this task misses a tick.
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let ticker = tokio::spawn({
+            let ticks = ticks.clone();
+            async move {
+                loop {
+                    ticks.fetch_add(1, Ordering::Relaxed);
+                    YieldNow::default().await;
+                }
+            }
+        });
+
+        // Four concurrent 4 MiB downloads
+        let downloads = (0..4).map(|_| async {
+            let mut response = HttpResponse::new(
+                200.try_into().unwrap(),
+                SdkBody::from_body_0_4(ReadyBody {
+                    remaining_chunks: 64,
+                    trailers: None,
+                    fails_after_chunks: false,
+                }),
+            );
+            read_body(&mut response).await.unwrap();
+            response.body().bytes().unwrap().len()
+        });
+        let lengths = futures_util::future::join_all(downloads).await;
+        ticker.abort();
+
+        assert_eq!(vec![4 * 1024 * 1024; 4], lengths);
+        // The reads yield once per MiB read, letting the ticker run in between.
+        assert!(
+            ticks.load(Ordering::Relaxed) >= 4 * 4,
+            "the executor stalled while reading bodies: the ticker only ran {} times",
+            ticks.load(Ordering::Relaxed)
+        );
+    }
+
+    #[tokio::test]
+    async fn buffered_responses_include_trailers() {
+        let mut trailers = http_02x::HeaderMap::new();
+        trailers.insert("x-amz-checksum-crc32", "sOO8/Q==".parse().unwrap());
+        let mut response = HttpResponse::new(
+            200.try_into().unwrap(),
+            SdkBody::from_body_0_4(ReadyBody {
+                remaining_chunks: 1,
+                trailers: Some(trailers),
+                fails_after_chunks: false,
+            }),
+        );
+        read_body(&mut response).await.unwrap();
+
+        assert_eq!(64 * 1024, response.body().bytes().unwrap().len());
+        assert_eq!(
+            Some("sOO8/Q=="),
+            response.trailers().unwrap().get("x-amz-checksum-crc32")
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_trailers_are_dropped() {
+        let mut trailers = http_02x::HeaderMap::new();
+        trailers.insert("x-amz-checksum-crc32", "sOO8/Q==".parse().unwrap());
+        trailers.insert(
+            "x-not-utf8",
+            http_02x::HeaderValue::from_bytes(b"\xff").unwrap(),
+        );
+        let mut response = HttpResponse::new(
+            200.try_into().unwrap(),
+            SdkBody::from_body_0_4(ReadyBody {
+                remaining_chunks: 1,
+                trailers: Some(trailers),
+                fails_after_chunks: false,
+            }),
+        );
+        read_body(&mut response).await.unwrap();
+
+        let trailers = response.trailers().unwrap();
+        assert_eq!(Some("sOO8/Q=="), trailers.get("x-amz-checksum-crc32"));
+        assert!(!trailers.contains_key("x-not-utf8"));
+    }
+
+    #[tokio::test]
+    async fn partial_bodies_of_failed_reads_are_truncated() {
+        let mut response = HttpResponse::new(
+            200.try_into().unwrap(),
+            SdkBody::from_body_0_4(ReadyBody {
+                remaining_chunks: 4,
+                trailers: None,
+                fails_after_chunks: true,
+            }),
+        );
+        read_body(&mut response).await.expect_err("the body fails");
+
+        assert_eq!(
+            MAX_RETAINED_PARTIAL_BODY,
+            response.body().bytes().unwrap().len()
+        );
+    }
+}
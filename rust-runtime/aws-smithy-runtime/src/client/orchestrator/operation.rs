@@ -9,7 +9,9 @@ use crate::client::http::connection_poisoning::ConnectionPoisoningInterceptor;
 use crate::client::identity::no_auth::NoAuthIdentityResolver;
 use crate::client::identity::IdentityCache;
 use crate::client::orchestrator::endpoints::StaticUriEndpointResolver;
-use crate::client::retries::strategy::{NeverRetryStrategy, StandardRetryStrategy};
+use crate::client::retries::strategy::{
+    AdaptiveRetryStrategy, NeverRetryStrategy, StandardRetryStrategy,
+};
 use aws_smithy_async::rt::sleep::AsyncSleep;
 use aws_smithy_async::time::TimeSource;
 use aws_smithy_runtime_api::client::auth::static_resolver::StaticAuthSchemeOptionResolver;
@@ -38,7 +40,7 @@ use aws_smithy_runtime_api::{
     box_error::BoxError, client::stalled_stream_protection::StalledStreamProtectionConfig,
 };
 use aws_smithy_types::config_bag::{ConfigBag, Layer};
-use aws_smithy_types::retry::RetryConfig;
+use aws_smithy_types::retry::{RetryConfig, RetryMode};
 use aws_smithy_types::timeout::TimeoutConfig;
 use std::borrow::Cow;
 use std::fmt;
@@ -232,10 +234,16 @@ impl<I, O, E> OperationBuilder<I, O, E> {
     }
 
     /// Configures the standard retry for the builder.
+    ///
+    /// When the retry mode is [`RetryMode::Adaptive`], the [`AdaptiveRetryStrategy`] is used.
     pub fn standard_retry(mut self, retry_config: &RetryConfig) -> Self {
         self.config.store_put(retry_config.clone());
+        let retry_strategy = match retry_config.mode() {
+            RetryMode::Adaptive => SharedRetryStrategy::new(AdaptiveRetryStrategy::new()),
+            _ => SharedRetryStrategy::new(StandardRetryStrategy::new()),
+        };
         self.runtime_components
-            .set_retry_strategy(Some(SharedRetryStrategy::new(StandardRetryStrategy::new())));
+            .set_retry_strategy(Some(retry_strategy));
         self
     }
 
@@ -540,4 +548,112 @@ mod tests {
 
         connector.assert_requests_match(&[]);
     }
+
+    #[cfg(feature = "http-auth")]
+    #[tokio::test]
+    async fn operation_falls_back_to_the_next_auth_scheme() {
+        use crate::client::auth::fallback::AuthSchemeFallback;
+        use crate::client::auth::http::{BasicAuthScheme, BearerAuthScheme};
+        use aws_smithy_runtime_api::client::auth::http::{
+            HTTP_BASIC_AUTH_SCHEME_ID, HTTP_BEARER_AUTH_SCHEME_ID,
+        };
+        use aws_smithy_runtime_api::client::identity::http::{Login, Token};
+        use aws_smithy_types::config_bag::Layer;
+
+        fn response(status: u16) -> http_02x::Response<SdkBody> {
+            http_02x::Response::builder()
+                .status(status)
+                .body(SdkBody::empty())
+                .unwrap()
+        }
+        let request = || {
+            http_02x::Request::builder()
+                .uri("http://localhost:1234/")
+                .body(SdkBody::empty())
+                .unwrap()
+        };
+
+        let invoke = |fallback: AuthSchemeFallback, responses: Vec<u16>| {
+            let connector = StaticReplayClient::new(
+                responses
+                    .into_iter()
+                    .map(|status| ReplayEvent::new(request(), response(status)))
+                    .collect(),
+            );
+            let mut config = Layer::new("auth");
+            config.store_put(AuthSchemeOptionResolverParams::new(()));
+            let auth = StaticRuntimePlugin::new()
+                .with_config(config.freeze())
+                .with_runtime_components(
+                    RuntimeComponentsBuilder::new("auth")
+                        .with_auth_scheme_option_resolver(Some(
+                            SharedAuthSchemeOptionResolver::new(
+                                StaticAuthSchemeOptionResolver::new(vec![
+                                    HTTP_BASIC_AUTH_SCHEME_ID,
+                                    HTTP_BEARER_AUTH_SCHEME_ID,
+                                ]),
+                            ),
+                        ))
+                        .with_auth_scheme(SharedAuthScheme::new(BasicAuthScheme::new()))
+                        .with_auth_scheme(SharedAuthScheme::new(BearerAuthScheme::new()))
+                        .with_identity_resolver(
+                            HTTP_BASIC_AUTH_SCHEME_ID,
+                            SharedIdentityResolver::new(Login::new("a", "b", None)),
+                        )
+                        .with_identity_resolver(
+                            HTTP_BEARER_AUTH_SCHEME_ID,
+                            SharedIdentityResolver::new(Token::new("t", None)),
+                        ),
+                );
+            let operation = Operation::builder()
+                .service_name("test")
+                .operation_name("test")
+                .http_client(connector.clone())
+                .endpoint_url("http://localhost:1234")
+                .runtime_plugin(auth)
+                .runtime_plugin(fallback)
+                .no_retry()
+                .timeout_config(TimeoutConfig::disabled())
+                .serializer(|_: ()| Ok(HttpRequest::new(SdkBody::empty())))
+                .deserializer::<_, Infallible>(|response| {
+                    if response.status().is_success() {
+                        Ok(())
+                    } else {
+                        Err(OrchestratorError::other("unauthorized"))
+                    }
+                })
+                .build();
+            (connector, operation)
+        };
+        let authorization_headers = |connector: &StaticReplayClient| {
+            connector
+                .actual_requests()
+                .map(|request| request.headers().get("Authorization").unwrap().to_owned())
+                .collect::<Vec<_>>()
+        };
+
+        // The request is retried once with the bearer token after basic auth was rejected
+        let (connector, operation) = invoke(AuthSchemeFallback::enabled(), vec![401, 200]);
+        operation.invoke(()).await.expect("success");
+        assert_eq!(
+            vec!["Basic YTpi", "Bearer t"],
+            authorization_headers(&connector)
+        );
+
+        // Falling back only happens once
+        let (connector, operation) = invoke(AuthSchemeFallback::enabled(), vec![403, 401]);
+        operation
+            .invoke(())
+            .await
+            .expect_err("both schemes rejected");
+        assert_eq!(
+            vec!["Basic YTpi", "Bearer t"],
+            authorization_headers(&connector)
+        );
+
+        // Without fallback, the rejection is returned
+        let (connector, operation) = invoke(AuthSchemeFallback::disabled(), vec![401]);
+        operation.invoke(()).await.expect_err("basic auth rejected");
+        assert_eq!(vec!["Basic YTpi"], authorization_headers(&connector));
+    }
 }
@@ -10,10 +10,13 @@ pub mod classifiers;
 pub mod strategy;
 
 mod client_rate_limiter;
+mod quota_metrics;
 mod token_bucket;
 
+use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
 use aws_smithy_types::config_bag::{Storable, StoreReplace};
 use std::fmt;
+use std::time::Duration;
 
 pub use client_rate_limiter::ClientRateLimiter;
 pub use token_bucket::TokenBucket;
@@ -44,3 +47,91 @@ impl fmt::Display for RetryPartition {
 impl Storable for RetryPartition {
     type Storer = StoreReplace<RetryPartition>;
 }
+
+/// Controls whether operations share the retry quota of their [`RetryPartition`].
+///
+/// By default, every operation sent to a retry partition draws retry tokens from the same token
+/// bucket, so a single operation failing repeatedly can exhaust the quota and prevent all other
+/// operations from retrying. Partitioning the quota gives each operation (or each group of
+/// operations sharing a key) a token bucket of its own.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum RetryQuotaPartitioning {
+    /// All operations share the retry quota of the retry partition.
+    #[default]
+    Shared,
+    /// Each operation has its own retry quota within the retry partition.
+    PerOperation,
+    /// Operations configured with the same key share a retry quota within the retry partition.
+    ///
+    /// This is typically set with a config override on individual operations.
+    Key(Cow<'static, str>),
+}
+
+impl RetryQuotaPartitioning {
+    /// Creates a [`RetryQuotaPartitioning::Key`] from the given `key`.
+    pub fn key(key: impl Into<Cow<'static, str>>) -> Self {
+        Self::Key(key.into())
+    }
+}
+
+impl Storable for RetryQuotaPartitioning {
+    type Storer = StoreReplace<RetryQuotaPartitioning>;
+}
+
+/// Returns the delay that a response asks the client to wait before making another request, if any.
+///
+/// The delay is read from the `x-amz-retry-after` header, in milliseconds, or from the `Retry-After`
+/// header, in seconds. `Retry-After` headers with an HTTP date are ignored.
+pub fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    let headers = response.headers();
+    headers
+        .get("x-amz-retry-after")
+        .and_then(|millis| millis.trim().parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .or_else(|| {
+            headers
+                .get("retry-after")
+                .and_then(|secs| secs.trim().parse::<u64>().ok())
+                .map(Duration::from_secs)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::retry_after;
+    use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
+    use aws_smithy_types::body::SdkBody;
+    use std::time::Duration;
+
+    fn response(headers: &[(&'static str, &'static str)]) -> HttpResponse {
+        let mut response = HttpResponse::new(429.try_into().unwrap(), SdkBody::empty());
+        for (name, value) in headers {
+            response.headers_mut().insert(*name, *value);
+        }
+        response
+    }
+
+    #[test]
+    fn retry_after_headers() {
+        assert_eq!(None, retry_after(&response(&[])));
+        assert_eq!(
+            Some(Duration::from_secs(3)),
+            retry_after(&response(&[("retry-after", "3")]))
+        );
+        assert_eq!(
+            Some(Duration::from_millis(1500)),
+            retry_after(&response(&[
+                ("retry-after", "3"),
+                ("x-amz-retry-after", "1500")
+            ]))
+        );
+        assert_eq!(
+            None,
+            retry_after(&response(&[(
+                "retry-after",
+                "Wed, 21 Oct 2015 07:28:00 GMT"
+            )]))
+        );
+    }
+}
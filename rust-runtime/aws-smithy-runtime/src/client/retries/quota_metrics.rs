@@ -0,0 +1,99 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::client::retries::RetryPartition;
+use aws_smithy_observability::global::get_telemetry_provider;
+use aws_smithy_observability::instruments::MonotonicCounter;
+use aws_smithy_observability::meter::Meter;
+use aws_smithy_observability::{AttributeValue, Attributes, TelemetryProvider};
+use aws_smithy_types::config_bag::{Storable, StoreReplace};
+use std::fmt;
+use std::sync::Arc;
+
+const SCOPE: &str = "aws-smithy-runtime";
+const PARTITION_ATTRIBUTE: &str = "retry_partition";
+
+/// Counters describing the consumption of the retry quota.
+#[derive(Clone, Debug)]
+pub(crate) struct RetryQuotaMetrics {
+    consumed: Arc<dyn MonotonicCounter>,
+    exhausted: Arc<dyn MonotonicCounter>,
+}
+
+impl RetryQuotaMetrics {
+    /// Creates the retry quota counters with the meter provider of the global [`TelemetryProvider`].
+    pub(crate) fn global() -> Self {
+        let provider = get_telemetry_provider().unwrap_or_else(|err| {
+            tracing::debug!(err = %err, "failed to get the global telemetry provider; retry quota metrics are disabled");
+            Arc::new(TelemetryProvider::noop())
+        });
+        Self::new(&provider.meter_provider().get_meter(SCOPE, None))
+    }
+
+    pub(crate) fn new(meter: &Meter) -> Self {
+        Self {
+            consumed: meter
+                .create_monotonic_counter("smithy.client.retries.quota.consumed")
+                .set_description("The number of retry tokens acquired from the retry quota")
+                .set_units("{token}")
+                .build(),
+            exhausted: meter
+                .create_monotonic_counter("smithy.client.retries.quota.exhausted")
+                .set_description(
+                    "The number of retries that were not attempted because the retry quota was exhausted",
+                )
+                .set_units("{retry}")
+                .build(),
+        }
+    }
+
+    /// Returns a recorder attributing the consumption of the retry quota to the given partition.
+    pub(crate) fn for_partition(&self, partition: &RetryPartition) -> RetryQuotaRecorder {
+        let mut attributes = Attributes::new();
+        attributes.set(
+            PARTITION_ATTRIBUTE,
+            AttributeValue::String(partition.to_string()),
+        );
+        RetryQuotaRecorder {
+            metrics: self.clone(),
+            partition: partition.clone(),
+            attributes,
+        }
+    }
+}
+
+/// Records the consumption of the retry quota of a single partition.
+///
+/// Stored in the config bag alongside the [`TokenBucket`](super::TokenBucket) it describes.
+#[derive(Clone)]
+pub(crate) struct RetryQuotaRecorder {
+    metrics: RetryQuotaMetrics,
+    partition: RetryPartition,
+    attributes: Attributes,
+}
+
+impl fmt::Debug for RetryQuotaRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryQuotaRecorder")
+            .field("partition", &self.partition)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RetryQuotaRecorder {
+    pub(crate) fn record_consumed(&self, tokens: u32) {
+        self.metrics
+            .consumed
+            .add(tokens.into(), Some(&self.attributes), None);
+    }
+
+    pub(crate) fn record_exhausted(&self) {
+        self.metrics.exhausted.add(1, Some(&self.attributes), None);
+    }
+}
+
+impl Storable for RetryQuotaRecorder {
+    type Storer = StoreReplace<Self>;
+}
@@ -0,0 +1,163 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::context::InterceptorContext;
+use aws_smithy_runtime_api::client::retries::{RetryStrategy, ShouldAttempt};
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::config_bag::ConfigBag;
+
+use super::StandardRetryStrategy;
+
+/// Retry strategy with exponential backoff, max attempts, a token bucket, and client-side rate
+/// limiting.
+///
+/// On top of what the [`StandardRetryStrategy`] does, this strategy measures the rate at which
+/// requests are sent, and once a throttling error is received, it limits that rate. The limit is
+/// lowered on each throttling error, and raised again as requests succeed. Requests, including
+/// initial requests, are delayed until the rate limiter allows them to be sent.
+///
+/// The rate limiter is shared by every operation in the same [`RetryPartition`](crate::client::retries::RetryPartition),
+/// which means all the operations of a client share it unless the partition is overridden.
+///
+/// Retry configs created with [`RetryConfig::adaptive()`](aws_smithy_types::retry::RetryConfig::adaptive)
+/// select this behavior: the [`StandardRetryStrategy`] limits the send rate when the retry mode is
+/// [`RetryMode::Adaptive`](aws_smithy_types::retry::RetryMode::Adaptive), and
+/// [`Operation::builder().standard_retry()`](crate::client::orchestrator::operation::OperationBuilder::standard_retry)
+/// uses this strategy for that mode. Unlike the [`StandardRetryStrategy`], this strategy limits the
+/// send rate regardless of the configured retry mode.
+#[derive(Debug)]
+pub struct AdaptiveRetryStrategy {
+    inner: StandardRetryStrategy,
+}
+
+impl AdaptiveRetryStrategy {
+    /// Creates a new `AdaptiveRetryStrategy`.
+    pub fn new() -> Self {
+        Self {
+            inner: StandardRetryStrategy::adaptive(),
+        }
+    }
+}
+
+impl Default for AdaptiveRetryStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryStrategy for AdaptiveRetryStrategy {
+    fn should_attempt_initial_request(
+        &self,
+        runtime_components: &RuntimeComponents,
+        cfg: &ConfigBag,
+    ) -> Result<ShouldAttempt, BoxError> {
+        self.inner
+            .should_attempt_initial_request(runtime_components, cfg)
+    }
+
+    fn should_attempt_retry(
+        &self,
+        context: &InterceptorContext,
+        runtime_components: &RuntimeComponents,
+        cfg: &ConfigBag,
+    ) -> Result<ShouldAttempt, BoxError> {
+        self.inner
+            .should_attempt_retry(context, runtime_components, cfg)
+    }
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use aws_smithy_async::test_util::ManualTimeSource;
+    use aws_smithy_runtime_api::client::interceptors::context::{Input, InterceptorContext};
+    use aws_smithy_runtime_api::client::orchestrator::OrchestratorError;
+    use aws_smithy_runtime_api::client::retries::classifiers::SharedRetryClassifier;
+    use aws_smithy_runtime_api::client::retries::{
+        AlwaysRetry, RequestAttempts, RetryStrategy, ShouldAttempt,
+    };
+    use aws_smithy_runtime_api::client::runtime_components::{
+        RuntimeComponents, RuntimeComponentsBuilder,
+    };
+    use aws_smithy_types::config_bag::{ConfigBag, Layer};
+    use aws_smithy_types::retry::{ErrorKind, RetryConfig};
+
+    use super::AdaptiveRetryStrategy;
+    use crate::client::retries::strategy::StandardRetryStrategy;
+    use crate::client::retries::{RetryPartition, TokenBucket};
+
+    fn set_up(
+        partition: &'static str,
+        retry_config: RetryConfig,
+    ) -> (InterceptorContext, RuntimeComponents, ConfigBag) {
+        let mut ctx = InterceptorContext::new(Input::doesnt_matter());
+        ctx.set_output_or_error(Err(OrchestratorError::other("doesn't matter")));
+        let rc = RuntimeComponentsBuilder::for_tests()
+            .with_retry_classifier(SharedRetryClassifier::new(AlwaysRetry(
+                ErrorKind::ThrottlingError,
+            )))
+            .with_time_source(Some(ManualTimeSource::new(
+                UNIX_EPOCH + Duration::from_secs(1_000_000),
+            )))
+            .build()
+            .unwrap();
+        let mut layer = Layer::new("test");
+        layer.store_put(RequestAttempts::new(1));
+        layer.store_put(retry_config.with_max_attempts(5));
+        layer.store_put(TokenBucket::default());
+        layer.store_put(RetryPartition::new(partition));
+        (ctx, rc, ConfigBag::of_layers(vec![layer]))
+    }
+
+    #[test]
+    fn throttling_errors_delay_requests_of_every_operation() {
+        let (ctx, rc, cfg) = set_up("adaptive-strategy-test", RetryConfig::standard());
+        let first_operation = AdaptiveRetryStrategy::new();
+        assert_eq!(
+            ShouldAttempt::Yes,
+            first_operation
+                .should_attempt_initial_request(&rc, &cfg)
+                .unwrap()
+        );
+        let retry = first_operation
+            .should_attempt_retry(&ctx, &rc, &cfg)
+            .unwrap();
+        assert!(
+            matches!(retry, ShouldAttempt::YesAfterDelay(_)),
+            "{retry:?}"
+        );
+
+        // The rate limiter is shared with the other operations in the same retry partition.
+        let second_operation = AdaptiveRetryStrategy::new();
+        let initial = second_operation
+            .should_attempt_initial_request(&rc, &cfg)
+            .unwrap();
+        assert!(
+            matches!(initial, ShouldAttempt::YesAfterDelay(delay) if delay > Duration::ZERO),
+            "{initial:?}"
+        );
+    }
+
+    #[test]
+    fn standard_strategy_only_limits_the_rate_in_adaptive_mode() {
+        let (ctx, rc, cfg) = set_up("standard-strategy-test", RetryConfig::standard());
+        let strategy = StandardRetryStrategy::new();
+        strategy.should_attempt_retry(&ctx, &rc, &cfg).unwrap();
+        assert_eq!(
+            ShouldAttempt::Yes,
+            strategy.should_attempt_initial_request(&rc, &cfg).unwrap()
+        );
+
+        let (ctx, rc, cfg) = set_up("adaptive-mode-test", RetryConfig::adaptive());
+        let strategy = StandardRetryStrategy::new();
+        strategy.should_attempt_retry(&ctx, &rc, &cfg).unwrap();
+        assert!(matches!(
+            strategy.should_attempt_initial_request(&rc, &cfg).unwrap(),
+            ShouldAttempt::YesAfterDelay(_)
+        ));
+    }
+}
@@ -14,6 +14,7 @@ use aws_smithy_runtime_api::client::interceptors::context::{
     BeforeTransmitInterceptorContextMut, InterceptorContext,
 };
 use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::orchestrator::Metadata;
 use aws_smithy_runtime_api::client::retries::classifiers::{RetryAction, RetryReason};
 use aws_smithy_runtime_api::client::retries::{RequestAttempts, RetryStrategy, ShouldAttempt};
 use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
@@ -22,11 +23,12 @@ use aws_smithy_types::retry::{ErrorKind, RetryConfig, RetryMode};
 
 use crate::client::retries::classifiers::run_classifiers_on_ctx;
 use crate::client::retries::client_rate_limiter::{ClientRateLimiter, RequestReason};
+use crate::client::retries::quota_metrics::{RetryQuotaMetrics, RetryQuotaRecorder};
 use crate::client::retries::strategy::standard::ReleaseResult::{
     APermitWasReleased, NoPermitWasReleased,
 };
 use crate::client::retries::token_bucket::TokenBucket;
-use crate::client::retries::{ClientRateLimiterPartition, RetryPartition};
+use crate::client::retries::{ClientRateLimiterPartition, RetryPartition, RetryQuotaPartitioning};
 use crate::static_partition_map::StaticPartitionMap;
 
 static CLIENT_RATE_LIMITER: StaticPartitionMap<ClientRateLimiterPartition, ClientRateLimiter> =
@@ -36,9 +38,13 @@ static CLIENT_RATE_LIMITER: StaticPartitionMap<ClientRateLimiterPartition, Clien
 static TOKEN_BUCKET: StaticPartitionMap<RetryPartition, TokenBucket> = StaticPartitionMap::new();
 
 /// Retry strategy with exponential backoff, max attempts, and a token bucket.
+///
+/// When the [`RetryConfig`] mode is [`RetryMode::Adaptive`], this strategy also limits the rate
+/// at which requests are sent, like [`AdaptiveRetryStrategy`](super::AdaptiveRetryStrategy).
 #[derive(Debug, Default)]
 pub struct StandardRetryStrategy {
     retry_permit: Mutex<Option<OwnedSemaphorePermit>>,
+    always_adaptive: bool,
 }
 
 impl Storable for StandardRetryStrategy {
@@ -51,6 +57,15 @@ impl StandardRetryStrategy {
         Default::default()
     }
 
+    /// Creates a standard retry strategy that limits the rate at which requests are sent,
+    /// regardless of the configured retry mode.
+    pub(super) fn adaptive() -> Self {
+        Self {
+            always_adaptive: true,
+            ..Default::default()
+        }
+    }
+
     fn release_retry_permit(&self) -> ReleaseResult {
         let mut retry_permit = self.retry_permit.lock().unwrap();
         match retry_permit.take() {
@@ -73,11 +88,12 @@ impl StandardRetryStrategy {
 
     /// Returns a [`ClientRateLimiter`] if adaptive retry is configured.
     fn adaptive_retry_rate_limiter(
+        &self,
         runtime_components: &RuntimeComponents,
         cfg: &ConfigBag,
     ) -> Option<ClientRateLimiter> {
         let retry_config = cfg.load::<RetryConfig>().expect("retry config is required");
-        if retry_config.mode() == RetryMode::Adaptive {
+        if self.always_adaptive || retry_config.mode() == RetryMode::Adaptive {
             if let Some(time_source) = runtime_components.time_source() {
                 let retry_partition = cfg.load::<RetryPartition>().expect("set in default config");
                 let seconds_since_unix_epoch = time_source
@@ -97,6 +113,41 @@ impl StandardRetryStrategy {
         None
     }
 
+    fn update_rate_limiter_if_exists(
+        &self,
+        runtime_components: &RuntimeComponents,
+        cfg: &ConfigBag,
+        is_throttling_error: bool,
+    ) {
+        if let Some(crl) = self.adaptive_retry_rate_limiter(runtime_components, cfg) {
+            let seconds_since_unix_epoch = get_seconds_since_unix_epoch(runtime_components);
+            crl.update_rate_limiter(seconds_since_unix_epoch, is_throttling_error);
+        }
+    }
+
+    fn check_rate_limiter_for_delay(
+        &self,
+        runtime_components: &RuntimeComponents,
+        cfg: &ConfigBag,
+        kind: ErrorKind,
+    ) -> Option<Duration> {
+        if let Some(crl) = self.adaptive_retry_rate_limiter(runtime_components, cfg) {
+            let retry_reason = if kind == ErrorKind::ThrottlingError {
+                RequestReason::RetryTimeout
+            } else {
+                RequestReason::Retry
+            };
+            if let Err(delay) = crl.acquire_permission_to_send_a_request(
+                get_seconds_since_unix_epoch(runtime_components),
+                retry_reason,
+            ) {
+                return Some(delay);
+            }
+        }
+
+        None
+    }
+
     fn calculate_backoff(
         &self,
         runtime_components: &RuntimeComponents,
@@ -116,7 +167,7 @@ impl StandardRetryStrategy {
                     debug!("explicit request from server to delay {delay:?} before retrying");
                     Ok(delay)
                 } else if let Some(delay) =
-                    check_rate_limiter_for_delay(runtime_components, cfg, *kind)
+                    self.check_rate_limiter_for_delay(runtime_components, cfg, *kind)
                 {
                     let delay = delay.min(retry_cfg.max_backoff());
                     debug!("rate limiter has requested a {delay:?} delay before retrying");
@@ -164,7 +215,7 @@ impl RetryStrategy for StandardRetryStrategy {
         runtime_components: &RuntimeComponents,
         cfg: &ConfigBag,
     ) -> Result<ShouldAttempt, BoxError> {
-        if let Some(crl) = Self::adaptive_retry_rate_limiter(runtime_components, cfg) {
+        if let Some(crl) = self.adaptive_retry_rate_limiter(runtime_components, cfg) {
             let seconds_since_unix_epoch = get_seconds_since_unix_epoch(runtime_components);
             if let Err(delay) = crl.acquire_permission_to_send_a_request(
                 seconds_since_unix_epoch,
@@ -201,7 +252,7 @@ impl RetryStrategy for StandardRetryStrategy {
         let is_throttling_error = error_kind
             .map(|kind| kind == ErrorKind::ThrottlingError)
             .unwrap_or(false);
-        update_rate_limiter_if_exists(runtime_components, cfg, is_throttling_error);
+        self.update_rate_limiter_if_exists(runtime_components, cfg, is_throttling_error);
 
         // on success release any retry quota held by previous attempts
         if !ctx.is_failed() {
@@ -241,9 +292,18 @@ impl RetryStrategy for StandardRetryStrategy {
 
         //  acquire permit for retry
         let error_kind = error_kind.expect("result was classified retryable");
+        let quota_recorder = cfg.load::<RetryQuotaRecorder>();
         match token_bucket.acquire(&error_kind) {
-            Some(permit) => self.set_retry_permit(permit),
+            Some(permit) => {
+                if let Some(recorder) = quota_recorder {
+                    recorder.record_consumed(token_bucket.retry_cost(&error_kind));
+                }
+                self.set_retry_permit(permit)
+            }
             None => {
+                if let Some(recorder) = quota_recorder {
+                    recorder.record_exhausted();
+                }
                 debug!("attempt #{request_attempts} failed with {error_kind:?}; However, not enough retry quota is available for another attempt so no retry will be attempted.");
                 return Ok(ShouldAttempt::No);
             }
@@ -273,39 +333,6 @@ fn error_kind(classifier_result: &RetryAction) -> Option<ErrorKind> {
     }
 }
 
-fn update_rate_limiter_if_exists(
-    runtime_components: &RuntimeComponents,
-    cfg: &ConfigBag,
-    is_throttling_error: bool,
-) {
-    if let Some(crl) = StandardRetryStrategy::adaptive_retry_rate_limiter(runtime_components, cfg) {
-        let seconds_since_unix_epoch = get_seconds_since_unix_epoch(runtime_components);
-        crl.update_rate_limiter(seconds_since_unix_epoch, is_throttling_error);
-    }
-}
-
-fn check_rate_limiter_for_delay(
-    runtime_components: &RuntimeComponents,
-    cfg: &ConfigBag,
-    kind: ErrorKind,
-) -> Option<Duration> {
-    if let Some(crl) = StandardRetryStrategy::adaptive_retry_rate_limiter(runtime_components, cfg) {
-        let retry_reason = if kind == ErrorKind::ThrottlingError {
-            RequestReason::RetryTimeout
-        } else {
-            RequestReason::Retry
-        };
-        if let Err(delay) = crl.acquire_permission_to_send_a_request(
-            get_seconds_since_unix_epoch(runtime_components),
-            retry_reason,
-        ) {
-            return Some(delay);
-        }
-    }
-
-    None
-}
-
 fn calculate_exponential_backoff(
     base: f64,
     initial_backoff: f64,
@@ -344,11 +371,13 @@ fn get_seconds_since_unix_epoch(runtime_components: &RuntimeComponents) -> f64 {
 
 /// Interceptor registered in default retry plugin that ensures a token bucket exists in config
 /// bag for every operation. Token bucket provided is partitioned by the retry partition **in the
-/// config bag** at the time an operation is executed.
+/// config bag** at the time an operation is executed, and further by operation when configured
+/// with a [`RetryQuotaPartitioning`].
 #[derive(Debug)]
 pub(crate) struct TokenBucketProvider {
     default_partition: RetryPartition,
     token_bucket: TokenBucket,
+    metrics: RetryQuotaMetrics,
 }
 
 impl TokenBucketProvider {
@@ -361,10 +390,27 @@ impl TokenBucketProvider {
         Self {
             default_partition,
             token_bucket,
+            metrics: RetryQuotaMetrics::global(),
         }
     }
 }
 
+/// Returns the partition of the retry quota used by an operation.
+fn quota_partition(retry_partition: &RetryPartition, cfg: &ConfigBag) -> Option<RetryPartition> {
+    let key = match cfg.load::<RetryQuotaPartitioning>() {
+        None | Some(RetryQuotaPartitioning::Shared) => return None,
+        Some(RetryQuotaPartitioning::PerOperation) => match cfg.load::<Metadata>() {
+            Some(metadata) => metadata.name().to_owned(),
+            None => {
+                debug!("no operation metadata in the config bag; using the shared retry quota");
+                return None;
+            }
+        },
+        Some(RetryQuotaPartitioning::Key(key)) => key.to_string(),
+    };
+    Some(RetryPartition::new(format!("{retry_partition}#{key}")))
+}
+
 impl Intercept for TokenBucketProvider {
     fn name(&self) -> &'static str {
         "TokenBucketProvider"
@@ -377,20 +423,28 @@ impl Intercept for TokenBucketProvider {
         cfg: &mut ConfigBag,
     ) -> Result<(), BoxError> {
         let retry_partition = cfg.load::<RetryPartition>().expect("set in default config");
+        let quota_partition = quota_partition(retry_partition, cfg);
 
         // we store the original retry partition configured and associated token bucket
         // for the client when created so that we can avoid locking on _every_ request
         // from _every_ client
-        let tb = if *retry_partition != self.default_partition {
-            TOKEN_BUCKET.get_or_init_default(retry_partition.clone())
-        } else {
+        let (tb, partition) = match quota_partition {
+            Some(partition) => (
+                TOKEN_BUCKET.get_or_init_default(partition.clone()),
+                partition,
+            ),
+            None if *retry_partition != self.default_partition => (
+                TOKEN_BUCKET.get_or_init_default(retry_partition.clone()),
+                retry_partition.clone(),
+            ),
             // avoid contention on the global lock
-            self.token_bucket.clone()
+            None => (self.token_bucket.clone(), self.default_partition.clone()),
         };
 
-        trace!("token bucket for {retry_partition:?} added to config bag");
+        trace!("token bucket for {partition:?} added to config bag");
         let mut layer = Layer::new("token_bucket_partition");
         layer.store_put(tb);
+        layer.store_put(self.metrics.for_partition(&partition));
         cfg.push_layer(layer);
         Ok(())
     }
@@ -419,8 +473,16 @@ mod tests {
     use aws_smithy_types::config_bag::{ConfigBag, Layer};
     use aws_smithy_types::retry::{ErrorKind, RetryConfig};
 
+    #[cfg(feature = "test-util")]
+    use super::TokenBucketProvider;
     use super::{calculate_exponential_backoff, StandardRetryStrategy};
     use crate::client::retries::TokenBucket;
+    #[cfg(feature = "test-util")]
+    use crate::client::retries::{RetryPartition, RetryQuotaPartitioning};
+    #[cfg(feature = "test-util")]
+    use aws_smithy_runtime_api::client::interceptors::Intercept;
+    #[cfg(feature = "test-util")]
+    use aws_smithy_runtime_api::client::orchestrator::Metadata;
 
     #[test]
     fn no_retry_necessary_for_ok_result() {
@@ -958,6 +1020,69 @@ mod tests {
         assert_eq!(token_bucket.available_permits(), 480);
     }
 
+    #[cfg(feature = "test-util")]
+    fn token_bucket_for(
+        provider: &TokenBucketProvider,
+        partitioning: Option<RetryQuotaPartitioning>,
+        operation: &'static str,
+    ) -> TokenBucket {
+        let rc = RuntimeComponentsBuilder::for_tests().build().unwrap();
+        let mut layer = Layer::new("test");
+        layer.store_put(provider.default_partition.clone());
+        layer.store_put(Metadata::new(operation, "TestService"));
+        layer.store_or_unset(partitioning);
+        let mut cfg = ConfigBag::of_layers(vec![layer]);
+        let mut ctx = InterceptorContext::new(Input::doesnt_matter());
+        provider
+            .modify_before_retry_loop(&mut (&mut ctx).into(), &rc, &mut cfg)
+            .unwrap();
+        cfg.load::<TokenBucket>().unwrap().clone()
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn retry_quota_is_shared_by_default() {
+        let provider = TokenBucketProvider::new(RetryPartition::new("quota-shared"));
+        let first = token_bucket_for(&provider, None, "First");
+        let second = token_bucket_for(&provider, Some(RetryQuotaPartitioning::Shared), "Second");
+
+        let _permit = first.acquire(&ErrorKind::ServerError).unwrap();
+        assert_eq!(495, first.available_permits());
+        assert_eq!(495, second.available_permits());
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn retry_quota_partitioned_per_operation() {
+        let provider = TokenBucketProvider::new(RetryPartition::new("quota-per-operation"));
+        let partitioning = Some(RetryQuotaPartitioning::PerOperation);
+        let first = token_bucket_for(&provider, partitioning.clone(), "First");
+        let second = token_bucket_for(&provider, partitioning.clone(), "Second");
+        let shared = token_bucket_for(&provider, None, "First");
+
+        let _permit = first.acquire(&ErrorKind::ServerError).unwrap();
+        assert_eq!(495, first.available_permits());
+        assert_eq!(500, second.available_permits());
+        assert_eq!(500, shared.available_permits());
+        assert_eq!(
+            495,
+            token_bucket_for(&provider, partitioning, "First").available_permits()
+        );
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn retry_quota_partitioned_by_key() {
+        let provider = TokenBucketProvider::new(RetryPartition::new("quota-key"));
+        let first = token_bucket_for(&provider, Some(RetryQuotaPartitioning::key("a")), "First");
+        let second = token_bucket_for(&provider, Some(RetryQuotaPartitioning::key("a")), "Second");
+        let third = token_bucket_for(&provider, Some(RetryQuotaPartitioning::key("b")), "Third");
+
+        let _permit = first.acquire(&ErrorKind::ServerError).unwrap();
+        assert_eq!(495, second.available_permits());
+        assert_eq!(500, third.available_permits());
+    }
+
     const MAX_BACKOFF: Duration = Duration::from_secs(20);
 
     #[test]
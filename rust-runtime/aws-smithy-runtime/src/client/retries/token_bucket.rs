@@ -50,18 +50,21 @@ impl TokenBucket {
     }
 
     pub(crate) fn acquire(&self, err: &ErrorKind) -> Option<OwnedSemaphorePermit> {
-        let retry_cost = if err == &ErrorKind::TransientError {
-            self.timeout_retry_cost
-        } else {
-            self.retry_cost
-        };
-
         self.semaphore
             .clone()
-            .try_acquire_many_owned(retry_cost)
+            .try_acquire_many_owned(self.retry_cost(err))
             .ok()
     }
 
+    /// Returns the number of tokens acquired to retry an error of the given kind.
+    pub(crate) fn retry_cost(&self, err: &ErrorKind) -> u32 {
+        if err == &ErrorKind::TransientError {
+            self.timeout_retry_cost
+        } else {
+            self.retry_cost
+        }
+    }
+
     pub(crate) fn regenerate_a_token(&self) {
         if self.semaphore.available_permits() < (self.max_permits) {
             trace!("adding {PERMIT_REGENERATION_AMOUNT} back into the bucket");
@@ -0,0 +1,357 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use aws_smithy_observability::global::get_telemetry_provider;
+use aws_smithy_observability::instruments::{Histogram, MonotonicCounter};
+use aws_smithy_observability::{semconv, AttributeValue, Attributes, TelemetryProvider};
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::context::{
+    BeforeDeserializationInterceptorContextRef, BeforeSerializationInterceptorContextRef,
+    BeforeTransmitInterceptorContextRef, Error, FinalizerInterceptorContextRef,
+};
+use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::orchestrator::{HttpResponse, Metadata, OrchestratorError};
+use aws_smithy_runtime_api::client::retries::RequestAttempts;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
+use std::fmt;
+use std::sync::Arc;
+use std::time::SystemTime;
+use tracing::field::display;
+
+const SCOPE: &str = "aws-smithy-runtime";
+
+/// The `smithy.attempt` span of the current request attempt.
+#[derive(Clone, Debug)]
+pub(crate) struct AttemptSpan(pub(crate) tracing::Span);
+
+impl Storable for AttemptSpan {
+    type Storer = StoreReplace<Self>;
+}
+
+/// Records the [semantic convention attributes](aws_smithy_observability::semconv) of each
+/// request attempt on its `smithy.attempt` span: the resend count on retries, the endpoint host,
+/// and the HTTP status code of the response.
+///
+/// The service and operation names are on the operation span that generated clients open around
+/// the attempts, so they aren't repeated on every attempt.
+///
+/// The attributes aren't recorded if they are disabled on the global
+/// [`TelemetryProvider`](aws_smithy_observability::TelemetryProvider) when the interceptor is created.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TelemetryInterceptor {
+    enabled: bool,
+}
+
+impl TelemetryInterceptor {
+    /// Creates a telemetry interceptor configured by the global
+    /// [`TelemetryProvider`](aws_smithy_observability::TelemetryProvider).
+    pub fn new() -> Self {
+        let enabled = get_telemetry_provider()
+            .map(|provider| provider.rpc_span_attributes_enabled())
+            .unwrap_or_else(|err| {
+                tracing::debug!(err = %err, "failed to get the global telemetry provider; recording the span attributes anyway");
+                true
+            });
+        Self { enabled }
+    }
+
+    fn attempt_span(&self, cfg: &ConfigBag) -> Option<tracing::Span> {
+        if !self.enabled {
+            return None;
+        }
+        cfg.load::<AttemptSpan>().map(|span| span.0.clone())
+    }
+}
+
+impl Default for TelemetryInterceptor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Intercept for TelemetryInterceptor {
+    fn name(&self) -> &'static str {
+        "TelemetryInterceptor"
+    }
+
+    fn read_before_attempt(
+        &self,
+        _context: &BeforeTransmitInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let Some(span) = self.attempt_span(cfg) else {
+            return Ok(());
+        };
+        if let Some(attempts) = cfg.load::<RequestAttempts>() {
+            if attempts.attempts() > 1 {
+                span.record(
+                    semconv::HTTP_REQUEST_RESEND_COUNT,
+                    attempts.attempts() as u64 - 1,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn read_before_transmit(
+        &self,
+        context: &BeforeTransmitInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let Some(span) = self.attempt_span(cfg) else {
+            return Ok(());
+        };
+        let uri = context.request().uri().parse::<http_02x::Uri>().ok();
+        if let Some(host) = uri.as_ref().and_then(|uri| uri.host()) {
+            span.record(semconv::SERVER_ADDRESS, display(host));
+        }
+        Ok(())
+    }
+
+    fn read_after_transmit(
+        &self,
+        context: &BeforeDeserializationInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if let Some(span) = self.attempt_span(cfg) {
+            span.record(
+                semconv::HTTP_STATUS_CODE,
+                context.response().status().as_u16(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The attributes and start time of the metrics of the current operation invocation.
+#[derive(Clone)]
+struct CallMetrics {
+    attributes: Attributes,
+    start: Option<SystemTime>,
+}
+
+impl fmt::Debug for CallMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallMetrics")
+            .field("attributes", self.attributes.attributes())
+            .field("start", &self.start)
+            .finish()
+    }
+}
+
+impl Storable for CallMetrics {
+    type Storer = StoreReplace<Self>;
+}
+
+/// Records metrics of every operation invocation with the meter provider of a
+/// [`TelemetryProvider`](aws_smithy_observability::TelemetryProvider):
+///
+/// - `smithy.client.call.duration`: a histogram of the duration of invocations, in seconds,
+///   including their retries.
+/// - `smithy.client.call.attempts`: a counter of the attempts to send requests.
+/// - `smithy.client.call.errors`: a counter of the failed invocations, with the type of error as
+///   the `error.type` attribute. The type of error is the HTTP status code of the response when it
+///   is an error status, and the kind of error, such as `timeout` or `io`, otherwise.
+/// - `smithy.client.call.request_payload_size` and `smithy.client.call.response_payload_size`:
+///   histograms of the sizes of the request and response bodies, in bytes, when they are known
+///   in advance.
+///
+/// Every metric has the service and operation names as the `rpc.service` and `rpc.method` attributes.
+#[derive(Debug)]
+pub struct MetricsInterceptor {
+    call_duration: Arc<dyn Histogram>,
+    attempts: Arc<dyn MonotonicCounter>,
+    errors: Arc<dyn MonotonicCounter>,
+    request_payload_size: Arc<dyn Histogram>,
+    response_payload_size: Arc<dyn Histogram>,
+}
+
+impl MetricsInterceptor {
+    /// Creates a metrics interceptor with the meter provider of the global [`TelemetryProvider`].
+    pub fn new() -> Self {
+        let provider = get_telemetry_provider().unwrap_or_else(|err| {
+            tracing::debug!(err = %err, "failed to get the global telemetry provider; client metrics are disabled");
+            Arc::new(TelemetryProvider::noop())
+        });
+        Self::from_provider(&provider)
+    }
+
+    /// Creates a metrics interceptor with the meter provider of `provider`.
+    pub fn from_provider(provider: &TelemetryProvider) -> Self {
+        let meter = provider.meter_provider().get_meter(SCOPE, None);
+        Self {
+            call_duration: meter
+                .create_histogram("smithy.client.call.duration")
+                .set_description("The duration of operation invocations, including retries")
+                .set_units("s")
+                .build(),
+            attempts: meter
+                .create_monotonic_counter("smithy.client.call.attempts")
+                .set_description("The number of attempts to send the requests of operations")
+                .set_units("{attempt}")
+                .build(),
+            errors: meter
+                .create_monotonic_counter("smithy.client.call.errors")
+                .set_description("The number of operation invocations that failed")
+                .set_units("{error}")
+                .build(),
+            request_payload_size: meter
+                .create_histogram("smithy.client.call.request_payload_size")
+                .set_description("The size of request bodies")
+                .set_units("By")
+                .build(),
+            response_payload_size: meter
+                .create_histogram("smithy.client.call.response_payload_size")
+                .set_description("The size of response bodies")
+                .set_units("By")
+                .build(),
+        }
+    }
+}
+
+impl Default for MetricsInterceptor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn call_attributes(cfg: &ConfigBag) -> Option<&Attributes> {
+    cfg.load::<CallMetrics>().map(|metrics| &metrics.attributes)
+}
+
+fn error_type(error: &OrchestratorError<Error>, response: Option<&HttpResponse>) -> String {
+    if let Some(status) = response.map(|response| response.status()) {
+        if status.is_client_error() || status.is_server_error() {
+            return status.as_u16().to_string();
+        }
+    }
+    let error_type = if error.is_timeout_error() {
+        "timeout"
+    } else if let Some(error) = error.as_connector_error() {
+        if error.is_timeout() {
+            "timeout"
+        } else if error.is_io() {
+            "io"
+        } else {
+            "connector"
+        }
+    } else if error.is_operation_error() {
+        "operation"
+    } else if error.is_response_error() {
+        "response"
+    } else if error.is_interceptor_error() {
+        "interceptor"
+    } else {
+        "other"
+    };
+    error_type.to_owned()
+}
+
+impl Intercept for MetricsInterceptor {
+    fn name(&self) -> &'static str {
+        "MetricsInterceptor"
+    }
+
+    fn read_before_serialization(
+        &self,
+        _context: &BeforeSerializationInterceptorContextRef<'_>,
+        runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let mut attributes = Attributes::new();
+        if let Some(metadata) = cfg.load::<Metadata>() {
+            attributes.set(
+                semconv::RPC_SERVICE,
+                AttributeValue::String(metadata.service().to_owned()),
+            );
+            attributes.set(
+                semconv::RPC_METHOD,
+                AttributeValue::String(metadata.name().to_owned()),
+            );
+        }
+        let start = runtime_components
+            .time_source()
+            .map(|time_source| time_source.now());
+        cfg.interceptor_state()
+            .store_put(CallMetrics { attributes, start });
+        Ok(())
+    }
+
+    fn read_before_attempt(
+        &self,
+        _context: &BeforeTransmitInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        self.attempts.add(1, call_attributes(cfg), None);
+        Ok(())
+    }
+
+    fn read_before_transmit(
+        &self,
+        context: &BeforeTransmitInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if let Some(size) = context.request().body().content_length() {
+            self.request_payload_size
+                .record(size as f64, call_attributes(cfg), None);
+        }
+        Ok(())
+    }
+
+    fn read_after_transmit(
+        &self,
+        context: &BeforeDeserializationInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let response = context.response();
+        let size = response.body().content_length().or_else(|| {
+            response
+                .headers()
+                .get("content-length")
+                .and_then(|length| length.parse().ok())
+        });
+        if let Some(size) = size {
+            self.response_payload_size
+                .record(size as f64, call_attributes(cfg), None);
+        }
+        Ok(())
+    }
+
+    fn read_after_execution(
+        &self,
+        context: &FinalizerInterceptorContextRef<'_>,
+        runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let Some(metrics) = cfg.load::<CallMetrics>() else {
+            return Ok(());
+        };
+        let now = runtime_components
+            .time_source()
+            .map(|time_source| time_source.now());
+        if let (Some(start), Some(now)) = (metrics.start, now) {
+            let duration = now.duration_since(start).unwrap_or_default();
+            self.call_duration
+                .record(duration.as_secs_f64(), Some(&metrics.attributes), None);
+        }
+        if let Some(Err(error)) = context.output_or_error() {
+            let mut attributes = metrics.attributes.clone();
+            attributes.set(
+                semconv::ERROR_TYPE,
+                AttributeValue::String(error_type(error, context.response())),
+            );
+            self.errors.add(1, Some(&attributes), None);
+        }
+        Ok(())
+    }
+}
@@ -8,12 +8,17 @@ use aws_smithy_async::rt::sleep::{AsyncSleep, SharedAsyncSleep, Sleep};
 use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
 use aws_smithy_runtime_api::client::result::SdkError;
 use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
-use aws_smithy_types::config_bag::ConfigBag;
-use aws_smithy_types::timeout::TimeoutConfig;
+use aws_smithy_types::body::SdkBody;
+use aws_smithy_types::config_bag::{ConfigBag, Storable, StoreReplace};
+use aws_smithy_types::timeout::{AttemptTimeoutMode, Deadline, TimeoutConfig};
+use bytes::Buf;
+use http_body_1x::{Frame, SizeHint};
 use pin_project_lite::pin_project;
 use std::future::Future;
 use std::pin::Pin;
-use std::task::{Context, Poll};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
 use std::time::Duration;
 
 #[derive(Debug)]
@@ -36,6 +41,7 @@ impl std::fmt::Display for MaybeTimeoutError {
             match self.kind {
                 TimeoutKind::Operation => "operation timeout (all attempts including retries)",
                 TimeoutKind::OperationAttempt => "operation attempt timeout (single attempt)",
+                TimeoutKind::Deadline => "operation deadline",
             },
             self.duration
         )
@@ -44,6 +50,97 @@ impl std::fmt::Display for MaybeTimeoutError {
 
 impl std::error::Error for MaybeTimeoutError {}
 
+#[derive(Debug)]
+struct ActivityTimeoutError {
+    duration: Duration,
+}
+
+impl std::fmt::Display for ActivityTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "operation attempt timeout (no request or response body data transferred) occurred after {:?}",
+            self.duration
+        )
+    }
+}
+
+impl std::error::Error for ActivityTimeoutError {}
+
+/// Counts the request and response body bytes transferred during an attempt.
+///
+/// When the operation attempt timeout uses [`AttemptTimeoutMode::Activity`], the attempt timeout
+/// is restarted as long as this count keeps increasing.
+#[derive(Clone, Debug, Default)]
+pub(super) struct AttemptActivity {
+    bytes_transferred: Arc<AtomicU64>,
+}
+
+impl AttemptActivity {
+    fn record(&self, bytes: u64) {
+        self.bytes_transferred.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    fn bytes_transferred(&self) -> u64 {
+        self.bytes_transferred.load(Ordering::Relaxed)
+    }
+
+    /// Wraps the given body so that the data read from it counts as activity.
+    pub(super) fn track(&self, body: &mut SdkBody) {
+        let activity = self.clone();
+        let tracked =
+            std::mem::replace(body, SdkBody::taken()).map_preserve_contents(move |body| {
+                SdkBody::from_body_1_x(ActivityTrackingBody {
+                    body,
+                    activity: activity.clone(),
+                })
+            });
+        *body = tracked;
+    }
+}
+
+impl Storable for AttemptActivity {
+    type Storer = StoreReplace<Self>;
+}
+
+pin_project! {
+    /// A body wrapper recording the data read from the inner body as [`AttemptActivity`].
+    struct ActivityTrackingBody<InnerBody> {
+        #[pin]
+        body: InnerBody,
+        activity: AttemptActivity,
+    }
+}
+
+impl<InnerBody> http_body_1x::Body for ActivityTrackingBody<InnerBody>
+where
+    InnerBody: http_body_1x::Body,
+    InnerBody::Data: Buf,
+{
+    type Data = InnerBody::Data;
+    type Error = InnerBody::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let frame = ready!(this.body.poll_frame(cx));
+        if let Some(data) = frame.as_ref().and_then(|f| f.as_ref().ok()?.data_ref()) {
+            this.activity.record(data.remaining() as u64);
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.body.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.body.size_hint()
+    }
+}
+
 pin_project! {
     #[non_exhaustive]
     #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -63,6 +160,17 @@ pin_project! {
             timeout_kind: TimeoutKind,
             duration: Duration,
         },
+        /// A wrapper around an inner future that will output an [`SdkError`] if no body data is
+        /// transferred for the given duration
+        ActivityTimeout {
+            #[pin]
+            future: F,
+            sleep: Sleep,
+            sleep_impl: SharedAsyncSleep,
+            activity: AttemptActivity,
+            last_bytes_transferred: u64,
+            duration: Duration,
+        },
         /// A thin wrapper around an inner future that will never time out
         NoTimeout {
             #[pin]
@@ -85,6 +193,31 @@ where
                 timeout_kind,
                 duration,
             } => (future, timeout_kind, duration),
+            MaybeTimeoutFutureProj::ActivityTimeout {
+                future,
+                sleep,
+                sleep_impl,
+                activity,
+                last_bytes_transferred,
+                duration,
+            } => {
+                if let Poll::Ready(output) = future.poll(cx) {
+                    return Poll::Ready(output);
+                }
+                // Each time the timer expires, restart it if body data was transferred since it
+                // was last started, and fail the attempt otherwise.
+                while Pin::new(&mut *sleep).poll(cx).is_ready() {
+                    let bytes_transferred = activity.bytes_transferred();
+                    if bytes_transferred == *last_bytes_transferred {
+                        return Poll::Ready(Err(SdkError::timeout_error(ActivityTimeoutError {
+                            duration: *duration,
+                        })));
+                    }
+                    *last_bytes_transferred = bytes_transferred;
+                    *sleep = sleep_impl.sleep(*duration);
+                }
+                return Poll::Pending;
+            }
         };
         match future.poll(cx) {
             Poll::Ready(Ok(response)) => Poll::Ready(response),
@@ -100,6 +233,8 @@ where
 pub(super) enum TimeoutKind {
     Operation,
     OperationAttempt,
+    /// The time remaining until the [`Deadline`] is shorter than the configured timeout
+    Deadline,
 }
 
 #[derive(Clone, Debug)]
@@ -107,6 +242,7 @@ pub(super) struct MaybeTimeoutConfig {
     sleep_impl: Option<SharedAsyncSleep>,
     timeout: Option<Duration>,
     timeout_kind: TimeoutKind,
+    activity: Option<AttemptActivity>,
 }
 
 impl MaybeTimeoutConfig {
@@ -115,7 +251,7 @@ impl MaybeTimeoutConfig {
         cfg: &ConfigBag,
         timeout_kind: TimeoutKind,
     ) -> MaybeTimeoutConfig {
-        if let Some(timeout_config) = cfg.load::<TimeoutConfig>() {
+        let mut config = if let Some(timeout_config) = cfg.load::<TimeoutConfig>() {
             let sleep_impl = runtime_components.sleep_impl();
             let timeout = match (sleep_impl.as_ref(), timeout_kind) {
                 (None, _) => None,
@@ -123,19 +259,55 @@ impl MaybeTimeoutConfig {
                 (Some(_), TimeoutKind::OperationAttempt) => {
                     timeout_config.operation_attempt_timeout()
                 }
+                (Some(_), TimeoutKind::Deadline) => None,
+            };
+            let activity = match (timeout, timeout_kind) {
+                (Some(_), TimeoutKind::OperationAttempt)
+                    if timeout_config.operation_attempt_timeout_mode()
+                        == AttemptTimeoutMode::Activity =>
+                {
+                    Some(AttemptActivity::default())
+                }
+                _ => None,
             };
             MaybeTimeoutConfig {
                 sleep_impl,
                 timeout,
                 timeout_kind,
+                activity,
             }
         } else {
             MaybeTimeoutConfig {
                 sleep_impl: None,
                 timeout: None,
                 timeout_kind,
+                activity: None,
             }
+        };
+        if let Some(deadline) = cfg.load::<Deadline>() {
+            config.cap_to_deadline(runtime_components, deadline);
         }
+        config
+    }
+
+    /// Caps the timeout to the time remaining until `deadline`
+    fn cap_to_deadline(&mut self, runtime_components: &RuntimeComponents, deadline: &Deadline) {
+        let Some(sleep_impl) = runtime_components.sleep_impl() else {
+            return;
+        };
+        let now = runtime_components.time_source().unwrap_or_default().now();
+        let remaining = deadline.remaining(now);
+        if self.timeout.map_or(true, |timeout| remaining < timeout) {
+            self.sleep_impl = Some(sleep_impl);
+            self.timeout = Some(remaining);
+            self.timeout_kind = TimeoutKind::Deadline;
+            // The deadline is absolute, so it isn't restarted by body activity
+            self.activity = None;
+        }
+    }
+
+    pub(super) fn attempt_activity(&self) -> Option<&AttemptActivity> {
+        self.activity.as_ref()
     }
 }
 
@@ -151,10 +323,24 @@ where
 {
     fn maybe_timeout(self, timeout_config: MaybeTimeoutConfig) -> MaybeTimeoutFuture<Self> {
         match timeout_config {
+            MaybeTimeoutConfig {
+                sleep_impl: Some(sleep_impl),
+                timeout: Some(timeout),
+                activity: Some(activity),
+                ..
+            } => MaybeTimeoutFuture::ActivityTimeout {
+                future: self,
+                sleep: sleep_impl.sleep(timeout),
+                sleep_impl,
+                last_bytes_transferred: activity.bytes_transferred(),
+                activity,
+                duration: timeout,
+            },
             MaybeTimeoutConfig {
                 sleep_impl: Some(sleep_impl),
                 timeout: Some(timeout),
                 timeout_kind,
+                activity: None,
             } => MaybeTimeoutFuture::Timeout {
                 future: Timeout::new(self, sleep_impl.sleep(timeout)),
                 timeout_kind,
@@ -171,12 +357,15 @@ mod tests {
     use aws_smithy_async::assert_elapsed;
     use aws_smithy_async::future::never::Never;
     use aws_smithy_async::rt::sleep::{AsyncSleep, SharedAsyncSleep, TokioSleep};
+    use aws_smithy_async::time::StaticTimeSource;
     use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
     use aws_smithy_runtime_api::client::result::SdkError;
     use aws_smithy_runtime_api::client::runtime_components::RuntimeComponentsBuilder;
+    use aws_smithy_types::body::SdkBody;
+    use aws_smithy_types::byte_stream::ByteStream;
     use aws_smithy_types::config_bag::{CloneableLayer, ConfigBag};
-    use aws_smithy_types::timeout::TimeoutConfig;
-    use std::time::Duration;
+    use aws_smithy_types::timeout::{AttemptTimeoutMode, Deadline, TimeoutConfig};
+    use std::time::{Duration, UNIX_EPOCH};
 
     #[tokio::test]
     async fn test_no_timeout() {
@@ -241,4 +430,171 @@ mod tests {
         assert_eq!(format!("{:?}", err), "TimeoutError(TimeoutError { source: MaybeTimeoutError { kind: Operation, duration: 250ms } })");
         assert_elapsed!(now, Duration::from_secs_f32(0.25));
     }
+
+    fn deadline_timeout_config(
+        timeout_config: Option<TimeoutConfig>,
+        deadline: Duration,
+        timeout_kind: TimeoutKind,
+    ) -> MaybeTimeoutConfig {
+        let runtime_components = RuntimeComponentsBuilder::for_tests()
+            .with_sleep_impl(Some(SharedAsyncSleep::new(TokioSleep::new())))
+            .with_time_source(Some(StaticTimeSource::new(
+                UNIX_EPOCH + Duration::from_secs(10),
+            )))
+            .build()
+            .unwrap();
+        let mut layer = CloneableLayer::new("timeout");
+        if let Some(timeout_config) = timeout_config {
+            layer.store_put(timeout_config);
+        }
+        layer.store_put(Deadline::new(UNIX_EPOCH + deadline));
+        let cfg = ConfigBag::of_layers(vec![layer.into()]);
+        MaybeTimeoutConfig::new(&runtime_components, &cfg, timeout_kind)
+    }
+
+    #[tokio::test]
+    async fn test_deadline_caps_timeouts() {
+        let now = tokio::time::Instant::now();
+        tokio::time::pause();
+
+        let maybe_timeout = deadline_timeout_config(
+            Some(
+                TimeoutConfig::builder()
+                    .operation_attempt_timeout(Duration::from_secs(1))
+                    .operation_attempt_timeout_mode(AttemptTimeoutMode::Activity)
+                    .build(),
+            ),
+            Duration::from_millis(10_250),
+            TimeoutKind::OperationAttempt,
+        );
+        assert!(maybe_timeout.attempt_activity().is_none());
+        let err = async {
+            Never::new().await;
+            Result::<_, SdkError<(), HttpResponse>>::Ok(())
+        }
+        .maybe_timeout(maybe_timeout)
+        .await
+        .expect_err("should have timed out");
+
+        assert_eq!(format!("{:?}", err), "TimeoutError(TimeoutError { source: MaybeTimeoutError { kind: Deadline, duration: 250ms } })");
+        assert_elapsed!(now, Duration::from_secs_f32(0.25));
+    }
+
+    #[tokio::test]
+    async fn test_deadline_without_timeout_config() {
+        tokio::time::pause();
+        let maybe_timeout =
+            deadline_timeout_config(None, Duration::from_secs(11), TimeoutKind::Operation);
+        let err = async {
+            Never::new().await;
+            Result::<_, SdkError<(), HttpResponse>>::Ok(())
+        }
+        .maybe_timeout(maybe_timeout)
+        .await
+        .expect_err("should have timed out");
+        assert_eq!(format!("{:?}", err), "TimeoutError(TimeoutError { source: MaybeTimeoutError { kind: Deadline, duration: 1s } })");
+    }
+
+    #[tokio::test]
+    async fn test_shorter_timeouts_are_kept_and_passed_deadlines_fail() {
+        let maybe_timeout = deadline_timeout_config(
+            Some(
+                TimeoutConfig::builder()
+                    .operation_timeout(Duration::from_millis(100))
+                    .build(),
+            ),
+            Duration::from_secs(11),
+            TimeoutKind::Operation,
+        );
+        assert_eq!(Some(Duration::from_millis(100)), maybe_timeout.timeout);
+        assert_eq!(TimeoutKind::Operation, maybe_timeout.timeout_kind);
+
+        let maybe_timeout =
+            deadline_timeout_config(None, Duration::from_secs(9), TimeoutKind::Operation);
+        assert_eq!(Some(Duration::ZERO), maybe_timeout.timeout);
+        assert_eq!(TimeoutKind::Deadline, maybe_timeout.timeout_kind);
+    }
+
+    fn activity_timeout_config(sleep_impl: SharedAsyncSleep) -> MaybeTimeoutConfig {
+        let runtime_components = RuntimeComponentsBuilder::for_tests()
+            .with_sleep_impl(Some(sleep_impl))
+            .build()
+            .unwrap();
+        let mut timeout_config = CloneableLayer::new("timeout");
+        timeout_config.store_put(
+            TimeoutConfig::builder()
+                .operation_attempt_timeout(Duration::from_millis(100))
+                .operation_attempt_timeout_mode(AttemptTimeoutMode::Activity)
+                .build(),
+        );
+        let cfg = ConfigBag::of_layers(vec![timeout_config.into()]);
+        MaybeTimeoutConfig::new(&runtime_components, &cfg, TimeoutKind::OperationAttempt)
+    }
+
+    #[tokio::test]
+    async fn test_activity_timeout_is_restarted_while_data_flows() {
+        let sleep_impl = SharedAsyncSleep::new(TokioSleep::new());
+        let now = tokio::time::Instant::now();
+        tokio::time::pause();
+
+        let maybe_timeout = activity_timeout_config(sleep_impl.clone());
+        let activity = maybe_timeout.attempt_activity().cloned().unwrap();
+        let underlying_future = async {
+            for _ in 0..5 {
+                sleep_impl.sleep(Duration::from_millis(80)).await;
+                activity.record(1);
+            }
+            Result::<_, SdkError<(), HttpResponse>>::Ok(())
+        };
+        underlying_future
+            .maybe_timeout(maybe_timeout)
+            .await
+            .expect("progressing attempts should not time out");
+
+        // The attempt outlived its timeout several times over
+        assert!(now.elapsed() >= Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn test_activity_timeout_once_data_stops_flowing() {
+        let sleep_impl = SharedAsyncSleep::new(TokioSleep::new());
+        let now = tokio::time::Instant::now();
+        tokio::time::pause();
+
+        let maybe_timeout = activity_timeout_config(sleep_impl.clone());
+        let activity = maybe_timeout.attempt_activity().cloned().unwrap();
+        let underlying_future = async {
+            for _ in 0..3 {
+                sleep_impl.sleep(Duration::from_millis(80)).await;
+                activity.record(1);
+            }
+            Never::new().await;
+            Result::<_, SdkError<(), HttpResponse>>::Ok(())
+        };
+        let err = underlying_future
+            .maybe_timeout(maybe_timeout)
+            .await
+            .expect_err("should have timed out");
+
+        assert_eq!(
+            format!("{:?}", err),
+            "TimeoutError(TimeoutError { source: ActivityTimeoutError { duration: 100ms } })"
+        );
+        assert_elapsed!(now, Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn test_tracked_body_records_activity() {
+        let activity = AttemptActivity::default();
+        let mut body = SdkBody::from("hello world");
+        activity.track(&mut body);
+        let retry = body.try_clone().expect("tracking preserves retryability");
+
+        let data = ByteStream::new(body).collect().await.unwrap();
+        assert_eq!(b"hello world", &data.into_bytes()[..]);
+        assert_eq!(11, activity.bytes_transferred());
+
+        ByteStream::new(retry).collect().await.unwrap();
+        assert_eq!(22, activity.bytes_transferred());
+    }
 }
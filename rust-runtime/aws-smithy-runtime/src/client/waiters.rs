@@ -18,6 +18,7 @@ use std::future::Future;
 use std::time::Duration;
 
 mod backoff;
+pub mod poller;
 
 /// Waiter acceptor state
 ///
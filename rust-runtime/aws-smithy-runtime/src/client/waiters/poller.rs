@@ -0,0 +1,512 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! A generic poller for operations that don't have modeled waiters.
+//!
+//! [`Poller`] repeatedly calls an operation, with the same jittered exponential backoff as the
+//! generated waiters, until an acceptor function considers the result final, the maximum wait time
+//! is exceeded, or the poller is cancelled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use aws_smithy_runtime::client::waiters::poller::Poller;
+//! use aws_smithy_runtime::client::waiters::AcceptorState;
+//! use std::time::Duration;
+//!
+//! # #[derive(Debug)] struct Output { status: &'static str }
+//! # #[derive(Debug)] struct Error;
+//! # async fn describe_job() -> Result<Output, Error> { Ok(Output { status: "COMPLETE" }) }
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let poller = Poller::builder()
+//!     .operation(describe_job)
+//!     .acceptor(|result: Result<&Output, &Error>| match result {
+//!         Ok(output) if output.status == "COMPLETE" => AcceptorState::Success,
+//!         Ok(output) if output.status == "FAILED" => AcceptorState::Failure,
+//!         Ok(_) => AcceptorState::Retry,
+//!         Err(_) => AcceptorState::NoAcceptorsMatched,
+//!     })
+//!     .max_wait(Duration::from_secs(600))
+//!     .build()?;
+//! match poller.poll().await {
+//!     Ok(final_poll) => println!("job finished: {:?}", final_poll.as_result()),
+//!     Err(err) => println!("job didn't finish: {err}"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::waiters::backoff::{Backoff, RandomImpl};
+use crate::client::waiters::AcceptorState;
+use aws_smithy_async::future::timeout::Timeout;
+use aws_smithy_async::rt::sleep::{default_async_sleep, AsyncSleep, SharedAsyncSleep};
+use aws_smithy_async::time::SharedTimeSource;
+use aws_smithy_runtime_api::client::waiters::error::{ExceededMaxWait, FailureState};
+use aws_smithy_runtime_api::client::waiters::FinalPoll;
+use aws_smithy_types::error::operation::BuildError;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+use tracing::Instrument;
+
+const DEFAULT_MIN_DELAY: Duration = Duration::from_secs(2);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(120);
+
+type Cancellation = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// An error that ended polling.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PollerError<O, E> {
+    /// The maximum wait time was exceeded without the acceptor considering a result final.
+    ExceededMaxWait(ExceededMaxWait),
+
+    /// The acceptor returned [`AcceptorState::Failure`] for a result.
+    FailureState(FailureState<O, E>),
+
+    /// The operation returned an error that the acceptor didn't match.
+    OperationFailed(E),
+
+    /// The cancellation future completed before polling ended.
+    Cancelled,
+}
+
+impl<O, E> fmt::Display for PollerError<O, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExceededMaxWait(ctx) => {
+                write!(f, "exceeded max wait time ({:?})", ctx.max_wait())
+            }
+            Self::FailureState(_) => f.write_str("polling failed"),
+            Self::OperationFailed(_) => f.write_str("operation failed while polling"),
+            Self::Cancelled => f.write_str("polling was cancelled"),
+        }
+    }
+}
+
+impl<O, E> std::error::Error for PollerError<O, E>
+where
+    O: fmt::Debug,
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::FailureState(inner) => match inner.final_poll().as_result() {
+                Ok(_) => None,
+                Err(err) => Some(err),
+            },
+            Self::OperationFailed(err) => Some(err),
+            Self::ExceededMaxWait(_) | Self::Cancelled => None,
+        }
+    }
+}
+
+/// Polls an operation until an acceptor function considers its result final.
+///
+/// Unlike [`WaiterOrchestrator`](crate::client::waiters::WaiterOrchestrator), the operation may
+/// return any result type, so the poller can be used with any generated operation, or any other
+/// future.
+pub struct Poller<AcceptorFn, OperationFn> {
+    backoff: Backoff,
+    time_source: SharedTimeSource,
+    sleep_impl: SharedAsyncSleep,
+    cancellation: Option<Cancellation>,
+    acceptor_fn: AcceptorFn,
+    operation_fn: OperationFn,
+}
+
+impl<AcceptorFn, OperationFn> fmt::Debug for Poller<AcceptorFn, OperationFn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Poller")
+            .field("backoff", &self.backoff)
+            .field("cancellable", &self.cancellation.is_some())
+            .finish()
+    }
+}
+
+impl Poller<(), ()> {
+    /// Returns a builder for the poller.
+    pub fn builder() -> PollerBuilder<(), ()> {
+        PollerBuilder::default()
+    }
+}
+
+impl<AcceptorFn, OperationFn, O, E, Fut> Poller<AcceptorFn, OperationFn>
+where
+    AcceptorFn: Fn(Result<&O, &E>) -> AcceptorState,
+    OperationFn: Fn() -> Fut,
+    Fut: Future<Output = Result<O, E>>,
+{
+    /// Polls the operation until the acceptor returns [`AcceptorState::Success`], returning the
+    /// result of the final poll.
+    pub async fn poll(mut self) -> Result<FinalPoll<O, E>, PollerError<O, E>> {
+        // Create a random seven-digit ID for the poller so that it can be correlated in the logs.
+        let span = tracing::debug_span!("poller", poller_id = fastrand::u32(1_000_000..10_000_000));
+        match self.cancellation.take() {
+            Some(cancellation) => Timeout::new(self.poll_until_done(), cancellation)
+                .instrument(span)
+                .await
+                .unwrap_or_else(|_| {
+                    tracing::debug!("poller was cancelled");
+                    Err(PollerError::Cancelled)
+                }),
+            None => self.poll_until_done().instrument(span).await,
+        }
+    }
+
+    async fn poll_until_done(self) -> Result<FinalPoll<O, E>, PollerError<O, E>> {
+        let start_time = self.time_source.now();
+        let mut attempt = 0;
+        let mut done_retrying = false;
+        loop {
+            tracing::debug!("executing poll attempt #{}", attempt + 1);
+            let result = (self.operation_fn)().await;
+            let acceptor_state = (self.acceptor_fn)(result.as_ref());
+            tracing::debug!("poller acceptor state: {acceptor_state:?}");
+            match acceptor_state {
+                AcceptorState::Success => return Ok(FinalPoll::new(result)),
+                AcceptorState::Failure => {
+                    return Err(PollerError::FailureState(FailureState::new(
+                        FinalPoll::new(result),
+                    )))
+                }
+                AcceptorState::NoAcceptorsMatched if result.is_err() => {
+                    return Err(PollerError::OperationFailed(
+                        result.err().expect("checked above"),
+                    ))
+                }
+                AcceptorState::Retry | AcceptorState::NoAcceptorsMatched => {
+                    attempt += 1;
+
+                    let elapsed = self
+                        .time_source
+                        .now()
+                        .duration_since(start_time)
+                        .unwrap_or_default();
+                    if done_retrying || elapsed > self.backoff.max_wait() {
+                        tracing::debug!(
+                            "poller exceeded max wait time of {:?}",
+                            self.backoff.max_wait()
+                        );
+                        return Err(PollerError::ExceededMaxWait(ExceededMaxWait::new(
+                            self.backoff.max_wait(),
+                            elapsed,
+                            attempt,
+                        )));
+                    }
+                    let delay = self.backoff.delay(attempt, elapsed);
+                    // A zero delay means the poller is close to the max wait time, so poll one
+                    // last time right away instead of sleeping (see `WaiterOrchestrator`).
+                    if delay.is_zero() {
+                        done_retrying = true;
+                    } else {
+                        tracing::debug!("poller will poll again in {delay:?}");
+                        self.sleep_impl.sleep(delay).await;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Builder for [`Poller`].
+#[derive(Default)]
+pub struct PollerBuilder<AcceptorFn = (), OperationFn = ()> {
+    min_delay: Option<Duration>,
+    max_delay: Option<Duration>,
+    max_wait: Option<Duration>,
+    time_source: Option<SharedTimeSource>,
+    sleep_impl: Option<SharedAsyncSleep>,
+    cancellation: Option<Cancellation>,
+    random_fn: RandomImpl,
+    acceptor_fn: Option<AcceptorFn>,
+    operation_fn: Option<OperationFn>,
+}
+
+impl<AcceptorFn, OperationFn> fmt::Debug for PollerBuilder<AcceptorFn, OperationFn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PollerBuilder")
+            .field("min_delay", &self.min_delay)
+            .field("max_delay", &self.max_delay)
+            .field("max_wait", &self.max_wait)
+            .field("cancellable", &self.cancellation.is_some())
+            .finish()
+    }
+}
+
+impl<AcceptorFn, OperationFn> PollerBuilder<AcceptorFn, OperationFn> {
+    /// Set the minimum delay between polls. Defaults to two seconds.
+    ///
+    /// Delays are computed in whole seconds, so the minimum delay must be at least one second.
+    pub fn min_delay(mut self, min_delay: Duration) -> Self {
+        self.min_delay = Some(min_delay);
+        self
+    }
+
+    /// Set the maximum delay between polls. Defaults to 120 seconds.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = Some(max_delay);
+        self
+    }
+
+    /// Set the maximum total time to poll for. This is required.
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// Set the time source the poller will use. Defaults to the system time.
+    pub fn time_source(mut self, time_source: SharedTimeSource) -> Self {
+        self.time_source = Some(time_source);
+        self
+    }
+
+    /// Set the async sleep implementation the poller will use to delay.
+    ///
+    /// Defaults to [`default_async_sleep`], which requires the `rt-tokio` feature.
+    pub fn sleep_impl(mut self, sleep_impl: SharedAsyncSleep) -> Self {
+        self.sleep_impl = Some(sleep_impl);
+        self
+    }
+
+    /// Stop polling when `cancellation` completes, which results in [`PollerError::Cancelled`].
+    ///
+    /// An in-flight poll is dropped when the poller is cancelled.
+    pub fn cancel_on(mut self, cancellation: impl Future<Output = ()> + Send + 'static) -> Self {
+        self.cancellation = Some(Box::pin(cancellation));
+        self
+    }
+
+    #[cfg(all(test, feature = "test-util"))]
+    fn random(mut self, random_fn: impl Fn(u64, u64) -> u64 + Send + Sync + 'static) -> Self {
+        self.random_fn = RandomImpl::Override(Box::new(random_fn));
+        self
+    }
+
+    /// Build a poller.
+    ///
+    /// Fails if the acceptor, operation, or maximum wait time are missing, if the delays are
+    /// invalid, or if no sleep implementation is available.
+    pub fn build(self) -> Result<Poller<AcceptorFn, OperationFn>, BuildError> {
+        let min_delay = self.min_delay.unwrap_or(DEFAULT_MIN_DELAY);
+        let max_delay = self.max_delay.unwrap_or(DEFAULT_MAX_DELAY);
+        if min_delay < Duration::from_secs(1) {
+            return Err(BuildError::invalid_field(
+                "min_delay",
+                "the minimum delay must be at least one second",
+            ));
+        }
+        if max_delay < min_delay {
+            return Err(BuildError::invalid_field(
+                "max_delay",
+                "the maximum delay must not be less than the minimum delay",
+            ));
+        }
+        let max_wait = self.max_wait.ok_or_else(|| {
+            BuildError::missing_field("max_wait", "the maximum wait time is required")
+        })?;
+        let sleep_impl = self
+            .sleep_impl
+            .or_else(default_async_sleep)
+            .ok_or_else(|| {
+                BuildError::missing_field(
+                    "sleep_impl",
+                    "a sleep implementation is required when the `rt-tokio` feature is disabled",
+                )
+            })?;
+        Ok(Poller {
+            backoff: Backoff::new(min_delay, max_delay, max_wait, self.random_fn),
+            time_source: self.time_source.unwrap_or_default(),
+            sleep_impl,
+            cancellation: self.cancellation,
+            acceptor_fn: self.acceptor_fn.ok_or_else(|| {
+                BuildError::missing_field("acceptor", "an acceptor function is required")
+            })?,
+            operation_fn: self.operation_fn.ok_or_else(|| {
+                BuildError::missing_field("operation", "an operation function is required")
+            })?,
+        })
+    }
+}
+
+impl<OperationFn> PollerBuilder<(), OperationFn> {
+    /// Set the acceptor function that decides whether a result is final.
+    pub fn acceptor<AcceptorFn>(
+        self,
+        acceptor: AcceptorFn,
+    ) -> PollerBuilder<AcceptorFn, OperationFn> {
+        PollerBuilder {
+            min_delay: self.min_delay,
+            max_delay: self.max_delay,
+            max_wait: self.max_wait,
+            time_source: self.time_source,
+            sleep_impl: self.sleep_impl,
+            cancellation: self.cancellation,
+            random_fn: self.random_fn,
+            acceptor_fn: Some(acceptor),
+            operation_fn: self.operation_fn,
+        }
+    }
+}
+
+impl<AcceptorFn> PollerBuilder<AcceptorFn, ()> {
+    /// Set the function that starts a poll of the operation.
+    pub fn operation<OperationFn>(
+        self,
+        operation: OperationFn,
+    ) -> PollerBuilder<AcceptorFn, OperationFn> {
+        PollerBuilder {
+            min_delay: self.min_delay,
+            max_delay: self.max_delay,
+            max_wait: self.max_wait,
+            time_source: self.time_source,
+            sleep_impl: self.sleep_impl,
+            cancellation: self.cancellation,
+            random_fn: self.random_fn,
+            acceptor_fn: self.acceptor_fn,
+            operation_fn: Some(operation),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::*;
+    use crate::test_util::capture_test_logs::show_test_logs;
+    use aws_smithy_async::test_util::tick_advance_sleep::tick_advance_time_and_sleep;
+    use aws_smithy_runtime_api::shared::IntoShared;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn test_poller(
+        sleep_impl: impl IntoShared<SharedAsyncSleep>,
+        time_source: impl IntoShared<SharedTimeSource>,
+    ) -> PollerBuilder<(), ()> {
+        let test_random = |min: u64, max: u64| (min + max) / 2;
+        Poller::builder()
+            .max_wait(Duration::from_secs(300))
+            .random(test_random)
+            .sleep_impl(sleep_impl.into_shared())
+            .time_source(time_source.into_shared())
+    }
+
+    #[tokio::test]
+    async fn polls_until_success() {
+        let _logs = show_test_logs();
+        let (time_source, sleep_impl) = tick_advance_time_and_sleep();
+        let attempt = Arc::new(AtomicUsize::new(1));
+        let poller = test_poller(sleep_impl, time_source.clone())
+            .operation(move || {
+                let attempt = attempt.fetch_add(1, Ordering::SeqCst);
+                async move { Ok::<_, String>(attempt) }
+            })
+            .acceptor(|result: Result<&usize, &String>| match result {
+                Ok(3) => AcceptorState::Success,
+                _ => AcceptorState::Retry,
+            })
+            .build()
+            .unwrap();
+
+        let task = tokio::spawn(poller.poll());
+        tokio::task::yield_now().await;
+        time_source.tick(Duration::from_secs(500)).await;
+        let result = task.await.unwrap().unwrap();
+        assert_eq!(Ok(&3), result.as_result());
+    }
+
+    #[tokio::test]
+    async fn errors_can_be_accepted() {
+        let (time_source, sleep_impl) = tick_advance_time_and_sleep();
+        let poller = test_poller(sleep_impl, time_source)
+            .operation(|| async { Err::<usize, _>("NotFound".to_string()) })
+            .acceptor(|result: Result<&usize, &String>| match result {
+                Err(err) if err == "NotFound" => AcceptorState::Success,
+                _ => AcceptorState::Retry,
+            })
+            .build()
+            .unwrap();
+
+        let result = poller.poll().await.unwrap();
+        assert_eq!(Err(&"NotFound".to_string()), result.as_result());
+    }
+
+    #[tokio::test]
+    async fn unmatched_errors_end_polling() {
+        let (time_source, sleep_impl) = tick_advance_time_and_sleep();
+        let poller = test_poller(sleep_impl, time_source)
+            .operation(|| async { Err::<usize, _>("AccessDenied".to_string()) })
+            .acceptor(|_result: Result<&usize, &String>| AcceptorState::NoAcceptorsMatched)
+            .build()
+            .unwrap();
+
+        match poller.poll().await {
+            Err(PollerError::OperationFailed(err)) => assert_eq!("AccessDenied", err),
+            result => panic!("expected OperationFailed, got {result:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn exceed_max_wait_time() {
+        let (time_source, sleep_impl) = tick_advance_time_and_sleep();
+        let poller = test_poller(sleep_impl, time_source.clone())
+            .operation(|| async { Ok::<_, String>(1) })
+            .acceptor(|_result: Result<&usize, &String>| AcceptorState::Retry)
+            .build()
+            .unwrap();
+
+        let task = tokio::spawn(poller.poll());
+        tokio::task::yield_now().await;
+        time_source.tick(Duration::from_secs(500)).await;
+        match task.await.unwrap() {
+            Err(PollerError::ExceededMaxWait(context)) => {
+                assert_eq!(Duration::from_secs(300), context.max_wait());
+                assert_eq!(300, context.elapsed().as_secs());
+            }
+            result => panic!("expected ExceededMaxWait, got {result:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn cancellation_stops_polling() {
+        let (time_source, sleep_impl) = tick_advance_time_and_sleep();
+        let (cancel, cancelled) = tokio::sync::oneshot::channel::<()>();
+        let poller = test_poller(sleep_impl, time_source.clone())
+            .operation(|| async { Ok::<_, String>(1) })
+            .acceptor(|_result: Result<&usize, &String>| AcceptorState::Retry)
+            .cancel_on(async move {
+                let _ = cancelled.await;
+            })
+            .build()
+            .unwrap();
+
+        let task = tokio::spawn(poller.poll());
+        tokio::task::yield_now().await;
+        time_source.tick(Duration::from_secs(10)).await;
+        cancel.send(()).unwrap();
+        assert!(matches!(task.await.unwrap(), Err(PollerError::Cancelled)));
+    }
+
+    #[test]
+    fn invalid_configuration_is_rejected() {
+        let builder = || {
+            Poller::builder()
+                .operation(|| async { Ok::<_, String>(1) })
+                .acceptor(|_result: Result<&usize, &String>| AcceptorState::Success)
+        };
+        assert!(builder().build().is_err(), "max wait is required");
+        assert!(builder()
+            .max_wait(Duration::from_secs(10))
+            .min_delay(Duration::from_millis(500))
+            .build()
+            .is_err());
+        assert!(builder()
+            .max_wait(Duration::from_secs(10))
+            .min_delay(Duration::from_secs(5))
+            .max_delay(Duration::from_secs(2))
+            .build()
+            .is_err());
+    }
+}
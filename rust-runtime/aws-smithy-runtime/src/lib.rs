@@ -12,6 +12,8 @@
 //!
 //! - `http-auth`: Enables auth scheme and identity resolver implementations for HTTP API Key,
 //!   Basic Auth, Bearer Token, and Digest Auth.
+//! - `response-decompression`: Enables a runtime plugin that decompresses `gzip` and `deflate`
+//!   encoded response bodies.
 //! - `test-util`: Enables utilities for unit tests. DO NOT ENABLE IN PRODUCTION.
 
 #![warn(
@@ -4,8 +4,9 @@
  */
 
 //! Conversions from [`DateTime`] to the types in the
-//! [`time`](https://crates.io/crates/time) or
-//! [`chrono`](https://crates.io/crates/chrono)
+//! [`time`](https://crates.io/crates/time),
+//! [`chrono`](https://crates.io/crates/chrono), or
+//! [`jiff`](https://crates.io/crates/jiff)
 //! crates.
 
 use aws_smithy_types::DateTime;
@@ -53,7 +54,7 @@ impl fmt::Display for Error {
     }
 }
 
-/// Adds functions to [`DateTime`] to convert it to `time` or `chrono` types.
+/// Adds functions to [`DateTime`] to convert it to `time`, `chrono`, or `jiff` types.
 ///
 #[cfg_attr(
     feature = "convert-time",
@@ -102,6 +103,30 @@ let date_time: DateTime = DateTime::from_chrono_utc(chrono_date_time);
 ```
 "##
 )]
+#[cfg_attr(
+    feature = "convert-jiff",
+    doc = r##"
+# Example with `jiff`
+
+Make sure your **Cargo.toml** enables the `convert-jiff` feature:
+```toml
+[dependencies]
+aws-smithy-types-convert = { version = "VERSION", features = ["convert-jiff"] }
+```
+
+Then import [`DateTimeExt`] to use the conversions:
+```rust
+# fn test_fn() -> Result<(), aws_smithy_types_convert::date_time::Error> {
+# use aws_smithy_types::DateTime;
+use aws_smithy_types_convert::date_time::DateTimeExt;
+
+let timestamp: jiff::Timestamp = DateTime::from_secs(5).to_jiff_timestamp()?;
+let date_time: DateTime = DateTime::from_jiff_timestamp(timestamp);
+# Ok(())
+# }
+```
+"##
+)]
 pub trait DateTimeExt {
     /// Converts a [`DateTime`] to a [`chrono::DateTime`] with timezone UTC.
     #[cfg(feature = "convert-chrono")]
@@ -125,6 +150,30 @@ pub trait DateTimeExt {
     /// Converts a [`time::OffsetDateTime`] to a [`DateTime`].
     #[cfg(feature = "convert-time")]
     fn from_time(time: time::OffsetDateTime) -> DateTime;
+
+    /// Returns the [`time::Duration`] elapsed from `earlier` to this [`DateTime`].
+    ///
+    /// The duration is negative if `earlier` is after this date-time. Returns an [`Error`] if
+    /// the duration doesn't fit into a [`time::Duration`].
+    #[cfg(feature = "convert-time")]
+    fn time_duration_since(&self, earlier: DateTime) -> Result<time::Duration, Error>;
+
+    /// Adds a [`time::Duration`], which may be negative, to this [`DateTime`].
+    ///
+    /// Returns an [`Error`] if the result is out of range for a [`DateTime`].
+    #[cfg(feature = "convert-time")]
+    fn checked_add_time_duration(&self, duration: time::Duration) -> Result<DateTime, Error>;
+
+    /// Converts a [`DateTime`] to a [`jiff::Timestamp`].
+    ///
+    /// Returns an [`Error`] if the time is after
+    /// `9999-12-31T23:59:59.999999999Z` or before `-9999-01-01T00:00:00Z`.
+    #[cfg(feature = "convert-jiff")]
+    fn to_jiff_timestamp(&self) -> Result<jiff::Timestamp, Error>;
+
+    /// Converts a [`jiff::Timestamp`] to a [`DateTime`].
+    #[cfg(feature = "convert-jiff")]
+    fn from_jiff_timestamp(timestamp: jiff::Timestamp) -> DateTime;
 }
 
 impl DateTimeExt for DateTime {
@@ -162,15 +211,65 @@ impl DateTimeExt for DateTime {
         DateTime::from_nanos(time.unix_timestamp_nanos())
             .expect("DateTime supports a greater range than OffsetDateTime")
     }
+
+    #[cfg(feature = "convert-time")]
+    fn time_duration_since(&self, earlier: DateTime) -> Result<time::Duration, Error> {
+        const NANOS_PER_SECOND: i128 = 1_000_000_000;
+        let nanos = self.as_nanos() - earlier.as_nanos();
+        let seconds = i64::try_from(nanos / NANOS_PER_SECOND).map_err(Error::out_of_range)?;
+        // The remainder has the same sign as `nanos` and is smaller than a second
+        Ok(time::Duration::new(
+            seconds,
+            (nanos % NANOS_PER_SECOND) as i32,
+        ))
+    }
+
+    #[cfg(feature = "convert-time")]
+    fn checked_add_time_duration(&self, duration: time::Duration) -> Result<DateTime, Error> {
+        let nanos = self
+            .as_nanos()
+            .checked_add(duration.whole_nanoseconds())
+            .ok_or_else(|| Error::out_of_range("adding the duration overflowed"))?;
+        DateTime::from_nanos(nanos).map_err(Error::out_of_range)
+    }
+
+    #[cfg(feature = "convert-jiff")]
+    fn to_jiff_timestamp(&self) -> Result<jiff::Timestamp, Error> {
+        let nanos = self.as_nanos();
+        // Check the range up front since jiff can panic on values far outside of it
+        if nanos < jiff::Timestamp::MIN.as_nanosecond()
+            || nanos > jiff::Timestamp::MAX.as_nanosecond()
+        {
+            return Err(Error::out_of_range(format!(
+                "out-of-range seconds {} for a jiff::Timestamp",
+                self.secs()
+            )));
+        }
+        jiff::Timestamp::from_nanosecond(nanos).map_err(Error::out_of_range)
+    }
+
+    #[cfg(feature = "convert-jiff")]
+    fn from_jiff_timestamp(timestamp: jiff::Timestamp) -> DateTime {
+        DateTime::from_nanos(timestamp.as_nanosecond())
+            .expect("DateTime supports a greater range than jiff::Timestamp")
+    }
 }
 
-#[cfg(all(test, any(feature = "convert-chrono", feature = "convert-time")))]
+#[cfg(all(
+    test,
+    any(
+        feature = "convert-chrono",
+        feature = "convert-time",
+        feature = "convert-jiff"
+    )
+))]
 mod test {
     use super::DateTimeExt;
     use aws_smithy_types::date_time::{DateTime, Format};
+    #[cfg(feature = "convert-chrono")]
     use chrono::Timelike;
 
-    #[cfg(feature = "convert-time")]
+    #[cfg(any(feature = "convert-time", feature = "convert-jiff"))]
     use super::{Error, ErrorKind};
 
     #[test]
@@ -284,4 +383,65 @@ mod test {
             })
         ));
     }
+
+    #[test]
+    #[cfg(feature = "convert-time")]
+    fn time_durations() {
+        let earlier = DateTime::from_str("2039-07-08T09:03:11.123Z", Format::DateTime).unwrap();
+        let later = DateTime::from_str("2039-07-09T09:03:12.001Z", Format::DateTime).unwrap();
+
+        let elapsed = later.time_duration_since(earlier).unwrap();
+        assert_eq!(
+            time::Duration::days(1) + time::Duration::milliseconds(878),
+            elapsed
+        );
+        assert_eq!(-elapsed, earlier.time_duration_since(later).unwrap());
+        assert_eq!(later, earlier.checked_add_time_duration(elapsed).unwrap());
+        assert_eq!(earlier, later.checked_add_time_duration(-elapsed).unwrap());
+
+        let max = DateTime::from_secs_and_nanos(i64::MAX, 0);
+        let min = DateTime::from_secs_and_nanos(i64::MIN, 0);
+        assert!(matches!(
+            max.time_duration_since(min),
+            Err(Error {
+                kind: ErrorKind::OutOfRange(_)
+            })
+        ));
+        assert!(matches!(
+            max.checked_add_time_duration(time::Duration::SECOND),
+            Err(Error {
+                kind: ErrorKind::OutOfRange(_)
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "convert-jiff")]
+    fn jiff_timestamps() {
+        let date_time =
+            DateTime::from_str("2039-07-08T09:03:11.123456789Z", Format::DateTime).unwrap();
+        let timestamp: jiff::Timestamp = "2039-07-08T09:03:11.123456789Z".parse().unwrap();
+        assert_eq!(timestamp, date_time.to_jiff_timestamp().unwrap());
+        assert_eq!(date_time, DateTime::from_jiff_timestamp(timestamp));
+
+        let date_time = DateTime::from_str("1000-07-08T09:03:11.456Z", Format::DateTime).unwrap();
+        let timestamp: jiff::Timestamp = "1000-07-08T09:03:11.456Z".parse().unwrap();
+        assert_eq!(timestamp, date_time.to_jiff_timestamp().unwrap());
+        assert_eq!(date_time, DateTime::from_jiff_timestamp(timestamp));
+
+        for date_time in [
+            DateTime::from_secs_and_nanos(i64::MAX, 0),
+            DateTime::from_secs_and_nanos(i64::MIN, 0),
+            DateTime::from_secs(jiff::Timestamp::MAX.as_second() + 1),
+        ] {
+            assert!(matches!(
+                date_time.to_jiff_timestamp(),
+                Err(Error {
+                    kind: ErrorKind::OutOfRange(_)
+                })
+            ));
+        }
+        let max = DateTime::from_jiff_timestamp(jiff::Timestamp::MAX);
+        assert_eq!(jiff::Timestamp::MAX, max.to_jiff_timestamp().unwrap());
+    }
 }
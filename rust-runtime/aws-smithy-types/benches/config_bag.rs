@@ -0,0 +1,78 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use aws_smithy_types::config_bag::{ConfigBag, Layer, Storable, StoreAppend, StoreReplace};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// A value stored in the bottom layer of the bag, like the retry strategy configured on a client.
+#[derive(Debug)]
+struct Bottom;
+impl Storable for Bottom {
+    type Storer = StoreReplace<Self>;
+}
+
+/// A value that isn't stored in the bag at all.
+#[derive(Debug)]
+struct Missing;
+impl Storable for Missing {
+    type Storer = StoreReplace<Self>;
+}
+
+/// Values appended to a few layers of the bag, like interceptors.
+#[derive(Debug)]
+struct Appended;
+impl Storable for Appended {
+    type Storer = StoreAppend<Self>;
+}
+
+/// A value that every layer stores, so the layers aren't empty.
+#[derive(Debug)]
+struct Filler;
+impl Storable for Filler {
+    type Storer = StoreReplace<Self>;
+}
+
+/// Builds a bag with `layer_count` frozen layers, similar to the layers an orchestrator builds from runtime plugins.
+fn bag(layer_count: usize) -> ConfigBag {
+    let mut bag = ConfigBag::base();
+    for i in 0..layer_count {
+        let mut layer = Layer::new("layer");
+        layer.store_put(Filler);
+        if i == 0 {
+            layer.store_put(Bottom);
+        }
+        if i % 4 == 0 {
+            layer.store_append(Appended);
+        }
+        bag.push_layer(layer);
+    }
+    bag
+}
+
+const LAYER_COUNTS: [usize; 3] = [4, 16, 64];
+
+fn bench_loads(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ConfigBag::load");
+
+    for layer_count in LAYER_COUNTS {
+        let bag = &bag(layer_count);
+
+        group.bench_with_input(
+            BenchmarkId::new("bottom_layer", layer_count),
+            bag,
+            |b, bag| b.iter(|| black_box(bag.load::<Bottom>())),
+        );
+        group.bench_with_input(BenchmarkId::new("missing", layer_count), bag, |b, bag| {
+            b.iter(|| black_box(bag.load::<Missing>()))
+        });
+        group.bench_with_input(BenchmarkId::new("appended", layer_count), bag, |b, bag| {
+            b.iter(|| black_box(bag.load::<Appended>().count()))
+        });
+    }
+    group.finish()
+}
+
+criterion_group!(benches, bench_loads);
+criterion_main!(benches);
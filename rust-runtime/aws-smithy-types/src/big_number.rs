@@ -0,0 +1,246 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Arbitrary-precision numbers for the Smithy `bigInteger` and `bigDecimal` shapes.
+//!
+//! [`BigInteger`] and [`BigDecimal`] hold the decimal text of a number so that values outside the
+//! range or precision of [`Number`](crate::Number) are carried without loss. They don't implement
+//! arithmetic; enable the `bignum` feature to convert them to and from the [`num-bigint`] and
+//! [`bigdecimal`] types.
+//!
+//! Generated code doesn't use these types yet, and [`Document`](crate::Document) numbers are
+//! always [`Number`](crate::Number)s.
+//!
+//! [`num-bigint`]: https://docs.rs/num-bigint
+//! [`bigdecimal`]: https://docs.rs/bigdecimal
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An arbitrary-precision integer, represented by its decimal text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigInteger(String);
+
+/// An arbitrary-precision decimal number, represented by its decimal text.
+///
+/// The text may include a fractional part and an exponent, e.g. `-1.5e300`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigDecimal(String);
+
+/// The error returned when parsing a [`BigInteger`] or [`BigDecimal`] from invalid text.
+#[derive(Debug)]
+pub struct ParseBigNumberError {
+    kind: &'static str,
+    input: String,
+}
+
+impl fmt::Display for ParseBigNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a valid {}", self.input, self.kind)
+    }
+}
+
+impl Error for ParseBigNumberError {}
+
+/// Returns the number of leading ASCII digits of `input`.
+fn digits(input: &[u8]) -> usize {
+    input.iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+/// Returns the length of the optionally negative integer at the start of `input`, if any.
+fn integer(input: &[u8]) -> Option<usize> {
+    let sign = usize::from(input.first() == Some(&b'-'));
+    match digits(&input[sign..]) {
+        0 => None,
+        len => Some(sign + len),
+    }
+}
+
+fn is_integer(input: &str) -> bool {
+    integer(input.as_bytes()) == Some(input.len())
+}
+
+fn is_decimal(input: &str) -> bool {
+    let input = input.as_bytes();
+    let Some(mut end) = integer(input) else {
+        return false;
+    };
+    if input.get(end) == Some(&b'.') {
+        match digits(&input[end + 1..]) {
+            0 => return false,
+            len => end += 1 + len,
+        }
+    }
+    if matches!(input.get(end), Some(b'e' | b'E')) {
+        end += 1;
+        if matches!(input.get(end), Some(b'+' | b'-')) {
+            end += 1;
+        }
+        match digits(&input[end..]) {
+            0 => return false,
+            len => end += len,
+        }
+    }
+    end == input.len()
+}
+
+macro_rules! big_number {
+    ($typ:ident, $kind:literal, $is_valid:ident) => {
+        impl $typ {
+            /// Returns the decimal text of this number.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Consumes this number and returns its decimal text.
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl FromStr for $typ {
+            type Err = ParseBigNumberError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                if $is_valid(value) {
+                    Ok(Self(value.to_owned()))
+                } else {
+                    Err(ParseBigNumberError {
+                        kind: $kind,
+                        input: value.to_owned(),
+                    })
+                }
+            }
+        }
+
+        impl AsRef<str> for $typ {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $typ {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl From<i64> for $typ {
+            fn from(value: i64) -> Self {
+                Self(value.to_string())
+            }
+        }
+
+        impl From<u64> for $typ {
+            fn from(value: u64) -> Self {
+                Self(value.to_string())
+            }
+        }
+    };
+}
+
+big_number!(BigInteger, "big integer", is_integer);
+big_number!(BigDecimal, "big decimal", is_decimal);
+
+impl From<BigInteger> for BigDecimal {
+    fn from(value: BigInteger) -> Self {
+        Self(value.0)
+    }
+}
+
+#[cfg(feature = "bignum")]
+mod bignum {
+    use super::{BigDecimal, BigInteger};
+
+    impl From<num_bigint::BigInt> for BigInteger {
+        fn from(value: num_bigint::BigInt) -> Self {
+            Self(value.to_string())
+        }
+    }
+
+    impl From<BigInteger> for num_bigint::BigInt {
+        fn from(value: BigInteger) -> Self {
+            value
+                .0
+                .parse()
+                .expect("a `BigInteger` always holds a valid integer")
+        }
+    }
+
+    impl From<bigdecimal::BigDecimal> for BigDecimal {
+        fn from(value: bigdecimal::BigDecimal) -> Self {
+            Self(value.to_string())
+        }
+    }
+
+    impl From<BigDecimal> for bigdecimal::BigDecimal {
+        fn from(value: BigDecimal) -> Self {
+            value
+                .0
+                .parse()
+                .expect("a `BigDecimal` always holds a valid decimal number")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BigDecimal, BigInteger};
+
+    #[test]
+    fn parse_big_integer() {
+        for valid in ["0", "-7", "123456789012345678901234567890"] {
+            assert_eq!(valid, valid.parse::<BigInteger>().unwrap().as_str());
+        }
+        for invalid in ["", "-", "+1", "1.0", "1e3", "12a", " 1"] {
+            let err = invalid.parse::<BigInteger>().unwrap_err();
+            assert_eq!(
+                format!("`{invalid}` is not a valid big integer"),
+                err.to_string()
+            );
+        }
+        assert_eq!("-5", BigInteger::from(-5_i64).as_str());
+        assert_eq!(u64::MAX.to_string(), BigInteger::from(u64::MAX).as_str());
+    }
+
+    #[test]
+    fn parse_big_decimal() {
+        for valid in [
+            "0",
+            "-7",
+            "3.14159265358979323846264338327950288",
+            "1e400",
+            "-1.5E-300",
+            "2e+10",
+        ] {
+            assert_eq!(valid, valid.parse::<BigDecimal>().unwrap().as_str());
+        }
+        for invalid in ["", "1.", ".5", "1e", "1e+", "1.5.2", "NaN", "Infinity"] {
+            assert!(invalid.parse::<BigDecimal>().is_err(), "{invalid}");
+        }
+        let integer: BigInteger = "-12345678901234567890".parse().unwrap();
+        assert_eq!(
+            "-12345678901234567890",
+            BigDecimal::from(integer).to_string()
+        );
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn bignum_conversions() {
+        let text = "-123456789012345678901234567890";
+        let integer: BigInteger = text.parse().unwrap();
+        let bigint = num_bigint::BigInt::from(integer.clone());
+        assert_eq!(text, bigint.to_string());
+        assert_eq!(integer, BigInteger::from(bigint));
+
+        let decimal: BigDecimal = "1.000000000000000000000000000001".parse().unwrap();
+        let bigdecimal = bigdecimal::BigDecimal::from(decimal.clone());
+        assert_eq!(decimal, BigDecimal::from(bigdecimal));
+        let exponent = bigdecimal::BigDecimal::from("-1.5e-3".parse::<BigDecimal>().unwrap());
+        assert_eq!("-0.0015", exponent.to_string());
+    }
+}
@@ -3,28 +3,49 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use bytes::Bytes;
+use std::fmt;
+
 /// Binary Blob Type
 ///
 /// Blobs represent protocol-agnostic binary content.
-#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+///
+/// A `Blob` is backed by [`Bytes`], so converting to and from `Bytes` or `Vec<u8>` doesn't copy
+/// its contents.
+#[derive(Default, PartialEq, Eq, Hash, Clone)]
 pub struct Blob {
-    inner: Vec<u8>,
+    inner: Bytes,
 }
 
 impl Blob {
     /// Creates a new blob from the given `input`.
     pub fn new<T: Into<Vec<u8>>>(input: T) -> Self {
         Blob {
-            inner: input.into(),
+            inner: Bytes::from(input.into()),
         }
     }
 
     /// Consumes the `Blob` and returns a `Vec<u8>` with its contents.
+    ///
+    /// This doesn't copy when the blob was created from a `Vec<u8>` and hasn't been cloned.
     pub fn into_inner(self) -> Vec<u8> {
+        self.inner.into()
+    }
+
+    /// Consumes the `Blob` and returns its contents as [`Bytes`] without copying.
+    pub fn into_bytes(self) -> Bytes {
         self.inner
     }
 }
 
+impl fmt::Debug for Blob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blob")
+            .field("inner", &self.inner.as_ref())
+            .finish()
+    }
+}
+
 impl AsRef<[u8]> for Blob {
     fn as_ref(&self) -> &[u8] {
         &self.inner
@@ -49,7 +70,19 @@ impl From<&[u8]> for Blob {
     }
 }
 
-#[cfg(all(aws_sdk_unstable, feature = "serde-serialize"))]
+impl From<Bytes> for Blob {
+    fn from(value: Bytes) -> Self {
+        Blob { inner: value }
+    }
+}
+
+impl From<Blob> for Bytes {
+    fn from(value: Blob) -> Self {
+        value.into_bytes()
+    }
+}
+
+#[cfg(any(feature = "serde", all(aws_sdk_unstable, feature = "serde-serialize")))]
 mod serde_serialize {
     use super::*;
     use serde::Serialize;
@@ -68,7 +101,10 @@ mod serde_serialize {
     }
 }
 
-#[cfg(all(aws_sdk_unstable, feature = "serde-deserialize"))]
+#[cfg(any(
+    feature = "serde",
+    all(aws_sdk_unstable, feature = "serde-deserialize")
+))]
 mod serde_deserialize {
     use super::*;
     use serde::{de::Visitor, Deserialize};
@@ -85,7 +121,7 @@ mod serde_deserialize {
             E: serde::de::Error,
         {
             match crate::base64::decode(v) {
-                Ok(inner) => Ok(Blob { inner }),
+                Ok(inner) => Ok(Blob::new(inner)),
                 Err(e) => Err(E::custom(e)),
             }
         }
@@ -102,7 +138,7 @@ mod serde_deserialize {
         where
             E: serde::de::Error,
         {
-            Ok(Blob { inner: v })
+            Ok(Blob::new(v))
         }
     }
 
@@ -123,6 +159,7 @@ mod serde_deserialize {
 #[cfg(test)]
 mod test {
     use crate::Blob;
+    use bytes::Bytes;
 
     #[test]
     fn blob_conversion() {
@@ -138,12 +175,43 @@ mod test {
         let vec2: Vec<u8> = blob2.into();
         assert_eq!(orig_vec, vec2);
     }
+
+    #[test]
+    fn bytes_conversion_does_not_copy() {
+        let bytes = Bytes::from_static(b"a large payload");
+        let ptr = bytes.as_ptr();
+
+        let blob = Blob::from(bytes);
+        assert_eq!(ptr, blob.as_ref().as_ptr());
+        let bytes = blob.into_bytes();
+        assert_eq!(ptr, bytes.as_ptr());
+
+        let vec = b"another payload".to_vec();
+        let ptr = vec.as_ptr();
+        let blob = Blob::new(vec);
+        assert_eq!(ptr, Bytes::from(blob.clone()).as_ptr());
+        assert_eq!(ptr, blob.into_inner().as_ptr());
+    }
+
+    #[test]
+    fn debug_format() {
+        assert_eq!(
+            "Blob { inner: [65, 87, 83] }",
+            format!("{:?}", Blob::new("AWS"))
+        );
+    }
 }
 
 #[cfg(all(
-    aws_sdk_unstable,
-    feature = "serde-serialize",
-    feature = "serde-deserialize"
+    test,
+    any(
+        feature = "serde",
+        all(
+            aws_sdk_unstable,
+            feature = "serde-serialize",
+            feature = "serde-deserialize"
+        )
+    )
 ))]
 mod test_serde {
     use crate::Blob;
@@ -159,9 +227,7 @@ mod test_serde {
     fn human_readable_blob() {
         let aws_in_base64 = r#"{"blob":"QVdT"}"#;
         let for_test = ForTest {
-            blob: Blob {
-                inner: vec![b'A', b'W', b'S'],
-            },
+            blob: Blob::new(vec![b'A', b'W', b'S']),
         };
         assert_eq!(for_test, serde_json::from_str(aws_in_base64).unwrap());
         assert_eq!(serde_json::to_string(&for_test).unwrap(), aws_in_base64);
@@ -172,9 +238,7 @@ mod test_serde {
         use std::ffi::CString;
 
         let for_test = ForTest {
-            blob: Blob {
-                inner: vec![b'A', b'W', b'S'],
-            },
+            blob: Blob::new(vec![b'A', b'W', b'S']),
         };
         let mut buf = vec![];
         let res = ciborium::ser::into_writer(&for_test, &mut buf);
@@ -183,7 +247,7 @@ mod test_serde {
         // checks whether the bytes are deserialized properly
         let n: HashMap<String, CString> =
             ciborium::de::from_reader(std::io::Cursor::new(buf.clone())).unwrap();
-        assert!(n.get("blob").is_some());
+        assert!(n.contains_key("blob"));
         assert!(n.get("blob") == CString::new([65, 87, 83]).ok().as_ref());
 
         let de: ForTest = ciborium::de::from_reader(std::io::Cursor::new(buf)).unwrap();
@@ -144,6 +144,9 @@ pub mod error;
 #[cfg(feature = "rt-tokio")]
 pub use self::bytestream_util::FsBuilder;
 
+#[cfg(feature = "rt-tokio")]
+pub use self::bytestream_util::SharedFile;
+
 /// This module is named after the `http-body` version number since we anticipate
 /// needing to provide equivalent functionality for 1.x of that crate in the future.
 /// The name has a suffix `_x` to avoid name collision with a third-party `http-body-0-4`.
@@ -414,6 +417,70 @@ impl ByteStream {
             .await
     }
 
+    /// Create a ByteStream that streams data from an [`AsyncRead`](tokio::io::AsyncRead)
+    ///
+    /// If the `length` of the data is known, it will be used as the size hint of the ByteStream
+    /// when used as an HTTP body, and exactly `length` bytes will be read. Reaching the end of
+    /// the reader before `length` bytes are read results in an error.
+    ///
+    /// ## Warning
+    /// The returned ByteStream is not retryable: once the request starts sending it, a failed
+    /// request cannot be retried. To stream a file in a retryable way, use [`ByteStream::from_path`].
+    /// Otherwise, wrap the creation of the reader in [`ByteStream::retryable`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use aws_smithy_types::byte_stream::ByteStream;
+    ///
+    /// use tokio::io::AsyncRead;
+    ///
+    /// fn make_bytestream(socket: impl AsyncRead + Send + 'static, content_length: u64) -> ByteStream {
+    ///     ByteStream::from_async_read(socket, Some(content_length))
+    /// }
+    /// ```
+    #[cfg(feature = "rt-tokio")]
+    pub fn from_async_read(
+        reader: impl tokio::io::AsyncRead + Send + 'static,
+        length: Option<u64>,
+    ) -> Self {
+        ByteStream::new(SdkBody::from_body_0_4_internal(
+            bytestream_util::AsyncReadBody::new(reader, length),
+        ))
+    }
+
+    /// Create a retryable ByteStream from a function creating the ByteStream
+    ///
+    /// Streaming ByteStreams, such as the ones created with [`ByteStream::from_async_read`], can only
+    /// be read once, so requests sending them can't be retried. When `f` is able to recreate the
+    /// stream from the start, this function makes the ByteStream retryable: `f` is called once to
+    /// create the initial ByteStream, and again each time the request is retried.
+    ///
+    /// All ByteStreams created from in-memory data (`String`, `Vec<u8>`, `Bytes`, etc.) and with
+    /// [`ByteStream::from_path`] are retryable out of the box.
+    ///
+    /// ## Warning
+    /// Every ByteStream returned by `f` MUST have the same contents. Otherwise, the length and
+    /// checksum computed for the initial attempt won't match the data sent when retrying.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # #[cfg(feature = "rt-tokio")]
+    /// # {
+    /// use aws_smithy_types::byte_stream::ByteStream;
+    /// use std::path::PathBuf;
+    ///
+    /// fn make_bytestream(path: PathBuf, length: u64) -> ByteStream {
+    ///     ByteStream::retryable(move || {
+    ///         let file = std::fs::File::open(&path).expect("file should be readable");
+    ///         ByteStream::from_async_read(tokio::fs::File::from_std(file), Some(length))
+    ///     })
+    /// }
+    /// # }
+    /// ```
+    pub fn retryable(f: impl Fn() -> ByteStream + Send + Sync + 'static) -> Self {
+        ByteStream::new(SdkBody::retryable(move || f().into_inner()))
+    }
+
     #[cfg(feature = "rt-tokio")]
     /// Convert this `ByteStream` into a struct that implements [`AsyncBufRead`](tokio::io::AsyncBufRead).
     ///
@@ -622,6 +689,66 @@ mod tests {
         assert_eq!(lines.next_line().await.unwrap(), None);
     }
 
+    #[tokio::test]
+    async fn bytestream_from_async_read() {
+        let (mut writer, reader) = tokio::io::duplex(4);
+        tokio::spawn(async move {
+            use tokio::io::AsyncWriteExt;
+            writer.write_all(b"data 1\ndata 2").await.unwrap();
+        });
+
+        let byte_stream = ByteStream::from_async_read(reader, None);
+        assert_eq!(byte_stream.size_hint(), (0, None));
+        let data = byte_stream.collect().await.unwrap().into_bytes();
+        assert_eq!(data, Bytes::from("data 1\ndata 2"));
+    }
+
+    #[tokio::test]
+    async fn bytestream_from_async_read_with_length() {
+        let byte_stream = ByteStream::from_async_read(&b"data 1\ndata 2"[..], Some(6));
+        assert_eq!(byte_stream.size_hint(), (6, Some(6)));
+        let data = byte_stream.collect().await.unwrap().into_bytes();
+        assert_eq!(data, Bytes::from("data 1"));
+
+        let byte_stream = ByteStream::from_async_read(&b"data"[..], Some(6));
+        let err = byte_stream.collect().await.unwrap_err();
+        let source = std::error::Error::source(&err).unwrap().to_string();
+        assert_eq!(
+            source,
+            "reader ended after 4 bytes but its length was 6 bytes"
+        );
+    }
+
+    #[tokio::test]
+    async fn retryable_bytestream_is_rebuilt() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let byte_stream = ByteStream::retryable({
+            let calls = calls.clone();
+            move || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                ByteStream::from_async_read(&b"streamed data"[..], Some(13))
+            }
+        });
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+        assert_eq!(byte_stream.size_hint(), (13, Some(13)));
+
+        let retry = ByteStream::new(
+            byte_stream
+                .inner
+                .body
+                .try_clone()
+                .expect("retryable bodies are cloneable"),
+        );
+        assert_eq!(2, calls.load(Ordering::SeqCst));
+        let first = byte_stream.collect().await.unwrap().into_bytes();
+        let second = retry.collect().await.unwrap().into_bytes();
+        assert_eq!(first, Bytes::from("streamed data"));
+        assert_eq!(first, second);
+    }
+
     #[tokio::test]
     async fn valid_size_hint() {
         assert_eq!(ByteStream::from_static(b"hello").size_hint().1, Some(5));
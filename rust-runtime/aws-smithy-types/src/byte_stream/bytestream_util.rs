@@ -5,12 +5,16 @@
 
 use crate::body::SdkBody;
 use crate::byte_stream::{error::Error, error::ErrorKind, ByteStream};
+use bytes::Bytes;
 use std::cmp::min;
 use std::future::Future;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::Poll;
 use tokio::fs::File;
-use tokio::io::{self, AsyncReadExt, AsyncSeekExt};
+use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeekExt};
+use tokio::task::JoinHandle;
 use tokio_util::io::ReaderStream;
 
 // TODO(https://github.com/smithy-lang/smithy-rs/issues/1925)
@@ -95,6 +99,7 @@ impl PathBody {
 pub struct FsBuilder {
     file: Option<File>,
     path: Option<PathBuf>,
+    shared_file: Option<SharedFile>,
     length: Option<Length>,
     buffer_size: usize,
     offset: Option<u64>,
@@ -127,6 +132,7 @@ impl FsBuilder {
             length: None,
             offset: None,
             path: None,
+            shared_file: None,
         }
     }
 
@@ -149,6 +155,41 @@ impl FsBuilder {
         self
     }
 
+    /// Sets the [`SharedFile`] to read from.
+    ///
+    /// This is intended for reading many windows of the same file, e.g. the parts of a multipart upload,
+    /// with [`offset`](FsBuilder::offset) and [`length`](FsBuilder::length). The file is opened once and its
+    /// handle is reused by every window, instead of being reopened for each of them.
+    ///
+    /// NOTE: The resulting ByteStream (after calling [build](FsBuilder::build)) will be retryable.
+    /// The returned ByteStream will provide a size hint when used as an HTTP body.
+    /// If the request fails, the read will begin again from the start of the window.
+    ///
+    /// ```no_run
+    /// use aws_smithy_types::byte_stream::{ByteStream, Length, SharedFile};
+    ///
+    /// async fn parts(path: &str, part_size: u64) -> Vec<ByteStream> {
+    ///     let file = SharedFile::open(path).await.expect("valid path");
+    ///     let mut parts = Vec::new();
+    ///     for offset in (0..file.len()).step_by(part_size as usize) {
+    ///         let part = ByteStream::read_from()
+    ///             .shared_file(file.clone())
+    ///             .offset(offset)
+    ///             .length(Length::UpTo(part_size))
+    ///             .buffer_size(65_536)
+    ///             .build()
+    ///             .await
+    ///             .expect("the part is within the file");
+    ///         parts.push(part);
+    ///     }
+    ///     parts
+    /// }
+    /// ```
+    pub fn shared_file(mut self, file: SharedFile) -> Self {
+        self.shared_file = Some(file);
+        self
+    }
+
     /// Specify the length to read (in bytes).
     ///
     /// By pre-specifying the length, this API skips an additional call to retrieve the size from file-system metadata.
@@ -178,8 +219,13 @@ impl FsBuilder {
 
     /// Returns a [`ByteStream`] from this builder.
     pub async fn build(self) -> Result<ByteStream, Error> {
-        if self.path.is_some() && self.file.is_some() {
-            panic!("The 'file' and 'path' options on an FsBuilder are mutually exclusive but both were set. Please set only one")
+        let sources = [
+            self.path.is_some(),
+            self.file.is_some(),
+            self.shared_file.is_some(),
+        ];
+        if sources.iter().filter(|is_set| **is_set).count() > 1 {
+            panic!("The 'file', 'path' and 'shared_file' options on an FsBuilder are mutually exclusive but several were set. Please set only one")
         };
 
         let buffer_size = self.buffer_size;
@@ -215,6 +261,17 @@ impl FsBuilder {
                 ))
             };
 
+            Ok(ByteStream::new(SdkBody::retryable(body_loader)))
+        } else if let Some(shared_file) = self.shared_file {
+            let body_loader = move || {
+                SdkBody::from_body_0_4_internal(SharedFileBody::new(
+                    shared_file.file.clone(),
+                    offset,
+                    length,
+                    buffer_size,
+                ))
+            };
+
             Ok(ByteStream::new(SdkBody::retryable(body_loader)))
         } else if let Some(mut file) = self.file {
             // When starting from a `File`, we need to do our own seeking
@@ -232,6 +289,9 @@ impl FsBuilder {
     }
 
     async fn get_file_size(&self) -> Result<u64, Error> {
+        if let Some(shared_file) = self.shared_file.as_ref() {
+            return Ok(shared_file.len());
+        }
         Ok(match self.path.as_ref() {
             Some(path) => tokio::fs::metadata(path).await,
             // If it's not path-based then it's file-based
@@ -241,6 +301,199 @@ impl FsBuilder {
     }
 }
 
+/// A file opened once, from which many windows can be read with [`FsBuilder::shared_file`].
+///
+/// Windows are read with positional reads, so they can be streamed concurrently from the same file
+/// handle without interfering with each other. On targets without positional reads, the reads
+/// seek the handle instead, one at a time. Cloning a `SharedFile` is cheap and shares the handle.
+#[derive(Clone, Debug)]
+pub struct SharedFile {
+    file: Arc<FileHandle>,
+    length: u64,
+}
+
+impl SharedFile {
+    /// Opens the file at `path` in read-only mode.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_file(File::open(path).await?).await
+    }
+
+    /// Creates a `SharedFile` from an open file.
+    ///
+    /// The position of the file's cursor is ignored: windows are always relative to the start of the file.
+    pub async fn from_file(file: File) -> Result<Self, Error> {
+        let length = file.metadata().await?.len();
+        let file = file.into_std().await;
+        #[cfg(not(any(unix, windows)))]
+        let file = Mutex::new(file);
+        Ok(Self {
+            file: Arc::new(file),
+            length,
+        })
+    }
+
+    /// Returns the length of the file (in bytes) when it was opened.
+    pub fn len(&self) -> u64 {
+        self.length
+    }
+
+    /// Returns `true` if the file was empty when it was opened.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+/// The file handle of a [`SharedFile`]
+#[cfg(any(unix, windows))]
+type FileHandle = std::fs::File;
+
+/// The file handle of a [`SharedFile`]
+///
+/// Without positional reads, reads seek the file's cursor, so they must not run concurrently.
+#[cfg(not(any(unix, windows)))]
+type FileHandle = Mutex<std::fs::File>;
+
+#[cfg(unix)]
+fn read_at(file: &std::fs::File, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+    std::os::unix::fs::FileExt::read_at(file, buf, offset)
+}
+
+#[cfg(windows)]
+fn read_at(file: &std::fs::File, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+    // `seek_read` moves the file's cursor, but all the reads of a `SharedFile` are positional.
+    std::os::windows::fs::FileExt::seek_read(file, buf, offset)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn read_at(file: &FileHandle, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+    use std::io::{Read, Seek, SeekFrom};
+    let mut file = file.lock().unwrap_or_else(PoisonError::into_inner);
+    file.seek(SeekFrom::Start(offset))?;
+    file.read(buf)
+}
+
+/// An HTTP Body streaming a window of a [`SharedFile`]
+///
+/// Each chunk is read at its offset on the blocking thread pool, like Tokio does for file reads.
+struct SharedFileBody {
+    file: Arc<FileHandle>,
+    offset: u64,
+    length: u64,
+    bytes_left: u64,
+    buffer_size: usize,
+    read: Option<JoinHandle<io::Result<Bytes>>>,
+}
+
+impl SharedFileBody {
+    fn new(file: Arc<FileHandle>, offset: u64, length: u64, buffer_size: usize) -> Self {
+        SharedFileBody {
+            file,
+            offset,
+            length,
+            bytes_left: length,
+            buffer_size: buffer_size.max(1),
+            read: None,
+        }
+    }
+
+    fn poll_next_bytes(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Result<Bytes, io::Error>>> {
+        if self.bytes_left == 0 {
+            return Poll::Ready(None);
+        }
+        let read = match self.read.as_mut() {
+            Some(read) => read,
+            None => {
+                let file = self.file.clone();
+                let offset = self.offset;
+                let len = min(self.buffer_size as u64, self.bytes_left) as usize;
+                self.read.insert(tokio::task::spawn_blocking(move || {
+                    let mut buf = vec![0; len];
+                    let read = read_at(&file, &mut buf, offset)?;
+                    buf.truncate(read);
+                    Ok(Bytes::from(buf))
+                }))
+            }
+        };
+        let result = futures_core::ready!(Pin::new(read).poll(cx));
+        self.read = None;
+        Poll::Ready(Some(match result {
+            Ok(Ok(bytes)) if bytes.is_empty() => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!(
+                    "file ended {} bytes before the end of the {} bytes long window",
+                    self.bytes_left, self.length
+                ),
+            )),
+            Ok(Ok(bytes)) => {
+                self.offset += bytes.len() as u64;
+                self.bytes_left -= bytes.len() as u64;
+                Ok(bytes)
+            }
+            Ok(Err(err)) => Err(err),
+            Err(err) => Err(io::Error::new(io::ErrorKind::Other, err)),
+        }))
+    }
+}
+
+type BoxedAsyncRead = Pin<Box<dyn AsyncRead + Send>>;
+
+/// An HTTP Body streaming the contents of an [`AsyncRead`](tokio::io::AsyncRead)
+///
+/// When a length is given, it is used as the size hint of the body and exactly that many bytes
+/// are read. Reaching the end of the reader before that is an error.
+pub(super) struct AsyncReadBody {
+    // `ReaderStream` isn't `Sync`, so it's wrapped in a `Mutex`. The `Mutex` is never locked:
+    // the stream is only ever accessed through `Mutex::get_mut`.
+    stream: Mutex<ReaderStream<io::Take<BoxedAsyncRead>>>,
+    length: Option<u64>,
+    bytes_read: u64,
+}
+
+impl AsyncReadBody {
+    pub(super) fn new(reader: impl AsyncRead + Send + 'static, length: Option<u64>) -> Self {
+        let reader: BoxedAsyncRead = Box::pin(reader);
+        AsyncReadBody {
+            stream: Mutex::new(ReaderStream::with_capacity(
+                reader.take(length.unwrap_or(u64::MAX)),
+                DEFAULT_BUFFER_SIZE,
+            )),
+            length,
+            bytes_read: 0,
+        }
+    }
+
+    fn poll_next_bytes(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Result<bytes::Bytes, io::Error>>> {
+        use futures_core::Stream;
+        let stream = self
+            .stream
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        match futures_core::ready!(Pin::new(stream).poll_next(cx)) {
+            Some(Ok(bytes)) => {
+                self.bytes_read += bytes.len() as u64;
+                Poll::Ready(Some(Ok(bytes)))
+            }
+            Some(Err(err)) => Poll::Ready(Some(Err(err))),
+            None => match self.length {
+                Some(length) if self.bytes_read < length => Poll::Ready(Some(Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!(
+                        "reader ended after {} bytes but its length was {length} bytes",
+                        self.bytes_read
+                    ),
+                )))),
+                _ => Poll::Ready(None),
+            },
+        }
+    }
+}
+
 enum State {
     Unloaded(PathBuf),
     Loading(Pin<Box<dyn Future<Output = io::Result<File>> + Send + Sync + 'static>>),
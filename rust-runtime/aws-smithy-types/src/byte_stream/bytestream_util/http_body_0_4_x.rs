@@ -3,7 +3,7 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use super::{PathBody, State, DEFAULT_OFFSET};
+use super::{AsyncReadBody, PathBody, SharedFileBody, State, DEFAULT_OFFSET};
 use std::future::Future;
 use std::pin::Pin;
 use std::task::Poll;
@@ -86,9 +86,70 @@ impl http_body_0_4::Body for PathBody {
     }
 }
 
+impl http_body_0_4::Body for AsyncReadBody {
+    type Data = bytes::Bytes;
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    fn poll_data(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        self.get_mut()
+            .poll_next_bytes(cx)
+            .map(|next| next.map(|result| result.map_err(Into::into)))
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        Poll::Ready(Ok(None))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.length == Some(self.bytes_read)
+    }
+
+    fn size_hint(&self) -> http_body_0_4::SizeHint {
+        match self.length {
+            Some(length) => http_body_0_4::SizeHint::with_exact(length),
+            None => http_body_0_4::SizeHint::default(),
+        }
+    }
+}
+
+impl http_body_0_4::Body for SharedFileBody {
+    type Data = bytes::Bytes;
+    type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    fn poll_data(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        self.get_mut()
+            .poll_next_bytes(cx)
+            .map(|next| next.map(|result| result.map_err(Into::into)))
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        Poll::Ready(Ok(None))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.bytes_left == 0
+    }
+
+    fn size_hint(&self) -> http_body_0_4::SizeHint {
+        http_body_0_4::SizeHint::with_exact(self.length)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::byte_stream::{ByteStream, FsBuilder, Length};
+    use crate::byte_stream::{ByteStream, FsBuilder, Length, SharedFile};
     use bytes::Buf;
     use http_body_0_4::Body;
     use std::io::Write;
@@ -411,4 +472,79 @@ mod test {
 
         assert_eq!(data_str, in_memory_copy_of_file_contents);
     }
+
+    #[tokio::test]
+    async fn shared_file_windows_are_read_concurrently() {
+        let mut file = NamedTempFile::new().unwrap();
+        let mut contents = Vec::new();
+        for i in 0..1000 {
+            writeln!(contents, "Line {:04}", i).unwrap();
+        }
+        file.write_all(&contents).unwrap();
+        file.flush().expect("flushing is OK");
+
+        let shared_file = SharedFile::open(file.path()).await.unwrap();
+        assert_eq!(contents.len() as u64, shared_file.len());
+
+        let part_size = contents.len() as u64 / 7 + 1;
+        let mut parts = Vec::new();
+        for (i, offset) in (0..shared_file.len())
+            .step_by(part_size as usize)
+            .enumerate()
+        {
+            let part = FsBuilder::new()
+                .shared_file(shared_file.clone())
+                .offset(offset)
+                .length(Length::UpTo(part_size))
+                // Use a different buffer size for each part
+                .buffer_size(16 << i)
+                .build()
+                .await
+                .unwrap();
+            parts.push(tokio::spawn(part.collect()));
+        }
+
+        let mut collected = Vec::new();
+        for part in parts {
+            collected.extend(part.await.unwrap().unwrap().into_bytes());
+        }
+        assert_eq!(contents, collected);
+    }
+
+    #[tokio::test]
+    async fn shared_file_windows_are_retryable() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "Line 0\nLine 1\nLine 2\n").unwrap();
+        file.flush().expect("flushing is OK");
+
+        let shared_file = SharedFile::from_file(tokio::fs::File::open(file.path()).await.unwrap())
+            .await
+            .unwrap();
+        let body = FsBuilder::new()
+            .shared_file(shared_file.clone())
+            .offset(7)
+            .length(Length::Exact(7))
+            .buffer_size(2)
+            .build()
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(body.content_length(), Some(7));
+
+        let mut first_attempt = body.try_clone().expect("retryable bodies are cloneable");
+        first_attempt.next().await.unwrap().unwrap();
+        for _ in 0..2 {
+            let retry = body.try_clone().expect("retryable bodies are cloneable");
+            let data = ByteStream::new(retry).collect().await.unwrap().into_bytes();
+            assert_eq!(&data[..], b"Line 1\n");
+        }
+
+        assert!(FsBuilder::new()
+            .shared_file(shared_file)
+            .offset(7)
+            .length(Length::Exact(100))
+            .build()
+            .await
+            .is_err());
+    }
 }
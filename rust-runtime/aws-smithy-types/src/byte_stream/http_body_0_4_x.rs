@@ -4,8 +4,11 @@
  */
 
 use crate::body::SdkBody;
+use crate::byte_stream::error::Error;
 use crate::byte_stream::ByteStream;
 use bytes::Bytes;
+use std::future::poll_fn;
+use std::pin::Pin;
 
 impl ByteStream {
     /// Construct a `ByteStream` from a type that implements [`http_body_0_4::Body<Data = Bytes>`](http_body_0_4::Body).
@@ -18,6 +21,19 @@ impl ByteStream {
     {
         ByteStream::new(SdkBody::from_body_0_4(body))
     }
+
+    /// Returns the trailers sent after the data of this `ByteStream`, such as checksum trailers.
+    ///
+    /// Trailers are only known once all the data has been read, so this must be called after
+    /// [`next`](ByteStream::next) has returned `None`. Returns `None` if there are no trailers.
+    ///
+    /// _Note: This is only available when the `http-body-0-4-x` feature is enabled._
+    pub async fn trailers(&mut self) -> Result<Option<http::HeaderMap>, Error> {
+        let mut body = Pin::new(&mut self.inner.body);
+        poll_fn(|cx| body.as_mut().poll_next_trailers(cx))
+            .await
+            .map_err(Error::streaming)
+    }
 }
 
 #[cfg(feature = "hyper-0-14-x")]
@@ -30,7 +46,7 @@ impl From<hyper_0_14::Body> for ByteStream {
 #[cfg(test)]
 mod tests {
     use crate::body::SdkBody;
-    use crate::byte_stream::Inner;
+    use crate::byte_stream::{ByteStream, Inner};
     use bytes::Bytes;
 
     #[tokio::test]
@@ -48,6 +64,28 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn trailers_are_read_after_the_data() {
+        let (mut sender, body) = hyper_0_14::Body::channel();
+        let mut byte_stream = ByteStream::from_body_0_4(body);
+        tokio::spawn(async move {
+            sender.send_data(Bytes::from("data")).await.unwrap();
+            let mut trailers = http::HeaderMap::new();
+            trailers.insert("x-amz-checksum-crc32", "sOO8/Q==".parse().unwrap());
+            sender.send_trailers(trailers).await.unwrap();
+        });
+        assert_eq!(
+            Bytes::from("data"),
+            byte_stream.next().await.unwrap().unwrap()
+        );
+        assert!(byte_stream.next().await.is_none());
+        let trailers = byte_stream.trailers().await.unwrap().unwrap();
+        assert_eq!("sOO8/Q==", trailers["x-amz-checksum-crc32"]);
+
+        let mut byte_stream = ByteStream::from_static(b"data");
+        assert!(byte_stream.trailers().await.unwrap().is_none());
+    }
+
     #[cfg(feature = "rt-tokio")]
     #[tokio::test]
     async fn path_based_bytestreams() -> Result<(), Box<dyn std::error::Error>> {
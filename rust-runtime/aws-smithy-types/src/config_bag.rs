@@ -130,6 +130,8 @@
 //! }
 //! ```
 //!
+#[cfg(feature = "config-bag-debug")]
+mod snapshot;
 mod storable;
 mod typeid_map;
 
@@ -144,6 +146,11 @@ use std::ops::Deref;
 use std::slice::Iter;
 use std::sync::Arc;
 
+#[cfg(feature = "config-bag-debug")]
+pub use snapshot::{
+    ConfigBagDiff, ConfigBagSnapshot, ItemSource, LayerSnapshot, StoredItem, TypeDiff,
+};
+use storable::DescribeStore;
 pub use storable::{AppendItemIter, Storable, Store, StoreAppend, StoreReplace};
 
 /// [`FrozenLayer`] is the immutable and shareable form of [`Layer`].
@@ -260,10 +267,11 @@ impl CloneableLayer {
         self
     }
 
-    fn put_directly_cloneable<T: Store>(&mut self, value: T::StoredType) -> &mut Self
+    fn put_directly_cloneable<T: DescribeStore>(&mut self, value: T::StoredType) -> &mut Self
     where
         T::StoredType: Clone,
     {
+        self.0.record::<T>(T::is_unset(&value));
         self.0.props.insert(
             TypeId::of::<T::StoredType>(),
             TypeErasedBox::new_with_clone(value),
@@ -315,10 +323,11 @@ impl CloneableLayer {
         self.put_directly_cloneable::<StoreAppend<T>>(Value::ExplicitlyUnset(type_name::<T>()));
     }
 
-    fn get_mut_or_default<T: Send + Sync + Store + 'static>(&mut self) -> &mut T::StoredType
+    fn get_mut_or_default<T: Send + Sync + DescribeStore + 'static>(&mut self) -> &mut T::StoredType
     where
         T::StoredType: Default + Clone,
     {
+        self.0.record::<T>(false);
         self.0
             .props
             .entry(TypeId::of::<T::StoredType>())
@@ -335,6 +344,9 @@ impl CloneableLayer {
 pub struct Layer {
     name: Cow<'static, str>,
     props: TypeIdMap<TypeErasedBox>,
+    /// The type of each item in `props`, for snapshots
+    #[cfg(feature = "config-bag-debug")]
+    items: TypeIdMap<snapshot::StoredItem>,
 }
 
 impl Debug for Layer {
@@ -363,14 +375,30 @@ impl Layer {
             Some(Layer {
                 name: self.name.clone(),
                 props: new_props,
+                #[cfg(feature = "config-bag-debug")]
+                items: self.items.clone(),
             })
         } else {
             None
         }
     }
 
+    /// Records the type of an item inserted into this layer for snapshots
+    #[cfg(feature = "config-bag-debug")]
+    fn record<T: DescribeStore>(&mut self, unset: bool) {
+        self.items.insert(
+            TypeId::of::<T::StoredType>(),
+            snapshot::StoredItem::new(T::item_type_name(), unset),
+        );
+    }
+
+    #[cfg(not(feature = "config-bag-debug"))]
+    #[inline]
+    fn record<T: DescribeStore>(&mut self, _unset: bool) {}
+
     /// Inserts `value` into the layer directly
-    fn put_directly<T: Store>(&mut self, value: T::StoredType) -> &mut Self {
+    fn put_directly<T: DescribeStore>(&mut self, value: T::StoredType) -> &mut Self {
+        self.record::<T>(T::is_unset(&value));
         self.props
             .insert(TypeId::of::<T::StoredType>(), TypeErasedBox::new(value));
         self
@@ -391,7 +419,7 @@ impl Layer {
         let name = name.into();
         Self {
             name,
-            props: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -399,16 +427,23 @@ impl Layer {
     pub fn with_name(self, name: impl Into<Cow<'static, str>>) -> Self {
         Self {
             name: name.into(),
-            props: self.props,
+            ..self
         }
     }
 
+    /// Returns a snapshot of the name of this layer and the types stored in it.
+    #[cfg(feature = "config-bag-debug")]
+    pub fn snapshot(&self) -> LayerSnapshot {
+        LayerSnapshot::new(self)
+    }
+
     /// Load a storable item from the bag
     pub fn load<T: Storable>(&self) -> <T::Storer as Store>::ReturnedType<'_> {
         T::Storer::merge_iter(ItemIter {
-            inner: BagIter {
+            inner: TypedLayerIter {
                 head: Some(self),
-                tail: [].iter().rev(),
+                tail: &[],
+                indices: [].iter().rev(),
             },
             t: Default::default(),
         })
@@ -504,10 +539,11 @@ impl Layer {
 
     /// Returns a mutable reference to `T` if it is stored in this layer, otherwise returns the
     /// [`Default`] implementation of `T`
-    fn get_mut_or_default<T: Send + Sync + Store + 'static>(&mut self) -> &mut T::StoredType
+    fn get_mut_or_default<T: Send + Sync + DescribeStore + 'static>(&mut self) -> &mut T::StoredType
     where
         T::StoredType: Default,
     {
+        self.record::<T>(false);
         self.props
             .entry(TypeId::of::<T::StoredType>())
             .or_insert_with(|| TypeErasedBox::new(T::StoredType::default()))
@@ -523,6 +559,10 @@ impl Layer {
 pub struct ConfigBag {
     interceptor_state: Layer,
     tail: Vec<FrozenLayer>,
+    /// For each type stored in the frozen layers, the indices into `tail` of the layers that store it, in the order
+    /// they were pushed. Frozen layers can't change, so this only grows as layers are pushed, and loads only visit
+    /// the layers that store the type being loaded instead of every layer of the bag.
+    tail_index: TypeIdMap<Vec<usize>>,
 }
 
 impl Debug for ConfigBag {
@@ -543,11 +583,9 @@ impl ConfigBag {
     /// Create a new config bag "base".
     pub fn base() -> Self {
         ConfigBag {
-            interceptor_state: Layer {
-                name: Cow::Borrowed("interceptor_state"),
-                props: Default::default(),
-            },
+            interceptor_state: Layer::new("interceptor_state"),
             tail: vec![],
+            tail_index: Default::default(),
         }
     }
 
@@ -562,12 +600,15 @@ impl ConfigBag {
 
     /// Add the given layer to the config bag.
     pub fn push_layer(&mut self, layer: Layer) -> &mut Self {
-        self.tail.push(layer.freeze());
-        self
+        self.push_shared_layer(layer.freeze())
     }
 
     /// Add a frozen/shared layer to the config bag.
     pub fn push_shared_layer(&mut self, layer: FrozenLayer) -> &mut Self {
+        let index = self.tail.len();
+        for type_id in layer.props.keys() {
+            self.tail_index.entry(*type_id).or_default().push(index);
+        }
         self.tail.push(layer);
         self
     }
@@ -595,7 +636,10 @@ impl ConfigBag {
             .get_mut::<StoreReplace<T>>()
             .is_none()
         {
-            let new_item = match self.tail.iter().find_map(|b| b.load::<T>()) {
+            let new_item = match self
+                .tail_layers::<StoreReplace<T>>()
+                .find_map(|b| b.load::<T>())
+            {
                 Some(item) => item.clone(),
                 None => return None,
             };
@@ -681,21 +725,15 @@ impl ConfigBag {
     /// assert_eq!(bag.load::<ExampleInt>(), Some(&ExampleInt(1)));
     /// ```
     pub fn with_fn(
-        self,
+        mut self,
         name: impl Into<Cow<'static, str>>,
         next: impl Fn(&mut Layer),
     ) -> ConfigBag {
         let mut new_layer = Layer::new(name);
         next(&mut new_layer);
-        let ConfigBag {
-            interceptor_state: head,
-            mut tail,
-        } = self;
-        tail.push(head.freeze());
-        ConfigBag {
-            interceptor_state: new_layer,
-            tail,
-        }
+        let head = std::mem::replace(&mut self.interceptor_state, new_layer);
+        self.push_layer(head);
+        self
     }
 
     /// Add a new layer with `name` after freezing the top layer so far
@@ -709,23 +747,59 @@ impl ConfigBag {
     /// `T` implements a [`Store`] trait.
     pub fn sourced_get<T: Store>(&self) -> T::ReturnedType<'_> {
         let stored_type_iter = ItemIter {
-            inner: self.layers(),
+            inner: TypedLayerIter {
+                head: Some(&self.interceptor_state),
+                tail: &self.tail,
+                indices: self.tail_indices::<T>().iter().rev(),
+            },
             t: PhantomData,
         };
         T::merge_iter(stored_type_iter)
     }
 
+    /// Returns a snapshot of the layers of this bag and the types stored in each of them.
+    ///
+    /// This is useful to debug which layer a value is loaded from. See [`ConfigBagSnapshot`] for an example.
+    #[cfg(feature = "config-bag-debug")]
+    pub fn snapshot(&self) -> ConfigBagSnapshot {
+        ConfigBagSnapshot::new(self.layers())
+    }
+
+    /// Returns the types that are stored in different layers of this bag and `other`.
+    ///
+    /// This is a shorthand for diffing the [snapshots](ConfigBag::snapshot) of both bags.
+    #[cfg(feature = "config-bag-debug")]
+    pub fn diff(&self, other: &ConfigBag) -> ConfigBagDiff {
+        self.snapshot().diff(&other.snapshot())
+    }
+
     fn layers(&self) -> BagIter<'_> {
         BagIter {
             head: Some(&self.interceptor_state),
             tail: self.tail.iter().rev(),
         }
     }
+
+    /// Returns the indices of the frozen layers that store `T`, in the order they were pushed.
+    fn tail_indices<T: Store>(&self) -> &[usize] {
+        self.tail_index
+            .get(&TypeId::of::<T::StoredType>())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the frozen layers that store `T`, from the most recently pushed one.
+    fn tail_layers<T: Store>(&self) -> impl Iterator<Item = &Layer> {
+        self.tail_indices::<T>()
+            .iter()
+            .rev()
+            .map(|index| self.tail[*index].deref())
+    }
 }
 
 /// Iterator of items returned from [`ConfigBag`].
 pub struct ItemIter<'a, T> {
-    inner: BagIter<'a>,
+    inner: TypedLayerIter<'a>,
     t: PhantomData<T>,
 }
 
@@ -749,6 +823,26 @@ where
     }
 }
 
+/// Iterator over the layers of a config bag that may store a given type: the head, which is mutable and always
+/// visited, followed by the frozen layers at `indices` that are known to store the type.
+struct TypedLayerIter<'a> {
+    head: Option<&'a Layer>,
+    tail: &'a [FrozenLayer],
+    indices: Rev<Iter<'a, usize>>,
+}
+
+impl<'a> Iterator for TypedLayerIter<'a> {
+    type Item = &'a Layer;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(head) = self.head.take() {
+            Some(head)
+        } else {
+            self.indices.next().map(|index| self.tail[*index].deref())
+        }
+    }
+}
+
 /// Iterator over the layers of a config bag
 struct BagIter<'a> {
     head: Option<&'a Layer>,
@@ -1025,4 +1119,135 @@ mod test {
                 .join(" ")
         );
     }
+
+    #[test]
+    fn loads_only_visit_layers_storing_the_type() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Replaced(usize);
+        impl Storable for Replaced {
+            type Storer = StoreReplace<Self>;
+        }
+        #[derive(Debug, PartialEq)]
+        struct Appended(usize);
+        impl Storable for Appended {
+            type Storer = StoreAppend<Self>;
+        }
+        #[derive(Debug)]
+        struct Unrelated;
+        impl Storable for Unrelated {
+            type Storer = StoreReplace<Self>;
+        }
+
+        let mut bag = ConfigBag::base();
+        for i in 0..50 {
+            let mut layer = Layer::new("layer");
+            layer.store_put(Unrelated);
+            if i % 10 == 0 {
+                layer.store_put(Replaced(i));
+                layer.store_append(Appended(i));
+            }
+            bag.push_layer(layer);
+        }
+        assert_eq!(
+            vec![0, 10, 20, 30, 40],
+            bag.tail_indices::<StoreReplace<Replaced>>()
+        );
+        assert_eq!(Some(&Replaced(40)), bag.load::<Replaced>());
+        assert_eq!(
+            vec![40, 30, 20, 10, 0],
+            bag.load::<Appended>().map(|a| a.0).collect::<Vec<_>>()
+        );
+
+        // Layers frozen by `with_fn` are indexed too, and unsetting a value hides the layers below.
+        bag = bag.with_fn("unset", |layer| {
+            layer.unset::<Replaced>();
+            layer.clear::<Appended>();
+        });
+        assert_eq!(None, bag.load::<Replaced>());
+        assert_eq!(0, bag.load::<Appended>().count());
+
+        // The mutable interceptor state is always visited first.
+        bag.interceptor_state().store_put(Replaced(100));
+        assert_eq!(Some(&Replaced(100)), bag.load::<Replaced>());
+        assert_eq!(Some(&mut Replaced(100)), bag.get_mut::<Replaced>());
+    }
+
+    #[cfg(feature = "config-bag-debug")]
+    #[test]
+    fn snapshot_and_diff() {
+        use std::any::type_name;
+
+        #[derive(Clone, Debug)]
+        struct Region(&'static str);
+        impl Storable for Region {
+            type Storer = StoreReplace<Self>;
+        }
+        #[derive(Clone, Debug)]
+        struct Interceptor;
+        impl Storable for Interceptor {
+            type Storer = StoreAppend<Self>;
+        }
+
+        let mut defaults = CloneableLayer::new("defaults");
+        defaults.store_put(Region("us-east-1"));
+        defaults.store_append(Interceptor);
+        let mut client_config = Layer::new("client config");
+        client_config.store_put(Region("us-west-2"));
+
+        let before = ConfigBag::of_layers(vec![defaults.clone().into(), client_config]);
+        let snapshot = before.snapshot();
+        assert_eq!(
+            vec!["interceptor_state", "client config", "defaults"],
+            snapshot
+                .layers()
+                .iter()
+                .map(|layer| layer.name())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![type_name::<Region>()],
+            snapshot.layers()[1]
+                .items()
+                .iter()
+                .map(|item| item.type_name())
+                .collect::<Vec<_>>()
+        );
+        let sources = snapshot.sources_of::<Region>();
+        assert_eq!("us-west-2", before.load::<Region>().unwrap().0);
+        assert_eq!(
+            vec!["client config", "defaults"],
+            sources.iter().map(|s| s.layer()).collect::<Vec<_>>()
+        );
+        assert_eq!(1, snapshot.sources_of::<Interceptor>().len());
+        assert!(before.diff(&before).is_empty());
+
+        let mut operation_config = Layer::new("operation config");
+        operation_config.unset::<Region>();
+        operation_config.clear::<Interceptor>();
+        let after = ConfigBag::of_layers(vec![defaults.into(), operation_config]);
+        let after_snapshot = after.snapshot();
+        assert!(after_snapshot.sources_of::<Region>()[0].is_unset());
+        assert_eq!(
+            format!(
+                "interceptor_state\n\
+                 operation config\n    {interceptor} (unset)\n    {region} (unset)\n\
+                 defaults\n    {interceptor}\n    {region}\n",
+                interceptor = type_name::<Interceptor>(),
+                region = type_name::<Region>(),
+            ),
+            after_snapshot.to_string()
+        );
+
+        let diff = before.diff(&after);
+        assert_eq!(2, diff.changes().len());
+        assert_eq!(
+            format!(
+                "{interceptor}: [defaults] -> [operation config (unset), defaults]\n\
+                 {region}: [client config, defaults] -> [operation config (unset), defaults]\n",
+                interceptor = type_name::<Interceptor>(),
+                region = type_name::<Region>(),
+            ),
+            diff.to_string()
+        );
+    }
 }
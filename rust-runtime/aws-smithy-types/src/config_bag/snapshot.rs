@@ -0,0 +1,274 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::config_bag::{Layer, Storable};
+use std::any::type_name;
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// The type of an item stored in a [`Layer`], and whether the layer explicitly unsets it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StoredItem {
+    type_name: &'static str,
+    unset: bool,
+}
+
+impl StoredItem {
+    pub(super) fn new(type_name: &'static str, unset: bool) -> Self {
+        Self { type_name, unset }
+    }
+
+    /// Returns the name of the stored type, as given by [`std::any::type_name`].
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns true if the layer explicitly unsets (or clears) this type.
+    pub fn is_unset(&self) -> bool {
+        self.unset
+    }
+}
+
+/// A snapshot of the name of a [`Layer`] and the types stored in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayerSnapshot {
+    name: Cow<'static, str>,
+    items: Vec<StoredItem>,
+}
+
+impl LayerSnapshot {
+    pub(super) fn new(layer: &Layer) -> Self {
+        let mut items: Vec<_> = layer.items.values().cloned().collect();
+        items.sort();
+        Self {
+            name: layer.name.clone(),
+            items,
+        }
+    }
+
+    /// Returns the name of the layer.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the items stored in the layer, sorted by type name.
+    pub fn items(&self) -> &[StoredItem] {
+        &self.items
+    }
+
+    fn item(&self, type_name: &str) -> Option<&StoredItem> {
+        self.items.iter().find(|item| item.type_name == type_name)
+    }
+}
+
+/// A layer of a config bag that stores a given type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemSource {
+    layer: Cow<'static, str>,
+    unset: bool,
+}
+
+impl ItemSource {
+    /// Returns the name of the layer.
+    pub fn layer(&self) -> &str {
+        &self.layer
+    }
+
+    /// Returns true if the layer explicitly unsets (or clears) the type.
+    pub fn is_unset(&self) -> bool {
+        self.unset
+    }
+}
+
+impl fmt::Display for ItemSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.unset {
+            write!(f, "{} (unset)", self.layer)
+        } else {
+            f.write_str(&self.layer)
+        }
+    }
+}
+
+/// A snapshot of the layers of a [`ConfigBag`](super::ConfigBag) and the types stored in each of them.
+///
+/// Snapshots only record type names, not values, so they are safe to log. Use them to find out which
+/// layer a value is loaded from:
+///
+/// ```
+/// use aws_smithy_types::config_bag::{ConfigBag, Layer, Storable, StoreReplace};
+///
+/// #[derive(Debug)]
+/// struct Region(&'static str);
+/// impl Storable for Region {
+///     type Storer = StoreReplace<Self>;
+/// }
+///
+/// let mut defaults = Layer::new("defaults");
+/// defaults.store_put(Region("us-east-1"));
+/// let mut client_config = Layer::new("client config");
+/// client_config.store_put(Region("us-west-2"));
+///
+/// let bag = ConfigBag::of_layers(vec![defaults, client_config]);
+/// let sources = bag.snapshot().sources_of::<Region>();
+/// // `Region` is loaded from the first source, which overrides the others
+/// assert_eq!("client config", sources[0].layer());
+/// assert_eq!("defaults", sources[1].layer());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigBagSnapshot {
+    layers: Vec<LayerSnapshot>,
+}
+
+impl ConfigBagSnapshot {
+    pub(super) fn new<'a>(layers: impl Iterator<Item = &'a Layer>) -> Self {
+        Self {
+            layers: layers.map(LayerSnapshot::new).collect(),
+        }
+    }
+
+    /// Returns the layers of the bag, from the one with the highest precedence (the interceptor state)
+    /// to the one with the lowest.
+    pub fn layers(&self) -> &[LayerSnapshot] {
+        &self.layers
+    }
+
+    /// Returns the layers that store the type named `type_name`, in order of precedence.
+    ///
+    /// The first source determines the value of a [`StoreReplace`](super::StoreReplace) type. The items
+    /// of a [`StoreAppend`](super::StoreAppend) type come from every source up to the first one that
+    /// unsets it.
+    pub fn sources(&self, type_name: &str) -> Vec<ItemSource> {
+        self.layers
+            .iter()
+            .filter_map(|layer| {
+                layer.item(type_name).map(|item| ItemSource {
+                    layer: layer.name.clone(),
+                    unset: item.unset,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the layers that store `T`, in order of precedence.
+    ///
+    /// See [`ConfigBagSnapshot::sources`] for details.
+    pub fn sources_of<T: Storable>(&self) -> Vec<ItemSource> {
+        self.sources(type_name::<T>())
+    }
+
+    /// Returns the types whose sources differ between this snapshot and `other`.
+    pub fn diff(&self, other: &ConfigBagSnapshot) -> ConfigBagDiff {
+        let type_names: BTreeSet<_> = self
+            .layers
+            .iter()
+            .chain(other.layers.iter())
+            .flat_map(|layer| layer.items.iter().map(|item| item.type_name))
+            .collect();
+        let changes = type_names
+            .into_iter()
+            .filter_map(|type_name| {
+                let before = self.sources(type_name);
+                let after = other.sources(type_name);
+                (before != after).then_some(TypeDiff {
+                    type_name,
+                    before,
+                    after,
+                })
+            })
+            .collect();
+        ConfigBagDiff { changes }
+    }
+}
+
+impl fmt::Display for ConfigBagSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for layer in &self.layers {
+            writeln!(f, "{}", layer.name)?;
+            for item in &layer.items {
+                if item.unset {
+                    writeln!(f, "    {} (unset)", item.type_name)?;
+                } else {
+                    writeln!(f, "    {}", item.type_name)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The types whose sources differ between two config bags.
+///
+/// Created by [`ConfigBag::diff`](super::ConfigBag::diff) or [`ConfigBagSnapshot::diff`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigBagDiff {
+    changes: Vec<TypeDiff>,
+}
+
+impl ConfigBagDiff {
+    /// Returns true if every type is stored in the same layers of both bags.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the types whose sources differ, sorted by type name.
+    pub fn changes(&self) -> &[TypeDiff] {
+        &self.changes
+    }
+}
+
+impl fmt::Display for ConfigBagDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Sources<'a>(&'a [ItemSource]);
+        impl fmt::Display for Sources<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("[")?;
+                for (index, source) in self.0.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{source}")?;
+                }
+                f.write_str("]")
+            }
+        }
+        for change in &self.changes {
+            writeln!(
+                f,
+                "{}: {} -> {}",
+                change.type_name,
+                Sources(&change.before),
+                Sources(&change.after)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A type whose sources differ between two config bags.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeDiff {
+    type_name: &'static str,
+    before: Vec<ItemSource>,
+    after: Vec<ItemSource>,
+}
+
+impl TypeDiff {
+    /// Returns the name of the type, as given by [`std::any::type_name`].
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the sources of the type in the first bag, in order of precedence.
+    pub fn before(&self) -> &[ItemSource] {
+        &self.before
+    }
+
+    /// Returns the sources of the type in the second bag, in order of precedence.
+    pub fn after(&self) -> &[ItemSource] {
+        &self.after
+    }
+}
@@ -5,6 +5,7 @@
 
 use crate::config_bag::value::Value;
 use crate::config_bag::ItemIter;
+use std::any::type_name;
 use std::fmt::{Debug, Formatter};
 use std::iter::Rev;
 use std::marker::PhantomData;
@@ -77,6 +78,36 @@ impl<U: Send + Sync + Debug + 'static> Store for StoreAppend<U> {
     }
 }
 
+/// Describes the items of a [`Store`] so that layers can record what they hold for snapshots
+pub(crate) trait DescribeStore: Store {
+    /// Returns the name of the type of the items stored
+    #[cfg_attr(not(feature = "config-bag-debug"), allow(dead_code))]
+    fn item_type_name() -> &'static str;
+
+    /// Returns true if `value` marks the items as explicitly unset
+    fn is_unset(value: &Self::StoredType) -> bool;
+}
+
+impl<U: Send + Sync + Debug + 'static> DescribeStore for StoreReplace<U> {
+    fn item_type_name() -> &'static str {
+        type_name::<U>()
+    }
+
+    fn is_unset(value: &Self::StoredType) -> bool {
+        matches!(value, Value::ExplicitlyUnset(_))
+    }
+}
+
+impl<U: Send + Sync + Debug + 'static> DescribeStore for StoreAppend<U> {
+    fn item_type_name() -> &'static str {
+        type_name::<U>()
+    }
+
+    fn is_unset(value: &Self::StoredType) -> bool {
+        matches!(value, Value::ExplicitlyUnset(_))
+    }
+}
+
 /// Iterator of items returned by [`StoreAppend`]
 pub struct AppendItemIter<'a, U> {
     inner: ItemIter<'a, StoreAppend<U>>,
@@ -260,6 +260,37 @@ pub(crate) mod http_date {
         parse_imf_fixdate(x)
     }
 
+    /// Parse an HTTP date in any of the formats RFC 7231 requires recipients to accept
+    ///
+    /// In addition to IMF-fixdate, this accepts the obsolete RFC 850 and asctime formats, `UTC` in place
+    /// of `GMT`, and surrounding whitespace. Two-digit RFC 850 years from `70` to `99` are in the 1900s,
+    /// and the others in the 2000s.
+    ///
+    /// Ok: "Mon, 16 Dec 2019 23:48:18 GMT"
+    /// Ok: "Mon, 16 Dec 2019 23:48:18 UTC"
+    /// Ok: "Monday, 16-Dec-19 23:48:18 GMT"
+    /// Ok: "Mon Dec 16 23:48:18 2019"
+    /// Ok: "Mon Dec  6 23:48:18 2019"
+    pub(crate) fn parse_lenient(s: &str) -> Result<DateTime, DateTimeParseError> {
+        if !s.is_ascii() {
+            return Err(DateTimeParseErrorKind::Invalid("date-time must be ASCII".into()).into());
+        }
+        let s = s.trim();
+        let gmt = match s.strip_suffix(" UTC") {
+            Some(date) => format!("{} GMT", date),
+            None => s.to_owned(),
+        };
+        parse_imf_fixdate(gmt.as_bytes())
+            .or_else(|_| parse_rfc850(&gmt))
+            .or_else(|_| parse_asctime(s))
+            .map_err(|_| {
+                DateTimeParseErrorKind::Invalid(
+                    "not an IMF-fixdate, RFC 850 or asctime HTTP date".into(),
+                )
+                .into()
+            })
+    }
+
     pub(crate) fn read(s: &str) -> Result<(DateTime, &str), DateTimeParseError> {
         if !s.is_ascii() {
             return Err(DateTimeParseErrorKind::Invalid("date-time must be ASCII".into()).into());
@@ -365,6 +396,92 @@ pub(crate) mod http_date {
             .expect("this date format cannot produce out of range date-times"))
     }
 
+    // Example: `Sunday, 06-Nov-94 08:49:37 GMT`
+    fn parse_rfc850(s: &str) -> Result<DateTime, DateTimeParseError> {
+        let date_time = s
+            .split_once(", ")
+            .and_then(|(_weekday, date_time)| date_time.strip_suffix(" GMT"));
+        let (date, time) = date_time
+            .and_then(|date_time| date_time.split_once(' '))
+            .ok_or_else(|| incorrectly_shaped("RFC 850"))?;
+        let (day, month, year) = match date.split('-').collect::<Vec<_>>()[..] {
+            [day, month, year] if day.len() == 2 && year.len() == 2 => (day, month, year),
+            _ => return Err(incorrectly_shaped("RFC 850")),
+        };
+        let year: i32 = parse_slice(year.as_bytes())?;
+        let year = if year >= 70 { 1900 + year } else { 2000 + year };
+        from_components(year, month, parse_slice(day.as_bytes())?, time)
+    }
+
+    // Example: `Sun Nov  6 08:49:37 1994`
+    fn parse_asctime(s: &str) -> Result<DateTime, DateTimeParseError> {
+        match s.split_whitespace().collect::<Vec<_>>()[..] {
+            [_weekday, month, day, time, year] if day.len() <= 2 && year.len() == 4 => {
+                from_components(
+                    parse_slice(year.as_bytes())?,
+                    month,
+                    parse_slice(day.as_bytes())?,
+                    time,
+                )
+            }
+            _ => Err(incorrectly_shaped("asctime")),
+        }
+    }
+
+    fn incorrectly_shaped(format: &str) -> DateTimeParseError {
+        DateTimeParseErrorKind::Invalid(format!("incorrectly shaped {} date", format).into()).into()
+    }
+
+    /// Builds a `DateTime` from a year, a three-letter month name, a day and a `HH:MM:SS` time of day in GMT.
+    fn from_components(
+        year: i32,
+        month: &str,
+        day: u8,
+        time: &str,
+    ) -> Result<DateTime, DateTimeParseError> {
+        let month = match month {
+            "Jan" => Month::January,
+            "Feb" => Month::February,
+            "Mar" => Month::March,
+            "Apr" => Month::April,
+            "May" => Month::May,
+            "Jun" => Month::June,
+            "Jul" => Month::July,
+            "Aug" => Month::August,
+            "Sep" => Month::September,
+            "Oct" => Month::October,
+            "Nov" => Month::November,
+            "Dec" => Month::December,
+            month => {
+                return Err(DateTimeParseErrorKind::Invalid(
+                    format!("invalid month: {}", month).into(),
+                )
+                .into())
+            }
+        };
+        let time = match time.as_bytes() {
+            [h1, h2, b':', m1, m2, b':', s1, s2] => Time::from_hms(
+                parse_slice(&[*h1, *h2])?,
+                parse_slice(&[*m1, *m2])?,
+                parse_slice(&[*s1, *s2])?,
+            )
+            .map_err(|err| {
+                DateTimeParseErrorKind::Invalid(
+                    format!("time components are out of range: {}", err).into(),
+                )
+            })?,
+            _ => return Err(DateTimeParseErrorKind::Invalid("invalid time of day".into()).into()),
+        };
+        let date = Date::from_calendar_date(year, month, day).map_err(|err| {
+            DateTimeParseErrorKind::Invalid(
+                format!("date components are out of range: {}", err).into(),
+            )
+        })?;
+        let date_time = PrimitiveDateTime::new(date, time).assume_offset(UtcOffset::UTC);
+        Ok(DateTime::from_nanos(date_time.unix_timestamp_nanos())
+            .expect("this date format cannot produce out of range date-times"))
+    }
+
     fn parse_slice<T>(ascii_slice: &[u8]) -> Result<T, DateTimeParseError>
     where
         T: FromStr,
@@ -400,6 +517,14 @@ pub(crate) mod rfc3339 {
         s: &str,
         allow_offsets: AllowOffsets,
     ) -> Result<DateTime, DateTimeParseError> {
+        parse_with_offset(s, allow_offsets).map(|(date_time, _offset_minutes)| date_time)
+    }
+
+    /// Parses an RFC-3339 date-time, returning it along with its UTC offset in minutes.
+    pub(crate) fn parse_with_offset(
+        s: &str,
+        allow_offsets: AllowOffsets,
+    ) -> Result<(DateTime, i16), DateTimeParseError> {
         if allow_offsets == AllowOffsets::OffsetsForbidden && !matches!(s.chars().last(), Some('Z'))
         {
             return Err(DateTimeParseErrorKind::Invalid(
@@ -416,8 +541,25 @@ pub(crate) mod rfc3339 {
         let date_time = OffsetDateTime::parse(s, &Rfc3339).map_err(|err| {
             DateTimeParseErrorKind::Invalid(format!("invalid RFC-3339 date-time: {}", err).into())
         })?;
-        Ok(DateTime::from_nanos(date_time.unix_timestamp_nanos())
-            .expect("this date format cannot produce out of range date-times"))
+        Ok((
+            DateTime::from_nanos(date_time.unix_timestamp_nanos())
+                .expect("this date format cannot produce out of range date-times"),
+            date_time.offset().whole_minutes(),
+        ))
+    }
+
+    /// Parses an RFC-3339 date-time with any offset, also accepting a space as the date and time
+    /// separator, as ISO-8601 and SQL timestamps commonly use, and surrounding whitespace.
+    pub(crate) fn parse_lenient(s: &str) -> Result<DateTime, DateTimeParseError> {
+        let s = s.trim();
+        if s.len() > 10 && s.as_bytes()[10] == b' ' {
+            parse(
+                &format!("{}T{}", &s[..10], &s[11..]),
+                AllowOffsets::OffsetsAllowed,
+            )
+        } else {
+            parse(s, AllowOffsets::OffsetsAllowed)
+        }
     }
 
     /// Read 1 RFC-3339 date from &str and return the remaining str
@@ -432,6 +574,16 @@ pub(crate) mod rfc3339 {
 
     /// Format a [DateTime] in the RFC-3339 date format
     pub(crate) fn format(date_time: &DateTime) -> Result<String, DateTimeFormatError> {
+        format_with_offset(date_time, 0)
+    }
+
+    /// Format a [DateTime] in the RFC-3339 date format, as the local time at the given UTC offset in minutes
+    ///
+    /// The offset must be less than 24 hours. A zero offset is formatted as `Z`.
+    pub(crate) fn format_with_offset(
+        date_time: &DateTime,
+        offset_minutes: i16,
+    ) -> Result<String, DateTimeFormatError> {
         use std::fmt::Write;
         fn out_of_range<E: std::fmt::Display>(cause: E) -> DateTimeFormatError {
             DateTimeFormatErrorKind::OutOfRange(
@@ -444,9 +596,10 @@ pub(crate) mod rfc3339 {
             )
             .into()
         }
+        // Shifting the timestamp by the offset gives the local time as if it were UTC.
+        let local_nanos = date_time.as_nanos() + i128::from(offset_minutes) * 60 * 1_000_000_000;
         let (year, month, day, hour, minute, second, micros) = {
-            let s = OffsetDateTime::from_unix_timestamp_nanos(date_time.as_nanos())
-                .map_err(out_of_range)?;
+            let s = OffsetDateTime::from_unix_timestamp_nanos(local_nanos).map_err(out_of_range)?;
             (
                 s.year(),
                 u8::from(s.month()),
@@ -472,7 +625,20 @@ pub(crate) mod rfc3339 {
         )
         .unwrap();
         format_subsecond_fraction(&mut out, micros);
-        out.push('Z');
+        if offset_minutes == 0 {
+            out.push('Z');
+        } else {
+            let sign = if offset_minutes < 0 { '-' } else { '+' };
+            let offset_minutes = offset_minutes.unsigned_abs();
+            write!(
+                out,
+                "{}{:02}:{:02}",
+                sign,
+                offset_minutes / 60,
+                offset_minutes % 60
+            )
+            .unwrap();
+        }
         Ok(out)
     }
 
@@ -816,6 +982,75 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_http_date_lenient() {
+        let expected = DateTime::from_secs(784111777);
+        for date in [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "  Sun, 06 Nov 1994 08:49:37 UTC ",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+            "Sun Nov 06 08:49:37 1994",
+        ] {
+            assert_eq!(expected, http_date::parse_lenient(date).unwrap(), "{date}");
+        }
+        assert_eq!(
+            DateTime::from_secs(1576540098),
+            http_date::parse_lenient("Monday, 16-Dec-19 23:48:18 GMT").unwrap()
+        );
+        for date in [
+            "Sunday, 06-Nov-1994 08:49:37 GMT",
+            "Sun Nov  6 08:49:37",
+            "Sun Nov 31 08:49:37 1994",
+            "Sun Foo  6 08:49:37 1994",
+            "Sun Nov  6 8:49:37 1994",
+            "Sun, 06 Nov 1994 08:49:37 PST",
+        ] {
+            assert!(http_date::parse_lenient(date).is_err(), "{date}");
+        }
+    }
+
+    #[test]
+    fn parse_rfc3339_lenient() {
+        let expected = DateTime::from_secs_and_nanos(482196051, 520_000_000);
+        for date in [
+            "1985-04-12T23:20:51.52Z",
+            "1985-04-12 23:20:51.52Z",
+            " 1985-04-12 21:20:51.52-02:00 ",
+        ] {
+            assert_eq!(expected, rfc3339::parse_lenient(date).unwrap(), "{date}");
+        }
+        assert!(rfc3339::parse_lenient("1985-04-12x23:20:51Z").is_err());
+    }
+
+    #[test]
+    fn format_rfc3339_with_offset() {
+        let date_time = DateTime::from_secs_and_nanos(1576540098, 520_000_000);
+        assert_eq!(
+            "2019-12-16T23:48:18.52Z",
+            rfc3339::format_with_offset(&date_time, 0).unwrap()
+        );
+        assert_eq!(
+            "2019-12-17T05:33:18.52+05:45",
+            rfc3339::format_with_offset(&date_time, 345).unwrap()
+        );
+        assert_eq!(
+            "2019-12-16T14:18:18.52-09:30",
+            rfc3339::format_with_offset(&date_time, -570).unwrap()
+        );
+        let (parsed, offset) = rfc3339::parse_with_offset(
+            "2019-12-16T14:18:18.52-09:30",
+            AllowOffsets::OffsetsAllowed,
+        )
+        .unwrap();
+        assert_eq!((date_time, -570), (parsed, offset));
+
+        // The local date must be representable, even when the UTC date is.
+        let last = DateTime::from_secs(253402300799);
+        assert!(rfc3339::format_with_offset(&last, -60).is_ok());
+        assert!(rfc3339::format_with_offset(&last, 60).is_err());
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(10000))]
 
@@ -17,10 +17,13 @@ use std::time::Duration;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
-#[cfg(all(aws_sdk_unstable, feature = "serde-deserialize"))]
+#[cfg(any(
+    feature = "serde",
+    all(aws_sdk_unstable, feature = "serde-deserialize")
+))]
 mod de;
 mod format;
-#[cfg(all(aws_sdk_unstable, feature = "serde-serialize"))]
+#[cfg(any(feature = "serde", all(aws_sdk_unstable, feature = "serde-serialize")))]
 mod ser;
 
 pub use self::format::DateTimeFormatError;
@@ -175,6 +178,37 @@ impl DateTime {
         }
     }
 
+    /// Parses a `DateTime` from a string using the given `format`, accepting variations of it seen in the wild.
+    ///
+    /// - [`Format::DateTime`] and [`Format::DateTimeWithOffset`] accept any UTC offset and a space as the
+    ///   date and time separator, e.g. `2019-12-16 23:48:18+01:00`.
+    /// - [`Format::HttpDate`] also accepts the obsolete RFC 850 and asctime formats that RFC-7231 requires
+    ///   recipients to accept, e.g. `Monday, 16-Dec-19 23:48:18 GMT` and `Mon Dec 16 23:48:18 2019`,
+    ///   as well as `UTC` in place of `GMT`.
+    /// - [`Format::EpochSeconds`] is parsed as with [`DateTime::from_str`].
+    ///
+    /// Surrounding whitespace is ignored for all formats.
+    pub fn from_str_lenient(s: &str, format: Format) -> Result<Self, DateTimeParseError> {
+        match format {
+            Format::DateTime | Format::DateTimeWithOffset => format::rfc3339::parse_lenient(s),
+            Format::HttpDate => format::http_date::parse_lenient(s),
+            Format::EpochSeconds => format::epoch_seconds::parse(s.trim()),
+        }
+    }
+
+    /// Parses an RFC-3339 date-time, returning it along with the UTC offset it was expressed in.
+    ///
+    /// ```rust
+    /// # use aws_smithy_types::date_time::{DateTime, UtcOffset};
+    /// let (date_time, offset) = DateTime::from_str_with_offset("2019-12-17T01:18:18+01:30").unwrap();
+    /// assert_eq!(DateTime::from_secs(1576540098), date_time);
+    /// assert_eq!(90, offset.whole_minutes());
+    /// ```
+    pub fn from_str_with_offset(s: &str) -> Result<(Self, UtcOffset), DateTimeParseError> {
+        format::rfc3339::parse_with_offset(s, AllowOffsets::OffsetsAllowed)
+            .map(|(date_time, minutes)| (date_time, UtcOffset { minutes }))
+    }
+
     /// Returns true if sub-second nanos is greater than zero.
     pub fn has_subsec_nanos(&self) -> bool {
         self.subsecond_nanos != 0
@@ -261,6 +295,33 @@ impl DateTime {
             Format::HttpDate => format::http_date::format(self),
         }
     }
+
+    /// Formats the `DateTime` as an RFC-3339 date-time in the local time of the given `offset`.
+    ///
+    /// Returns an error if the local date is outside of the years 0001 to 9999.
+    ///
+    /// ```rust
+    /// # use aws_smithy_types::date_time::{DateTime, UtcOffset};
+    /// let date_time = DateTime::from_secs(1576540098);
+    /// let offset = UtcOffset::from_minutes(-300).unwrap();
+    /// assert_eq!("2019-12-16T18:48:18-05:00", date_time.fmt_with_offset(offset).unwrap());
+    /// ```
+    pub fn fmt_with_offset(&self, offset: UtcOffset) -> Result<String, DateTimeFormatError> {
+        format::rfc3339::format_with_offset(self, offset.minutes)
+    }
+
+    /// Returns this `DateTime` advanced by `duration`, or an error if the result is out of range.
+    pub fn checked_add(self, duration: Duration) -> Result<Self, DateTimeOverflowError> {
+        // A `Duration` is at most about 1.8e28 nanos, so this can't overflow an `i128`.
+        Self::from_nanos(self.as_nanos() + duration.as_nanos() as i128)
+            .map_err(|_| DateTimeOverflowError(()))
+    }
+
+    /// Returns this `DateTime` moved back by `duration`, or an error if the result is out of range.
+    pub fn checked_sub(self, duration: Duration) -> Result<Self, DateTimeOverflowError> {
+        Self::from_nanos(self.as_nanos() - duration.as_nanos() as i128)
+            .map_err(|_| DateTimeOverflowError(()))
+    }
 }
 
 /// Tries to convert a [`DateTime`] into a [`SystemTime`].
@@ -367,6 +428,46 @@ impl fmt::Display for ConversionError {
     }
 }
 
+/// Failure to add a [`Duration`] to, or subtract it from, a `DateTime` because the result is out of range.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct DateTimeOverflowError(());
+
+impl StdError for DateTimeOverflowError {}
+
+impl fmt::Display for DateTimeOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "date-time arithmetic overflowed")
+    }
+}
+
+/// An offset from UTC with minute precision, as used by RFC-3339 date-times.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct UtcOffset {
+    minutes: i16,
+}
+
+impl UtcOffset {
+    /// The UTC offset itself, formatted as `Z`.
+    pub const UTC: Self = Self { minutes: 0 };
+
+    /// Creates an offset of `minutes` east of UTC, or west of it if negative.
+    ///
+    /// Returns an error if the offset isn't strictly between -24 and 24 hours.
+    pub fn from_minutes(minutes: i16) -> Result<Self, ConversionError> {
+        if minutes.unsigned_abs() < 24 * 60 {
+            Ok(Self { minutes })
+        } else {
+            Err(ConversionError("UTC offsets must be less than 24 hours"))
+        }
+    }
+
+    /// Returns the number of minutes of this offset east of UTC, negative if west of it.
+    pub fn whole_minutes(&self) -> i16 {
+        self.minutes
+    }
+}
+
 /// Formats for representing a `DateTime` in the Smithy protocols.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Format {
@@ -389,9 +490,10 @@ pub enum Format {
 
 #[cfg(test)]
 mod test {
-    use crate::date_time::Format;
+    use crate::date_time::{Format, UtcOffset};
     use crate::DateTime;
     use proptest::proptest;
+    use std::time::Duration;
     use std::time::SystemTime;
     use time::format_description::well_known::Rfc3339;
     use time::OffsetDateTime;
@@ -687,6 +789,69 @@ mod test {
     }
 
     /// https://github.com/smithy-lang/smithy-rs/issues/3805
+    #[test]
+    fn checked_arithmetic() {
+        let date_time = DateTime::from_secs_and_nanos(10, 500_000_000);
+        assert_eq!(
+            DateTime::from_secs(11),
+            date_time.checked_add(Duration::from_millis(500)).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_secs_and_nanos(-1, 999_999_999),
+            date_time
+                .checked_sub(Duration::new(10, 500_000_001))
+                .unwrap()
+        );
+
+        let max = DateTime::from_secs_and_nanos(i64::MAX, 999_999_999);
+        assert!(max.checked_add(Duration::from_nanos(1)).is_err());
+        assert!(DateTime::from_secs(i64::MIN)
+            .checked_sub(Duration::from_nanos(1))
+            .is_err());
+        assert!(max.checked_add(Duration::MAX).is_err());
+        assert_eq!(
+            "date-time arithmetic overflowed",
+            max.checked_add(Duration::MAX).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn utc_offsets() {
+        assert_eq!(0, UtcOffset::UTC.whole_minutes());
+        assert_eq!(
+            -1439,
+            UtcOffset::from_minutes(-1439).unwrap().whole_minutes()
+        );
+        assert!(UtcOffset::from_minutes(1440).is_err());
+        assert!(UtcOffset::from_minutes(i16::MIN).is_err());
+
+        let date_time = DateTime::from_secs(1576540098);
+        let offset = UtcOffset::from_minutes(60).unwrap();
+        let formatted = date_time.fmt_with_offset(offset).unwrap();
+        assert_eq!("2019-12-17T00:48:18+01:00", formatted);
+        assert_eq!(
+            (date_time, offset),
+            DateTime::from_str_with_offset(&formatted).unwrap()
+        );
+        assert_eq!(
+            "2019-12-16T23:48:18Z",
+            date_time.fmt_with_offset(UtcOffset::UTC).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_str_lenient() {
+        let date_time = DateTime::from_secs(1576540098);
+        for (date, format) in [
+            ("2019-12-17 00:48:18+01:00", Format::DateTime),
+            ("2019-12-16T23:48:18Z", Format::DateTimeWithOffset),
+            ("Mon Dec 16 23:48:18 2019", Format::HttpDate),
+            (" 1576540098 ", Format::EpochSeconds),
+        ] {
+            assert_eq!(date_time, DateTime::from_str_lenient(date, format).unwrap());
+        }
+    }
+
     #[test]
     fn panic_in_fromsecs_f64() {
         assert_eq!(DateTime::from_secs_f64(-1.0), DateTime::from_secs(-1));
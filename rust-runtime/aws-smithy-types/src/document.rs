@@ -7,12 +7,6 @@ use crate::Number;
 use std::borrow::Cow;
 use std::collections::HashMap;
 
-#[cfg(any(
-    all(aws_sdk_unstable, feature = "serde-deserialize"),
-    all(aws_sdk_unstable, feature = "serde-serialize")
-))]
-use serde;
-
 /* ANCHOR: document */
 
 /// Document Type
@@ -21,22 +15,9 @@ use serde;
 /// Open content is useful for modeling unstructured data that has no schema, data that can't be
 /// modeled using rigid types, or data that has a schema that evolves outside of the purview of a model.
 /// The serialization format of a document is an implementation detail of a protocol.
+///
+/// With the `serde` feature, documents serialize as the JSON value they represent.
 #[derive(Clone, Debug, PartialEq)]
-#[cfg_attr(
-    all(aws_sdk_unstable, feature = "serde-serialize"),
-    derive(serde::Serialize)
-)]
-#[cfg_attr(
-    all(aws_sdk_unstable, feature = "serde-deserialize"),
-    derive(serde::Deserialize)
-)]
-#[cfg_attr(
-    any(
-        all(aws_sdk_unstable, feature = "serde-deserialize"),
-        all(aws_sdk_unstable, feature = "serde-serialize")
-    ),
-    serde(untagged)
-)]
 pub enum Document {
     /// JSON object
     Object(HashMap<String, Document>),
@@ -231,14 +212,172 @@ impl From<Number> for Document {
 
 /* ANCHOR END: document */
 
+#[cfg(any(feature = "serde", all(aws_sdk_unstable, feature = "serde-serialize")))]
+mod serde_serialize {
+    use super::Document;
+    use serde::ser::{SerializeMap, SerializeSeq};
+    use serde::{Serialize, Serializer};
+
+    impl Serialize for Document {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Document::Object(object) => {
+                    let mut map = serializer.serialize_map(Some(object.len()))?;
+                    for (key, value) in object {
+                        map.serialize_entry(key, value)?;
+                    }
+                    map.end()
+                }
+                Document::Array(array) => {
+                    let mut seq = serializer.serialize_seq(Some(array.len()))?;
+                    for value in array {
+                        seq.serialize_element(value)?;
+                    }
+                    seq.end()
+                }
+                Document::Number(number) => number.serialize(serializer),
+                Document::String(string) => serializer.serialize_str(string),
+                Document::Bool(boolean) => serializer.serialize_bool(*boolean),
+                Document::Null => serializer.serialize_unit(),
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "serde",
+    all(aws_sdk_unstable, feature = "serde-deserialize")
+))]
+mod serde_deserialize {
+    use super::Document;
+    use crate::Number;
+    use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+    use serde::{Deserialize, Deserializer};
+    use std::collections::HashMap;
+    use std::fmt;
+
+    struct DocumentVisitor;
+
+    impl<'de> Visitor<'de> for DocumentVisitor {
+        type Value = Document;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("any JSON value")
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Document::Bool(v))
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Document::Number(match u64::try_from(v) {
+                Ok(v) => Number::PosInt(v),
+                Err(_) => Number::NegInt(v),
+            }))
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Document::Number(Number::PosInt(v)))
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Document::Number(Number::Float(v)))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Document::String(v.to_owned()))
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Document::String(v))
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Document::Null)
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Document::Null)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Document::deserialize(deserializer)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut array = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+            while let Some(value) = seq.next_element()? {
+                array.push(value);
+            }
+            Ok(Document::Array(array))
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut object = HashMap::with_capacity(map.size_hint().unwrap_or_default());
+            while let Some((key, value)) = map.next_entry()? {
+                object.insert(key, value);
+            }
+            Ok(Document::Object(object))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Document {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(DocumentVisitor)
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     /// checks if a) serialization of json suceeds and b) it is compatible with serde_json
     #[test]
-    #[cfg(all(
-        aws_sdk_unstable,
-        feature = "serde-serialize",
-        feature = "serde-deserialize"
+    #[cfg(any(
+        feature = "serde",
+        all(
+            aws_sdk_unstable,
+            feature = "serde-serialize",
+            feature = "serde-deserialize"
+        )
     ))]
     fn serialize_json() {
         use crate::Document;
@@ -248,15 +387,9 @@ mod test {
         // string
         map.insert("hello".into(), "world".to_string().into());
         // numbers
-        map.insert("pos_int".into(), Document::Number(Number::PosInt(1).into()));
-        map.insert(
-            "neg_int".into(),
-            Document::Number(Number::NegInt(-1).into()),
-        );
-        map.insert(
-            "float".into(),
-            Document::Number(Number::Float(0.1 + 0.2).into()),
-        );
+        map.insert("pos_int".into(), Document::Number(Number::PosInt(1)));
+        map.insert("neg_int".into(), Document::Number(Number::NegInt(-1)));
+        map.insert("float".into(), Document::Number(Number::Float(0.1 + 0.2)));
         // booleans
         map.insert("true".into(), true.into());
         map.insert("false".into(), false.into());
@@ -284,4 +417,35 @@ mod test {
         let doc: Result<Document, _> = serde_json::from_str(target_file);
         assert_eq!(obj, doc.unwrap());
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn document_serde_round_trip() {
+        use crate::Document;
+        use crate::Number;
+
+        let doc = Document::Object(
+            [
+                ("string".to_string(), "value".into()),
+                ("pos_int".to_string(), u64::MAX.into()),
+                ("neg_int".to_string(), Number::NegInt(-7).into()),
+                ("float".to_string(), 2.5.into()),
+                (
+                    "array".to_string(),
+                    vec![true.into(), Document::Null, Document::Array(vec![])].into(),
+                ),
+                ("object".to_string(), Document::Object(Default::default())),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let json = serde_json::to_string(&doc).unwrap();
+        assert_eq!(doc, serde_json::from_str::<Document>(&json).unwrap());
+
+        let mut cbor = vec![];
+        ciborium::ser::into_writer(&doc, &mut cbor).unwrap();
+        let de: Document = ciborium::de::from_reader(cbor.as_slice()).unwrap();
+        assert_eq!(doc, de);
+    }
 }
@@ -8,6 +8,19 @@
 use crate::retry::{ErrorKind, ProvideErrorKind};
 use std::collections::HashMap;
 use std::fmt;
+use std::str::FromStr;
+
+/// Well-known keys of the [extras](ErrorMetadata::extras) of [`ErrorMetadata`].
+///
+/// The generic error parsers populate these keys when the information is present in an error
+/// response, regardless of the protocol it was received with. Services can add their own keys
+/// with [`Builder::custom`].
+pub mod keys {
+    /// Whether the error was caused by the sender of the request or by the receiver, given as
+    /// `Sender` or `Receiver`. This is the `Type` of an XML error, or the fault of an
+    /// `x-amzn-query-error` header.
+    pub const ERROR_TYPE: &str = "type";
+}
 
 /// Trait to retrieve error metadata from a result
 pub trait ProvideErrorMetadata {
@@ -96,14 +109,37 @@ impl Builder {
     /// }
     /// ```
     pub fn custom(mut self, key: &'static str, value: impl Into<String>) -> Self {
-        if self.inner.extras.is_none() {
-            self.inner.extras = Some(HashMap::new());
+        self.set_custom(key, Some(value));
+        self
+    }
+
+    /// Sets or removes a custom field on the error metadata
+    ///
+    /// Values that aren't strings can be stored with their [`Display`](fmt::Display)
+    /// representation, and read back with [`ErrorMetadata::extra_as`]:
+    /// ```rust
+    /// use aws_smithy_types::error::ErrorMetadata;
+    /// const RETRY_AFTER_SECS: &str = "retry_after_secs";
+    ///
+    /// let mut builder = ErrorMetadata::builder();
+    /// builder.set_custom(RETRY_AFTER_SECS, Some(30.to_string()));
+    /// let err = builder.build();
+    /// assert_eq!(Some(Ok(30)), err.extra_as::<u64>(RETRY_AFTER_SECS));
+    /// ```
+    pub fn set_custom(&mut self, key: &'static str, value: Option<impl Into<String>>) -> &mut Self {
+        match value {
+            Some(value) => {
+                self.inner
+                    .extras
+                    .get_or_insert_with(HashMap::new)
+                    .insert(key, value.into());
+            }
+            None => {
+                if let Some(extras) = self.inner.extras.as_mut() {
+                    extras.remove(key);
+                }
+            }
         }
-        self.inner
-            .extras
-            .as_mut()
-            .unwrap()
-            .insert(key, value.into());
         self
     }
 
@@ -123,12 +159,28 @@ impl ErrorMetadata {
         self.message.as_deref()
     }
     /// Returns additional information about the error if it's present.
-    pub fn extra(&self, key: &'static str) -> Option<&str> {
+    ///
+    /// See [`keys`] for the keys that the generic error parsers populate.
+    pub fn extra(&self, key: &str) -> Option<&str> {
         self.extras
             .as_ref()
             .and_then(|extras| extras.get(key).map(|k| k.as_str()))
     }
 
+    /// Parses additional information about the error if it's present.
+    ///
+    /// Returns `None` if `key` isn't set, and an error if its value can't be parsed as a `T`.
+    pub fn extra_as<T: FromStr>(&self, key: &str) -> Option<Result<T, T::Err>> {
+        self.extra(key).map(str::parse)
+    }
+
+    /// Returns an iterator over all the additional information about the error, in no particular order.
+    pub fn extras(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.extras
+            .iter()
+            .flat_map(|extras| extras.iter().map(|(k, v)| (*k, v.as_str())))
+    }
+
     /// Creates an `Error` builder.
     pub fn builder() -> Builder {
         Builder::default()
@@ -169,3 +221,36 @@ impl fmt::Display for ErrorMetadata {
 }
 
 impl std::error::Error for ErrorMetadata {}
+
+#[cfg(test)]
+mod test {
+    use super::{keys, ErrorMetadata};
+
+    #[test]
+    fn extras() {
+        let mut builder = ErrorMetadata::builder()
+            .code("ThrottlingException")
+            .custom(keys::ERROR_TYPE, "Sender")
+            .custom("quota_reset_at", "1700000000")
+            .custom("request_id", "abc");
+        builder.set_custom("request_id", None::<String>);
+        let err = builder.build();
+
+        assert_eq!(Some("Sender"), err.extra(keys::ERROR_TYPE));
+        assert_eq!(None, err.extra("request_id"));
+        assert_eq!(
+            Some(Ok(1_700_000_000)),
+            err.extra_as::<u64>("quota_reset_at")
+        );
+        assert!(err.extra_as::<u64>(keys::ERROR_TYPE).unwrap().is_err());
+        assert_eq!(None, err.extra_as::<u64>("missing"));
+
+        let mut extras: Vec<_> = err.extras().collect();
+        extras.sort();
+        assert_eq!(
+            vec![("quota_reset_at", "1700000000"), ("type", "Sender")],
+            extras
+        );
+        assert_eq!(0, ErrorMetadata::builder().build().extras().count());
+    }
+}
@@ -18,6 +18,7 @@
 )]
 
 pub mod base64;
+pub mod big_number;
 pub mod body;
 pub mod byte_stream;
 pub mod checksum_config;
@@ -30,6 +31,7 @@ pub mod event_stream;
 pub mod primitive;
 pub mod retry;
 pub mod timeout;
+pub mod units;
 
 /// Utilities for type erasure.
 pub mod type_erasure;
@@ -39,6 +41,7 @@ mod document;
 mod number;
 pub mod str_bytes;
 
+pub use big_number::{BigDecimal, BigInteger};
 pub use blob::Blob;
 pub use date_time::DateTime;
 pub use document::Document;
@@ -6,30 +6,14 @@
 //! A number type that implements Javascript / JSON semantics.
 
 use crate::error::{TryFromNumberError, TryFromNumberErrorKind};
-#[cfg(all(
-    aws_sdk_unstable,
-    any(feature = "serde-serialize", feature = "serde-deserialize")
-))]
-use serde;
 
 /// A number type that implements Javascript / JSON semantics, modeled on serde_json:
 /// <https://docs.serde.rs/src/serde_json/number.rs.html#20-22>
+///
+/// With the `serde` feature, numbers serialize as plain JSON numbers. Since JSON can't represent
+/// them, non-finite floats serialize as the strings `"NaN"`, `"Infinity"` and `"-Infinity"` in
+/// human-readable formats, like the Smithy JSON protocols do.
 #[derive(Debug, Clone, Copy, PartialEq)]
-#[cfg_attr(
-    all(aws_sdk_unstable, feature = "serde-deserialize"),
-    derive(serde::Deserialize)
-)]
-#[cfg_attr(
-    all(aws_sdk_unstable, feature = "serde-serialize"),
-    derive(serde::Serialize)
-)]
-#[cfg_attr(
-    any(
-        all(aws_sdk_unstable, feature = "serde-deserialize"),
-        all(aws_sdk_unstable, feature = "serde-serialize")
-    ),
-    serde(untagged)
-)]
 pub enum Number {
     /// Unsigned 64-bit integer value.
     PosInt(u64),
@@ -208,6 +192,97 @@ impl TryFrom<Number> for f32 {
     }
 }
 
+#[cfg(any(feature = "serde", all(aws_sdk_unstable, feature = "serde-serialize")))]
+mod serde_serialize {
+    use super::Number;
+    use serde::{Serialize, Serializer};
+
+    impl Serialize for Number {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match *self {
+                Number::PosInt(v) => serializer.serialize_u64(v),
+                Number::NegInt(v) => serializer.serialize_i64(v),
+                Number::Float(v) if v.is_finite() || !serializer.is_human_readable() => {
+                    serializer.serialize_f64(v)
+                }
+                Number::Float(v) if v.is_nan() => serializer.serialize_str("NaN"),
+                Number::Float(v) if v.is_sign_positive() => serializer.serialize_str("Infinity"),
+                Number::Float(_) => serializer.serialize_str("-Infinity"),
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "serde",
+    all(aws_sdk_unstable, feature = "serde-deserialize")
+))]
+mod serde_deserialize {
+    use super::Number;
+    use serde::de::{Error, Visitor};
+    use serde::{Deserialize, Deserializer};
+    use std::fmt;
+
+    struct NumberVisitor;
+
+    impl<'de> Visitor<'de> for NumberVisitor {
+        type Value = Number;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a number, or one of `NaN`, `Infinity` and `-Infinity`")
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            // Non-negative integers are always `PosInt`, regardless of how the format represents them.
+            Ok(match u64::try_from(v) {
+                Ok(v) => Number::PosInt(v),
+                Err(_) => Number::NegInt(v),
+            })
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Number::PosInt(v))
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(Number::Float(v))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            match v {
+                "NaN" => Ok(Number::Float(f64::NAN)),
+                "Infinity" => Ok(Number::Float(f64::INFINITY)),
+                "-Infinity" => Ok(Number::Float(f64::NEG_INFINITY)),
+                _ => Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Number {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(NumberVisitor)
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Number;
@@ -494,11 +569,13 @@ mod test {
     }
 
     #[test]
-    #[cfg(all(
-        test,
-        aws_sdk_unstable,
-        feature = "serde-deserialize",
-        feature = "serde-serialize"
+    #[cfg(any(
+        feature = "serde",
+        all(
+            aws_sdk_unstable,
+            feature = "serde-deserialize",
+            feature = "serde-serialize"
+        )
     ))]
     /// ensures that numbers are deserialized as expected
     /// 0 <= PosInt
@@ -519,4 +596,38 @@ mod test {
         assert_eq!("0", serde_json::to_string(&Number::PosInt(0)).unwrap());
         assert_eq!("-1", serde_json::to_string(&Number::NegInt(-1)).unwrap());
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn number_serde_round_trip() {
+        for number in [
+            Number::PosInt(u64::MAX),
+            Number::NegInt(i64::MIN),
+            Number::Float(-0.5),
+            Number::Float(1e300),
+            Number::Float(f64::INFINITY),
+            Number::Float(f64::NEG_INFINITY),
+        ] {
+            let json = serde_json::to_string(&number).unwrap();
+            assert_eq!(
+                number,
+                serde_json::from_str::<Number>(&json).unwrap(),
+                "{json}"
+            );
+
+            let mut cbor = vec![];
+            ciborium::ser::into_writer(&number, &mut cbor).unwrap();
+            let de: Number = ciborium::de::from_reader(cbor.as_slice()).unwrap();
+            assert_eq!(number, de);
+        }
+
+        assert_eq!(
+            "\"NaN\"",
+            serde_json::to_string(&Number::Float(f64::NAN)).unwrap()
+        );
+        let nan: Number = serde_json::from_str("\"NaN\"").unwrap();
+        assert!(nan.to_f64_lossy().is_nan());
+        assert!(serde_json::from_str::<Number>("\"1\"").is_err());
+        assert!(serde_json::from_str::<Number>("true").is_err());
+    }
 }
@@ -8,7 +8,7 @@
 
 use crate::config_bag::value::Value;
 use crate::config_bag::{ItemIter, Storable, Store, StoreReplace};
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 #[derive(Clone, Debug, PartialEq, Copy)]
 enum CanDisable<T> {
@@ -57,6 +57,24 @@ impl<T> Default for CanDisable<T> {
     }
 }
 
+/// How the operation attempt timeout is measured.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AttemptTimeoutMode {
+    /// The attempt fails once it has run for longer than the operation attempt timeout,
+    /// regardless of progress.
+    #[default]
+    Absolute,
+
+    /// The attempt fails once a full operation attempt timeout elapses without any request or
+    /// response body bytes being transferred.
+    ///
+    /// The timer is restarted after every period in which body data flowed, so a slow but steadily progressing
+    /// upload or download is not interrupted. This is useful for large streaming bodies
+    /// whose total transfer time can't be bounded up front.
+    Activity,
+}
+
 /// Builder for [`TimeoutConfig`].
 #[non_exhaustive]
 #[derive(Clone, Debug, Default)]
@@ -65,6 +83,7 @@ pub struct TimeoutConfigBuilder {
     read_timeout: CanDisable<Duration>,
     operation_timeout: CanDisable<Duration>,
     operation_attempt_timeout: CanDisable<Duration>,
+    operation_attempt_timeout_mode: Option<AttemptTimeoutMode>,
 }
 
 impl TimeoutConfigBuilder {
@@ -195,6 +214,26 @@ impl TimeoutConfigBuilder {
         self
     }
 
+    /// Sets how the operation attempt timeout is measured.
+    ///
+    /// Defaults to [`AttemptTimeoutMode::Absolute`]. With [`AttemptTimeoutMode::Activity`], the
+    /// operation attempt timeout only elapses while no request or response body data is flowing.
+    pub fn operation_attempt_timeout_mode(mut self, mode: AttemptTimeoutMode) -> Self {
+        self.operation_attempt_timeout_mode = Some(mode);
+        self
+    }
+
+    /// Sets how the operation attempt timeout is measured.
+    ///
+    /// If `None` is passed, the default [`AttemptTimeoutMode::Absolute`] is used.
+    pub fn set_operation_attempt_timeout_mode(
+        &mut self,
+        mode: Option<AttemptTimeoutMode>,
+    ) -> &mut Self {
+        self.operation_attempt_timeout_mode = mode;
+        self
+    }
+
     /// Merges two timeout config builders together.
     ///
     /// Values from `other` will only be used as a fallback for values
@@ -232,6 +271,9 @@ impl TimeoutConfigBuilder {
             operation_attempt_timeout: self
                 .operation_attempt_timeout
                 .merge_from_lower_priority(other.operation_attempt_timeout),
+            operation_attempt_timeout_mode: self
+                .operation_attempt_timeout_mode
+                .or(other.operation_attempt_timeout_mode),
         }
     }
 
@@ -242,6 +284,7 @@ impl TimeoutConfigBuilder {
             read_timeout: self.read_timeout,
             operation_timeout: self.operation_timeout,
             operation_attempt_timeout: self.operation_attempt_timeout,
+            operation_attempt_timeout_mode: self.operation_attempt_timeout_mode,
         }
     }
 }
@@ -253,6 +296,7 @@ impl From<TimeoutConfig> for TimeoutConfigBuilder {
             read_timeout: timeout_config.read_timeout,
             operation_timeout: timeout_config.operation_timeout,
             operation_attempt_timeout: timeout_config.operation_attempt_timeout,
+            operation_attempt_timeout_mode: timeout_config.operation_attempt_timeout_mode,
         }
     }
 }
@@ -294,6 +338,7 @@ pub struct TimeoutConfig {
     read_timeout: CanDisable<Duration>,
     operation_timeout: CanDisable<Duration>,
     operation_attempt_timeout: CanDisable<Duration>,
+    operation_attempt_timeout_mode: Option<AttemptTimeoutMode>,
 }
 
 impl Storable for TimeoutConfig {
@@ -323,7 +368,7 @@ impl Store for MergeTimeoutConfig {
             match (result.as_mut(), tc) {
                 (Some(result), Value::Set(tc)) => {
                     // This maintains backwards compatible behavior where setting an EMPTY timeout config is equivalent to `TimeoutConfig::disabled()`
-                    if result.has_timeouts() {
+                    if result.has_timeouts() || result.operation_attempt_timeout_mode.is_some() {
                         result.take_defaults_from(tc);
                     }
                 }
@@ -367,6 +412,9 @@ impl TimeoutConfig {
         self.operation_attempt_timeout = self
             .operation_attempt_timeout
             .merge_from_lower_priority(other.operation_attempt_timeout);
+        self.operation_attempt_timeout_mode = self
+            .operation_attempt_timeout_mode
+            .or(other.operation_attempt_timeout_mode);
         self
     }
 
@@ -377,6 +425,7 @@ impl TimeoutConfig {
             read_timeout: CanDisable::Disabled,
             operation_timeout: CanDisable::Disabled,
             operation_attempt_timeout: CanDisable::Disabled,
+            operation_attempt_timeout_mode: None,
         }
     }
 
@@ -413,6 +462,13 @@ impl TimeoutConfig {
         self.operation_attempt_timeout.value()
     }
 
+    /// Returns how this config's operation attempt timeout is measured.
+    ///
+    /// Defaults to [`AttemptTimeoutMode::Absolute`] when not set.
+    pub fn operation_attempt_timeout_mode(&self) -> AttemptTimeoutMode {
+        self.operation_attempt_timeout_mode.unwrap_or_default()
+    }
+
     /// Returns true if any of the possible timeouts are set.
     pub fn has_timeouts(&self) -> bool {
         self.connect_timeout.is_some()
@@ -470,10 +526,46 @@ impl From<TimeoutConfig> for OperationTimeoutConfig {
     }
 }
 
+/// An absolute point in time by which an operation must complete.
+///
+/// When a `Deadline` is stored in the config bag, the operation and operation attempt timeouts are
+/// capped to the time remaining until the deadline, so that a deadline received from an upstream
+/// caller can be propagated to downstream requests. A deadline that has already passed fails the
+/// operation with a timeout error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Deadline(SystemTime);
+
+impl Deadline {
+    /// Creates a `Deadline` at the given time.
+    pub fn new(deadline: SystemTime) -> Self {
+        Self(deadline)
+    }
+
+    /// Returns the time of this deadline.
+    pub fn time(&self) -> SystemTime {
+        self.0
+    }
+
+    /// Returns the time remaining until this deadline at the time `now`, or zero if it has passed.
+    pub fn remaining(&self, now: SystemTime) -> Duration {
+        self.0.duration_since(now).unwrap_or(Duration::ZERO)
+    }
+}
+
+impl From<SystemTime> for Deadline {
+    fn from(deadline: SystemTime) -> Self {
+        Self::new(deadline)
+    }
+}
+
+impl Storable for Deadline {
+    type Storer = StoreReplace<Deadline>;
+}
+
 #[cfg(test)]
 mod test {
     use crate::config_bag::{CloneableLayer, ConfigBag};
-    use crate::timeout::{MergeTimeoutConfig, TimeoutConfig};
+    use crate::timeout::{AttemptTimeoutMode, MergeTimeoutConfig, TimeoutConfig};
     use std::time::Duration;
 
     #[test]
@@ -524,4 +616,42 @@ mod test {
             Some(Duration::from_secs(3))
         );
     }
+
+    #[test]
+    fn attempt_timeout_mode_merged_in_config_bag() {
+        let mut base = CloneableLayer::new("base");
+        base.store_put(
+            TimeoutConfig::builder()
+                .operation_attempt_timeout(Duration::from_secs(30))
+                .build(),
+        );
+        let cfg = ConfigBag::of_layers(vec![base.into()]);
+        let loaded = cfg.load::<MergeTimeoutConfig>();
+        assert_eq!(
+            loaded.operation_attempt_timeout_mode(),
+            AttemptTimeoutMode::Absolute
+        );
+
+        // Setting only the mode keeps the timeouts of lower layers
+        let mut next = cfg.add_layer("mode");
+        next.interceptor_state().store_put(
+            TimeoutConfig::builder()
+                .operation_attempt_timeout_mode(AttemptTimeoutMode::Activity)
+                .build(),
+        );
+        let loaded = next.load::<MergeTimeoutConfig>();
+        assert_eq!(
+            loaded.operation_attempt_timeout(),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            loaded.operation_attempt_timeout_mode(),
+            AttemptTimeoutMode::Activity
+        );
+
+        assert_eq!(
+            TimeoutConfig::disabled().operation_attempt_timeout_mode(),
+            AttemptTimeoutMode::Absolute
+        );
+    }
 }
@@ -0,0 +1,319 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Parsers for human-readable durations and byte sizes.
+//!
+//! Configuration surfaces such as environment variables and profile files commonly express
+//! timeouts and sizes as strings like `"500ms"` or `"8MiB"`. The parsers in this module are strict
+//! so that every configuration source handles these values the same way:
+//!
+//! - No whitespace is allowed, neither around the value nor between the number and its unit.
+//! - Units are case-sensitive.
+//! - Values that don't fit in the target type are rejected rather than saturated.
+//!
+//! ```
+//! use aws_smithy_types::units::{parse_byte_size, parse_duration};
+//! use std::time::Duration;
+//!
+//! assert_eq!(Duration::from_millis(500), parse_duration("500ms").unwrap());
+//! assert_eq!(Duration::from_millis(1500), parse_duration("1.5s").unwrap());
+//! assert_eq!(8 * 1024 * 1024, parse_byte_size("8MiB").unwrap().as_u64());
+//! assert_eq!(5_000_000, parse_byte_size("5MB").unwrap().as_u64());
+//! assert!(parse_duration("500").is_err());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+const DURATION_UNITS: &[(&str, u128)] = &[
+    ("ns", 1),
+    ("us", 1_000),
+    ("µs", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+    ("m", 60 * 1_000_000_000),
+    ("h", 60 * 60 * 1_000_000_000),
+];
+
+const BYTE_SIZE_UNITS: &[(&str, u64)] = &[
+    ("B", 1),
+    ("kB", 1_000),
+    ("KB", 1_000),
+    ("MB", 1_000_000),
+    ("GB", 1_000_000_000),
+    ("TB", 1_000_000_000_000),
+    ("KiB", 1 << 10),
+    ("MiB", 1 << 20),
+    ("GiB", 1 << 30),
+    ("TiB", 1 << 40),
+];
+
+/// A size in bytes.
+///
+/// `ByteSize` can be parsed from strings like `"512"`, `"5MB"` or `"8MiB"` with [`parse_byte_size`]
+/// or [`str::parse`]. Decimal units (`kB`/`KB`, `MB`, `GB`, `TB`) are powers of 1000, and binary
+/// units (`KiB`, `MiB`, `GiB`, `TiB`) are powers of 1024. A value without a unit is a number of
+/// bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// Creates a `ByteSize` of `bytes` bytes.
+    pub const fn from_bytes(bytes: u64) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the number of bytes.
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(size: ByteSize) -> Self {
+        size.0
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl FromStr for ByteSize {
+    type Err = UnitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_byte_size(s)
+    }
+}
+
+/// Parses a duration like `"500ms"`, `"30s"` or `"1.5h"`.
+///
+/// The value is a non-negative decimal number immediately followed by one of the units `ns`, `us`
+/// (or `µs`), `ms`, `s`, `m` or `h`. The unit is required. Fractions are allowed, but the resulting
+/// duration must be a whole number of nanoseconds.
+pub fn parse_duration(value: &str) -> Result<Duration, UnitParseError> {
+    let err = |reason| UnitParseError::new(value, "duration", reason);
+    let (number, unit) = split_unit(value);
+    if number.is_empty() {
+        return Err(err("missing number"));
+    }
+    let nanos_per_unit = match DURATION_UNITS.iter().find(|(name, _)| *name == unit) {
+        Some((_, nanos)) => *nanos,
+        None if unit.is_empty() => return Err(err("missing unit")),
+        None => return Err(err("unknown unit")),
+    };
+
+    let (whole, fraction) = match number.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (number, ""),
+    };
+    if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+        return Err(err("invalid number"));
+    }
+    if number.ends_with('.') {
+        return Err(err("invalid number"));
+    }
+
+    let overflow = || err("value is too large");
+    let whole: u128 = whole.parse().map_err(|_| overflow())?;
+    let mut nanos = whole.checked_mul(nanos_per_unit).ok_or_else(overflow)?;
+    let fraction = fraction.trim_end_matches('0');
+    if !fraction.is_empty() {
+        let scale = u32::try_from(fraction.len())
+            .ok()
+            .and_then(|len| 10u128.checked_pow(len))
+            .ok_or_else(|| err("too many fractional digits"))?;
+        let fraction: u128 = fraction
+            .parse()
+            .map_err(|_| err("too many fractional digits"))?;
+        let fraction_nanos = fraction
+            .checked_mul(nanos_per_unit)
+            .ok_or_else(|| err("too many fractional digits"))?;
+        if fraction_nanos % scale != 0 {
+            return Err(err("value is not a whole number of nanoseconds"));
+        }
+        nanos = nanos
+            .checked_add(fraction_nanos / scale)
+            .ok_or_else(overflow)?;
+    }
+
+    let secs = u64::try_from(nanos / 1_000_000_000).map_err(|_| overflow())?;
+    Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
+
+/// Parses a byte size like `"512"`, `"5MB"` or `"8MiB"`.
+///
+/// The value is a non-negative integer, optionally immediately followed by one of the units `B`,
+/// `kB`/`KB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, `GiB` or `TiB`. See [`ByteSize`] for details.
+pub fn parse_byte_size(value: &str) -> Result<ByteSize, UnitParseError> {
+    let err = |reason| UnitParseError::new(value, "byte size", reason);
+    let (number, unit) = split_unit(value);
+    if number.is_empty() {
+        return Err(err("missing number"));
+    }
+    if !is_digits(number) {
+        return Err(err("invalid number"));
+    }
+    let bytes_per_unit = match BYTE_SIZE_UNITS.iter().find(|(name, _)| *name == unit) {
+        Some((_, bytes)) => *bytes,
+        None if unit.is_empty() => 1,
+        None => return Err(err("unknown unit")),
+    };
+
+    let overflow = || err("value is too large");
+    let number: u64 = number.parse().map_err(|_| overflow())?;
+    number
+        .checked_mul(bytes_per_unit)
+        .map(ByteSize)
+        .ok_or_else(overflow)
+}
+
+/// Splits a value into its leading number and trailing unit.
+fn split_unit(value: &str) -> (&str, &str) {
+    let unit_start = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    value.split_at(unit_start)
+}
+
+fn is_digits(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Failure to parse a duration or byte size from a string.
+#[derive(Debug)]
+pub struct UnitParseError {
+    value: String,
+    kind: &'static str,
+    reason: &'static str,
+}
+
+impl UnitParseError {
+    fn new(value: &str, kind: &'static str, reason: &'static str) -> Self {
+        Self {
+            value: value.to_string(),
+            kind,
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for UnitParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "error parsing string '{}' as a {}: {}",
+            self.value, self.kind, self.reason
+        )
+    }
+}
+
+impl std::error::Error for UnitParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_byte_size, parse_duration, ByteSize};
+    use std::time::Duration;
+
+    #[test]
+    fn parse_valid_durations() {
+        let cases = [
+            ("0s", Duration::ZERO),
+            ("250ns", Duration::from_nanos(250)),
+            ("10us", Duration::from_micros(10)),
+            ("10µs", Duration::from_micros(10)),
+            ("500ms", Duration::from_millis(500)),
+            ("30s", Duration::from_secs(30)),
+            ("1.5s", Duration::from_millis(1500)),
+            ("0.25ms", Duration::from_micros(250)),
+            ("2.000s", Duration::from_secs(2)),
+            ("5m", Duration::from_secs(300)),
+            ("1.5h", Duration::from_secs(5400)),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(expected, parse_duration(input).unwrap(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_invalid_durations() {
+        let cases = [
+            ("", "missing number"),
+            ("ms", "missing number"),
+            ("500", "missing unit"),
+            ("500 ms", "unknown unit"),
+            (" 500ms", "missing number"),
+            ("500ms ", "unknown unit"),
+            ("500MS", "unknown unit"),
+            ("5d", "unknown unit"),
+            ("-5s", "missing number"),
+            (".5s", "invalid number"),
+            ("5.s", "invalid number"),
+            ("1.2.3s", "invalid number"),
+            ("1.5ns", "value is not a whole number of nanoseconds"),
+            ("99999999999999999999999h", "value is too large"),
+        ];
+        for (input, reason) in cases {
+            let err = parse_duration(input).expect_err(input).to_string();
+            assert!(err.ends_with(reason), "{input}: {err}");
+        }
+    }
+
+    #[test]
+    fn parse_valid_byte_sizes() {
+        let cases = [
+            ("0", 0),
+            ("512", 512),
+            ("512B", 512),
+            ("10kB", 10_000),
+            ("10KB", 10_000),
+            ("5MB", 5_000_000),
+            ("2GB", 2_000_000_000),
+            ("1TB", 1_000_000_000_000),
+            ("10KiB", 10 * 1024),
+            ("8MiB", 8 * 1024 * 1024),
+            ("2GiB", 2 * 1024 * 1024 * 1024),
+            ("1TiB", 1024 * 1024 * 1024 * 1024),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                ByteSize::from_bytes(expected),
+                parse_byte_size(input).unwrap(),
+                "{input}"
+            );
+        }
+        assert_eq!(Ok(ByteSize::from_bytes(1024)), "1KiB".parse().map_err(drop));
+    }
+
+    #[test]
+    fn parse_invalid_byte_sizes() {
+        let cases = [
+            ("", "missing number"),
+            ("MB", "missing number"),
+            ("1.5MB", "invalid number"),
+            ("5 MB", "unknown unit"),
+            ("5mb", "unknown unit"),
+            ("5Mb", "unknown unit"),
+            ("5PB", "unknown unit"),
+            ("-5", "missing number"),
+            ("20000000TiB", "value is too large"),
+            ("99999999999999999999", "value is too large"),
+        ];
+        for (input, reason) in cases {
+            let err = parse_byte_size(input).expect_err(input).to_string();
+            assert!(err.ends_with(reason), "{input}: {err}");
+        }
+    }
+}
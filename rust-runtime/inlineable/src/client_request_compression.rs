@@ -29,10 +29,15 @@ pub(crate) struct RequestCompressionRuntimePlugin {
 }
 
 impl RequestCompressionRuntimePlugin {
-    pub(crate) fn new() -> Self {
+    /// Creates a runtime plugin that compresses request bodies with the given algorithm.
+    ///
+    /// The algorithm is the first supported one in the operation's modeled encodings.
+    pub(crate) fn new(algorithm: CompressionAlgorithm) -> Self {
         Self {
             runtime_components: RuntimeComponentsBuilder::new("RequestCompressionRuntimePlugin")
-                .with_interceptor(SharedInterceptor::new(RequestCompressionInterceptor::new())),
+                .with_interceptor(SharedInterceptor::new(RequestCompressionInterceptor::new(
+                    algorithm,
+                ))),
         }
     }
 }
@@ -58,17 +63,21 @@ impl Storable for RequestCompressionInterceptorState {
 /// Interceptor for Smithy [`@requestCompression`][spec].
 ///
 /// [spec]: https://smithy.io/2.0/spec/behavior-traits.html#requestcompression-trait
-pub(crate) struct RequestCompressionInterceptor {}
+pub(crate) struct RequestCompressionInterceptor {
+    algorithm: CompressionAlgorithm,
+}
 
 impl fmt::Debug for RequestCompressionInterceptor {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("RequestCompressionInterceptor").finish()
+        f.debug_struct("RequestCompressionInterceptor")
+            .field("algorithm", &self.algorithm)
+            .finish()
     }
 }
 
 impl RequestCompressionInterceptor {
-    pub(crate) fn new() -> Self {
-        Self {}
+    pub(crate) fn new(algorithm: CompressionAlgorithm) -> Self {
+        Self { algorithm }
     }
 }
 
@@ -144,10 +153,12 @@ impl Intercept for RequestCompressionInterceptor {
 
         wrap_request_body_in_compressed_body(
             request,
-            CompressionAlgorithm::Gzip.into_impl_http_body_0_4_x(&options),
+            self.algorithm.into_impl_http_body_0_4_x(&options),
         )?;
-        cfg.interceptor_state()
-            .store_append::<SmithySdkFeature>(SmithySdkFeature::GzipRequestCompression);
+        if self.algorithm == CompressionAlgorithm::Gzip {
+            cfg.interceptor_state()
+                .store_append::<SmithySdkFeature>(SmithySdkFeature::GzipRequestCompression);
+        }
 
         Ok(())
     }
@@ -307,7 +318,7 @@ mod tests {
         let mut context = context();
         let ctx = Into::into(&context);
 
-        let sut = RequestCompressionInterceptor::new();
+        let sut = RequestCompressionInterceptor::new(CompressionAlgorithm::Gzip);
         sut.read_before_execution(&ctx, &mut cfg).unwrap();
 
         let rc = RuntimeComponentsBuilder::for_tests().build().unwrap();
@@ -320,4 +331,32 @@ mod tests {
             cfg.load::<SmithySdkFeature>().next().unwrap()
         );
     }
+
+    #[tokio::test]
+    async fn test_brotli_request_compression() {
+        let mut cfg = ConfigBag::base();
+        let mut layer = Layer::new("test");
+        layer.store_put(RequestMinCompressionSizeBytes::from(0));
+        cfg.push_layer(layer);
+        let mut context = context();
+        let ctx = Into::into(&context);
+
+        let sut = RequestCompressionInterceptor::new(CompressionAlgorithm::Brotli);
+        sut.read_before_execution(&ctx, &mut cfg).unwrap();
+
+        let rc = RuntimeComponentsBuilder::for_tests().build().unwrap();
+        let mut ctx = Into::into(&mut context);
+        sut.modify_before_retry_loop(&mut ctx, &rc, &mut cfg)
+            .unwrap();
+
+        let request = context.request().unwrap();
+        assert_eq!(Some("br"), request.headers().get("content-encoding"));
+        let compressed = request.body().bytes().unwrap();
+        assert_ne!(UNCOMPRESSED_INPUT, compressed);
+        assert_eq!(
+            Some(compressed.len().to_string().as_str()),
+            request.headers().get("content-length")
+        );
+        assert!(cfg.load::<SmithySdkFeature>().next().is_none());
+    }
 }
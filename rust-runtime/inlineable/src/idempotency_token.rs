@@ -4,7 +4,9 @@
  */
 
 use aws_smithy_types::config_bag::{Storable, StoreReplace};
-use std::sync::Mutex;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 pub(crate) fn uuid_v4(input: u128) -> String {
     let mut out = String::with_capacity(36);
@@ -31,22 +33,38 @@ pub(crate) fn uuid_v4(input: u128) -> String {
     out
 }
 
+/// Generates idempotency tokens.
+///
+/// Implement this trait to plug a custom token source, such as KSUIDs, into
+/// [`IdempotencyTokenProvider::new`].
+pub trait ProvideIdempotencyToken: Send + Sync + fmt::Debug {
+    /// Returns a new idempotency token.
+    fn make_idempotency_token(&self) -> String;
+}
+
 /// IdempotencyTokenProvider generates idempotency tokens for idempotent API requests
 ///
 /// Generally, customers will not need to interact with this at all. A sensible default will be
 /// provided automatically during config construction. However, if you need deterministic behavior
-/// for testing, two options are available:
-/// 1. Utilize the From<&'static str>` implementation to hard code an idempotency token
+/// for testing, the following options are available:
+/// 1. Utilize the `From<&'static str>` implementation to hard code an idempotency token
 /// 2. Seed the token provider with [`IdempotencyTokenProvider::with_seed`](IdempotencyTokenProvider::with_seed)
-#[derive(Debug)]
+/// 3. Use [`IdempotencyTokenProvider::sequential`](IdempotencyTokenProvider::sequential) to generate
+///    a predictable series of UUIDs
+///
+/// Clones share their token source, so a seeded or sequential provider yields the same series
+/// of tokens no matter how many times the client config is cloned.
+#[derive(Clone, Debug)]
 pub struct IdempotencyTokenProvider {
     inner: Inner,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum Inner {
     Static(&'static str),
-    Random(Mutex<fastrand::Rng>),
+    Random(Arc<Mutex<fastrand::Rng>>),
+    Sequential(Arc<AtomicU64>),
+    Custom(Arc<dyn ProvideIdempotencyToken>),
 }
 
 pub fn default_provider() -> IdempotencyTokenProvider {
@@ -64,6 +82,13 @@ impl Storable for IdempotencyTokenProvider {
 }
 
 impl IdempotencyTokenProvider {
+    /// Creates a provider that generates tokens with the given custom implementation.
+    pub fn new(provider: impl ProvideIdempotencyToken + 'static) -> Self {
+        Self {
+            inner: Inner::Custom(Arc::new(provider)),
+        }
+    }
+
     pub fn make_idempotency_token(&self) -> String {
         match &self.inner {
             Inner::Static(token) => token.to_string(),
@@ -71,18 +96,23 @@ impl IdempotencyTokenProvider {
                 let input: u128 = rng.lock().unwrap().u128(..);
                 uuid_v4(input)
             }
+            Inner::Sequential(counter) => {
+                let count = counter.fetch_add(1, Ordering::Relaxed);
+                format!("00000000-0000-4000-8000-{:012x}", count & 0xffff_ffff_ffff)
+            }
+            Inner::Custom(provider) => provider.make_idempotency_token(),
         }
     }
 
     pub fn with_seed(seed: u64) -> Self {
         Self {
-            inner: Inner::Random(Mutex::new(fastrand::Rng::with_seed(seed))),
+            inner: Inner::Random(Arc::new(Mutex::new(fastrand::Rng::with_seed(seed)))),
         }
     }
 
     pub fn random() -> Self {
         Self {
-            inner: Inner::Random(Mutex::new(fastrand::Rng::new())),
+            inner: Inner::Random(Arc::new(Mutex::new(fastrand::Rng::new()))),
         }
     }
 
@@ -91,13 +121,14 @@ impl IdempotencyTokenProvider {
             inner: Inner::Static(token),
         }
     }
-}
 
-impl Clone for IdempotencyTokenProvider {
-    fn clone(&self) -> Self {
-        match &self.inner {
-            Inner::Static(token) => IdempotencyTokenProvider::fixed(token),
-            Inner::Random(_) => IdempotencyTokenProvider::random(),
+    /// Creates a provider that generates UUIDs counting up from zero, i.e.
+    /// `00000000-0000-4000-8000-000000000000`, `00000000-0000-4000-8000-000000000001`, and so on.
+    ///
+    /// This is intended for tests that need distinct yet reproducible tokens.
+    pub fn sequential() -> Self {
+        Self {
+            inner: Inner::Sequential(Arc::new(AtomicU64::new(0))),
         }
     }
 }
@@ -47,7 +47,7 @@ mod client_request_compression;
 #[cfg(test)]
 mod test {
     use crate::idempotency_token;
-    use crate::idempotency_token::{uuid_v4, IdempotencyTokenProvider};
+    use crate::idempotency_token::{uuid_v4, IdempotencyTokenProvider, ProvideIdempotencyToken};
     use proptest::prelude::*;
     use regex_lite::Regex;
 
@@ -84,6 +84,53 @@ mod test {
         );
     }
 
+    #[test]
+    fn seeded_provider_is_shared_between_clones() {
+        let provider = IdempotencyTokenProvider::with_seed(42);
+        let tokens: Vec<_> = (0..3)
+            .map(|_| provider.clone().make_idempotency_token())
+            .collect();
+        let reference = IdempotencyTokenProvider::with_seed(42);
+        let expected: Vec<_> = (0..3).map(|_| reference.make_idempotency_token()).collect();
+        assert_eq!(expected, tokens);
+    }
+
+    #[test]
+    fn sequential_provider() {
+        let provider = IdempotencyTokenProvider::sequential();
+        assert_eq!(
+            "00000000-0000-4000-8000-000000000000",
+            provider.make_idempotency_token()
+        );
+        assert_eq!(
+            "00000000-0000-4000-8000-000000000001",
+            provider.clone().make_idempotency_token()
+        );
+        for _ in 0..14 {
+            provider.make_idempotency_token();
+        }
+        let token = provider.make_idempotency_token();
+        assert_eq!("00000000-0000-4000-8000-000000000010", token);
+        assert_valid(token);
+    }
+
+    #[test]
+    fn custom_provider() {
+        #[derive(Debug)]
+        struct Ksuid;
+        impl ProvideIdempotencyToken for Ksuid {
+            fn make_idempotency_token(&self) -> String {
+                "0ujtsYcgvSTl8PAuAdqWYSMnLOv".into()
+            }
+        }
+
+        let provider = IdempotencyTokenProvider::new(Ksuid);
+        assert_eq!(
+            "0ujtsYcgvSTl8PAuAdqWYSMnLOv",
+            provider.make_idempotency_token()
+        );
+    }
+
     fn assert_valid(uuid: String) {
         assert_eq!(uuid.len(), 36);
         let bytes = uuid.as_bytes();
@@ -3,7 +3,7 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use aws_smithy_types::error::metadata::{Builder as ErrorMetadataBuilder, ErrorMetadata};
+use aws_smithy_types::error::metadata::{keys, Builder as ErrorMetadataBuilder, ErrorMetadata};
 use aws_smithy_xml::decode::{try_data, Document, ScopedDecoder, XmlDecodeError};
 
 #[allow(unused)]
@@ -28,6 +28,10 @@ pub fn parse_error_metadata(body: &[u8]) -> Result<ErrorMetadataBuilder, XmlDeco
                     "Message" => {
                         err_builder = err_builder.message(try_data(&mut error_field)?);
                     }
+                    "Type" => {
+                        err_builder =
+                            err_builder.custom(keys::ERROR_TYPE, try_data(&mut error_field)?);
+                    }
                     _ => {}
                 }
             }
@@ -62,6 +66,7 @@ pub fn error_scope<'a, 'b>(
 mod test {
     use super::{body_is_error, parse_error_metadata};
     use crate::rest_xml_wrapped_errors::error_scope;
+    use aws_smithy_types::error::metadata::keys;
     use aws_smithy_xml::decode::Document;
 
     #[test]
@@ -80,6 +85,7 @@ mod test {
         let parsed = parse_error_metadata(xml).expect("valid xml").build();
         assert_eq!(parsed.message(), Some("Hi"));
         assert_eq!(parsed.code(), Some("InvalidGreeting"));
+        assert_eq!(parsed.extra(keys::ERROR_TYPE), Some("Sender"));
     }
 
     #[test]
@@ -0,0 +1,279 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// An API report of a generated crate, as generated in `api.json`
+#[derive(Debug, Deserialize)]
+pub struct ApiReport {
+    #[serde(rename = "crate")]
+    pub crate_name: String,
+    pub version: String,
+    pub items: Vec<Item>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Item {
+    pub path: String,
+    pub kind: String,
+    pub stability: Stability,
+    #[serde(default)]
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub stability: Stability,
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Stability {
+    Stable,
+    Unstable,
+    Deprecated,
+}
+
+impl Stability {
+    fn is_unstable(self) -> bool {
+        self == Stability::Unstable
+    }
+}
+
+impl fmt::Display for Stability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Stability::Stable => "stable",
+            Stability::Unstable => "unstable",
+            Stability::Deprecated => "deprecated",
+        })
+    }
+}
+
+/// A change to the public API of a crate
+#[derive(Debug, Eq, PartialEq)]
+pub struct Change {
+    pub breaking: bool,
+    pub description: String,
+}
+
+impl Change {
+    fn new(breaking: bool, description: impl Into<String>) -> Self {
+        Self {
+            breaking,
+            description: description.into(),
+        }
+    }
+}
+
+/// Returns the changes between two API reports, in the order of the items of the reports
+pub fn diff(old: &ApiReport, new: &ApiReport) -> Vec<Change> {
+    let new_items: BTreeMap<_, _> = new.items.iter().map(|item| (&item.path, item)).collect();
+    let old_items: BTreeMap<_, _> = old.items.iter().map(|item| (&item.path, item)).collect();
+
+    let mut changes = Vec::new();
+    for old_item in &old.items {
+        match new_items.get(&old_item.path) {
+            Some(new_item) => diff_item(old_item, new_item, &mut changes),
+            None => changes.push(Change::new(
+                !old_item.stability.is_unstable(),
+                format!(
+                    "removed {} {} `{}`",
+                    old_item.stability, old_item.kind, old_item.path
+                ),
+            )),
+        }
+    }
+    for new_item in &new.items {
+        if !old_items.contains_key(&new_item.path) {
+            changes.push(Change::new(
+                false,
+                format!(
+                    "added {} {} `{}`",
+                    new_item.stability, new_item.kind, new_item.path
+                ),
+            ));
+        }
+    }
+    changes
+}
+
+fn diff_item(old: &Item, new: &Item, changes: &mut Vec<Change>) {
+    // Changes to unstable items are exempt from semver
+    let exempt = old.stability.is_unstable();
+    let path = &old.path;
+    if old.kind != new.kind {
+        changes.push(Change::new(
+            !exempt,
+            format!(
+                "changed the kind of `{path}` from {} to {}",
+                old.kind, new.kind
+            ),
+        ));
+    }
+    if old.stability != new.stability {
+        changes.push(Change::new(
+            !exempt && new.stability.is_unstable(),
+            format!(
+                "changed `{path}` from {} to {}",
+                old.stability, new.stability
+            ),
+        ));
+    }
+
+    let new_members: BTreeMap<_, _> = new
+        .members
+        .iter()
+        .map(|member| (&member.name, member))
+        .collect();
+    for old_member in &old.members {
+        let exempt = exempt || old_member.stability.is_unstable();
+        let name = &old_member.name;
+        let Some(new_member) = new_members.get(&old_member.name) else {
+            changes.push(Change::new(
+                !exempt,
+                format!(
+                    "removed {} member `{name}` of `{path}`",
+                    old_member.stability
+                ),
+            ));
+            continue;
+        };
+        if old_member.ty != new_member.ty {
+            changes.push(Change::new(
+                !exempt,
+                format!(
+                    "changed the type of member `{name}` of `{path}` from `{}` to `{}`",
+                    old_member.ty.as_deref().unwrap_or("()"),
+                    new_member.ty.as_deref().unwrap_or("()"),
+                ),
+            ));
+        }
+        if old_member.stability != new_member.stability {
+            changes.push(Change::new(
+                !exempt && new_member.stability.is_unstable(),
+                format!(
+                    "changed member `{name}` of `{path}` from {} to {}",
+                    old_member.stability, new_member.stability
+                ),
+            ));
+        }
+    }
+    for new_member in &new.members {
+        if !old
+            .members
+            .iter()
+            .any(|member| member.name == new_member.name)
+        {
+            changes.push(Change::new(
+                false,
+                format!(
+                    "added {} member `{}` to `{path}`",
+                    new_member.stability, new_member.name
+                ),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn report(json: &str) -> ApiReport {
+        serde_json::from_str(json).unwrap()
+    }
+
+    const OLD: &str = r#"{
+        "crate": "aws-sdk-example",
+        "version": "1.0.0",
+        "items": [
+            { "path": "crate::Client::get_item", "kind": "operation", "stability": "stable", "members": [] },
+            { "path": "crate::Client::preview", "kind": "operation", "stability": "unstable", "members": [] },
+            {
+                "path": "crate::types::Item",
+                "kind": "struct",
+                "stability": "stable",
+                "members": [
+                    { "name": "id", "type": "::std::option::Option<::std::string::String>", "stability": "stable" },
+                    { "name": "size", "type": "::std::option::Option<i32>", "stability": "stable" },
+                    { "name": "beta", "type": "::std::option::Option<bool>", "stability": "unstable" }
+                ]
+            },
+            {
+                "path": "crate::types::Color",
+                "kind": "enum",
+                "stability": "stable",
+                "members": [
+                    { "name": "Red", "stability": "stable" },
+                    { "name": "Blue", "stability": "stable" }
+                ]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn identical_reports_have_no_changes() {
+        assert_eq!(Vec::<Change>::new(), diff(&report(OLD), &report(OLD)));
+    }
+
+    #[test]
+    fn flags_breaking_changes() {
+        let new = report(
+            r#"{
+                "crate": "aws-sdk-example",
+                "version": "1.1.0",
+                "items": [
+                    { "path": "crate::Client::get_item", "kind": "operation", "stability": "deprecated" },
+                    { "path": "crate::Client::put_item", "kind": "operation", "stability": "stable" },
+                    {
+                        "path": "crate::types::Item",
+                        "kind": "struct",
+                        "stability": "stable",
+                        "members": [
+                            { "name": "id", "type": "::std::option::Option<::std::string::String>", "stability": "unstable" },
+                            { "name": "size", "type": "::std::option::Option<i64>", "stability": "stable" },
+                            { "name": "color", "type": "::std::option::Option<crate::types::Color>", "stability": "stable" }
+                        ]
+                    },
+                    {
+                        "path": "crate::types::Color",
+                        "kind": "union",
+                        "stability": "stable",
+                        "members": [
+                            { "name": "Red", "stability": "stable" },
+                            { "name": "Green", "stability": "stable" }
+                        ]
+                    }
+                ]
+            }"#,
+        );
+        let changes: Vec<_> = diff(&report(OLD), &new)
+            .into_iter()
+            .map(|change| (change.breaking, change.description))
+            .collect();
+        assert_eq!(
+            vec![
+                (false, "changed `crate::Client::get_item` from stable to deprecated".to_string()),
+                (false, "removed unstable operation `crate::Client::preview`".to_string()),
+                (true, "changed member `id` of `crate::types::Item` from stable to unstable".to_string()),
+                (true, "changed the type of member `size` of `crate::types::Item` from `::std::option::Option<i32>` to `::std::option::Option<i64>`".to_string()),
+                (false, "removed unstable member `beta` of `crate::types::Item`".to_string()),
+                (false, "added stable member `color` to `crate::types::Item`".to_string()),
+                (true, "changed the kind of `crate::types::Color` from enum to union".to_string()),
+                (true, "removed stable member `Blue` of `crate::types::Color`".to_string()),
+                (false, "added stable member `Green` to `crate::types::Color`".to_string()),
+                (false, "added stable operation `crate::Client::put_item`".to_string()),
+            ],
+            changes
+        );
+    }
+}
@@ -0,0 +1,68 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::diff::{diff, ApiReport, Change};
+use anyhow::{Context, Result};
+use clap::Parser;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod diff;
+
+#[derive(Debug, Parser)]
+#[clap(name = "api-diff", version)]
+#[clap(about = "Flags breaking changes between two API reports of a generated crate")]
+struct Cli {
+    /// API report (`api.json`) of the previous generation of the crate
+    old: PathBuf,
+
+    /// API report (`api.json`) of the new generation of the crate
+    new: PathBuf,
+
+    /// Don't exit with a non-zero status if there are breaking changes
+    #[clap(long)]
+    allow_breaking: bool,
+}
+
+fn load(path: &Path) -> Result<ApiReport> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("failed to parse the API report in {}", path.display()))
+}
+
+fn print_changes(title: &str, changes: &[&Change]) {
+    if !changes.is_empty() {
+        println!("{title}:");
+        for change in changes {
+            println!("  - {}", change.description);
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let args = Cli::parse();
+    let old = load(&args.old)?;
+    let new = load(&args.new)?;
+
+    println!(
+        "Comparing the API of `{}` {} to `{}` {}",
+        old.crate_name, old.version, new.crate_name, new.version
+    );
+    let changes = diff(&old, &new);
+    let (breaking, other): (Vec<_>, Vec<_>) = changes.iter().partition(|change| change.breaking);
+    if changes.is_empty() {
+        println!("No changes");
+    }
+    print_changes("Breaking changes", &breaking);
+    print_changes("Other changes", &other);
+
+    if !breaking.is_empty() && !args.allow_breaking {
+        eprintln!("{} breaking change(s) found", breaking.len());
+        process::exit(1);
+    }
+    Ok(())
+}